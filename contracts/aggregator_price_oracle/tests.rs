@@ -0,0 +1,237 @@
+use crate::contract::*;
+use core::ops::{
+    Div,
+    Mul,
+};
+use ink::{
+    env::{
+        test::{
+            self,
+            recorded_events,
+            DefaultAccounts,
+            EmittedEvent,
+        },
+        DefaultEnvironment,
+    },
+    prelude::vec::Vec,
+};
+use logics::{
+    impls::{
+        aggregator_price_oracle::*,
+        exp_no_err::exp_scale,
+    },
+    traits::types::WrappedU256,
+};
+use openbrush::{
+    contracts::ownable::OwnableError,
+    traits::AccountId,
+};
+use primitive_types::U256;
+use scale::Decode;
+
+type Event = <AggregatorPriceOracleContract as ink::reflect::ContractEventBase>::Type;
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    test::default_accounts::<DefaultEnvironment>()
+}
+fn set_caller(id: AccountId) {
+    test::set_caller::<DefaultEnvironment>(id);
+}
+fn get_emitted_events() -> Vec<EmittedEvent> {
+    recorded_events().collect::<Vec<_>>()
+}
+fn decode_event(event: &EmittedEvent) -> Event {
+    <Event as Decode>::decode(&mut &event.data[..])
+        .expect("encountered invalid contract event data buffer")
+}
+fn percent(val: u128) -> WrappedU256 {
+    WrappedU256::from(exp_scale().mul(U256::from(val)).div(U256::from(100)))
+}
+
+#[ink::test]
+fn new_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let contract = AggregatorPriceOracleContract::new();
+    assert_eq!(contract.owner(), accounts.bob);
+    // asset without sources has no price, without calling any source
+    assert_eq!(contract.get_price(AccountId::from([0x01; 32])), None);
+}
+
+#[ink::test]
+fn set_sources_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = AggregatorPriceOracleContract::new();
+    let asset = AccountId::from([0x01; 32]);
+    let sources = PriceSources {
+        primary: AccountId::from([0x02; 32]),
+        anchor: Some(AccountId::from([0x03; 32])),
+        fallback: Some(AccountId::from([0x04; 32])),
+        anchor_tolerance_mantissa: percent(5),
+    };
+    assert!(contract.set_sources(asset, sources).is_ok());
+    assert_eq!(contract.sources(asset), Some(sources));
+    assert!(contract.remove_sources(asset).is_ok());
+    assert_eq!(contract.sources(asset), None);
+
+    assert_eq!(
+        contract.set_sources(
+            asset,
+            PriceSources {
+                anchor_tolerance_mantissa: percent(101),
+                ..sources
+            }
+        ),
+        Err(Error::InvalidAnchorTolerance)
+    );
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.set_sources(asset, sources),
+        Err(Error::Ownable(OwnableError::CallerIsNotOwner))
+    );
+}
+
+#[ink::test]
+fn set_derivative_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = AggregatorPriceOracleContract::new();
+    let asset = AccountId::from([0x01; 32]);
+    let derivative = DerivativeSource {
+        base: AccountId::from([0x02; 32]),
+        exchange_rate: ExchangeRateSource::Pool(asset),
+    };
+    assert!(contract.set_derivative(asset, derivative).is_ok());
+    assert_eq!(contract.derivative(asset), Some(derivative));
+    assert!(contract.remove_derivative(asset).is_ok());
+    assert_eq!(contract.derivative(asset), None);
+
+    assert_eq!(
+        contract.set_derivative(
+            asset,
+            DerivativeSource {
+                base: asset,
+                ..derivative
+            }
+        ),
+        Err(Error::InvalidDerivative)
+    );
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.set_derivative(asset, derivative),
+        Err(Error::Ownable(OwnableError::CallerIsNotOwner))
+    );
+}
+
+#[ink::test]
+fn override_price_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = AggregatorPriceOracleContract::new();
+    let asset = AccountId::from([0x01; 32]);
+    assert!(contract.set_guardian(accounts.charlie).is_ok());
+    assert_eq!(contract.guardian(), Some(accounts.charlie));
+    assert_eq!(
+        contract.set_override_price(asset, 100, 60_000, None),
+        Err(Error::CallerIsNotGuardian)
+    );
+
+    set_caller(accounts.charlie);
+    test::set_block_timestamp::<DefaultEnvironment>(10_000);
+    assert_eq!(
+        contract.set_override_price(asset, 0, 60_000, None),
+        Err(Error::InvalidOverride)
+    );
+    assert_eq!(
+        contract.set_override_price(asset, 100, MAX_OVERRIDE_DURATION + 1, None),
+        Err(Error::InvalidOverride)
+    );
+    assert!(contract
+        .set_override_price(asset, 100, 60_000, None)
+        .is_ok());
+    assert_eq!(
+        contract.override_price(asset),
+        Some(PriceOverride {
+            price: 100,
+            expires_at: 70_000,
+        })
+    );
+    // the override takes precedence over the unset sources
+    assert_eq!(contract.get_price(asset), Some(100));
+
+    // expires automatically
+    test::set_block_timestamp::<DefaultEnvironment>(70_000);
+    assert_eq!(contract.get_price(asset), None);
+
+    test::set_block_timestamp::<DefaultEnvironment>(80_000);
+    assert!(contract
+        .set_override_price(asset, 100, 60_000, None)
+        .is_ok());
+    assert!(contract.clear_override_price(asset).is_ok());
+    assert_eq!(contract.override_price(asset), None);
+    assert_eq!(contract.get_price(asset), None);
+
+    assert_eq!(
+        contract.set_guardian(accounts.charlie),
+        Err(Error::Ownable(OwnableError::CallerIsNotOwner))
+    );
+    assert_eq!(
+        contract.set_manager(accounts.charlie),
+        Err(Error::Ownable(OwnableError::CallerIsNotOwner))
+    );
+}
+
+#[ink::test]
+fn clear_expired_override_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = AggregatorPriceOracleContract::new();
+    let asset = AccountId::from([0x01; 32]);
+    assert!(contract.set_guardian(accounts.charlie).is_ok());
+    assert_eq!(
+        contract.clear_expired_override(asset),
+        Err(Error::InvalidOverride)
+    );
+
+    set_caller(accounts.charlie);
+    test::set_block_timestamp::<DefaultEnvironment>(10_000);
+    assert!(contract
+        .set_override_price(asset, 100, 60_000, None)
+        .is_ok());
+    // no pool was paused without one to pause
+    assert_eq!(contract.override_paused_pool(asset), None);
+
+    // anyone can clear it, only once expired
+    set_caller(accounts.django);
+    test::set_block_timestamp::<DefaultEnvironment>(69_999);
+    assert_eq!(
+        contract.clear_expired_override(asset),
+        Err(Error::OverrideNotExpired)
+    );
+    assert_eq!(contract.get_price(asset), Some(100));
+
+    test::set_block_timestamp::<DefaultEnvironment>(70_000);
+    assert!(contract.clear_expired_override(asset).is_ok());
+    assert_eq!(contract.override_price(asset), None);
+    assert_eq!(contract.override_paused_pool(asset), None);
+    assert_eq!(
+        contract.clear_expired_override(asset),
+        Err(Error::InvalidOverride)
+    );
+
+    let events = get_emitted_events();
+    if let Event::OverrideCleared(event) = decode_event(events.last().unwrap()) {
+        assert_eq!(event.asset, asset);
+        assert!(!event.borrow_resumed);
+    } else {
+        panic!("unexpected event kind: expected OverrideCleared event")
+    }
+}
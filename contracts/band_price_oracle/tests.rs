@@ -0,0 +1,83 @@
+use crate::contract::*;
+use ink::env::{
+    test::{
+        self,
+        DefaultAccounts,
+    },
+    DefaultEnvironment,
+};
+use logics::impls::band_price_oracle::*;
+use openbrush::{
+    contracts::ownable::OwnableError,
+    traits::{
+        AccountId,
+        String,
+    },
+};
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    test::default_accounts::<DefaultEnvironment>()
+}
+fn set_caller(id: AccountId) {
+    test::set_caller::<DefaultEnvironment>(id);
+}
+
+#[ink::test]
+fn new_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let std_reference = AccountId::from([0x01; 32]);
+    let contract = BandPriceOracleContract::new(std_reference);
+    assert_eq!(contract.owner(), accounts.bob);
+    assert_eq!(contract.std_reference(), Some(std_reference));
+}
+
+#[ink::test]
+fn set_symbols_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = BandPriceOracleContract::new(AccountId::from([0x01; 32]));
+    let asset = AccountId::from([0x02; 32]);
+    assert_eq!(contract.symbols(asset), None);
+    // asset without symbols has no price, without calling Band's contract
+    assert_eq!(contract.get_price(asset), None);
+
+    assert!(contract
+        .set_symbols(asset, String::from("ASTR"), String::from("USD"), 60_000)
+        .is_ok());
+    assert_eq!(
+        contract.symbols(asset),
+        Some(BandSymbols {
+            base: String::from("ASTR"),
+            quote: String::from("USD"),
+            heartbeat: 60_000,
+        })
+    );
+    assert!(contract.remove_symbols(asset).is_ok());
+    assert_eq!(contract.symbols(asset), None);
+}
+
+#[ink::test]
+fn admin_functions_are_owner_only() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = BandPriceOracleContract::new(AccountId::from([0x01; 32]));
+    let asset = AccountId::from([0x02; 32]);
+    assert_eq!(
+        contract.set_fixed_price(asset, 1),
+        Err(Error::FixedPriceNotSupported)
+    );
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.set_std_reference(accounts.charlie),
+        Err(Error::Ownable(OwnableError::CallerIsNotOwner))
+    );
+    assert_eq!(
+        contract.set_symbols(asset, String::from("ASTR"), String::from("USD"), 60_000),
+        Err(Error::Ownable(OwnableError::CallerIsNotOwner))
+    );
+}
@@ -0,0 +1,63 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![feature(min_specialization)]
+
+#[cfg(test)]
+mod tests;
+
+/// Definition of Collateral Swap Contract
+#[openbrush::contract]
+pub mod contract {
+    use ink::prelude::vec::Vec;
+    use logics::{
+        impls::collateral_swap::*,
+        traits::flashloan_receiver::FlashloanReceiver,
+    };
+    use openbrush::traits::Storage;
+
+    /// Contract's Storage
+    #[ink(storage)]
+    #[derive(Storage, Default)]
+    pub struct CollateralSwapContract {
+        #[storage_field]
+        collateral_swap: Data,
+    }
+
+    impl CollateralSwap for CollateralSwapContract {}
+    impl Internal for CollateralSwapContract {}
+
+    impl FlashloanReceiver for CollateralSwapContract {
+        #[ink(message)]
+        fn execute_operation(
+            &mut self,
+            assets: Vec<AccountId>,
+            amounts: Vec<Balance>,
+            premiums: Vec<Balance>,
+            initiator: AccountId,
+            params: Vec<u8>,
+        ) -> bool {
+            self._execute_operation(assets, amounts, premiums, initiator, params)
+                .is_ok()
+        }
+    }
+
+    impl CollateralSwapContract {
+        /// Generate this contract
+        #[ink(constructor)]
+        pub fn new(
+            controller: AccountId,
+            flashloan_gateway: AccountId,
+            dex_router: AccountId,
+        ) -> Self {
+            let mut instance: CollateralSwapContract = Default::default();
+            instance._initialize(controller, flashloan_gateway, dex_router);
+            instance
+        }
+    }
+}
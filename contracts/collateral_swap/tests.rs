@@ -0,0 +1,54 @@
+use crate::contract::*;
+use ink::env::{
+    test::{
+        self,
+        DefaultAccounts,
+    },
+    DefaultEnvironment,
+};
+use logics::impls::collateral_swap::*;
+use openbrush::traits::AccountId;
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    test::default_accounts::<DefaultEnvironment>()
+}
+fn set_caller(id: AccountId) {
+    test::set_caller::<DefaultEnvironment>(id);
+}
+
+#[ink::test]
+fn new_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let controller = AccountId::from([0x01; 32]);
+    let flashloan_gateway = AccountId::from([0x02; 32]);
+    let dex_router = AccountId::from([0x03; 32]);
+    let contract = CollateralSwapContract::new(controller, flashloan_gateway, dex_router);
+    assert_eq!(contract.controller(), Some(controller));
+    assert_eq!(contract.flashloan_gateway(), Some(flashloan_gateway));
+    assert_eq!(contract.dex_router(), Some(dex_router));
+}
+
+#[ink::test]
+fn swap_collateral_fails_with_invalid_path() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let asset = AccountId::from([0x04; 32]);
+    let mut contract = CollateralSwapContract::new(
+        AccountId::from([0x01; 32]),
+        AccountId::from([0x02; 32]),
+        AccountId::from([0x03; 32]),
+    );
+    assert_eq!(
+        contract.swap_collateral(100, 0, vec![asset]).unwrap_err(),
+        Error::InvalidSwapPath
+    );
+    assert_eq!(
+        contract
+            .swap_collateral(100, 0, vec![asset, asset])
+            .unwrap_err(),
+        Error::InvalidSwapPath
+    );
+}
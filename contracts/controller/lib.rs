@@ -13,22 +13,40 @@ mod tests;
 /// Definition of Controller Contract
 #[openbrush::contract]
 pub mod contract {
-    use ink::codegen::{
-        EmitEvent,
-        Env,
+    use ink::{
+        codegen::{
+            EmitEvent,
+            Env,
+        },
+        prelude::vec::Vec,
     };
-    use logics::impls::controller::{
-        Internal,
-        *,
+    use logics::{
+        impls::{
+            controller::{
+                Internal,
+                *,
+            },
+            upgradeable::{
+                self,
+                Internal as UpgradeableInternal,
+                Upgradeable,
+            },
+        },
+        traits::types::ActionId,
     };
     use openbrush::traits::Storage;
 
+    /// Version of the storage layout this code is built for
+    pub const STORAGE_VERSION: u32 = 1;
+
     /// Contract's Storage
     #[ink(storage)]
     #[derive(Default, Storage)]
     pub struct ControllerContract {
         #[storage_field]
         controller: Data,
+        #[storage_field]
+        upgradeable: upgradeable::Data,
     }
 
     /// Event: Controller starts to support Pool
@@ -37,14 +55,52 @@ pub mod contract {
         pub pool: AccountId,
     }
 
+    /// Event: Protocol enters a stage of the emergency shutdown
+    #[ink(event)]
+    pub struct ProtocolStatusChanged {
+        pub status: ProtocolStatus,
+    }
+
+    /// Event: Privileged action is executed.
+    ///
+    /// `action` is the selector of the message, values are SCALE encoded.
+    #[ink(event)]
+    pub struct AdminAction {
+        #[ink(topic)]
+        pub action: ActionId,
+        #[ink(topic)]
+        pub caller: AccountId,
+        #[ink(topic)]
+        pub target: Option<AccountId>,
+        pub old_value: Vec<u8>,
+        pub new_value: Vec<u8>,
+    }
+
+    /// Event: Code of this contract is replaced
+    #[ink(event)]
+    pub struct CodeUpgraded {
+        pub code_hash: Hash,
+        pub version: u32,
+    }
+
+    /// Event: Storage is migrated to the layout of the running code
+    #[ink(event)]
+    pub struct Migrated {
+        pub from: u32,
+        pub to: u32,
+    }
+
     impl Controller for ControllerContract {}
 
+    impl Upgradeable for ControllerContract {}
+
     impl ControllerContract {
         /// Generate this contract
         #[ink(constructor)]
         pub fn new(manager: AccountId) -> Self {
             let mut instance = Self::default();
             instance.controller.manager = Some(manager);
+            instance._set_version(STORAGE_VERSION);
             instance
         }
     }
@@ -53,5 +109,46 @@ pub mod contract {
         fn _emit_market_listed_event(&self, pool: AccountId) {
             self.env().emit_event(MarketListed { pool });
         }
+
+        fn _emit_protocol_status_changed_event(&self, status: ProtocolStatus) {
+            self.env().emit_event(ProtocolStatusChanged { status });
+        }
+
+        fn _emit_admin_action_event(
+            &self,
+            action: ActionId,
+            target: Option<AccountId>,
+            old_value: Vec<u8>,
+            new_value: Vec<u8>,
+        ) {
+            self.env().emit_event(AdminAction {
+                action,
+                caller: self.env().caller(),
+                target,
+                old_value,
+                new_value,
+            })
+        }
+    }
+
+    impl upgradeable::Internal for ControllerContract {
+        fn _assert_upgrade_admin(&self) -> upgradeable::Result<()> {
+            if self._manager() != Some(self.env().caller()) {
+                return Err(upgradeable::Error::CallerIsNotAdmin)
+            }
+            Ok(())
+        }
+
+        fn _code_version(&self) -> u32 {
+            STORAGE_VERSION
+        }
+
+        fn _emit_code_upgraded_event(&self, code_hash: Hash, version: u32) {
+            self.env().emit_event(CodeUpgraded { code_hash, version });
+        }
+
+        fn _emit_migrated_event(&self, from: u32, to: u32) {
+            self.env().emit_event(Migrated { from, to });
+        }
     }
 }
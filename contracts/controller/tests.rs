@@ -17,10 +17,18 @@ use logics::{
     impls::{
         controller::*,
         exp_no_err::exp_scale,
+        upgradeable::{
+            self,
+            Internal as UpgradeableInternal,
+            Upgradeable,
+        },
     },
     traits::types::WrappedU256,
 };
-use openbrush::traits::AccountId;
+use openbrush::traits::{
+    AccountId,
+    Hash,
+};
 use primitive_types::U256;
 use scale::Decode;
 
@@ -209,6 +217,34 @@ fn support_market_works() {
     assert_eq!(contract.markets(), [p1, p2]);
 }
 
+#[ink::test]
+fn support_market_works_by_pool_factory() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let mut contract = ControllerContract::new(accounts.bob);
+    assert!(contract.set_pool_factory(Some(accounts.charlie)).is_ok());
+    assert_eq!(contract.pool_factory(), Some(accounts.charlie));
+
+    let pool = AccountId::from([0x01; 32]);
+    let underlying = AccountId::from([0x01; 32]);
+    set_caller(accounts.charlie);
+    assert!(contract.support_market(pool, underlying).is_ok());
+    assert_eq!(contract.markets(), [pool]);
+    // the factory only lists markets
+    assert_eq!(
+        contract.set_borrow_cap(pool, 0).unwrap_err(),
+        Error::CallerIsNotManager
+    );
+
+    set_caller(accounts.django);
+    let pool = AccountId::from([0x02; 32]);
+    let underlying = AccountId::from([0x02; 32]);
+    assert_eq!(
+        contract.support_market(pool, underlying).unwrap_err(),
+        Error::CallerIsNotManager
+    );
+}
+
 #[ink::test]
 fn support_market_fails_when_duplicate() {
     let accounts = default_accounts();
@@ -304,6 +340,50 @@ fn set_collateral_factor_mantissa_fail_when_invalid_value() {
     );
 }
 
+#[ink::test]
+fn set_close_factor_and_liquidation_incentive_fail_when_out_of_bounds() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let mut contract = ControllerContract::new(accounts.bob);
+    let percent = |val: u128| exp_scale().mul(U256::from(val)).div(U256::from(100));
+
+    assert_eq!(
+        contract
+            .set_close_factor_mantissa(WrappedU256::from(percent(4)))
+            .unwrap_err(),
+        Error::InvalidCloseFactor
+    );
+    assert_eq!(
+        contract
+            .set_close_factor_mantissa(WrappedU256::from(percent(91)))
+            .unwrap_err(),
+        Error::InvalidCloseFactor
+    );
+    assert!(contract
+        .set_close_factor_mantissa(WrappedU256::from(percent(50)))
+        .is_ok());
+    assert_eq!(
+        contract.close_factor_mantissa(),
+        WrappedU256::from(percent(50))
+    );
+
+    assert_eq!(
+        contract
+            .set_liquidation_incentive_mantissa(WrappedU256::from(percent(99)))
+            .unwrap_err(),
+        Error::InvalidLiquidationIncentive
+    );
+    assert_eq!(
+        contract
+            .set_liquidation_incentive_mantissa(WrappedU256::from(percent(121)))
+            .unwrap_err(),
+        Error::InvalidLiquidationIncentive
+    );
+    assert!(contract
+        .set_liquidation_incentive_mantissa(WrappedU256::from(percent(108)))
+        .is_ok());
+}
+
 #[ink::test]
 fn mint_guardian_paused_works() {
     let accounts = default_accounts();
@@ -375,6 +455,7 @@ fn assert_manager_works() {
     let dummy_id = AccountId::from([0xff; 32]);
     let underlying = AccountId::from([0x01; 32]);
     let admin_funcs: Vec<Result<()>> = vec![
+        contract.set_manager(dummy_id),
         contract.set_price_oracle(dummy_id),
         contract.support_market(dummy_id, underlying),
         contract.support_market_with_collateral_factor_mantissa(
@@ -390,8 +471,150 @@ fn assert_manager_works() {
         contract.set_close_factor_mantissa(WrappedU256::from(0)),
         contract.set_liquidation_incentive_mantissa(WrappedU256::from(0)),
         contract.set_borrow_cap(dummy_id, 0),
+        contract.set_shutdown_guardian(None),
+        contract.set_pool_factory(None),
+        contract.recover(),
+        contract.reactivate(),
     ];
     for func in admin_funcs {
         assert_eq!(func.unwrap_err(), Error::CallerIsNotManager);
     }
 }
+
+#[ink::test]
+fn shutdown_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let mut contract = ControllerContract::new(accounts.bob);
+
+    let pool = AccountId::from([0x01; 32]);
+    let underlying = AccountId::from([0x01; 32]);
+    assert!(contract.support_market(pool, underlying).is_ok());
+    assert_eq!(contract.protocol_status(), ProtocolStatus::Active);
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.shutdown().unwrap_err(),
+        Error::CallerIsNotShutdownGuardian
+    );
+    set_caller(accounts.bob);
+    assert!(contract
+        .set_shutdown_guardian(Some(accounts.charlie))
+        .is_ok());
+    assert_eq!(contract.shutdown_guardian(), Some(accounts.charlie));
+
+    // shutdown freezes mints, borrows and transfers
+    set_caller(accounts.charlie);
+    assert!(contract.shutdown().is_ok());
+    assert_eq!(contract.protocol_status(), ProtocolStatus::Shutdown);
+    assert_eq!(
+        contract.shutdown().unwrap_err(),
+        Error::InvalidProtocolStatus
+    );
+    assert_eq!(
+        contract.mint_allowed(pool, accounts.bob, 0).unwrap_err(),
+        Error::ProtocolIsShutdown
+    );
+    assert_eq!(
+        contract
+            .borrow_allowed(pool, accounts.bob, 0, None)
+            .unwrap_err(),
+        Error::ProtocolIsShutdown
+    );
+    assert_eq!(
+        contract
+            .transfer_allowed(pool, accounts.bob, accounts.charlie, 0, None)
+            .unwrap_err(),
+        Error::ProtocolIsShutdown
+    );
+
+    // re-activation is staged by the manager
+    assert_eq!(contract.recover().unwrap_err(), Error::CallerIsNotManager);
+    set_caller(accounts.bob);
+    assert_eq!(
+        contract.reactivate().unwrap_err(),
+        Error::InvalidProtocolStatus
+    );
+    assert!(contract.recover().is_ok());
+    assert_eq!(contract.protocol_status(), ProtocolStatus::Recovering);
+    assert!(contract.mint_allowed(pool, accounts.bob, 0).is_ok());
+    assert_eq!(
+        contract
+            .borrow_allowed(pool, accounts.bob, 0, None)
+            .unwrap_err(),
+        Error::ProtocolIsShutdown
+    );
+    assert!(contract.reactivate().is_ok());
+    assert_eq!(contract.protocol_status(), ProtocolStatus::Active);
+}
+
+#[ink::test]
+fn set_manager_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = ControllerContract::new(accounts.bob);
+    assert!(contract.set_manager(accounts.charlie).is_ok());
+    assert_eq!(contract.manager(), Some(accounts.bob));
+    assert_eq!(contract.pending_manager(), Some(accounts.charlie));
+    assert_eq!(
+        contract.accept_manager().unwrap_err(),
+        Error::CallerIsNotPendingManager
+    );
+
+    set_caller(accounts.charlie);
+    assert!(contract.accept_manager().is_ok());
+    assert_eq!(contract.manager(), Some(accounts.charlie));
+    assert_eq!(contract.pending_manager(), None);
+
+    set_caller(accounts.bob);
+    assert_eq!(
+        contract.set_manager(accounts.bob).unwrap_err(),
+        Error::CallerIsNotManager
+    );
+}
+
+#[ink::test]
+fn upgrade_code_fails_by_non_manager() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let mut contract = ControllerContract::new(accounts.bob);
+    assert_eq!(contract.version(), STORAGE_VERSION);
+    assert_eq!(contract.code_version(), STORAGE_VERSION);
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.upgrade_code(Hash::from([0x01; 32])).unwrap_err(),
+        upgradeable::Error::CallerIsNotAdmin
+    );
+}
+
+#[ink::test]
+fn migrate_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let mut contract = ControllerContract::new(accounts.bob);
+
+    // nothing to migrate
+    assert!(contract.migrate().is_ok());
+    assert_eq!(contract.version(), STORAGE_VERSION);
+
+    // no step from a layout this code does not know
+    contract._set_version(STORAGE_VERSION - 1);
+    assert_eq!(
+        contract.migrate().unwrap_err(),
+        upgradeable::Error::MigrationNotFound
+    );
+    assert_eq!(contract.version(), STORAGE_VERSION - 1);
+
+    // storage newer than the code
+    contract._set_version(STORAGE_VERSION + 1);
+    assert_eq!(
+        contract.migrate().unwrap_err(),
+        upgradeable::Error::VersionMismatch
+    );
+    assert_eq!(
+        contract.upgrade_code(Hash::from([0x01; 32])).unwrap_err(),
+        upgradeable::Error::VersionMismatch
+    );
+}
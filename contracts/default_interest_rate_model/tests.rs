@@ -30,3 +30,22 @@ fn new_works() {
         WrappedU256::from(0),
     );
 }
+
+#[ink::test]
+fn utilization_rate_works() {
+    use logics::impls::interest_rate_model::InterestRateModel;
+    use primitive_types::U256;
+
+    let contract = DefaultInterestRateModelContract::new(
+        WrappedU256::from(0),
+        WrappedU256::from(0),
+        WrappedU256::from(0),
+        WrappedU256::from(0),
+    );
+    assert_eq!(contract.utilization_rate(100, 0, 0), WrappedU256::from(0));
+    // 300 / (100 + 300 - 200)
+    assert_eq!(
+        U256::from(contract.utilization_rate(100, 300, 200)),
+        U256::from(10_u128.pow(18)) * 3 / 2
+    );
+}
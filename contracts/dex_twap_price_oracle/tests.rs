@@ -0,0 +1,72 @@
+use crate::contract::*;
+use ink::env::{
+    test::{
+        self,
+        DefaultAccounts,
+    },
+    DefaultEnvironment,
+};
+use logics::impls::dex_twap_price_oracle::*;
+use openbrush::{
+    contracts::ownable::OwnableError,
+    traits::AccountId,
+};
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    test::default_accounts::<DefaultEnvironment>()
+}
+fn set_caller(id: AccountId) {
+    test::set_caller::<DefaultEnvironment>(id);
+}
+
+#[ink::test]
+fn new_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let contract = DexTwapPriceOracleContract::new();
+    assert_eq!(contract.owner(), accounts.bob);
+    assert_eq!(contract.get_price(AccountId::from([0x01; 32])), None);
+}
+
+#[ink::test]
+fn update_fails_without_config() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = DexTwapPriceOracleContract::new();
+    assert_eq!(
+        contract.update(AccountId::from([0x01; 32])),
+        Err(Error::TwapConfigIsNotSet)
+    );
+}
+
+#[ink::test]
+fn set_twap_config_validates_and_is_owner_only() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = DexTwapPriceOracleContract::new();
+    let asset = AccountId::from([0x01; 32]);
+    let config = TwapConfig {
+        pair: AccountId::from([0x02; 32]),
+        is_token_0: true,
+        window: 0,
+        base_decimals: 18,
+        quote_decimals: 6,
+    };
+    assert_eq!(
+        contract.set_twap_config(asset, config),
+        Err(Error::InvalidTwapConfig)
+    );
+    assert_eq!(
+        contract.set_fixed_price(asset, 1),
+        Err(Error::FixedPriceNotSupported)
+    );
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.set_twap_config(asset, config),
+        Err(Error::Ownable(OwnableError::CallerIsNotOwner))
+    );
+}
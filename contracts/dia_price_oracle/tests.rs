@@ -0,0 +1,83 @@
+use crate::contract::*;
+use ink::env::{
+    test::{
+        self,
+        DefaultAccounts,
+    },
+    DefaultEnvironment,
+};
+use logics::impls::dia_price_oracle::*;
+use openbrush::{
+    contracts::ownable::OwnableError,
+    traits::{
+        AccountId,
+        String,
+    },
+};
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    test::default_accounts::<DefaultEnvironment>()
+}
+fn set_caller(id: AccountId) {
+    test::set_caller::<DefaultEnvironment>(id);
+}
+
+#[ink::test]
+fn new_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dia_oracle = AccountId::from([0x01; 32]);
+    let contract = DIAPriceOracleContract::new(dia_oracle);
+    assert_eq!(contract.owner(), accounts.bob);
+    assert_eq!(contract.dia_oracle(), Some(dia_oracle));
+}
+
+#[ink::test]
+fn set_feed_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = DIAPriceOracleContract::new(AccountId::from([0x01; 32]));
+    let asset = AccountId::from([0x02; 32]);
+    assert_eq!(contract.feed(asset), None);
+    // asset without feed has no price, without calling DIA's contract
+    assert_eq!(contract.get_price(asset), None);
+
+    assert!(contract
+        .set_feed(asset, String::from("ASTR/USD"), 18, 60_000)
+        .is_ok());
+    assert_eq!(
+        contract.feed(asset),
+        Some(DIAFeed {
+            key: String::from("ASTR/USD"),
+            decimals: 18,
+            heartbeat: 60_000,
+        })
+    );
+    assert!(contract.remove_feed(asset).is_ok());
+    assert_eq!(contract.feed(asset), None);
+}
+
+#[ink::test]
+fn admin_functions_are_owner_only() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = DIAPriceOracleContract::new(AccountId::from([0x01; 32]));
+    let asset = AccountId::from([0x02; 32]);
+    assert_eq!(
+        contract.set_fixed_price(asset, 1),
+        Err(Error::FixedPriceNotSupported)
+    );
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.set_dia_oracle(accounts.charlie),
+        Err(Error::Ownable(OwnableError::CallerIsNotOwner))
+    );
+    assert_eq!(
+        contract.set_feed(asset, String::from("ASTR/USD"), 18, 60_000),
+        Err(Error::Ownable(OwnableError::CallerIsNotOwner))
+    );
+}
@@ -0,0 +1,113 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![feature(min_specialization)]
+
+#[cfg(test)]
+mod tests;
+
+/// Definition of Double Kink Rate Model Contract
+#[openbrush::contract]
+pub mod contract {
+    use ink::prelude::vec::Vec;
+    use logics::{
+        impls::{
+            double_kink_rate_model::{
+                Data,
+                Internal,
+                *,
+            },
+            interest_rate_model::{
+                sample_curve,
+                utilization_rate,
+                InterestRateModel,
+                RateCurvePoint,
+            },
+        },
+        traits::types::WrappedU256,
+    };
+    use openbrush::traits::Storage;
+
+    /// Contract's Storage
+    #[ink(storage)]
+    #[derive(Storage)]
+    pub struct DoubleKinkRateModelContract {
+        #[storage_field]
+        model: Data,
+    }
+
+    impl DoubleKinkRateModel for DoubleKinkRateModelContract {}
+
+    impl InterestRateModel for DoubleKinkRateModelContract {
+        #[ink(message)]
+        fn utilization_rate(
+            &self,
+            cash: Balance,
+            borrows: Balance,
+            reserves: Balance,
+        ) -> WrappedU256 {
+            WrappedU256::from(utilization_rate(cash, borrows, reserves))
+        }
+
+        #[ink(message)]
+        fn get_borrow_rate(
+            &self,
+            cash: Balance,
+            borrows: Balance,
+            reserves: Balance,
+        ) -> WrappedU256 {
+            self._get_borrow_rate(cash, borrows, reserves)
+        }
+
+        #[ink(message)]
+        fn get_supply_rate(
+            &self,
+            cash: Balance,
+            borrows: Balance,
+            reserves: Balance,
+            reserve_factor_mantissa: WrappedU256,
+        ) -> WrappedU256 {
+            self._get_supply_rate(cash, borrows, reserves, reserve_factor_mantissa)
+        }
+
+        #[ink(message)]
+        fn sample_curve(
+            &self,
+            points: u8,
+            reserve_factor_mantissa: WrappedU256,
+        ) -> Vec<RateCurvePoint> {
+            sample_curve(self, points, reserve_factor_mantissa)
+        }
+    }
+
+    impl DoubleKinkRateModelContract {
+        /// Generate this contract
+        #[ink(constructor)]
+        pub fn new(
+            base_rate_per_year: WrappedU256,
+            multiplier_per_year_slope_1: WrappedU256,
+            multiplier_per_year_slope_2: WrappedU256,
+            multiplier_per_year_slope_3: WrappedU256,
+            kink_1: WrappedU256,
+            kink_2: WrappedU256,
+        ) -> Self {
+            Self {
+                model: Data::new(
+                    base_rate_per_year,
+                    [
+                        multiplier_per_year_slope_1,
+                        multiplier_per_year_slope_2,
+                        multiplier_per_year_slope_3,
+                    ],
+                    kink_1,
+                    kink_2,
+                ),
+            }
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use crate::contract::*;
+use core::ops::{
+    Add,
+    Div,
+    Mul,
+};
+use ink::env::{
+    test::{
+        self,
+        DefaultAccounts,
+    },
+    DefaultEnvironment,
+};
+use logics::{
+    impls::{
+        double_kink_rate_model::DoubleKinkRateModel,
+        interest_rate_model::{
+            base,
+            milliseconds_per_year,
+            InterestRateModel,
+        },
+    },
+    traits::types::WrappedU256,
+};
+use openbrush::traits::AccountId;
+use primitive_types::U256;
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    test::default_accounts::<DefaultEnvironment>()
+}
+fn set_caller(id: AccountId) {
+    test::set_caller::<DefaultEnvironment>(id);
+}
+fn percent(val: u128) -> U256 {
+    base().mul(U256::from(val)).div(U256::from(100))
+}
+fn per_year(val: U256) -> WrappedU256 {
+    WrappedU256::from(val.mul(milliseconds_per_year()))
+}
+
+#[ink::test]
+fn new_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let contract = DoubleKinkRateModelContract::new(
+        per_year(percent(1)),
+        per_year(percent(10)),
+        per_year(percent(50)),
+        per_year(percent(300)),
+        WrappedU256::from(percent(80)),
+        WrappedU256::from(percent(95)),
+    );
+    assert_eq!(
+        contract.base_rate_per_milli_second(),
+        WrappedU256::from(percent(1))
+    );
+    assert_eq!(
+        contract.kinks(),
+        (
+            WrappedU256::from(percent(80)),
+            WrappedU256::from(percent(95))
+        )
+    );
+    // utilization 100%: 1% + 10% * 80% + 50% * 15% + 300% * 5%
+    assert_eq!(
+        U256::from(contract.get_borrow_rate(0, 100, 0)),
+        percent(1)
+            .add(percent(8))
+            .add(percent(75).div(10))
+            .add(percent(15))
+    );
+}
+
+#[ink::test]
+#[should_panic(expected = "kinks are not ordered")]
+fn new_panics_with_unordered_kinks() {
+    let zero = WrappedU256::from(U256::zero());
+    DoubleKinkRateModelContract::new(
+        zero,
+        zero,
+        zero,
+        zero,
+        WrappedU256::from(percent(95)),
+        WrappedU256::from(percent(80)),
+    );
+}
@@ -0,0 +1,209 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![feature(min_specialization)]
+
+#[cfg(test)]
+mod tests;
+
+/// Definition of Dynamic Rate Model Contract
+#[openbrush::contract]
+pub mod contract {
+    use ink::{
+        codegen::{
+            EmitEvent,
+            Env,
+        },
+        prelude::vec::Vec,
+    };
+    use logics::{
+        impls::{
+            dynamic_rate_model::{
+                Data,
+                Internal,
+                *,
+            },
+            interest_rate_model::{
+                sample_curve,
+                utilization_rate,
+                InterestRateModel,
+                RateCurvePoint,
+            },
+            ownable_2step::{
+                self,
+                Internal as Ownable2StepInternal,
+                Ownable2Step,
+            },
+        },
+        traits::types::WrappedU256,
+    };
+    use openbrush::{
+        contracts::ownable::*,
+        modifiers,
+        traits::Storage,
+    };
+
+    /// Contract's Storage
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct DynamicRateModelContract {
+        #[storage_field]
+        model: Data,
+        #[storage_field]
+        ownable: ownable::Data,
+        #[storage_field]
+        ownable_2step: ownable_2step::Data,
+    }
+
+    /// Event: Multiplier of the model is stored
+    #[ink(event)]
+    pub struct NewMultiplier {
+        pub multiplier_per_milli_second: WrappedU256,
+    }
+
+    /// Event: Ownership is proposed to a new owner
+    #[ink(event)]
+    pub struct OwnershipTransferStarted {
+        #[ink(topic)]
+        pub previous_owner: AccountId,
+        #[ink(topic)]
+        pub new_owner: AccountId,
+    }
+
+    /// Event: Ownership is transferred
+    #[ink(event)]
+    pub struct OwnershipTransferred {
+        #[ink(topic)]
+        pub previous_owner: Option<AccountId>,
+        #[ink(topic)]
+        pub new_owner: Option<AccountId>,
+    }
+
+    impl Ownable for DynamicRateModelContract {
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn transfer_ownership(
+            &mut self,
+            new_owner: AccountId,
+        ) -> core::result::Result<(), OwnableError> {
+            self._propose_owner(new_owner)
+        }
+
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn renounce_ownership(&mut self) -> core::result::Result<(), OwnableError> {
+            self._renounce_ownership()
+        }
+    }
+
+    impl Ownable2Step for DynamicRateModelContract {}
+
+    impl ownable::Internal for DynamicRateModelContract {
+        fn _emit_ownership_transferred_event(
+            &self,
+            previous_owner: Option<AccountId>,
+            new_owner: Option<AccountId>,
+        ) {
+            self.env().emit_event(OwnershipTransferred {
+                previous_owner,
+                new_owner,
+            })
+        }
+    }
+
+    impl ownable_2step::Internal for DynamicRateModelContract {
+        fn _emit_ownership_transfer_started_event(
+            &self,
+            previous_owner: AccountId,
+            new_owner: AccountId,
+        ) {
+            self.env().emit_event(OwnershipTransferStarted {
+                previous_owner,
+                new_owner,
+            })
+        }
+    }
+
+    impl DynamicRateModel for DynamicRateModelContract {}
+
+    impl InterestRateModel for DynamicRateModelContract {
+        #[ink(message)]
+        fn utilization_rate(
+            &self,
+            cash: Balance,
+            borrows: Balance,
+            reserves: Balance,
+        ) -> WrappedU256 {
+            WrappedU256::from(utilization_rate(cash, borrows, reserves))
+        }
+
+        #[ink(message)]
+        fn get_borrow_rate(
+            &self,
+            cash: Balance,
+            borrows: Balance,
+            reserves: Balance,
+        ) -> WrappedU256 {
+            self._get_borrow_rate(cash, borrows, reserves)
+        }
+
+        #[ink(message)]
+        fn get_supply_rate(
+            &self,
+            cash: Balance,
+            borrows: Balance,
+            reserves: Balance,
+            reserve_factor_mantissa: WrappedU256,
+        ) -> WrappedU256 {
+            self._get_supply_rate(cash, borrows, reserves, reserve_factor_mantissa)
+        }
+
+        #[ink(message)]
+        fn sample_curve(
+            &self,
+            points: u8,
+            reserve_factor_mantissa: WrappedU256,
+        ) -> Vec<RateCurvePoint> {
+            sample_curve(self, points, reserve_factor_mantissa)
+        }
+    }
+
+    impl Internal for DynamicRateModelContract {
+        fn _emit_new_multiplier_event(&self, multiplier_per_milli_second: WrappedU256) {
+            self.env().emit_event(NewMultiplier {
+                multiplier_per_milli_second,
+            })
+        }
+    }
+
+    impl DynamicRateModelContract {
+        /// Generate this contract
+        #[ink(constructor)]
+        pub fn new(
+            base_rate_per_year: WrappedU256,
+            multiplier_per_year: WrappedU256,
+            min_multiplier_per_year: WrappedU256,
+            max_multiplier_per_year: WrappedU256,
+            target_utilization: WrappedU256,
+            adjustment_speed: WrappedU256,
+        ) -> Self {
+            let mut instance = Self::default();
+            instance._init_with_owner(Self::env().caller());
+            instance.model = Data::new(
+                base_rate_per_year,
+                multiplier_per_year,
+                min_multiplier_per_year,
+                max_multiplier_per_year,
+                target_utilization,
+                adjustment_speed,
+                Self::env().block_timestamp(),
+            );
+            instance
+        }
+    }
+}
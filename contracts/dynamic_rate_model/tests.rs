@@ -0,0 +1,144 @@
+use crate::contract::*;
+use core::ops::{
+    Add,
+    Div,
+    Mul,
+};
+use ink::env::{
+    test::{
+        self,
+        DefaultAccounts,
+    },
+    DefaultEnvironment,
+};
+use logics::{
+    impls::{
+        dynamic_rate_model::*,
+        interest_rate_model::{
+            base,
+            milliseconds_per_year,
+            InterestRateModel,
+        },
+    },
+    traits::types::WrappedU256,
+};
+use openbrush::{
+    contracts::ownable::OwnableError,
+    traits::AccountId,
+};
+use primitive_types::U256;
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    test::default_accounts::<DefaultEnvironment>()
+}
+fn set_caller(id: AccountId) {
+    test::set_caller::<DefaultEnvironment>(id);
+}
+fn set_block_timestamp(timestamp: u64) {
+    test::set_block_timestamp::<DefaultEnvironment>(timestamp);
+}
+fn percent(val: u128) -> U256 {
+    base().mul(U256::from(val)).div(U256::from(100))
+}
+fn per_year(val: U256) -> WrappedU256 {
+    WrappedU256::from(val.mul(milliseconds_per_year()))
+}
+fn new_contract() -> DynamicRateModelContract {
+    DynamicRateModelContract::new(
+        per_year(U256::from(2)),
+        per_year(base()),
+        per_year(base().div(10)),
+        per_year(base().mul(10)),
+        WrappedU256::from(percent(80)),
+        WrappedU256::from(percent(1)),
+    )
+}
+
+#[ink::test]
+fn new_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let contract = new_contract();
+    assert_eq!(contract.owner(), accounts.bob);
+    assert_eq!(contract.pool(), None);
+    assert_eq!(
+        contract.multiplier_per_milli_second(),
+        WrappedU256::from(base())
+    );
+    assert_eq!(
+        contract.target_utilization(),
+        WrappedU256::from(percent(80))
+    );
+    assert_eq!(contract.adjustment_speed(), WrappedU256::from(percent(1)));
+    // rate is base + util * multiplier at the target utilization
+    assert_eq!(
+        contract.get_borrow_rate(20, 80, 0),
+        WrappedU256::from(percent(80).add(U256::from(2)))
+    );
+}
+
+#[ink::test]
+fn borrow_rate_follows_utilization_over_time() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    set_block_timestamp(0);
+
+    let contract = new_contract();
+    set_block_timestamp(10);
+    // full utilization raises the multiplier by 1% per ms
+    assert_eq!(
+        contract.current_multiplier_per_milli_second(WrappedU256::from(base())),
+        WrappedU256::from(base().add(percent(10)))
+    );
+    assert_eq!(
+        contract.get_borrow_rate(0, 100, 0),
+        WrappedU256::from(base().add(percent(10)).add(U256::from(2)))
+    );
+    // the stored multiplier is unchanged until it is updated
+    assert_eq!(
+        contract.multiplier_per_milli_second(),
+        WrappedU256::from(base())
+    );
+    assert_eq!(contract.last_update(), 0);
+}
+
+#[ink::test]
+fn update_multiplier_fails_without_pool() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = new_contract();
+    assert_eq!(contract.update_multiplier(), Err(Error::PoolIsNotSet));
+}
+
+#[ink::test]
+fn setters_work() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = new_contract();
+    assert!(contract.set_pool(accounts.charlie).is_ok());
+    assert_eq!(contract.pool(), Some(accounts.charlie));
+    assert!(contract
+        .set_controller_params(
+            WrappedU256::from(percent(90)),
+            WrappedU256::from(percent(2))
+        )
+        .is_ok());
+    assert_eq!(
+        contract.target_utilization(),
+        WrappedU256::from(percent(90))
+    );
+    assert_eq!(contract.adjustment_speed(), WrappedU256::from(percent(2)));
+    assert_eq!(
+        contract.set_controller_params(WrappedU256::from(base()), WrappedU256::from(percent(2))),
+        Err(Error::InvalidTargetUtilization)
+    );
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.set_pool(accounts.charlie),
+        Err(Error::Ownable(OwnableError::CallerIsNotOwner))
+    );
+}
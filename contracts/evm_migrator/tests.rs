@@ -0,0 +1,67 @@
+use crate::contract::*;
+use ink::env::{
+    test::{
+        self,
+        DefaultAccounts,
+    },
+    DefaultEnvironment,
+};
+use logics::impls::evm_migrator::*;
+use openbrush::traits::AccountId;
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    test::default_accounts::<DefaultEnvironment>()
+}
+fn set_caller(id: AccountId) {
+    test::set_caller::<DefaultEnvironment>(id);
+}
+
+#[ink::test]
+fn new_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let controller = AccountId::from([0x01; 32]);
+    let flashloan_gateway = AccountId::from([0x02; 32]);
+    let evm_lending_pool = [0x03; 20];
+    let contract = EvmMigratorContract::new(controller, flashloan_gateway, evm_lending_pool);
+    assert_eq!(contract.controller(), Some(controller));
+    assert_eq!(contract.flashloan_gateway(), Some(flashloan_gateway));
+    assert_eq!(contract.evm_lending_pool(), Some(evm_lending_pool));
+    assert_eq!(contract.migration_nonce([0x04; 20]), 0);
+    assert_ne!(
+        contract.migration_digest([0x04; 20], accounts.bob),
+        contract.migration_digest([0x04; 20], accounts.charlie)
+    );
+}
+
+#[ink::test]
+fn migrate_fails_without_position_or_signature() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let pool = AccountId::from([0x05; 32]);
+    let mut contract = EvmMigratorContract::new(
+        AccountId::from([0x01; 32]),
+        AccountId::from([0x02; 32]),
+        [0x03; 20],
+    );
+    assert_eq!(
+        contract
+            .migrate([0x04; 20], [0; 65], pool, 0, Vec::new())
+            .unwrap_err(),
+        Error::NothingToMigrate
+    );
+    let collaterals = vec![CollateralMigration {
+        pool,
+        l_token: [0x06; 20],
+        amount: 100,
+    }];
+    assert_eq!(
+        contract
+            .migrate([0x04; 20], [0; 65], pool, 0, collaterals)
+            .unwrap_err(),
+        Error::InvalidSignature
+    );
+    assert_eq!(contract.migration_nonce([0x04; 20]), 0);
+}
@@ -0,0 +1,150 @@
+use crate::contract::*;
+use core::ops::{
+    Div,
+    Mul,
+};
+use ink::env::{
+    test::{
+        self,
+        DefaultAccounts,
+    },
+    DefaultEnvironment,
+};
+use logics::{
+    impls::{
+        fixed_rate_model::*,
+        interest_rate_model::{
+            base,
+            milliseconds_per_year,
+            InterestRateModel,
+        },
+        ownable_2step::{
+            self,
+            Ownable2Step,
+        },
+    },
+    traits::types::WrappedU256,
+};
+use openbrush::{
+    contracts::ownable::{
+        Ownable,
+        OwnableError,
+    },
+    traits::AccountId,
+};
+use primitive_types::U256;
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    test::default_accounts::<DefaultEnvironment>()
+}
+fn set_caller(id: AccountId) {
+    test::set_caller::<DefaultEnvironment>(id);
+}
+fn percent(val: u128) -> U256 {
+    base().mul(U256::from(val)).div(U256::from(100))
+}
+fn per_year(val: U256) -> WrappedU256 {
+    WrappedU256::from(val.mul(milliseconds_per_year()))
+}
+
+#[ink::test]
+fn new_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let contract = FixedRateModelContract::new(per_year(U256::from(5)));
+    assert_eq!(contract.owner(), accounts.bob);
+    assert_eq!(
+        contract.borrow_rate_per_milli_second(),
+        WrappedU256::from(5)
+    );
+}
+
+#[ink::test]
+fn borrow_rate_ignores_utilization() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let contract = FixedRateModelContract::new(per_year(base()));
+    assert_eq!(
+        contract.get_borrow_rate(100, 0, 0),
+        WrappedU256::from(base())
+    );
+    assert_eq!(
+        contract.get_borrow_rate(20, 80, 0),
+        WrappedU256::from(base())
+    );
+    assert_eq!(
+        contract.get_borrow_rate(0, 100, 0),
+        WrappedU256::from(base())
+    );
+    // supply rate = utilization * borrow rate * (1 - reserve factor)
+    assert_eq!(
+        contract.get_supply_rate(20, 80, 0, WrappedU256::from(percent(50))),
+        WrappedU256::from(percent(40))
+    );
+}
+
+#[ink::test]
+fn set_borrow_rate_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = FixedRateModelContract::new(per_year(U256::from(5)));
+    assert!(contract.set_borrow_rate(per_year(U256::from(7))).is_ok());
+    assert_eq!(
+        contract.borrow_rate_per_milli_second(),
+        WrappedU256::from(7)
+    );
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.set_borrow_rate(per_year(U256::from(9))),
+        Err(Error::Ownable(OwnableError::CallerIsNotOwner))
+    );
+}
+
+#[ink::test]
+fn sample_curve_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let contract = FixedRateModelContract::new(per_year(base()));
+    assert!(contract.sample_curve(0, WrappedU256::from(0)).is_empty());
+
+    let curve = contract.sample_curve(5, WrappedU256::from(0));
+    assert_eq!(curve.len(), 5);
+    for (i, point) in curve.iter().enumerate() {
+        let utilization = percent(25 * i as u128);
+        assert_eq!(point.utilization_rate, WrappedU256::from(utilization));
+        assert_eq!(point.borrow_rate, WrappedU256::from(base()));
+        assert_eq!(point.supply_rate, WrappedU256::from(utilization));
+    }
+}
+
+#[ink::test]
+fn transfer_ownership_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = FixedRateModelContract::new(per_year(U256::from(5)));
+    assert!(contract.transfer_ownership(accounts.charlie).is_ok());
+    assert_eq!(contract.owner(), accounts.bob);
+    assert_eq!(contract.pending_owner(), Some(accounts.charlie));
+    assert_eq!(
+        contract.accept_ownership().unwrap_err(),
+        ownable_2step::Error::CallerIsNotPendingOwner
+    );
+
+    set_caller(accounts.charlie);
+    assert!(contract.accept_ownership().is_ok());
+    assert_eq!(contract.owner(), accounts.charlie);
+    assert_eq!(contract.pending_owner(), None);
+
+    assert!(contract.renounce_ownership().is_ok());
+    assert_eq!(contract.owner(), AccountId::from([0x00; 32]));
+    assert_eq!(
+        contract.transfer_ownership(accounts.bob).unwrap_err(),
+        OwnableError::CallerIsNotOwner
+    );
+}
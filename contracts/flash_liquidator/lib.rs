@@ -0,0 +1,63 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![feature(min_specialization)]
+
+#[cfg(test)]
+mod tests;
+
+/// Definition of Flash Liquidator Contract
+#[openbrush::contract]
+pub mod contract {
+    use ink::prelude::vec::Vec;
+    use logics::{
+        impls::flash_liquidator::*,
+        traits::flashloan_receiver::FlashloanReceiver,
+    };
+    use openbrush::traits::Storage;
+
+    /// Contract's Storage
+    #[ink(storage)]
+    #[derive(Storage, Default)]
+    pub struct FlashLiquidatorContract {
+        #[storage_field]
+        flash_liquidator: Data,
+    }
+
+    impl FlashLiquidator for FlashLiquidatorContract {}
+    impl Internal for FlashLiquidatorContract {}
+
+    impl FlashloanReceiver for FlashLiquidatorContract {
+        #[ink(message)]
+        fn execute_operation(
+            &mut self,
+            assets: Vec<AccountId>,
+            amounts: Vec<Balance>,
+            premiums: Vec<Balance>,
+            initiator: AccountId,
+            params: Vec<u8>,
+        ) -> bool {
+            self._execute_operation(assets, amounts, premiums, initiator, params)
+                .is_ok()
+        }
+    }
+
+    impl FlashLiquidatorContract {
+        /// Generate this contract
+        #[ink(constructor)]
+        pub fn new(
+            controller: AccountId,
+            flashloan_gateway: AccountId,
+            dex_router: AccountId,
+        ) -> Self {
+            let mut instance: FlashLiquidatorContract = Default::default();
+            instance._initialize(controller, flashloan_gateway, dex_router);
+            instance
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::contract::*;
+use ink::env::{
+    test::{
+        self,
+        DefaultAccounts,
+    },
+    DefaultEnvironment,
+};
+use logics::impls::flash_liquidator::*;
+use openbrush::traits::AccountId;
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    test::default_accounts::<DefaultEnvironment>()
+}
+fn set_caller(id: AccountId) {
+    test::set_caller::<DefaultEnvironment>(id);
+}
+
+#[ink::test]
+fn new_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let controller = AccountId::from([0x01; 32]);
+    let flashloan_gateway = AccountId::from([0x02; 32]);
+    let dex_router = AccountId::from([0x03; 32]);
+    let contract = FlashLiquidatorContract::new(controller, flashloan_gateway, dex_router);
+    assert_eq!(contract.controller(), Some(controller));
+    assert_eq!(contract.flashloan_gateway(), Some(flashloan_gateway));
+    assert_eq!(contract.dex_router(), Some(dex_router));
+}
+
+#[ink::test]
+fn liquidate_fails_with_invalid_path() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let repay_asset = AccountId::from([0x04; 32]);
+    let collateral_asset = AccountId::from([0x05; 32]);
+    let mut contract = FlashLiquidatorContract::new(
+        AccountId::from([0x01; 32]),
+        AccountId::from([0x02; 32]),
+        AccountId::from([0x03; 32]),
+    );
+    // swapping is required between different assets
+    assert_eq!(
+        contract
+            .liquidate(
+                accounts.charlie,
+                repay_asset,
+                100,
+                collateral_asset,
+                vec![],
+                0
+            )
+            .unwrap_err(),
+        Error::InvalidSwapPath
+    );
+    assert_eq!(
+        contract
+            .liquidate(
+                accounts.charlie,
+                repay_asset,
+                100,
+                collateral_asset,
+                vec![repay_asset, collateral_asset],
+                0
+            )
+            .unwrap_err(),
+        Error::InvalidSwapPath
+    );
+    // and not allowed for the same asset
+    assert_eq!(
+        contract
+            .liquidate(
+                accounts.charlie,
+                repay_asset,
+                100,
+                repay_asset,
+                vec![repay_asset, collateral_asset, repay_asset],
+                0
+            )
+            .unwrap_err(),
+        Error::InvalidSwapPath
+    );
+}
@@ -8,6 +8,9 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 #![feature(min_specialization)]
 
+#[cfg(test)]
+mod tests;
+
 /// Definition of Flashloan Gateway Contract
 #[openbrush::contract]
 pub mod contract {
@@ -16,12 +19,34 @@ pub mod contract {
         Env,
     };
 
-    use logics::impls::flashloan_gateway::{
-        Data,
-        Internal,
-        *,
+    use logics::impls::{
+        flashloan_gateway::{
+            Data,
+            Internal,
+            *,
+        },
+        ownable_2step::{
+            self,
+            Internal as Ownable2StepInternal,
+            Ownable2Step,
+        },
+        upgradeable::{
+            self,
+            Internal as UpgradeableInternal,
+            Upgradeable,
+        },
+    };
+    use openbrush::{
+        contracts::{
+            ownable::*,
+            reentrancy_guard,
+        },
+        modifiers,
+        traits::Storage,
     };
-    use openbrush::traits::Storage;
+
+    /// Version of the storage layout this code is built for
+    pub const STORAGE_VERSION: u32 = 1;
 
     /// Contract's Storage
     #[ink(storage)]
@@ -29,46 +54,228 @@ pub mod contract {
     pub struct FlashloanGatewayContract {
         #[storage_field]
         gateway: Data,
+        #[storage_field]
+        ownable: ownable::Data,
+        #[storage_field]
+        guard: reentrancy_guard::Data,
+        #[storage_field]
+        ownable_2step: ownable_2step::Data,
+        #[storage_field]
+        upgradeable: upgradeable::Data,
     }
 
     /// Event: Flashloan is executed.
+    ///
+    /// `premium` includes `protocol_premium` sent to the treasury, the rest being added to the
+    /// reserves of the pool, which emits `ReservesAdded`. Borrowed amounts have no premium.
     #[ink(event)]
     pub struct FlashLoan {
         #[ink(topic)]
-        target: AccountId,
+        receiver: AccountId,
         #[ink(topic)]
         initiator: AccountId,
         asset: AccountId,
         amount: Balance,
         premium: Balance,
+        protocol_premium: Balance,
+    }
+
+    /// Event: The reduced flashloan fee of an account is changed.
+    #[ink(event)]
+    pub struct PremiumExemptionUpdated {
+        #[ink(topic)]
+        account: AccountId,
+        premium: Option<u128>,
+    }
+
+    /// Event: All flashloans are paused or resumed.
+    #[ink(event)]
+    pub struct FlashloanPaused {
+        paused: bool,
+    }
+
+    /// Event: Flashloans of an asset are enabled or disabled.
+    #[ink(event)]
+    pub struct FlashloanEnabledUpdated {
+        #[ink(topic)]
+        asset: AccountId,
+        enabled: bool,
+    }
+
+    /// Event: The pool lending an asset is registered.
+    #[ink(event)]
+    pub struct PoolRegistered {
+        #[ink(topic)]
+        asset: AccountId,
+        pool: AccountId,
+    }
+
+    /// Event: Flashloan fee or its protocol share is changed.
+    #[ink(event)]
+    pub struct NewFlashloanPremium {
+        premium_total: u128,
+        premium_to_protocol: u128,
+    }
+
+    /// Event: Ownership is proposed to a new owner
+    #[ink(event)]
+    pub struct OwnershipTransferStarted {
+        #[ink(topic)]
+        pub previous_owner: AccountId,
+        #[ink(topic)]
+        pub new_owner: AccountId,
+    }
+
+    /// Event: Ownership is transferred
+    #[ink(event)]
+    pub struct OwnershipTransferred {
+        #[ink(topic)]
+        pub previous_owner: Option<AccountId>,
+        #[ink(topic)]
+        pub new_owner: Option<AccountId>,
+    }
+
+    /// Event: Code of this contract is replaced
+    #[ink(event)]
+    pub struct CodeUpgraded {
+        pub code_hash: Hash,
+        pub version: u32,
+    }
+
+    /// Event: Storage is migrated to the layout of the running code
+    #[ink(event)]
+    pub struct Migrated {
+        pub from: u32,
+        pub to: u32,
     }
 
     impl Internal for FlashloanGatewayContract {
         fn _emit_flashloan_event(
             &self,
-            target: AccountId,
+            receiver: AccountId,
             initiator: AccountId,
             asset: AccountId,
             amount: Balance,
             premium: Balance,
+            protocol_premium: Balance,
         ) {
             self.env().emit_event(FlashLoan {
-                target,
+                receiver,
                 initiator,
                 asset,
                 amount,
                 premium,
+                protocol_premium,
+            })
+        }
+
+        fn _emit_new_flashloan_premium_event(
+            &self,
+            premium_total: u128,
+            premium_to_protocol: u128,
+        ) {
+            self.env().emit_event(NewFlashloanPremium {
+                premium_total,
+                premium_to_protocol,
             })
         }
+
+        fn _emit_premium_exemption_updated_event(&self, account: AccountId, premium: Option<u128>) {
+            self.env()
+                .emit_event(PremiumExemptionUpdated { account, premium })
+        }
+
+        fn _emit_flashloan_paused_event(&self, paused: bool) {
+            self.env().emit_event(FlashloanPaused { paused })
+        }
+
+        fn _emit_pool_registered_event(&self, asset: AccountId, pool: AccountId) {
+            self.env().emit_event(PoolRegistered { asset, pool })
+        }
+
+        fn _emit_flashloan_enabled_updated_event(&self, asset: AccountId, enabled: bool) {
+            self.env()
+                .emit_event(FlashloanEnabledUpdated { asset, enabled })
+        }
     }
     impl FlashloanGateway for FlashloanGatewayContract {}
 
+    impl Ownable for FlashloanGatewayContract {
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn transfer_ownership(
+            &mut self,
+            new_owner: AccountId,
+        ) -> core::result::Result<(), OwnableError> {
+            self._propose_owner(new_owner)
+        }
+
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn renounce_ownership(&mut self) -> core::result::Result<(), OwnableError> {
+            self._renounce_ownership()
+        }
+    }
+
+    impl Ownable2Step for FlashloanGatewayContract {}
+
+    impl ownable::Internal for FlashloanGatewayContract {
+        fn _emit_ownership_transferred_event(
+            &self,
+            previous_owner: Option<AccountId>,
+            new_owner: Option<AccountId>,
+        ) {
+            self.env().emit_event(OwnershipTransferred {
+                previous_owner,
+                new_owner,
+            })
+        }
+    }
+
+    impl ownable_2step::Internal for FlashloanGatewayContract {
+        fn _emit_ownership_transfer_started_event(
+            &self,
+            previous_owner: AccountId,
+            new_owner: AccountId,
+        ) {
+            self.env().emit_event(OwnershipTransferStarted {
+                previous_owner,
+                new_owner,
+            })
+        }
+    }
+
+    impl Upgradeable for FlashloanGatewayContract {}
+
+    impl upgradeable::Internal for FlashloanGatewayContract {
+        fn _assert_upgrade_admin(&self) -> upgradeable::Result<()> {
+            if self.owner() != self.env().caller() {
+                return Err(upgradeable::Error::CallerIsNotAdmin)
+            }
+            Ok(())
+        }
+
+        fn _code_version(&self) -> u32 {
+            STORAGE_VERSION
+        }
+
+        fn _emit_code_upgraded_event(&self, code_hash: Hash, version: u32) {
+            self.env().emit_event(CodeUpgraded { code_hash, version });
+        }
+
+        fn _emit_migrated_event(&self, from: u32, to: u32) {
+            self.env().emit_event(Migrated { from, to });
+        }
+    }
+
     impl FlashloanGatewayContract {
         /// Generate this contract
         #[ink(constructor)]
         pub fn new(controller: AccountId) -> Self {
             let mut instance = Self::default();
+            instance._init_with_owner(Self::env().caller());
             instance._initialize(controller);
+            instance._set_version(STORAGE_VERSION);
             instance
         }
     }
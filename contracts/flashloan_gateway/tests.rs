@@ -0,0 +1,281 @@
+use crate::contract::*;
+use ink::env::{
+    test::{
+        self,
+        DefaultAccounts,
+    },
+    DefaultEnvironment,
+};
+use logics::impls::flashloan_gateway::*;
+use openbrush::{
+    contracts::ownable::OwnableError,
+    traits::AccountId,
+};
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    test::default_accounts::<DefaultEnvironment>()
+}
+fn set_caller(id: AccountId) {
+    test::set_caller::<DefaultEnvironment>(id);
+}
+
+#[ink::test]
+fn new_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let controller = AccountId::from([0x01; 32]);
+    let contract = FlashloanGatewayContract::new(controller);
+    assert_eq!(contract.controller(), Some(controller));
+    assert_eq!(contract.flashloan_premium_total(), 9);
+    assert_eq!(contract.flashloan_premium_to_protocol(), 0);
+    assert_eq!(contract.treasury(), None);
+}
+
+#[ink::test]
+fn set_flashloan_premium_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = FlashloanGatewayContract::new(AccountId::from([0x01; 32]));
+    assert!(contract.set_flashloan_premium_total(50).is_ok());
+    assert!(contract.set_flashloan_premium_to_protocol(3000).is_ok());
+    assert!(contract.set_treasury(accounts.charlie).is_ok());
+    assert_eq!(contract.flashloan_premium_total(), 50);
+    assert_eq!(contract.flashloan_premium_to_protocol(), 3000);
+    assert_eq!(contract.treasury(), Some(accounts.charlie));
+
+    assert_eq!(
+        contract
+            .set_flashloan_premium_total(MAX_BPS + 1)
+            .unwrap_err(),
+        Error::InvalidFlashloanPremium
+    );
+    assert_eq!(
+        contract
+            .set_flashloan_premium_to_protocol(MAX_BPS + 1)
+            .unwrap_err(),
+        Error::InvalidFlashloanPremium
+    );
+}
+
+#[ink::test]
+fn set_flashloan_premium_fails_by_non_owner() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = FlashloanGatewayContract::new(AccountId::from([0x01; 32]));
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.set_flashloan_premium_total(50).unwrap_err(),
+        Error::Ownable(OwnableError::CallerIsNotOwner)
+    );
+    assert_eq!(
+        contract
+            .set_flashloan_premium_to_protocol(3000)
+            .unwrap_err(),
+        Error::Ownable(OwnableError::CallerIsNotOwner)
+    );
+    assert_eq!(
+        contract.set_treasury(accounts.charlie).unwrap_err(),
+        Error::Ownable(OwnableError::CallerIsNotOwner)
+    );
+}
+
+#[ink::test]
+fn premium_exemption_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = FlashloanGatewayContract::new(AccountId::from([0x01; 32]));
+    assert_eq!(contract.premium_exemption(accounts.charlie), None);
+    assert_eq!(
+        contract._flashloan_premium_of(accounts.charlie, accounts.django),
+        9
+    );
+
+    assert!(contract
+        .set_premium_exemption(accounts.charlie, Some(5))
+        .is_ok());
+    assert!(contract
+        .set_premium_exemption(accounts.django, Some(0))
+        .is_ok());
+    assert_eq!(contract.premium_exemption(accounts.charlie), Some(5));
+    // the lowest premium of the receiver and the initiator applies
+    assert_eq!(
+        contract._flashloan_premium_of(accounts.charlie, accounts.eve),
+        5
+    );
+    assert_eq!(
+        contract._flashloan_premium_of(accounts.eve, accounts.django),
+        0
+    );
+    assert_eq!(
+        contract._flashloan_premium_of(accounts.charlie, accounts.django),
+        0
+    );
+    // an exemption never raises the premium
+    assert!(contract
+        .set_premium_exemption(accounts.eve, Some(100))
+        .is_ok());
+    assert_eq!(
+        contract._flashloan_premium_of(accounts.eve, accounts.frank),
+        9
+    );
+
+    assert!(contract
+        .set_premium_exemption(accounts.charlie, None)
+        .is_ok());
+    assert_eq!(contract.premium_exemption(accounts.charlie), None);
+    assert_eq!(
+        contract._flashloan_premium_of(accounts.charlie, accounts.frank),
+        9
+    );
+
+    assert_eq!(
+        contract
+            .set_premium_exemption(accounts.charlie, Some(MAX_BPS + 1))
+            .unwrap_err(),
+        Error::InvalidFlashloanPremium
+    );
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract
+            .set_premium_exemption(accounts.charlie, Some(0))
+            .unwrap_err(),
+        Error::Ownable(OwnableError::CallerIsNotOwner)
+    );
+}
+
+#[ink::test]
+fn flashloan_pause_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = FlashloanGatewayContract::new(AccountId::from([0x01; 32]));
+    let asset = AccountId::from([0x02; 32]);
+    assert_eq!(contract.guardian(), None);
+    assert!(!contract.flashloan_paused());
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.set_guardian(accounts.charlie).unwrap_err(),
+        Error::Ownable(OwnableError::CallerIsNotOwner)
+    );
+    assert_eq!(
+        contract.set_flashloan_paused(true).unwrap_err(),
+        Error::CallerIsNotGuardian
+    );
+
+    set_caller(accounts.bob);
+    assert!(contract.set_guardian(accounts.charlie).is_ok());
+    assert_eq!(contract.guardian(), Some(accounts.charlie));
+    set_caller(accounts.charlie);
+    assert!(contract.set_flashloan_paused(true).is_ok());
+    assert!(contract.flashloan_paused());
+    assert_eq!(
+        contract
+            .flashloan(
+                accounts.django,
+                vec![asset],
+                vec![100],
+                vec![0],
+                accounts.charlie,
+                vec![],
+            )
+            .unwrap_err(),
+        Error::FlashloanPaused
+    );
+
+    // the owner can unpause as well
+    set_caller(accounts.bob);
+    assert!(contract.set_flashloan_paused(false).is_ok());
+    assert!(!contract.flashloan_paused());
+}
+
+#[ink::test]
+fn flashloan_enabled_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = FlashloanGatewayContract::new(AccountId::from([0x01; 32]));
+    let asset = AccountId::from([0x02; 32]);
+    let other_asset = AccountId::from([0x03; 32]);
+    assert!(contract.flashloan_enabled(asset));
+
+    assert!(contract.set_guardian(accounts.charlie).is_ok());
+    set_caller(accounts.django);
+    assert_eq!(
+        contract.set_flashloan_enabled(asset, false).unwrap_err(),
+        Error::CallerIsNotGuardian
+    );
+    set_caller(accounts.charlie);
+    assert!(contract.set_flashloan_enabled(asset, false).is_ok());
+    assert!(!contract.flashloan_enabled(asset));
+    assert!(contract.flashloan_enabled(other_asset));
+    assert_eq!(
+        contract
+            .flashloan(
+                accounts.django,
+                vec![other_asset, asset],
+                vec![100, 100],
+                vec![0, 0],
+                accounts.charlie,
+                vec![],
+            )
+            .unwrap_err(),
+        Error::FlashloanDisabled
+    );
+
+    assert!(contract.set_flashloan_enabled(asset, true).is_ok());
+    assert!(contract.flashloan_enabled(asset));
+}
+
+#[ink::test]
+fn flashloan_fails_with_inconsistent_params() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = FlashloanGatewayContract::new(AccountId::from([0x01; 32]));
+    let asset = AccountId::from([0x02; 32]);
+    let other_asset = AccountId::from([0x03; 32]);
+    assert_eq!(
+        contract
+            .flashloan(
+                accounts.django,
+                vec![asset],
+                vec![100, 100],
+                vec![0],
+                accounts.bob,
+                vec![],
+            )
+            .unwrap_err(),
+        Error::InconsistentFlashloanParams
+    );
+    // a single balance check per pool must cover the whole amount lent
+    assert_eq!(
+        contract
+            .flashloan(
+                accounts.django,
+                vec![asset, other_asset, asset],
+                vec![100, 100, 100],
+                vec![0, 0, 1],
+                accounts.bob,
+                vec![],
+            )
+            .unwrap_err(),
+        Error::InconsistentFlashloanParams
+    );
+    assert_eq!(
+        contract
+            .flashloan(
+                accounts.django,
+                vec![asset],
+                vec![100],
+                vec![2],
+                accounts.bob,
+                vec![],
+            )
+            .unwrap_err(),
+        Error::InvalidFlashloanMode
+    );
+}
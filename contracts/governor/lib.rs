@@ -0,0 +1,199 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![feature(min_specialization)]
+
+#[cfg(test)]
+mod tests;
+
+/// Definition of Governor Contract
+#[openbrush::contract]
+pub mod contract {
+    use ink::{
+        codegen::{
+            EmitEvent,
+            Env,
+        },
+        prelude::{
+            string::String,
+            vec::Vec,
+        },
+    };
+    use logics::impls::governor::{
+        Data,
+        Internal,
+        *,
+    };
+    use openbrush::traits::Storage;
+
+    /// Contract's Storage
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct GovernorContract {
+        #[storage_field]
+        governor: Data,
+    }
+
+    /// Event: Proposal is created
+    #[ink(event)]
+    pub struct ProposalCreated {
+        #[ink(topic)]
+        pub proposal_id: ProposalId,
+        #[ink(topic)]
+        pub proposer: AccountId,
+        pub actions: Vec<ProposalAction>,
+        pub snapshot: Timestamp,
+        pub deadline: Timestamp,
+        pub description: String,
+    }
+
+    /// Event: Vote is cast on a proposal
+    #[ink(event)]
+    pub struct VoteCast {
+        #[ink(topic)]
+        pub voter: AccountId,
+        #[ink(topic)]
+        pub proposal_id: ProposalId,
+        pub support: VoteType,
+        pub votes: Balance,
+    }
+
+    /// Event: Proposal is queued in the timelock
+    #[ink(event)]
+    pub struct ProposalQueued {
+        #[ink(topic)]
+        pub proposal_id: ProposalId,
+        pub eta: Timestamp,
+    }
+
+    /// Event: Proposal is executed
+    #[ink(event)]
+    pub struct ProposalExecuted {
+        #[ink(topic)]
+        pub proposal_id: ProposalId,
+    }
+
+    /// Event: Proposal is canceled
+    #[ink(event)]
+    pub struct ProposalCanceled {
+        #[ink(topic)]
+        pub proposal_id: ProposalId,
+    }
+
+    /// Event: Voting delay is changed
+    #[ink(event)]
+    pub struct NewVotingDelay {
+        pub voting_delay: Timestamp,
+    }
+
+    /// Event: Voting period is changed
+    #[ink(event)]
+    pub struct NewVotingPeriod {
+        pub voting_period: Timestamp,
+    }
+
+    /// Event: Proposal threshold is changed
+    #[ink(event)]
+    pub struct NewProposalThreshold {
+        pub proposal_threshold: Balance,
+    }
+
+    /// Event: Quorum fraction is changed
+    #[ink(event)]
+    pub struct NewQuorumFraction {
+        pub quorum_fraction: u128,
+    }
+
+    impl Internal for GovernorContract {
+        fn _emit_proposal_created_event(
+            &self,
+            proposal_id: ProposalId,
+            proposer: AccountId,
+            actions: Vec<ProposalAction>,
+            snapshot: Timestamp,
+            deadline: Timestamp,
+            description: String,
+        ) {
+            self.env().emit_event(ProposalCreated {
+                proposal_id,
+                proposer,
+                actions,
+                snapshot,
+                deadline,
+                description,
+            })
+        }
+        fn _emit_vote_cast_event(
+            &self,
+            voter: AccountId,
+            proposal_id: ProposalId,
+            support: VoteType,
+            votes: Balance,
+        ) {
+            self.env().emit_event(VoteCast {
+                voter,
+                proposal_id,
+                support,
+                votes,
+            })
+        }
+        fn _emit_proposal_queued_event(&self, proposal_id: ProposalId, eta: Timestamp) {
+            self.env().emit_event(ProposalQueued { proposal_id, eta })
+        }
+        fn _emit_proposal_executed_event(&self, proposal_id: ProposalId) {
+            self.env().emit_event(ProposalExecuted { proposal_id })
+        }
+        fn _emit_proposal_canceled_event(&self, proposal_id: ProposalId) {
+            self.env().emit_event(ProposalCanceled { proposal_id })
+        }
+        fn _emit_new_voting_delay_event(&self, voting_delay: Timestamp) {
+            self.env().emit_event(NewVotingDelay { voting_delay })
+        }
+        fn _emit_new_voting_period_event(&self, voting_period: Timestamp) {
+            self.env().emit_event(NewVotingPeriod { voting_period })
+        }
+        fn _emit_new_proposal_threshold_event(&self, proposal_threshold: Balance) {
+            self.env()
+                .emit_event(NewProposalThreshold { proposal_threshold })
+        }
+        fn _emit_new_quorum_fraction_event(&self, quorum_fraction: u128) {
+            self.env().emit_event(NewQuorumFraction { quorum_fraction })
+        }
+    }
+
+    impl Governor for GovernorContract {}
+
+    impl GovernorContract {
+        /// Generate this contract
+        #[ink(constructor)]
+        pub fn new(
+            token: AccountId,
+            timelock: AccountId,
+            voting_delay: Timestamp,
+            voting_period: Timestamp,
+            proposal_threshold: Balance,
+            quorum_fraction: u128,
+        ) -> Self {
+            let mut instance = Self::default();
+            if instance
+                ._initialize(
+                    token,
+                    timelock,
+                    voting_delay,
+                    voting_period,
+                    proposal_threshold,
+                    quorum_fraction,
+                )
+                .is_err()
+            {
+                panic!("invalid governor settings");
+            }
+            instance
+        }
+    }
+}
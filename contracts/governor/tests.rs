@@ -0,0 +1,135 @@
+use crate::contract::*;
+use ink::env::{
+    test::{
+        self,
+        DefaultAccounts,
+    },
+    DefaultEnvironment,
+};
+use logics::impls::governor::*;
+use openbrush::traits::AccountId;
+
+const DAY: u64 = 24 * 60 * 60 * 1000;
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    test::default_accounts::<DefaultEnvironment>()
+}
+fn set_caller(id: AccountId) {
+    test::set_caller::<DefaultEnvironment>(id);
+}
+fn new_contract() -> GovernorContract {
+    let accounts = default_accounts();
+    GovernorContract::new(accounts.django, accounts.eve, DAY, 3 * DAY, 1000, 400)
+}
+
+#[ink::test]
+fn new_works() {
+    let accounts = default_accounts();
+    let contract = new_contract();
+    assert_eq!(contract.token(), Some(accounts.django));
+    assert_eq!(contract.timelock(), Some(accounts.eve));
+    assert_eq!(contract.voting_delay(), DAY);
+    assert_eq!(contract.voting_period(), 3 * DAY);
+    assert_eq!(contract.proposal_threshold(), 1000);
+    assert_eq!(contract.quorum_fraction(), 400);
+    assert_eq!(contract.proposal_count(), 0);
+    assert_eq!(contract.proposal(0), None);
+    assert_eq!(contract.proposal_actions(0), vec![]);
+    assert_eq!(contract.receipt(0, accounts.bob), None);
+}
+
+#[ink::test]
+#[should_panic(expected = "invalid governor settings")]
+fn new_fails_when_quorum_fraction_is_too_large() {
+    let accounts = default_accounts();
+    GovernorContract::new(
+        accounts.django,
+        accounts.eve,
+        DAY,
+        3 * DAY,
+        1000,
+        MAX_BPS + 1,
+    );
+}
+
+#[ink::test]
+fn propose_fails_when_actions_are_invalid() {
+    let mut contract = new_contract();
+    assert_eq!(
+        contract.propose(vec![], String::from("")).unwrap_err(),
+        Error::InvalidActions
+    );
+
+    let action = ProposalAction {
+        target: AccountId::from([0x01; 32]),
+        selector: [0x12, 0x34, 0x56, 0x78],
+        input: vec![],
+    };
+    let actions = vec![action; MAX_ACTIONS as usize + 1];
+    assert_eq!(
+        contract.propose(actions, String::from("")).unwrap_err(),
+        Error::InvalidActions
+    );
+}
+
+#[ink::test]
+fn proposal_lifecycle_fails_for_unknown_proposal() {
+    let mut contract = new_contract();
+    assert_eq!(contract.state(0).unwrap_err(), Error::ProposalNotFound);
+    assert_eq!(
+        contract.cast_vote(0, VoteType::For).unwrap_err(),
+        Error::ProposalNotFound
+    );
+    assert_eq!(contract.queue(0).unwrap_err(), Error::ProposalNotFound);
+    assert_eq!(contract.execute(0).unwrap_err(), Error::ProposalNotFound);
+    assert_eq!(contract.cancel(0).unwrap_err(), Error::ProposalNotFound);
+}
+
+#[ink::test]
+fn settings_can_be_changed_by_timelock() {
+    let accounts = default_accounts();
+    let mut contract = new_contract();
+
+    set_caller(accounts.eve);
+    assert!(contract.set_voting_delay(2 * DAY).is_ok());
+    assert!(contract.set_voting_period(7 * DAY).is_ok());
+    assert!(contract.set_proposal_threshold(2000).is_ok());
+    assert!(contract.set_quorum_fraction(1000).is_ok());
+    assert_eq!(contract.voting_delay(), 2 * DAY);
+    assert_eq!(contract.voting_period(), 7 * DAY);
+    assert_eq!(contract.proposal_threshold(), 2000);
+    assert_eq!(contract.quorum_fraction(), 1000);
+
+    assert_eq!(
+        contract.set_voting_period(0).unwrap_err(),
+        Error::InvalidVotingPeriod
+    );
+    assert_eq!(
+        contract.set_quorum_fraction(MAX_BPS + 1).unwrap_err(),
+        Error::InvalidQuorumFraction
+    );
+}
+
+#[ink::test]
+fn settings_fail_by_non_timelock() {
+    let accounts = default_accounts();
+    let mut contract = new_contract();
+
+    set_caller(accounts.bob);
+    assert_eq!(
+        contract.set_voting_delay(2 * DAY).unwrap_err(),
+        Error::CallerIsNotTimelock
+    );
+    assert_eq!(
+        contract.set_voting_period(7 * DAY).unwrap_err(),
+        Error::CallerIsNotTimelock
+    );
+    assert_eq!(
+        contract.set_proposal_threshold(2000).unwrap_err(),
+        Error::CallerIsNotTimelock
+    );
+    assert_eq!(
+        contract.set_quorum_fraction(1000).unwrap_err(),
+        Error::CallerIsNotTimelock
+    );
+}
@@ -0,0 +1,295 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![feature(min_specialization)]
+
+#[cfg(test)]
+mod tests;
+
+/// Definition of Incentives Controller Contract
+#[openbrush::contract]
+pub mod contract {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+
+    use logics::impls::{
+        incentives_controller::{
+            Data,
+            Internal,
+            *,
+        },
+        ownable_2step::{
+            self,
+            Internal as Ownable2StepInternal,
+            Ownable2Step,
+        },
+        upgradeable::{
+            self,
+            Internal as UpgradeableInternal,
+            Upgradeable,
+        },
+    };
+    use openbrush::{
+        contracts::ownable::*,
+        modifiers,
+        traits::Storage,
+    };
+
+    /// Version of the storage layout this code is built for
+    pub const STORAGE_VERSION: u32 = 1;
+
+    /// Contract's Storage
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct IncentivesControllerContract {
+        #[storage_field]
+        incentives: Data,
+        #[storage_field]
+        ownable: ownable::Data,
+        #[storage_field]
+        ownable_2step: ownable_2step::Data,
+        #[storage_field]
+        upgradeable: upgradeable::Data,
+    }
+
+    /// Event: Rewards are accrued to an account
+    #[ink(event)]
+    pub struct RewardsAccrued {
+        #[ink(topic)]
+        user: AccountId,
+        #[ink(topic)]
+        pool: AccountId,
+        amount: Balance,
+    }
+
+    /// Event: Rewards of an account are claimed
+    #[ink(event)]
+    pub struct RewardsClaimed {
+        #[ink(topic)]
+        pub user: AccountId,
+        #[ink(topic)]
+        pub to: AccountId,
+        pub claimer: AccountId,
+        pub amount: Balance,
+    }
+
+    /// Event: Rewards emitted to suppliers and borrowers of a pool are changed
+    #[ink(event)]
+    pub struct RewardSpeedsUpdated {
+        #[ink(topic)]
+        pool: AccountId,
+        supply_speed: Balance,
+        borrow_speed: Balance,
+    }
+
+    /// Event: Token in which rewards are paid is changed
+    #[ink(event)]
+    pub struct NewRewardToken {
+        old: Option<AccountId>,
+        new: Option<AccountId>,
+    }
+
+    /// Event: Claimer authorized by an account is changed
+    #[ink(event)]
+    pub struct ClaimerSet {
+        #[ink(topic)]
+        user: AccountId,
+        claimer: Option<AccountId>,
+    }
+
+    /// Event: Claim operator approved by an account is changed
+    #[ink(event)]
+    pub struct ClaimOperatorSet {
+        #[ink(topic)]
+        pub user: AccountId,
+        #[ink(topic)]
+        pub operator: AccountId,
+        pub approved: bool,
+    }
+
+    /// Event: Vote escrow whose veLAY boosts rewards is changed
+    #[ink(event)]
+    pub struct NewVoteEscrow {
+        old: Option<AccountId>,
+        new: Option<AccountId>,
+    }
+
+    /// Event: Ownership is proposed to a new owner
+    #[ink(event)]
+    pub struct OwnershipTransferStarted {
+        #[ink(topic)]
+        pub previous_owner: AccountId,
+        #[ink(topic)]
+        pub new_owner: AccountId,
+    }
+
+    /// Event: Ownership is transferred
+    #[ink(event)]
+    pub struct OwnershipTransferred {
+        #[ink(topic)]
+        pub previous_owner: Option<AccountId>,
+        #[ink(topic)]
+        pub new_owner: Option<AccountId>,
+    }
+
+    /// Event: Code of this contract is replaced
+    #[ink(event)]
+    pub struct CodeUpgraded {
+        pub code_hash: Hash,
+        pub version: u32,
+    }
+
+    /// Event: Storage is migrated to the layout of the running code
+    #[ink(event)]
+    pub struct Migrated {
+        pub from: u32,
+        pub to: u32,
+    }
+
+    impl Internal for IncentivesControllerContract {
+        fn _emit_rewards_accrued_event(&self, user: AccountId, pool: AccountId, amount: Balance) {
+            self.env().emit_event(RewardsAccrued { user, pool, amount })
+        }
+
+        fn _emit_rewards_claimed_event(
+            &self,
+            user: AccountId,
+            to: AccountId,
+            claimer: AccountId,
+            amount: Balance,
+        ) {
+            self.env().emit_event(RewardsClaimed {
+                user,
+                to,
+                claimer,
+                amount,
+            })
+        }
+
+        fn _emit_reward_speeds_updated_event(
+            &self,
+            pool: AccountId,
+            supply_speed: Balance,
+            borrow_speed: Balance,
+        ) {
+            self.env().emit_event(RewardSpeedsUpdated {
+                pool,
+                supply_speed,
+                borrow_speed,
+            })
+        }
+
+        fn _emit_new_reward_token_event(&self, old: Option<AccountId>, new: Option<AccountId>) {
+            self.env().emit_event(NewRewardToken { old, new })
+        }
+
+        fn _emit_claimer_set_event(&self, user: AccountId, claimer: Option<AccountId>) {
+            self.env().emit_event(ClaimerSet { user, claimer })
+        }
+
+        fn _emit_claim_operator_set_event(
+            &self,
+            user: AccountId,
+            operator: AccountId,
+            approved: bool,
+        ) {
+            self.env().emit_event(ClaimOperatorSet {
+                user,
+                operator,
+                approved,
+            })
+        }
+
+        fn _emit_new_vote_escrow_event(&self, old: Option<AccountId>, new: Option<AccountId>) {
+            self.env().emit_event(NewVoteEscrow { old, new })
+        }
+    }
+    impl IncentivesController for IncentivesControllerContract {}
+
+    impl Ownable for IncentivesControllerContract {
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn transfer_ownership(
+            &mut self,
+            new_owner: AccountId,
+        ) -> core::result::Result<(), OwnableError> {
+            self._propose_owner(new_owner)
+        }
+
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn renounce_ownership(&mut self) -> core::result::Result<(), OwnableError> {
+            self._renounce_ownership()
+        }
+    }
+
+    impl Ownable2Step for IncentivesControllerContract {}
+
+    impl ownable::Internal for IncentivesControllerContract {
+        fn _emit_ownership_transferred_event(
+            &self,
+            previous_owner: Option<AccountId>,
+            new_owner: Option<AccountId>,
+        ) {
+            self.env().emit_event(OwnershipTransferred {
+                previous_owner,
+                new_owner,
+            })
+        }
+    }
+
+    impl ownable_2step::Internal for IncentivesControllerContract {
+        fn _emit_ownership_transfer_started_event(
+            &self,
+            previous_owner: AccountId,
+            new_owner: AccountId,
+        ) {
+            self.env().emit_event(OwnershipTransferStarted {
+                previous_owner,
+                new_owner,
+            })
+        }
+    }
+
+    impl Upgradeable for IncentivesControllerContract {}
+
+    impl upgradeable::Internal for IncentivesControllerContract {
+        fn _assert_upgrade_admin(&self) -> upgradeable::Result<()> {
+            if self.owner() != self.env().caller() {
+                return Err(upgradeable::Error::CallerIsNotAdmin)
+            }
+            Ok(())
+        }
+
+        fn _code_version(&self) -> u32 {
+            STORAGE_VERSION
+        }
+
+        fn _emit_code_upgraded_event(&self, code_hash: Hash, version: u32) {
+            self.env().emit_event(CodeUpgraded { code_hash, version });
+        }
+
+        fn _emit_migrated_event(&self, from: u32, to: u32) {
+            self.env().emit_event(Migrated { from, to });
+        }
+    }
+
+    impl IncentivesControllerContract {
+        /// Generate this contract
+        #[ink(constructor)]
+        pub fn new(reward_token: Option<AccountId>) -> Self {
+            let mut instance = Self::default();
+            instance._init_with_owner(Self::env().caller());
+            instance._initialize(reward_token);
+            instance._set_version(STORAGE_VERSION);
+            instance
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use crate::contract::*;
+use ink::{
+    env::{
+        test::{
+            self,
+            recorded_events,
+            DefaultAccounts,
+            EmittedEvent,
+        },
+        DefaultEnvironment,
+    },
+    prelude::vec::Vec,
+};
+use logics::impls::incentives_controller::*;
+use openbrush::traits::AccountId;
+use scale::Decode;
+
+type Event = <IncentivesControllerContract as ink::reflect::ContractEventBase>::Type;
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    test::default_accounts::<DefaultEnvironment>()
+}
+fn set_caller(id: AccountId) {
+    test::set_caller::<DefaultEnvironment>(id);
+}
+fn get_emitted_events() -> Vec<EmittedEvent> {
+    recorded_events().collect::<Vec<_>>()
+}
+fn decode_event(event: &EmittedEvent) -> Event {
+    <Event as Decode>::decode(&mut &event.data[..])
+        .expect("encountered invalid contract event data buffer")
+}
+
+#[ink::test]
+fn set_claim_operator_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let reward_token = AccountId::from([0x01; 32]);
+    let mut contract = IncentivesControllerContract::new(Some(reward_token));
+    assert!(!contract.is_claim_operator(accounts.bob, accounts.charlie));
+
+    assert!(contract.set_claim_operator(accounts.charlie, true).is_ok());
+    assert!(contract.is_claim_operator(accounts.bob, accounts.charlie));
+    // approvals are per account
+    assert!(!contract.is_claim_operator(accounts.django, accounts.charlie));
+
+    let events = get_emitted_events();
+    match decode_event(events.last().unwrap()) {
+        Event::ClaimOperatorSet(event) => {
+            assert_eq!(event.user, accounts.bob);
+            assert_eq!(event.operator, accounts.charlie);
+            assert!(event.approved);
+        }
+        _ => panic!("unexpected event kind: expected ClaimOperatorSet event"),
+    }
+
+    assert!(contract.set_claim_operator(accounts.charlie, false).is_ok());
+    assert!(!contract.is_claim_operator(accounts.bob, accounts.charlie));
+}
+
+#[ink::test]
+fn claim_rewards_on_behalf_by_operator_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let reward_token = AccountId::from([0x01; 32]);
+    let mut contract = IncentivesControllerContract::new(Some(reward_token));
+    assert!(contract.set_claim_operator(accounts.charlie, true).is_ok());
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract
+            .claim_rewards_on_behalf(accounts.bob, accounts.bob)
+            .unwrap(),
+        0
+    );
+    let events = get_emitted_events();
+    match decode_event(events.last().unwrap()) {
+        Event::RewardsClaimed(event) => {
+            assert_eq!(event.user, accounts.bob);
+            assert_eq!(event.to, accounts.bob);
+            assert_eq!(event.claimer, accounts.charlie);
+            assert_eq!(event.amount, 0);
+        }
+        _ => panic!("unexpected event kind: expected RewardsClaimed event"),
+    }
+
+    // operators never take custody of the rewards
+    assert_eq!(
+        contract
+            .claim_rewards_on_behalf(accounts.bob, accounts.charlie)
+            .unwrap_err(),
+        Error::OperatorCanOnlyClaimToUser
+    );
+}
+
+#[ink::test]
+fn claim_rewards_on_behalf_fails_by_unauthorized_caller() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let reward_token = AccountId::from([0x01; 32]);
+    let mut contract = IncentivesControllerContract::new(Some(reward_token));
+    assert!(contract.set_claim_operator(accounts.charlie, true).is_ok());
+    assert!(contract.set_claim_operator(accounts.charlie, false).is_ok());
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract
+            .claim_rewards_on_behalf(accounts.bob, accounts.bob)
+            .unwrap_err(),
+        Error::CallerIsNotAuthorizedClaimer
+    );
+}
+
+#[ink::test]
+fn claimer_can_claim_to_any_account() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let reward_token = AccountId::from([0x01; 32]);
+    let mut contract = IncentivesControllerContract::new(Some(reward_token));
+    assert!(contract.set_claimer(Some(accounts.charlie)).is_ok());
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract
+            .claim_rewards_on_behalf(accounts.bob, accounts.charlie)
+            .unwrap(),
+        0
+    );
+}
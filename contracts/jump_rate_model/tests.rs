@@ -0,0 +1,127 @@
+use crate::contract::*;
+use core::ops::{
+    Add,
+    Div,
+    Mul,
+};
+use ink::env::{
+    test::{
+        self,
+        DefaultAccounts,
+    },
+    DefaultEnvironment,
+};
+use logics::{
+    impls::{
+        interest_rate_model::{
+            base,
+            milliseconds_per_year,
+            InterestRateModel,
+        },
+        jump_rate_model::*,
+    },
+    traits::types::WrappedU256,
+};
+use openbrush::{
+    contracts::ownable::OwnableError,
+    traits::AccountId,
+};
+use primitive_types::U256;
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    test::default_accounts::<DefaultEnvironment>()
+}
+fn set_caller(id: AccountId) {
+    test::set_caller::<DefaultEnvironment>(id);
+}
+fn percent(val: u128) -> U256 {
+    base().mul(U256::from(val)).div(U256::from(100))
+}
+fn per_year(val: U256) -> WrappedU256 {
+    WrappedU256::from(val.mul(milliseconds_per_year()))
+}
+
+#[ink::test]
+fn new_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let contract = JumpRateModelContract::new(
+        per_year(U256::from(2)),
+        per_year(U256::from(10)),
+        per_year(U256::from(100)),
+        WrappedU256::from(percent(80)),
+    );
+    assert_eq!(contract.base_rate_per_milli_second(), WrappedU256::from(2));
+    assert_eq!(
+        contract.multiplier_per_milli_second(),
+        WrappedU256::from(10)
+    );
+    assert_eq!(
+        contract.jump_multiplier_per_milli_second(),
+        WrappedU256::from(100)
+    );
+    assert_eq!(contract.kink(), WrappedU256::from(percent(80)));
+}
+
+#[ink::test]
+fn borrow_rate_jumps_above_kink() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let contract = JumpRateModelContract::new(
+        per_year(percent(2)),
+        per_year(percent(10)),
+        per_year(percent(100)),
+        WrappedU256::from(percent(80)),
+    );
+    // utilization 50%: 2% + 10% * 50%
+    assert_eq!(
+        U256::from(contract.get_borrow_rate(50, 50, 0)),
+        percent(2).add(percent(5))
+    );
+    // utilization 90%: 2% + 10% * 80% + 100% * 10%
+    assert_eq!(
+        U256::from(contract.get_borrow_rate(10, 90, 0)),
+        percent(2).add(percent(8)).add(percent(10))
+    );
+    assert_eq!(
+        U256::from(contract.get_supply_rate(10, 90, 0, WrappedU256::from(U256::zero()))),
+        percent(20).mul(percent(90)).div(base())
+    );
+}
+
+#[ink::test]
+fn update_jump_rate_model_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let zero = WrappedU256::from(U256::zero());
+    let mut contract = JumpRateModelContract::new(zero, zero, zero, zero);
+    assert!(contract
+        .update_jump_rate_model(
+            per_year(U256::from(1)),
+            per_year(U256::from(2)),
+            per_year(U256::from(3)),
+            WrappedU256::from(percent(90)),
+        )
+        .is_ok());
+    assert_eq!(
+        contract.jump_multiplier_per_milli_second(),
+        WrappedU256::from(3)
+    );
+    assert_eq!(
+        contract
+            .update_jump_rate_model(zero, zero, zero, WrappedU256::from(percent(101)))
+            .unwrap_err(),
+        Error::InvalidKink
+    );
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract
+            .update_jump_rate_model(zero, zero, zero, zero)
+            .unwrap_err(),
+        Error::Ownable(OwnableError::CallerIsNotOwner)
+    );
+}
@@ -0,0 +1,273 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![feature(min_specialization)]
+
+#[cfg(test)]
+mod tests;
+
+/// Definition of LAY Token Contract
+#[openbrush::contract]
+pub mod contract {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use logics::impls::{
+        lay_token::{
+            self,
+            Internal as LayTokenInternal,
+            Result,
+        },
+        votes::{
+            self,
+            Votes,
+        },
+    };
+    use openbrush::{
+        contracts::{
+            access_control::{
+                self,
+                Internal as AccessControlInternal,
+                RoleType,
+            },
+            psp22::{
+                extensions::metadata::{
+                    self,
+                    PSP22Metadata,
+                },
+                psp22,
+                PSP22Error,
+                PSP22,
+            },
+        },
+        modifiers,
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    pub const MINTER: RoleType = ink::selector_id!("MINTER");
+
+    /// Contract's Storage
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct LayTokenContract {
+        #[storage_field]
+        lay_token: lay_token::Data,
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        metadata: metadata::Data,
+        #[storage_field]
+        votes: votes::Data,
+        #[storage_field]
+        access: access_control::Data,
+    }
+
+    /// Event: Token is transferred, minted or burned
+    #[ink(event)]
+    pub struct Transfer {
+        #[ink(topic)]
+        pub from: Option<AccountId>,
+        #[ink(topic)]
+        pub to: Option<AccountId>,
+        pub value: Balance,
+    }
+
+    /// Event: Allowance is changed
+    #[ink(event)]
+    pub struct Approval {
+        #[ink(topic)]
+        pub owner: AccountId,
+        #[ink(topic)]
+        pub spender: AccountId,
+        pub value: Balance,
+    }
+
+    /// Event: Delegatee of an account is changed
+    #[ink(event)]
+    pub struct DelegateChanged {
+        #[ink(topic)]
+        pub delegator: AccountId,
+        pub from_delegate: Option<AccountId>,
+        #[ink(topic)]
+        pub to_delegate: AccountId,
+    }
+
+    /// Event: Voting weight of a delegatee is changed
+    #[ink(event)]
+    pub struct DelegateVotesChanged {
+        #[ink(topic)]
+        pub delegate: AccountId,
+        pub previous_balance: Balance,
+        pub new_balance: Balance,
+    }
+
+    /// Event: The admin role holder has changed
+    #[ink(event)]
+    pub struct RoleAdminChanged {
+        #[ink(topic)]
+        pub role: RoleType,
+        #[ink(topic)]
+        pub previous_admin_role: RoleType,
+        #[ink(topic)]
+        pub new_admin_role: RoleType,
+    }
+
+    /// Event: New role is assigned to the account
+    #[ink(event)]
+    pub struct RoleGranted {
+        #[ink(topic)]
+        pub role: RoleType,
+        #[ink(topic)]
+        pub grantee: AccountId,
+        #[ink(topic)]
+        pub grantor: Option<AccountId>,
+    }
+
+    /// Event: The role has been revoked from the account
+    #[ink(event)]
+    pub struct RoleRevoked {
+        #[ink(topic)]
+        pub role: RoleType,
+        #[ink(topic)]
+        pub account: AccountId,
+        #[ink(topic)]
+        pub admin: AccountId,
+    }
+
+    /// NOTE: Apply permission control by overriding the Default implementation with roles.
+    impl lay_token::LayToken for LayTokenContract {
+        #[ink(message)]
+        #[modifiers(access_control::only_role(MINTER))]
+        fn mint(&mut self, to: AccountId, amount: Balance) -> Result<()> {
+            self._mint(to, amount)
+        }
+    }
+
+    impl PSP22 for LayTokenContract {}
+
+    impl psp22::Internal for LayTokenContract {
+        fn _emit_transfer_event(
+            &self,
+            from: Option<AccountId>,
+            to: Option<AccountId>,
+            amount: Balance,
+        ) {
+            self.env().emit_event(Transfer {
+                from,
+                to,
+                value: amount,
+            });
+        }
+
+        fn _emit_approval_event(&self, owner: AccountId, spender: AccountId, amount: Balance) {
+            self.env().emit_event(Approval {
+                owner,
+                spender,
+                value: amount,
+            });
+        }
+
+        fn _after_token_transfer(
+            &mut self,
+            from: Option<&AccountId>,
+            to: Option<&AccountId>,
+            amount: &Balance,
+        ) -> core::result::Result<(), PSP22Error> {
+            votes::Internal::_after_token_transfer_votes(self, from.copied(), to.copied(), *amount);
+            Ok(())
+        }
+    }
+
+    impl PSP22Metadata for LayTokenContract {}
+
+    impl Votes for LayTokenContract {}
+
+    impl votes::Internal for LayTokenContract {
+        fn _emit_delegate_changed_event(
+            &self,
+            delegator: AccountId,
+            from_delegate: Option<AccountId>,
+            to_delegate: AccountId,
+        ) {
+            self.env().emit_event(DelegateChanged {
+                delegator,
+                from_delegate,
+                to_delegate,
+            })
+        }
+
+        fn _emit_delegate_votes_changed_event(
+            &self,
+            delegate: AccountId,
+            previous_balance: Balance,
+            new_balance: Balance,
+        ) {
+            self.env().emit_event(DelegateVotesChanged {
+                delegate,
+                previous_balance,
+                new_balance,
+            })
+        }
+    }
+
+    impl access_control::AccessControl for LayTokenContract {}
+
+    impl access_control::Internal for LayTokenContract {
+        fn _emit_role_admin_changed(
+            &mut self,
+            role: u32,
+            previous_admin_role: u32,
+            new_admin_role: u32,
+        ) {
+            self.env().emit_event(RoleAdminChanged {
+                role,
+                previous_admin_role,
+                new_admin_role,
+            })
+        }
+
+        fn _emit_role_granted(
+            &mut self,
+            role: u32,
+            grantee: AccountId,
+            grantor: Option<AccountId>,
+        ) {
+            self.env().emit_event(RoleGranted {
+                role,
+                grantee,
+                grantor,
+            })
+        }
+
+        fn _emit_role_revoked(&mut self, role: u32, account: AccountId, sender: AccountId) {
+            self.env().emit_event(RoleRevoked {
+                role,
+                account,
+                admin: sender,
+            })
+        }
+    }
+
+    impl LayTokenContract {
+        /// Generate this contract
+        #[ink(constructor)]
+        pub fn new(cap: Balance, name: String, symbol: String, decimals: u8) -> Self {
+            let mut instance = Self::default();
+            instance._init_with_caller();
+            instance._initialize(cap);
+            instance.metadata.name = Some(name);
+            instance.metadata.symbol = Some(symbol);
+            instance.metadata.decimals = decimals;
+            instance
+        }
+    }
+}
@@ -0,0 +1,148 @@
+use crate::contract::*;
+use ink::env::{
+    test::{
+        self,
+        DefaultAccounts,
+    },
+    DefaultEnvironment,
+};
+use logics::impls::lay_token::*;
+use openbrush::{
+    contracts::{
+        access_control::{
+            AccessControl,
+            AccessControlError,
+        },
+        psp22::{
+            extensions::metadata::PSP22Metadata,
+            PSP22Error,
+            PSP22,
+        },
+    },
+    traits::{
+        AccountId,
+        String,
+    },
+};
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    test::default_accounts::<DefaultEnvironment>()
+}
+fn set_caller(id: AccountId) {
+    test::set_caller::<DefaultEnvironment>(id);
+}
+
+fn deploy(cap: u128) -> LayTokenContract {
+    let mut contract =
+        LayTokenContract::new(cap, String::from("Starlay Token"), String::from("LAY"), 18);
+    let accounts = default_accounts();
+    assert!(contract.grant_role(MINTER, accounts.bob).is_ok());
+    contract
+}
+
+#[ink::test]
+fn new_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let contract = LayTokenContract::new(
+        1_000,
+        String::from("Starlay Token"),
+        String::from("LAY"),
+        18,
+    );
+    assert_eq!(contract.cap(), 1_000);
+    assert_eq!(contract.total_supply(), 0);
+    assert_eq!(contract.token_name(), Some(String::from("Starlay Token")));
+    assert_eq!(contract.token_symbol(), Some(String::from("LAY")));
+    assert_eq!(contract.token_decimals(), 18);
+    assert!(!contract.has_role(MINTER, accounts.bob));
+}
+
+#[ink::test]
+fn mint_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = deploy(1_000);
+    assert!(contract.mint(accounts.charlie, 600).is_ok());
+    assert!(contract.mint(accounts.django, 400).is_ok());
+    assert_eq!(contract.balance_of(accounts.charlie), 600);
+    assert_eq!(contract.balance_of(accounts.django), 400);
+    assert_eq!(contract.total_supply(), 1_000);
+}
+
+#[ink::test]
+fn mint_fails_if_cap_exceeded() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = deploy(1_000);
+    assert_eq!(
+        contract.mint(accounts.charlie, 1_001).unwrap_err(),
+        Error::CapExceeded
+    );
+    assert!(contract.mint(accounts.charlie, 999).is_ok());
+    assert_eq!(
+        contract.mint(accounts.charlie, 2).unwrap_err(),
+        Error::CapExceeded
+    );
+
+    // burning frees room under the cap
+    set_caller(accounts.charlie);
+    assert!(contract.burn(100).is_ok());
+    set_caller(accounts.bob);
+    assert!(contract.mint(accounts.charlie, 101).is_ok());
+    assert_eq!(contract.total_supply(), 1_000);
+}
+
+#[ink::test]
+fn mint_fails_by_non_minter() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = deploy(1_000);
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.mint(accounts.charlie, 100).unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn burn_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = deploy(1_000);
+    assert!(contract.mint(accounts.bob, 100).is_ok());
+    assert!(contract.burn(40).is_ok());
+    assert_eq!(contract.balance_of(accounts.bob), 60);
+    assert_eq!(contract.total_supply(), 60);
+    assert_eq!(
+        contract.burn(61).unwrap_err(),
+        Error::PSP22(PSP22Error::InsufficientBalance)
+    );
+}
+
+#[ink::test]
+fn burn_from_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = deploy(1_000);
+    assert!(contract.mint(accounts.charlie, 100).is_ok());
+
+    set_caller(accounts.charlie);
+    assert!(contract.approve(accounts.django, 50).is_ok());
+
+    set_caller(accounts.django);
+    assert_eq!(
+        contract.burn_from(accounts.charlie, 51).unwrap_err(),
+        Error::PSP22(PSP22Error::InsufficientAllowance)
+    );
+    assert!(contract.burn_from(accounts.charlie, 30).is_ok());
+    assert_eq!(contract.balance_of(accounts.charlie), 70);
+    assert_eq!(contract.allowance(accounts.charlie, accounts.django), 20);
+    assert_eq!(contract.total_supply(), 70);
+}
@@ -17,11 +17,15 @@ mod tests;
 #[openbrush::contract]
 pub mod contract {
     use ink::prelude::vec::Vec;
-    use logics::traits::{
-        controller::ControllerRef,
-        pool::PoolRef,
-        price_oracle::PriceOracleRef,
-        types::WrappedU256,
+    use logics::{
+        impls::exp_no_err::exp_scale,
+        traits::{
+            controller::ControllerRef,
+            incentives_controller::IncentivesControllerRef,
+            pool::PoolRef,
+            price_oracle::PriceOracleRef,
+            types::WrappedU256,
+        },
     };
     use openbrush::{
         contracts::traits::psp22::{
@@ -33,11 +37,61 @@ pub mod contract {
             String,
         },
     };
+    use primitive_types::U256;
     use scale::{
         Decode,
         Encode,
     };
 
+    /// Milliseconds in a year of 365 days
+    pub const MSEC_PER_YEAR: u128 = 365 * 24 * 60 * 60 * 1000;
+
+    /// Annualizes a rate per millisecond scaled by 1e18, compounded every `compounding_period`.
+    /// The rate is not compounded if `compounding_period` is 0 or at least a year.
+    pub fn annualize(rate_per_msec: U256, compounding_period: u64) -> U256 {
+        let period = U256::from(compounding_period);
+        if period.is_zero() || period >= U256::from(MSEC_PER_YEAR) {
+            return rate_per_msec.saturating_mul(U256::from(MSEC_PER_YEAR))
+        }
+
+        // (1 + rate * period) ^ (year / period) - 1, by exponentiation by squaring
+        let mut base = exp_scale().saturating_add(rate_per_msec.saturating_mul(period));
+        let mut exponent = U256::from(MSEC_PER_YEAR) / period;
+        let mut result = exp_scale();
+        while !exponent.is_zero() {
+            if exponent.bit(0) {
+                result = result.saturating_mul(base) / exp_scale();
+            }
+            base = base.saturating_mul(base) / exp_scale();
+            exponent >>= 1;
+        }
+        result.saturating_sub(exp_scale())
+    }
+
+    /// Value of rewards emitted at `speed` per millisecond over a year, divided by the value of
+    /// `total` they are emitted to, scaled by 1e18. 0 if nothing is emitted to.
+    pub fn reward_apr_of(
+        speed: Balance,
+        reward_token_price: u128,
+        reward_decimals: u8,
+        total: Balance,
+        underlying_price: u128,
+        underlying_decimals: u8,
+    ) -> U256 {
+        let denominator = U256::from(total)
+            .saturating_mul(U256::from(underlying_price))
+            .saturating_mul(U256::from(10).pow(U256::from(reward_decimals)));
+        if denominator.is_zero() {
+            return U256::zero()
+        }
+        U256::from(speed)
+            .saturating_mul(U256::from(MSEC_PER_YEAR))
+            .saturating_mul(U256::from(reward_token_price))
+            .saturating_mul(U256::from(10).pow(U256::from(underlying_decimals)))
+            .saturating_mul(exp_scale())
+            / denominator
+    }
+
     /// Metadata in the Pool
     #[derive(Decode, Encode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
@@ -55,6 +109,7 @@ pub mod contract {
         exchange_rate_current: WrappedU256,
         supply_rate_per_msec: WrappedU256,
         borrow_rate_per_msec: WrappedU256,
+        utilization_rate: WrappedU256,
         collateral_factor_mantissa: WrappedU256,
         reserve_factor_mantissa: WrappedU256,
         borrow_cap: Option<u128>,
@@ -62,6 +117,32 @@ pub mod contract {
         borrow_guardian_paused: bool,
     }
 
+    /// Aggregated state of a Pool, to get it in a single call
+    #[derive(Decode, Encode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct PoolData {
+        pool: AccountId,
+        underlying_asset_address: Option<AccountId>,
+        is_listed: bool,
+        exchange_rate_current: WrappedU256,
+        supply_rate_per_msec: WrappedU256,
+        borrow_rate_per_msec: WrappedU256,
+        utilization_rate: WrappedU256,
+        total_cash: Balance,
+        total_supply: Balance,
+        total_borrows: Balance,
+        total_reserves: Balance,
+        borrow_cap: Option<u128>,
+        account_supply_cap: Balance,
+        collateral_factor_mantissa: WrappedU256,
+        liquidation_threshold: u128,
+        reserve_factor_mantissa: WrappedU256,
+        mint_guardian_paused: bool,
+        borrow_guardian_paused: bool,
+        /// price of the underlying from the oracle, 0 if unavailable
+        underlying_price: u128,
+    }
+
     /// Pool's Balance Information
     #[derive(Decode, Encode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
@@ -89,6 +170,71 @@ pub mod contract {
         shortfall: Balance,
     }
 
+    /// Position of an account in a Pool
+    #[derive(Decode, Encode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct AccountPoolData {
+        pool: AccountId,
+        supply_balance: Balance,
+        borrow_balance: Balance,
+        using_as_collateral: bool,
+    }
+
+    /// Positions of an account in all entered Pools, and its overall health
+    #[derive(Decode, Encode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct AccountSnapshot {
+        pools: Vec<AccountPoolData>,
+        liquidity: WrappedU256,
+        shortfall: WrappedU256,
+        health_factor: WrappedU256,
+    }
+
+    /// Rewards emitted to a Pool per millisecond
+    #[derive(Decode, Encode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct PoolRewardSpeeds {
+        pool: AccountId,
+        supply_speed: Balance,
+        borrow_speed: Balance,
+    }
+
+    /// Annual rate of rewards over the value supplied or borrowed in a Pool, scaled by 1e18
+    #[derive(Decode, Encode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct PoolRewardApr {
+        pool: AccountId,
+        supply_apr: WrappedU256,
+        borrow_apr: WrappedU256,
+    }
+
+    /// Largest repayment a liquidator may make of a borrow, and the collateral to seize for it
+    #[derive(Decode, Encode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct LiquidationOpportunity {
+        pool_borrowed: AccountId,
+        pool_collateral: AccountId,
+        max_repay_amount: Balance,
+    }
+
+    /// Account with a shortfall, and its borrows that can be liquidated
+    #[derive(Decode, Encode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct UnderwaterAccount {
+        account: AccountId,
+        shortfall: WrappedU256,
+        opportunities: Vec<LiquidationOpportunity>,
+    }
+
+    /// Annual yields of a Pool, scaled by 1e18
+    #[derive(Decode, Encode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct PoolApy {
+        pool: AccountId,
+        supply_apy: WrappedU256,
+        borrow_apy: WrappedU256,
+    }
+
     /// Protocol's Configuration
     #[derive(Decode, Encode)]
     #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
@@ -133,6 +279,21 @@ pub mod contract {
                 .collect()
         }
 
+        /// Get rates, caps, collateral factor, pause states and price of a pool at once
+        #[ink(message)]
+        pub fn pool_data(&self, pool: AccountId) -> PoolData {
+            self._pool_data(pool)
+        }
+
+        /// Get data of all pools managed by the controller
+        #[ink(message)]
+        pub fn all_pool_data(&self, controller: AccountId) -> Vec<PoolData> {
+            self._pools(controller)
+                .iter()
+                .map(|pool| self._pool_data(*pool))
+                .collect()
+        }
+
         /// Get balance information for a specified user in a pool
         #[ink(message)]
         pub fn pool_balances(&self, pool: AccountId, account: AccountId) -> PoolBalances {
@@ -186,6 +347,123 @@ pub mod contract {
                 .collect()
         }
 
+        /// Get balances in every entered pool, liquidity, shortfall and health factor of an account
+        #[ink(message)]
+        pub fn account_data(&self, controller: AccountId, account: AccountId) -> AccountSnapshot {
+            let pools = ControllerRef::account_assets(&controller, account)
+                .iter()
+                .map(|pool| {
+                    AccountPoolData {
+                        pool: *pool,
+                        supply_balance: PSP22Ref::balance_of(pool, account),
+                        borrow_balance: PoolRef::borrow_balance_current(pool, account)
+                            .unwrap_or_default(),
+                        using_as_collateral: PoolRef::using_reserve_as_collateral(pool, account),
+                    }
+                })
+                .collect();
+            let (liquidity, shortfall) =
+                ControllerRef::get_account_liquidity(&controller, account).unwrap_or_default();
+            let health_factor =
+                ControllerRef::calculate_user_account_data(&controller, account, None)
+                    .map(|data| data.health_factor)
+                    .unwrap_or_default();
+            AccountSnapshot {
+                pools,
+                liquidity: WrappedU256::from(liquidity),
+                shortfall: WrappedU256::from(shortfall),
+                health_factor: WrappedU256::from(health_factor),
+            }
+        }
+
+        /// Get reward speeds of a pool, 0 if it has no incentives controller
+        #[ink(message)]
+        pub fn reward_speeds(&self, pool: AccountId) -> PoolRewardSpeeds {
+            self._reward_speeds(pool)
+        }
+
+        /// Get reward speeds of pools
+        #[ink(message)]
+        pub fn reward_speeds_all(&self, pools: Vec<AccountId>) -> Vec<PoolRewardSpeeds> {
+            pools
+                .iter()
+                .map(|pool| self._reward_speeds(*pool))
+                .collect()
+        }
+
+        /// Get rewards accrued by an account and not claimed yet
+        #[ink(message)]
+        pub fn unclaimed_rewards(
+            &self,
+            incentives_controller: AccountId,
+            account: AccountId,
+        ) -> Balance {
+            IncentivesControllerRef::unclaimed_rewards(&incentives_controller, account)
+        }
+
+        /// Get reward APRs of a pool, given the price of the reward token in the oracle's precision
+        #[ink(message)]
+        pub fn reward_apr(&self, pool: AccountId, reward_token_price: u128) -> PoolRewardApr {
+            self._reward_apr(pool, reward_token_price)
+        }
+
+        /// Get reward APRs of pools
+        #[ink(message)]
+        pub fn reward_apr_all(
+            &self,
+            pools: Vec<AccountId>,
+            reward_token_price: u128,
+        ) -> Vec<PoolRewardApr> {
+            pools
+                .iter()
+                .map(|pool| self._reward_apr(*pool, reward_token_price))
+                .collect()
+        }
+
+        /// Get accounts with a shortfall among the given ones, with the max repay of their borrows
+        #[ink(message)]
+        pub fn underwater_accounts(
+            &self,
+            controller: AccountId,
+            accounts: Vec<AccountId>,
+        ) -> Vec<UnderwaterAccount> {
+            accounts
+                .iter()
+                .filter_map(|account| self._underwater_account(controller, *account))
+                .collect()
+        }
+
+        /// Get the accounts with a shortfall among a page of borrowers of a pool
+        #[ink(message)]
+        pub fn underwater_borrowers(
+            &self,
+            controller: AccountId,
+            pool: AccountId,
+            offset: u32,
+            limit: u32,
+        ) -> Vec<UnderwaterAccount> {
+            self.underwater_accounts(
+                controller,
+                PoolRef::borrowers_paginated(&pool, offset, limit),
+            )
+        }
+
+        /// Get supply and borrow APYs of a pool from its current rates,
+        /// compounded every `compounding_period` msec, or not compounded if 0
+        #[ink(message)]
+        pub fn apy(&self, pool: AccountId, compounding_period: u64) -> PoolApy {
+            self._apy(pool, compounding_period)
+        }
+
+        /// Get APYs of pools
+        #[ink(message)]
+        pub fn apy_all(&self, pools: Vec<AccountId>, compounding_period: u64) -> Vec<PoolApy> {
+            pools
+                .iter()
+                .map(|pool| self._apy(*pool, compounding_period))
+                .collect()
+        }
+
         /// Get protocol's configuration
         #[ink(message)]
         pub fn configuration(&self, controller: AccountId) -> Configuration {
@@ -252,6 +530,7 @@ pub mod contract {
                 exchange_rate_current: PoolRef::exchange_rate_current(&pool).unwrap_or_default(),
                 supply_rate_per_msec: PoolRef::supply_rate_per_msec(&pool),
                 borrow_rate_per_msec: PoolRef::borrow_rate_per_msec(&pool),
+                utilization_rate: PoolRef::utilization_rate(&pool),
                 collateral_factor_mantissa,
                 reserve_factor_mantissa: PoolRef::reserve_factor_mantissa(&pool),
                 borrow_cap,
@@ -260,6 +539,182 @@ pub mod contract {
             }
         }
 
+        fn _pool_data(&self, pool: AccountId) -> PoolData {
+            let controller = PoolRef::controller(&pool);
+            let underlying_asset_address = PoolRef::underlying(&pool);
+
+            let (
+                is_listed,
+                collateral_factor_mantissa,
+                borrow_cap,
+                mint_guardian_paused,
+                borrow_guardian_paused,
+                underlying_price,
+            ) = if let Some(_controller) = controller {
+                let underlying_price = match (
+                    ControllerRef::oracle(&_controller),
+                    underlying_asset_address,
+                ) {
+                    (Some(oracle), Some(underlying)) => {
+                        PriceOracleRef::get_price(&oracle, underlying).unwrap_or_default()
+                    }
+                    _ => 0,
+                };
+                (
+                    ControllerRef::is_listed(&_controller, pool),
+                    ControllerRef::collateral_factor_mantissa(&_controller, pool)
+                        .unwrap_or_default(),
+                    ControllerRef::borrow_cap(&_controller, pool),
+                    ControllerRef::mint_guardian_paused(&_controller, pool).unwrap_or_default(),
+                    ControllerRef::borrow_guardian_paused(&_controller, pool).unwrap_or_default(),
+                    underlying_price,
+                )
+            } else {
+                (false, Default::default(), Some(0), true, true, 0)
+            };
+
+            PoolData {
+                pool,
+                underlying_asset_address,
+                is_listed,
+                exchange_rate_current: PoolRef::exchange_rate_current(&pool).unwrap_or_default(),
+                supply_rate_per_msec: PoolRef::supply_rate_per_msec(&pool),
+                borrow_rate_per_msec: PoolRef::borrow_rate_per_msec(&pool),
+                utilization_rate: PoolRef::utilization_rate(&pool),
+                total_cash: PoolRef::get_cash_prior(&pool),
+                total_supply: PSP22Ref::total_supply(&pool),
+                total_borrows: PoolRef::total_borrows(&pool),
+                total_reserves: PoolRef::total_reserves(&pool),
+                borrow_cap,
+                account_supply_cap: PoolRef::account_supply_cap(&pool),
+                collateral_factor_mantissa,
+                liquidation_threshold: PoolRef::liquidation_threshold(&pool),
+                reserve_factor_mantissa: PoolRef::reserve_factor_mantissa(&pool),
+                mint_guardian_paused,
+                borrow_guardian_paused,
+                underlying_price,
+            }
+        }
+
+        fn _underwater_account(
+            &self,
+            controller: AccountId,
+            account: AccountId,
+        ) -> Option<UnderwaterAccount> {
+            let (_, shortfall) =
+                ControllerRef::get_account_liquidity(&controller, account).unwrap_or_default();
+            if shortfall.is_zero() {
+                return None
+            }
+
+            // (pool, collateral tokens, borrow balance, exchange rate)
+            let snapshots: Vec<(AccountId, Balance, Balance, U256)> =
+                ControllerRef::account_assets(&controller, account)
+                    .iter()
+                    .map(|pool| {
+                        let (tokens, borrow_balance, exchange_rate) =
+                            PoolRef::get_account_snapshot(pool, account);
+                        (*pool, tokens, borrow_balance, exchange_rate)
+                    })
+                    .collect();
+
+            let mut opportunities: Vec<LiquidationOpportunity> = Vec::new();
+            for (pool_borrowed, _, borrow_balance, _) in snapshots.iter() {
+                if *borrow_balance == 0 {
+                    continue
+                }
+                // the collateral allowing the largest repayment
+                let best = snapshots
+                    .iter()
+                    .filter(|(_, tokens, _, _)| *tokens > 0)
+                    .map(|(pool_collateral, tokens, _, exchange_rate)| {
+                        let max_repay_amount = ControllerRef::liquidate_max_repay_amount(
+                            &controller,
+                            *pool_borrowed,
+                            *pool_collateral,
+                            WrappedU256::from(*exchange_rate),
+                            *borrow_balance,
+                            *tokens,
+                            None,
+                            None,
+                        )
+                        .unwrap_or_default();
+                        (*pool_collateral, max_repay_amount)
+                    })
+                    .max_by_key(|(_, max_repay_amount)| *max_repay_amount)
+                    .filter(|(_, max_repay_amount)| *max_repay_amount > 0);
+                if let Some((pool_collateral, max_repay_amount)) = best {
+                    opportunities.push(LiquidationOpportunity {
+                        pool_borrowed: *pool_borrowed,
+                        pool_collateral,
+                        max_repay_amount,
+                    });
+                }
+            }
+
+            Some(UnderwaterAccount {
+                account,
+                shortfall: WrappedU256::from(shortfall),
+                opportunities,
+            })
+        }
+
+        fn _apy(&self, pool: AccountId, compounding_period: u64) -> PoolApy {
+            PoolApy {
+                pool,
+                supply_apy: WrappedU256::from(annualize(
+                    PoolRef::supply_rate_per_msec(&pool).into(),
+                    compounding_period,
+                )),
+                borrow_apy: WrappedU256::from(annualize(
+                    PoolRef::borrow_rate_per_msec(&pool).into(),
+                    compounding_period,
+                )),
+            }
+        }
+
+        fn _reward_speeds(&self, pool: AccountId) -> PoolRewardSpeeds {
+            let (supply_speed, borrow_speed) = match PoolRef::incentives_controller(&pool) {
+                Some(incentives_controller) => {
+                    IncentivesControllerRef::reward_speeds(&incentives_controller, pool)
+                }
+                None => (0, 0),
+            };
+            PoolRewardSpeeds {
+                pool,
+                supply_speed,
+                borrow_speed,
+            }
+        }
+
+        fn _reward_apr(&self, pool: AccountId, reward_token_price: u128) -> PoolRewardApr {
+            let speeds = self._reward_speeds(pool);
+            let reward_decimals = PoolRef::incentives_controller(&pool)
+                .and_then(|incentives_controller| {
+                    IncentivesControllerRef::reward_token(&incentives_controller)
+                })
+                .map(|reward_token| PSP22MetadataRef::token_decimals(&reward_token))
+                .unwrap_or_default();
+            let underlying_price = self._pool_data(pool).underlying_price;
+            let underlying_decimals = PSP22MetadataRef::token_decimals(&pool);
+
+            let apr = |speed: Balance, total: Balance| -> WrappedU256 {
+                WrappedU256::from(reward_apr_of(
+                    speed,
+                    reward_token_price,
+                    reward_decimals,
+                    total,
+                    underlying_price,
+                    underlying_decimals,
+                ))
+            };
+            PoolRewardApr {
+                pool,
+                supply_apr: apr(speeds.supply_speed, PSP22Ref::total_supply(&pool)),
+                borrow_apr: apr(speeds.borrow_speed, PoolRef::total_borrows(&pool)),
+            }
+        }
+
         fn _pool_balances(&self, pool: AccountId, account: AccountId) -> PoolBalances {
             let underlying = PoolRef::underlying(&pool);
             let (token_balance, token_allowance) = if let Some(_underlying) = underlying {
@@ -6,7 +6,9 @@ use ink::env::{
     },
     DefaultEnvironment,
 };
+use logics::impls::exp_no_err::exp_scale;
 use openbrush::traits::AccountId;
+use primitive_types::U256;
 
 fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
     test::default_accounts::<DefaultEnvironment>()
@@ -22,3 +24,41 @@ fn new_works() {
 
     let _contract = LensContract::new();
 }
+
+#[ink::test]
+fn annualize_works() {
+    // 10% a year
+    let rate_per_msec = exp_scale() / U256::from(10) / U256::from(MSEC_PER_YEAR);
+    let apr = annualize(rate_per_msec, 0);
+    assert!(apr <= exp_scale() / U256::from(10));
+    assert!(apr > exp_scale() / U256::from(10) - U256::from(MSEC_PER_YEAR));
+    assert_eq!(annualize(rate_per_msec, MSEC_PER_YEAR as u64), apr);
+
+    // compounded daily: 10.5156%
+    let apy = annualize(rate_per_msec, 24 * 60 * 60 * 1000);
+    assert!(apy > U256::from(105_155_000_000_000_000_u128));
+    assert!(apy < U256::from(105_157_000_000_000_000_u128));
+
+    assert_eq!(annualize(U256::zero(), 1000), U256::zero());
+}
+
+#[ink::test]
+fn reward_apr_of_works() {
+    // 1 reward token (18 decimals) a second over 1,000,000 supplied tokens (6 decimals),
+    // the reward token being worth twice the underlying
+    let speed = 10_u128.pow(18) / 1000;
+    let total = 1_000_000 * 10_u128.pow(6);
+    let apr = reward_apr_of(speed, 2, 18, total, 1, 6);
+    // 365 * 24 * 60 * 60 * 2 / 1,000,000 = 63.072, i.e. 6307.2%
+    assert_eq!(apr, U256::from(63_072_000_000_000_000_000_u128));
+
+    // twice the value emitted to halves the apr
+    assert_eq!(
+        reward_apr_of(speed, 2, 18, total * 2, 1, 6),
+        apr / U256::from(2)
+    );
+
+    assert_eq!(reward_apr_of(0, 2, 18, total, 1, 6), U256::zero());
+    assert_eq!(reward_apr_of(speed, 2, 18, 0, 1, 6), U256::zero());
+    assert_eq!(reward_apr_of(speed, 2, 18, total, 0, 6), U256::zero());
+}
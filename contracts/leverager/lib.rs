@@ -14,7 +14,11 @@ mod tests;
 /// Definition of Leverager Contract
 #[openbrush::contract]
 pub mod contract {
-    use logics::impls::leverager::*;
+    use ink::prelude::vec::Vec;
+    use logics::{
+        impls::leverager::*,
+        traits::flashloan_receiver::FlashloanReceiver,
+    };
     use openbrush::traits::Storage;
 
     /// Contract's Storage
@@ -28,6 +32,21 @@ pub mod contract {
     impl Leverager for LeveragerContract {}
     impl Internal for LeveragerContract {}
 
+    impl FlashloanReceiver for LeveragerContract {
+        #[ink(message)]
+        fn execute_operation(
+            &mut self,
+            assets: Vec<AccountId>,
+            amounts: Vec<Balance>,
+            premiums: Vec<Balance>,
+            initiator: AccountId,
+            params: Vec<u8>,
+        ) -> bool {
+            self._execute_operation(assets, amounts, premiums, initiator, params)
+                .is_ok()
+        }
+    }
+
     impl LeveragerContract {
         /// Generate this contract
         #[ink(constructor)]
@@ -90,3 +90,43 @@ fn all_methods_not_working_without_initialize() {
     let liquidation_threshold = contract.liquidation_threshold(asset);
     assert_eq!(liquidation_threshold, 0);
 }
+
+#[ink::test]
+fn set_flashloan_gateway_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = LeveragerContract::new(accounts.bob);
+    assert!(contract.flashloan_gateway().is_none());
+
+    let flashloan_gateway = AccountId::from([0x04; 32]);
+    assert!(contract.set_flashloan_gateway(flashloan_gateway).is_ok());
+    assert_eq!(contract.flashloan_gateway(), Some(flashloan_gateway));
+
+    set_caller(accounts.alice);
+    assert_eq!(
+        contract
+            .set_flashloan_gateway(flashloan_gateway)
+            .unwrap_err(),
+        Error::CallerIsNotManager
+    );
+}
+
+#[ink::test]
+fn leverage_asset_fails_with_inappropriate_leverage() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = LeveragerContract::new(accounts.bob);
+    let asset = AccountId::from([0x01; 32]);
+
+    // without controller, the loan to value of any asset is 0
+    assert_eq!(
+        contract.leverage_asset(asset, 100, 20000).unwrap_err(),
+        Error::InappropriateLeverage
+    );
+    assert_eq!(
+        contract.leverage_asset(asset, 100, 10000).unwrap_err(),
+        Error::InappropriateLeverage
+    );
+}
@@ -14,9 +14,12 @@ mod tests;
 /// Definition of Manager Contract
 #[openbrush::contract]
 pub mod contract {
-    use ink::codegen::{
-        EmitEvent,
-        Env,
+    use ink::{
+        codegen::{
+            EmitEvent,
+            Env,
+        },
+        prelude::vec::Vec,
     };
     use logics::{
         impls::manager::{
@@ -25,7 +28,23 @@ pub mod contract {
         },
         traits::{
             manager::Result,
-            types::WrappedU256,
+            pool::{
+                Facet,
+                KeeperIncentive,
+                UnderlyingCallConfig,
+            },
+            pool_factory::{
+                PoolParameters,
+                RateModelParameters,
+            },
+            price_oracle::{
+                DeviationBreaker,
+                PriceBounds,
+            },
+            types::{
+                ActionId,
+                WrappedU256,
+            },
         },
     };
     use openbrush::{
@@ -43,6 +62,8 @@ pub mod contract {
     pub const TOKEN_ADMIN: RoleType = ink::selector_id!("TOKEN_ADMIN");
     pub const BORROW_CAP_GUARDIAN: RoleType = ink::selector_id!("BORROW_CAP_GUARDIAN");
     pub const PAUSE_GUARDIAN: RoleType = ink::selector_id!("PAUSE_GUARDIAN");
+    pub const ORACLE_ADMIN: RoleType = ink::selector_id!("ORACLE_ADMIN");
+    pub const FLASHLOAN_ADMIN: RoleType = ink::selector_id!("FLASHLOAN_ADMIN");
 
     /// Contract's Storage
     #[ink(storage)]
@@ -87,6 +108,21 @@ pub mod contract {
         pub admin: AccountId,
     }
 
+    /// Event: Privileged action is executed.
+    ///
+    /// `action` is the selector of the message, values are SCALE encoded.
+    #[ink(event)]
+    pub struct AdminAction {
+        #[ink(topic)]
+        pub action: ActionId,
+        #[ink(topic)]
+        pub caller: AccountId,
+        #[ink(topic)]
+        pub target: Option<AccountId>,
+        pub old_value: Vec<u8>,
+        pub new_value: Vec<u8>,
+    }
+
     /// NOTE: Apply permission control by overriding the Default implementation to use the permission settings in Manager.
     impl manager::Manager for ManagerContract {
         #[ink(message)]
@@ -179,9 +215,311 @@ pub mod contract {
         }
         #[ink(message)]
         #[modifiers(access_control::only_role(TOKEN_ADMIN))]
+        fn cover_bad_debt(&mut self, pool: AccountId, amount: Balance) -> Result<()> {
+            self._cover_bad_debt(pool, amount)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(TOKEN_ADMIN))]
         fn sweep_token(&mut self, pool: AccountId, asset: AccountId) -> Result<()> {
             self._sweep_token(pool, asset)
         }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(PAUSE_GUARDIAN))]
+        fn set_seize_guardian_paused(&mut self, paused: bool) -> Result<()> {
+            self._set_seize_guardian_paused(paused)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(PAUSE_GUARDIAN))]
+        fn set_transfer_guardian_paused(&mut self, paused: bool) -> Result<()> {
+            self._set_transfer_guardian_paused(paused)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(CONTROLLER_ADMIN))]
+        fn set_shutdown_guardian(&mut self, guardian: Option<AccountId>) -> Result<()> {
+            self._set_shutdown_guardian(guardian)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(CONTROLLER_ADMIN))]
+        fn set_pool_factory(&mut self, pool_factory: Option<AccountId>) -> Result<()> {
+            self._set_pool_factory(pool_factory)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(CONTROLLER_ADMIN))]
+        fn create_pool(&mut self, underlying: AccountId, parameters: PoolParameters) -> Result<()> {
+            self._create_pool(underlying, parameters)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(DEFAULT_ADMIN_ROLE))]
+        fn set_pool_code_hash(&mut self, code_hash: Hash) -> Result<()> {
+            self._set_pool_code_hash(code_hash)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(CONTROLLER_ADMIN))]
+        fn create_rate_model(
+            &mut self,
+            code_hash: Hash,
+            parameters: RateModelParameters,
+        ) -> Result<()> {
+            self._create_rate_model(code_hash, parameters)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(DEFAULT_ADMIN_ROLE))]
+        fn set_rate_model_code_hash_approval(
+            &mut self,
+            code_hash: Hash,
+            approved: bool,
+        ) -> Result<()> {
+            self._set_rate_model_code_hash_approval(code_hash, approved)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(PAUSE_GUARDIAN))]
+        fn shutdown_protocol(&mut self) -> Result<()> {
+            self._shutdown_protocol()
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(CONTROLLER_ADMIN))]
+        fn recover_protocol(&mut self) -> Result<()> {
+            self._recover_protocol()
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(CONTROLLER_ADMIN))]
+        fn reactivate_protocol(&mut self) -> Result<()> {
+            self._reactivate_protocol()
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(TOKEN_ADMIN))]
+        fn set_interest_rate_model(
+            &mut self,
+            pool: AccountId,
+            new_interest_rate_model: AccountId,
+        ) -> Result<()> {
+            self._set_interest_rate_model(pool, new_interest_rate_model)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(TOKEN_ADMIN))]
+        fn set_liquidation_threshold(
+            &mut self,
+            pool: AccountId,
+            new_liquidation_threshold: u128,
+        ) -> Result<()> {
+            self._set_liquidation_threshold(pool, new_liquidation_threshold)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(TOKEN_ADMIN))]
+        fn set_borrow_rate_max_mantissa(
+            &mut self,
+            pool: AccountId,
+            new_borrow_rate_max_mantissa: WrappedU256,
+        ) -> Result<()> {
+            self._set_borrow_rate_max_mantissa(pool, new_borrow_rate_max_mantissa)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(TOKEN_ADMIN))]
+        fn set_stable_rate_config(
+            &mut self,
+            pool: AccountId,
+            enabled: bool,
+            premium_mantissa: WrappedU256,
+            rebalance_threshold_mantissa: WrappedU256,
+        ) -> Result<()> {
+            self._set_stable_rate_config(
+                pool,
+                enabled,
+                premium_mantissa,
+                rebalance_threshold_mantissa,
+            )
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(TOKEN_ADMIN))]
+        fn set_underlying_call_config(
+            &mut self,
+            pool: AccountId,
+            config: UnderlyingCallConfig,
+        ) -> Result<()> {
+            self._set_underlying_call_config(pool, config)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(TOKEN_ADMIN))]
+        fn set_max_accrual_delta(
+            &mut self,
+            pool: AccountId,
+            new_max_accrual_delta: Timestamp,
+        ) -> Result<()> {
+            self._set_max_accrual_delta(pool, new_max_accrual_delta)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(TOKEN_ADMIN))]
+        fn set_compound_interest(&mut self, pool: AccountId, enabled: bool) -> Result<()> {
+            self._set_compound_interest(pool, enabled)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(TOKEN_ADMIN))]
+        fn set_flash_mint_premium(&mut self, pool: AccountId, premium: u128) -> Result<()> {
+            self._set_flash_mint_premium(pool, premium)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(TOKEN_ADMIN))]
+        fn set_origination_fee(&mut self, pool: AccountId, fee: u128) -> Result<()> {
+            self._set_origination_fee(pool, fee)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(TOKEN_ADMIN))]
+        fn set_reserves_sweep_threshold(
+            &mut self,
+            pool: AccountId,
+            threshold: Balance,
+        ) -> Result<()> {
+            self._set_reserves_sweep_threshold(pool, threshold)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(TOKEN_ADMIN))]
+        fn set_keeper_incentive(
+            &mut self,
+            pool: AccountId,
+            incentive: KeeperIncentive,
+        ) -> Result<()> {
+            self._set_keeper_incentive(pool, incentive)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(TOKEN_ADMIN))]
+        fn set_incentives_controller(
+            &mut self,
+            pool: AccountId,
+            incentives_controller: AccountId,
+        ) -> Result<()> {
+            self._set_incentives_controller(pool, incentives_controller)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(BORROW_CAP_GUARDIAN))]
+        fn set_account_supply_cap(&mut self, pool: AccountId, new_cap: Balance) -> Result<()> {
+            self._set_account_supply_cap(pool, new_cap)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(BORROW_CAP_GUARDIAN))]
+        fn set_outflow_limit(
+            &mut self,
+            pool: AccountId,
+            limit: Balance,
+            window: Timestamp,
+        ) -> Result<()> {
+            self._set_outflow_limit(pool, limit, window)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(ORACLE_ADMIN))]
+        fn set_reporter(&mut self, reporter: AccountId, authorized: bool) -> Result<()> {
+            self._set_reporter(reporter, authorized)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(ORACLE_ADMIN))]
+        fn set_heartbeat(&mut self, asset: AccountId, heartbeat: Timestamp) -> Result<()> {
+            self._set_heartbeat(asset, heartbeat)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(ORACLE_ADMIN))]
+        fn set_price_decimals(&mut self, asset: AccountId, decimals: u8) -> Result<()> {
+            self._set_price_decimals(asset, decimals)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(ORACLE_ADMIN))]
+        fn set_price_bounds(&mut self, asset: AccountId, bounds: PriceBounds) -> Result<()> {
+            self._set_price_bounds(asset, bounds)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(ORACLE_ADMIN))]
+        fn set_deviation_breaker(
+            &mut self,
+            asset: AccountId,
+            breaker: DeviationBreaker,
+        ) -> Result<()> {
+            self._set_deviation_breaker(asset, breaker)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(FLASHLOAN_ADMIN))]
+        fn set_flashloan_premium_total(&mut self, premium: u128) -> Result<()> {
+            self._set_flashloan_premium_total(premium)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(FLASHLOAN_ADMIN))]
+        fn set_flashloan_premium_to_protocol(&mut self, premium_to_protocol: u128) -> Result<()> {
+            self._set_flashloan_premium_to_protocol(premium_to_protocol)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(FLASHLOAN_ADMIN))]
+        fn set_treasury(&mut self, treasury: AccountId) -> Result<()> {
+            self._set_treasury(treasury)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(FLASHLOAN_ADMIN))]
+        fn set_premium_exemption(
+            &mut self,
+            account: AccountId,
+            premium: Option<u128>,
+        ) -> Result<()> {
+            self._set_premium_exemption(account, premium)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(PAUSE_GUARDIAN))]
+        fn set_flashloan_paused(&mut self, paused: bool) -> Result<()> {
+            self._set_flashloan_paused(paused)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(PAUSE_GUARDIAN))]
+        fn set_flashloan_enabled(&mut self, asset: AccountId, enabled: bool) -> Result<()> {
+            self._set_flashloan_enabled(asset, enabled)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(DEFAULT_ADMIN_ROLE))]
+        fn accept_controller_manager(&mut self) -> Result<()> {
+            self._accept_controller_manager()
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(DEFAULT_ADMIN_ROLE))]
+        fn accept_pool_manager(&mut self, pool: AccountId) -> Result<()> {
+            self._accept_pool_manager(pool)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(DEFAULT_ADMIN_ROLE))]
+        fn accept_ownership_of(&mut self, target: AccountId) -> Result<()> {
+            self._accept_ownership_of(target)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(DEFAULT_ADMIN_ROLE))]
+        fn upgrade_controller_code(&mut self, code_hash: Hash) -> Result<()> {
+            self._upgrade_controller_code(code_hash)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(DEFAULT_ADMIN_ROLE))]
+        fn upgrade_pool_code(&mut self, pool: AccountId, code_hash: Hash) -> Result<()> {
+            self._upgrade_pool_code(pool, code_hash)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(DEFAULT_ADMIN_ROLE))]
+        fn set_pool_facet(
+            &mut self,
+            pool: AccountId,
+            facet: Facet,
+            code_hash: Option<Hash>,
+        ) -> Result<()> {
+            self._set_pool_facet(pool, facet, code_hash)
+        }
+    }
+
+    impl manager::Internal for ManagerContract {
+        fn _emit_admin_action_event(
+            &self,
+            action: ActionId,
+            target: Option<AccountId>,
+            old_value: Vec<u8>,
+            new_value: Vec<u8>,
+        ) {
+            self.env().emit_event(AdminAction {
+                action,
+                caller: self.env().caller(),
+                target,
+                old_value,
+                new_value,
+            })
+        }
     }
 
     impl access_control::AccessControl for ManagerContract {}
@@ -227,7 +565,10 @@ pub mod contract {
         #[ink(constructor)]
         pub fn new(controller: AccountId) -> Self {
             let mut instance = Self {
-                manager: manager::Data { controller },
+                manager: manager::Data {
+                    controller,
+                    treasury: None,
+                },
                 access: access_control::Data::default(),
             };
             instance._init_with_caller();
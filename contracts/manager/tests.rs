@@ -9,7 +9,23 @@ use ink::env::{
 use logics::{
     impls::manager::Manager,
     traits::{
-        manager::Error,
+        manager::{
+            Call,
+            Error,
+        },
+        pool::{
+            Facet,
+            KeeperIncentive,
+            UnderlyingCallConfig,
+        },
+        pool_factory::{
+            PoolParameters,
+            RateModelParameters,
+        },
+        price_oracle::{
+            DeviationBreaker,
+            PriceBounds,
+        },
         types::WrappedU256,
     },
 };
@@ -19,7 +35,10 @@ use openbrush::{
         AccessControlError,
         DEFAULT_ADMIN_ROLE,
     },
-    traits::AccountId,
+    traits::{
+        AccountId,
+        Hash,
+    },
 };
 use scale::Decode;
 
@@ -58,6 +77,7 @@ fn new_works() {
     let contract = ManagerContract::new(controller);
 
     assert_eq!(contract.controller(), controller);
+    assert_eq!(contract.treasury(), None);
     assert!(contract.has_role(DEFAULT_ADMIN_ROLE, accounts.bob));
     let events = get_emitted_events();
     assert_eq!(events.len(), 1);
@@ -463,6 +483,10 @@ fn reduce_reserves_fails_by_no_authority() {
         contract.reduce_reserves(pool, 100).unwrap_err(),
         Error::AccessControl(AccessControlError::MissingRole)
     );
+    assert_eq!(
+        contract.cover_bad_debt(pool, 100).unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
 }
 
 #[ink::test]
@@ -497,3 +521,782 @@ fn sweep_token_fails_by_no_authority() {
         Error::AccessControl(AccessControlError::MissingRole)
     );
 }
+
+#[ink::test]
+fn set_seize_guardian_paused_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(TOKEN_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(ORACLE_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    assert_eq!(
+        contract.set_seize_guardian_paused(true).unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_transfer_guardian_paused_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(TOKEN_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(ORACLE_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    assert_eq!(
+        contract.set_transfer_guardian_paused(true).unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_shutdown_guardian_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(TOKEN_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(PAUSE_GUARDIAN, accounts.bob).is_ok());
+    assert!(contract.grant_role(ORACLE_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    assert_eq!(
+        contract.set_shutdown_guardian(None).unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_pool_factory_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(TOKEN_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(PAUSE_GUARDIAN, accounts.bob).is_ok());
+    assert!(contract.grant_role(ORACLE_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    assert_eq!(
+        contract.set_pool_factory(None).unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+    let underlying = AccountId::from([0x02; 32]);
+    let parameters = PoolParameters {
+        incentives_controller: None,
+        rate_model: AccountId::from([0x03; 32]),
+        initial_exchange_rate_mantissa: WrappedU256::from(0),
+        reserve_factor_mantissa: WrappedU256::from(0),
+        collateral_factor_mantissa: None,
+        liquidation_threshold: 10000,
+    };
+    assert_eq!(
+        contract.create_pool(underlying, parameters).unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+    let parameters = RateModelParameters {
+        base_rate_per_year: WrappedU256::from(0),
+        multiplier_per_year: WrappedU256::from(0),
+        jump_multiplier_per_year: WrappedU256::from(0),
+        kink: WrappedU256::from(0),
+    };
+    assert_eq!(
+        contract
+            .create_rate_model(Hash::from([0x04; 32]), parameters)
+            .unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn shutdown_protocol_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(TOKEN_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(ORACLE_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    assert_eq!(
+        contract.shutdown_protocol().unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn recover_protocol_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(TOKEN_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(PAUSE_GUARDIAN, accounts.bob).is_ok());
+    assert!(contract.grant_role(ORACLE_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    assert_eq!(
+        contract.recover_protocol().unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn reactivate_protocol_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(TOKEN_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(PAUSE_GUARDIAN, accounts.bob).is_ok());
+    assert!(contract.grant_role(ORACLE_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    assert_eq!(
+        contract.reactivate_protocol().unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_interest_rate_model_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(PAUSE_GUARDIAN, accounts.bob).is_ok());
+    assert!(contract.grant_role(ORACLE_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    let pool = AccountId::from([0x02; 32]);
+    let new_interest_rate_model = AccountId::from([0x03; 32]);
+    assert_eq!(
+        contract
+            .set_interest_rate_model(pool, new_interest_rate_model)
+            .unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_liquidation_threshold_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(PAUSE_GUARDIAN, accounts.bob).is_ok());
+    assert!(contract.grant_role(ORACLE_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    let pool = AccountId::from([0x02; 32]);
+    assert_eq!(
+        contract.set_liquidation_threshold(pool, 100).unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_borrow_rate_max_mantissa_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(PAUSE_GUARDIAN, accounts.bob).is_ok());
+    assert!(contract.grant_role(ORACLE_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    let pool = AccountId::from([0x02; 32]);
+    assert_eq!(
+        contract
+            .set_borrow_rate_max_mantissa(pool, WrappedU256::from(100))
+            .unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_stable_rate_config_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(PAUSE_GUARDIAN, accounts.bob).is_ok());
+    assert!(contract.grant_role(ORACLE_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    let pool = AccountId::from([0x02; 32]);
+    assert_eq!(
+        contract
+            .set_stable_rate_config(pool, true, WrappedU256::from(100), WrappedU256::from(100))
+            .unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_underlying_call_config_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(PAUSE_GUARDIAN, accounts.bob).is_ok());
+    assert!(contract.grant_role(ORACLE_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    let pool = AccountId::from([0x02; 32]);
+    assert_eq!(
+        contract
+            .set_underlying_call_config(pool, UnderlyingCallConfig::default())
+            .unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_max_accrual_delta_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(PAUSE_GUARDIAN, accounts.bob).is_ok());
+    assert!(contract.grant_role(ORACLE_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    let pool = AccountId::from([0x02; 32]);
+    assert_eq!(
+        contract.set_max_accrual_delta(pool, 1000).unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_compound_interest_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(PAUSE_GUARDIAN, accounts.bob).is_ok());
+    assert!(contract.grant_role(ORACLE_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    let pool = AccountId::from([0x02; 32]);
+    assert_eq!(
+        contract.set_compound_interest(pool, true).unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_flash_mint_premium_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(PAUSE_GUARDIAN, accounts.bob).is_ok());
+    assert!(contract.grant_role(ORACLE_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    let pool = AccountId::from([0x02; 32]);
+    assert_eq!(
+        contract.set_flash_mint_premium(pool, 100).unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_origination_fee_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(PAUSE_GUARDIAN, accounts.bob).is_ok());
+    assert!(contract.grant_role(ORACLE_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    let pool = AccountId::from([0x02; 32]);
+    assert_eq!(
+        contract.set_origination_fee(pool, 10).unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_reserves_sweep_threshold_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(PAUSE_GUARDIAN, accounts.bob).is_ok());
+    assert!(contract.grant_role(ORACLE_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    let pool = AccountId::from([0x02; 32]);
+    assert_eq!(
+        contract
+            .set_reserves_sweep_threshold(pool, 100)
+            .unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_keeper_incentive_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(PAUSE_GUARDIAN, accounts.bob).is_ok());
+    assert!(contract.grant_role(ORACLE_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    let pool = AccountId::from([0x02; 32]);
+    assert_eq!(
+        contract
+            .set_keeper_incentive(pool, KeeperIncentive::default())
+            .unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_incentives_controller_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(PAUSE_GUARDIAN, accounts.bob).is_ok());
+    assert!(contract.grant_role(ORACLE_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    let pool = AccountId::from([0x02; 32]);
+    let incentives_controller = AccountId::from([0x03; 32]);
+    assert_eq!(
+        contract
+            .set_incentives_controller(pool, incentives_controller)
+            .unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_account_supply_cap_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(TOKEN_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(PAUSE_GUARDIAN, accounts.bob).is_ok());
+    assert!(contract.grant_role(ORACLE_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    let pool = AccountId::from([0x02; 32]);
+    assert_eq!(
+        contract.set_account_supply_cap(pool, 100).unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_outflow_limit_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(TOKEN_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(PAUSE_GUARDIAN, accounts.bob).is_ok());
+    assert!(contract.grant_role(ORACLE_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    let pool = AccountId::from([0x02; 32]);
+    assert_eq!(
+        contract.set_outflow_limit(pool, 100, 1000).unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_reporter_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(TOKEN_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(PAUSE_GUARDIAN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    let reporter = AccountId::from([0x02; 32]);
+    assert_eq!(
+        contract.set_reporter(reporter, true).unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_heartbeat_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(TOKEN_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(PAUSE_GUARDIAN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    let asset = AccountId::from([0x02; 32]);
+    assert_eq!(
+        contract.set_heartbeat(asset, 1000).unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_price_decimals_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(TOKEN_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(PAUSE_GUARDIAN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    let asset = AccountId::from([0x02; 32]);
+    assert_eq!(
+        contract.set_price_decimals(asset, 12).unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_price_bounds_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(TOKEN_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(PAUSE_GUARDIAN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    let asset = AccountId::from([0x02; 32]);
+    assert_eq!(
+        contract
+            .set_price_bounds(asset, PriceBounds::default())
+            .unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_deviation_breaker_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(TOKEN_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(PAUSE_GUARDIAN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    let asset = AccountId::from([0x02; 32]);
+    assert_eq!(
+        contract
+            .set_deviation_breaker(asset, DeviationBreaker::default())
+            .unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_flashloan_premium_total_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(TOKEN_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(PAUSE_GUARDIAN, accounts.bob).is_ok());
+    assert!(contract.grant_role(ORACLE_ADMIN, accounts.bob).is_ok());
+    assert_eq!(
+        contract.set_flashloan_premium_total(100).unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_flashloan_premium_to_protocol_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(TOKEN_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(PAUSE_GUARDIAN, accounts.bob).is_ok());
+    assert!(contract.grant_role(ORACLE_ADMIN, accounts.bob).is_ok());
+    assert_eq!(
+        contract.set_flashloan_premium_to_protocol(100).unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_treasury_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(TOKEN_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(PAUSE_GUARDIAN, accounts.bob).is_ok());
+    assert!(contract.grant_role(ORACLE_ADMIN, accounts.bob).is_ok());
+    let treasury = AccountId::from([0x02; 32]);
+    assert_eq!(
+        contract.set_treasury(treasury).unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_premium_exemption_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(TOKEN_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(PAUSE_GUARDIAN, accounts.bob).is_ok());
+    assert!(contract.grant_role(ORACLE_ADMIN, accounts.bob).is_ok());
+    let account = AccountId::from([0x02; 32]);
+    assert_eq!(
+        contract
+            .set_premium_exemption(account, Some(100))
+            .unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_flashloan_paused_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(TOKEN_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(ORACLE_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    assert_eq!(
+        contract.set_flashloan_paused(true).unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn set_flashloan_enabled_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.grant_role(CONTROLLER_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(TOKEN_ADMIN, accounts.bob).is_ok());
+    assert!(contract
+        .grant_role(BORROW_CAP_GUARDIAN, accounts.bob)
+        .is_ok());
+    assert!(contract.grant_role(ORACLE_ADMIN, accounts.bob).is_ok());
+    assert!(contract.grant_role(FLASHLOAN_ADMIN, accounts.bob).is_ok());
+    let asset = AccountId::from([0x02; 32]);
+    assert_eq!(
+        contract.set_flashloan_enabled(asset, true).unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn accept_controller_manager_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.accept_controller_manager().unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn accept_pool_manager_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    set_caller(accounts.charlie);
+    let pool = AccountId::from([0x02; 32]);
+    assert_eq!(
+        contract.accept_pool_manager(pool).unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn accept_ownership_of_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    set_caller(accounts.charlie);
+    let target = AccountId::from([0x02; 32]);
+    assert_eq!(
+        contract.accept_ownership_of(target).unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn upgrade_code_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    set_caller(accounts.charlie);
+    let pool = AccountId::from([0x02; 32]);
+    let code_hash = Hash::from([0x03; 32]);
+    assert_eq!(
+        contract.upgrade_controller_code(code_hash).unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+    assert_eq!(
+        contract.upgrade_pool_code(pool, code_hash).unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+    assert_eq!(
+        contract
+            .set_pool_facet(pool, Facet::Admin, Some(code_hash))
+            .unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+    assert_eq!(
+        contract.set_pool_code_hash(code_hash).unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+    assert_eq!(
+        contract
+            .set_rate_model_code_hash_approval(code_hash, true)
+            .unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
+
+#[ink::test]
+fn execute_batch_fails_by_no_authority() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    let controller = AccountId::from([0x01; 32]);
+    let mut contract = ManagerContract::new(controller);
+    assert!(contract.execute_batch(vec![]).is_ok());
+
+    // each call requires the role of its own message
+    set_caller(accounts.charlie);
+    let pool = AccountId::from([0x02; 32]);
+    assert_eq!(
+        contract
+            .execute_batch(vec![Call::SetBorrowCap { pool, new_cap: 0 }])
+            .unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+    set_caller(accounts.bob);
+    assert!(contract
+        .grant_role(CONTROLLER_ADMIN, accounts.charlie)
+        .is_ok());
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract
+            .execute_batch(vec![Call::SetBorrowCap { pool, new_cap: 0 }])
+            .unwrap_err(),
+        Error::AccessControl(AccessControlError::MissingRole)
+    );
+}
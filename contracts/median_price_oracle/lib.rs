@@ -0,0 +1,186 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![feature(min_specialization)]
+
+#[cfg(test)]
+mod tests;
+
+/// Definition of Median Price Oracle Contract
+#[openbrush::contract]
+pub mod contract {
+    use ink::{
+        codegen::{
+            EmitEvent,
+            Env,
+        },
+        prelude::vec::Vec,
+    };
+    use logics::impls::{
+        median_price_oracle::{
+            Internal,
+            *,
+        },
+        ownable_2step::{
+            self,
+            Internal as Ownable2StepInternal,
+            Ownable2Step,
+        },
+    };
+    use openbrush::{
+        contracts::ownable::*,
+        modifiers,
+        traits::Storage,
+    };
+
+    /// Contract's Storage
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct MedianPriceOracleContract {
+        #[storage_field]
+        price_oracle: Data,
+        #[storage_field]
+        ownable: ownable::Data,
+        #[storage_field]
+        ownable_2step: ownable_2step::Data,
+    }
+
+    /// Event: A reporter posts a price
+    #[ink(event)]
+    pub struct PricePosted {
+        #[ink(topic)]
+        asset: AccountId,
+        price: u128,
+        reporter: AccountId,
+    }
+
+    /// Event: A reporter is authorized or revoked
+    #[ink(event)]
+    pub struct ReporterUpdated {
+        #[ink(topic)]
+        reporter: AccountId,
+        authorized: bool,
+    }
+
+    /// Event: Ownership is proposed to a new owner
+    #[ink(event)]
+    pub struct OwnershipTransferStarted {
+        #[ink(topic)]
+        pub previous_owner: AccountId,
+        #[ink(topic)]
+        pub new_owner: AccountId,
+    }
+
+    /// Event: Ownership is transferred
+    #[ink(event)]
+    pub struct OwnershipTransferred {
+        #[ink(topic)]
+        pub previous_owner: Option<AccountId>,
+        #[ink(topic)]
+        pub new_owner: Option<AccountId>,
+    }
+
+    impl PriceOracle for MedianPriceOracleContract {
+        #[ink(message)]
+        fn get_price(&self, asset: AccountId) -> Option<u128> {
+            self._get_price(asset)
+        }
+
+        #[ink(message)]
+        fn get_underlying_price(&self, pool: AccountId) -> Option<u128> {
+            self._get_underlying_price(pool)
+        }
+
+        #[ink(message)]
+        fn set_fixed_price(&mut self, asset: AccountId, value: u128) -> Result<()> {
+            self._post_price(asset, value)
+        }
+    }
+
+    impl Ownable for MedianPriceOracleContract {
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn transfer_ownership(
+            &mut self,
+            new_owner: AccountId,
+        ) -> core::result::Result<(), OwnableError> {
+            self._propose_owner(new_owner)
+        }
+
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn renounce_ownership(&mut self) -> core::result::Result<(), OwnableError> {
+            self._renounce_ownership()
+        }
+    }
+
+    impl Ownable2Step for MedianPriceOracleContract {}
+
+    impl ownable::Internal for MedianPriceOracleContract {
+        fn _emit_ownership_transferred_event(
+            &self,
+            previous_owner: Option<AccountId>,
+            new_owner: Option<AccountId>,
+        ) {
+            self.env().emit_event(OwnershipTransferred {
+                previous_owner,
+                new_owner,
+            })
+        }
+    }
+
+    impl ownable_2step::Internal for MedianPriceOracleContract {
+        fn _emit_ownership_transfer_started_event(
+            &self,
+            previous_owner: AccountId,
+            new_owner: AccountId,
+        ) {
+            self.env().emit_event(OwnershipTransferStarted {
+                previous_owner,
+                new_owner,
+            })
+        }
+    }
+
+    impl MedianPriceOracle for MedianPriceOracleContract {}
+
+    impl Internal for MedianPriceOracleContract {
+        fn _emit_price_posted_event(&self, asset: AccountId, price: u128, reporter: AccountId) {
+            self.env().emit_event(PricePosted {
+                asset,
+                price,
+                reporter,
+            })
+        }
+
+        fn _emit_reporter_updated_event(&self, reporter: AccountId, authorized: bool) {
+            self.env().emit_event(ReporterUpdated {
+                reporter,
+                authorized,
+            })
+        }
+    }
+
+    impl MedianPriceOracleContract {
+        /// Generate this contract
+        #[ink(constructor)]
+        pub fn new(reporters: Vec<AccountId>, min_reports: u8) -> Self {
+            let mut instance = Self::default();
+            instance._init_with_owner(Self::env().caller());
+            for reporter in reporters {
+                instance
+                    ._set_reporter(reporter, true)
+                    .expect("failed to register reporter");
+            }
+            instance
+                ._set_min_reports(min_reports)
+                .expect("invalid min reports");
+            instance
+        }
+    }
+}
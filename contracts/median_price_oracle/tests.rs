@@ -0,0 +1,139 @@
+use crate::contract::*;
+use ink::{
+    env::{
+        test::{
+            self,
+            DefaultAccounts,
+        },
+        DefaultEnvironment,
+    },
+    prelude::vec,
+};
+use logics::impls::median_price_oracle::*;
+use openbrush::{
+    contracts::ownable::OwnableError,
+    traits::AccountId,
+};
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    test::default_accounts::<DefaultEnvironment>()
+}
+fn set_caller(id: AccountId) {
+    test::set_caller::<DefaultEnvironment>(id);
+}
+fn set_block_timestamp(timestamp: u64) {
+    test::set_block_timestamp::<DefaultEnvironment>(timestamp);
+}
+
+#[ink::test]
+fn new_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let contract = MedianPriceOracleContract::new(vec![accounts.charlie, accounts.django], 2);
+    assert_eq!(contract.owner(), accounts.bob);
+    assert_eq!(
+        contract.reporters(),
+        vec![accounts.charlie, accounts.django]
+    );
+    assert_eq!(contract.min_reports(), 2);
+}
+
+#[ink::test]
+fn get_price_returns_median_of_reports() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract =
+        MedianPriceOracleContract::new(vec![accounts.charlie, accounts.django, accounts.eve], 2);
+    let asset = AccountId::from([0x01; 32]);
+
+    set_caller(accounts.charlie);
+    assert!(contract.set_fixed_price(asset, 100).is_ok());
+    // not enough reports
+    assert_eq!(contract.get_price(asset), None);
+
+    set_caller(accounts.django);
+    assert!(contract.set_fixed_price(asset, 110).is_ok());
+    assert_eq!(contract.get_price(asset), Some(105));
+
+    // a compromised reporter does not move the price out of the honest range
+    set_caller(accounts.eve);
+    assert!(contract.set_fixed_price(asset, u128::MAX).is_ok());
+    assert_eq!(contract.get_price(asset), Some(110));
+
+    // reports of a revoked reporter are ignored
+    set_caller(accounts.bob);
+    assert!(contract.set_reporter(accounts.eve, false).is_ok());
+    assert_eq!(contract.get_price(asset), Some(105));
+    set_caller(accounts.eve);
+    assert_eq!(
+        contract.set_fixed_price(asset, 1),
+        Err(Error::CallerIsNotReporter)
+    );
+}
+
+#[ink::test]
+fn get_price_ignores_stale_reports() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract =
+        MedianPriceOracleContract::new(vec![accounts.charlie, accounts.django, accounts.eve], 2);
+    let asset = AccountId::from([0x01; 32]);
+    assert!(contract.set_heartbeat(asset, 60_000).is_ok());
+
+    set_block_timestamp(0);
+    set_caller(accounts.charlie);
+    assert!(contract.set_fixed_price(asset, 90).is_ok());
+    set_block_timestamp(30_000);
+    set_caller(accounts.django);
+    assert!(contract.set_fixed_price(asset, 100).is_ok());
+    set_caller(accounts.eve);
+    assert!(contract.set_fixed_price(asset, 110).is_ok());
+    assert_eq!(contract.get_price(asset), Some(100));
+
+    set_block_timestamp(60_001);
+    assert_eq!(contract.get_price(asset), Some(105));
+    assert_eq!(
+        contract
+            .report(asset, accounts.charlie)
+            .map(|report| report.price),
+        Some(90)
+    );
+
+    set_block_timestamp(90_001);
+    assert_eq!(contract.get_price(asset), None);
+}
+
+#[ink::test]
+fn admin_functions_are_owner_only() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = MedianPriceOracleContract::new(vec![accounts.charlie], 1);
+    assert_eq!(contract.set_min_reports(0), Err(Error::InvalidMinReports));
+    for i in 0..(MAX_REPORTERS - 1) {
+        assert!(contract
+            .set_reporter(AccountId::from([i as u8; 32]), true)
+            .is_ok());
+    }
+    assert_eq!(
+        contract.set_reporter(AccountId::from([0xff; 32]), true),
+        Err(Error::TooManyReporters)
+    );
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.set_reporter(accounts.charlie, false),
+        Err(Error::Ownable(OwnableError::CallerIsNotOwner))
+    );
+    assert_eq!(
+        contract.set_min_reports(1),
+        Err(Error::Ownable(OwnableError::CallerIsNotOwner))
+    );
+    assert_eq!(
+        contract.set_heartbeat(AccountId::from([0x01; 32]), 0),
+        Err(Error::Ownable(OwnableError::CallerIsNotOwner))
+    );
+}
@@ -0,0 +1,195 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![feature(min_specialization)]
+
+/// Definition of Merkle Distributor Contract
+#[openbrush::contract]
+pub mod contract {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+
+    use logics::impls::{
+        merkle_distributor::{
+            Data,
+            Internal,
+            *,
+        },
+        ownable_2step::{
+            self,
+            Internal as Ownable2StepInternal,
+            Ownable2Step,
+        },
+        upgradeable::{
+            self,
+            Internal as UpgradeableInternal,
+            Upgradeable,
+        },
+    };
+    use openbrush::{
+        contracts::ownable::*,
+        modifiers,
+        traits::Storage,
+    };
+
+    /// Version of the storage layout this code is built for
+    pub const STORAGE_VERSION: u32 = 1;
+
+    /// Contract's Storage
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct MerkleDistributorContract {
+        #[storage_field]
+        merkle_distributor: Data,
+        #[storage_field]
+        ownable: ownable::Data,
+        #[storage_field]
+        ownable_2step: ownable_2step::Data,
+        #[storage_field]
+        upgradeable: upgradeable::Data,
+    }
+
+    /// Event: Merkle root is replaced
+    #[ink(event)]
+    pub struct MerkleRootUpdated {
+        merkle_root: Hash,
+    }
+
+    /// Event: Rewards are claimed to an account
+    #[ink(event)]
+    pub struct Claimed {
+        #[ink(topic)]
+        account: AccountId,
+        amount: Balance,
+    }
+
+    /// Event: Ownership is proposed to a new owner
+    #[ink(event)]
+    pub struct OwnershipTransferStarted {
+        #[ink(topic)]
+        pub previous_owner: AccountId,
+        #[ink(topic)]
+        pub new_owner: AccountId,
+    }
+
+    /// Event: Ownership is transferred
+    #[ink(event)]
+    pub struct OwnershipTransferred {
+        #[ink(topic)]
+        pub previous_owner: Option<AccountId>,
+        #[ink(topic)]
+        pub new_owner: Option<AccountId>,
+    }
+
+    /// Event: Code of this contract is replaced
+    #[ink(event)]
+    pub struct CodeUpgraded {
+        pub code_hash: Hash,
+        pub version: u32,
+    }
+
+    /// Event: Storage is migrated to the layout of the running code
+    #[ink(event)]
+    pub struct Migrated {
+        pub from: u32,
+        pub to: u32,
+    }
+
+    impl Internal for MerkleDistributorContract {
+        fn _emit_merkle_root_updated_event(&self, merkle_root: Hash) {
+            self.env().emit_event(MerkleRootUpdated { merkle_root })
+        }
+
+        fn _emit_claimed_event(&self, account: AccountId, amount: Balance) {
+            self.env().emit_event(Claimed { account, amount })
+        }
+    }
+    impl MerkleDistributor for MerkleDistributorContract {}
+
+    impl Ownable for MerkleDistributorContract {
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn transfer_ownership(
+            &mut self,
+            new_owner: AccountId,
+        ) -> core::result::Result<(), OwnableError> {
+            self._propose_owner(new_owner)
+        }
+
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn renounce_ownership(&mut self) -> core::result::Result<(), OwnableError> {
+            self._renounce_ownership()
+        }
+    }
+
+    impl Ownable2Step for MerkleDistributorContract {}
+
+    impl ownable::Internal for MerkleDistributorContract {
+        fn _emit_ownership_transferred_event(
+            &self,
+            previous_owner: Option<AccountId>,
+            new_owner: Option<AccountId>,
+        ) {
+            self.env().emit_event(OwnershipTransferred {
+                previous_owner,
+                new_owner,
+            })
+        }
+    }
+
+    impl ownable_2step::Internal for MerkleDistributorContract {
+        fn _emit_ownership_transfer_started_event(
+            &self,
+            previous_owner: AccountId,
+            new_owner: AccountId,
+        ) {
+            self.env().emit_event(OwnershipTransferStarted {
+                previous_owner,
+                new_owner,
+            })
+        }
+    }
+
+    impl Upgradeable for MerkleDistributorContract {}
+
+    impl upgradeable::Internal for MerkleDistributorContract {
+        fn _assert_upgrade_admin(&self) -> upgradeable::Result<()> {
+            if self.owner() != self.env().caller() {
+                return Err(upgradeable::Error::CallerIsNotAdmin)
+            }
+            Ok(())
+        }
+
+        fn _code_version(&self) -> u32 {
+            STORAGE_VERSION
+        }
+
+        fn _emit_code_upgraded_event(&self, code_hash: Hash, version: u32) {
+            self.env().emit_event(CodeUpgraded { code_hash, version });
+        }
+
+        fn _emit_migrated_event(&self, from: u32, to: u32) {
+            self.env().emit_event(Migrated { from, to });
+        }
+    }
+
+    impl MerkleDistributorContract {
+        /// Generate this contract
+        #[ink(constructor)]
+        pub fn new(token: AccountId) -> Self {
+            let mut instance = Self::default();
+            instance._init_with_owner(Self::env().caller());
+            instance._initialize(token);
+            instance._set_version(STORAGE_VERSION);
+            instance
+        }
+    }
+}
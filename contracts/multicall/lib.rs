@@ -0,0 +1,153 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+
+#[cfg(test)]
+mod tests;
+
+/// Definition of Multicall Contract
+///
+/// This is a contract to batch queries to protocol contracts into a single dry-run for the frontend
+#[ink::contract]
+pub mod contract {
+    use ink::{
+        env::call::{
+            build_call,
+            Call as CallType,
+            ExecutionInput,
+            Selector,
+        },
+        prelude::{
+            vec,
+            vec::Vec,
+        },
+    };
+    use scale::{
+        Decode,
+        Encode,
+        Input,
+        Output,
+    };
+
+    /// Message to send to a contract
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct Call {
+        target: AccountId,
+        selector: [u8; 4],
+        /// encoded arguments of the message
+        input: Vec<u8>,
+    }
+
+    impl Call {
+        pub fn new(target: AccountId, selector: [u8; 4], input: Vec<u8>) -> Self {
+            Self {
+                target,
+                selector,
+                input,
+            }
+        }
+    }
+
+    /// Outcome of a Call
+    #[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub struct CallResult {
+        success: bool,
+        /// encoded return value of the message
+        output: Vec<u8>,
+    }
+
+    #[derive(Debug, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+    pub enum Error {
+        /// Call at this index failed while all calls were required to succeed
+        CallFailed(u32),
+    }
+
+    pub type Result<T> = core::result::Result<T, Error>;
+
+    /// Arguments already encoded, pushed to the input as is
+    pub struct RawInput<'a>(pub &'a [u8]);
+
+    impl Encode for RawInput<'_> {
+        fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+            dest.write(self.0);
+        }
+    }
+
+    /// Return value left encoded, made of all remaining bytes of the output
+    pub struct RawOutput(pub Vec<u8>);
+
+    impl Decode for RawOutput {
+        fn decode<I: Input>(input: &mut I) -> core::result::Result<Self, scale::Error> {
+            let len = input
+                .remaining_len()?
+                .ok_or("Remaining length of the output is unknown")?;
+            let mut output = vec![0; len];
+            input.read(&mut output)?;
+            Ok(RawOutput(output))
+        }
+    }
+
+    #[ink(storage)]
+    #[derive(Default)]
+    pub struct MulticallContract {}
+
+    impl MulticallContract {
+        /// Generate this contract
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self {}
+        }
+
+        /// Sends the calls in order and returns their outputs.
+        /// Meant to be dry-run, as calls are sent from this contract and state changes are its own.
+        #[ink(message)]
+        pub fn aggregate(
+            &self,
+            calls: Vec<Call>,
+            require_success: bool,
+        ) -> Result<Vec<CallResult>> {
+            let mut results: Vec<CallResult> = Vec::with_capacity(calls.len());
+            for (index, call) in calls.iter().enumerate() {
+                let result = self._call(call);
+                if require_success && !result.success {
+                    return Err(Error::CallFailed(index as u32))
+                }
+                results.push(result);
+            }
+            Ok(results)
+        }
+
+        fn _call(&self, call: &Call) -> CallResult {
+            let result = build_call::<Environment>()
+                .call_type(CallType::new(call.target))
+                .exec_input(
+                    ExecutionInput::new(Selector::new(call.selector))
+                        .push_arg(RawInput(&call.input)),
+                )
+                .returns::<RawOutput>()
+                .try_invoke();
+            match result {
+                Ok(Ok(RawOutput(output))) => {
+                    CallResult {
+                        success: true,
+                        output,
+                    }
+                }
+                _ => {
+                    CallResult {
+                        success: false,
+                        output: Vec::new(),
+                    }
+                }
+            }
+        }
+    }
+}
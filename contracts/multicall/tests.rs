@@ -0,0 +1,37 @@
+use crate::contract::*;
+use ink::env::{
+    test::{
+        self,
+        DefaultAccounts,
+    },
+    DefaultEnvironment,
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    test::default_accounts::<DefaultEnvironment>()
+}
+fn set_caller(id: ink::primitives::AccountId) {
+    test::set_caller::<DefaultEnvironment>(id);
+}
+
+#[ink::test]
+fn new_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let contract = MulticallContract::new();
+    assert_eq!(contract.aggregate(Vec::new(), true), Ok(Vec::new()));
+}
+
+#[ink::test]
+fn raw_input_and_output_are_not_reencoded() {
+    let encoded = (42_u128, true).encode();
+    assert_eq!(RawInput(&encoded).encode(), encoded);
+
+    let output = RawOutput::decode(&mut &encoded[..]).unwrap();
+    assert_eq!(output.0, encoded);
+}
@@ -21,12 +21,36 @@ pub mod contract {
         },
         prelude::vec::Vec,
     };
+    #[cfg(feature = "facets")]
+    use ink::{
+        env::call::{
+            ExecutionInput,
+            Selector,
+        },
+        selector_bytes,
+    };
     use logics::{
-        impls::pool::{
-            Internal,
-            *,
+        impls::{
+            params::is_valid_liquidation_threshold,
+            pool::{
+                Internal,
+                *,
+            },
+            upgradeable::{
+                self,
+                Internal as UpgradeableInternal,
+                Upgradeable,
+            },
+            vault,
+            votes::{
+                self,
+                Votes,
+            },
+        },
+        traits::types::{
+            ActionId,
+            WrappedU256,
         },
-        traits::types::WrappedU256,
     };
     use openbrush::{
         contracts::psp22::{
@@ -44,6 +68,9 @@ pub mod contract {
         },
     };
 
+    /// Version of the storage layout this code is built for
+    pub const STORAGE_VERSION: u32 = 1;
+
     /// Contract's Storage
     #[ink(storage)]
     #[derive(Default, Storage)]
@@ -54,11 +81,16 @@ pub mod contract {
         psp22: psp22::Data,
         #[storage_field]
         metadata: metadata::Data,
+        #[storage_field]
+        votes: votes::Data,
+        #[storage_field]
+        upgradeable: upgradeable::Data,
     }
 
     /// Event: Execute `Mint`
     #[ink(event)]
     pub struct Mint {
+        #[ink(topic)]
         pub minter: AccountId,
         pub mint_amount: Balance,
         pub mint_tokens: Balance,
@@ -66,12 +98,14 @@ pub mod contract {
     /// Event: Execute `Redeem`
     #[ink(event)]
     pub struct Redeem {
+        #[ink(topic)]
         pub redeemer: AccountId,
         pub redeem_amount: Balance,
     }
     /// Event: Execute `Borrow`
     #[ink(event)]
     pub struct Borrow {
+        #[ink(topic)]
         pub borrower: AccountId,
         pub borrow_amount: Balance,
         pub account_borrows: Balance,
@@ -80,7 +114,9 @@ pub mod contract {
     /// Event: Execute `RepayBorrow`
     #[ink(event)]
     pub struct RepayBorrow {
+        #[ink(topic)]
         pub payer: AccountId,
+        #[ink(topic)]
         pub borrower: AccountId,
         pub repay_amount: Balance,
         pub account_borrows: Balance,
@@ -89,19 +125,78 @@ pub mod contract {
     /// Event: Execute `LiquidateBorrow`
     #[ink(event)]
     pub struct LiquidateBorrow {
+        #[ink(topic)]
         pub liquidator: AccountId,
+        #[ink(topic)]
         pub borrower: AccountId,
         pub repay_amount: Balance,
+        #[ink(topic)]
         pub token_collateral: AccountId,
         pub seize_tokens: Balance,
     }
     /// Event: Adding to Reserves
     #[ink(event)]
     pub struct ReservesAdded {
+        #[ink(topic)]
         pub benefactor: AccountId,
         pub add_amount: Balance,
         pub new_total_reserves: Balance,
     }
+    /// Event: Reducing Reserves
+    #[ink(event)]
+    pub struct ReservesReduced {
+        pub reduce_amount: Balance,
+        pub total_reserves_new: Balance,
+    }
+    /// Event: Reserves above the sweep threshold are transferred to the treasury
+    #[ink(event)]
+    pub struct ReservesSwept {
+        #[ink(topic)]
+        pub treasury: AccountId,
+        pub amount: Balance,
+        pub total_reserves_new: Balance,
+    }
+    /// Event: Debt of a borrower is written off
+    #[ink(event)]
+    pub struct BadDebtAbsorbed {
+        #[ink(topic)]
+        pub borrower: AccountId,
+        pub amount: Balance,
+        pub bad_debt: Balance,
+    }
+    /// Event: Bad debt is repaid
+    #[ink(event)]
+    pub struct BadDebtRepaid {
+        #[ink(topic)]
+        pub payer: AccountId,
+        pub amount: Balance,
+        pub bad_debt: Balance,
+    }
+    /// Event: Interest is accrued
+    #[ink(event)]
+    pub struct AccrueInterest {
+        pub interest_accumulated: Balance,
+        pub new_index: WrappedU256,
+        pub new_total_borrows: Balance,
+    }
+    /// Event: Controller is changed
+    #[ink(event)]
+    pub struct NewController {
+        pub old: Option<AccountId>,
+        pub new: Option<AccountId>,
+    }
+    /// Event: Interest Rate Model is changed
+    #[ink(event)]
+    pub struct NewInterestRateModel {
+        pub old: Option<AccountId>,
+        pub new: Option<AccountId>,
+    }
+    /// Event: Reserve Factor is changed
+    #[ink(event)]
+    pub struct NewReserveFactor {
+        pub old: WrappedU256,
+        pub new: WrappedU256,
+    }
 
     /// Event: Transfer Pool Token
     ///
@@ -138,6 +233,61 @@ pub mod contract {
         pub amount: Balance,
     }
 
+    /// Event: Debt of a borrower is converted into another rate mode
+    #[ink(event)]
+    pub struct SwapBorrowRateMode {
+        #[ink(topic)]
+        pub borrower: AccountId,
+        pub rate_mode: RateMode,
+        pub amount: Balance,
+    }
+
+    /// Event: Pool tokens are flash minted to a receiver and burned with a premium
+    #[ink(event)]
+    pub struct FlashMint {
+        #[ink(topic)]
+        pub receiver: AccountId,
+        #[ink(topic)]
+        pub initiator: AccountId,
+        pub amount: Balance,
+        pub premium: Balance,
+    }
+
+    /// Event: Bounty is paid from reserves to a keeper of this pool
+    #[ink(event)]
+    pub struct KeeperBountyPaid {
+        #[ink(topic)]
+        pub keeper: AccountId,
+        pub amount: Balance,
+    }
+
+    /// Event: Origination fee is charged on a borrow and credited to reserves
+    #[ink(event)]
+    pub struct OriginationFeeCharged {
+        #[ink(topic)]
+        pub borrower: AccountId,
+        pub fee: Balance,
+    }
+
+    /// Event: Voting weight of an account is delegated to another delegatee
+    #[ink(event)]
+    pub struct DelegateChanged {
+        #[ink(topic)]
+        pub delegator: AccountId,
+        pub from_delegate: Option<AccountId>,
+        #[ink(topic)]
+        pub to_delegate: AccountId,
+    }
+
+    /// Event: Voting weight of a delegatee is changed
+    #[ink(event)]
+    pub struct DelegateVotesChanged {
+        #[ink(topic)]
+        pub delegate: AccountId,
+        pub previous_balance: Balance,
+        pub new_balance: Balance,
+    }
+
     #[ink(event)]
     pub struct ReserveUsedAsCollateralEnabled {
         #[ink(topic)]
@@ -150,13 +300,42 @@ pub mod contract {
         pub user: AccountId,
     }
 
+    /// Event: Privileged action is executed.
+    ///
+    /// `action` is the selector of the message, values are SCALE encoded.
+    #[ink(event)]
+    pub struct AdminAction {
+        #[ink(topic)]
+        pub action: ActionId,
+        #[ink(topic)]
+        pub caller: AccountId,
+        #[ink(topic)]
+        pub target: Option<AccountId>,
+        pub old_value: Vec<u8>,
+        pub new_value: Vec<u8>,
+    }
+
+    /// Event: Code of this contract is replaced
+    #[ink(event)]
+    pub struct CodeUpgraded {
+        pub code_hash: Hash,
+        pub version: u32,
+    }
+
+    /// Event: Storage is migrated to the layout of the running code
+    #[ink(event)]
+    pub struct Migrated {
+        pub from: u32,
+        pub to: u32,
+    }
+
     impl Pool for PoolContract {
         #[ink(message)]
         fn set_controller(&mut self, _new_controller: AccountId) -> Result<()> {
             Err(Error::NotImplemented)
         }
 
-        #[ink(message)]
+        #[ink(message, payable)]
         fn add_reserves(&mut self, _amount: Balance) -> Result<()> {
             Err(Error::NotImplemented)
         }
@@ -165,8 +344,325 @@ pub mod contract {
         fn set_interest_rate_model(&mut self, _new_interest_rate_model: AccountId) -> Result<()> {
             Err(Error::NotImplemented)
         }
+
+        #[ink(message)]
+        #[cfg(feature = "facets")]
+        fn set_reserve_factor_mantissa(
+            &mut self,
+            new_reserve_factor_mantissa: WrappedU256,
+        ) -> Result<()> {
+            self._delegate_to_facet(
+                Facet::Admin,
+                ExecutionInput::new(Selector::new(selector_bytes!(
+                    "PoolAdminFacet::set_reserve_factor_mantissa"
+                )))
+                .push_arg(new_reserve_factor_mantissa),
+            )
+        }
+
+        #[ink(message)]
+        #[cfg(feature = "facets")]
+        fn set_liquidation_threshold(&mut self, new_liquidation_threshold: u128) -> Result<()> {
+            self._delegate_to_facet(
+                Facet::Admin,
+                ExecutionInput::new(Selector::new(selector_bytes!(
+                    "PoolAdminFacet::set_liquidation_threshold"
+                )))
+                .push_arg(new_liquidation_threshold),
+            )
+        }
+
+        #[ink(message)]
+        #[cfg(feature = "facets")]
+        fn set_borrow_rate_max_mantissa(
+            &mut self,
+            new_borrow_rate_max_mantissa: WrappedU256,
+        ) -> Result<()> {
+            self._delegate_to_facet(
+                Facet::Admin,
+                ExecutionInput::new(Selector::new(selector_bytes!(
+                    "PoolAdminFacet::set_borrow_rate_max_mantissa"
+                )))
+                .push_arg(new_borrow_rate_max_mantissa),
+            )
+        }
+
+        #[ink(message)]
+        #[cfg(feature = "facets")]
+        fn set_stable_rate_config(
+            &mut self,
+            enabled: bool,
+            premium_mantissa: WrappedU256,
+            rebalance_threshold_mantissa: WrappedU256,
+        ) -> Result<()> {
+            self._delegate_to_facet(
+                Facet::Admin,
+                ExecutionInput::new(Selector::new(selector_bytes!(
+                    "PoolAdminFacet::set_stable_rate_config"
+                )))
+                .push_arg(enabled)
+                .push_arg(premium_mantissa)
+                .push_arg(rebalance_threshold_mantissa),
+            )
+        }
+
+        #[ink(message)]
+        #[cfg(feature = "facets")]
+        fn set_underlying_call_config(&mut self, config: UnderlyingCallConfig) -> Result<()> {
+            self._delegate_to_facet(
+                Facet::Admin,
+                ExecutionInput::new(Selector::new(selector_bytes!(
+                    "PoolAdminFacet::set_underlying_call_config"
+                )))
+                .push_arg(config),
+            )
+        }
+
+        #[ink(message)]
+        #[cfg(feature = "facets")]
+        fn set_max_accrual_delta(&mut self, new_max_accrual_delta: Timestamp) -> Result<()> {
+            self._delegate_to_facet(
+                Facet::Admin,
+                ExecutionInput::new(Selector::new(selector_bytes!(
+                    "PoolAdminFacet::set_max_accrual_delta"
+                )))
+                .push_arg(new_max_accrual_delta),
+            )
+        }
+
+        #[ink(message)]
+        #[cfg(feature = "facets")]
+        fn set_compound_interest(&mut self, enabled: bool) -> Result<()> {
+            self._delegate_to_facet(
+                Facet::Admin,
+                ExecutionInput::new(Selector::new(selector_bytes!(
+                    "PoolAdminFacet::set_compound_interest"
+                )))
+                .push_arg(enabled),
+            )
+        }
+
+        #[ink(message)]
+        #[cfg(feature = "facets")]
+        fn set_flash_mint_premium(&mut self, premium: u128) -> Result<()> {
+            self._delegate_to_facet(
+                Facet::Admin,
+                ExecutionInput::new(Selector::new(selector_bytes!(
+                    "PoolAdminFacet::set_flash_mint_premium"
+                )))
+                .push_arg(premium),
+            )
+        }
+
+        #[ink(message)]
+        #[cfg(feature = "facets")]
+        fn set_origination_fee(&mut self, fee: u128) -> Result<()> {
+            self._delegate_to_facet(
+                Facet::Admin,
+                ExecutionInput::new(Selector::new(selector_bytes!(
+                    "PoolAdminFacet::set_origination_fee"
+                )))
+                .push_arg(fee),
+            )
+        }
+
+        #[ink(message)]
+        #[cfg(feature = "facets")]
+        fn set_keeper_incentive(&mut self, incentive: KeeperIncentive) -> Result<()> {
+            self._delegate_to_facet(
+                Facet::Admin,
+                ExecutionInput::new(Selector::new(selector_bytes!(
+                    "PoolAdminFacet::set_keeper_incentive"
+                )))
+                .push_arg(incentive),
+            )
+        }
+
+        #[ink(message)]
+        #[cfg(feature = "facets")]
+        fn set_account_supply_cap(&mut self, new_cap: Balance) -> Result<()> {
+            self._delegate_to_facet(
+                Facet::Admin,
+                ExecutionInput::new(Selector::new(selector_bytes!(
+                    "PoolAdminFacet::set_account_supply_cap"
+                )))
+                .push_arg(new_cap),
+            )
+        }
+
+        #[ink(message)]
+        #[cfg(feature = "facets")]
+        fn set_outflow_limit(&mut self, limit: Balance, window: Timestamp) -> Result<()> {
+            self._delegate_to_facet(
+                Facet::Admin,
+                ExecutionInput::new(Selector::new(selector_bytes!(
+                    "PoolAdminFacet::set_outflow_limit"
+                )))
+                .push_arg(limit)
+                .push_arg(window),
+            )
+        }
+
+        #[ink(message)]
+        #[cfg(feature = "facets")]
+        fn set_reserves_sweep_config(
+            &mut self,
+            treasury: Option<AccountId>,
+            threshold: Balance,
+        ) -> Result<()> {
+            self._delegate_to_facet(
+                Facet::Admin,
+                ExecutionInput::new(Selector::new(selector_bytes!(
+                    "PoolAdminFacet::set_reserves_sweep_config"
+                )))
+                .push_arg(treasury)
+                .push_arg(threshold),
+            )
+        }
+
+        #[ink(message)]
+        #[cfg(feature = "facets")]
+        fn reduce_reserves(&mut self, amount: Balance) -> Result<()> {
+            self._delegate_to_facet(
+                Facet::Admin,
+                ExecutionInput::new(Selector::new(selector_bytes!(
+                    "PoolAdminFacet::reduce_reserves"
+                )))
+                .push_arg(amount),
+            )
+        }
+
+        #[ink(message)]
+        #[cfg(feature = "facets")]
+        fn cover_bad_debt(&mut self, amount: Balance) -> Result<()> {
+            self._delegate_to_facet(
+                Facet::Admin,
+                ExecutionInput::new(Selector::new(selector_bytes!(
+                    "PoolAdminFacet::cover_bad_debt"
+                )))
+                .push_arg(amount),
+            )
+        }
+
+        #[ink(message)]
+        #[cfg(feature = "facets")]
+        fn sweep_token(&mut self, asset: AccountId) -> Result<()> {
+            self._delegate_to_facet(
+                Facet::Admin,
+                ExecutionInput::new(Selector::new(selector_bytes!(
+                    "PoolAdminFacet::sweep_token"
+                )))
+                .push_arg(asset),
+            )
+        }
+
+        #[ink(message)]
+        #[cfg(feature = "facets")]
+        fn sweep_reserves_to_treasury(&mut self) -> Result<Balance> {
+            self._delegate_to_facet(
+                Facet::Admin,
+                ExecutionInput::new(Selector::new(selector_bytes!(
+                    "PoolAdminFacet::sweep_reserves_to_treasury"
+                ))),
+            )
+        }
+
+        #[ink(message)]
+        #[cfg(feature = "facets")]
+        fn set_incentives_controller(&mut self, incentives_controller: AccountId) -> Result<()> {
+            self._delegate_to_facet(
+                Facet::Admin,
+                ExecutionInput::new(Selector::new(selector_bytes!(
+                    "PoolAdminFacet::set_incentives_controller"
+                )))
+                .push_arg(incentives_controller),
+            )
+        }
+
+        #[ink(message, payable)]
+        #[cfg(feature = "facets")]
+        fn liquidate_borrow(
+            &mut self,
+            borrower: AccountId,
+            repay_amount: Balance,
+            collateral: AccountId,
+        ) -> Result<()> {
+            self._delegate_to_facet(
+                Facet::Liquidation,
+                ExecutionInput::new(Selector::new(selector_bytes!(
+                    "PoolLiquidationFacet::liquidate_borrow"
+                )))
+                .push_arg(borrower)
+                .push_arg(repay_amount)
+                .push_arg(collateral),
+            )
+        }
+
+        #[ink(message, payable)]
+        #[cfg(feature = "facets")]
+        fn liquidate_borrow_max(
+            &mut self,
+            borrower: AccountId,
+            collateral: AccountId,
+        ) -> Result<()> {
+            self._delegate_to_facet(
+                Facet::Liquidation,
+                ExecutionInput::new(Selector::new(selector_bytes!(
+                    "PoolLiquidationFacet::liquidate_borrow_max"
+                )))
+                .push_arg(borrower)
+                .push_arg(collateral),
+            )
+        }
+
+        #[ink(message)]
+        #[cfg(feature = "facets")]
+        fn seize(
+            &mut self,
+            liquidator: AccountId,
+            borrower: AccountId,
+            seize_tokens: Balance,
+        ) -> Result<()> {
+            self._delegate_to_facet(
+                Facet::Liquidation,
+                ExecutionInput::new(Selector::new(selector_bytes!(
+                    "PoolLiquidationFacet::seize"
+                )))
+                .push_arg(liquidator)
+                .push_arg(borrower)
+                .push_arg(seize_tokens),
+            )
+        }
+
+        #[ink(message)]
+        #[cfg(feature = "facets")]
+        fn absorb(&mut self, borrower: AccountId) -> Result<Balance> {
+            self._delegate_to_facet(
+                Facet::Liquidation,
+                ExecutionInput::new(Selector::new(selector_bytes!(
+                    "PoolLiquidationFacet::absorb"
+                )))
+                .push_arg(borrower),
+            )
+        }
     }
     impl Internal for PoolContract {
+        fn _emit_admin_action_event(
+            &self,
+            action: ActionId,
+            target: Option<AccountId>,
+            old_value: Vec<u8>,
+            new_value: Vec<u8>,
+        ) {
+            self.env().emit_event(AdminAction {
+                action,
+                caller: self.env().caller(),
+                target,
+                old_value,
+                new_value,
+            })
+        }
+
         fn _emit_mint_event(&self, minter: AccountId, mint_amount: Balance, mint_tokens: Balance) {
             self.env().emit_event(Mint {
                 minter,
@@ -226,6 +722,39 @@ pub mod contract {
                 seize_tokens,
             })
         }
+        fn _emit_swap_borrow_rate_mode_event(
+            &self,
+            borrower: AccountId,
+            rate_mode: RateMode,
+            amount: Balance,
+        ) {
+            self.env().emit_event(SwapBorrowRateMode {
+                borrower,
+                rate_mode,
+                amount,
+            })
+        }
+        fn _emit_flash_mint_event(
+            &self,
+            receiver: AccountId,
+            initiator: AccountId,
+            amount: Balance,
+            premium: Balance,
+        ) {
+            self.env().emit_event(FlashMint {
+                receiver,
+                initiator,
+                amount,
+                premium,
+            })
+        }
+        fn _emit_keeper_bounty_paid_event(&self, keeper: AccountId, amount: Balance) {
+            self.env().emit_event(KeeperBountyPaid { keeper, amount })
+        }
+        fn _emit_origination_fee_charged_event(&self, borrower: AccountId, fee: Balance) {
+            self.env()
+                .emit_event(OriginationFeeCharged { borrower, fee })
+        }
         fn _emit_reserves_added_event(
             &self,
             benefactor: AccountId,
@@ -238,6 +767,77 @@ pub mod contract {
                 new_total_reserves,
             })
         }
+        fn _emit_reserves_reduced_event(
+            &self,
+            reduce_amount: Balance,
+            total_reserves_new: Balance,
+        ) {
+            self.env().emit_event(ReservesReduced {
+                reduce_amount,
+                total_reserves_new,
+            })
+        }
+        fn _emit_reserves_swept_event(
+            &self,
+            treasury: AccountId,
+            amount: Balance,
+            total_reserves_new: Balance,
+        ) {
+            self.env().emit_event(ReservesSwept {
+                treasury,
+                amount,
+                total_reserves_new,
+            })
+        }
+        fn _emit_bad_debt_absorbed_event(
+            &self,
+            borrower: AccountId,
+            amount: Balance,
+            bad_debt: Balance,
+        ) {
+            self.env().emit_event(BadDebtAbsorbed {
+                borrower,
+                amount,
+                bad_debt,
+            })
+        }
+        fn _emit_bad_debt_repaid_event(
+            &self,
+            payer: AccountId,
+            amount: Balance,
+            bad_debt: Balance,
+        ) {
+            self.env().emit_event(BadDebtRepaid {
+                payer,
+                amount,
+                bad_debt,
+            })
+        }
+        fn _emit_accrue_interest_event(
+            &self,
+            interest_accumulated: Balance,
+            new_index: WrappedU256,
+            new_total_borrows: Balance,
+        ) {
+            self.env().emit_event(AccrueInterest {
+                interest_accumulated,
+                new_index,
+                new_total_borrows,
+            })
+        }
+        fn _emit_new_controller_event(&self, old: Option<AccountId>, new: Option<AccountId>) {
+            self.env().emit_event(NewController { old, new })
+        }
+        fn _emit_new_interest_rate_model_event(
+            &self,
+            old: Option<AccountId>,
+            new: Option<AccountId>,
+        ) {
+            self.env().emit_event(NewInterestRateModel { old, new })
+        }
+        fn _emit_new_reserve_factor_event(&self, old: WrappedU256, new: WrappedU256) {
+            self.env().emit_event(NewReserveFactor { old, new })
+        }
 
         fn _emit_delegate_approval_event(
             &self,
@@ -313,10 +913,90 @@ pub mod contract {
                 value,
             });
         }
+
+        fn _before_token_transfer(
+            &mut self,
+            from: Option<&AccountId>,
+            to: Option<&AccountId>,
+            _amount: &Balance,
+        ) -> core::result::Result<(), PSP22Error> {
+            // accrue rewards with the balances before any mint, burn or transfer
+            for account in [from, to].into_iter().flatten() {
+                if Internal::_accrue_reward(self, *account).is_err() {
+                    return Err(PSP22Error::Custom(String::from("AccrueRewardFailed")))
+                }
+            }
+            Ok(())
+        }
+
+        fn _after_token_transfer(
+            &mut self,
+            from: Option<&AccountId>,
+            to: Option<&AccountId>,
+            amount: &Balance,
+        ) -> core::result::Result<(), PSP22Error> {
+            votes::Internal::_after_token_transfer_votes(self, from.copied(), to.copied(), *amount);
+            Ok(())
+        }
+    }
+
+    impl Votes for PoolContract {}
+
+    impl vault::Vault for PoolContract {}
+
+    impl votes::Internal for PoolContract {
+        fn _emit_delegate_changed_event(
+            &self,
+            delegator: AccountId,
+            from_delegate: Option<AccountId>,
+            to_delegate: AccountId,
+        ) {
+            self.env().emit_event(DelegateChanged {
+                delegator,
+                from_delegate,
+                to_delegate,
+            })
+        }
+
+        fn _emit_delegate_votes_changed_event(
+            &self,
+            delegate: AccountId,
+            previous_balance: Balance,
+            new_balance: Balance,
+        ) {
+            self.env().emit_event(DelegateVotesChanged {
+                delegate,
+                previous_balance,
+                new_balance,
+            })
+        }
     }
 
     impl metadata::PSP22Metadata for PoolContract {}
 
+    impl Upgradeable for PoolContract {}
+
+    impl upgradeable::Internal for PoolContract {
+        fn _assert_upgrade_admin(&self) -> upgradeable::Result<()> {
+            if self._manager() != Some(self.env().caller()) {
+                return Err(upgradeable::Error::CallerIsNotAdmin)
+            }
+            Ok(())
+        }
+
+        fn _code_version(&self) -> u32 {
+            STORAGE_VERSION
+        }
+
+        fn _emit_code_upgraded_event(&self, code_hash: Hash, version: u32) {
+            self.env().emit_event(CodeUpgraded { code_hash, version });
+        }
+
+        fn _emit_migrated_event(&self, from: u32, to: u32) {
+            self.env().emit_event(Migrated { from, to });
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     impl PoolContract {
         /// Generate this contract
@@ -338,10 +1018,13 @@ pub mod contract {
             if controller.is_zero() {
                 panic!("controller is zero address");
             }
+            if !is_valid_liquidation_threshold(liquidation_threshold) {
+                panic!("liquidation threshold is out of bounds");
+            }
             let mut instance = Self::default();
             instance._initialize(
                 incentives_controller,
-                underlying,
+                Underlying::PSP22(underlying),
                 controller,
                 Self::env().caller(),
                 rate_model,
@@ -370,6 +1053,9 @@ pub mod contract {
             if controller.is_zero() {
                 panic!("controller is zero address");
             }
+            if !is_valid_liquidation_threshold(liquidation_threshold) {
+                panic!("liquidation threshold is out of bounds");
+            }
             let base_name = PSP22MetadataRef::token_name(&underlying);
             let base_symbol = PSP22MetadataRef::token_symbol(&underlying);
             let decimals = PSP22MetadataRef::token_decimals(&underlying);
@@ -380,7 +1066,111 @@ pub mod contract {
             let mut instance = Self::default();
             instance._initialize(
                 incentives_controller,
-                underlying,
+                Underlying::PSP22(underlying),
+                controller,
+                Self::env().caller(),
+                rate_model,
+                initial_exchange_rate_mantissa,
+                liquidation_threshold,
+                name,
+                symbol,
+                decimals,
+            );
+            instance
+        }
+
+        /// Generate this contract with the native token as underlying
+        #[ink(constructor)]
+        pub fn new_native(
+            incentives_controller: Option<AccountId>,
+            controller: AccountId,
+            rate_model: AccountId,
+            initial_exchange_rate_mantissa: WrappedU256,
+            liquidation_threshold: u128,
+            name: String,
+            symbol: String,
+            decimals: u8,
+        ) -> Self {
+            if controller.is_zero() {
+                panic!("controller is zero address");
+            }
+            if !is_valid_liquidation_threshold(liquidation_threshold) {
+                panic!("liquidation threshold is out of bounds");
+            }
+            let mut instance = Self::default();
+            instance._initialize(
+                incentives_controller,
+                Underlying::Native,
+                controller,
+                Self::env().caller(),
+                rate_model,
+                initial_exchange_rate_mantissa,
+                liquidation_threshold,
+                name,
+                symbol,
+                decimals,
+            );
+            instance
+        }
+
+        /// Generate this contract with a pallet-assets asset as underlying
+        #[ink(constructor)]
+        pub fn new_pallet_asset(
+            incentives_controller: Option<AccountId>,
+            asset_id: u128,
+            controller: AccountId,
+            rate_model: AccountId,
+            initial_exchange_rate_mantissa: WrappedU256,
+            liquidation_threshold: u128,
+            name: String,
+            symbol: String,
+            decimals: u8,
+        ) -> Self {
+            if controller.is_zero() {
+                panic!("controller is zero address");
+            }
+            if !is_valid_liquidation_threshold(liquidation_threshold) {
+                panic!("liquidation threshold is out of bounds");
+            }
+            let mut instance = Self::default();
+            instance._initialize(
+                incentives_controller,
+                Underlying::PalletAsset(asset_id),
+                controller,
+                Self::env().caller(),
+                rate_model,
+                initial_exchange_rate_mantissa,
+                liquidation_threshold,
+                name,
+                symbol,
+                decimals,
+            );
+            instance
+        }
+
+        /// Generate this contract with an EVM ERC-20 token as underlying, moved through XVM
+        #[ink(constructor)]
+        pub fn new_evm_erc20(
+            incentives_controller: Option<AccountId>,
+            token: EvmAddress,
+            controller: AccountId,
+            rate_model: AccountId,
+            initial_exchange_rate_mantissa: WrappedU256,
+            liquidation_threshold: u128,
+            name: String,
+            symbol: String,
+            decimals: u8,
+        ) -> Self {
+            if controller.is_zero() {
+                panic!("controller is zero address");
+            }
+            if !is_valid_liquidation_threshold(liquidation_threshold) {
+                panic!("liquidation threshold is out of bounds");
+            }
+            let mut instance = Self::default();
+            instance._initialize(
+                incentives_controller,
+                Underlying::EvmErc20(token),
                 controller,
                 Self::env().caller(),
                 rate_model,
@@ -397,7 +1187,7 @@ pub mod contract {
         fn _initialize(
             &mut self,
             incentives_controller: Option<AccountId>,
-            underlying: AccountId,
+            underlying: Underlying,
             controller: AccountId,
             manager: AccountId,
             rate_model: AccountId,
@@ -408,7 +1198,8 @@ pub mod contract {
             decimals: u8,
         ) {
             self.pool.incentives_controller = incentives_controller;
-            self.pool.underlying = Some(underlying);
+            self.pool.underlying = Some(underlying.asset_id());
+            self.pool.underlying_kind = underlying.kind();
             self.pool.controller = Some(controller);
             self.pool.manager = Some(manager);
             self.pool.rate_model = Some(rate_model);
@@ -418,6 +1209,7 @@ pub mod contract {
             self.metadata.name = Some(name);
             self.metadata.symbol = Some(symbol);
             self.metadata.decimals = decimals;
+            self._set_version(STORAGE_VERSION);
         }
     }
 }
@@ -3,7 +3,9 @@ use ink::{
     env::{
         test::{
             self,
+            recorded_events,
             DefaultAccounts,
+            EmittedEvent,
         },
         DefaultEnvironment,
     },
@@ -13,25 +15,55 @@ use logics::{
     impls::{
         exp_no_err::exp_scale,
         pool::*,
+        upgradeable::{
+            self,
+            Upgradeable,
+        },
+        votes::{
+            Checkpoint,
+            Error as VotesError,
+            Votes,
+        },
     },
     traits::types::WrappedU256,
 };
 use openbrush::{
-    contracts::psp22::PSP22,
-    traits::AccountId,
+    contracts::psp22::{
+        Internal as PSP22Internal,
+        PSP22,
+    },
+    traits::{
+        AccountId,
+        Hash,
+        Storage,
+    },
 };
 use primitive_types::U256;
+use scale::{
+    Decode,
+    Encode,
+};
 use std::ops::{
     Add,
     Div,
+    Mul,
 };
 
+type Event = <PoolContract as ink::reflect::ContractEventBase>::Type;
+
 fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
     test::default_accounts::<DefaultEnvironment>()
 }
 fn set_caller(id: AccountId) {
     test::set_caller::<DefaultEnvironment>(id);
 }
+fn get_emitted_events() -> Vec<EmittedEvent> {
+    recorded_events().collect::<Vec<_>>()
+}
+fn decode_event(event: &EmittedEvent) -> Event {
+    <Event as Decode>::decode(&mut &event.data[..])
+        .expect("encountered invalid contract event data buffer")
+}
 
 #[ink::test]
 fn new_works() {
@@ -44,7 +76,7 @@ fn new_works() {
     let incentives_controller = AccountId::from([0x04; 32]);
     let initial_exchange_rate_mantissa = WrappedU256::from(exp_scale());
     let liquidation_threshold = 10000;
-    let contract = PoolContract::new(
+    let mut contract = PoolContract::new(
         Some(incentives_controller),
         underlying,
         controller,
@@ -56,6 +88,10 @@ fn new_works() {
         8,
     );
     assert_eq!(contract.underlying(), Some(underlying));
+    assert_eq!(
+        contract.underlying_asset(),
+        Some(Underlying::PSP22(underlying))
+    );
     assert_eq!(contract.controller(), Some(controller));
     assert_eq!(contract.manager(), Some(accounts.bob));
     assert_eq!(
@@ -71,6 +107,17 @@ fn new_works() {
         WrappedU256::from(U256::from(0))
     );
     assert_eq!(contract.total_borrows(), 0);
+    assert_eq!(contract.get_cash_prior(), 0);
+    assert_eq!(contract.checkpoints_count(), 0);
+    assert!(contract.checkpoints(0, 10).is_empty());
+    assert_eq!(
+        contract.borrow_snapshot(accounts.alice),
+        BorrowSnapshot::default()
+    );
+    assert_eq!(
+        contract.accrued_interest_of(accounts.alice).unwrap(),
+        (0, WrappedU256::from(0))
+    );
     assert_eq!(contract.liquidation_threshold(), liquidation_threshold);
 }
 
@@ -224,6 +271,26 @@ fn set_reserve_factor_works() {
         contract.reserve_factor_mantissa(),
         WrappedU256::from(half_exp_scale)
     );
+    let events = get_emitted_events();
+    assert_eq!(events.len(), 2);
+    if let Event::NewReserveFactor(event) = decode_event(&events[0]) {
+        assert_eq!(event.old, WrappedU256::from(0));
+        assert_eq!(event.new, WrappedU256::from(half_exp_scale));
+    } else {
+        panic!("unexpected event kind: expected NewReserveFactor event")
+    }
+    if let Event::AdminAction(event) = decode_event(&events[1]) {
+        assert_eq!(
+            event.action,
+            ink::selector_bytes!("Pool::set_reserve_factor_mantissa")
+        );
+        assert_eq!(event.caller, accounts.bob);
+        assert_eq!(event.target, None);
+        assert_eq!(event.old_value, WrappedU256::from(0).encode());
+        assert_eq!(event.new_value, WrappedU256::from(half_exp_scale).encode());
+    } else {
+        panic!("unexpected event kind: expected AdminAction event")
+    }
     let over_exp_scale = exp_scale().add(1);
     assert_eq!(
         contract
@@ -255,6 +322,7 @@ fn assert_manager_works() {
     set_caller(accounts.charlie);
     let admin_funcs: Vec<Result<()>> = vec![
         contract.reduce_reserves(100),
+        contract.cover_bad_debt(100),
         contract.sweep_token(dummy_id),
         contract.set_reserve_factor_mantissa(WrappedU256::from(0)),
     ];
@@ -263,6 +331,42 @@ fn assert_manager_works() {
     }
 }
 
+#[ink::test]
+fn set_facet_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let mut contract = PoolContract::new(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        10000,
+        String::from("Token Name"),
+        String::from("symbol"),
+        8,
+    );
+    assert_eq!(contract.facet(Facet::Admin), None);
+
+    let code_hash = Hash::from([0x02; 32]);
+    assert!(contract.set_facet(Facet::Admin, Some(code_hash)).is_ok());
+    assert_eq!(contract.facet(Facet::Admin), Some(code_hash));
+    assert_eq!(contract.facet(Facet::Liquidation), None);
+
+    assert!(contract.set_facet(Facet::Admin, None).is_ok());
+    assert_eq!(contract.facet(Facet::Admin), None);
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract
+            .set_facet(Facet::Liquidation, Some(code_hash))
+            .unwrap_err(),
+        Error::CallerIsNotManager
+    );
+}
+
 #[ink::test]
 fn set_liquidation_threshold_works() {
     let accounts = default_accounts();
@@ -285,4 +389,1213 @@ fn set_liquidation_threshold_works() {
     liquidation_threshold = 8000;
     let _ = contract.set_liquidation_threshold(liquidation_threshold);
     assert_eq!(contract.liquidation_threshold(), liquidation_threshold);
+
+    assert_eq!(
+        contract.set_liquidation_threshold(10001).unwrap_err(),
+        Error::InvalidLiquidationThreshold
+    );
+    assert_eq!(contract.liquidation_threshold(), liquidation_threshold);
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.set_liquidation_threshold(5000).unwrap_err(),
+        Error::CallerIsNotManager
+    );
+}
+
+#[ink::test]
+fn emit_lending_events_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let liquidation_threshold = 10000;
+    let contract = PoolContract::new(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        liquidation_threshold,
+        String::from("Token Name"),
+        String::from("symbol"),
+        8,
+    );
+
+    contract._emit_mint_event(accounts.alice, 100, 90);
+    contract._emit_redeem_event(accounts.alice, 50);
+    contract._emit_borrow_event(accounts.alice, 30, 30, 130);
+    contract._emit_repay_borrow_event(accounts.bob, accounts.alice, 10, 20, 120);
+    contract._emit_liquidate_borrow_event(accounts.charlie, accounts.alice, 5, dummy_id, 4);
+    contract._emit_accrue_interest_event(1, WrappedU256::from(exp_scale()), 121);
+
+    let events = get_emitted_events();
+    assert_eq!(events.len(), 6);
+    // event signature + indexed accounts
+    let topics: Vec<usize> = events.iter().map(|e| e.topics.len()).collect();
+    assert_eq!(topics, vec![2, 2, 2, 3, 4, 1]);
+    match decode_event(&events[0]) {
+        Event::Mint(event) => {
+            assert_eq!(event.minter, accounts.alice);
+            assert_eq!(event.mint_amount, 100);
+            assert_eq!(event.mint_tokens, 90);
+        }
+        _ => panic!("unexpected event kind: expected Mint event"),
+    }
+    match decode_event(&events[1]) {
+        Event::Redeem(event) => {
+            assert_eq!(event.redeemer, accounts.alice);
+            assert_eq!(event.redeem_amount, 50);
+        }
+        _ => panic!("unexpected event kind: expected Redeem event"),
+    }
+    match decode_event(&events[2]) {
+        Event::Borrow(event) => {
+            assert_eq!(event.borrower, accounts.alice);
+            assert_eq!(event.borrow_amount, 30);
+            assert_eq!(event.account_borrows, 30);
+            assert_eq!(event.total_borrows, 130);
+        }
+        _ => panic!("unexpected event kind: expected Borrow event"),
+    }
+    match decode_event(&events[3]) {
+        Event::RepayBorrow(event) => {
+            assert_eq!(event.payer, accounts.bob);
+            assert_eq!(event.borrower, accounts.alice);
+            assert_eq!(event.repay_amount, 10);
+            assert_eq!(event.account_borrows, 20);
+            assert_eq!(event.total_borrows, 120);
+        }
+        _ => panic!("unexpected event kind: expected RepayBorrow event"),
+    }
+    match decode_event(&events[4]) {
+        Event::LiquidateBorrow(event) => {
+            assert_eq!(event.liquidator, accounts.charlie);
+            assert_eq!(event.borrower, accounts.alice);
+            assert_eq!(event.repay_amount, 5);
+            assert_eq!(event.token_collateral, dummy_id);
+            assert_eq!(event.seize_tokens, 4);
+        }
+        _ => panic!("unexpected event kind: expected LiquidateBorrow event"),
+    }
+    match decode_event(&events[5]) {
+        Event::AccrueInterest(event) => {
+            assert_eq!(event.interest_accumulated, 1);
+            assert_eq!(event.new_index, WrappedU256::from(exp_scale()));
+            assert_eq!(event.new_total_borrows, 121);
+        }
+        _ => panic!("unexpected event kind: expected AccrueInterest event"),
+    }
+}
+
+#[ink::test]
+fn emit_reserves_events_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let liquidation_threshold = 10000;
+    let contract = PoolContract::new(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        liquidation_threshold,
+        String::from("Token Name"),
+        String::from("symbol"),
+        8,
+    );
+
+    contract._emit_reserves_added_event(accounts.alice, 100, 100);
+    contract._emit_reserves_reduced_event(40, 60);
+
+    let events = get_emitted_events();
+    assert_eq!(events.len(), 2);
+    // event signature + indexed accounts
+    let topics: Vec<usize> = events.iter().map(|e| e.topics.len()).collect();
+    assert_eq!(topics, vec![2, 1]);
+    match decode_event(&events[0]) {
+        Event::ReservesAdded(event) => {
+            assert_eq!(event.benefactor, accounts.alice);
+            assert_eq!(event.add_amount, 100);
+            assert_eq!(event.new_total_reserves, 100);
+        }
+        _ => panic!("unexpected event kind: expected ReservesAdded event"),
+    }
+    match decode_event(&events[1]) {
+        Event::ReservesReduced(event) => {
+            assert_eq!(event.reduce_amount, 40);
+            assert_eq!(event.total_reserves_new, 60);
+        }
+        _ => panic!("unexpected event kind: expected ReservesReduced event"),
+    }
+}
+
+#[ink::test]
+fn emit_admin_events_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let new_id = AccountId::from([0x02; 32]);
+    let liquidation_threshold = 10000;
+    let contract = PoolContract::new(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        liquidation_threshold,
+        String::from("Token Name"),
+        String::from("symbol"),
+        8,
+    );
+
+    contract._emit_reserves_added_event(accounts.bob, 100, 100);
+    contract._emit_reserves_reduced_event(40, 60);
+    contract._emit_new_controller_event(Some(dummy_id), Some(new_id));
+    contract._emit_new_interest_rate_model_event(Some(dummy_id), Some(new_id));
+
+    let events = get_emitted_events();
+    assert_eq!(events.len(), 4);
+    match decode_event(&events[0]) {
+        Event::ReservesAdded(event) => {
+            assert_eq!(event.benefactor, accounts.bob);
+            assert_eq!(event.add_amount, 100);
+            assert_eq!(event.new_total_reserves, 100);
+        }
+        _ => panic!("unexpected event kind: expected ReservesAdded event"),
+    }
+    match decode_event(&events[1]) {
+        Event::ReservesReduced(event) => {
+            assert_eq!(event.reduce_amount, 40);
+            assert_eq!(event.total_reserves_new, 60);
+        }
+        _ => panic!("unexpected event kind: expected ReservesReduced event"),
+    }
+    match decode_event(&events[2]) {
+        Event::NewController(event) => {
+            assert_eq!(event.old, Some(dummy_id));
+            assert_eq!(event.new, Some(new_id));
+        }
+        _ => panic!("unexpected event kind: expected NewController event"),
+    }
+    match decode_event(&events[3]) {
+        Event::NewInterestRateModel(event) => {
+            assert_eq!(event.old, Some(dummy_id));
+            assert_eq!(event.new, Some(new_id));
+        }
+        _ => panic!("unexpected event kind: expected NewInterestRateModel event"),
+    }
+}
+
+#[ink::test]
+fn borrower_registry_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let liquidation_threshold = 10000;
+    let mut contract = PoolContract::new(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        liquidation_threshold,
+        String::from("Token Name"),
+        String::from("symbol"),
+        8,
+    );
+    assert_eq!(contract.borrowers_count(), 0);
+
+    contract._add_borrower(accounts.alice);
+    contract._add_borrower(accounts.bob);
+    contract._add_borrower(accounts.charlie);
+    contract._add_borrower(accounts.bob);
+    assert_eq!(contract.borrowers_count(), 3);
+    assert_eq!(
+        contract.borrowers_paginated(0, 10),
+        vec![accounts.alice, accounts.bob, accounts.charlie]
+    );
+    assert_eq!(contract.borrowers_paginated(1, 1), vec![accounts.bob]);
+    assert!(contract.borrowers_paginated(5, 1).is_empty());
+
+    contract._remove_borrower(accounts.alice);
+    contract._remove_borrower(accounts.django);
+    assert_eq!(contract.borrowers_count(), 2);
+    assert_eq!(
+        contract.borrowers_paginated(0, 10),
+        vec![accounts.charlie, accounts.bob]
+    );
+}
+
+#[ink::test]
+fn bad_debt_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let liquidation_threshold = 10000;
+    let mut contract = PoolContract::new(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        liquidation_threshold,
+        String::from("Token Name"),
+        String::from("symbol"),
+        8,
+    );
+    assert_eq!(contract.bad_debt(), 0);
+    assert_eq!(
+        contract.cover_bad_debt(100).unwrap_err(),
+        Error::BadDebtExceeded
+    );
+    assert_eq!(
+        contract.repay_bad_debt(100).unwrap_err(),
+        Error::BadDebtExceeded
+    );
+
+    contract._emit_bad_debt_absorbed_event(accounts.alice, 100, 100);
+    contract._emit_bad_debt_repaid_event(accounts.bob, 40, 60);
+    let events = get_emitted_events();
+    assert_eq!(events.len(), 2);
+    match decode_event(&events[0]) {
+        Event::BadDebtAbsorbed(event) => {
+            assert_eq!(event.borrower, accounts.alice);
+            assert_eq!(event.amount, 100);
+            assert_eq!(event.bad_debt, 100);
+        }
+        _ => panic!("unexpected event kind: expected BadDebtAbsorbed event"),
+    }
+    match decode_event(&events[1]) {
+        Event::BadDebtRepaid(event) => {
+            assert_eq!(event.payer, accounts.bob);
+            assert_eq!(event.amount, 40);
+            assert_eq!(event.bad_debt, 60);
+        }
+        _ => panic!("unexpected event kind: expected BadDebtRepaid event"),
+    }
+}
+
+#[ink::test]
+fn new_native_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let liquidation_threshold = 10000;
+    let contract = PoolContract::new_native(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        liquidation_threshold,
+        String::from("Token Name"),
+        String::from("symbol"),
+        18,
+    );
+    assert_eq!(contract.underlying_asset(), Some(Underlying::Native));
+    assert_eq!(contract.underlying(), Some(native_asset_id()));
+    assert_eq!(contract.get_cash_prior(), 0);
+}
+
+#[ink::test]
+fn transfer_underlying_fails_above_cash() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let liquidation_threshold = 10000;
+    let mut contract = PoolContract::new(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        liquidation_threshold,
+        String::from("Token Name"),
+        String::from("symbol"),
+        8,
+    );
+    assert_eq!(
+        contract
+            ._transfer_underlying(accounts.alice, 1)
+            .unwrap_err(),
+        Error::InsufficientCash
+    );
+    assert_eq!(contract.get_cash_prior(), 0);
+}
+
+#[ink::test]
+fn transfer_underlying_from_fails_with_value_for_non_native() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let liquidation_threshold = 10000;
+    let mut contract = PoolContract::new(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        liquidation_threshold,
+        String::from("Token Name"),
+        String::from("symbol"),
+        8,
+    );
+    let contract_addr = ink::env::account_id::<DefaultEnvironment>();
+    test::set_value_transferred::<DefaultEnvironment>(100);
+    assert_eq!(
+        contract
+            ._transfer_underlying_from(accounts.bob, contract_addr, 100)
+            .unwrap_err(),
+        Error::InvalidParameter
+    );
+    assert_eq!(contract.get_cash_prior(), 0);
+}
+
+#[ink::test]
+fn new_pallet_asset_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let liquidation_threshold = 10000;
+    let contract = PoolContract::new_pallet_asset(
+        Some(dummy_id),
+        1,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        liquidation_threshold,
+        String::from("Token Name"),
+        String::from("symbol"),
+        10,
+    );
+    assert_eq!(
+        contract.underlying_asset(),
+        Some(Underlying::PalletAsset(1))
+    );
+    assert_eq!(contract.underlying(), Some(pallet_asset_id(1)));
+    assert_ne!(pallet_asset_id(1), pallet_asset_id(2));
+    assert_ne!(pallet_asset_id(1), native_asset_id());
+}
+
+#[ink::test]
+fn new_evm_erc20_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let token = [0x02; 20];
+    let liquidation_threshold = 10000;
+    let contract = PoolContract::new_evm_erc20(
+        Some(dummy_id),
+        token,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        liquidation_threshold,
+        String::from("Token Name"),
+        String::from("symbol"),
+        10,
+    );
+    assert_eq!(
+        contract.underlying_asset(),
+        Some(Underlying::EvmErc20(token))
+    );
+    assert_eq!(contract.underlying(), Some(evm_erc20_id(token)));
+    assert_ne!(evm_erc20_id(token), evm_erc20_id([0x03; 20]));
+    assert_ne!(evm_erc20_id(token), native_asset_id());
+}
+
+#[ink::test]
+fn outflow_limit_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let liquidation_threshold = 10000;
+    let mut contract = PoolContract::new(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        liquidation_threshold,
+        String::from("Token Name"),
+        String::from("symbol"),
+        8,
+    );
+    assert_eq!(contract.outflow_limit(), 0);
+    assert!(contract._consume_outflow(u128::MAX).is_ok());
+
+    assert_eq!(
+        contract.set_outflow_limit(100, 0).unwrap_err(),
+        Error::InvalidParameter
+    );
+    assert!(contract.set_outflow_limit(100, 1000).is_ok());
+    assert_eq!(contract.outflow_limit(), 100);
+    assert_eq!(contract.outflow_window(), 1000);
+
+    assert!(contract._consume_outflow(60).is_ok());
+    assert_eq!(contract.outflow_in_window(), 60);
+    assert_eq!(
+        contract._consume_outflow(41).unwrap_err(),
+        Error::OutflowLimitExceeded
+    );
+    assert!(contract._consume_outflow(40).is_ok());
+
+    test::set_block_timestamp::<DefaultEnvironment>(1000);
+    assert_eq!(contract.outflow_in_window(), 0);
+    assert!(contract._consume_outflow(100).is_ok());
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.set_outflow_limit(0, 0).unwrap_err(),
+        Error::CallerIsNotManager
+    );
+}
+
+#[ink::test]
+fn set_borrow_rate_max_mantissa_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let liquidation_threshold = 10000;
+    let mut contract = PoolContract::new(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        liquidation_threshold,
+        String::from("Token Name"),
+        String::from("symbol"),
+        8,
+    );
+    assert_eq!(
+        contract.borrow_rate_max_mantissa(),
+        WrappedU256::from(utils::borrow_rate_max_mantissa())
+    );
+
+    let new_max = utils::borrow_rate_max_mantissa().div(2);
+    assert!(contract
+        .set_borrow_rate_max_mantissa(WrappedU256::from(new_max))
+        .is_ok());
+    assert_eq!(
+        contract.borrow_rate_max_mantissa(),
+        WrappedU256::from(new_max)
+    );
+
+    let over_max = utils::borrow_rate_absolute_max_mantissa().add(1);
+    assert_eq!(
+        contract
+            .set_borrow_rate_max_mantissa(WrappedU256::from(over_max))
+            .unwrap_err(),
+        Error::SetBorrowRateMaxBoundsCheck
+    );
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract
+            .set_borrow_rate_max_mantissa(WrappedU256::from(new_max))
+            .unwrap_err(),
+        Error::CallerIsNotManager
+    );
+}
+
+#[ink::test]
+fn set_account_supply_cap_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let liquidation_threshold = 10000;
+    let mut contract = PoolContract::new(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        liquidation_threshold,
+        String::from("Token Name"),
+        String::from("symbol"),
+        8,
+    );
+    assert_eq!(contract.account_supply_cap(), 0);
+    assert!(contract.set_account_supply_cap(1_000).is_ok());
+    assert_eq!(contract.account_supply_cap(), 1_000);
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.set_account_supply_cap(0).unwrap_err(),
+        Error::CallerIsNotManager
+    );
+}
+
+#[ink::test]
+fn stable_rate_config_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let liquidation_threshold = 10000;
+    let mut contract = PoolContract::new(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        liquidation_threshold,
+        String::from("Token Name"),
+        String::from("symbol"),
+        8,
+    );
+    assert!(!contract.stable_borrow_enabled());
+    assert_eq!(contract.total_stable_borrows(), 0);
+    assert_eq!(
+        contract.stable_borrow_of(accounts.alice),
+        StableBorrow::default()
+    );
+    assert_eq!(
+        contract
+            .borrow_with_rate_mode(100, RateMode::Stable)
+            .unwrap_err(),
+        Error::StableBorrowNotEnabled
+    );
+    assert_eq!(
+        contract
+            .rebalance_stable_borrow_rate(accounts.alice)
+            .unwrap_err(),
+        Error::RebalanceConditionsNotMet
+    );
+
+    let premium = WrappedU256::from(U256::from(1_000));
+    let threshold = WrappedU256::from(U256::from(5_000));
+    assert!(contract
+        .set_stable_rate_config(true, premium, threshold)
+        .is_ok());
+    assert!(contract.stable_borrow_enabled());
+    assert_eq!(contract.stable_rate_premium_mantissa(), premium);
+    assert_eq!(contract.stable_rebalance_threshold_mantissa(), threshold);
+    assert_eq!(
+        contract
+            .set_stable_rate_config(
+                true,
+                WrappedU256::from(utils::borrow_rate_absolute_max_mantissa().add(1)),
+                threshold
+            )
+            .unwrap_err(),
+        Error::InvalidParameter
+    );
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract
+            .set_stable_rate_config(false, premium, threshold)
+            .unwrap_err(),
+        Error::CallerIsNotManager
+    );
+}
+
+#[ink::test]
+fn swap_borrow_rate_mode_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let liquidation_threshold = 10000;
+    let mut contract = PoolContract::new(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        liquidation_threshold,
+        String::from("Token Name"),
+        String::from("symbol"),
+        8,
+    );
+    assert_eq!(
+        contract
+            .swap_borrow_rate_mode(RateMode::Stable)
+            .unwrap_err(),
+        Error::StableBorrowNotEnabled
+    );
+    assert_eq!(
+        contract
+            .swap_borrow_rate_mode(RateMode::Variable)
+            .unwrap_err(),
+        Error::NoDebtOfRateMode
+    );
+
+    let zero = WrappedU256::from(U256::zero());
+    assert!(contract.set_stable_rate_config(true, zero, zero).is_ok());
+    assert_eq!(
+        contract
+            .swap_borrow_rate_mode(RateMode::Stable)
+            .unwrap_err(),
+        Error::NoDebtOfRateMode
+    );
+}
+
+#[ink::test]
+fn stable_interest_is_credited_to_reserves() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let liquidation_threshold = 10000;
+    let mut contract = PoolContract::new(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        liquidation_threshold,
+        String::from("Token Name"),
+        String::from("symbol"),
+        8,
+    );
+    assert!(contract
+        .set_reserve_factor_mantissa(WrappedU256::from(exp_scale().div(10))) // 10%
+        .is_ok());
+
+    let rate = WrappedU256::from(exp_scale().div(1000)); // 0.1% / ms
+    assert!(contract
+        ._update_stable_totals(1_000_000, rate, false)
+        .is_ok());
+    assert_eq!(contract.total_stable_borrows(), 1_000_000);
+    assert_eq!(contract.total_reserves(), 0);
+
+    // interest of 1_000_000 accrues in 1000 ms
+    contract.data::<Data>().accrual_block_timestamp = 1000;
+    assert_eq!(contract.total_stable_borrows(), 2_000_000);
+    assert!(contract._update_stable_totals(0, rate, false).is_ok());
+    assert_eq!(contract.total_stable_borrows(), 2_000_000);
+    assert_eq!(contract.total_reserves(), 100_000);
+
+    // no interest is credited twice
+    assert!(contract._update_stable_totals(0, rate, false).is_ok());
+    assert_eq!(contract.total_reserves(), 100_000);
+}
+
+#[ink::test]
+fn borrow_snapshot_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let liquidation_threshold = 10000;
+    let mut contract = PoolContract::new(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        liquidation_threshold,
+        String::from("Token Name"),
+        String::from("symbol"),
+        8,
+    );
+
+    assert!(contract._increase_debt(accounts.alice, 100, false).is_ok());
+    assert_eq!(
+        contract.borrow_snapshot(accounts.alice),
+        BorrowSnapshot {
+            principal: 100,
+            interest_index: WrappedU256::from(exp_scale()),
+        }
+    );
+
+    // the borrow index grows by 25%
+    let index = WrappedU256::from(exp_scale() * 5 / 4);
+    contract.data::<Data>().borrow_index = index;
+    assert_eq!(
+        contract.accrued_interest_of(accounts.alice).unwrap(),
+        (25, WrappedU256::from(exp_scale()))
+    );
+
+    // repayments settle accrued interest first
+    assert!(contract._increase_debt(accounts.alice, 25, true).is_ok());
+    assert_eq!(
+        contract.borrow_snapshot(accounts.alice),
+        BorrowSnapshot {
+            principal: 100,
+            interest_index: index,
+        }
+    );
+    assert_eq!(
+        contract.accrued_interest_of(accounts.alice).unwrap(),
+        (0, index)
+    );
+
+    // then principal
+    assert!(contract._increase_debt(accounts.alice, 50, true).is_ok());
+    assert_eq!(contract.borrow_snapshot(accounts.alice).principal, 50);
+
+    // a full repayment clears the snapshot
+    assert!(contract._increase_debt(accounts.alice, 50, true).is_ok());
+    assert_eq!(
+        contract.borrow_snapshot(accounts.alice),
+        BorrowSnapshot::default()
+    );
+}
+
+#[ink::test]
+fn votes_checkpointing_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let liquidation_threshold = 10000;
+    let mut contract = PoolContract::new(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        liquidation_threshold,
+        String::from("Token Name"),
+        String::from("symbol"),
+        8,
+    );
+    test::set_block_timestamp::<DefaultEnvironment>(100);
+    PSP22Internal::_mint_to(&mut contract, accounts.bob, 1_000).unwrap();
+    assert_eq!(contract.get_votes(accounts.bob), 0);
+    assert!(contract.delegate(accounts.bob).is_ok());
+    assert_eq!(contract.delegates(accounts.bob), Some(accounts.bob));
+    assert_eq!(contract.get_votes(accounts.bob), 1_000);
+
+    test::set_block_timestamp::<DefaultEnvironment>(200);
+    PSP22Internal::_transfer_from_to(
+        &mut contract,
+        accounts.bob,
+        accounts.charlie,
+        400,
+        Vec::new(),
+    )
+    .unwrap();
+    PSP22Internal::_burn_from(&mut contract, accounts.bob, 100).unwrap();
+    assert_eq!(contract.get_votes(accounts.bob), 500);
+    assert_eq!(contract.vote_checkpoints_count(accounts.bob), 2);
+    assert_eq!(
+        contract.vote_checkpoint(accounts.bob, 1),
+        Some(Checkpoint {
+            timestamp: 200,
+            value: 500
+        })
+    );
+
+    test::set_block_timestamp::<DefaultEnvironment>(300);
+    assert_eq!(contract.get_past_votes(accounts.bob, 99).unwrap(), 0);
+    assert_eq!(contract.get_past_votes(accounts.bob, 150).unwrap(), 1_000);
+    assert_eq!(contract.get_past_votes(accounts.bob, 200).unwrap(), 500);
+    assert_eq!(contract.get_past_votes(accounts.charlie, 250).unwrap(), 0);
+    assert_eq!(
+        contract.get_past_balance(accounts.charlie, 250).unwrap(),
+        400
+    );
+    assert_eq!(contract.get_past_total_supply(150).unwrap(), 1_000);
+    assert_eq!(contract.get_past_total_supply(250).unwrap(), 900);
+    assert_eq!(
+        contract.get_past_votes(accounts.bob, 300).unwrap_err(),
+        VotesError::FutureLookup
+    );
+}
+
+#[ink::test]
+fn vault_views_work() {
+    use logics::impls::vault::{
+        Error as VaultError,
+        Vault,
+    };
+
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let liquidation_threshold = 10000;
+    let mut contract = PoolContract::new(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(exp_scale().mul(2)),
+        liquidation_threshold,
+        String::from("Token Name"),
+        String::from("symbol"),
+        8,
+    );
+    assert_eq!(contract.asset(), Some(dummy_id));
+    assert_eq!(contract.total_assets(), 0);
+    assert_eq!(contract.convert_to_shares(100), 50);
+    assert_eq!(contract.convert_to_assets(50), 100);
+    assert_eq!(contract.preview_mint(3), 6);
+    assert_eq!(contract.preview_withdraw(3), 2);
+    assert_eq!(contract.max_deposit(accounts.bob), u128::MAX);
+    assert_eq!(contract.max_redeem(accounts.bob), 0);
+
+    assert!(contract.set_account_supply_cap(1_000).is_ok());
+    assert_eq!(contract.max_deposit(accounts.bob), 1_000);
+    assert_eq!(contract.max_mint(accounts.bob), 500);
+
+    assert_eq!(
+        Vault::redeem(&mut contract, 10, accounts.bob, accounts.charlie).unwrap_err(),
+        VaultError::CallerIsNotOwner
+    );
+}
+
+#[ink::test]
+fn set_max_accrual_delta_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let liquidation_threshold = 10000;
+    let mut contract = PoolContract::new(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        liquidation_threshold,
+        String::from("Token Name"),
+        String::from("symbol"),
+        8,
+    );
+    assert_eq!(contract.max_accrual_delta(), utils::accrual_delta_max());
+    assert!(contract.set_max_accrual_delta(0).is_ok());
+    assert_eq!(contract.max_accrual_delta(), 0);
+    assert_eq!(
+        contract
+            .set_max_accrual_delta(utils::accrual_delta_max() + 1)
+            .unwrap_err(),
+        Error::InvalidMaxAccrualDelta
+    );
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.set_max_accrual_delta(1_000).unwrap_err(),
+        Error::CallerIsNotManager
+    );
+}
+
+#[ink::test]
+fn set_compound_interest_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let mut contract = PoolContract::new(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        10000,
+        String::from("Token Name"),
+        String::from("symbol"),
+        8,
+    );
+    assert!(contract.compound_interest());
+    assert!(contract.set_compound_interest(false).is_ok());
+    assert!(!contract.compound_interest());
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.set_compound_interest(true).unwrap_err(),
+        Error::CallerIsNotManager
+    );
+}
+
+#[ink::test]
+fn set_flash_mint_premium_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let mut contract = PoolContract::new(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        10000,
+        String::from("Token Name"),
+        String::from("symbol"),
+        8,
+    );
+    assert_eq!(contract.flash_mint_premium(), 0);
+    assert!(contract.set_flash_mint_premium(9).is_ok());
+    assert_eq!(contract.flash_mint_premium(), 9);
+    assert_eq!(
+        contract.set_flash_mint_premium(10001).unwrap_err(),
+        Error::InvalidFlashMintPremium
+    );
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.set_flash_mint_premium(0).unwrap_err(),
+        Error::CallerIsNotManager
+    );
+}
+
+#[ink::test]
+fn flash_minted_tokens_do_not_dilute_exchange_rate() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let mut contract = PoolContract::new(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        10000,
+        String::from("Token Name"),
+        String::from("symbol"),
+        8,
+    );
+    PSP22Internal::_mint_to(&mut contract, accounts.alice, 100).unwrap();
+    contract.data::<Data>().cash = 200;
+    assert_eq!(contract._exchange_rate_stored(), exp_scale() * 2);
+
+    // tokens of a flash mint in progress are not backed by underlying
+    PSP22Internal::_mint_to(&mut contract, accounts.charlie, 1_000).unwrap();
+    contract.data::<Data>().flash_minted = 1_000;
+    assert_eq!(contract._exchange_rate_supply(), 100);
+    assert_eq!(contract._exchange_rate_stored(), exp_scale() * 2);
+}
+
+#[ink::test]
+fn set_origination_fee_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let mut contract = PoolContract::new(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        10000,
+        String::from("Token Name"),
+        String::from("symbol"),
+        8,
+    );
+    assert_eq!(contract.origination_fee(), 0);
+    assert!(contract.set_origination_fee(10).is_ok());
+    assert_eq!(contract.origination_fee(), 10);
+    assert_eq!(
+        contract.set_origination_fee(1001).unwrap_err(),
+        Error::InvalidOriginationFee
+    );
+
+    contract._emit_origination_fee_charged_event(accounts.alice, 1);
+    let events = get_emitted_events();
+    match decode_event(events.last().unwrap()) {
+        Event::OriginationFeeCharged(event) => {
+            assert_eq!(event.borrower, accounts.alice);
+            assert_eq!(event.fee, 1);
+        }
+        _ => panic!("unexpected event kind: expected OriginationFeeCharged event"),
+    }
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.set_origination_fee(0).unwrap_err(),
+        Error::CallerIsNotManager
+    );
+}
+
+#[ink::test]
+fn reserves_sweep_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let treasury = AccountId::from([0x02; 32]);
+    let mut contract = PoolContract::new(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        10000,
+        String::from("Token Name"),
+        String::from("symbol"),
+        8,
+    );
+    assert_eq!(contract.treasury(), None);
+    assert_eq!(contract.reserves_sweep_threshold(), 0);
+    assert_eq!(
+        contract.sweep_reserves_to_treasury().unwrap_err(),
+        Error::TreasuryIsNotSet
+    );
+
+    assert!(contract
+        .set_reserves_sweep_config(Some(treasury), 100)
+        .is_ok());
+    assert_eq!(contract.treasury(), Some(treasury));
+    assert_eq!(contract.reserves_sweep_threshold(), 100);
+    // callable by anyone
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.sweep_reserves_to_treasury().unwrap_err(),
+        Error::ReservesBelowSweepThreshold
+    );
+    assert_eq!(
+        contract.set_reserves_sweep_config(None, 0).unwrap_err(),
+        Error::CallerIsNotManager
+    );
+}
+
+#[ink::test]
+fn set_manager_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let mut contract = PoolContract::new(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        10000,
+        String::from("Token Name"),
+        String::from("symbol"),
+        8,
+    );
+    assert!(contract.set_manager(accounts.charlie).is_ok());
+    assert_eq!(contract.manager(), Some(accounts.bob));
+    assert_eq!(contract.pending_manager(), Some(accounts.charlie));
+    assert_eq!(
+        contract.accept_manager().unwrap_err(),
+        Error::CallerIsNotPendingManager
+    );
+
+    set_caller(accounts.charlie);
+    assert!(contract.accept_manager().is_ok());
+    assert_eq!(contract.manager(), Some(accounts.charlie));
+    assert_eq!(contract.pending_manager(), None);
+
+    set_caller(accounts.bob);
+    assert_eq!(
+        contract.set_manager(accounts.bob).unwrap_err(),
+        Error::CallerIsNotManager
+    );
+}
+
+#[ink::test]
+fn set_keeper_incentive_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let mut contract = PoolContract::new(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        10000,
+        String::from("Token Name"),
+        String::from("symbol"),
+        8,
+    );
+    assert_eq!(contract.keeper_incentive(), KeeperIncentive::default());
+    let incentive = KeeperIncentive {
+        accrual_bounty: 100,
+        stale_after: 60 * 60 * 1000,
+        liquidation_bounty: 200,
+        min_liquidation_profit: 1000,
+    };
+    assert!(contract.set_keeper_incentive(incentive).is_ok());
+    assert_eq!(contract.keeper_incentive(), incentive);
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract
+            .set_keeper_incentive(KeeperIncentive::default())
+            .unwrap_err(),
+        Error::CallerIsNotManager
+    );
+}
+
+#[ink::test]
+fn set_underlying_call_config_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let liquidation_threshold = 10000;
+    let mut contract = PoolContract::new(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        liquidation_threshold,
+        String::from("Token Name"),
+        String::from("symbol"),
+        8,
+    );
+    assert_eq!(
+        contract.underlying_call_config(),
+        UnderlyingCallConfig {
+            allow_reentry: false,
+            gas_limit: 0,
+        }
+    );
+    let config = UnderlyingCallConfig {
+        allow_reentry: true,
+        gas_limit: 5_000_000_000,
+    };
+    assert!(contract.set_underlying_call_config(config).is_ok());
+    assert_eq!(contract.underlying_call_config(), config);
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract
+            .set_underlying_call_config(UnderlyingCallConfig::default())
+            .unwrap_err(),
+        Error::CallerIsNotManager
+    );
+}
+
+#[ink::test]
+fn upgrade_code_fails_by_non_manager() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let dummy_id = AccountId::from([0x01; 32]);
+    let mut contract = PoolContract::new(
+        Some(dummy_id),
+        dummy_id,
+        dummy_id,
+        dummy_id,
+        WrappedU256::from(U256::from(0)),
+        10000,
+        String::from("Token Name"),
+        String::from("symbol"),
+        8,
+    );
+    assert_eq!(contract.version(), STORAGE_VERSION);
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.upgrade_code(Hash::from([0x02; 32])).unwrap_err(),
+        upgradeable::Error::CallerIsNotAdmin
+    );
 }
@@ -0,0 +1,158 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![feature(min_specialization)]
+
+/// Definition of Pool Factory Contract
+#[openbrush::contract]
+pub mod contract {
+    use default_interest_rate_model::contract::DefaultInterestRateModelContractRef;
+    use ink::{
+        codegen::{
+            EmitEvent,
+            Env,
+        },
+        ToAccountId,
+    };
+    use logics::impls::pool_factory::{
+        Data,
+        Internal,
+        *,
+    };
+    use openbrush::traits::Storage;
+    use pool::contract::PoolContractRef;
+
+    /// Contract's Storage
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct PoolFactoryContract {
+        #[storage_field]
+        factory: Data,
+    }
+
+    /// Event: Pool is deployed and listed
+    #[ink(event)]
+    pub struct PoolCreated {
+        #[ink(topic)]
+        underlying: AccountId,
+        #[ink(topic)]
+        pool: AccountId,
+    }
+
+    /// Event: Code new pools are deployed from is changed
+    #[ink(event)]
+    pub struct NewPoolCodeHash {
+        old: Option<Hash>,
+        new: Hash,
+    }
+
+    /// Event: Rate model is deployed
+    #[ink(event)]
+    pub struct RateModelCreated {
+        #[ink(topic)]
+        code_hash: Hash,
+        #[ink(topic)]
+        rate_model: AccountId,
+    }
+
+    /// Event: Code rate models can be deployed from is approved or revoked
+    #[ink(event)]
+    pub struct RateModelCodeHashApprovalUpdated {
+        #[ink(topic)]
+        code_hash: Hash,
+        approved: bool,
+    }
+
+    impl PoolFactory for PoolFactoryContract {}
+
+    impl Internal for PoolFactoryContract {
+        fn _instantiate_pool(
+            &mut self,
+            underlying: AccountId,
+            parameters: &PoolParameters,
+        ) -> Result<AccountId> {
+            let controller = self._controller().ok_or(Error::ControllerIsNotSet)?;
+            let code_hash = self._pool_code_hash().ok_or(Error::PoolCodeHashIsNotSet)?;
+            // salted with the underlying, the address of its pool is deterministic
+            let pool: PoolContractRef = PoolContractRef::new_from_asset(
+                parameters.incentives_controller,
+                underlying,
+                controller,
+                parameters.rate_model,
+                parameters.initial_exchange_rate_mantissa,
+                parameters.liquidation_threshold,
+            )
+            .code_hash(code_hash)
+            .endowment(0)
+            .salt_bytes(underlying)
+            .try_instantiate()
+            .map_err(|_| Error::InstantiatePoolFailed)?
+            .map_err(|_| Error::InstantiatePoolFailed)?;
+            Ok(pool.to_account_id())
+        }
+
+        fn _instantiate_rate_model(
+            &mut self,
+            code_hash: Hash,
+            parameters: &RateModelParameters,
+            salt: u32,
+        ) -> Result<AccountId> {
+            // approved codes share the constructor of the default model
+            let rate_model: DefaultInterestRateModelContractRef =
+                DefaultInterestRateModelContractRef::new(
+                    parameters.base_rate_per_year,
+                    parameters.multiplier_per_year,
+                    parameters.jump_multiplier_per_year,
+                    parameters.kink,
+                )
+                .code_hash(code_hash)
+                .endowment(0)
+                .salt_bytes(salt.to_le_bytes())
+                .try_instantiate()
+                .map_err(|_| Error::InstantiateRateModelFailed)?
+                .map_err(|_| Error::InstantiateRateModelFailed)?;
+            Ok(rate_model.to_account_id())
+        }
+
+        fn _emit_pool_created_event(&self, underlying: AccountId, pool: AccountId) {
+            self.env().emit_event(PoolCreated { underlying, pool })
+        }
+
+        fn _emit_new_pool_code_hash_event(&self, old: Option<Hash>, new: Hash) {
+            self.env().emit_event(NewPoolCodeHash { old, new })
+        }
+
+        fn _emit_rate_model_created_event(&self, code_hash: Hash, rate_model: AccountId) {
+            self.env().emit_event(RateModelCreated {
+                code_hash,
+                rate_model,
+            })
+        }
+
+        fn _emit_rate_model_code_hash_approval_updated_event(
+            &self,
+            code_hash: Hash,
+            approved: bool,
+        ) {
+            self.env().emit_event(RateModelCodeHashApprovalUpdated {
+                code_hash,
+                approved,
+            })
+        }
+    }
+
+    impl PoolFactoryContract {
+        /// Generate this contract
+        #[ink(constructor)]
+        pub fn new(controller: AccountId, pool_code_hash: Hash) -> Self {
+            let mut instance = Self::default();
+            instance._initialize(controller, pool_code_hash);
+            instance
+        }
+    }
+}
@@ -14,8 +14,26 @@ mod tests;
 /// Definition of PriceOracle Contract
 #[openbrush::contract]
 pub mod contract {
-    use logics::impls::price_oracle::*;
-    use openbrush::traits::Storage;
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use logics::impls::{
+        ownable_2step::{
+            self,
+            Internal as Ownable2StepInternal,
+            Ownable2Step,
+        },
+        price_oracle::{
+            Internal,
+            *,
+        },
+    };
+    use openbrush::{
+        contracts::ownable::*,
+        modifiers,
+        traits::Storage,
+    };
 
     /// Contract's Storage
     #[ink(storage)]
@@ -23,10 +41,138 @@ pub mod contract {
     pub struct PriceOracleContract {
         #[storage_field]
         price_oracle: Data,
+        #[storage_field]
+        ownable: ownable::Data,
+        #[storage_field]
+        ownable_2step: ownable_2step::Data,
+    }
+
+    /// Event: A reporter posts a price
+    #[ink(event)]
+    pub struct PricePosted {
+        #[ink(topic)]
+        asset: AccountId,
+        price: u128,
+        reporter: AccountId,
+    }
+
+    /// Event: A reporter is authorized or revoked
+    #[ink(event)]
+    pub struct ReporterUpdated {
+        #[ink(topic)]
+        reporter: AccountId,
+        authorized: bool,
+    }
+
+    /// Event: A price deviating too much trips the breaker of an asset
+    #[ink(event)]
+    pub struct BreakerTripped {
+        #[ink(topic)]
+        asset: AccountId,
+        last_price: u128,
+        price: u128,
+        borrow_paused: bool,
+    }
+
+    /// Event: Ownership is proposed to a new owner
+    #[ink(event)]
+    pub struct OwnershipTransferStarted {
+        #[ink(topic)]
+        pub previous_owner: AccountId,
+        #[ink(topic)]
+        pub new_owner: AccountId,
+    }
+
+    /// Event: Ownership is transferred
+    #[ink(event)]
+    pub struct OwnershipTransferred {
+        #[ink(topic)]
+        pub previous_owner: Option<AccountId>,
+        #[ink(topic)]
+        pub new_owner: Option<AccountId>,
     }
 
     impl PriceOracle for PriceOracleContract {}
 
+    impl Ownable for PriceOracleContract {
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn transfer_ownership(
+            &mut self,
+            new_owner: AccountId,
+        ) -> core::result::Result<(), OwnableError> {
+            self._propose_owner(new_owner)
+        }
+
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn renounce_ownership(&mut self) -> core::result::Result<(), OwnableError> {
+            self._renounce_ownership()
+        }
+    }
+
+    impl Ownable2Step for PriceOracleContract {}
+
+    impl ownable::Internal for PriceOracleContract {
+        fn _emit_ownership_transferred_event(
+            &self,
+            previous_owner: Option<AccountId>,
+            new_owner: Option<AccountId>,
+        ) {
+            self.env().emit_event(OwnershipTransferred {
+                previous_owner,
+                new_owner,
+            })
+        }
+    }
+
+    impl ownable_2step::Internal for PriceOracleContract {
+        fn _emit_ownership_transfer_started_event(
+            &self,
+            previous_owner: AccountId,
+            new_owner: AccountId,
+        ) {
+            self.env().emit_event(OwnershipTransferStarted {
+                previous_owner,
+                new_owner,
+            })
+        }
+    }
+
+    impl ManualPriceOracle for PriceOracleContract {}
+
+    impl Internal for PriceOracleContract {
+        fn _emit_price_posted_event(&self, asset: AccountId, price: u128, reporter: AccountId) {
+            self.env().emit_event(PricePosted {
+                asset,
+                price,
+                reporter,
+            })
+        }
+
+        fn _emit_reporter_updated_event(&self, reporter: AccountId, authorized: bool) {
+            self.env().emit_event(ReporterUpdated {
+                reporter,
+                authorized,
+            })
+        }
+
+        fn _emit_breaker_tripped_event(
+            &self,
+            asset: AccountId,
+            last_price: u128,
+            price: u128,
+            borrow_paused: bool,
+        ) {
+            self.env().emit_event(BreakerTripped {
+                asset,
+                last_price,
+                price,
+                borrow_paused,
+            })
+        }
+    }
+
     impl Default for PriceOracleContract {
         fn default() -> Self {
             Self::new()
@@ -35,13 +181,21 @@ pub mod contract {
 
     impl PriceOracleContract {
         /// Generate this contract
+        ///
+        /// The deployer becomes the owner and the first reporter
         #[ink(constructor)]
         pub fn new() -> Self {
-            Self {
-                price_oracle: Data {
-                    fixed_prices: Default::default(),
-                },
-            }
+            let caller = Self::env().caller();
+            let mut instance = Self {
+                price_oracle: Default::default(),
+                ownable: Default::default(),
+                ownable_2step: Default::default(),
+            };
+            instance._init_with_owner(caller);
+            instance
+                ._set_reporter(caller, true)
+                .expect("failed to register the deployer as reporter");
+            instance
         }
     }
 }
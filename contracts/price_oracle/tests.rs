@@ -6,8 +6,24 @@ use ink::env::{
     },
     DefaultEnvironment,
 };
-use logics::impls::price_oracle::*;
-use openbrush::traits::AccountId;
+use logics::{
+    impls::{
+        ownable_2step::{
+            self,
+            Ownable2Step,
+        },
+        price_oracle::*,
+    },
+    traits::types::WrappedU256,
+};
+use openbrush::{
+    contracts::ownable::{
+        Ownable,
+        OwnableError,
+    },
+    traits::AccountId,
+};
+use primitive_types::U256;
 
 fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
     test::default_accounts::<DefaultEnvironment>()
@@ -15,13 +31,19 @@ fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
 fn set_caller(id: AccountId) {
     test::set_caller::<DefaultEnvironment>(id);
 }
+fn set_block_timestamp(timestamp: u64) {
+    test::set_block_timestamp::<DefaultEnvironment>(timestamp);
+}
 
 #[ink::test]
 fn new_works() {
     let accounts = default_accounts();
     set_caller(accounts.bob);
 
-    let _contract = PriceOracleContract::new();
+    let contract = PriceOracleContract::new();
+    assert_eq!(contract.owner(), accounts.bob);
+    assert!(contract.is_reporter(accounts.bob));
+    assert!(!contract.is_reporter(accounts.charlie));
 }
 
 #[ink::test]
@@ -40,3 +62,273 @@ fn set_fixed_price_works() {
         Some(PRICE_PRECISION * 101 / 100)
     )
 }
+
+#[ink::test]
+fn set_fixed_price_records_timestamp() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    set_block_timestamp(100);
+
+    let mut contract = PriceOracleContract::new();
+    let asset_addr = AccountId::from([0x01; 32]);
+    assert_eq!(contract.get_price_data(asset_addr), None);
+
+    assert!(contract
+        .set_fixed_price(asset_addr, PRICE_PRECISION)
+        .is_ok());
+    assert_eq!(
+        contract.get_price_data(asset_addr),
+        Some(PriceData {
+            price: PRICE_PRECISION,
+            updated_at: 100,
+        })
+    );
+}
+
+#[ink::test]
+fn only_reporters_post_prices() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = PriceOracleContract::new();
+    let asset_addr = AccountId::from([0x01; 32]);
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.set_fixed_price(asset_addr, PRICE_PRECISION),
+        Err(Error::CallerIsNotReporter)
+    );
+    assert_eq!(
+        contract.set_reporter(accounts.charlie, true),
+        Err(Error::Ownable(OwnableError::CallerIsNotOwner))
+    );
+
+    set_caller(accounts.bob);
+    assert!(contract.set_reporter(accounts.charlie, true).is_ok());
+    set_caller(accounts.charlie);
+    assert!(contract
+        .set_fixed_price(asset_addr, PRICE_PRECISION)
+        .is_ok());
+
+    set_caller(accounts.bob);
+    assert!(contract.set_reporter(accounts.charlie, false).is_ok());
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.set_fixed_price(asset_addr, PRICE_PRECISION),
+        Err(Error::CallerIsNotReporter)
+    );
+}
+
+#[ink::test]
+fn stale_prices_are_not_used() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    set_block_timestamp(100);
+
+    let mut contract = PriceOracleContract::new();
+    let asset_addr = AccountId::from([0x01; 32]);
+    assert!(contract
+        .set_fixed_price(asset_addr, PRICE_PRECISION)
+        .is_ok());
+    assert!(contract.set_heartbeat(asset_addr, 1_000).is_ok());
+    assert_eq!(contract.heartbeat(asset_addr), 1_000);
+
+    set_block_timestamp(1_100);
+    assert_eq!(contract.get_price(asset_addr), Some(PRICE_PRECISION));
+    set_block_timestamp(1_101);
+    assert_eq!(contract.get_price(asset_addr), None);
+    assert_eq!(
+        contract.get_price_data(asset_addr),
+        Some(PriceData {
+            price: PRICE_PRECISION,
+            updated_at: 100,
+        })
+    );
+
+    // a new post makes the price fresh again
+    assert!(contract
+        .set_fixed_price(asset_addr, PRICE_PRECISION)
+        .is_ok());
+    assert_eq!(contract.get_price(asset_addr), Some(PRICE_PRECISION));
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.set_heartbeat(asset_addr, 0),
+        Err(Error::Ownable(OwnableError::CallerIsNotOwner))
+    );
+}
+
+#[ink::test]
+fn price_bounds_work() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = PriceOracleContract::new();
+    let asset_addr = AccountId::from([0x01; 32]);
+    assert_eq!(contract.price_bounds(asset_addr), PriceBounds::default());
+    assert_eq!(
+        contract.set_price_bounds(asset_addr, PriceBounds { min: 2, max: 1 }),
+        Err(Error::InvalidPriceBounds)
+    );
+
+    let bounds = PriceBounds {
+        min: PRICE_PRECISION / 2,
+        max: PRICE_PRECISION * 2,
+    };
+    assert!(contract.set_price_bounds(asset_addr, bounds).is_ok());
+    assert_eq!(contract.price_bounds(asset_addr), bounds);
+    assert_eq!(
+        contract.set_fixed_price(asset_addr, PRICE_PRECISION * 3),
+        Err(Error::PriceOutOfBounds)
+    );
+    assert_eq!(
+        contract.set_fixed_price(asset_addr, PRICE_PRECISION / 3),
+        Err(Error::PriceOutOfBounds)
+    );
+    assert!(contract
+        .set_fixed_price(asset_addr, PRICE_PRECISION)
+        .is_ok());
+    assert_eq!(contract.get_price(asset_addr), Some(PRICE_PRECISION));
+
+    // tightened bounds reject prices posted before
+    assert!(contract
+        .set_price_bounds(
+            asset_addr,
+            PriceBounds {
+                min: PRICE_PRECISION * 2,
+                max: 0,
+            }
+        )
+        .is_ok());
+    assert_eq!(contract.get_price(asset_addr), None);
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.set_price_bounds(asset_addr, bounds),
+        Err(Error::Ownable(OwnableError::CallerIsNotOwner))
+    );
+}
+
+#[ink::test]
+fn prices_are_normalized_to_price_precision() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = PriceOracleContract::new();
+    let asset_addr = AccountId::from([0x01; 32]);
+    assert_eq!(contract.price_decimals(asset_addr), 18);
+
+    // a feed with 8 decimals
+    assert!(contract.set_price_decimals(asset_addr, 8).is_ok());
+    assert!(contract.set_fixed_price(asset_addr, 150_000_000).is_ok());
+    assert_eq!(
+        contract.get_price(asset_addr),
+        Some(PRICE_PRECISION * 3 / 2)
+    );
+    assert_eq!(
+        contract.set_fixed_price(asset_addr, u128::MAX),
+        Err(Error::PriceOverflow)
+    );
+    assert_eq!(
+        contract.set_price_decimals(asset_addr, 37),
+        Err(Error::InvalidPriceDecimals)
+    );
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.set_price_decimals(asset_addr, 6),
+        Err(Error::Ownable(OwnableError::CallerIsNotOwner))
+    );
+}
+
+#[ink::test]
+fn deviation_breaker_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    set_block_timestamp(100);
+
+    let mut contract = PriceOracleContract::new();
+    let asset_addr = AccountId::from([0x01; 32]);
+    // without pool, tripping does not call the manager
+    let breaker = DeviationBreaker {
+        max_deviation_mantissa: WrappedU256::from(U256::from(PRICE_PRECISION / 10)),
+        window: 1_000,
+        pool: None,
+    };
+    assert!(contract.set_deviation_breaker(asset_addr, breaker).is_ok());
+    assert_eq!(contract.deviation_breaker(asset_addr), breaker);
+    assert!(contract
+        .set_fixed_price(asset_addr, PRICE_PRECISION)
+        .is_ok());
+
+    // within 10%
+    assert!(contract
+        .set_fixed_price(asset_addr, PRICE_PRECISION * 11 / 10)
+        .is_ok());
+    assert_eq!(
+        contract.get_price(asset_addr),
+        Some(PRICE_PRECISION * 11 / 10)
+    );
+
+    // over 10% within the window trips the breaker
+    assert!(contract
+        .set_fixed_price(asset_addr, PRICE_PRECISION * 2)
+        .is_ok());
+    assert!(contract.is_tripped(asset_addr));
+    assert_eq!(contract.get_price(asset_addr), None);
+    assert_eq!(
+        contract.get_price_data(asset_addr).unwrap().price,
+        PRICE_PRECISION * 11 / 10
+    );
+
+    assert!(contract.reset_breaker(asset_addr).is_ok());
+    assert!(!contract.is_tripped(asset_addr));
+    assert_eq!(
+        contract.get_price(asset_addr),
+        Some(PRICE_PRECISION * 11 / 10)
+    );
+
+    // large moves after the window are accepted
+    set_block_timestamp(1_101);
+    assert!(contract
+        .set_fixed_price(asset_addr, PRICE_PRECISION * 2)
+        .is_ok());
+    assert_eq!(contract.get_price(asset_addr), Some(PRICE_PRECISION * 2));
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.reset_breaker(asset_addr),
+        Err(Error::Ownable(OwnableError::CallerIsNotOwner))
+    );
+    assert_eq!(
+        contract.set_manager(accounts.charlie),
+        Err(Error::Ownable(OwnableError::CallerIsNotOwner))
+    );
+}
+
+#[ink::test]
+fn transfer_ownership_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = PriceOracleContract::new();
+    assert!(contract.transfer_ownership(accounts.charlie).is_ok());
+    assert_eq!(contract.owner(), accounts.bob);
+    assert_eq!(contract.pending_owner(), Some(accounts.charlie));
+    assert_eq!(
+        contract.accept_ownership().unwrap_err(),
+        ownable_2step::Error::CallerIsNotPendingOwner
+    );
+
+    set_caller(accounts.charlie);
+    assert!(contract.accept_ownership().is_ok());
+    assert_eq!(contract.owner(), accounts.charlie);
+    assert_eq!(contract.pending_owner(), None);
+
+    assert!(contract.renounce_ownership().is_ok());
+    assert_eq!(contract.owner(), AccountId::from([0x00; 32]));
+    assert_eq!(
+        contract.transfer_ownership(accounts.bob).unwrap_err(),
+        OwnableError::CallerIsNotOwner
+    );
+}
@@ -0,0 +1,175 @@
+use crate::contract::*;
+use ink::{
+    env::{
+        test::{
+            self,
+            recorded_events,
+            DefaultAccounts,
+            EmittedEvent,
+        },
+        DefaultEnvironment,
+    },
+    prelude::vec::Vec,
+};
+use logics::impls::registry::*;
+use openbrush::{
+    contracts::ownable::{
+        Ownable,
+        OwnableError,
+    },
+    traits::AccountId,
+};
+use scale::Decode;
+
+type Event = <RegistryContract as ink::reflect::ContractEventBase>::Type;
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    test::default_accounts::<DefaultEnvironment>()
+}
+fn set_caller(id: AccountId) {
+    test::set_caller::<DefaultEnvironment>(id);
+}
+fn get_emitted_events() -> Vec<EmittedEvent> {
+    recorded_events().collect::<Vec<_>>()
+}
+fn decode_event(event: &EmittedEvent) -> Event {
+    <Event as Decode>::decode(&mut &event.data[..])
+        .expect("encountered invalid contract event data buffer")
+}
+
+fn addresses(seed: u8) -> AssetAddresses {
+    AssetAddresses {
+        pool: Some(AccountId::from([seed; 32])),
+        gateway: Some(AccountId::from([seed + 1; 32])),
+        debt_token: None,
+        oracle_adapter: Some(AccountId::from([seed + 2; 32])),
+    }
+}
+
+#[ink::test]
+fn new_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let contract = RegistryContract::new(None);
+    assert_eq!(contract.owner(), accounts.bob);
+    assert_eq!(contract.controller(), None);
+    assert!(contract.assets().is_empty());
+    assert_eq!(
+        contract.addresses_of(AccountId::from([0x01; 32])),
+        AssetAddresses::default()
+    );
+}
+
+#[ink::test]
+fn set_addresses_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = RegistryContract::new(None);
+    let asset = AccountId::from([0x01; 32]);
+    let entry = addresses(0x10);
+    assert!(contract.set_addresses(asset, entry).is_ok());
+    assert_eq!(contract.addresses_of(asset), entry);
+    assert_eq!(contract.pool_of(asset), entry.pool);
+    assert_eq!(contract.gateway_of(asset), entry.gateway);
+    assert_eq!(contract.debt_token_of(asset), None);
+    assert_eq!(contract.oracle_adapter_of(asset), entry.oracle_adapter);
+    assert_eq!(contract.assets(), [asset]);
+
+    // replacing an entry does not register the asset twice
+    let replaced = addresses(0x20);
+    assert!(contract.set_addresses(asset, replaced).is_ok());
+    assert_eq!(contract.addresses_of(asset), replaced);
+    assert_eq!(contract.assets(), [asset]);
+
+    let events = get_emitted_events();
+    match decode_event(events.last().unwrap()) {
+        Event::AddressesUpdated(event) => {
+            assert_eq!(event.asset, asset);
+            assert_eq!(event.addresses, replaced);
+        }
+        _ => panic!("unexpected event kind: expected AddressesUpdated event"),
+    }
+}
+
+#[ink::test]
+fn remove_addresses_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = RegistryContract::new(None);
+    let asset = AccountId::from([0x01; 32]);
+    let other = AccountId::from([0x02; 32]);
+    assert_eq!(
+        contract.remove_addresses(asset).unwrap_err(),
+        Error::AssetIsNotRegistered
+    );
+
+    assert!(contract.set_addresses(asset, addresses(0x10)).is_ok());
+    assert!(contract.set_addresses(other, addresses(0x20)).is_ok());
+    assert!(contract.remove_addresses(asset).is_ok());
+    assert_eq!(contract.addresses_of(asset), AssetAddresses::default());
+    assert_eq!(contract.assets(), [other]);
+
+    let events = get_emitted_events();
+    match decode_event(events.last().unwrap()) {
+        Event::AddressesRemoved(event) => assert_eq!(event.asset, asset),
+        _ => panic!("unexpected event kind: expected AddressesRemoved event"),
+    }
+}
+
+#[ink::test]
+fn set_controller_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = RegistryContract::new(None);
+    let controller = AccountId::from([0x01; 32]);
+    assert!(contract.set_controller(Some(controller)).is_ok());
+    assert_eq!(contract.controller(), Some(controller));
+
+    let events = get_emitted_events();
+    match decode_event(events.last().unwrap()) {
+        Event::NewController(event) => {
+            assert_eq!(event.old, None);
+            assert_eq!(event.new, Some(controller));
+        }
+        _ => panic!("unexpected event kind: expected NewController event"),
+    }
+}
+
+#[ink::test]
+fn registered_pool_is_not_resolved_from_controller() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    // resolving from this controller would fail as it is not a contract
+    let mut contract = RegistryContract::new(Some(AccountId::from([0x01; 32])));
+    let asset = AccountId::from([0x02; 32]);
+    let entry = addresses(0x10);
+    assert!(contract.set_addresses(asset, entry).is_ok());
+    assert_eq!(contract.pool_of(asset), entry.pool);
+}
+
+#[ink::test]
+fn admin_functions_fail_by_non_owner() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = RegistryContract::new(None);
+    let asset = AccountId::from([0x01; 32]);
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract.set_addresses(asset, addresses(0x10)).unwrap_err(),
+        Error::Ownable(OwnableError::CallerIsNotOwner)
+    );
+    assert_eq!(
+        contract.remove_addresses(asset).unwrap_err(),
+        Error::Ownable(OwnableError::CallerIsNotOwner)
+    );
+    assert_eq!(
+        contract.set_controller(Some(asset)).unwrap_err(),
+        Error::Ownable(OwnableError::CallerIsNotOwner)
+    );
+}
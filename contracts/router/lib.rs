@@ -0,0 +1,40 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![feature(min_specialization)]
+
+#[cfg(test)]
+mod tests;
+
+/// Definition of Router Contract
+#[openbrush::contract]
+pub mod contract {
+    use logics::impls::router::*;
+    use openbrush::traits::Storage;
+
+    /// Contract's Storage
+    #[ink(storage)]
+    #[derive(Storage, Default)]
+    pub struct RouterContract {
+        #[storage_field]
+        router: Data,
+    }
+
+    impl Router for RouterContract {}
+    impl Internal for RouterContract {}
+
+    impl RouterContract {
+        /// Generate this contract
+        #[ink(constructor)]
+        pub fn new(controller: AccountId) -> Self {
+            let mut instance: RouterContract = Default::default();
+            instance._initialize(controller);
+            instance
+        }
+    }
+}
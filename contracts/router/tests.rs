@@ -0,0 +1,39 @@
+use crate::contract::*;
+use ink::env::{
+    test::{
+        self,
+        DefaultAccounts,
+    },
+    DefaultEnvironment,
+};
+use logics::impls::router::*;
+use openbrush::traits::AccountId;
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    test::default_accounts::<DefaultEnvironment>()
+}
+fn set_caller(id: AccountId) {
+    test::set_caller::<DefaultEnvironment>(id);
+}
+
+#[ink::test]
+fn new_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let controller = AccountId::from([0x01; 32]);
+    let contract = RouterContract::new(controller);
+    assert_eq!(contract.controller(), Some(controller));
+}
+
+#[ink::test]
+fn deposit_multiple_fails_without_deposits() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut contract = RouterContract::new(AccountId::from([0x01; 32]));
+    assert_eq!(
+        contract.deposit_multiple(Vec::new()).unwrap_err(),
+        Error::EmptyDeposits
+    );
+}
@@ -0,0 +1,113 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![feature(min_specialization)]
+
+#[cfg(test)]
+mod tests;
+
+/// Definition of Timelock Contract
+#[openbrush::contract]
+pub mod contract {
+    use ink::{
+        codegen::{
+            EmitEvent,
+            Env,
+        },
+        prelude::vec::Vec,
+    };
+    use logics::impls::timelock::{
+        Data,
+        Internal,
+        *,
+    };
+    use openbrush::{
+        contracts::ownable::*,
+        traits::Storage,
+    };
+
+    /// Contract's Storage
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct TimelockContract {
+        #[storage_field]
+        timelock: Data,
+        #[storage_field]
+        ownable: ownable::Data,
+    }
+
+    /// Event: Transaction is queued
+    #[ink(event)]
+    pub struct QueueTransaction {
+        #[ink(topic)]
+        pub id: TransactionId,
+        #[ink(topic)]
+        pub target: AccountId,
+        pub selector: [u8; 4],
+        pub input: Vec<u8>,
+        pub eta: Timestamp,
+    }
+
+    /// Event: Queued transaction is cancelled
+    #[ink(event)]
+    pub struct CancelTransaction {
+        #[ink(topic)]
+        pub id: TransactionId,
+    }
+
+    /// Event: Queued transaction is executed
+    #[ink(event)]
+    pub struct ExecuteTransaction {
+        #[ink(topic)]
+        pub id: TransactionId,
+    }
+
+    /// Event: Delay is changed
+    #[ink(event)]
+    pub struct NewDelay {
+        pub delay: Timestamp,
+    }
+
+    impl Internal for TimelockContract {
+        fn _emit_queue_transaction_event(&self, id: TransactionId, transaction: Transaction) {
+            self.env().emit_event(QueueTransaction {
+                id,
+                target: transaction.target,
+                selector: transaction.selector,
+                input: transaction.input,
+                eta: transaction.eta,
+            })
+        }
+        fn _emit_cancel_transaction_event(&self, id: TransactionId) {
+            self.env().emit_event(CancelTransaction { id })
+        }
+        fn _emit_execute_transaction_event(&self, id: TransactionId) {
+            self.env().emit_event(ExecuteTransaction { id })
+        }
+        fn _emit_new_delay_event(&self, delay: Timestamp) {
+            self.env().emit_event(NewDelay { delay })
+        }
+    }
+
+    impl Timelock for TimelockContract {}
+
+    impl Ownable for TimelockContract {}
+
+    impl TimelockContract {
+        /// Generate this contract
+        #[ink(constructor)]
+        pub fn new(delay: Timestamp) -> Self {
+            let mut instance = Self::default();
+            instance._init_with_owner(Self::env().caller());
+            if instance._initialize(delay).is_err() {
+                panic!("delay is out of bounds");
+            }
+            instance
+        }
+    }
+}
@@ -0,0 +1,181 @@
+use crate::contract::*;
+use ink::env::{
+    test::{
+        self,
+        DefaultAccounts,
+    },
+    DefaultEnvironment,
+};
+use logics::impls::timelock::*;
+use openbrush::{
+    contracts::ownable::{
+        Ownable,
+        OwnableError,
+    },
+    traits::AccountId,
+};
+
+const DAY: u64 = 24 * 60 * 60 * 1000;
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    test::default_accounts::<DefaultEnvironment>()
+}
+fn set_caller(id: AccountId) {
+    test::set_caller::<DefaultEnvironment>(id);
+}
+fn set_block_timestamp(timestamp: u64) {
+    test::set_block_timestamp::<DefaultEnvironment>(timestamp);
+}
+fn transaction(eta: u64) -> Transaction {
+    Transaction {
+        target: AccountId::from([0x01; 32]),
+        selector: [0x12, 0x34, 0x56, 0x78],
+        input: vec![0x01, 0x02],
+        eta,
+    }
+}
+
+#[ink::test]
+fn new_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let contract = TimelockContract::new(2 * DAY);
+    assert_eq!(contract.delay(), 2 * DAY);
+    assert_eq!(contract.grace_period(), GRACE_PERIOD);
+    assert_eq!(contract.owner(), accounts.bob);
+}
+
+#[ink::test]
+#[should_panic(expected = "delay is out of bounds")]
+fn new_fails_when_delay_is_out_of_bounds() {
+    TimelockContract::new(DAY);
+}
+
+#[ink::test]
+fn queue_and_cancel_transaction_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    set_block_timestamp(DAY);
+
+    let mut contract = TimelockContract::new(2 * DAY);
+    assert_eq!(
+        contract
+            .queue_transaction(transaction(2 * DAY))
+            .unwrap_err(),
+        Error::EtaBeforeDelay
+    );
+
+    let id = contract.queue_transaction(transaction(3 * DAY)).unwrap();
+    assert_eq!(id, contract.transaction_id(transaction(3 * DAY)));
+    assert_ne!(id, contract.transaction_id(transaction(4 * DAY)));
+    assert!(contract.is_queued(id));
+
+    assert!(contract.cancel_transaction(transaction(3 * DAY)).is_ok());
+    assert!(!contract.is_queued(id));
+    assert_eq!(
+        contract
+            .cancel_transaction(transaction(3 * DAY))
+            .unwrap_err(),
+        Error::TransactionNotQueued
+    );
+}
+
+#[ink::test]
+fn execute_transaction_fails_outside_its_window() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    set_block_timestamp(DAY);
+
+    let mut contract = TimelockContract::new(2 * DAY);
+    assert_eq!(
+        contract
+            .execute_transaction(transaction(3 * DAY))
+            .unwrap_err(),
+        Error::TransactionNotQueued
+    );
+    assert!(contract.queue_transaction(transaction(3 * DAY)).is_ok());
+
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract
+            .execute_transaction(transaction(3 * DAY))
+            .unwrap_err(),
+        Error::TransactionNotReady
+    );
+    set_block_timestamp(3 * DAY + GRACE_PERIOD + 1);
+    assert_eq!(
+        contract
+            .execute_transaction(transaction(3 * DAY))
+            .unwrap_err(),
+        Error::TransactionIsStale
+    );
+}
+
+#[ink::test]
+fn admin_functions_fail_by_non_admin() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    set_block_timestamp(DAY);
+
+    let mut contract = TimelockContract::new(2 * DAY);
+    set_caller(accounts.charlie);
+    assert_eq!(
+        contract
+            .queue_transaction(transaction(3 * DAY))
+            .unwrap_err(),
+        Error::Ownable(OwnableError::CallerIsNotOwner)
+    );
+    assert_eq!(
+        contract
+            .cancel_transaction(transaction(3 * DAY))
+            .unwrap_err(),
+        Error::Ownable(OwnableError::CallerIsNotOwner)
+    );
+
+    set_caller(accounts.bob);
+    assert_eq!(
+        contract.set_delay(3 * DAY).unwrap_err(),
+        Error::CallerIsNotTimelock
+    );
+}
+
+#[ink::test]
+fn execute_transaction_sets_delay_of_timelock() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    set_block_timestamp(DAY);
+
+    let mut contract = TimelockContract::new(2 * DAY);
+    let set_delay = Transaction {
+        target: ink::env::account_id::<DefaultEnvironment>(),
+        selector: ink::selector_bytes!("Timelock::set_delay"),
+        input: scale::Encode::encode(&(5 * DAY)),
+        eta: 3 * DAY,
+    };
+    let id = contract.queue_transaction(set_delay.clone()).unwrap();
+
+    set_block_timestamp(3 * DAY);
+    assert!(contract.execute_transaction(set_delay).is_ok());
+    assert!(!contract.is_queued(id));
+    assert_eq!(contract.delay(), 5 * DAY);
+}
+
+#[ink::test]
+fn queue_transaction_fails_for_other_messages_of_timelock() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+    set_block_timestamp(DAY);
+
+    let mut contract = TimelockContract::new(2 * DAY);
+    let transfer_ownership = Transaction {
+        target: ink::env::account_id::<DefaultEnvironment>(),
+        selector: ink::selector_bytes!("Ownable::transfer_ownership"),
+        input: scale::Encode::encode(&accounts.charlie),
+        eta: 3 * DAY,
+    };
+    assert_eq!(
+        contract.queue_transaction(transfer_ownership).unwrap_err(),
+        Error::UnsupportedSelfCall
+    );
+}
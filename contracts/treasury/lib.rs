@@ -0,0 +1,229 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![feature(min_specialization)]
+
+/// Definition of Treasury Contract
+#[openbrush::contract]
+pub mod contract {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use logics::impls::treasury::{
+        self,
+        Internal as TreasuryInternal,
+        Result,
+        Stream,
+        StreamId,
+    };
+    use openbrush::{
+        contracts::access_control::{
+            self,
+            Internal as AccessControlInternal,
+            RoleType,
+        },
+        modifiers,
+        traits::Storage,
+    };
+
+    pub const TREASURER: RoleType = ink::selector_id!("TREASURER");
+    pub const BUDGET_ADMIN: RoleType = ink::selector_id!("BUDGET_ADMIN");
+
+    /// Contract's Storage
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct TreasuryContract {
+        #[storage_field]
+        treasury: treasury::Data,
+        #[storage_field]
+        access: access_control::Data,
+    }
+
+    /// Event: Asset is transferred out of the treasury
+    #[ink(event)]
+    pub struct Transfer {
+        #[ink(topic)]
+        pub asset: AccountId,
+        #[ink(topic)]
+        pub to: AccountId,
+        pub amount: Balance,
+    }
+
+    /// Event: Budget stream is created
+    #[ink(event)]
+    pub struct StreamCreated {
+        #[ink(topic)]
+        pub stream_id: StreamId,
+        #[ink(topic)]
+        pub recipient: AccountId,
+        pub stream: Stream,
+    }
+
+    /// Event: Budget stream is canceled, releasing the amount not streamed
+    #[ink(event)]
+    pub struct StreamCanceled {
+        #[ink(topic)]
+        pub stream_id: StreamId,
+        pub released: Balance,
+    }
+
+    /// Event: Streamed amount is withdrawn by the recipient
+    #[ink(event)]
+    pub struct StreamWithdrawn {
+        #[ink(topic)]
+        pub stream_id: StreamId,
+        pub amount: Balance,
+    }
+
+    /// Event: The admin role holder has changed
+    #[ink(event)]
+    pub struct RoleAdminChanged {
+        #[ink(topic)]
+        pub role: RoleType,
+        #[ink(topic)]
+        pub previous_admin_role: RoleType,
+        #[ink(topic)]
+        pub new_admin_role: RoleType,
+    }
+
+    /// Event: New role is assigned to the account
+    #[ink(event)]
+    pub struct RoleGranted {
+        #[ink(topic)]
+        pub role: RoleType,
+        #[ink(topic)]
+        pub grantee: AccountId,
+        #[ink(topic)]
+        pub grantor: Option<AccountId>,
+    }
+
+    /// Event: The role has been revoked from the account
+    #[ink(event)]
+    pub struct RoleRevoked {
+        #[ink(topic)]
+        pub role: RoleType,
+        #[ink(topic)]
+        pub account: AccountId,
+        #[ink(topic)]
+        pub admin: AccountId,
+    }
+
+    /// NOTE: Apply permission control by overriding the Default implementation with roles.
+    impl treasury::Treasury for TreasuryContract {
+        #[ink(message)]
+        #[modifiers(access_control::only_role(TREASURER))]
+        fn transfer(&mut self, asset: AccountId, to: AccountId, amount: Balance) -> Result<()> {
+            self._transfer(asset, to, amount)?;
+            self._emit_transfer_event(asset, to, amount);
+            Ok(())
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(BUDGET_ADMIN))]
+        fn create_stream(
+            &mut self,
+            recipient: AccountId,
+            asset: AccountId,
+            amount: Balance,
+            start: Timestamp,
+            end: Timestamp,
+        ) -> Result<StreamId> {
+            let stream = Stream {
+                recipient,
+                asset,
+                amount,
+                withdrawn: 0,
+                start,
+                end,
+            };
+            let stream_id = self._create_stream(stream)?;
+            self._emit_stream_created_event(stream_id, stream);
+            Ok(stream_id)
+        }
+        #[ink(message)]
+        #[modifiers(access_control::only_role(BUDGET_ADMIN))]
+        fn cancel_stream(&mut self, stream_id: StreamId) -> Result<()> {
+            let released = self._cancel_stream(stream_id)?;
+            self._emit_stream_canceled_event(stream_id, released);
+            Ok(())
+        }
+    }
+
+    impl treasury::Internal for TreasuryContract {
+        fn _emit_transfer_event(&self, asset: AccountId, to: AccountId, amount: Balance) {
+            self.env().emit_event(Transfer { asset, to, amount })
+        }
+
+        fn _emit_stream_created_event(&self, stream_id: StreamId, stream: Stream) {
+            self.env().emit_event(StreamCreated {
+                stream_id,
+                recipient: stream.recipient,
+                stream,
+            })
+        }
+
+        fn _emit_stream_canceled_event(&self, stream_id: StreamId, released: Balance) {
+            self.env().emit_event(StreamCanceled {
+                stream_id,
+                released,
+            })
+        }
+
+        fn _emit_stream_withdrawn_event(&self, stream_id: StreamId, amount: Balance) {
+            self.env().emit_event(StreamWithdrawn { stream_id, amount })
+        }
+    }
+
+    impl access_control::AccessControl for TreasuryContract {}
+
+    impl access_control::Internal for TreasuryContract {
+        fn _emit_role_admin_changed(
+            &mut self,
+            role: u32,
+            previous_admin_role: u32,
+            new_admin_role: u32,
+        ) {
+            self.env().emit_event(RoleAdminChanged {
+                role,
+                previous_admin_role,
+                new_admin_role,
+            })
+        }
+
+        fn _emit_role_granted(
+            &mut self,
+            role: u32,
+            grantee: AccountId,
+            grantor: Option<AccountId>,
+        ) {
+            self.env().emit_event(RoleGranted {
+                role,
+                grantee,
+                grantor,
+            })
+        }
+
+        fn _emit_role_revoked(&mut self, role: u32, account: AccountId, sender: AccountId) {
+            self.env().emit_event(RoleRevoked {
+                role,
+                account,
+                admin: sender,
+            })
+        }
+    }
+
+    impl TreasuryContract {
+        /// Generate this contract
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            let mut instance = Self::default();
+            instance._init_with_caller();
+            instance
+        }
+    }
+}
@@ -0,0 +1,75 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![feature(min_specialization)]
+
+/// Definition of Vote Escrow Contract
+#[openbrush::contract]
+pub mod contract {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use logics::impls::vote_escrow::{
+        Data,
+        Internal,
+        *,
+    };
+    use openbrush::traits::Storage;
+
+    /// Contract's Storage
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct VoteEscrowContract {
+        #[storage_field]
+        escrow: Data,
+    }
+
+    /// Event: Lock of an account is created or increased
+    #[ink(event)]
+    pub struct Locked {
+        #[ink(topic)]
+        account: AccountId,
+        amount: Balance,
+        end: Timestamp,
+    }
+
+    /// Event: Expired lock of an account is withdrawn
+    #[ink(event)]
+    pub struct Withdrawn {
+        #[ink(topic)]
+        account: AccountId,
+        amount: Balance,
+    }
+
+    impl VoteEscrow for VoteEscrowContract {}
+
+    impl Internal for VoteEscrowContract {
+        fn _emit_locked_event(&self, account: AccountId, amount: Balance, end: Timestamp) {
+            self.env().emit_event(Locked {
+                account,
+                amount,
+                end,
+            })
+        }
+
+        fn _emit_withdrawn_event(&self, account: AccountId, amount: Balance) {
+            self.env().emit_event(Withdrawn { account, amount })
+        }
+    }
+
+    impl VoteEscrowContract {
+        /// Generate this contract
+        #[ink(constructor)]
+        pub fn new(token: AccountId) -> Self {
+            let mut instance = Self::default();
+            instance._initialize(token);
+            instance
+        }
+    }
+}
@@ -16,16 +16,32 @@ pub mod contract {
         Env,
     };
 
-    use logics::impls::weth_gateway::{
-        Data,
-        Internal,
-        *,
+    use logics::impls::{
+        ownable_2step::{
+            self,
+            Internal as Ownable2StepInternal,
+            Ownable2Step,
+        },
+        upgradeable::{
+            self,
+            Internal as UpgradeableInternal,
+            Upgradeable,
+        },
+        weth_gateway::{
+            Data,
+            Internal,
+            *,
+        },
     };
     use openbrush::{
         contracts::ownable::*,
+        modifiers,
         traits::Storage,
     };
 
+    /// Version of the storage layout this code is built for
+    pub const STORAGE_VERSION: u32 = 1;
+
     /// Contract's Storage
     #[ink(storage)]
     #[derive(Default, Storage)]
@@ -34,6 +50,10 @@ pub mod contract {
         gateway: Data,
         #[storage_field]
         ownable: ownable::Data,
+        #[storage_field]
+        ownable_2step: ownable_2step::Data,
+        #[storage_field]
+        upgradeable: upgradeable::Data,
     }
 
     /// Event: DepositETH is executed.
@@ -76,7 +96,115 @@ pub mod contract {
         value: Balance,
     }
 
-    impl Ownable for WETHGatewayContract {}
+    /// Event: DepositWithPermit is executed.
+    #[ink(event)]
+    pub struct DepositWithPermit {
+        #[ink(topic)]
+        pool: AccountId,
+        #[ink(topic)]
+        from: AccountId,
+        value: Balance,
+    }
+
+    /// Event: Ownership is proposed to a new owner
+    #[ink(event)]
+    pub struct OwnershipTransferStarted {
+        #[ink(topic)]
+        pub previous_owner: AccountId,
+        #[ink(topic)]
+        pub new_owner: AccountId,
+    }
+
+    /// Event: Ownership is transferred
+    #[ink(event)]
+    pub struct OwnershipTransferred {
+        #[ink(topic)]
+        pub previous_owner: Option<AccountId>,
+        #[ink(topic)]
+        pub new_owner: Option<AccountId>,
+    }
+
+    /// Event: Code of this contract is replaced
+    #[ink(event)]
+    pub struct CodeUpgraded {
+        pub code_hash: Hash,
+        pub version: u32,
+    }
+
+    /// Event: Storage is migrated to the layout of the running code
+    #[ink(event)]
+    pub struct Migrated {
+        pub from: u32,
+        pub to: u32,
+    }
+
+    impl Ownable for WETHGatewayContract {
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn transfer_ownership(
+            &mut self,
+            new_owner: AccountId,
+        ) -> core::result::Result<(), OwnableError> {
+            self._propose_owner(new_owner)
+        }
+
+        #[ink(message)]
+        #[modifiers(only_owner)]
+        fn renounce_ownership(&mut self) -> core::result::Result<(), OwnableError> {
+            self._renounce_ownership()
+        }
+    }
+
+    impl Ownable2Step for WETHGatewayContract {}
+
+    impl ownable::Internal for WETHGatewayContract {
+        fn _emit_ownership_transferred_event(
+            &self,
+            previous_owner: Option<AccountId>,
+            new_owner: Option<AccountId>,
+        ) {
+            self.env().emit_event(OwnershipTransferred {
+                previous_owner,
+                new_owner,
+            })
+        }
+    }
+
+    impl ownable_2step::Internal for WETHGatewayContract {
+        fn _emit_ownership_transfer_started_event(
+            &self,
+            previous_owner: AccountId,
+            new_owner: AccountId,
+        ) {
+            self.env().emit_event(OwnershipTransferStarted {
+                previous_owner,
+                new_owner,
+            })
+        }
+    }
+
+    impl Upgradeable for WETHGatewayContract {}
+
+    impl upgradeable::Internal for WETHGatewayContract {
+        fn _assert_upgrade_admin(&self) -> upgradeable::Result<()> {
+            if self.owner() != self.env().caller() {
+                return Err(upgradeable::Error::CallerIsNotAdmin)
+            }
+            Ok(())
+        }
+
+        fn _code_version(&self) -> u32 {
+            STORAGE_VERSION
+        }
+
+        fn _emit_code_upgraded_event(&self, code_hash: Hash, version: u32) {
+            self.env().emit_event(CodeUpgraded { code_hash, version });
+        }
+
+        fn _emit_migrated_event(&self, from: u32, to: u32) {
+            self.env().emit_event(Migrated { from, to });
+        }
+    }
 
     impl Internal for WETHGatewayContract {
         fn _emit_deposit_eth_event_(&self, pool: AccountId, from: AccountId, value: Balance) {
@@ -94,6 +222,16 @@ pub mod contract {
         fn _emit_repay_eth_event_(&self, pool: AccountId, from: AccountId, value: Balance) {
             self.env().emit_event(RepayEth { pool, from, value });
         }
+
+        fn _emit_deposit_with_permit_event_(
+            &self,
+            pool: AccountId,
+            from: AccountId,
+            value: Balance,
+        ) {
+            self.env()
+                .emit_event(DepositWithPermit { pool, from, value });
+        }
     }
     impl WETHGateway for WETHGatewayContract {}
 
@@ -105,6 +243,7 @@ pub mod contract {
             let caller = Self::env().caller();
             instance._init_with_owner(caller);
             instance._initialize(weth);
+            instance._set_version(STORAGE_VERSION);
             instance
         }
     }
@@ -0,0 +1,71 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![feature(min_specialization)]
+
+#[cfg(test)]
+mod tests;
+
+/// Definition of XCM Deposit Contract
+#[openbrush::contract]
+pub mod contract {
+    use ink::codegen::{
+        EmitEvent,
+        Env,
+    };
+    use logics::impls::xcm_deposit::*;
+    use openbrush::traits::Storage;
+
+    /// Contract's Storage
+    #[ink(storage)]
+    #[derive(Storage, Default)]
+    pub struct XcmDepositContract {
+        #[storage_field]
+        xcm_deposit: Data,
+    }
+
+    /// Event: XC-20 asset received through XCM is deposited into its pool
+    #[ink(event)]
+    pub struct Deposited {
+        #[ink(topic)]
+        asset_id: u128,
+        #[ink(topic)]
+        pool: AccountId,
+        #[ink(topic)]
+        account: AccountId,
+        amount: Balance,
+    }
+
+    impl XcmDeposit for XcmDepositContract {}
+    impl Internal for XcmDepositContract {
+        fn _emit_deposited_event(
+            &self,
+            asset_id: u128,
+            pool: AccountId,
+            account: AccountId,
+            amount: Balance,
+        ) {
+            self.env().emit_event(Deposited {
+                asset_id,
+                pool,
+                account,
+                amount,
+            });
+        }
+    }
+
+    impl XcmDepositContract {
+        /// Generate this contract
+        #[ink(constructor)]
+        pub fn new(controller: AccountId) -> Self {
+            let mut instance: XcmDepositContract = Default::default();
+            instance._initialize(controller);
+            instance
+        }
+    }
+}
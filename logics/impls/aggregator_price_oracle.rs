@@ -0,0 +1,445 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+pub use super::params::MAX_OVERRIDE_DURATION;
+use super::{
+    exp_no_err::exp_scale,
+    params::{
+        is_valid_override_duration,
+        is_valid_price_deviation,
+    },
+    price_oracle::is_within_deviation,
+};
+pub use crate::traits::{
+    aggregator_price_oracle::*,
+    price_oracle::*,
+};
+use crate::traits::{
+    manager::ManagerRef,
+    pool::PoolRef,
+};
+use core::ops::Div;
+use openbrush::{
+    contracts::ownable::{
+        self,
+        only_owner,
+    },
+    modifiers,
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Storage,
+        Timestamp,
+    },
+};
+use primitive_types::U256;
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Debug, Default)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// Sources pricing each asset
+    pub sources: Mapping<AccountId, PriceSources>,
+    /// Composition of each asset priced as a derivative
+    pub derivatives: Mapping<AccountId, DerivativeSource>,
+    /// Guardian allowed to override prices
+    pub guardian: Option<AccountId>,
+    /// Manager the borrows of overridden markets are paused through
+    pub manager: Option<AccountId>,
+    /// Prices pinned by the guardian
+    pub overrides: Mapping<AccountId, PriceOverride>,
+    /// Pool whose borrows are paused by the override of each asset
+    pub paused_pools: Mapping<AccountId, AccountId>,
+}
+
+/// Price of the primary source if it is available and within the anchor, otherwise the fallback
+///
+/// An unavailable anchor does not invalidate the primary price.
+pub fn select_price(
+    primary: Option<u128>,
+    anchor: Option<u128>,
+    fallback: Option<u128>,
+    tolerance_mantissa: U256,
+) -> Option<u128> {
+    let valid = |price: &u128| *price != 0;
+    match primary.filter(valid) {
+        Some(price) => {
+            match anchor.filter(valid) {
+                Some(anchor) if !is_within_deviation(price, anchor, tolerance_mantissa) => {
+                    fallback.filter(valid)
+                }
+                _ => Some(price),
+            }
+        }
+        None => fallback.filter(valid),
+    }
+}
+
+/// Price of a derivative worth `exchange_rate` (scaled by 1e18) of a base asset priced `base_price`
+pub fn derivative_price(base_price: u128, exchange_rate: U256) -> Option<u128> {
+    if base_price == 0 || exchange_rate.is_zero() {
+        return None
+    }
+    let price = U256::from(base_price)
+        .checked_mul(exchange_rate)?
+        .div(exp_scale());
+    if price.is_zero() || price > U256::from(u128::MAX) {
+        return None
+    }
+    Some(price.as_u128())
+}
+
+pub trait Internal {
+    fn _get_price(&self, asset: AccountId) -> Option<u128>;
+    fn _get_sources_price(&self, asset: AccountId) -> Option<u128>;
+    fn _get_derivative_price(&self, derivative: DerivativeSource) -> Option<u128>;
+    fn _get_underlying_price(&self, pool: AccountId) -> Option<u128>;
+    fn _set_sources(&mut self, asset: AccountId, sources: PriceSources) -> Result<()>;
+    fn _remove_sources(&mut self, asset: AccountId) -> Result<()>;
+    fn _set_derivative(&mut self, asset: AccountId, derivative: DerivativeSource) -> Result<()>;
+    fn _remove_derivative(&mut self, asset: AccountId) -> Result<()>;
+    fn _set_guardian(&mut self, guardian: AccountId) -> Result<()>;
+    fn _set_manager(&mut self, manager: AccountId) -> Result<()>;
+    fn _set_override_price(
+        &mut self,
+        asset: AccountId,
+        price: u128,
+        duration: Timestamp,
+        pool: Option<AccountId>,
+    ) -> Result<()>;
+    fn _clear_override_price(&mut self, asset: AccountId) -> Result<()>;
+    fn _clear_expired_override(&mut self, asset: AccountId) -> Result<()>;
+    fn _active_override_price(&self, asset: AccountId) -> Option<u128>;
+    fn _assert_guardian(&self) -> Result<()>;
+
+    // event emission
+    fn _emit_price_overridden_event(
+        &self,
+        asset: AccountId,
+        price: u128,
+        expires_at: Timestamp,
+        borrow_paused: bool,
+    );
+    fn _emit_override_cleared_event(&self, asset: AccountId, borrow_resumed: bool);
+}
+
+impl<T: Storage<Data> + Storage<ownable::Data>> AggregatorPriceOracle for T {
+    #[modifiers(only_owner)]
+    default fn set_sources(&mut self, asset: AccountId, sources: PriceSources) -> Result<()> {
+        self._set_sources(asset, sources)
+    }
+
+    #[modifiers(only_owner)]
+    default fn remove_sources(&mut self, asset: AccountId) -> Result<()> {
+        self._remove_sources(asset)
+    }
+
+    default fn sources(&self, asset: AccountId) -> Option<PriceSources> {
+        self.data::<Data>().sources.get(&asset)
+    }
+
+    #[modifiers(only_owner)]
+    default fn set_derivative(
+        &mut self,
+        asset: AccountId,
+        derivative: DerivativeSource,
+    ) -> Result<()> {
+        self._set_derivative(asset, derivative)
+    }
+
+    #[modifiers(only_owner)]
+    default fn remove_derivative(&mut self, asset: AccountId) -> Result<()> {
+        self._remove_derivative(asset)
+    }
+
+    default fn derivative(&self, asset: AccountId) -> Option<DerivativeSource> {
+        self.data::<Data>().derivatives.get(&asset)
+    }
+
+    #[modifiers(only_owner)]
+    default fn set_guardian(&mut self, guardian: AccountId) -> Result<()> {
+        self._set_guardian(guardian)
+    }
+
+    default fn guardian(&self) -> Option<AccountId> {
+        self.data::<Data>().guardian
+    }
+
+    #[modifiers(only_owner)]
+    default fn set_manager(&mut self, manager: AccountId) -> Result<()> {
+        self._set_manager(manager)
+    }
+
+    default fn manager(&self) -> Option<AccountId> {
+        self.data::<Data>().manager
+    }
+
+    default fn set_override_price(
+        &mut self,
+        asset: AccountId,
+        price: u128,
+        duration: Timestamp,
+        pool: Option<AccountId>,
+    ) -> Result<()> {
+        self._assert_guardian()?;
+        self._set_override_price(asset, price, duration, pool)
+    }
+
+    default fn clear_override_price(&mut self, asset: AccountId) -> Result<()> {
+        self._assert_guardian()?;
+        self._clear_override_price(asset)
+    }
+
+    default fn clear_expired_override(&mut self, asset: AccountId) -> Result<()> {
+        self._clear_expired_override(asset)
+    }
+
+    default fn override_price(&self, asset: AccountId) -> Option<PriceOverride> {
+        self.data::<Data>().overrides.get(&asset)
+    }
+
+    default fn override_paused_pool(&self, asset: AccountId) -> Option<AccountId> {
+        self.data::<Data>().paused_pools.get(&asset)
+    }
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _get_price(&self, asset: AccountId) -> Option<u128> {
+        if let Some(price) = self._active_override_price(asset) {
+            return Some(price)
+        }
+        // derivatives take precedence over the sources of the asset
+        match self.data::<Data>().derivatives.get(&asset) {
+            Some(derivative) => self._get_derivative_price(derivative),
+            None => self._get_sources_price(asset),
+        }
+    }
+
+    default fn _get_sources_price(&self, asset: AccountId) -> Option<u128> {
+        let sources = self.data::<Data>().sources.get(&asset)?;
+        let primary = PriceOracleRef::get_price(&sources.primary, asset);
+        // the anchor is only queried when the primary price can be validated
+        let anchor = match (primary, sources.anchor) {
+            (Some(_), Some(anchor)) => PriceOracleRef::get_price(&anchor, asset),
+            _ => None,
+        };
+        let tolerance = sources.anchor_tolerance_mantissa.into();
+        // the fallback is only queried when the primary price is rejected
+        if let Some(price) = select_price(primary, anchor, None, tolerance) {
+            return Some(price)
+        }
+        let fallback = sources
+            .fallback
+            .and_then(|fallback| PriceOracleRef::get_price(&fallback, asset));
+        select_price(None, None, fallback, tolerance)
+    }
+
+    default fn _get_derivative_price(&self, derivative: DerivativeSource) -> Option<u128> {
+        // the base asset may itself be a derivative
+        let base_price = self._get_price(derivative.base)?;
+        let exchange_rate = match derivative.exchange_rate {
+            ExchangeRateSource::Pool(pool) => PoolRef::exchange_rate_stored(&pool),
+            ExchangeRateSource::Provider(provider) => {
+                ExchangeRateProviderRef::exchange_rate(&provider)
+            }
+        };
+        derivative_price(base_price, exchange_rate.into())
+    }
+
+    default fn _get_underlying_price(&self, pool: AccountId) -> Option<u128> {
+        let underlying = PoolRef::underlying(&pool)?;
+        self._get_price(underlying)
+    }
+
+    default fn _set_sources(&mut self, asset: AccountId, sources: PriceSources) -> Result<()> {
+        if !is_valid_price_deviation(sources.anchor_tolerance_mantissa.into()) {
+            return Err(Error::InvalidAnchorTolerance)
+        }
+        self.data::<Data>().sources.insert(&asset, &sources);
+        Ok(())
+    }
+
+    default fn _remove_sources(&mut self, asset: AccountId) -> Result<()> {
+        self.data::<Data>().sources.remove(&asset);
+        Ok(())
+    }
+
+    default fn _set_derivative(
+        &mut self,
+        asset: AccountId,
+        derivative: DerivativeSource,
+    ) -> Result<()> {
+        if derivative.base == asset {
+            return Err(Error::InvalidDerivative)
+        }
+        self.data::<Data>().derivatives.insert(&asset, &derivative);
+        Ok(())
+    }
+
+    default fn _remove_derivative(&mut self, asset: AccountId) -> Result<()> {
+        self.data::<Data>().derivatives.remove(&asset);
+        Ok(())
+    }
+
+    default fn _set_guardian(&mut self, guardian: AccountId) -> Result<()> {
+        self.data::<Data>().guardian = Some(guardian);
+        Ok(())
+    }
+
+    default fn _set_manager(&mut self, manager: AccountId) -> Result<()> {
+        self.data::<Data>().manager = Some(manager);
+        Ok(())
+    }
+
+    default fn _set_override_price(
+        &mut self,
+        asset: AccountId,
+        price: u128,
+        duration: Timestamp,
+        pool: Option<AccountId>,
+    ) -> Result<()> {
+        if price == 0 || !is_valid_override_duration(duration) {
+            return Err(Error::InvalidOverride)
+        }
+        if let Some(pool) = pool {
+            if PoolRef::underlying(&pool) != Some(asset) {
+                return Err(Error::PoolAssetMismatch)
+            }
+        }
+        let expires_at = Self::env().block_timestamp().saturating_add(duration);
+        self.data::<Data>()
+            .overrides
+            .insert(&asset, &PriceOverride { price, expires_at });
+        // a failing pause must not block the override, which is itself an emergency measure
+        let borrow_paused = match (self.data::<Data>().manager, pool) {
+            (Some(manager), Some(pool)) => {
+                ManagerRef::set_borrow_guardian_paused(&manager, pool, true).is_ok()
+            }
+            _ => false,
+        };
+        if let (true, Some(pool)) = (borrow_paused, pool) {
+            self.data::<Data>().paused_pools.insert(&asset, &pool);
+        }
+        self._emit_price_overridden_event(asset, price, expires_at, borrow_paused);
+        Ok(())
+    }
+
+    default fn _clear_override_price(&mut self, asset: AccountId) -> Result<()> {
+        self.data::<Data>().overrides.remove(&asset);
+        // borrows paused by the override are resumed with it, unless the manager revoked our role
+        let borrow_resumed = match (
+            self.data::<Data>().manager,
+            self.data::<Data>().paused_pools.get(&asset),
+        ) {
+            (Some(manager), Some(pool)) => {
+                ManagerRef::set_borrow_guardian_paused(&manager, pool, false).is_ok()
+            }
+            _ => false,
+        };
+        self.data::<Data>().paused_pools.remove(&asset);
+        self._emit_override_cleared_event(asset, borrow_resumed);
+        Ok(())
+    }
+
+    default fn _clear_expired_override(&mut self, asset: AccountId) -> Result<()> {
+        let price_override = self
+            .data::<Data>()
+            .overrides
+            .get(&asset)
+            .ok_or(Error::InvalidOverride)?;
+        if Self::env().block_timestamp() < price_override.expires_at {
+            return Err(Error::OverrideNotExpired)
+        }
+        self._clear_override_price(asset)
+    }
+
+    default fn _active_override_price(&self, asset: AccountId) -> Option<u128> {
+        let price_override = self.data::<Data>().overrides.get(&asset)?;
+        if Self::env().block_timestamp() >= price_override.expires_at {
+            return None
+        }
+        Some(price_override.price)
+    }
+
+    default fn _assert_guardian(&self) -> Result<()> {
+        if self.data::<Data>().guardian != Some(Self::env().caller()) {
+            return Err(Error::CallerIsNotGuardian)
+        }
+        Ok(())
+    }
+
+    default fn _emit_price_overridden_event(
+        &self,
+        _asset: AccountId,
+        _price: u128,
+        _expires_at: Timestamp,
+        _borrow_paused: bool,
+    ) {
+    }
+    default fn _emit_override_cleared_event(&self, _asset: AccountId, _borrow_resumed: bool) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ops::{
+        Div,
+        Mul,
+    };
+
+    fn percent(val: u128) -> U256 {
+        exp_scale().mul(U256::from(val)).div(U256::from(100))
+    }
+
+    #[test]
+    fn test_select_price() {
+        let tolerance = percent(5);
+        // primary within the anchor, or without anchor
+        assert_eq!(
+            select_price(Some(104), Some(100), Some(1), tolerance),
+            Some(104)
+        );
+        assert_eq!(select_price(Some(104), None, Some(1), tolerance), Some(104));
+        assert_eq!(
+            select_price(Some(104), Some(0), Some(1), tolerance),
+            Some(104)
+        );
+        // primary out of bounds or unavailable
+        assert_eq!(
+            select_price(Some(110), Some(100), Some(1), tolerance),
+            Some(1)
+        );
+        assert_eq!(select_price(None, Some(100), Some(1), tolerance), Some(1));
+        assert_eq!(
+            select_price(Some(0), Some(100), Some(1), tolerance),
+            Some(1)
+        );
+        // no valid source
+        assert_eq!(select_price(Some(110), Some(100), None, tolerance), None);
+        assert_eq!(select_price(None, None, Some(0), tolerance), None);
+    }
+
+    #[test]
+    fn test_derivative_price() {
+        let base_price = 2 * 10_u128.pow(18);
+        assert_eq!(derivative_price(base_price, exp_scale()), Some(base_price));
+        assert_eq!(
+            derivative_price(base_price, percent(105)),
+            Some(21 * 10_u128.pow(17))
+        );
+        assert_eq!(
+            derivative_price(base_price, percent(2)),
+            Some(4 * 10_u128.pow(16))
+        );
+        // unavailable base price or exchange rate
+        assert_eq!(derivative_price(0, exp_scale()), None);
+        assert_eq!(derivative_price(base_price, U256::zero()), None);
+        // overflow
+        assert_eq!(derivative_price(u128::MAX, percent(200)), None);
+    }
+}
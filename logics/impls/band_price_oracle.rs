@@ -0,0 +1,124 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::price_oracle::is_stale;
+use crate::traits::pool::PoolRef;
+pub use crate::traits::{
+    band_price_oracle::*,
+    price_oracle::*,
+};
+use ink::prelude::string::String;
+use openbrush::{
+    contracts::ownable::{
+        self,
+        only_owner,
+    },
+    modifiers,
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Storage,
+        Timestamp,
+    },
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Debug, Default)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// Band's std_reference contract
+    pub std_reference: Option<AccountId>,
+    /// Symbols of the pair pricing each asset
+    pub symbols: Mapping<AccountId, BandSymbols>,
+}
+
+pub trait Internal {
+    fn _get_price(&self, asset: AccountId) -> Option<u128>;
+    fn _get_underlying_price(&self, pool: AccountId) -> Option<u128>;
+    fn _set_std_reference(&mut self, std_reference: AccountId) -> Result<()>;
+    fn _set_symbols(&mut self, asset: AccountId, symbols: BandSymbols) -> Result<()>;
+    fn _remove_symbols(&mut self, asset: AccountId) -> Result<()>;
+}
+
+impl<T: Storage<Data> + Storage<ownable::Data>> BandPriceOracle for T {
+    #[modifiers(only_owner)]
+    default fn set_std_reference(&mut self, std_reference: AccountId) -> Result<()> {
+        self._set_std_reference(std_reference)
+    }
+
+    #[modifiers(only_owner)]
+    default fn set_symbols(
+        &mut self,
+        asset: AccountId,
+        base: String,
+        quote: String,
+        heartbeat: Timestamp,
+    ) -> Result<()> {
+        self._set_symbols(
+            asset,
+            BandSymbols {
+                base,
+                quote,
+                heartbeat,
+            },
+        )
+    }
+
+    #[modifiers(only_owner)]
+    default fn remove_symbols(&mut self, asset: AccountId) -> Result<()> {
+        self._remove_symbols(asset)
+    }
+
+    default fn std_reference(&self) -> Option<AccountId> {
+        self.data::<Data>().std_reference
+    }
+
+    default fn symbols(&self, asset: AccountId) -> Option<BandSymbols> {
+        self.data::<Data>().symbols.get(&asset)
+    }
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _get_price(&self, asset: AccountId) -> Option<u128> {
+        let std_reference = self.data::<Data>().std_reference?;
+        let symbols = self.data::<Data>().symbols.get(&asset)?;
+        let data =
+            StdReferenceRef::get_reference_data(&std_reference, symbols.base, symbols.quote)?;
+        // Band's timestamps are in seconds
+        let updated_at = data.last_updated_base.min(data.last_updated_quote);
+        if is_stale(
+            updated_at.saturating_mul(1000),
+            symbols.heartbeat,
+            Self::env().block_timestamp(),
+        ) {
+            return None
+        }
+        // rates of std_reference have the same 18 decimals as PRICE_PRECISION
+        Some(data.rate)
+    }
+
+    default fn _get_underlying_price(&self, pool: AccountId) -> Option<u128> {
+        let underlying = PoolRef::underlying(&pool)?;
+        self._get_price(underlying)
+    }
+
+    default fn _set_std_reference(&mut self, std_reference: AccountId) -> Result<()> {
+        self.data::<Data>().std_reference = Some(std_reference);
+        Ok(())
+    }
+
+    default fn _set_symbols(&mut self, asset: AccountId, symbols: BandSymbols) -> Result<()> {
+        self.data::<Data>().symbols.insert(&asset, &symbols);
+        Ok(())
+    }
+
+    default fn _remove_symbols(&mut self, asset: AccountId) -> Result<()> {
+        self.data::<Data>().symbols.remove(&asset);
+        Ok(())
+    }
+}
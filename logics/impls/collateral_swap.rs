@@ -0,0 +1,205 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+pub use crate::traits::collateral_swap::*;
+use crate::traits::{
+    controller::ControllerRef,
+    dex_router::DexRouterRef,
+    flashloan_gateway::{
+        FlashLoanType,
+        FlashloanGatewayRef,
+    },
+    pool::PoolRef,
+};
+use ink::prelude::{
+    vec,
+    vec::Vec,
+};
+use openbrush::{
+    contracts::psp22::PSP22Ref,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+    },
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Debug, Default)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// AccountId of Controller
+    pub controller: Option<AccountId>,
+    /// AccountId of Flashloan Gateway
+    pub flashloan_gateway: Option<AccountId>,
+    /// AccountId of DEX Router
+    pub dex_router: Option<AccountId>,
+}
+
+pub trait Internal {
+    fn _initialize(
+        &mut self,
+        controller: AccountId,
+        flashloan_gateway: AccountId,
+        dex_router: AccountId,
+    );
+    fn _controller(&self) -> Option<AccountId>;
+    fn _flashloan_gateway(&self) -> Option<AccountId>;
+    fn _dex_router(&self) -> Option<AccountId>;
+    fn _pool_of(&self, asset: AccountId) -> Result<AccountId>;
+    fn _execute_operation(
+        &mut self,
+        assets: Vec<AccountId>,
+        amounts: Vec<Balance>,
+        premiums: Vec<Balance>,
+        initiator: AccountId,
+        params: Vec<u8>,
+    ) -> Result<()>;
+}
+
+impl<T: Storage<Data>> CollateralSwap for T {
+    default fn controller(&self) -> Option<AccountId> {
+        self._controller()
+    }
+
+    default fn flashloan_gateway(&self) -> Option<AccountId> {
+        self._flashloan_gateway()
+    }
+
+    default fn dex_router(&self) -> Option<AccountId> {
+        self._dex_router()
+    }
+
+    default fn swap_collateral(
+        &mut self,
+        amount: Balance,
+        min_amount_out: Balance,
+        path: Vec<AccountId>,
+    ) -> Result<()> {
+        if path.len() < 2 || path[0] == path[path.len() - 1] {
+            return Err(Error::InvalidSwapPath)
+        }
+        let flashloan_gateway = self
+            ._flashloan_gateway()
+            .ok_or(Error::FlashloanGatewayIsNotSet)?;
+
+        let caller = Self::env().caller();
+        let contract_addr = Self::env().account_id();
+        let asset = path[0];
+        let params = CollateralSwapParams {
+            account: caller,
+            min_amount_out,
+            path,
+        }
+        .encode();
+        FlashloanGatewayRef::flashloan_builder(
+            &flashloan_gateway,
+            contract_addr,
+            vec![asset],
+            vec![amount],
+            vec![FlashLoanType::None as u8],
+            caller,
+            params,
+        )
+        .call_flags(ink_env::CallFlags::default().set_allow_reentry(true))
+        .try_invoke()
+        .unwrap()
+        .unwrap()?;
+        Ok(())
+    }
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _initialize(
+        &mut self,
+        controller: AccountId,
+        flashloan_gateway: AccountId,
+        dex_router: AccountId,
+    ) {
+        self.data().controller = Some(controller);
+        self.data().flashloan_gateway = Some(flashloan_gateway);
+        self.data().dex_router = Some(dex_router);
+    }
+
+    default fn _controller(&self) -> Option<AccountId> {
+        self.data().controller
+    }
+
+    default fn _flashloan_gateway(&self) -> Option<AccountId> {
+        self.data().flashloan_gateway
+    }
+
+    default fn _dex_router(&self) -> Option<AccountId> {
+        self.data().dex_router
+    }
+
+    default fn _pool_of(&self, asset: AccountId) -> Result<AccountId> {
+        let controller = self._controller().ok_or(Error::ControllerIsNotSet)?;
+        ControllerRef::market_of_underlying(&controller, asset).ok_or(Error::MarketNotListed)
+    }
+
+    default fn _execute_operation(
+        &mut self,
+        assets: Vec<AccountId>,
+        amounts: Vec<Balance>,
+        premiums: Vec<Balance>,
+        initiator: AccountId,
+        params: Vec<u8>,
+    ) -> Result<()> {
+        let flashloan_gateway = self
+            ._flashloan_gateway()
+            .ok_or(Error::FlashloanGatewayIsNotSet)?;
+        if Self::env().caller() != flashloan_gateway {
+            return Err(Error::CallerIsNotFlashloanGateway)
+        }
+        let contract_addr = Self::env().account_id();
+        // only flashloans initiated by this contract carry swaps to run
+        if initiator != contract_addr || assets.len() != 1 {
+            return Err(Error::InvalidFlashloanParams)
+        }
+        let CollateralSwapParams {
+            account,
+            min_amount_out,
+            path,
+        } = CollateralSwapParams::decode(&mut &params[..])
+            .map_err(|_| Error::InvalidFlashloanParams)?;
+
+        let (asset, amount, premium) = (assets[0], amounts[0], premiums[0]);
+        let target_asset = path[path.len() - 1];
+        let pool = self._pool_of(asset)?;
+        let target_pool = self._pool_of(target_asset)?;
+        let dex_router = self._dex_router().ok_or(Error::DexRouterIsNotSet)?;
+
+        PSP22Ref::approve(&asset, dex_router, amount)?;
+        let amounts_out = DexRouterRef::swap_exact_tokens_for_tokens(
+            &dex_router,
+            amount,
+            min_amount_out,
+            path,
+            contract_addr,
+            Self::env().block_timestamp(),
+        )?;
+        let amount_out = amounts_out[amounts_out.len() - 1];
+        PSP22Ref::approve(&target_asset, target_pool, amount_out)?;
+        PoolRef::mint_to(&target_pool, account, amount_out)?;
+
+        // the swapped collateral pays back the flashloan
+        let repayment = amount + premium;
+        if PoolRef::balance_of(&pool, account) < repayment {
+            return Err(Error::InsufficientDeposit)
+        }
+        PoolRef::transfer_from(&pool, account, contract_addr, repayment, Default::default())?;
+        PoolRef::redeem_underlying(&pool, repayment)?;
+        PSP22Ref::approve(&asset, flashloan_gateway, repayment)?;
+        Ok(())
+    }
+}
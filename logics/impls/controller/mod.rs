@@ -7,19 +7,33 @@ pub use crate::traits::{
     },
 };
 use crate::{
-    impls::price_oracle::PRICE_PRECISION,
+    impls::{
+        ownable_2step::ManagerHandover,
+        params::{
+            is_valid_close_factor,
+            is_valid_collateral_factor,
+            is_valid_liquidation_incentive,
+        },
+        price_oracle::PRICE_PRECISION,
+    },
     traits::{
         price_oracle::PriceOracleRef,
-        types::WrappedU256,
+        types::{
+            ActionId,
+            SignedExp,
+            WrappedU256,
+        },
     },
 };
 use core::ops::{
     Add,
     Div,
     Mul,
-    Sub,
 };
-use ink::prelude::vec::Vec;
+use ink::{
+    prelude::vec::Vec,
+    selector_bytes,
+};
 use openbrush::{
     storage::Mapping,
     traits::{
@@ -30,18 +44,20 @@ use openbrush::{
     },
 };
 use primitive_types::U256;
+use scale::Encode;
 mod utils;
 pub use self::utils::{
     balance_decrease_allowed,
     calculate_available_borrow_in_base_currency,
     calculate_health_factor_from_balances,
-    collateral_factor_max_mantissa,
     get_hypothetical_account_liquidity,
     liquidate_calculate_seize_tokens,
+    liquidate_max_repay_amount,
     BalanceDecreaseAllowedParam,
     GetHypotheticalAccountLiquidityInput,
     HypotheticalAccountLiquidityCalculationParam,
     LiquidateCalculateSeizeTokensInput,
+    LiquidateMaxRepayAmountInput,
 };
 
 pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
@@ -75,6 +91,24 @@ pub struct Data {
     pub manager: Option<AccountId>,
     /// Flashloan Gateway's AccountId associated with this contract
     pub flashloan_gateway: Option<AccountId>,
+    /// AccountId proposed as the next manager
+    pub pending_manager: Option<AccountId>,
+    /// Stage of the emergency shutdown
+    pub protocol_status: ProtocolStatus,
+    /// AccountId allowed to shut the protocol down besides the manager
+    pub shutdown_guardian: Option<AccountId>,
+    /// AccountId of the factory allowed to list the pools it deploys
+    pub pool_factory: Option<AccountId>,
+}
+
+impl ManagerHandover for Data {
+    fn manager_mut(&mut self) -> &mut Option<AccountId> {
+        &mut self.manager
+    }
+
+    fn pending_manager_mut(&mut self) -> &mut Option<AccountId> {
+        &mut self.pending_manager
+    }
 }
 
 impl Default for Data {
@@ -93,6 +127,10 @@ impl Default for Data {
             borrow_caps: Default::default(),
             manager: None,
             flashloan_gateway: None,
+            pending_manager: None,
+            protocol_status: ProtocolStatus::Active,
+            shutdown_guardian: None,
+            pool_factory: None,
         }
     }
 }
@@ -182,6 +220,7 @@ pub trait Internal {
         borrower: AccountId,
         seize_tokens: Balance,
     ) -> Result<()>;
+    fn _absorb_allowed(&self, pool: AccountId, borrower: AccountId) -> Result<()>;
     fn _transfer_allowed(
         &self,
         pool: AccountId,
@@ -206,9 +245,26 @@ pub trait Internal {
         pool_borrowed_attributes: Option<PoolAttributesForSeizeCalculation>,
         pool_collateral_attributes: Option<PoolAttributesForSeizeCalculation>,
     ) -> Result<Balance>;
+    fn _liquidate_max_repay_amount(
+        &self,
+        pool_borrowed: AccountId,
+        pool_collateral: AccountId,
+        exchange_rate_mantissa: WrappedU256,
+        borrow_balance: Balance,
+        collateral_tokens: Balance,
+        pool_borrowed_attributes: Option<PoolAttributesForSeizeCalculation>,
+        pool_collateral_attributes: Option<PoolAttributesForSeizeCalculation>,
+    ) -> Result<Balance>;
+    fn _underlying_price_and_decimals(
+        &self,
+        pool: AccountId,
+        pool_attributes: Option<PoolAttributesForSeizeCalculation>,
+    ) -> Result<(u128, u8)>;
     fn _assert_manager(&self) -> Result<()>;
+    fn _assert_market_lister(&self) -> Result<()>;
 
     // admin functions
+    fn _set_manager(&mut self, new_manager: AccountId) -> Result<()>;
     fn _set_price_oracle(&mut self, new_oracle: AccountId) -> Result<()>;
     fn _support_market(
         &mut self,
@@ -226,6 +282,9 @@ pub trait Internal {
     fn _set_borrow_guardian_paused(&mut self, pool: &AccountId, paused: bool) -> Result<()>;
     fn _set_seize_guardian_paused(&mut self, paused: bool) -> Result<()>;
     fn _set_transfer_guardian_paused(&mut self, paused: bool) -> Result<()>;
+    fn _set_shutdown_guardian(&mut self, guardian: Option<AccountId>) -> Result<()>;
+    fn _set_pool_factory(&mut self, pool_factory: Option<AccountId>) -> Result<()>;
+    fn _set_protocol_status(&mut self, status: ProtocolStatus) -> Result<()>;
     fn _set_close_factor_mantissa(&mut self, new_close_factor_mantissa: WrappedU256) -> Result<()>;
     fn _set_liquidation_incentive_mantissa(
         &mut self,
@@ -243,11 +302,15 @@ pub trait Internal {
     fn _borrow_guardian_paused(&self, pool: AccountId) -> Option<bool>;
     fn _seize_guardian_paused(&self) -> bool;
     fn _transfer_guardian_paused(&self) -> bool;
+    fn _protocol_status(&self) -> ProtocolStatus;
+    fn _shutdown_guardian(&self) -> Option<AccountId>;
+    fn _pool_factory(&self) -> Option<AccountId>;
     fn _oracle(&self) -> Option<AccountId>;
     fn _close_factor_mantissa(&self) -> WrappedU256;
     fn _liquidation_incentive_mantissa(&self) -> WrappedU256;
     fn _borrow_cap(&self, pool: AccountId) -> Option<Balance>;
     fn _manager(&self) -> Option<AccountId>;
+    fn _pending_manager(&self) -> Option<AccountId>;
     fn _account_assets(
         &self,
         account: AccountId,
@@ -293,6 +356,14 @@ pub trait Internal {
     fn _emit_new_close_factor_event(&self, old: WrappedU256, new: WrappedU256);
     fn _emit_new_liquidation_incentive_event(&self, old: WrappedU256, new: WrappedU256);
     fn _emit_new_borrow_cap_event(&self, pool: AccountId, new: Balance);
+    fn _emit_protocol_status_changed_event(&self, status: ProtocolStatus);
+    fn _emit_admin_action_event(
+        &self,
+        action: ActionId,
+        target: Option<AccountId>,
+        old_value: Vec<u8>,
+        new_value: Vec<u8>,
+    );
 }
 
 impl<T: Storage<Data>> Controller for T {
@@ -446,6 +517,10 @@ impl<T: Storage<Data>> Controller for T {
         )
     }
 
+    default fn absorb_allowed(&self, pool: AccountId, borrower: AccountId) -> Result<()> {
+        self._absorb_allowed(pool, borrower)
+    }
+
     default fn transfer_allowed(
         &self,
         pool: AccountId,
@@ -486,18 +561,78 @@ impl<T: Storage<Data>> Controller for T {
         )
     }
 
+    default fn liquidate_max_repay_amount(
+        &self,
+        pool_borrowed: AccountId,
+        pool_collateral: AccountId,
+        exchange_rate_mantissa: WrappedU256,
+        borrow_balance: Balance,
+        collateral_tokens: Balance,
+        pool_borrowed_attributes: Option<PoolAttributesForSeizeCalculation>,
+        pool_collateral_attributes: Option<PoolAttributesForSeizeCalculation>,
+    ) -> Result<Balance> {
+        self._liquidate_max_repay_amount(
+            pool_borrowed,
+            pool_collateral,
+            exchange_rate_mantissa,
+            borrow_balance,
+            collateral_tokens,
+            pool_borrowed_attributes,
+            pool_collateral_attributes,
+        )
+    }
+
+    default fn set_manager(&mut self, new_manager: AccountId) -> Result<()> {
+        self._assert_manager()?;
+        let old = self.data().propose_manager(new_manager);
+        self._emit_admin_action_event(
+            selector_bytes!("Controller::set_manager"),
+            None,
+            old.encode(),
+            Some(new_manager).encode(),
+        );
+        Ok(())
+    }
+
+    default fn accept_manager(&mut self) -> Result<()> {
+        let caller = Self::env().caller();
+        let old = self
+            .data()
+            .accept_manager(caller)
+            .map_err(|_| Error::CallerIsNotPendingManager)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Controller::accept_manager"),
+            None,
+            old.encode(),
+            Some(caller).encode(),
+        );
+        Ok(())
+    }
+
     default fn set_price_oracle(&mut self, new_oracle: AccountId) -> Result<()> {
         self._assert_manager()?;
         let old = self._oracle();
         self._set_price_oracle(new_oracle)?;
         self._emit_new_price_oracle_event(old, Some(new_oracle));
+        self._emit_admin_action_event(
+            selector_bytes!("Controller::set_price_oracle"),
+            None,
+            old.encode(),
+            Some(new_oracle).encode(),
+        );
         Ok(())
     }
 
     default fn support_market(&mut self, pool: AccountId, underlying: AccountId) -> Result<()> {
-        self._assert_manager()?;
+        self._assert_market_lister()?;
         self._support_market(&pool, &underlying, None)?;
         self._emit_market_listed_event(pool);
+        self._emit_admin_action_event(
+            selector_bytes!("Controller::support_market"),
+            Some(pool),
+            Vec::new(),
+            underlying.encode(),
+        );
         Ok(())
     }
 
@@ -506,6 +641,12 @@ impl<T: Storage<Data>> Controller for T {
         let old = self._flashloan_gateway();
         self._set_flashloan_gateway(new_flashloan_gateway)?;
         self._emit_new_flashloan_gateway_event(old, Some(new_flashloan_gateway));
+        self._emit_admin_action_event(
+            selector_bytes!("Controller::set_flashloan_gateway"),
+            None,
+            old.encode(),
+            Some(new_flashloan_gateway).encode(),
+        );
         Ok(())
     }
 
@@ -515,9 +656,15 @@ impl<T: Storage<Data>> Controller for T {
         underlying: AccountId,
         collateral_factor_mantissa: WrappedU256,
     ) -> Result<()> {
-        self._assert_manager()?;
+        self._assert_market_lister()?;
         self._support_market(&pool, &underlying, Some(collateral_factor_mantissa))?;
         self._emit_market_listed_event(pool);
+        self._emit_admin_action_event(
+            selector_bytes!("Controller::support_market_with_collateral_factor_mantissa"),
+            Some(pool),
+            Vec::new(),
+            (underlying, collateral_factor_mantissa).encode(),
+        );
         Ok(())
     }
 
@@ -530,34 +677,144 @@ impl<T: Storage<Data>> Controller for T {
         let old = self._collateral_factor_mantissa(pool).unwrap_or_default();
         self._set_collateral_factor_mantissa(&pool, new_collateral_factor_mantissa)?;
         self._emit_new_collateral_factor_event(pool, old, new_collateral_factor_mantissa);
+        self._emit_admin_action_event(
+            selector_bytes!("Controller::set_collateral_factor_mantissa"),
+            Some(pool),
+            old.encode(),
+            new_collateral_factor_mantissa.encode(),
+        );
         Ok(())
     }
 
     default fn set_mint_guardian_paused(&mut self, pool: AccountId, paused: bool) -> Result<()> {
         self._assert_manager()?;
+        let old = self._mint_guardian_paused(pool).unwrap_or_default();
         self._set_mint_guardian_paused(&pool, paused)?;
         self._emit_pool_action_paused_event(pool, String::from("Mint"), paused);
+        self._emit_admin_action_event(
+            selector_bytes!("Controller::set_mint_guardian_paused"),
+            Some(pool),
+            old.encode(),
+            paused.encode(),
+        );
         Ok(())
     }
 
     default fn set_borrow_guardian_paused(&mut self, pool: AccountId, paused: bool) -> Result<()> {
         self._assert_manager()?;
+        let old = self._borrow_guardian_paused(pool).unwrap_or_default();
         self._set_borrow_guardian_paused(&pool, paused)?;
         self._emit_pool_action_paused_event(pool, String::from("Borrow"), paused);
+        self._emit_admin_action_event(
+            selector_bytes!("Controller::set_borrow_guardian_paused"),
+            Some(pool),
+            old.encode(),
+            paused.encode(),
+        );
         Ok(())
     }
 
     default fn set_seize_guardian_paused(&mut self, paused: bool) -> Result<()> {
         self._assert_manager()?;
+        let old = self._seize_guardian_paused();
         self._set_seize_guardian_paused(paused)?;
         self._emit_action_paused_event(String::from("Seize"), paused);
+        self._emit_admin_action_event(
+            selector_bytes!("Controller::set_seize_guardian_paused"),
+            None,
+            old.encode(),
+            paused.encode(),
+        );
         Ok(())
     }
 
     default fn set_transfer_guardian_paused(&mut self, paused: bool) -> Result<()> {
         self._assert_manager()?;
+        let old = self._transfer_guardian_paused();
         self._set_transfer_guardian_paused(paused)?;
         self._emit_action_paused_event(String::from("Transfer"), paused);
+        self._emit_admin_action_event(
+            selector_bytes!("Controller::set_transfer_guardian_paused"),
+            None,
+            old.encode(),
+            paused.encode(),
+        );
+        Ok(())
+    }
+
+    default fn set_shutdown_guardian(&mut self, guardian: Option<AccountId>) -> Result<()> {
+        self._assert_manager()?;
+        let old = self._shutdown_guardian();
+        self._set_shutdown_guardian(guardian)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Controller::set_shutdown_guardian"),
+            None,
+            old.encode(),
+            guardian.encode(),
+        );
+        Ok(())
+    }
+
+    default fn set_pool_factory(&mut self, pool_factory: Option<AccountId>) -> Result<()> {
+        self._assert_manager()?;
+        let old = self._pool_factory();
+        self._set_pool_factory(pool_factory)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Controller::set_pool_factory"),
+            None,
+            old.encode(),
+            pool_factory.encode(),
+        );
+        Ok(())
+    }
+
+    default fn shutdown(&mut self) -> Result<()> {
+        let caller = Self::env().caller();
+        if Some(caller) != self._shutdown_guardian() {
+            self._assert_manager()
+                .map_err(|_| Error::CallerIsNotShutdownGuardian)?;
+        }
+        let old = self._protocol_status();
+        if old == ProtocolStatus::Shutdown {
+            return Err(Error::InvalidProtocolStatus)
+        }
+        self._set_protocol_status(ProtocolStatus::Shutdown)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Controller::shutdown"),
+            None,
+            old.encode(),
+            ProtocolStatus::Shutdown.encode(),
+        );
+        Ok(())
+    }
+
+    default fn recover(&mut self) -> Result<()> {
+        self._assert_manager()?;
+        if self._protocol_status() != ProtocolStatus::Shutdown {
+            return Err(Error::InvalidProtocolStatus)
+        }
+        self._set_protocol_status(ProtocolStatus::Recovering)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Controller::recover"),
+            None,
+            ProtocolStatus::Shutdown.encode(),
+            ProtocolStatus::Recovering.encode(),
+        );
+        Ok(())
+    }
+
+    default fn reactivate(&mut self) -> Result<()> {
+        self._assert_manager()?;
+        if self._protocol_status() != ProtocolStatus::Recovering {
+            return Err(Error::InvalidProtocolStatus)
+        }
+        self._set_protocol_status(ProtocolStatus::Active)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Controller::reactivate"),
+            None,
+            ProtocolStatus::Recovering.encode(),
+            ProtocolStatus::Active.encode(),
+        );
         Ok(())
     }
 
@@ -569,6 +826,12 @@ impl<T: Storage<Data>> Controller for T {
         let old = self._close_factor_mantissa();
         self._set_close_factor_mantissa(new_close_factor_mantissa)?;
         self._emit_new_close_factor_event(old, new_close_factor_mantissa);
+        self._emit_admin_action_event(
+            selector_bytes!("Controller::set_close_factor_mantissa"),
+            None,
+            old.encode(),
+            new_close_factor_mantissa.encode(),
+        );
         Ok(())
     }
 
@@ -580,13 +843,26 @@ impl<T: Storage<Data>> Controller for T {
         let old = self._liquidation_incentive_mantissa();
         self._set_liquidation_incentive_mantissa(new_liquidation_incentive_mantissa)?;
         self._emit_new_liquidation_incentive_event(old, new_liquidation_incentive_mantissa);
+        self._emit_admin_action_event(
+            selector_bytes!("Controller::set_liquidation_incentive_mantissa"),
+            None,
+            old.encode(),
+            new_liquidation_incentive_mantissa.encode(),
+        );
         Ok(())
     }
 
     default fn set_borrow_cap(&mut self, pool: AccountId, new_cap: Balance) -> Result<()> {
         self._assert_manager()?;
+        let old = self._borrow_cap(pool).unwrap_or_default();
         self._set_borrow_cap(&pool, new_cap)?;
         self._emit_new_borrow_cap_event(pool, new_cap);
+        self._emit_admin_action_event(
+            selector_bytes!("Controller::set_borrow_cap"),
+            Some(pool),
+            old.encode(),
+            new_cap.encode(),
+        );
         Ok(())
     }
 
@@ -622,6 +898,18 @@ impl<T: Storage<Data>> Controller for T {
         self._transfer_guardian_paused()
     }
 
+    default fn protocol_status(&self) -> ProtocolStatus {
+        self._protocol_status()
+    }
+
+    default fn shutdown_guardian(&self) -> Option<AccountId> {
+        self._shutdown_guardian()
+    }
+
+    default fn pool_factory(&self) -> Option<AccountId> {
+        self._pool_factory()
+    }
+
     default fn oracle(&self) -> Option<AccountId> {
         self._oracle()
     }
@@ -642,6 +930,10 @@ impl<T: Storage<Data>> Controller for T {
         self._manager()
     }
 
+    default fn pending_manager(&self) -> Option<AccountId> {
+        self._pending_manager()
+    }
+
     default fn is_listed(&self, pool: AccountId) -> bool {
         self._is_listed(pool)
     }
@@ -704,6 +996,9 @@ impl<T: Storage<Data>> Internal for T {
         _minter: AccountId,
         _mint_amount: Balance,
     ) -> Result<()> {
+        if self._protocol_status() == ProtocolStatus::Shutdown {
+            return Err(Error::ProtocolIsShutdown)
+        }
         if let Some(true) | None = self._mint_guardian_paused(pool) {
             return Err(Error::MintIsPaused)
         }
@@ -751,8 +1046,7 @@ impl<T: Storage<Data>> Internal for T {
                 borrow_amount: 0,
             });
 
-        // These are safe, as the underflow condition is checked first
-        if sum_collateral < sum_borrow_plus_effect {
+        if SignedExp::difference(sum_collateral, sum_borrow_plus_effect).is_negative() {
             return Err(Error::InsufficientLiquidity)
         }
 
@@ -794,6 +1088,9 @@ impl<T: Storage<Data>> Internal for T {
         borrow_amount: Balance,
         pool_attribute: Option<PoolAttributes>,
     ) -> Result<()> {
+        if self._protocol_status() != ProtocolStatus::Active {
+            return Err(Error::ProtocolIsShutdown)
+        }
         if let Some(true) | None = self._borrow_guardian_paused(pool) {
             return Err(Error::BorrowIsPaused)
         }
@@ -965,6 +1262,22 @@ impl<T: Storage<Data>> Internal for T {
         Ok(()) // do nothing
     }
 
+    default fn _absorb_allowed(&self, pool: AccountId, borrower: AccountId) -> Result<()> {
+        if !self._is_listed(pool) {
+            return Err(Error::MarketNotListed)
+        }
+        for market in self._markets() {
+            if market == pool {
+                continue // NOTE: the pool checks the balance by itself
+            }
+            // NOTE: the snapshot hides supply not used as collateral, so check the raw pool tokens
+            if PoolRef::principal_balance_of(&market, borrower) > 0 {
+                return Err(Error::BorrowerHasCollateral)
+            }
+        }
+        Ok(())
+    }
+
     default fn _transfer_allowed(
         &self,
         pool: AccountId,
@@ -973,6 +1286,9 @@ impl<T: Storage<Data>> Internal for T {
         transfer_tokens: Balance,
         pool_attribute: Option<PoolAttributes>,
     ) -> Result<()> {
+        if self._protocol_status() == ProtocolStatus::Shutdown {
+            return Err(Error::ProtocolIsShutdown)
+        }
         if self._transfer_guardian_paused() {
             return Err(Error::TransferIsPaused)
         }
@@ -1003,42 +1319,10 @@ impl<T: Storage<Data>> Internal for T {
         pool_borrowed_attributes: Option<PoolAttributesForSeizeCalculation>,
         pool_collateral_attributes: Option<PoolAttributesForSeizeCalculation>,
     ) -> Result<Balance> {
-        let oracle = self._oracle().ok_or(Error::OracleIsNotSet)?;
         let (price_borrowed_mantissa, pool_decimals_borrowed) =
-            if let Some(attrs) = pool_borrowed_attributes {
-                let underlying = attrs.underlying.ok_or(Error::UnderlyingIsNotSet)?;
-                (
-                    PriceOracleRef::get_price(&oracle, underlying).ok_or(Error::PriceError)?,
-                    attrs.decimals,
-                )
-            } else {
-                (
-                    PriceOracleRef::get_underlying_price(&oracle, pool_borrowed)
-                        .ok_or(Error::PriceError)?,
-                    PoolRef::token_decimals(&pool_borrowed),
-                )
-            };
-        if price_borrowed_mantissa == 0 {
-            return Err(Error::PriceError)
-        }
-
+            self._underlying_price_and_decimals(pool_borrowed, pool_borrowed_attributes)?;
         let (price_collateral_mantissa, pool_decimals_collateral) =
-            if let Some(attrs) = pool_collateral_attributes {
-                let underlying = attrs.underlying.ok_or(Error::UnderlyingIsNotSet)?;
-                (
-                    PriceOracleRef::get_price(&oracle, underlying).ok_or(Error::PriceError)?,
-                    attrs.decimals,
-                )
-            } else {
-                (
-                    PriceOracleRef::get_underlying_price(&oracle, pool_collateral)
-                        .ok_or(Error::PriceError)?,
-                    PoolRef::token_decimals(&pool_collateral),
-                )
-            };
-        if price_collateral_mantissa == 0 {
-            return Err(Error::PriceError)
-        }
+            self._underlying_price_and_decimals(pool_collateral, pool_collateral_attributes)?;
 
         let result = liquidate_calculate_seize_tokens(&LiquidateCalculateSeizeTokensInput {
             price_borrowed_mantissa: U256::from(price_borrowed_mantissa),
@@ -1048,11 +1332,66 @@ impl<T: Storage<Data>> Internal for T {
             exchange_rate_mantissa: exchange_rate_mantissa.into(),
             liquidation_incentive_mantissa: self._liquidation_incentive_mantissa().into(),
             actual_repay_amount: repay_amount,
-        });
+        })?;
 
         Ok(result)
     }
 
+    default fn _liquidate_max_repay_amount(
+        &self,
+        pool_borrowed: AccountId,
+        pool_collateral: AccountId,
+        exchange_rate_mantissa: WrappedU256,
+        borrow_balance: Balance,
+        collateral_tokens: Balance,
+        pool_borrowed_attributes: Option<PoolAttributesForSeizeCalculation>,
+        pool_collateral_attributes: Option<PoolAttributesForSeizeCalculation>,
+    ) -> Result<Balance> {
+        if !self._is_listed(pool_borrowed) || !self._is_listed(pool_collateral) {
+            return Err(Error::MarketNotListed)
+        }
+        let (price_borrowed_mantissa, pool_decimals_borrowed) =
+            self._underlying_price_and_decimals(pool_borrowed, pool_borrowed_attributes)?;
+        let (price_collateral_mantissa, pool_decimals_collateral) =
+            self._underlying_price_and_decimals(pool_collateral, pool_collateral_attributes)?;
+
+        liquidate_max_repay_amount(&LiquidateMaxRepayAmountInput {
+            price_borrowed_mantissa: U256::from(price_borrowed_mantissa),
+            decimals_borrowed: pool_decimals_borrowed,
+            price_collateral_mantissa: U256::from(price_collateral_mantissa),
+            decimals_collateral: pool_decimals_collateral,
+            exchange_rate_mantissa: exchange_rate_mantissa.into(),
+            liquidation_incentive_mantissa: self._liquidation_incentive_mantissa().into(),
+            close_factor_mantissa: self._close_factor_mantissa().into(),
+            borrow_balance,
+            collateral_tokens,
+        })
+    }
+
+    default fn _underlying_price_and_decimals(
+        &self,
+        pool: AccountId,
+        pool_attributes: Option<PoolAttributesForSeizeCalculation>,
+    ) -> Result<(u128, u8)> {
+        let oracle = self._oracle().ok_or(Error::OracleIsNotSet)?;
+        let (price, decimals) = if let Some(attrs) = pool_attributes {
+            let underlying = attrs.underlying.ok_or(Error::UnderlyingIsNotSet)?;
+            (
+                PriceOracleRef::get_price(&oracle, underlying).ok_or(Error::PriceError)?,
+                attrs.decimals,
+            )
+        } else {
+            (
+                PriceOracleRef::get_underlying_price(&oracle, pool).ok_or(Error::PriceError)?,
+                PoolRef::token_decimals(&pool),
+            )
+        };
+        if price == 0 {
+            return Err(Error::PriceError)
+        }
+        Ok((price, decimals))
+    }
+
     default fn _assert_manager(&self) -> Result<()> {
         let manager = self._manager().ok_or(Error::ManagerIsNotSet)?;
         if Self::env().caller() != manager {
@@ -1062,6 +1401,19 @@ impl<T: Storage<Data>> Internal for T {
         Ok(())
     }
 
+    default fn _assert_market_lister(&self) -> Result<()> {
+        if Some(Self::env().caller()) != self._pool_factory() {
+            return self._assert_manager()
+        }
+
+        Ok(())
+    }
+
+    default fn _set_manager(&mut self, new_manager: AccountId) -> Result<()> {
+        self.data().manager = Some(new_manager);
+        Ok(())
+    }
+
     default fn _set_price_oracle(&mut self, new_oracle: AccountId) -> Result<()> {
         self.data().oracle = Some(new_oracle);
         Ok(())
@@ -1103,10 +1455,7 @@ impl<T: Storage<Data>> Internal for T {
         pool: &AccountId,
         new_collateral_factor_mantissa: WrappedU256,
     ) -> Result<()> {
-        let new_collateral_factor_mantissa_u256 = U256::from(new_collateral_factor_mantissa);
-        if new_collateral_factor_mantissa_u256.is_zero()
-            || new_collateral_factor_mantissa_u256.gt(&collateral_factor_max_mantissa())
-        {
+        if !is_valid_collateral_factor(new_collateral_factor_mantissa.into()) {
             return Err(Error::InvalidCollateralFactor)
         }
 
@@ -1146,10 +1495,29 @@ impl<T: Storage<Data>> Internal for T {
         Ok(())
     }
 
+    default fn _set_shutdown_guardian(&mut self, guardian: Option<AccountId>) -> Result<()> {
+        self.data().shutdown_guardian = guardian;
+        Ok(())
+    }
+
+    default fn _set_pool_factory(&mut self, pool_factory: Option<AccountId>) -> Result<()> {
+        self.data().pool_factory = pool_factory;
+        Ok(())
+    }
+
+    default fn _set_protocol_status(&mut self, status: ProtocolStatus) -> Result<()> {
+        self.data().protocol_status = status;
+        self._emit_protocol_status_changed_event(status);
+        Ok(())
+    }
+
     default fn _set_close_factor_mantissa(
         &mut self,
         new_close_factor_mantissa: WrappedU256,
     ) -> Result<()> {
+        if !is_valid_close_factor(new_close_factor_mantissa.into()) {
+            return Err(Error::InvalidCloseFactor)
+        }
         self.data().close_factor_mantissa = new_close_factor_mantissa;
         Ok(())
     }
@@ -1158,6 +1526,9 @@ impl<T: Storage<Data>> Internal for T {
         &mut self,
         new_liquidation_incentive_mantissa: WrappedU256,
     ) -> Result<()> {
+        if !is_valid_liquidation_incentive(new_liquidation_incentive_mantissa.into()) {
+            return Err(Error::InvalidLiquidationIncentive)
+        }
         self.data().liquidation_incentive_mantissa = new_liquidation_incentive_mantissa;
         Ok(())
     }
@@ -1208,6 +1579,18 @@ impl<T: Storage<Data>> Internal for T {
         self.data().transfer_guardian_paused
     }
 
+    default fn _protocol_status(&self) -> ProtocolStatus {
+        self.data().protocol_status
+    }
+
+    default fn _shutdown_guardian(&self) -> Option<AccountId> {
+        self.data().shutdown_guardian
+    }
+
+    default fn _pool_factory(&self) -> Option<AccountId> {
+        self.data().pool_factory
+    }
+
     default fn _oracle(&self) -> Option<AccountId> {
         self.data().oracle
     }
@@ -1228,6 +1611,10 @@ impl<T: Storage<Data>> Internal for T {
         self.data().manager
     }
 
+    default fn _pending_manager(&self) -> Option<AccountId> {
+        self.data().pending_manager
+    }
+
     default fn _account_assets(
         &self,
         account: AccountId,
@@ -1275,14 +1662,8 @@ impl<T: Storage<Data>> Internal for T {
                 borrow_amount,
             });
 
-        // These are safe, as the underflow condition is checked first
-        let value = if sum_collateral > sum_borrow_plus_effect {
-            (sum_collateral.sub(sum_borrow_plus_effect), U256::from(0))
-        } else {
-            (U256::from(0), sum_borrow_plus_effect.sub(sum_collateral))
-        };
-
-        Ok(value)
+        // (liquidity, shortfall)
+        Ok(SignedExp::difference(sum_collateral, sum_borrow_plus_effect).split())
     }
 
     default fn _calculate_user_account_data(
@@ -1553,4 +1934,15 @@ impl<T: Storage<Data>> Internal for T {
     default fn _emit_new_liquidation_incentive_event(&self, _old: WrappedU256, _new: WrappedU256) {}
 
     default fn _emit_new_borrow_cap_event(&self, _pool: AccountId, _new: Balance) {}
+
+    default fn _emit_protocol_status_changed_event(&self, _status: ProtocolStatus) {}
+
+    default fn _emit_admin_action_event(
+        &self,
+        _action: ActionId,
+        _target: Option<AccountId>,
+        _old_value: Vec<u8>,
+        _new_value: Vec<u8>,
+    ) {
+    }
 }
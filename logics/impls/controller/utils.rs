@@ -37,7 +37,9 @@ pub struct LiquidateCalculateSeizeTokensInput {
 }
 
 /// Calculate seize value when liquidation
-pub fn liquidate_calculate_seize_tokens(input: &LiquidateCalculateSeizeTokensInput) -> Balance {
+pub fn liquidate_calculate_seize_tokens(
+    input: &LiquidateCalculateSeizeTokensInput,
+) -> Result<Balance> {
     let numerator = Exp {
         mantissa: WrappedU256::from(input.liquidation_incentive_mantissa),
     }
@@ -56,7 +58,41 @@ pub fn liquidate_calculate_seize_tokens(input: &LiquidateCalculateSeizeTokensInp
             .mul(U256::from(10).pow(input.decimals_collateral.into()))
             .div(U256::from(10).pow(input.decimals_borrowed.into())),
     );
-    seize_tokens.as_u128()
+    Balance::try_from(seize_tokens).map_err(|_| Error::MathOverflow)
+}
+
+pub struct LiquidateMaxRepayAmountInput {
+    pub price_borrowed_mantissa: U256,
+    pub decimals_borrowed: u8,
+    pub price_collateral_mantissa: U256,
+    pub decimals_collateral: u8,
+    pub exchange_rate_mantissa: U256,
+    pub liquidation_incentive_mantissa: U256,
+    pub close_factor_mantissa: U256,
+    pub borrow_balance: Balance,
+    pub collateral_tokens: Balance,
+}
+
+/// Calculate the largest repay amount allowed by the close factor and the borrower's collateral
+pub fn liquidate_max_repay_amount(input: &LiquidateMaxRepayAmountInput) -> Result<Balance> {
+    let max_close = Exp {
+        mantissa: WrappedU256::from(input.close_factor_mantissa),
+    }
+    .mul_scalar_truncate(U256::from(input.borrow_balance));
+    if input.liquidation_incentive_mantissa.is_zero() || input.price_borrowed_mantissa.is_zero() {
+        return Balance::try_from(max_close).map_err(|_| Error::MathOverflow)
+    }
+
+    // inverse of liquidate_calculate_seize_tokens, rounded down so that seize tokens never exceed collateral
+    let max_by_collateral = U256::from(input.collateral_tokens)
+        .mul(input.price_collateral_mantissa)
+        .div(input.price_borrowed_mantissa)
+        .mul(input.exchange_rate_mantissa)
+        .div(input.liquidation_incentive_mantissa)
+        .mul(U256::from(10).pow(input.decimals_borrowed.into()))
+        .div(U256::from(10).pow(input.decimals_collateral.into()));
+
+    Balance::try_from(max_close.min(max_by_collateral)).map_err(|_| Error::MathOverflow)
 }
 
 #[derive(Clone, Debug)]
@@ -102,12 +138,6 @@ pub fn balance_decrease_allowed(param: BalanceDecreaseAllowedParam) -> bool {
     health_factor_after_decrease >= U256::from(HEALTH_FACTOR_LIQUIDATION_THRESHOLD)
 }
 
-/// Maximum value of Collateral Factor
-pub fn collateral_factor_max_mantissa() -> U256 {
-    // 90%
-    exp_scale().mul(U256::from(90)).div(U256::from(100))
-}
-
 #[derive(Debug)]
 pub struct GetHypotheticalAccountLiquidityInput {
     pub asset_params: Vec<HypotheticalAccountLiquidityCalculationParam>,
@@ -372,7 +402,7 @@ mod tests {
             },
         ];
         for case in cases {
-            let got = liquidate_calculate_seize_tokens(case.input);
+            let got = liquidate_calculate_seize_tokens(case.input).unwrap();
             //  seize_amount = actual_repay_amount * liquidation_incentive * price_borrowed / price_collateral
 
             //  seize_tokens = seize_amount / exchange_rate
@@ -389,6 +419,81 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_liquidate_calculate_seize_tokens_overflow() {
+        let input = LiquidateCalculateSeizeTokensInput {
+            price_borrowed_mantissa: mts(1),
+            decimals_borrowed: 0,
+            price_collateral_mantissa: mts(1),
+            decimals_collateral: 0,
+            exchange_rate_mantissa: mts(1),
+            liquidation_incentive_mantissa: mts(1),
+            actual_repay_amount: u128::MAX,
+        };
+        assert_eq!(liquidate_calculate_seize_tokens(&input), Ok(u128::MAX));
+        // seizing more tokens than u128 can hold fails instead of truncating
+        let input = LiquidateCalculateSeizeTokensInput {
+            liquidation_incentive_mantissa: mts(2),
+            ..input
+        };
+        assert_eq!(
+            liquidate_calculate_seize_tokens(&input),
+            Err(Error::MathOverflow)
+        );
+    }
+
+    #[test]
+    fn test_liquidate_max_repay_amount() {
+        let base = LiquidateMaxRepayAmountInput {
+            price_borrowed_mantissa: mts(1),
+            decimals_borrowed: 18,
+            price_collateral_mantissa: mts(2),
+            decimals_collateral: 18,
+            exchange_rate_mantissa: mts(1),
+            liquidation_incentive_mantissa: mts(108).div(U256::from(100)),
+            close_factor_mantissa: mts(50).div(U256::from(100)),
+            borrow_balance: 1000,
+            collateral_tokens: 10000,
+        };
+        // limited by close factor
+        assert_eq!(liquidate_max_repay_amount(&base), Ok(500));
+
+        // limited by collateral: 135 tokens * 2 / 1.08 = 250
+        let input = LiquidateMaxRepayAmountInput {
+            collateral_tokens: 135,
+            ..base
+        };
+        let got = liquidate_max_repay_amount(&input).unwrap();
+        assert_eq!(got, 250);
+        let seize_tokens = liquidate_calculate_seize_tokens(&LiquidateCalculateSeizeTokensInput {
+            price_borrowed_mantissa: input.price_borrowed_mantissa,
+            decimals_borrowed: input.decimals_borrowed,
+            price_collateral_mantissa: input.price_collateral_mantissa,
+            decimals_collateral: input.decimals_collateral,
+            exchange_rate_mantissa: input.exchange_rate_mantissa,
+            liquidation_incentive_mantissa: input.liquidation_incentive_mantissa,
+            actual_repay_amount: got,
+        })
+        .unwrap();
+        assert!(seize_tokens <= input.collateral_tokens);
+
+        // no collateral
+        let input = LiquidateMaxRepayAmountInput {
+            collateral_tokens: 0,
+            ..base
+        };
+        assert_eq!(liquidate_max_repay_amount(&input), Ok(0));
+
+        // close amount not fitting u128
+        let input = LiquidateMaxRepayAmountInput {
+            close_factor_mantissa: mts(2),
+            borrow_balance: u128::MAX,
+            liquidation_incentive_mantissa: U256::zero(),
+            ..base
+        };
+        assert_eq!(liquidate_max_repay_amount(&input), Err(Error::MathOverflow));
+    }
+
     #[test]
     fn test_get_hypothetical_account_liquidity_per_asset() {
         let mantissa = 10_u128.pow(18);
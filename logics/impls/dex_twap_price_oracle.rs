@@ -0,0 +1,263 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::price_oracle::normalize_price;
+pub use crate::traits::{
+    dex_twap_price_oracle::*,
+    price_oracle::*,
+};
+use crate::traits::{
+    pool::PoolRef,
+    types::WrappedU256,
+};
+use core::ops::{
+    Div,
+    Shl,
+    Shr,
+};
+use openbrush::{
+    contracts::ownable::{
+        self,
+        only_owner,
+    },
+    modifiers,
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Storage,
+        Timestamp,
+    },
+};
+use primitive_types::U256;
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Debug, Default)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// Pair pricing each asset
+    pub configs: Mapping<AccountId, TwapConfig>,
+    /// Observation the current window of each asset started at
+    pub observations: Mapping<AccountId, TwapObservation>,
+}
+
+/// Fractional bits of UQ112x112 prices of the pair
+const RESOLUTION: usize = 112;
+
+/// Cumulative price of the pair as of `now`, including the time since its last update
+pub fn current_price_cumulative(
+    price_cumulative_last: U256,
+    reserve_base: u128,
+    reserve_quote: u128,
+    last_update: Timestamp,
+    now: Timestamp,
+) -> U256 {
+    if reserve_base == 0 || now <= last_update {
+        return price_cumulative_last
+    }
+    let price = U256::from(reserve_quote)
+        .shl(RESOLUTION)
+        .div(U256::from(reserve_base));
+    // cumulative prices are expected to overflow, as in the pair
+    price_cumulative_last
+        .overflowing_add(price.overflowing_mul(U256::from(now - last_update)).0)
+        .0
+}
+
+/// Average price between two cumulative prices, per whole asset with `PRICE_PRECISION`
+pub fn twap_price(
+    price_cumulative_start: U256,
+    price_cumulative_end: U256,
+    elapsed: Timestamp,
+    base_decimals: u8,
+    quote_decimals: u8,
+) -> Option<u128> {
+    if elapsed == 0 {
+        return None
+    }
+    let average = price_cumulative_end
+        .overflowing_sub(price_cumulative_start)
+        .0
+        .div(U256::from(elapsed));
+    // quote units per whole asset, i.e. a price with the decimals of the quote token
+    let price = average
+        .checked_mul(U256::from(10).checked_pow(U256::from(base_decimals))?)?
+        .shr(RESOLUTION);
+    if price > U256::from(u128::MAX) {
+        return None
+    }
+    normalize_price(price.as_u128(), quote_decimals)
+}
+
+pub trait Internal {
+    fn _get_price(&self, asset: AccountId) -> Option<u128>;
+    fn _get_underlying_price(&self, pool: AccountId) -> Option<u128>;
+    fn _set_twap_config(&mut self, asset: AccountId, config: TwapConfig) -> Result<()>;
+    fn _update(&mut self, asset: AccountId) -> Result<()>;
+    fn _current_price_cumulative(&self, config: &TwapConfig) -> U256;
+
+    // event emission
+    fn _emit_twap_updated_event(&self, asset: AccountId, price: u128);
+}
+
+impl<T: Storage<Data> + Storage<ownable::Data>> DexTwapPriceOracle for T {
+    #[modifiers(only_owner)]
+    default fn set_twap_config(&mut self, asset: AccountId, config: TwapConfig) -> Result<()> {
+        self._set_twap_config(asset, config)
+    }
+
+    default fn update(&mut self, asset: AccountId) -> Result<()> {
+        self._update(asset)
+    }
+
+    default fn twap_config(&self, asset: AccountId) -> Option<TwapConfig> {
+        self.data::<Data>().configs.get(&asset)
+    }
+
+    default fn observation(&self, asset: AccountId) -> Option<TwapObservation> {
+        self.data::<Data>().observations.get(&asset)
+    }
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _get_price(&self, asset: AccountId) -> Option<u128> {
+        match self.data::<Data>().observations.get(&asset)?.price {
+            0 => None,
+            price => Some(price),
+        }
+    }
+
+    default fn _get_underlying_price(&self, pool: AccountId) -> Option<u128> {
+        let underlying = PoolRef::underlying(&pool)?;
+        self._get_price(underlying)
+    }
+
+    default fn _set_twap_config(&mut self, asset: AccountId, config: TwapConfig) -> Result<()> {
+        if config.window == 0 {
+            return Err(Error::InvalidTwapConfig)
+        }
+        let observation = TwapObservation {
+            price_cumulative: WrappedU256::from(self._current_price_cumulative(&config)),
+            timestamp: Self::env().block_timestamp(),
+            price: 0,
+        };
+        let data = self.data::<Data>();
+        data.configs.insert(&asset, &config);
+        data.observations.insert(&asset, &observation);
+        Ok(())
+    }
+
+    default fn _update(&mut self, asset: AccountId) -> Result<()> {
+        let config = self
+            .data::<Data>()
+            .configs
+            .get(&asset)
+            .ok_or(Error::TwapConfigIsNotSet)?;
+        let observation = self
+            .data::<Data>()
+            .observations
+            .get(&asset)
+            .ok_or(Error::TwapConfigIsNotSet)?;
+        let now = Self::env().block_timestamp();
+        let elapsed = now.saturating_sub(observation.timestamp);
+        if elapsed < config.window {
+            return Err(Error::TwapWindowNotElapsed)
+        }
+        let price_cumulative = self._current_price_cumulative(&config);
+        let price = twap_price(
+            observation.price_cumulative.into(),
+            price_cumulative,
+            elapsed,
+            config.base_decimals,
+            config.quote_decimals,
+        )
+        .unwrap_or_default();
+        self.data::<Data>().observations.insert(
+            &asset,
+            &TwapObservation {
+                price_cumulative: WrappedU256::from(price_cumulative),
+                timestamp: now,
+                price,
+            },
+        );
+        self._emit_twap_updated_event(asset, price);
+        Ok(())
+    }
+
+    default fn _current_price_cumulative(&self, config: &TwapConfig) -> U256 {
+        let (reserve_0, reserve_1, last_update) = PairRef::get_reserves(&config.pair);
+        let now = Self::env().block_timestamp();
+        if config.is_token_0 {
+            current_price_cumulative(
+                PairRef::price_0_cumulative_last(&config.pair).into(),
+                reserve_0,
+                reserve_1,
+                last_update,
+                now,
+            )
+        } else {
+            current_price_cumulative(
+                PairRef::price_1_cumulative_last(&config.pair).into(),
+                reserve_1,
+                reserve_0,
+                last_update,
+                now,
+            )
+        }
+    }
+
+    default fn _emit_twap_updated_event(&self, _asset: AccountId, _price: u128) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::impls::price_oracle::PRICE_PRECISION;
+    use core::ops::Mul;
+
+    fn uq112x112(numerator: u128, denominator: u128) -> U256 {
+        U256::from(numerator)
+            .shl(RESOLUTION)
+            .div(U256::from(denominator))
+    }
+
+    #[test]
+    fn test_current_price_cumulative() {
+        let last = U256::from(1_000);
+        // unchanged without elapsed time or reserves
+        assert_eq!(current_price_cumulative(last, 1, 2, 10, 10), last);
+        assert_eq!(current_price_cumulative(last, 0, 2, 10, 20), last);
+        // 2 quote per base for 10ms
+        assert_eq!(
+            current_price_cumulative(last, 100, 200, 10, 20),
+            last + uq112x112(2, 1).mul(U256::from(10))
+        );
+    }
+
+    #[test]
+    fn test_twap_price() {
+        // 1.5 quote units per base unit over 100ms
+        let start = U256::from(12345);
+        let end = start + uq112x112(3, 2).mul(U256::from(100));
+        assert_eq!(
+            twap_price(start, end, 100, 18, 18),
+            Some(PRICE_PRECISION * 3 / 2)
+        );
+        // a whole base of 18 decimals is 1.5e12 quote units of 6 decimals
+        assert_eq!(
+            twap_price(start, end, 100, 18, 6),
+            Some(PRICE_PRECISION * 3 / 2 * 10_u128.pow(12))
+        );
+        assert_eq!(twap_price(start, end, 0, 18, 18), None);
+        // cumulative prices wrapping around are handled
+        let start = U256::MAX - U256::from(10);
+        let end = start
+            .overflowing_add(uq112x112(1, 1).mul(U256::from(100)))
+            .0;
+        assert_eq!(twap_price(start, end, 100, 18, 18), Some(PRICE_PRECISION));
+    }
+}
@@ -0,0 +1,124 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::price_oracle::{
+    is_stale,
+    normalize_price,
+};
+use crate::traits::pool::PoolRef;
+pub use crate::traits::{
+    dia_price_oracle::*,
+    price_oracle::*,
+};
+use ink::prelude::string::String;
+use openbrush::{
+    contracts::ownable::{
+        self,
+        only_owner,
+    },
+    modifiers,
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Storage,
+        Timestamp,
+    },
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Debug, Default)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// DIA's price feed contract
+    pub dia_oracle: Option<AccountId>,
+    /// Feed of DIA for each asset
+    pub feeds: Mapping<AccountId, DIAFeed>,
+}
+
+pub trait Internal {
+    fn _get_price(&self, asset: AccountId) -> Option<u128>;
+    fn _get_underlying_price(&self, pool: AccountId) -> Option<u128>;
+    fn _set_dia_oracle(&mut self, dia_oracle: AccountId) -> Result<()>;
+    fn _set_feed(&mut self, asset: AccountId, feed: DIAFeed) -> Result<()>;
+    fn _remove_feed(&mut self, asset: AccountId) -> Result<()>;
+}
+
+impl<T: Storage<Data> + Storage<ownable::Data>> DIAPriceOracle for T {
+    #[modifiers(only_owner)]
+    default fn set_dia_oracle(&mut self, dia_oracle: AccountId) -> Result<()> {
+        self._set_dia_oracle(dia_oracle)
+    }
+
+    #[modifiers(only_owner)]
+    default fn set_feed(
+        &mut self,
+        asset: AccountId,
+        key: String,
+        decimals: u8,
+        heartbeat: Timestamp,
+    ) -> Result<()> {
+        self._set_feed(
+            asset,
+            DIAFeed {
+                key,
+                decimals,
+                heartbeat,
+            },
+        )
+    }
+
+    #[modifiers(only_owner)]
+    default fn remove_feed(&mut self, asset: AccountId) -> Result<()> {
+        self._remove_feed(asset)
+    }
+
+    default fn dia_oracle(&self) -> Option<AccountId> {
+        self.data::<Data>().dia_oracle
+    }
+
+    default fn feed(&self, asset: AccountId) -> Option<DIAFeed> {
+        self.data::<Data>().feeds.get(&asset)
+    }
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _get_price(&self, asset: AccountId) -> Option<u128> {
+        let dia_oracle = self.data::<Data>().dia_oracle?;
+        let feed = self.data::<Data>().feeds.get(&asset)?;
+        let (updated_at, price) = TokenPriceStorageRef::get_latest_price(&dia_oracle, feed.key)?;
+        // DIA's timestamps are in seconds
+        if is_stale(
+            updated_at.saturating_mul(1000),
+            feed.heartbeat,
+            Self::env().block_timestamp(),
+        ) {
+            return None
+        }
+        normalize_price(price, feed.decimals)
+    }
+
+    default fn _get_underlying_price(&self, pool: AccountId) -> Option<u128> {
+        let underlying = PoolRef::underlying(&pool)?;
+        self._get_price(underlying)
+    }
+
+    default fn _set_dia_oracle(&mut self, dia_oracle: AccountId) -> Result<()> {
+        self.data::<Data>().dia_oracle = Some(dia_oracle);
+        Ok(())
+    }
+
+    default fn _set_feed(&mut self, asset: AccountId, feed: DIAFeed) -> Result<()> {
+        self.data::<Data>().feeds.insert(&asset, &feed);
+        Ok(())
+    }
+
+    default fn _remove_feed(&mut self, asset: AccountId) -> Result<()> {
+        self.data::<Data>().feeds.remove(&asset);
+        Ok(())
+    }
+}
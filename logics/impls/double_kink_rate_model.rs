@@ -0,0 +1,122 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::interest_rate_model::{
+    base,
+    double_kink_rate,
+    milliseconds_per_year,
+    supply_rate,
+    utilization_rate,
+};
+pub use crate::traits::double_kink_rate_model::*;
+use crate::traits::types::WrappedU256;
+use core::ops::{
+    Div,
+    Sub,
+};
+use openbrush::traits::{
+    Balance,
+    Storage,
+};
+use primitive_types::U256;
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Debug, Default)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// Borrow rate per milliseconds at zero utilization
+    pub base_rate_per_milli_second: WrappedU256,
+    /// Slopes of the borrow rate per milliseconds in each utilization range
+    pub multipliers_per_milli_second: [WrappedU256; 3],
+    /// Utilization rate above which the second slope applies
+    pub kink_1: WrappedU256,
+    /// Utilization rate above which the third slope applies
+    pub kink_2: WrappedU256,
+}
+
+impl Data {
+    pub fn new(
+        base_rate_per_year: WrappedU256,
+        multipliers_per_year: [WrappedU256; 3],
+        kink_1: WrappedU256,
+        kink_2: WrappedU256,
+    ) -> Self {
+        if U256::from(kink_1).gt(&U256::from(kink_2)) || U256::from(kink_2).gt(&base()) {
+            panic!("kinks are not ordered");
+        }
+        let to_milli_seconds_func = |val: WrappedU256| -> WrappedU256 {
+            WrappedU256::from(U256::from(val).div(milliseconds_per_year()))
+        };
+        Self {
+            base_rate_per_milli_second: to_milli_seconds_func(base_rate_per_year),
+            multipliers_per_milli_second: multipliers_per_year.map(to_milli_seconds_func),
+            kink_1,
+            kink_2,
+        }
+    }
+}
+
+pub trait Internal {
+    fn _get_borrow_rate(&self, cash: Balance, borrows: Balance, reserves: Balance) -> WrappedU256;
+    fn _get_supply_rate(
+        &self,
+        cash: Balance,
+        borrows: Balance,
+        reserves: Balance,
+        reserve_factor_mantissa: WrappedU256,
+    ) -> WrappedU256;
+}
+
+impl<T: Storage<Data>> DoubleKinkRateModel for T {
+    default fn base_rate_per_milli_second(&self) -> WrappedU256 {
+        self.data::<Data>().base_rate_per_milli_second
+    }
+
+    default fn multipliers_per_milli_second(&self) -> [WrappedU256; 3] {
+        self.data::<Data>().multipliers_per_milli_second
+    }
+
+    default fn kinks(&self) -> (WrappedU256, WrappedU256) {
+        let data = self.data::<Data>();
+        (data.kink_1, data.kink_2)
+    }
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _get_borrow_rate(
+        &self,
+        cash: Balance,
+        borrows: Balance,
+        reserves: Balance,
+    ) -> WrappedU256 {
+        let data = self.data::<Data>();
+        WrappedU256::from(double_kink_rate(
+            utilization_rate(cash, borrows, reserves),
+            data.base_rate_per_milli_second.into(),
+            data.multipliers_per_milli_second.map(U256::from),
+            data.kink_1.into(),
+            data.kink_2.into(),
+        ))
+    }
+
+    default fn _get_supply_rate(
+        &self,
+        cash: Balance,
+        borrows: Balance,
+        reserves: Balance,
+        reserve_factor_mantissa: WrappedU256,
+    ) -> WrappedU256 {
+        let one_minus_reserve_factor = base().sub(U256::from(reserve_factor_mantissa));
+        let borrow_rate = self._get_borrow_rate(cash, borrows, reserves);
+        WrappedU256::from(supply_rate(
+            utilization_rate(cash, borrows, reserves),
+            borrow_rate.into(),
+            one_minus_reserve_factor,
+        ))
+    }
+}
@@ -0,0 +1,315 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::interest_rate_model::{
+    base,
+    milliseconds_per_year,
+    supply_rate,
+    utilization_rate,
+};
+pub use crate::traits::dynamic_rate_model::*;
+use crate::traits::{
+    pool::PoolRef,
+    types::WrappedU256,
+};
+use core::ops::{
+    Add,
+    Div,
+    Mul,
+    Sub,
+};
+use openbrush::{
+    contracts::ownable::{
+        self,
+        only_owner,
+    },
+    modifiers,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+        Timestamp,
+    },
+};
+use primitive_types::U256;
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Debug, Default)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// Pool whose utilization drives the multiplier
+    pub pool: Option<AccountId>,
+    /// Borrow rate per milliseconds at zero utilization
+    pub base_rate_per_milli_second: WrappedU256,
+    /// Multiplier per milliseconds as of `last_update`
+    pub multiplier_per_milli_second: WrappedU256,
+    /// Lower bound of the multiplier per milliseconds
+    pub min_multiplier_per_milli_second: WrappedU256,
+    /// Upper bound of the multiplier per milliseconds
+    pub max_multiplier_per_milli_second: WrappedU256,
+    /// Utilization the multiplier is driven toward
+    pub target_utilization: WrappedU256,
+    /// Relative change of the multiplier per milliseconds at full distance from the target
+    pub adjustment_speed: WrappedU256,
+    /// Last time the multiplier was stored
+    pub last_update: Timestamp,
+}
+
+pub struct AdjustedMultiplierInput {
+    pub multiplier: U256,
+    pub min_multiplier: U256,
+    pub max_multiplier: U256,
+    pub utilization_rate: U256,
+    pub target_utilization: U256,
+    pub adjustment_speed: U256,
+    pub elapsed: Timestamp,
+}
+
+/// Multiplier after adjusting toward the target utilization for `elapsed` milliseconds
+///
+/// The distance from the target is normalized to the range on its side, so that
+/// full utilization and zero utilization both move the multiplier at full speed.
+pub fn adjusted_multiplier(input: &AdjustedMultiplierInput) -> U256 {
+    let target = input.target_utilization;
+    let (distance, range, rising) = if input.utilization_rate > target {
+        (input.utilization_rate.sub(target), base().sub(target), true)
+    } else {
+        (target.sub(input.utilization_rate), target, false)
+    };
+    if distance.is_zero() || range.is_zero() || input.elapsed == 0 {
+        return input
+            .multiplier
+            .max(input.min_multiplier)
+            .min(input.max_multiplier)
+    }
+    let change = input
+        .multiplier
+        .mul(input.adjustment_speed)
+        .div(base())
+        .mul(distance)
+        .div(range)
+        .mul(U256::from(input.elapsed));
+    let multiplier = if rising {
+        input.multiplier.saturating_add(change)
+    } else {
+        input.multiplier.saturating_sub(change)
+    };
+    multiplier
+        .max(input.min_multiplier)
+        .min(input.max_multiplier)
+}
+
+impl Data {
+    pub fn new(
+        base_rate_per_year: WrappedU256,
+        multiplier_per_year: WrappedU256,
+        min_multiplier_per_year: WrappedU256,
+        max_multiplier_per_year: WrappedU256,
+        target_utilization: WrappedU256,
+        adjustment_speed: WrappedU256,
+        now: Timestamp,
+    ) -> Self {
+        let to_milli_seconds_func = |val: WrappedU256| -> WrappedU256 {
+            WrappedU256::from(U256::from(val).div(milliseconds_per_year()))
+        };
+        Self {
+            pool: None,
+            base_rate_per_milli_second: to_milli_seconds_func(base_rate_per_year),
+            multiplier_per_milli_second: to_milli_seconds_func(multiplier_per_year),
+            min_multiplier_per_milli_second: to_milli_seconds_func(min_multiplier_per_year),
+            max_multiplier_per_milli_second: to_milli_seconds_func(max_multiplier_per_year),
+            target_utilization,
+            adjustment_speed,
+            last_update: now,
+        }
+    }
+}
+
+pub trait Internal {
+    fn _current_multiplier(&self, utilization_rate: U256) -> U256;
+    fn _store_multiplier(&mut self, utilization_rate: U256);
+    fn _get_borrow_rate(&self, cash: Balance, borrows: Balance, reserves: Balance) -> WrappedU256;
+    fn _get_supply_rate(
+        &self,
+        cash: Balance,
+        borrows: Balance,
+        reserves: Balance,
+        reserve_factor_mantissa: WrappedU256,
+    ) -> WrappedU256;
+
+    // event emission
+    fn _emit_new_multiplier_event(&self, multiplier_per_milli_second: WrappedU256);
+}
+
+impl<T: Storage<Data> + Storage<ownable::Data>> DynamicRateModel for T {
+    default fn update_multiplier(&mut self) -> Result<()> {
+        let pool = self.data::<Data>().pool.ok_or(Error::PoolIsNotSet)?;
+        let utilization = utilization_rate(
+            PoolRef::get_cash_prior(&pool),
+            PoolRef::total_borrows(&pool),
+            PoolRef::total_reserves(&pool),
+        );
+        self._store_multiplier(utilization);
+        Ok(())
+    }
+
+    #[modifiers(only_owner)]
+    default fn set_pool(&mut self, pool: AccountId) -> Result<()> {
+        self.data::<Data>().pool = Some(pool);
+        Ok(())
+    }
+
+    #[modifiers(only_owner)]
+    default fn set_controller_params(
+        &mut self,
+        target_utilization: WrappedU256,
+        adjustment_speed: WrappedU256,
+    ) -> Result<()> {
+        let target = U256::from(target_utilization);
+        if target.is_zero() || target.ge(&base()) {
+            return Err(Error::InvalidTargetUtilization)
+        }
+        let data = self.data::<Data>();
+        data.target_utilization = target_utilization;
+        data.adjustment_speed = adjustment_speed;
+        Ok(())
+    }
+
+    default fn pool(&self) -> Option<AccountId> {
+        self.data::<Data>().pool
+    }
+
+    default fn multiplier_per_milli_second(&self) -> WrappedU256 {
+        self.data::<Data>().multiplier_per_milli_second
+    }
+
+    default fn current_multiplier_per_milli_second(
+        &self,
+        utilization_rate: WrappedU256,
+    ) -> WrappedU256 {
+        self._current_multiplier(utilization_rate.into()).into()
+    }
+
+    default fn target_utilization(&self) -> WrappedU256 {
+        self.data::<Data>().target_utilization
+    }
+
+    default fn adjustment_speed(&self) -> WrappedU256 {
+        self.data::<Data>().adjustment_speed
+    }
+
+    default fn last_update(&self) -> Timestamp {
+        self.data::<Data>().last_update
+    }
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _current_multiplier(&self, utilization_rate: U256) -> U256 {
+        let data = self.data::<Data>();
+        adjusted_multiplier(&AdjustedMultiplierInput {
+            multiplier: data.multiplier_per_milli_second.into(),
+            min_multiplier: data.min_multiplier_per_milli_second.into(),
+            max_multiplier: data.max_multiplier_per_milli_second.into(),
+            utilization_rate,
+            target_utilization: data.target_utilization.into(),
+            adjustment_speed: data.adjustment_speed.into(),
+            elapsed: Self::env()
+                .block_timestamp()
+                .saturating_sub(data.last_update),
+        })
+    }
+
+    default fn _store_multiplier(&mut self, utilization_rate: U256) {
+        let multiplier = WrappedU256::from(self._current_multiplier(utilization_rate));
+        let data = self.data::<Data>();
+        data.multiplier_per_milli_second = multiplier;
+        data.last_update = Self::env().block_timestamp();
+        self._emit_new_multiplier_event(multiplier);
+    }
+
+    default fn _get_borrow_rate(
+        &self,
+        cash: Balance,
+        borrows: Balance,
+        reserves: Balance,
+    ) -> WrappedU256 {
+        let util = utilization_rate(cash, borrows, reserves);
+        let multiplier = self._current_multiplier(util);
+        WrappedU256::from(
+            util.mul(multiplier)
+                .div(base())
+                .add(U256::from(self.data::<Data>().base_rate_per_milli_second)),
+        )
+    }
+
+    default fn _get_supply_rate(
+        &self,
+        cash: Balance,
+        borrows: Balance,
+        reserves: Balance,
+        reserve_factor_mantissa: WrappedU256,
+    ) -> WrappedU256 {
+        let one_minus_reserve_factor = base().sub(U256::from(reserve_factor_mantissa));
+        let borrow_rate = self._get_borrow_rate(cash, borrows, reserves);
+        WrappedU256::from(supply_rate(
+            utilization_rate(cash, borrows, reserves),
+            borrow_rate.into(),
+            one_minus_reserve_factor,
+        ))
+    }
+
+    default fn _emit_new_multiplier_event(&self, _multiplier_per_milli_second: WrappedU256) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn percent(val: u128) -> U256 {
+        base().mul(U256::from(val)).div(U256::from(100))
+    }
+
+    fn input(utilization_rate: U256, elapsed: Timestamp) -> AdjustedMultiplierInput {
+        AdjustedMultiplierInput {
+            multiplier: base(),
+            min_multiplier: base().div(10),
+            max_multiplier: base().mul(10),
+            utilization_rate,
+            target_utilization: percent(80),
+            // 1% per ms at full distance
+            adjustment_speed: percent(1),
+            elapsed,
+        }
+    }
+
+    #[test]
+    fn test_adjusted_multiplier() {
+        // at target or without elapsed time the multiplier is unchanged
+        assert_eq!(adjusted_multiplier(&input(percent(80), 100)), base());
+        assert_eq!(adjusted_multiplier(&input(percent(100), 0)), base());
+        // full utilization rises at full speed: +1% * 10ms
+        assert_eq!(
+            adjusted_multiplier(&input(percent(100), 10)),
+            base().add(percent(10))
+        );
+        // half way below the target falls at half speed: -0.5% * 10ms
+        assert_eq!(
+            adjusted_multiplier(&input(percent(40), 10)),
+            base().sub(percent(5))
+        );
+        // bounded by min and max
+        assert_eq!(
+            adjusted_multiplier(&input(percent(100), 100_000)),
+            base().mul(10)
+        );
+        assert_eq!(
+            adjusted_multiplier(&input(percent(0), 100_000)),
+            base().div(10)
+        );
+    }
+}
@@ -0,0 +1,339 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+pub use crate::traits::evm_migrator::*;
+use crate::{
+    impls::pool::xvm,
+    traits::{
+        controller::ControllerRef,
+        flashloan_gateway::{
+            FlashLoanType,
+            FlashloanGatewayRef,
+        },
+        pool::{
+            PoolRef,
+            Underlying,
+        },
+    },
+};
+use ink::{
+    env::hash::Keccak256,
+    prelude::{
+        vec,
+        vec::Vec,
+    },
+};
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+    },
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+/// Selectors of the EVM lending pool functions
+const SELECTOR_REPAY: [u8; 4] = [0x57, 0x3a, 0xde, 0x81];
+const SELECTOR_WITHDRAW: [u8; 4] = [0x69, 0x32, 0x8d, 0xec];
+
+/// Interest rate mode of variable borrows in the EVM lending pool
+const RATE_MODE_VARIABLE: u128 = 2;
+
+#[derive(Debug, Default)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// AccountId of Controller
+    pub controller: Option<AccountId>,
+    /// AccountId of Flashloan Gateway
+    pub flashloan_gateway: Option<AccountId>,
+    /// Address of the lending pool of the EVM deployment
+    pub evm_lending_pool: Option<EvmAddress>,
+    /// Number of migrations done from each EVM account
+    pub nonces: Mapping<EvmAddress, u64>,
+}
+
+pub trait Internal {
+    fn _initialize(
+        &mut self,
+        controller: AccountId,
+        flashloan_gateway: AccountId,
+        evm_lending_pool: EvmAddress,
+    );
+    fn _controller(&self) -> Option<AccountId>;
+    fn _flashloan_gateway(&self) -> Option<AccountId>;
+    fn _evm_lending_pool(&self) -> Option<EvmAddress>;
+    fn _migration_nonce(&self, evm_account: EvmAddress) -> u64;
+    fn _migration_digest(&self, evm_account: EvmAddress, account: AccountId) -> [u8; 32];
+    fn _verify_signature(
+        &self,
+        evm_account: EvmAddress,
+        account: AccountId,
+        signature: [u8; 65],
+    ) -> Result<()>;
+    fn _evm_token_of(&self, pool: AccountId) -> Result<EvmAddress>;
+    fn _repay_evm_debt(
+        &mut self,
+        evm_account: EvmAddress,
+        debt_token: EvmAddress,
+        amount: Balance,
+    ) -> Result<()>;
+    fn _migrate_collaterals(
+        &mut self,
+        account: AccountId,
+        evm_account: EvmAddress,
+        collaterals: Vec<CollateralMigration>,
+    ) -> Result<()>;
+    fn _execute_operation(
+        &mut self,
+        assets: Vec<AccountId>,
+        amounts: Vec<Balance>,
+        premiums: Vec<Balance>,
+        initiator: AccountId,
+        params: Vec<u8>,
+    ) -> Result<()>;
+}
+
+impl<T: Storage<Data>> EvmMigrator for T {
+    default fn controller(&self) -> Option<AccountId> {
+        self._controller()
+    }
+
+    default fn flashloan_gateway(&self) -> Option<AccountId> {
+        self._flashloan_gateway()
+    }
+
+    default fn evm_lending_pool(&self) -> Option<EvmAddress> {
+        self._evm_lending_pool()
+    }
+
+    default fn migration_nonce(&self, evm_account: EvmAddress) -> u64 {
+        self._migration_nonce(evm_account)
+    }
+
+    default fn migration_digest(&self, evm_account: EvmAddress, account: AccountId) -> [u8; 32] {
+        self._migration_digest(evm_account, account)
+    }
+
+    default fn migrate(
+        &mut self,
+        evm_account: EvmAddress,
+        signature: [u8; 65],
+        debt_pool: AccountId,
+        debt_amount: Balance,
+        collaterals: Vec<CollateralMigration>,
+    ) -> Result<()> {
+        if debt_amount == 0 && collaterals.is_empty() {
+            return Err(Error::NothingToMigrate)
+        }
+        let caller = Self::env().caller();
+        self._verify_signature(evm_account, caller, signature)?;
+        let nonce = self._migration_nonce(evm_account);
+        self.data().nonces.insert(&evm_account, &(nonce + 1));
+
+        if debt_amount == 0 {
+            return self._migrate_collaterals(caller, evm_account, collaterals)
+        }
+
+        let flashloan_gateway = self
+            ._flashloan_gateway()
+            .ok_or(Error::FlashloanGatewayIsNotSet)?;
+        let debt_token = self._evm_token_of(debt_pool)?;
+        let debt_asset = PoolRef::underlying(&debt_pool).ok_or(Error::UnderlyingIsNotEvmErc20)?;
+        let params = MigrationParams {
+            account: caller,
+            evm_account,
+            debt_token,
+            collaterals,
+        }
+        .encode();
+        // the debt is recreated here as a borrow of caller, so nothing is paid back
+        FlashloanGatewayRef::flashloan_builder(
+            &flashloan_gateway,
+            Self::env().account_id(),
+            vec![debt_asset],
+            vec![debt_amount],
+            vec![FlashLoanType::Borrowing as u8],
+            caller,
+            params,
+        )
+        .call_flags(ink_env::CallFlags::default().set_allow_reentry(true))
+        .try_invoke()
+        .unwrap()
+        .unwrap()?;
+        Ok(())
+    }
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _initialize(
+        &mut self,
+        controller: AccountId,
+        flashloan_gateway: AccountId,
+        evm_lending_pool: EvmAddress,
+    ) {
+        self.data().controller = Some(controller);
+        self.data().flashloan_gateway = Some(flashloan_gateway);
+        self.data().evm_lending_pool = Some(evm_lending_pool);
+    }
+
+    default fn _controller(&self) -> Option<AccountId> {
+        self.data().controller
+    }
+
+    default fn _flashloan_gateway(&self) -> Option<AccountId> {
+        self.data().flashloan_gateway
+    }
+
+    default fn _evm_lending_pool(&self) -> Option<EvmAddress> {
+        self.data().evm_lending_pool
+    }
+
+    default fn _migration_nonce(&self, evm_account: EvmAddress) -> u64 {
+        self.data().nonces.get(&evm_account).unwrap_or(0)
+    }
+
+    default fn _migration_digest(&self, evm_account: EvmAddress, account: AccountId) -> [u8; 32] {
+        let input = (
+            Self::env().account_id(),
+            evm_account,
+            account,
+            self._migration_nonce(evm_account),
+        )
+            .encode();
+        let mut output = [0_u8; 32];
+        ink::env::hash_bytes::<Keccak256>(&input, &mut output);
+        output
+    }
+
+    default fn _verify_signature(
+        &self,
+        evm_account: EvmAddress,
+        account: AccountId,
+        signature: [u8; 65],
+    ) -> Result<()> {
+        // `personal_sign` prefixes the signed hash
+        let mut message = b"\x19Ethereum Signed Message:\n32".to_vec();
+        message.extend_from_slice(&self._migration_digest(evm_account, account));
+        let mut message_hash = [0_u8; 32];
+        ink::env::hash_bytes::<Keccak256>(&message, &mut message_hash);
+
+        let pubkey = Self::env()
+            .ecdsa_recover(&signature, &message_hash)
+            .map_err(|_| Error::InvalidSignature)?;
+        let signer = Self::env()
+            .ecdsa_to_eth_address(&pubkey)
+            .map_err(|_| Error::InvalidSignature)?;
+        if signer != evm_account {
+            return Err(Error::InvalidSignature)
+        }
+        Ok(())
+    }
+
+    default fn _evm_token_of(&self, pool: AccountId) -> Result<EvmAddress> {
+        let controller = self._controller().ok_or(Error::ControllerIsNotSet)?;
+        if !ControllerRef::is_listed(&controller, pool) {
+            return Err(Error::MarketNotListed)
+        }
+        match PoolRef::underlying_asset(&pool) {
+            Some(Underlying::EvmErc20(token)) => Ok(token),
+            _ => Err(Error::UnderlyingIsNotEvmErc20),
+        }
+    }
+
+    default fn _repay_evm_debt(
+        &mut self,
+        evm_account: EvmAddress,
+        debt_token: EvmAddress,
+        amount: Balance,
+    ) -> Result<()> {
+        let lending_pool = self
+            ._evm_lending_pool()
+            .ok_or(Error::EvmLendingPoolIsNotSet)?;
+        xvm::approve(debt_token, lending_pool, amount).map_err(|_| Error::EvmCallFailed)?;
+
+        let mut input = SELECTOR_REPAY.to_vec();
+        xvm::encode_address(&mut input, &debt_token);
+        xvm::encode_uint(&mut input, amount);
+        xvm::encode_uint(&mut input, RATE_MODE_VARIABLE);
+        xvm::encode_address(&mut input, &evm_account);
+        xvm::call(lending_pool, input).map_err(|_| Error::EvmCallFailed)?;
+        Ok(())
+    }
+
+    default fn _migrate_collaterals(
+        &mut self,
+        account: AccountId,
+        evm_account: EvmAddress,
+        collaterals: Vec<CollateralMigration>,
+    ) -> Result<()> {
+        let lending_pool = self
+            ._evm_lending_pool()
+            .ok_or(Error::EvmLendingPoolIsNotSet)?;
+        let contract_address = xvm::to_evm_address(&Self::env().account_id());
+        for collateral in collaterals {
+            let token = self._evm_token_of(collateral.pool)?;
+            // lTokens are pulled first, as only their holder can withdraw them
+            xvm::transfer_from_address(
+                collateral.l_token,
+                evm_account,
+                contract_address,
+                collateral.amount,
+            )
+            .map_err(|_| Error::EvmCallFailed)?;
+
+            let mut input = SELECTOR_WITHDRAW.to_vec();
+            xvm::encode_address(&mut input, &token);
+            xvm::encode_uint(&mut input, collateral.amount);
+            xvm::encode_address(&mut input, &contract_address);
+            xvm::call(lending_pool, input).map_err(|_| Error::EvmCallFailed)?;
+
+            xvm::approve(
+                token,
+                xvm::to_evm_address(&collateral.pool),
+                collateral.amount,
+            )
+            .map_err(|_| Error::EvmCallFailed)?;
+            PoolRef::mint_to(&collateral.pool, account, collateral.amount)?;
+        }
+        Ok(())
+    }
+
+    default fn _execute_operation(
+        &mut self,
+        assets: Vec<AccountId>,
+        amounts: Vec<Balance>,
+        _premiums: Vec<Balance>,
+        initiator: AccountId,
+        params: Vec<u8>,
+    ) -> Result<()> {
+        let flashloan_gateway = self
+            ._flashloan_gateway()
+            .ok_or(Error::FlashloanGatewayIsNotSet)?;
+        if Self::env().caller() != flashloan_gateway {
+            return Err(Error::CallerIsNotFlashloanGateway)
+        }
+        // only flashloans initiated by this contract carry migrations to run
+        if initiator != Self::env().account_id() || assets.len() != 1 {
+            return Err(Error::InvalidFlashloanParams)
+        }
+        let MigrationParams {
+            account,
+            evm_account,
+            debt_token,
+            collaterals,
+        } = MigrationParams::decode(&mut &params[..]).map_err(|_| Error::InvalidFlashloanParams)?;
+
+        self._repay_evm_debt(evm_account, debt_token, amounts[0])?;
+        self._migrate_collaterals(account, evm_account, collaterals)
+    }
+}
@@ -32,6 +32,24 @@ pub fn exp_scale() -> U256 {
 pub fn half_exp_scale() -> U256 {
     exp_scale().div(2)
 }
+
+pub fn double_scale() -> U256 {
+    U256::from(10_u128.pow(36))
+}
+
+pub fn half_double_scale() -> U256 {
+    double_scale().div(2)
+}
+/// `a / b` rounded up
+pub fn div_ceil(a: U256, b: U256) -> U256 {
+    let (quotient, remainder) = a.div_mod(b);
+    if remainder.is_zero() {
+        quotient
+    } else {
+        quotient.add(U256::one())
+    }
+}
+
 fn mantissa_one() -> U256 {
     exp_scale()
 }
@@ -42,6 +60,19 @@ pub struct Exp {
 }
 
 impl Exp {
+    pub fn one() -> Exp {
+        Exp {
+            mantissa: WrappedU256::from(mantissa_one()),
+        }
+    }
+
+    /// `num / denom` scaled by 1e18, rounded down
+    pub fn from_ratio(num: U256, denom: U256) -> Exp {
+        Exp {
+            mantissa: WrappedU256::from(num.mul(exp_scale()).div(denom)),
+        }
+    }
+
     pub fn add(&self, a: Exp) -> Exp {
         self._op(a, |o, v| o.add(v))
     }
@@ -67,6 +98,46 @@ impl Exp {
     pub fn div(&self, another: Exp) -> Exp {
         self._op(another, |o, v| o.mul(exp_scale()).div(v))
     }
+
+    pub fn div_scalar(&self, scalar: U256) -> Exp {
+        Exp {
+            mantissa: WrappedU256::from(U256::from(self.mantissa).div(scalar)),
+        }
+    }
+
+    /// `scalar / divisor` as an Exp, rounded down
+    pub fn div_scalar_by_exp(scalar: U256, divisor: Exp) -> Exp {
+        Exp {
+            mantissa: WrappedU256::from(
+                scalar
+                    .mul(exp_scale())
+                    .mul(exp_scale())
+                    .div(U256::from(divisor.mantissa)),
+            ),
+        }
+    }
+
+    /// `1 / self`, rounded down
+    pub fn reciprocal(&self) -> Exp {
+        Exp::div_scalar_by_exp(U256::one(), self.clone())
+    }
+
+    /// `self ^ exponent` by squaring, each product rounded down
+    pub fn pow(&self, exponent: u32) -> Exp {
+        let mut result = Exp::one();
+        let mut base = self.clone();
+        let mut exponent = exponent;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = result.mul(base.clone());
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base.mul(base.clone());
+            }
+        }
+        result
+    }
     pub fn mul_scalar_truncate(&self, scalar: U256) -> U256 {
         let product = self.mul_scalar(scalar);
         product._trunc()
@@ -74,6 +145,12 @@ impl Exp {
     pub fn mul_scalar_truncate_add_uint(&self, scalar: U256, addend: U256) -> U256 {
         self.mul_scalar_truncate(scalar).add(addend)
     }
+    pub fn mul_scalar_ceil(&self, scalar: U256) -> U256 {
+        div_ceil(self.mul_scalar(scalar).mantissa.into(), exp_scale())
+    }
+    pub fn mul_scalar_ceil_add_uint(&self, scalar: U256, addend: U256) -> U256 {
+        self.mul_scalar_ceil(scalar).add(addend)
+    }
 
     fn lt(&self, another: Exp) -> bool {
         self._cmp(another, |a, b| a.lt(&b))
@@ -111,6 +188,51 @@ impl Exp {
     }
 }
 
+/// Fixed-point number scaled by 1e36, for ratios too small for `Exp` such as reward indexes
+#[derive(Clone, Debug)]
+pub struct Double {
+    pub mantissa: WrappedU256,
+}
+
+impl Double {
+    /// `num / denom` scaled by 1e36, rounded down
+    pub fn from_ratio(num: U256, denom: U256) -> Double {
+        Double {
+            mantissa: WrappedU256::from(num.mul(double_scale()).div(denom)),
+        }
+    }
+
+    pub fn add(&self, another: Double) -> Double {
+        self._op(another, |o, v| o.add(v))
+    }
+
+    pub fn sub(&self, another: Double) -> Double {
+        self._op(another, |o, v| o.sub(v))
+    }
+
+    pub fn mul(&self, another: Double) -> Double {
+        self._op(another, |o, v| o.mul(v).div(double_scale()))
+    }
+
+    pub fn div(&self, another: Double) -> Double {
+        self._op(another, |o, v| o.mul(double_scale()).div(v))
+    }
+
+    pub fn mul_scalar_truncate(&self, scalar: U256) -> U256 {
+        U256::from(self.mantissa).mul(scalar).div(double_scale())
+    }
+
+    pub fn truncate(&self) -> U256 {
+        U256::from(self.mantissa).div(double_scale())
+    }
+
+    fn _op(&self, a: Double, op: fn(one: U256, another: U256) -> U256) -> Double {
+        Double {
+            mantissa: WrappedU256::from(op(U256::from(self.mantissa), U256::from(a.mantissa))),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
 
@@ -178,4 +300,119 @@ mod tests {
         };
         assert_eq!(U256::one(), a.truncate())
     }
+    #[test]
+    fn test_from_ratio() {
+        assert_eq!(
+            U256::from(1_500_000_000_000_000_000_u128),
+            Exp::from_ratio(U256::from(3), U256::from(2))
+                .mantissa
+                .into()
+        );
+        assert_eq!(
+            U256::from(333_333_333_333_333_333_u128),
+            Exp::from_ratio(U256::from(1), U256::from(3))
+                .mantissa
+                .into()
+        );
+    }
+    #[test]
+    fn test_div_scalar() {
+        let a = Exp {
+            mantissa: WrappedU256::from(U256::from(10).mul(exp_scale())),
+        };
+        assert_eq!(
+            U256::from(20).mul(exp_scale()),
+            a.div_scalar(U256::from(2))
+                .mul_scalar(U256::from(4))
+                .mantissa
+                .into()
+        );
+    }
+    #[test]
+    fn test_div_scalar_by_exp() {
+        let a = Exp {
+            mantissa: WrappedU256::from(U256::from(4).mul(exp_scale())),
+        };
+        assert_eq!(
+            U256::from(2_500_000_000_000_000_000_u128),
+            Exp::div_scalar_by_exp(U256::from(10), a).mantissa.into()
+        );
+    }
+    #[test]
+    fn test_reciprocal() {
+        let a = Exp {
+            mantissa: WrappedU256::from(U256::from(4).mul(exp_scale())),
+        };
+        assert_eq!(
+            U256::from(250_000_000_000_000_000_u128),
+            a.reciprocal().mantissa.into()
+        );
+        let b = Exp {
+            mantissa: WrappedU256::from(U256::from(3).mul(exp_scale())),
+        };
+        assert_eq!(
+            U256::from(333_333_333_333_333_333_u128),
+            b.reciprocal().mantissa.into()
+        );
+    }
+    #[test]
+    fn test_pow() {
+        let a = Exp {
+            mantissa: WrappedU256::from(U256::from(2).mul(exp_scale())),
+        };
+        assert_eq!(exp_scale(), a.pow(0).mantissa.into());
+        assert_eq!(U256::from(2).mul(exp_scale()), a.pow(1).mantissa.into());
+        assert_eq!(U256::from(1024).mul(exp_scale()), a.pow(10).mantissa.into());
+        // 1.1 ^ 3 = 1.331
+        let b = Exp::from_ratio(U256::from(11), U256::from(10));
+        assert_eq!(
+            U256::from(1_331_000_000_000_000_000_u128),
+            b.pow(3).mantissa.into()
+        );
+    }
+    #[test]
+    fn test_double() {
+        let a = Double::from_ratio(U256::from(1), U256::from(3));
+        assert_eq!(U256::from(10_u128.pow(36) / 3), a.mantissa.into());
+        // keeps precision an Exp would lose on tiny ratios
+        let tiny = Double::from_ratio(U256::from(1), U256::from(10_u128.pow(24)));
+        assert_eq!(U256::from(10_u128.pow(12)), tiny.mantissa.into());
+        assert_eq!(
+            U256::from(10_u128.pow(6)),
+            tiny.mul_scalar_truncate(U256::from(10_u128.pow(30)))
+        );
+        let two = Double::from_ratio(U256::from(2), U256::one());
+        assert_eq!(U256::from(2), two.truncate());
+        assert_eq!(U256::from(4), two.mul(two.clone()).truncate());
+        assert_eq!(U256::one(), two.div(two.clone()).truncate());
+        assert_eq!(
+            U256::from(3),
+            two.add(Double::from_ratio(U256::one(), U256::one()))
+                .truncate()
+        );
+        assert_eq!(
+            U256::one(),
+            two.sub(Double::from_ratio(U256::one(), U256::one()))
+                .truncate()
+        );
+    }
+
+    #[test]
+    fn test_div_ceil() {
+        assert_eq!(U256::zero(), div_ceil(U256::zero(), U256::from(3)));
+        assert_eq!(U256::from(2), div_ceil(U256::from(6), U256::from(3)));
+        assert_eq!(U256::from(3), div_ceil(U256::from(7), U256::from(3)));
+    }
+    #[test]
+    fn test_mul_scalar_ceil() {
+        let third = Exp::from_ratio(U256::one(), U256::from(3));
+        assert_eq!(U256::from(3), third.mul_scalar_truncate(U256::from(10)));
+        assert_eq!(U256::from(4), third.mul_scalar_ceil(U256::from(10)));
+        assert_eq!(
+            U256::from(14),
+            third.mul_scalar_ceil_add_uint(U256::from(10), U256::from(10))
+        );
+        let two = Exp::from_ratio(U256::from(2), U256::one());
+        assert_eq!(U256::from(20), two.mul_scalar_ceil(U256::from(10)));
+    }
 }
@@ -0,0 +1,102 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::interest_rate_model::{
+    base,
+    milliseconds_per_year,
+    supply_rate,
+    utilization_rate,
+};
+pub use crate::traits::fixed_rate_model::*;
+use crate::traits::types::WrappedU256;
+use core::ops::{
+    Div,
+    Sub,
+};
+use openbrush::{
+    contracts::ownable::{
+        self,
+        only_owner,
+    },
+    modifiers,
+    traits::{
+        Balance,
+        Storage,
+    },
+};
+use primitive_types::U256;
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Debug, Default)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// Borrow rate per milliseconds
+    pub borrow_rate_per_milli_second: WrappedU256,
+}
+
+pub trait Internal {
+    fn _set_borrow_rate(&mut self, borrow_rate_per_year: WrappedU256);
+    fn _get_borrow_rate(&self, cash: Balance, borrows: Balance, reserves: Balance) -> WrappedU256;
+    fn _get_supply_rate(
+        &self,
+        cash: Balance,
+        borrows: Balance,
+        reserves: Balance,
+        reserve_factor_mantissa: WrappedU256,
+    ) -> WrappedU256;
+
+    // event emission
+    fn _emit_new_borrow_rate_event(&self, borrow_rate_per_milli_second: WrappedU256);
+}
+
+impl<T: Storage<Data> + Storage<ownable::Data>> FixedRateModel for T {
+    #[modifiers(only_owner)]
+    default fn set_borrow_rate(&mut self, borrow_rate_per_year: WrappedU256) -> Result<()> {
+        self._set_borrow_rate(borrow_rate_per_year);
+        Ok(())
+    }
+
+    default fn borrow_rate_per_milli_second(&self) -> WrappedU256 {
+        self.data::<Data>().borrow_rate_per_milli_second
+    }
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _set_borrow_rate(&mut self, borrow_rate_per_year: WrappedU256) {
+        let rate = WrappedU256::from(U256::from(borrow_rate_per_year).div(milliseconds_per_year()));
+        self.data::<Data>().borrow_rate_per_milli_second = rate;
+        self._emit_new_borrow_rate_event(rate);
+    }
+
+    default fn _get_borrow_rate(
+        &self,
+        _cash: Balance,
+        _borrows: Balance,
+        _reserves: Balance,
+    ) -> WrappedU256 {
+        self.data::<Data>().borrow_rate_per_milli_second
+    }
+
+    default fn _get_supply_rate(
+        &self,
+        cash: Balance,
+        borrows: Balance,
+        reserves: Balance,
+        reserve_factor_mantissa: WrappedU256,
+    ) -> WrappedU256 {
+        let one_minus_reserve_factor = base().sub(U256::from(reserve_factor_mantissa));
+        let borrow_rate = self._get_borrow_rate(cash, borrows, reserves);
+        WrappedU256::from(supply_rate(
+            utilization_rate(cash, borrows, reserves),
+            borrow_rate.into(),
+            one_minus_reserve_factor,
+        ))
+    }
+
+    default fn _emit_new_borrow_rate_event(&self, _borrow_rate_per_milli_second: WrappedU256) {}
+}
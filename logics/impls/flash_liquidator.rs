@@ -0,0 +1,229 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+pub use crate::traits::flash_liquidator::*;
+use crate::traits::{
+    controller::ControllerRef,
+    dex_router::DexRouterRef,
+    flashloan_gateway::{
+        FlashLoanType,
+        FlashloanGatewayRef,
+    },
+    pool::PoolRef,
+};
+use ink::prelude::{
+    vec,
+    vec::Vec,
+};
+use openbrush::{
+    contracts::psp22::PSP22Ref,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+    },
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Debug, Default)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// AccountId of Controller
+    pub controller: Option<AccountId>,
+    /// AccountId of Flashloan Gateway
+    pub flashloan_gateway: Option<AccountId>,
+    /// AccountId of DEX Router
+    pub dex_router: Option<AccountId>,
+}
+
+pub trait Internal {
+    fn _initialize(
+        &mut self,
+        controller: AccountId,
+        flashloan_gateway: AccountId,
+        dex_router: AccountId,
+    );
+    fn _controller(&self) -> Option<AccountId>;
+    fn _flashloan_gateway(&self) -> Option<AccountId>;
+    fn _dex_router(&self) -> Option<AccountId>;
+    fn _pool_of(&self, asset: AccountId) -> Result<AccountId>;
+    fn _execute_operation(
+        &mut self,
+        assets: Vec<AccountId>,
+        amounts: Vec<Balance>,
+        premiums: Vec<Balance>,
+        initiator: AccountId,
+        params: Vec<u8>,
+    ) -> Result<()>;
+}
+
+impl<T: Storage<Data>> FlashLiquidator for T {
+    default fn controller(&self) -> Option<AccountId> {
+        self._controller()
+    }
+
+    default fn flashloan_gateway(&self) -> Option<AccountId> {
+        self._flashloan_gateway()
+    }
+
+    default fn dex_router(&self) -> Option<AccountId> {
+        self._dex_router()
+    }
+
+    default fn liquidate(
+        &mut self,
+        borrower: AccountId,
+        repay_asset: AccountId,
+        repay_amount: Balance,
+        collateral_asset: AccountId,
+        path: Vec<AccountId>,
+        min_profit: Balance,
+    ) -> Result<()> {
+        let valid_path = if collateral_asset == repay_asset {
+            path.is_empty()
+        } else {
+            path.len() >= 2 && path[0] == collateral_asset && path[path.len() - 1] == repay_asset
+        };
+        if !valid_path {
+            return Err(Error::InvalidSwapPath)
+        }
+        let flashloan_gateway = self
+            ._flashloan_gateway()
+            .ok_or(Error::FlashloanGatewayIsNotSet)?;
+
+        let caller = Self::env().caller();
+        let contract_addr = Self::env().account_id();
+        let params = LiquidationParams {
+            account: caller,
+            borrower,
+            collateral_asset,
+            path,
+            min_profit,
+        }
+        .encode();
+        FlashloanGatewayRef::flashloan_builder(
+            &flashloan_gateway,
+            contract_addr,
+            vec![repay_asset],
+            vec![repay_amount],
+            vec![FlashLoanType::None as u8],
+            caller,
+            params,
+        )
+        .call_flags(ink_env::CallFlags::default().set_allow_reentry(true))
+        .try_invoke()
+        .unwrap()
+        .unwrap()?;
+        Ok(())
+    }
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _initialize(
+        &mut self,
+        controller: AccountId,
+        flashloan_gateway: AccountId,
+        dex_router: AccountId,
+    ) {
+        self.data().controller = Some(controller);
+        self.data().flashloan_gateway = Some(flashloan_gateway);
+        self.data().dex_router = Some(dex_router);
+    }
+
+    default fn _controller(&self) -> Option<AccountId> {
+        self.data().controller
+    }
+
+    default fn _flashloan_gateway(&self) -> Option<AccountId> {
+        self.data().flashloan_gateway
+    }
+
+    default fn _dex_router(&self) -> Option<AccountId> {
+        self.data().dex_router
+    }
+
+    default fn _pool_of(&self, asset: AccountId) -> Result<AccountId> {
+        let controller = self._controller().ok_or(Error::ControllerIsNotSet)?;
+        ControllerRef::market_of_underlying(&controller, asset).ok_or(Error::MarketNotListed)
+    }
+
+    default fn _execute_operation(
+        &mut self,
+        assets: Vec<AccountId>,
+        amounts: Vec<Balance>,
+        premiums: Vec<Balance>,
+        initiator: AccountId,
+        params: Vec<u8>,
+    ) -> Result<()> {
+        let flashloan_gateway = self
+            ._flashloan_gateway()
+            .ok_or(Error::FlashloanGatewayIsNotSet)?;
+        if Self::env().caller() != flashloan_gateway {
+            return Err(Error::CallerIsNotFlashloanGateway)
+        }
+        let contract_addr = Self::env().account_id();
+        // only flashloans initiated by this contract carry liquidations to run
+        if initiator != contract_addr || assets.len() != 1 {
+            return Err(Error::InvalidFlashloanParams)
+        }
+        let LiquidationParams {
+            account,
+            borrower,
+            collateral_asset,
+            path,
+            min_profit,
+        } = LiquidationParams::decode(&mut &params[..])
+            .map_err(|_| Error::InvalidFlashloanParams)?;
+
+        let (repay_asset, repay_amount, premium) = (assets[0], amounts[0], premiums[0]);
+        let repay_pool = self._pool_of(repay_asset)?;
+        let collateral_pool = self._pool_of(collateral_asset)?;
+
+        PSP22Ref::approve(&repay_asset, repay_pool, repay_amount)?;
+        PoolRef::liquidate_borrow(&repay_pool, borrower, repay_amount, collateral_pool)?;
+
+        // seized pool tokens are only redeemable once used as collateral
+        let seized = PoolRef::balance_of(&collateral_pool, contract_addr);
+        PoolRef::set_use_reserve_as_collateral(&collateral_pool, true)?;
+        PoolRef::redeem_underlying(&collateral_pool, seized)?;
+
+        let repayment = repay_amount + premium;
+        let mut proceeds = PSP22Ref::balance_of(&repay_asset, contract_addr);
+        if !path.is_empty() {
+            let dex_router = self._dex_router().ok_or(Error::DexRouterIsNotSet)?;
+            let collateral_amount = PSP22Ref::balance_of(&collateral_asset, contract_addr);
+            PSP22Ref::approve(&collateral_asset, dex_router, collateral_amount)?;
+            DexRouterRef::swap_exact_tokens_for_tokens(
+                &dex_router,
+                collateral_amount,
+                repayment + min_profit,
+                path,
+                contract_addr,
+                Self::env().block_timestamp(),
+            )?;
+            proceeds = PSP22Ref::balance_of(&repay_asset, contract_addr);
+        }
+        if proceeds < repayment + min_profit {
+            return Err(Error::InsufficientProfit)
+        }
+
+        PSP22Ref::transfer(
+            &repay_asset,
+            account,
+            proceeds - repayment,
+            Default::default(),
+        )?;
+        // the gateway pulls the repayment from this contract
+        PSP22Ref::approve(&repay_asset, flashloan_gateway, repayment)?;
+        Ok(())
+    }
+}
@@ -9,17 +9,34 @@ pub use crate::traits::flashloan_gateway::*;
 use crate::traits::{
     controller::ControllerRef,
     flashloan_receiver::FlashloanReceiverRef,
-    pool::PoolRef,
+    pool::{
+        PoolRef,
+        Underlying,
+    },
 };
 use ink::prelude::vec::Vec;
 use openbrush::{
-    contracts::psp22::PSP22Ref,
+    contracts::{
+        ownable::{
+            self,
+            only_owner,
+        },
+        psp22::PSP22Ref,
+        reentrancy_guard::{
+            self,
+            non_reentrant,
+        },
+    },
+    modifier_definition,
+    modifiers,
+    storage::Mapping,
     traits::{
         AccountId,
         Balance,
         Storage,
     },
 };
+use primitive_types::U256;
 
 pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
 
@@ -29,8 +46,39 @@ pub struct Data {
     /// Flashloan Fee in percentage * 100.
     /// Default value is 9 = 0.09%
     pub flashloan_premium_total: u128,
+    /// Share of the fee sent to the treasury in percentage * 100 of the fee.
+    pub flashloan_premium_to_protocol: u128,
     /// AccountId of Controller managing Flashloan Gateway
     pub controller: Option<AccountId>,
+    /// AccountId receiving the protocol share of the fee
+    pub treasury: Option<AccountId>,
+    /// Reduced fee of whitelisted receivers and initiators
+    pub premium_exemptions: Mapping<AccountId, u128>,
+    /// AccountId allowed to pause flashloans along with the owner
+    pub guardian: Option<AccountId>,
+    /// Whether all flashloans are paused
+    pub flashloan_paused: bool,
+    /// Assets whose flashloans are disabled
+    pub flashloan_disabled: Mapping<AccountId, bool>,
+    /// Pool lending each underlying asset, synced from the controller
+    pub pools: Mapping<AccountId, AccountId>,
+}
+
+/// Premium of a flashloan of `amount` and its share sent to the treasury
+pub fn flashloan_premiums(
+    amount: Balance,
+    premium_total: u128,
+    premium_to_protocol: u128,
+) -> (Balance, Balance) {
+    // U256 keeps `amount * premium` from wrapping for amounts above u128::MAX / MAX_BPS
+    let premium = U256::from(amount) * U256::from(premium_total) / U256::from(MAX_BPS);
+    let protocol_premium = premium * U256::from(premium_to_protocol) / U256::from(MAX_BPS);
+    (as_balance(premium), as_balance(protocol_premium))
+}
+
+fn as_balance(value: U256) -> Balance {
+    // premium rates are capped at MAX_BPS, so premiums never exceed the amount
+    value.min(U256::from(Balance::MAX)).as_u128()
 }
 
 pub trait Internal {
@@ -38,43 +86,123 @@ pub trait Internal {
 
     // View function
     fn _flashloan_premium_total(&self) -> u128;
+    fn _flashloan_premium_to_protocol(&self) -> u128;
     fn _controller(&self) -> Option<AccountId>;
+    fn _treasury(&self) -> Option<AccountId>;
+
+    fn _set_flashloan_premium_total(&mut self, premium: u128) -> Result<()>;
+    fn _set_flashloan_premium_to_protocol(&mut self, premium_to_protocol: u128) -> Result<()>;
+    fn _set_treasury(&mut self, treasury: AccountId) -> Result<()>;
+    fn _set_premium_exemption(&mut self, account: AccountId, premium: Option<u128>) -> Result<()>;
+    fn _premium_exemption(&self, account: AccountId) -> Option<u128>;
+    fn _flashloan_premium_of(&self, receiver: AccountId, initiator: AccountId) -> u128;
+    fn _set_guardian(&mut self, guardian: AccountId) -> Result<()>;
+    fn _sync_pools(&mut self) -> Result<()>;
+    fn _pool_of(&self, controller: AccountId, asset: AccountId) -> Option<AccountId>;
+    fn _set_flashloan_paused(&mut self, paused: bool) -> Result<()>;
+    fn _set_flashloan_enabled(&mut self, asset: AccountId, enabled: bool) -> Result<()>;
+    fn _flashloan_enabled(&self, asset: AccountId) -> bool;
     // events
     fn _emit_flashloan_event(
         &self,
-        target: AccountId,
+        receiver: AccountId,
         initiator: AccountId,
         asset: AccountId,
         amount: Balance,
         premium: Balance,
+        protocol_premium: Balance,
     );
+    fn _emit_new_flashloan_premium_event(&self, premium_total: u128, premium_to_protocol: u128);
+    fn _emit_premium_exemption_updated_event(&self, account: AccountId, premium: Option<u128>);
+    fn _emit_flashloan_paused_event(&self, paused: bool);
+    fn _emit_pool_registered_event(&self, asset: AccountId, pool: AccountId);
+    fn _emit_flashloan_enabled_updated_event(&self, asset: AccountId, enabled: bool);
+}
+
+#[modifier_definition]
+pub fn only_guardian<T, F, R>(instance: &mut T, body: F) -> Result<R>
+where
+    T: Storage<Data> + Storage<ownable::Data>,
+    F: FnOnce(&mut T) -> Result<R>,
+{
+    let caller = T::env().caller();
+    if instance.data::<Data>().guardian != Some(caller)
+        && instance.data::<ownable::Data>().owner != caller
+    {
+        return Err(Error::CallerIsNotGuardian)
+    }
+
+    body(instance)
 }
 
-impl<T: Storage<Data>> FlashloanGateway for T {
+impl<T: Storage<Data> + Storage<ownable::Data> + Storage<reentrancy_guard::Data>> FlashloanGateway
+    for T
+{
+    #[modifiers(non_reentrant)]
     default fn flashloan(
-        &self,
+        &mut self,
         receiver_address: AccountId,
         assets: Vec<AccountId>,
         amounts: Vec<Balance>,
         mods: Vec<u8>,
-        _on_behalf_of: AccountId,
+        on_behalf_of: AccountId,
         params: Vec<u8>,
     ) -> Result<()> {
-        if assets.len() != amounts.len() {
+        if assets.len() != amounts.len() || assets.len() != mods.len() {
+            return Err(Error::InconsistentFlashloanParams)
+        }
+        if self.data::<Data>().flashloan_paused {
+            return Err(Error::FlashloanPaused)
+        }
+        if !assets.iter().all(|asset| self._flashloan_enabled(*asset)) {
+            return Err(Error::FlashloanDisabled)
+        }
+        // a single balance check per pool must cover the whole amount lent
+        if (1..assets.len()).any(|index| assets[..index].contains(&assets[index])) {
             return Err(Error::InconsistentFlashloanParams)
         }
+        let flashloan_types = mods
+            .into_iter()
+            .map(FlashLoanType::try_from)
+            .collect::<Result<Vec<FlashLoanType>>>()?;
 
         let mut lp_token_addresses: Vec<AccountId> = Default::default();
         let mut premiums: Vec<Balance> = Default::default();
+        let mut protocol_premiums: Vec<Balance> = Default::default();
+        let mut balances_before: Vec<Balance> = Default::default();
 
         let controller = self._controller().ok_or(Error::ControllerIsNotSet)?;
-        let flashloan_premium_total = self._flashloan_premium_total();
+        let caller = Self::env().caller();
+        let flashloan_premium_total = self._flashloan_premium_of(receiver_address, caller);
+        // without treasury, the whole premium goes to the reserves
+        let treasury = self._treasury();
+        let flashloan_premium_to_protocol = match treasury {
+            Some(_) => self._flashloan_premium_to_protocol(),
+            None => 0,
+        };
         for index in 0..assets.len() {
-            let market = ControllerRef::market_of_underlying(&controller, assets[index])
+            let market = self
+                ._pool_of(controller, assets[index])
                 .ok_or(Error::MarketNotListed)?;
             lp_token_addresses.push(market);
-            let premium: u128 = amounts[index] * flashloan_premium_total / 10000;
+            // borrowed amounts pay interest instead of the fee
+            let (premium, protocol_premium) = match flashloan_types[index] {
+                FlashLoanType::None => {
+                    flashloan_premiums(
+                        amounts[index],
+                        flashloan_premium_total,
+                        flashloan_premium_to_protocol,
+                    )
+                }
+                FlashLoanType::Borrowing => (0, 0),
+            };
             premiums.push(premium);
+            protocol_premiums.push(protocol_premium);
+            // only repaid amounts are checked against the balance of the pool
+            balances_before.push(match flashloan_types[index] {
+                FlashLoanType::None => PSP22Ref::balance_of(&assets[index], market),
+                FlashLoanType::Borrowing => 0,
+            });
 
             PoolRef::transfer_underlying(
                 &lp_token_addresses[index],
@@ -83,7 +211,6 @@ impl<T: Storage<Data>> FlashloanGateway for T {
             )?;
         }
 
-        let caller = Self::env().caller();
         let operation_result = FlashloanReceiverRef::execute_operation(
             &receiver_address,
             assets.clone(),
@@ -101,21 +228,46 @@ impl<T: Storage<Data>> FlashloanGateway for T {
             let current_asset = assets[index];
             let current_amount = amounts[index];
             let current_premium = premiums[index];
+            let current_protocol_premium = protocol_premiums[index];
+            let current_pool_premium = current_premium - current_protocol_premium;
             let current_lp_token = lp_token_addresses[index];
-            let current_amount_plus_premium = current_amount + current_premium;
 
-            if mods[index] == FlashLoanType::None as u8 {
+            if flashloan_types[index] == FlashLoanType::None {
                 PoolRef::accrue_interest(&current_lp_token)?;
 
                 PSP22Ref::transfer_from(
                     &current_asset,
                     receiver_address,
                     current_lp_token,
-                    current_amount_plus_premium,
+                    current_amount + current_pool_premium,
                     Vec::<u8>::new(),
                 )?;
+                if let Some(treasury) = treasury.filter(|_| current_protocol_premium > 0) {
+                    PSP22Ref::transfer_from(
+                        &current_asset,
+                        receiver_address,
+                        treasury,
+                        current_protocol_premium,
+                        Vec::<u8>::new(),
+                    )?;
+                }
+                // the receiver may have called the pool back during the operation
+                let balance_after = PSP22Ref::balance_of(&current_asset, current_lp_token);
+                if balance_after < balances_before[index] + current_pool_premium {
+                    return Err(Error::InsufficientFlashloanRepayment)
+                }
+                PoolRef::record_flashloan_repayment(
+                    &current_lp_token,
+                    current_amount,
+                    current_pool_premium,
+                )?;
             } else {
-                PoolRef::borrow_for_flashloan(&current_lp_token, caller, current_amount)?;
+                PoolRef::borrow_for_flashloan(
+                    &current_lp_token,
+                    on_behalf_of,
+                    caller,
+                    current_amount,
+                )?;
             }
 
             self._emit_flashloan_event(
@@ -124,6 +276,7 @@ impl<T: Storage<Data>> FlashloanGateway for T {
                 current_asset,
                 current_amount,
                 current_premium,
+                current_protocol_premium,
             );
         }
 
@@ -134,9 +287,97 @@ impl<T: Storage<Data>> FlashloanGateway for T {
         self._flashloan_premium_total()
     }
 
+    default fn flashloan_premium_to_protocol(&self) -> u128 {
+        self._flashloan_premium_to_protocol()
+    }
+
     default fn controller(&self) -> Option<AccountId> {
         self._controller()
     }
+
+    default fn sync_pools(&mut self) -> Result<()> {
+        self._sync_pools()
+    }
+
+    default fn pool_of(&self, asset: AccountId) -> Option<AccountId> {
+        let controller = self._controller()?;
+        self._pool_of(controller, asset)
+    }
+
+    default fn treasury(&self) -> Option<AccountId> {
+        self._treasury()
+    }
+
+    #[modifiers(only_owner)]
+    default fn set_flashloan_premium_total(&mut self, premium: u128) -> Result<()> {
+        self._set_flashloan_premium_total(premium)?;
+        self._emit_new_flashloan_premium_event(premium, self._flashloan_premium_to_protocol());
+        Ok(())
+    }
+
+    #[modifiers(only_owner)]
+    default fn set_flashloan_premium_to_protocol(
+        &mut self,
+        premium_to_protocol: u128,
+    ) -> Result<()> {
+        self._set_flashloan_premium_to_protocol(premium_to_protocol)?;
+        self._emit_new_flashloan_premium_event(
+            self._flashloan_premium_total(),
+            premium_to_protocol,
+        );
+        Ok(())
+    }
+
+    #[modifiers(only_owner)]
+    default fn set_treasury(&mut self, treasury: AccountId) -> Result<()> {
+        self._set_treasury(treasury)
+    }
+
+    #[modifiers(only_owner)]
+    default fn set_premium_exemption(
+        &mut self,
+        account: AccountId,
+        premium: Option<u128>,
+    ) -> Result<()> {
+        self._set_premium_exemption(account, premium)?;
+        self._emit_premium_exemption_updated_event(account, premium);
+        Ok(())
+    }
+
+    default fn premium_exemption(&self, account: AccountId) -> Option<u128> {
+        self._premium_exemption(account)
+    }
+
+    #[modifiers(only_owner)]
+    default fn set_guardian(&mut self, guardian: AccountId) -> Result<()> {
+        self._set_guardian(guardian)
+    }
+
+    default fn guardian(&self) -> Option<AccountId> {
+        self.data::<Data>().guardian
+    }
+
+    #[modifiers(only_guardian)]
+    default fn set_flashloan_paused(&mut self, paused: bool) -> Result<()> {
+        self._set_flashloan_paused(paused)?;
+        self._emit_flashloan_paused_event(paused);
+        Ok(())
+    }
+
+    default fn flashloan_paused(&self) -> bool {
+        self.data::<Data>().flashloan_paused
+    }
+
+    #[modifiers(only_guardian)]
+    default fn set_flashloan_enabled(&mut self, asset: AccountId, enabled: bool) -> Result<()> {
+        self._set_flashloan_enabled(asset, enabled)?;
+        self._emit_flashloan_enabled_updated_event(asset, enabled);
+        Ok(())
+    }
+
+    default fn flashloan_enabled(&self, asset: AccountId) -> bool {
+        self._flashloan_enabled(asset)
+    }
 }
 
 impl<T: Storage<Data>> Internal for T {
@@ -149,17 +390,184 @@ impl<T: Storage<Data>> Internal for T {
         self.data::<Data>().flashloan_premium_total
     }
 
+    default fn _flashloan_premium_to_protocol(&self) -> u128 {
+        self.data::<Data>().flashloan_premium_to_protocol
+    }
+
     default fn _controller(&self) -> Option<AccountId> {
         self.data::<Data>().controller
     }
 
+    default fn _treasury(&self) -> Option<AccountId> {
+        self.data::<Data>().treasury
+    }
+
+    default fn _set_flashloan_premium_total(&mut self, premium: u128) -> Result<()> {
+        if premium > MAX_BPS {
+            return Err(Error::InvalidFlashloanPremium)
+        }
+        self.data::<Data>().flashloan_premium_total = premium;
+        Ok(())
+    }
+
+    default fn _set_flashloan_premium_to_protocol(
+        &mut self,
+        premium_to_protocol: u128,
+    ) -> Result<()> {
+        if premium_to_protocol > MAX_BPS {
+            return Err(Error::InvalidFlashloanPremium)
+        }
+        self.data::<Data>().flashloan_premium_to_protocol = premium_to_protocol;
+        Ok(())
+    }
+
+    default fn _set_treasury(&mut self, treasury: AccountId) -> Result<()> {
+        self.data::<Data>().treasury = Some(treasury);
+        Ok(())
+    }
+
+    default fn _set_premium_exemption(
+        &mut self,
+        account: AccountId,
+        premium: Option<u128>,
+    ) -> Result<()> {
+        match premium {
+            Some(premium) => {
+                if premium > MAX_BPS {
+                    return Err(Error::InvalidFlashloanPremium)
+                }
+                self.data::<Data>()
+                    .premium_exemptions
+                    .insert(&account, &premium);
+            }
+            None => self.data::<Data>().premium_exemptions.remove(&account),
+        }
+        Ok(())
+    }
+
+    default fn _premium_exemption(&self, account: AccountId) -> Option<u128> {
+        self.data::<Data>().premium_exemptions.get(&account)
+    }
+
+    default fn _set_guardian(&mut self, guardian: AccountId) -> Result<()> {
+        self.data::<Data>().guardian = Some(guardian);
+        Ok(())
+    }
+
+    default fn _sync_pools(&mut self) -> Result<()> {
+        let controller = self._controller().ok_or(Error::ControllerIsNotSet)?;
+        for pool in ControllerRef::markets(&controller) {
+            // only PSP22 underlyings can be lent, other kinds have no token contract
+            if let Some(Underlying::PSP22(asset)) = PoolRef::underlying_asset(&pool) {
+                if self.data::<Data>().pools.get(&asset) != Some(pool) {
+                    self.data::<Data>().pools.insert(&asset, &pool);
+                    self._emit_pool_registered_event(asset, pool);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    default fn _pool_of(&self, controller: AccountId, asset: AccountId) -> Option<AccountId> {
+        if let Some(pool) = self.data::<Data>().pools.get(&asset) {
+            return Some(pool)
+        }
+        // markets listed since the last sync are discovered from the controller
+        let pool = ControllerRef::market_of_underlying(&controller, asset)?;
+        match PoolRef::underlying_asset(&pool) {
+            Some(Underlying::PSP22(underlying)) if underlying == asset => Some(pool),
+            _ => None,
+        }
+    }
+
+    default fn _set_flashloan_paused(&mut self, paused: bool) -> Result<()> {
+        self.data::<Data>().flashloan_paused = paused;
+        Ok(())
+    }
+
+    default fn _set_flashloan_enabled(&mut self, asset: AccountId, enabled: bool) -> Result<()> {
+        if enabled {
+            self.data::<Data>().flashloan_disabled.remove(&asset);
+        } else {
+            self.data::<Data>().flashloan_disabled.insert(&asset, &true);
+        }
+        Ok(())
+    }
+
+    default fn _flashloan_enabled(&self, asset: AccountId) -> bool {
+        !self
+            .data::<Data>()
+            .flashloan_disabled
+            .get(&asset)
+            .unwrap_or(false)
+    }
+
+    default fn _flashloan_premium_of(&self, receiver: AccountId, initiator: AccountId) -> u128 {
+        // the lowest fee applies, an exemption never raises it
+        [
+            self._premium_exemption(receiver),
+            self._premium_exemption(initiator),
+        ]
+        .into_iter()
+        .flatten()
+        .fold(self._flashloan_premium_total(), u128::min)
+    }
+
     default fn _emit_flashloan_event(
         &self,
-        _target: AccountId,
+        _receiver: AccountId,
         _initiator: AccountId,
         _asset: AccountId,
         _amount: Balance,
         _premium: Balance,
+        _protocol_premium: Balance,
     ) {
     }
+
+    default fn _emit_new_flashloan_premium_event(
+        &self,
+        _premium_total: u128,
+        _premium_to_protocol: u128,
+    ) {
+    }
+
+    default fn _emit_premium_exemption_updated_event(
+        &self,
+        _account: AccountId,
+        _premium: Option<u128>,
+    ) {
+    }
+
+    default fn _emit_flashloan_paused_event(&self, _paused: bool) {}
+
+    default fn _emit_pool_registered_event(&self, _asset: AccountId, _pool: AccountId) {}
+
+    default fn _emit_flashloan_enabled_updated_event(&self, _asset: AccountId, _enabled: bool) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flashloan_premiums() {
+        assert_eq!(flashloan_premiums(1_000_000, 9, 0), (900, 0));
+        assert_eq!(flashloan_premiums(1_000_000, 9, 3000), (900, 270));
+        assert_eq!(flashloan_premiums(1_000_000, 9, MAX_BPS), (900, 900));
+        assert_eq!(flashloan_premiums(1_000_000, 0, 3000), (0, 0));
+        assert_eq!(flashloan_premiums(1_000, 9, 3000), (0, 0)); // rounded down
+
+        // amounts whose product with the premium exceeds u128::MAX
+        assert_eq!(
+            flashloan_premiums(Balance::MAX / 2, 9, 3000),
+            (
+                153_127_065_114_422_308_558_518_573_344_295_695,
+                45_938_119_534_326_692_567_555_572_003_288_708
+            )
+        );
+        assert_eq!(
+            flashloan_premiums(Balance::MAX, MAX_BPS, MAX_BPS),
+            (Balance::MAX, Balance::MAX)
+        );
+    }
 }
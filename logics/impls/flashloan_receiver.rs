@@ -5,7 +5,17 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use ink::prelude::vec::Vec;
+use crate::traits::{
+    flashloan_gateway::FlashloanGatewayRef,
+    pool::PoolRef,
+};
+use ink::{
+    env::hash::Blake2x256,
+    prelude::{
+        vec,
+        vec::Vec,
+    },
+};
 use openbrush::{
     contracts::psp22::PSP22Ref,
     traits::{
@@ -14,6 +24,10 @@ use openbrush::{
         Storage,
     },
 };
+use scale::{
+    Decode,
+    Encode,
+};
 
 pub use crate::traits::flashloan_receiver::*;
 
@@ -26,12 +40,53 @@ pub struct Data {
     pub flashloan_gateway: Option<AccountId>,
     /// For mock only: Set flashloan execution as success or fail
     pub fail_execution: bool,
+    /// For mock only: Initiator of the last flashloan executed
+    pub last_initiator: Option<AccountId>,
+    /// For mock only: Params of the last flashloan executed
+    pub last_params: Vec<u8>,
+    /// For mock only: Behavior when executing a flashloan
+    pub execution_mode: ExecutionMode,
+    /// For mock only: Asset approved instead of the lent one in `WrongAsset` mode
+    pub wrong_asset: Option<AccountId>,
+    /// For mock only: Number of hashes computed in `BurnGas` mode
+    pub gas_burn_rounds: u32,
+}
+
+/// For mock only: Behavior of the receiver, to test how the gateway handles misbehaving ones
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum ExecutionMode {
+    /// Repays the amount plus the premium
+    #[default]
+    Normal,
+    /// Repays half of the amount plus the premium
+    PartialRepayment,
+    /// Approves the wrong asset instead of the lent one
+    WrongAsset,
+    /// Requests another flashloan from the gateway before repaying
+    ReenterGateway,
+    /// Requests the underlying of the lending pools directly before repaying
+    ReenterPool,
+    /// Computes `gas_burn_rounds` hashes before repaying
+    BurnGas,
+    /// Supplies all its underlying to the pools flash minting to it before repaying
+    ReenterMint,
 }
 
 pub trait Internal {
     fn _initialize(&mut self, flashloan_gateway: AccountId);
     fn _set_fail_execution_transfer(&mut self, fail: bool);
     fn _fail_execution_transfer(&self) -> bool;
+    fn _last_initiator(&self) -> Option<AccountId>;
+    fn _last_params(&self) -> Vec<u8>;
+    fn _set_execution_mode(&mut self, mode: ExecutionMode);
+    fn _execution_mode(&self) -> ExecutionMode;
+    fn _set_wrong_asset(&mut self, asset: AccountId);
+    fn _set_gas_burn_rounds(&mut self, rounds: u32);
+    fn _misbehave(&self, gateway: AccountId, assets: &[AccountId], amounts: &[Balance]);
 }
 
 impl<T: Storage<Data>> Internal for T {
@@ -46,22 +101,92 @@ impl<T: Storage<Data>> Internal for T {
     default fn _fail_execution_transfer(&self) -> bool {
         self.data().fail_execution
     }
+
+    default fn _last_initiator(&self) -> Option<AccountId> {
+        self.data().last_initiator
+    }
+
+    default fn _last_params(&self) -> Vec<u8> {
+        self.data().last_params.clone()
+    }
+
+    default fn _set_execution_mode(&mut self, mode: ExecutionMode) {
+        self.data().execution_mode = mode;
+    }
+
+    default fn _execution_mode(&self) -> ExecutionMode {
+        self.data().execution_mode
+    }
+
+    default fn _set_wrong_asset(&mut self, asset: AccountId) {
+        self.data().wrong_asset = Some(asset);
+    }
+
+    default fn _set_gas_burn_rounds(&mut self, rounds: u32) {
+        self.data().gas_burn_rounds = rounds;
+    }
+
+    default fn _misbehave(&self, gateway: AccountId, assets: &[AccountId], amounts: &[Balance]) {
+        let contract_addr = Self::env().account_id();
+        // results are ignored: the gateway must reject these calls or the flashloan
+        match self._execution_mode() {
+            ExecutionMode::ReenterGateway => {
+                let _ = FlashloanGatewayRef::flashloan(
+                    &gateway,
+                    contract_addr,
+                    assets.to_vec(),
+                    amounts.to_vec(),
+                    vec![0; assets.len()],
+                    contract_addr,
+                    Vec::new(),
+                );
+            }
+            ExecutionMode::ReenterPool => {
+                for (asset, amount) in assets.iter().zip(amounts) {
+                    if let Some(pool) = FlashloanGatewayRef::pool_of(&gateway, *asset) {
+                        let _ = PoolRef::transfer_underlying(&pool, contract_addr, *amount);
+                    }
+                }
+            }
+            ExecutionMode::ReenterMint => {
+                for pool in assets {
+                    if let Some(underlying) = PoolRef::underlying(pool) {
+                        let balance = PSP22Ref::balance_of(&underlying, contract_addr);
+                        let _ = PSP22Ref::approve(&underlying, *pool, balance);
+                        let _ = PoolRef::mint(pool, balance);
+                    }
+                }
+            }
+            ExecutionMode::BurnGas => {
+                let mut output = [0_u8; 32];
+                for _ in 0..self.data().gas_burn_rounds {
+                    let input = output;
+                    ink::env::hash_bytes::<Blake2x256>(&input, &mut output);
+                }
+            }
+            _ => {}
+        }
+    }
 }
 
 impl<T: Storage<Data>> FlashloanReceiver for T {
     default fn execute_operation(
-        &self,
+        &mut self,
         assets: Vec<AccountId>,
         amounts: Vec<Balance>,
         premiums: Vec<Balance>,
         initiator: AccountId,
-        _params: Vec<u8>,
+        params: Vec<u8>,
     ) -> bool {
         if self._fail_execution_transfer() {
             return false
         }
+        self.data().last_initiator = Some(initiator);
+        self.data().last_params = params;
         let contract_addr = Self::env().account_id();
         if let Some(gateway) = self.data().flashloan_gateway {
+            self._misbehave(gateway, &assets, &amounts);
+            let mode = self._execution_mode();
             for index in 0..assets.len() {
                 let current_asset = assets[index];
                 let transfer_result = PSP22Ref::transfer_from(
@@ -83,7 +208,20 @@ impl<T: Storage<Data>> FlashloanReceiver for T {
                     return false
                 }
 
-                let approve_result = PSP22Ref::approve(&current_asset, gateway, amount_to_return);
+                let (asset_to_approve, amount_to_approve) = match mode {
+                    ExecutionMode::PartialRepayment => {
+                        (current_asset, amounts[index] / 2 + premiums[index])
+                    }
+                    ExecutionMode::WrongAsset => {
+                        (
+                            self.data().wrong_asset.unwrap_or(current_asset),
+                            amount_to_return,
+                        )
+                    }
+                    _ => (current_asset, amount_to_return),
+                };
+                let approve_result =
+                    PSP22Ref::approve(&asset_to_approve, gateway, amount_to_approve);
                 if approve_result.is_err() {
                     return false
                 }
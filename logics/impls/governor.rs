@@ -0,0 +1,465 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+pub use crate::traits::governor::*;
+use crate::traits::{
+    timelock::{
+        TimelockRef,
+        Transaction,
+    },
+    votes::VotesRef,
+};
+use ink::prelude::{
+    string::String,
+    vec::Vec,
+};
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+        Timestamp,
+    },
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Debug, Default)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// AccountId of the token votes are weighted by
+    pub token: Option<AccountId>,
+    /// AccountId of the Timelock executing proposals
+    pub timelock: Option<AccountId>,
+    pub voting_delay: Timestamp,
+    pub voting_period: Timestamp,
+    pub proposal_threshold: Balance,
+    /// Quorum in basis points of the total supply
+    pub quorum_fraction: u128,
+    pub proposal_count: ProposalId,
+    pub proposals: Mapping<ProposalId, Proposal>,
+    pub actions: Mapping<ProposalId, Vec<ProposalAction>>,
+    pub receipts: Mapping<(ProposalId, AccountId), Receipt>,
+}
+
+pub trait Internal {
+    fn _initialize(
+        &mut self,
+        token: AccountId,
+        timelock: AccountId,
+        voting_delay: Timestamp,
+        voting_period: Timestamp,
+        proposal_threshold: Balance,
+        quorum_fraction: u128,
+    ) -> Result<()>;
+    fn _token(&self) -> Result<AccountId>;
+    fn _timelock(&self) -> Result<AccountId>;
+    fn _proposal(&self, proposal_id: ProposalId) -> Result<Proposal>;
+    fn _state(&self, proposal_id: ProposalId) -> Result<ProposalState>;
+    fn _quorum(&self, timestamp: Timestamp) -> Result<Balance>;
+    fn _past_votes(&self, account: AccountId, timestamp: Timestamp) -> Result<Balance>;
+    fn _transaction(&self, action: ProposalAction, eta: Timestamp) -> Transaction;
+    fn _assert_timelock(&self) -> Result<()>;
+    fn _set_voting_delay(&mut self, voting_delay: Timestamp) -> Result<()>;
+    fn _set_voting_period(&mut self, voting_period: Timestamp) -> Result<()>;
+    fn _set_proposal_threshold(&mut self, proposal_threshold: Balance) -> Result<()>;
+    fn _set_quorum_fraction(&mut self, quorum_fraction: u128) -> Result<()>;
+
+    // event emission
+    fn _emit_proposal_created_event(
+        &self,
+        proposal_id: ProposalId,
+        proposer: AccountId,
+        actions: Vec<ProposalAction>,
+        snapshot: Timestamp,
+        deadline: Timestamp,
+        description: String,
+    );
+    fn _emit_vote_cast_event(
+        &self,
+        voter: AccountId,
+        proposal_id: ProposalId,
+        support: VoteType,
+        votes: Balance,
+    );
+    fn _emit_proposal_queued_event(&self, proposal_id: ProposalId, eta: Timestamp);
+    fn _emit_proposal_executed_event(&self, proposal_id: ProposalId);
+    fn _emit_proposal_canceled_event(&self, proposal_id: ProposalId);
+    fn _emit_new_voting_delay_event(&self, voting_delay: Timestamp);
+    fn _emit_new_voting_period_event(&self, voting_period: Timestamp);
+    fn _emit_new_proposal_threshold_event(&self, proposal_threshold: Balance);
+    fn _emit_new_quorum_fraction_event(&self, quorum_fraction: u128);
+}
+
+impl<T: Storage<Data>> Governor for T {
+    default fn token(&self) -> Option<AccountId> {
+        self.data().token
+    }
+
+    default fn timelock(&self) -> Option<AccountId> {
+        self.data().timelock
+    }
+
+    default fn voting_delay(&self) -> Timestamp {
+        self.data().voting_delay
+    }
+
+    default fn voting_period(&self) -> Timestamp {
+        self.data().voting_period
+    }
+
+    default fn proposal_threshold(&self) -> Balance {
+        self.data().proposal_threshold
+    }
+
+    default fn quorum_fraction(&self) -> u128 {
+        self.data().quorum_fraction
+    }
+
+    default fn quorum(&self, timestamp: Timestamp) -> Result<Balance> {
+        self._quorum(timestamp)
+    }
+
+    default fn proposal_count(&self) -> ProposalId {
+        self.data().proposal_count
+    }
+
+    default fn proposal(&self, proposal_id: ProposalId) -> Option<Proposal> {
+        self.data().proposals.get(&proposal_id)
+    }
+
+    default fn proposal_actions(&self, proposal_id: ProposalId) -> Vec<ProposalAction> {
+        self.data().actions.get(&proposal_id).unwrap_or_default()
+    }
+
+    default fn receipt(&self, proposal_id: ProposalId, account: AccountId) -> Option<Receipt> {
+        self.data().receipts.get(&(proposal_id, account))
+    }
+
+    default fn state(&self, proposal_id: ProposalId) -> Result<ProposalState> {
+        self._state(proposal_id)
+    }
+
+    default fn propose(
+        &mut self,
+        actions: Vec<ProposalAction>,
+        description: String,
+    ) -> Result<ProposalId> {
+        if actions.is_empty() || actions.len() > MAX_ACTIONS as usize {
+            return Err(Error::InvalidActions)
+        }
+        let proposer = Self::env().caller();
+        let now = Self::env().block_timestamp();
+        // votes of the current block can still change, so the previous one is read
+        if self._past_votes(proposer, now - 1)? < self.data().proposal_threshold {
+            return Err(Error::BelowProposalThreshold)
+        }
+
+        let proposal_id = self.data().proposal_count;
+        let snapshot = now + self.data().voting_delay;
+        let deadline = snapshot + self.data().voting_period;
+        let proposal = Proposal {
+            proposer,
+            snapshot,
+            deadline,
+            eta: None,
+            for_votes: 0,
+            against_votes: 0,
+            abstain_votes: 0,
+            canceled: false,
+            executed: false,
+        };
+        self.data().proposals.insert(&proposal_id, &proposal);
+        self.data().actions.insert(&proposal_id, &actions);
+        self.data().proposal_count = proposal_id + 1;
+
+        self._emit_proposal_created_event(
+            proposal_id,
+            proposer,
+            actions,
+            snapshot,
+            deadline,
+            description,
+        );
+        Ok(proposal_id)
+    }
+
+    default fn cast_vote(&mut self, proposal_id: ProposalId, support: VoteType) -> Result<Balance> {
+        if self._state(proposal_id)? != ProposalState::Active {
+            return Err(Error::ProposalNotActive)
+        }
+        let voter = Self::env().caller();
+        if self.data().receipts.contains(&(proposal_id, voter)) {
+            return Err(Error::AlreadyVoted)
+        }
+        let mut proposal = self._proposal(proposal_id)?;
+        let votes = self._past_votes(voter, proposal.snapshot)?;
+        match support {
+            VoteType::Against => proposal.against_votes += votes,
+            VoteType::For => proposal.for_votes += votes,
+            VoteType::Abstain => proposal.abstain_votes += votes,
+        }
+        self.data().proposals.insert(&proposal_id, &proposal);
+        self.data()
+            .receipts
+            .insert(&(proposal_id, voter), &Receipt { support, votes });
+
+        self._emit_vote_cast_event(voter, proposal_id, support, votes);
+        Ok(votes)
+    }
+
+    default fn queue(&mut self, proposal_id: ProposalId) -> Result<()> {
+        if self._state(proposal_id)? != ProposalState::Succeeded {
+            return Err(Error::ProposalNotSucceeded)
+        }
+        let timelock = self._timelock()?;
+        let eta = Self::env().block_timestamp() + TimelockRef::delay(&timelock);
+        for action in self.proposal_actions(proposal_id) {
+            let transaction = self._transaction(action, eta);
+            // identical actions would share one queued transaction
+            let id = TimelockRef::transaction_id(&timelock, transaction.clone());
+            if TimelockRef::is_queued(&timelock, id) {
+                return Err(Error::ActionAlreadyQueued)
+            }
+            TimelockRef::queue_transaction(&timelock, transaction)?;
+        }
+        let mut proposal = self._proposal(proposal_id)?;
+        proposal.eta = Some(eta);
+        self.data().proposals.insert(&proposal_id, &proposal);
+
+        self._emit_proposal_queued_event(proposal_id, eta);
+        Ok(())
+    }
+
+    default fn execute(&mut self, proposal_id: ProposalId) -> Result<()> {
+        if self._state(proposal_id)? != ProposalState::Queued {
+            return Err(Error::ProposalNotQueued)
+        }
+        let timelock = self._timelock()?;
+        let mut proposal = self._proposal(proposal_id)?;
+        let eta = proposal.eta.ok_or(Error::ProposalNotQueued)?;
+        proposal.executed = true;
+        self.data().proposals.insert(&proposal_id, &proposal);
+
+        for action in self.proposal_actions(proposal_id) {
+            let transaction = self._transaction(action, eta);
+            // actions may call back this contract, e.g. to update its settings
+            TimelockRef::execute_transaction_builder(&timelock, transaction)
+                .call_flags(ink_env::CallFlags::default().set_allow_reentry(true))
+                .try_invoke()
+                .unwrap()
+                .unwrap()?;
+        }
+
+        self._emit_proposal_executed_event(proposal_id);
+        Ok(())
+    }
+
+    default fn cancel(&mut self, proposal_id: ProposalId) -> Result<()> {
+        let state = self._state(proposal_id)?;
+        if matches!(
+            state,
+            ProposalState::Canceled | ProposalState::Expired | ProposalState::Executed
+        ) {
+            return Err(Error::ProposalIsFinalized)
+        }
+        let mut proposal = self._proposal(proposal_id)?;
+        if Self::env().caller() != proposal.proposer {
+            let now = Self::env().block_timestamp();
+            if self._past_votes(proposal.proposer, now - 1)? >= self.data().proposal_threshold {
+                return Err(Error::CallerIsNotProposer)
+            }
+        }
+
+        if let (ProposalState::Queued, Some(eta)) = (state, proposal.eta) {
+            let timelock = self._timelock()?;
+            for action in self.proposal_actions(proposal_id) {
+                let transaction = self._transaction(action, eta);
+                TimelockRef::cancel_transaction(&timelock, transaction)?;
+            }
+        }
+        proposal.canceled = true;
+        self.data().proposals.insert(&proposal_id, &proposal);
+
+        self._emit_proposal_canceled_event(proposal_id);
+        Ok(())
+    }
+
+    default fn set_voting_delay(&mut self, voting_delay: Timestamp) -> Result<()> {
+        self._assert_timelock()?;
+        self._set_voting_delay(voting_delay)
+    }
+
+    default fn set_voting_period(&mut self, voting_period: Timestamp) -> Result<()> {
+        self._assert_timelock()?;
+        self._set_voting_period(voting_period)
+    }
+
+    default fn set_proposal_threshold(&mut self, proposal_threshold: Balance) -> Result<()> {
+        self._assert_timelock()?;
+        self._set_proposal_threshold(proposal_threshold)
+    }
+
+    default fn set_quorum_fraction(&mut self, quorum_fraction: u128) -> Result<()> {
+        self._assert_timelock()?;
+        self._set_quorum_fraction(quorum_fraction)
+    }
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _initialize(
+        &mut self,
+        token: AccountId,
+        timelock: AccountId,
+        voting_delay: Timestamp,
+        voting_period: Timestamp,
+        proposal_threshold: Balance,
+        quorum_fraction: u128,
+    ) -> Result<()> {
+        self.data().token = Some(token);
+        self.data().timelock = Some(timelock);
+        self._set_voting_delay(voting_delay)?;
+        self._set_voting_period(voting_period)?;
+        self._set_proposal_threshold(proposal_threshold)?;
+        self._set_quorum_fraction(quorum_fraction)
+    }
+
+    default fn _token(&self) -> Result<AccountId> {
+        self.data().token.ok_or(Error::TokenIsNotSet)
+    }
+
+    default fn _timelock(&self) -> Result<AccountId> {
+        self.data().timelock.ok_or(Error::TimelockIsNotSet)
+    }
+
+    default fn _proposal(&self, proposal_id: ProposalId) -> Result<Proposal> {
+        self.data()
+            .proposals
+            .get(&proposal_id)
+            .ok_or(Error::ProposalNotFound)
+    }
+
+    default fn _state(&self, proposal_id: ProposalId) -> Result<ProposalState> {
+        let proposal = self._proposal(proposal_id)?;
+        if proposal.canceled {
+            return Ok(ProposalState::Canceled)
+        }
+        if proposal.executed {
+            return Ok(ProposalState::Executed)
+        }
+        let now = Self::env().block_timestamp();
+        if now <= proposal.snapshot {
+            return Ok(ProposalState::Pending)
+        }
+        if now <= proposal.deadline {
+            return Ok(ProposalState::Active)
+        }
+        let quorum = self._quorum(proposal.snapshot)?;
+        if proposal.for_votes <= proposal.against_votes
+            || proposal.for_votes + proposal.abstain_votes < quorum
+        {
+            return Ok(ProposalState::Defeated)
+        }
+        match proposal.eta {
+            None => Ok(ProposalState::Succeeded),
+            Some(eta) => {
+                let grace_period = TimelockRef::grace_period(&self._timelock()?);
+                if now > eta + grace_period {
+                    Ok(ProposalState::Expired)
+                } else {
+                    Ok(ProposalState::Queued)
+                }
+            }
+        }
+    }
+
+    default fn _quorum(&self, timestamp: Timestamp) -> Result<Balance> {
+        let total_supply = VotesRef::get_past_total_supply(&self._token()?, timestamp)?;
+        Ok(total_supply * self.data().quorum_fraction / MAX_BPS)
+    }
+
+    default fn _past_votes(&self, account: AccountId, timestamp: Timestamp) -> Result<Balance> {
+        Ok(VotesRef::get_past_votes(
+            &self._token()?,
+            account,
+            timestamp,
+        )?)
+    }
+
+    default fn _transaction(&self, action: ProposalAction, eta: Timestamp) -> Transaction {
+        Transaction {
+            target: action.target,
+            selector: action.selector,
+            input: action.input,
+            eta,
+        }
+    }
+
+    default fn _assert_timelock(&self) -> Result<()> {
+        if Some(Self::env().caller()) != self.data().timelock {
+            return Err(Error::CallerIsNotTimelock)
+        }
+        Ok(())
+    }
+
+    default fn _set_voting_delay(&mut self, voting_delay: Timestamp) -> Result<()> {
+        self.data().voting_delay = voting_delay;
+        self._emit_new_voting_delay_event(voting_delay);
+        Ok(())
+    }
+
+    default fn _set_voting_period(&mut self, voting_period: Timestamp) -> Result<()> {
+        if voting_period == 0 {
+            return Err(Error::InvalidVotingPeriod)
+        }
+        self.data().voting_period = voting_period;
+        self._emit_new_voting_period_event(voting_period);
+        Ok(())
+    }
+
+    default fn _set_proposal_threshold(&mut self, proposal_threshold: Balance) -> Result<()> {
+        self.data().proposal_threshold = proposal_threshold;
+        self._emit_new_proposal_threshold_event(proposal_threshold);
+        Ok(())
+    }
+
+    default fn _set_quorum_fraction(&mut self, quorum_fraction: u128) -> Result<()> {
+        if quorum_fraction > MAX_BPS {
+            return Err(Error::InvalidQuorumFraction)
+        }
+        self.data().quorum_fraction = quorum_fraction;
+        self._emit_new_quorum_fraction_event(quorum_fraction);
+        Ok(())
+    }
+
+    default fn _emit_proposal_created_event(
+        &self,
+        _proposal_id: ProposalId,
+        _proposer: AccountId,
+        _actions: Vec<ProposalAction>,
+        _snapshot: Timestamp,
+        _deadline: Timestamp,
+        _description: String,
+    ) {
+    }
+    default fn _emit_vote_cast_event(
+        &self,
+        _voter: AccountId,
+        _proposal_id: ProposalId,
+        _support: VoteType,
+        _votes: Balance,
+    ) {
+    }
+    default fn _emit_proposal_queued_event(&self, _proposal_id: ProposalId, _eta: Timestamp) {}
+    default fn _emit_proposal_executed_event(&self, _proposal_id: ProposalId) {}
+    default fn _emit_proposal_canceled_event(&self, _proposal_id: ProposalId) {}
+    default fn _emit_new_voting_delay_event(&self, _voting_delay: Timestamp) {}
+    default fn _emit_new_voting_period_event(&self, _voting_period: Timestamp) {}
+    default fn _emit_new_proposal_threshold_event(&self, _proposal_threshold: Balance) {}
+    default fn _emit_new_quorum_fraction_event(&self, _quorum_fraction: u128) {}
+}
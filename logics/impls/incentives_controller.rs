@@ -5,46 +5,613 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use super::exp_no_err::exp_scale;
 pub use crate::traits::incentives_controller::*;
-
-use openbrush::traits::{
-    AccountId,
-    Balance,
-    Storage,
+use crate::traits::{
+    pool::PoolRef,
+    types::WrappedU256,
+    vote_escrow::VoteEscrowRef,
+};
+use core::ops::{
+    Add,
+    Div,
+    Mul,
 };
+use ink::prelude::vec::Vec;
+use openbrush::{
+    contracts::{
+        ownable::{
+            self,
+            only_owner,
+        },
+        psp22::PSP22Ref,
+    },
+    modifiers,
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+        Timestamp,
+    },
+};
+use primitive_types::U256;
 
 pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
 
 #[derive(Debug, Default)]
 #[openbrush::upgradeable_storage(STORAGE_KEY)]
 pub struct Data {
-    /// For mock only: return if handle_action result is oks
-    pub is_ok: bool,
+    /// Token in which rewards are paid
+    pub reward_token: Option<AccountId>,
+    /// Rewards emitted per millisecond to suppliers and to borrowers of each pool
+    pub reward_speeds: Mapping<AccountId, (Balance, Balance)>,
+    /// Reward index of suppliers of each pool
+    pub supply_states: Mapping<AccountId, RewardState>,
+    /// Reward index of borrowers of each pool
+    pub borrow_states: Mapping<AccountId, RewardState>,
+    /// Supply index of each pool at which each account last accrued
+    pub supplier_indexes: Mapping<(AccountId, AccountId), WrappedU256>,
+    /// Borrow index of each pool at which each account last accrued
+    pub borrower_indexes: Mapping<(AccountId, AccountId), WrappedU256>,
+    /// Rewards accrued and not claimed by each account
+    pub unclaimed_rewards: Mapping<AccountId, Balance>,
+    /// Claimer authorized by each account
+    pub claimers: Mapping<AccountId, AccountId>,
+    /// Claim operators approved by each account
+    pub claim_operators: Mapping<(AccountId, AccountId), bool>,
+    /// Pools rewards have been configured for
+    pub pools: Vec<AccountId>,
+    /// AccountId of Vote Escrow whose veLAY boosts rewards
+    pub vote_escrow: Option<AccountId>,
+}
+
+/// Reward index of a pool after `elapsed` milliseconds at `speed` shared by `total`
+pub fn next_reward_index(index: U256, speed: Balance, elapsed: Timestamp, total: Balance) -> U256 {
+    if total == 0 || speed == 0 || elapsed == 0 {
+        return index
+    }
+    index.add(
+        U256::from(speed)
+            .mul(U256::from(elapsed))
+            .mul(exp_scale())
+            .div(U256::from(total)),
+    )
+}
+
+/// Rewards accrued by `balance` since the account last accrued at `account_index`
+pub fn accrued_rewards(balance: Balance, index: U256, account_index: U256) -> Balance {
+    U256::from(balance)
+        .mul(index.saturating_sub(account_index))
+        .div(exp_scale())
+        .as_u128()
+}
+
+/// `rewards` accrued by `balance` out of `total` boosted by the veLAY share of the account.
+/// `UNBOOSTED_REWARDS_BPS` of the rewards are earned without veLAY and all of them once the
+/// veLAY share of the account reaches its share of the pool
+pub fn boosted_rewards(
+    rewards: Balance,
+    balance: Balance,
+    total: Balance,
+    ve_balance: Balance,
+    ve_total: Balance,
+) -> Balance {
+    if rewards == 0 || balance == 0 {
+        return 0
+    }
+    let unboosted = U256::from(balance).mul(U256::from(UNBOOSTED_REWARDS_BPS));
+    let boost = if ve_total == 0 {
+        U256::zero()
+    } else {
+        U256::from(total)
+            .mul(U256::from(ve_balance))
+            .div(U256::from(ve_total))
+            .mul(U256::from(BPS - UNBOOSTED_REWARDS_BPS))
+    };
+    let working_balance = unboosted
+        .add(boost)
+        .min(U256::from(balance).mul(U256::from(BPS)));
+    U256::from(rewards)
+        .mul(working_balance)
+        .div(U256::from(balance).mul(U256::from(BPS)))
+        .as_u128()
 }
 
 pub trait Internal {
-    fn _set_ok(&mut self, is_ok: bool) -> Result<()>;
+    fn _initialize(&mut self, reward_token: Option<AccountId>);
+    fn _update_reward_states(
+        &mut self,
+        pool: AccountId,
+        total_deposit: Balance,
+        total_borrow: Balance,
+    ) -> (RewardState, RewardState);
+    fn _accrue_rewards(
+        &mut self,
+        pool: AccountId,
+        user: AccountId,
+        total_deposit: Balance,
+        total_borrow: Balance,
+        user_deposit: Balance,
+        user_borrow: Balance,
+    ) -> Balance;
+    /// Accrues the rewards of `user` in every pool with its current balances
+    fn _accrue_all_rewards(&mut self, user: AccountId);
+    fn _claim_rewards(&mut self, user: AccountId, to: AccountId) -> Result<Balance>;
+    fn _set_reward_speeds(
+        &mut self,
+        pool: AccountId,
+        supply_speed: Balance,
+        borrow_speed: Balance,
+    ) -> Result<()>;
+    fn _set_reward_token(&mut self, reward_token: Option<AccountId>) -> Result<()>;
+    fn _set_claimer(&mut self, user: AccountId, claimer: Option<AccountId>) -> Result<()>;
+    fn _set_claim_operator(
+        &mut self,
+        user: AccountId,
+        operator: AccountId,
+        approved: bool,
+    ) -> Result<()>;
+    fn _set_vote_escrow(&mut self, vote_escrow: Option<AccountId>) -> Result<()>;
+
+    // view functions
+    fn _reward_token(&self) -> Option<AccountId>;
+    fn _reward_speeds(&self, pool: AccountId) -> Option<(Balance, Balance)>;
+    fn _reward_states(&self, pool: AccountId) -> (RewardState, RewardState);
+    fn _unclaimed_rewards(&self, user: AccountId) -> Balance;
+    fn _claimer_of(&self, user: AccountId) -> Option<AccountId>;
+    fn _is_claim_operator(&self, user: AccountId, operator: AccountId) -> bool;
+    fn _pools(&self) -> Vec<AccountId>;
+    fn _vote_escrow(&self) -> Option<AccountId>;
+
+    // event emission
+    fn _emit_rewards_accrued_event(&self, user: AccountId, pool: AccountId, amount: Balance);
+    fn _emit_rewards_claimed_event(
+        &self,
+        user: AccountId,
+        to: AccountId,
+        claimer: AccountId,
+        amount: Balance,
+    );
+    fn _emit_reward_speeds_updated_event(
+        &self,
+        pool: AccountId,
+        supply_speed: Balance,
+        borrow_speed: Balance,
+    );
+    fn _emit_new_reward_token_event(&self, old: Option<AccountId>, new: Option<AccountId>);
+    fn _emit_claimer_set_event(&self, user: AccountId, claimer: Option<AccountId>);
+    fn _emit_claim_operator_set_event(&self, user: AccountId, operator: AccountId, approved: bool);
+    fn _emit_new_vote_escrow_event(&self, old: Option<AccountId>, new: Option<AccountId>);
 }
 
-impl<T: Storage<Data>> IncentivesController for T {
+impl<T: Storage<Data> + Storage<ownable::Data>> IncentivesController for T {
     default fn handle_action(
         &mut self,
-        _user: AccountId,
-        _total_deposit: Balance,
-        _total_borrow: Balance,
-        _user_deposit: Balance,
-        _assetuser_borrow: Balance,
+        user: AccountId,
+        total_deposit: Balance,
+        total_borrow: Balance,
+        user_deposit: Balance,
+        user_borrow: Balance,
     ) -> Result<()> {
-        if self.data::<Data>().is_ok {
+        let pool = Self::env().caller();
+        // accounts of pools never configured have nothing to accrue
+        if self._reward_speeds(pool).is_none() {
             return Ok(())
         }
-        return Err(Error::CallerIsNotConfiguredAsset)
+        self._accrue_rewards(
+            pool,
+            user,
+            total_deposit,
+            total_borrow,
+            user_deposit,
+            user_borrow,
+        );
+        Ok(())
+    }
+
+    default fn claim_rewards_on_behalf(
+        &mut self,
+        user: AccountId,
+        to: AccountId,
+    ) -> Result<Balance> {
+        let caller = Self::env().caller();
+        if caller != user && self._claimer_of(user) != Some(caller) {
+            if !self._is_claim_operator(user, caller) {
+                return Err(Error::CallerIsNotAuthorizedClaimer)
+            }
+            // operators never take custody of the rewards
+            if to != user {
+                return Err(Error::OperatorCanOnlyClaimToUser)
+            }
+        }
+        let amount = self._claim_rewards(user, to)?;
+        self._emit_rewards_claimed_event(user, to, caller, amount);
+        Ok(amount)
+    }
+
+    default fn reward_token(&self) -> Option<AccountId> {
+        self._reward_token()
+    }
+
+    default fn reward_speeds(&self, pool: AccountId) -> (Balance, Balance) {
+        self._reward_speeds(pool).unwrap_or_default()
+    }
+
+    default fn unclaimed_rewards(&self, user: AccountId) -> Balance {
+        self._unclaimed_rewards(user)
+    }
+
+    #[modifiers(only_owner)]
+    default fn set_reward_speeds(
+        &mut self,
+        pool: AccountId,
+        supply_speed: Balance,
+        borrow_speed: Balance,
+    ) -> Result<()> {
+        self._set_reward_speeds(pool, supply_speed, borrow_speed)?;
+        self._emit_reward_speeds_updated_event(pool, supply_speed, borrow_speed);
+        Ok(())
+    }
+
+    #[modifiers(only_owner)]
+    default fn set_reward_token(&mut self, reward_token: Option<AccountId>) -> Result<()> {
+        let old = self._reward_token();
+        self._set_reward_token(reward_token)?;
+        self._emit_new_reward_token_event(old, reward_token);
+        Ok(())
+    }
+
+    default fn set_claimer(&mut self, claimer: Option<AccountId>) -> Result<()> {
+        let user = Self::env().caller();
+        self._set_claimer(user, claimer)?;
+        self._emit_claimer_set_event(user, claimer);
+        Ok(())
+    }
+
+    default fn claimer_of(&self, user: AccountId) -> Option<AccountId> {
+        self._claimer_of(user)
+    }
+
+    default fn set_claim_operator(&mut self, operator: AccountId, approved: bool) -> Result<()> {
+        let user = Self::env().caller();
+        self._set_claim_operator(user, operator, approved)?;
+        self._emit_claim_operator_set_event(user, operator, approved);
+        Ok(())
+    }
+
+    default fn is_claim_operator(&self, user: AccountId, operator: AccountId) -> bool {
+        self._is_claim_operator(user, operator)
+    }
+
+    default fn pools(&self) -> Vec<AccountId> {
+        self._pools()
+    }
+
+    default fn reward_states(&self, pool: AccountId) -> (RewardState, RewardState) {
+        self._reward_states(pool)
+    }
+
+    #[modifiers(only_owner)]
+    default fn set_vote_escrow(&mut self, vote_escrow: Option<AccountId>) -> Result<()> {
+        let old = self._vote_escrow();
+        self._set_vote_escrow(vote_escrow)?;
+        self._emit_new_vote_escrow_event(old, vote_escrow);
+        Ok(())
+    }
+
+    default fn vote_escrow(&self) -> Option<AccountId> {
+        self._vote_escrow()
     }
 }
 
 impl<T: Storage<Data>> Internal for T {
-    default fn _set_ok(&mut self, is_ok: bool) -> Result<()> {
-        self.data::<Data>().is_ok = is_ok;
+    default fn _initialize(&mut self, reward_token: Option<AccountId>) {
+        self.data::<Data>().reward_token = reward_token;
+    }
+
+    default fn _update_reward_states(
+        &mut self,
+        pool: AccountId,
+        total_deposit: Balance,
+        total_borrow: Balance,
+    ) -> (RewardState, RewardState) {
+        let now = Self::env().block_timestamp();
+        let (supply_speed, borrow_speed) = self._reward_speeds(pool).unwrap_or_default();
+        let (supply_state, borrow_state) = self._reward_states(pool);
+        let supply_state = RewardState {
+            index: WrappedU256::from(next_reward_index(
+                supply_state.index.into(),
+                supply_speed,
+                now.saturating_sub(supply_state.timestamp),
+                total_deposit,
+            )),
+            timestamp: now,
+        };
+        let borrow_state = RewardState {
+            index: WrappedU256::from(next_reward_index(
+                borrow_state.index.into(),
+                borrow_speed,
+                now.saturating_sub(borrow_state.timestamp),
+                total_borrow,
+            )),
+            timestamp: now,
+        };
+        let data = self.data::<Data>();
+        data.supply_states.insert(&pool, &supply_state);
+        data.borrow_states.insert(&pool, &borrow_state);
+        (supply_state, borrow_state)
+    }
+
+    default fn _accrue_rewards(
+        &mut self,
+        pool: AccountId,
+        user: AccountId,
+        total_deposit: Balance,
+        total_borrow: Balance,
+        user_deposit: Balance,
+        user_borrow: Balance,
+    ) -> Balance {
+        let (supply_state, borrow_state) =
+            self._update_reward_states(pool, total_deposit, total_borrow);
+        let data = self.data::<Data>();
+        let supplier_index = data.supplier_indexes.get(&(pool, user)).unwrap_or_default();
+        let borrower_index = data.borrower_indexes.get(&(pool, user)).unwrap_or_default();
+        let supply_rewards = accrued_rewards(
+            user_deposit,
+            supply_state.index.into(),
+            supplier_index.into(),
+        );
+        let borrow_rewards = accrued_rewards(
+            user_borrow,
+            borrow_state.index.into(),
+            borrower_index.into(),
+        );
+        let accrued = match data.vote_escrow {
+            Some(vote_escrow) if supply_rewards > 0 || borrow_rewards > 0 => {
+                let ve_balance = VoteEscrowRef::balance_of(&vote_escrow, user);
+                let ve_total = VoteEscrowRef::total_supply(&vote_escrow);
+                boosted_rewards(
+                    supply_rewards,
+                    user_deposit,
+                    total_deposit,
+                    ve_balance,
+                    ve_total,
+                ) + boosted_rewards(
+                    borrow_rewards,
+                    user_borrow,
+                    total_borrow,
+                    ve_balance,
+                    ve_total,
+                )
+            }
+            _ => supply_rewards + borrow_rewards,
+        };
+        data.supplier_indexes
+            .insert(&(pool, user), &supply_state.index);
+        data.borrower_indexes
+            .insert(&(pool, user), &borrow_state.index);
+        if accrued == 0 {
+            return 0
+        }
+        let unclaimed = data.unclaimed_rewards.get(&user).unwrap_or_default();
+        data.unclaimed_rewards.insert(&user, &(unclaimed + accrued));
+        self._emit_rewards_accrued_event(user, pool, accrued);
+        accrued
+    }
+
+    default fn _accrue_all_rewards(&mut self, user: AccountId) {
+        for pool in self._pools() {
+            self._accrue_rewards(
+                pool,
+                user,
+                PoolRef::principal_total_supply(&pool),
+                PoolRef::total_borrows(&pool),
+                PoolRef::principal_balance_of(&pool, user),
+                PoolRef::borrow_balance_stored(&pool, user),
+            );
+        }
+    }
+
+    default fn _claim_rewards(&mut self, user: AccountId, to: AccountId) -> Result<Balance> {
+        let reward_token = self._reward_token().ok_or(Error::RewardTokenIsNotSet)?;
+        self._accrue_all_rewards(user);
+        let amount = self._unclaimed_rewards(user);
+        if amount == 0 {
+            return Ok(0)
+        }
+        self.data::<Data>().unclaimed_rewards.remove(&user);
+        PSP22Ref::transfer(&reward_token, to, amount, Vec::<u8>::new())?;
+        Ok(amount)
+    }
+
+    default fn _set_reward_speeds(
+        &mut self,
+        pool: AccountId,
+        supply_speed: Balance,
+        borrow_speed: Balance,
+    ) -> Result<()> {
+        if self._reward_speeds(pool).is_some() {
+            // accumulate at the previous speeds until now
+            self._update_reward_states(
+                pool,
+                PoolRef::principal_total_supply(&pool),
+                PoolRef::total_borrows(&pool),
+            );
+        } else {
+            let state = RewardState {
+                index: WrappedU256::from(0),
+                timestamp: Self::env().block_timestamp(),
+            };
+            let data = self.data::<Data>();
+            data.supply_states.insert(&pool, &state);
+            data.borrow_states.insert(&pool, &state);
+            data.pools.push(pool);
+        }
+        self.data::<Data>()
+            .reward_speeds
+            .insert(&pool, &(supply_speed, borrow_speed));
         Ok(())
     }
+
+    default fn _set_reward_token(&mut self, reward_token: Option<AccountId>) -> Result<()> {
+        self.data::<Data>().reward_token = reward_token;
+        Ok(())
+    }
+
+    default fn _set_claimer(&mut self, user: AccountId, claimer: Option<AccountId>) -> Result<()> {
+        let data = self.data::<Data>();
+        match claimer {
+            Some(claimer) => data.claimers.insert(&user, &claimer),
+            None => data.claimers.remove(&user),
+        };
+        Ok(())
+    }
+
+    default fn _set_claim_operator(
+        &mut self,
+        user: AccountId,
+        operator: AccountId,
+        approved: bool,
+    ) -> Result<()> {
+        let data = self.data::<Data>();
+        if approved {
+            data.claim_operators.insert(&(user, operator), &true);
+        } else {
+            data.claim_operators.remove(&(user, operator));
+        }
+        Ok(())
+    }
+
+    default fn _set_vote_escrow(&mut self, vote_escrow: Option<AccountId>) -> Result<()> {
+        self.data::<Data>().vote_escrow = vote_escrow;
+        Ok(())
+    }
+
+    default fn _reward_token(&self) -> Option<AccountId> {
+        self.data::<Data>().reward_token
+    }
+
+    default fn _reward_speeds(&self, pool: AccountId) -> Option<(Balance, Balance)> {
+        self.data::<Data>().reward_speeds.get(&pool)
+    }
+
+    default fn _reward_states(&self, pool: AccountId) -> (RewardState, RewardState) {
+        let data = self.data::<Data>();
+        (
+            data.supply_states.get(&pool).unwrap_or_default(),
+            data.borrow_states.get(&pool).unwrap_or_default(),
+        )
+    }
+
+    default fn _unclaimed_rewards(&self, user: AccountId) -> Balance {
+        self.data::<Data>()
+            .unclaimed_rewards
+            .get(&user)
+            .unwrap_or_default()
+    }
+
+    default fn _claimer_of(&self, user: AccountId) -> Option<AccountId> {
+        self.data::<Data>().claimers.get(&user)
+    }
+
+    default fn _is_claim_operator(&self, user: AccountId, operator: AccountId) -> bool {
+        self.data::<Data>()
+            .claim_operators
+            .get(&(user, operator))
+            .unwrap_or_default()
+    }
+
+    default fn _pools(&self) -> Vec<AccountId> {
+        self.data::<Data>().pools.clone()
+    }
+
+    default fn _vote_escrow(&self) -> Option<AccountId> {
+        self.data::<Data>().vote_escrow
+    }
+
+    default fn _emit_rewards_accrued_event(
+        &self,
+        _user: AccountId,
+        _pool: AccountId,
+        _amount: Balance,
+    ) {
+    }
+    default fn _emit_rewards_claimed_event(
+        &self,
+        _user: AccountId,
+        _to: AccountId,
+        _claimer: AccountId,
+        _amount: Balance,
+    ) {
+    }
+    default fn _emit_reward_speeds_updated_event(
+        &self,
+        _pool: AccountId,
+        _supply_speed: Balance,
+        _borrow_speed: Balance,
+    ) {
+    }
+    default fn _emit_new_reward_token_event(
+        &self,
+        _old: Option<AccountId>,
+        _new: Option<AccountId>,
+    ) {
+    }
+    default fn _emit_claimer_set_event(&self, _user: AccountId, _claimer: Option<AccountId>) {}
+    default fn _emit_claim_operator_set_event(
+        &self,
+        _user: AccountId,
+        _operator: AccountId,
+        _approved: bool,
+    ) {
+    }
+    default fn _emit_new_vote_escrow_event(
+        &self,
+        _old: Option<AccountId>,
+        _new: Option<AccountId>,
+    ) {
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_next_reward_index() {
+        let index = exp_scale();
+        // nothing accumulates without time, speed or balances
+        assert_eq!(next_reward_index(index, 10, 0, 100), index);
+        assert_eq!(next_reward_index(index, 0, 10, 100), index);
+        assert_eq!(next_reward_index(index, 10, 10, 0), index);
+        // 10 per ms for 10ms shared by 100 units: 1 per unit
+        assert_eq!(
+            next_reward_index(index, 10, 10, 100),
+            index.add(exp_scale())
+        );
+    }
+
+    #[test]
+    fn test_accrued_rewards() {
+        let index = exp_scale().mul(3);
+        assert_eq!(accrued_rewards(100, index, exp_scale()), 200);
+        assert_eq!(accrued_rewards(100, index, index), 0);
+        assert_eq!(accrued_rewards(0, index, U256::zero()), 0);
+        // an account index ahead of the pool never underflows
+        assert_eq!(accrued_rewards(100, exp_scale(), index), 0);
+    }
+
+    #[test]
+    fn test_boosted_rewards() {
+        // without veLAY only the unboosted share is earned
+        assert_eq!(boosted_rewards(1000, 100, 1000, 0, 0), 400);
+        assert_eq!(boosted_rewards(1000, 100, 1000, 0, 500), 400);
+        // half of the veLAY share needed for the full boost: 10% of the pool, 5% of veLAY
+        assert_eq!(boosted_rewards(1000, 100, 1000, 25, 500), 700);
+        // the boost is capped once the veLAY share reaches the share of the pool
+        assert_eq!(boosted_rewards(1000, 100, 1000, 50, 500), 1000);
+        assert_eq!(boosted_rewards(1000, 100, 1000, 500, 500), 1000);
+        assert_eq!(boosted_rewards(1000, 0, 1000, 500, 500), 0);
+    }
 }
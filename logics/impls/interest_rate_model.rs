@@ -17,6 +17,7 @@ pub use crate::traits::{
     interest_rate_model::*,
     types,
 };
+use ink::prelude::vec::Vec;
 use openbrush::traits::{
     Balance,
     Storage,
@@ -37,24 +38,28 @@ pub struct Data {
     kink: WrappedU256,
 }
 
-fn base() -> U256 {
+pub fn base() -> U256 {
     // 1e18
     U256::from_dec_str("1000000000000000000").unwrap()
 }
 
-fn supply_rate(utilization_rate: U256, borrow_rate: U256, one_minus_reserve_factor: U256) -> U256 {
+pub fn supply_rate(
+    utilization_rate: U256,
+    borrow_rate: U256,
+    one_minus_reserve_factor: U256,
+) -> U256 {
     let rate_to_pol = borrow_rate.mul(one_minus_reserve_factor).div(base());
     utilization_rate.mul(rate_to_pol).div(base())
 }
 
-fn milliseconds_per_year() -> U256 {
+pub fn milliseconds_per_year() -> U256 {
     U256::from(60 * 60 * 24 * 365).mul(U256::from(1000))
 }
 
 fn u256_from_balance(b: Balance) -> U256 {
     U256::from(b)
 }
-fn utilization_rate(cash: Balance, borrows: Balance, reserves: Balance) -> U256 {
+pub fn utilization_rate(cash: Balance, borrows: Balance, reserves: Balance) -> U256 {
     let (_cash, _borrows, _reserves) = (
         u256_from_balance(cash),
         u256_from_balance(borrows),
@@ -66,6 +71,64 @@ fn utilization_rate(cash: Balance, borrows: Balance, reserves: Balance) -> U256
     _borrows.mul(base()).div(_cash.add(_borrows).sub(_reserves))
 }
 
+/// Borrow rate rising by `multiplier` per utilization up to `kink`, and by `jump_multiplier` above it
+pub fn jump_rate(
+    utilization_rate: U256,
+    base_rate: U256,
+    multiplier: U256,
+    jump_multiplier: U256,
+    kink: U256,
+) -> U256 {
+    if utilization_rate.le(&kink) {
+        return utilization_rate.mul(multiplier).div(base()).add(base_rate)
+    }
+    let normal_rate = kink.mul(multiplier).div(base()).add(base_rate);
+    let excess_util = utilization_rate.sub(kink);
+    normal_rate.add(excess_util.mul(jump_multiplier).div(base()))
+}
+
+/// Borrow rate with a second, steeper jump above `kink_2`
+pub fn double_kink_rate(
+    utilization_rate: U256,
+    base_rate: U256,
+    multipliers: [U256; 3],
+    kink_1: U256,
+    kink_2: U256,
+) -> U256 {
+    if utilization_rate.le(&kink_2) {
+        return jump_rate(
+            utilization_rate,
+            base_rate,
+            multipliers[0],
+            multipliers[1],
+            kink_1,
+        )
+    }
+    let rate_at_kink_2 = jump_rate(kink_2, base_rate, multipliers[0], multipliers[1], kink_1);
+    let excess_util = utilization_rate.sub(kink_2);
+    rate_at_kink_2.add(excess_util.mul(multipliers[2]).div(base()))
+}
+
+/// Samples the rates of `model` at `points` utilization rates evenly spaced from 0 to 100%
+pub fn sample_curve<T: InterestRateModel + ?Sized>(
+    model: &T,
+    points: u8,
+    reserve_factor_mantissa: WrappedU256,
+) -> Vec<RateCurvePoint> {
+    let last = Balance::from(points.saturating_sub(1));
+    (0..Balance::from(points))
+        .map(|i| {
+            // no reserves, so that cash and borrows alone give the utilization rate
+            let (cash, borrows) = (last - i, i);
+            RateCurvePoint {
+                utilization_rate: model.utilization_rate(cash, borrows, 0),
+                borrow_rate: model.get_borrow_rate(cash, borrows, 0),
+                supply_rate: model.get_supply_rate(cash, borrows, 0, reserve_factor_mantissa),
+            }
+        })
+        .collect()
+}
+
 pub trait Internal {
     fn _get_borrow_rate(&self, cash: Balance, borrows: Balance, reserves: Balance) -> WrappedU256;
     fn _get_supply_rate(
@@ -97,23 +160,13 @@ impl Data {
 
     fn borrow_rate(&self, _cash: Balance, _borrows: Balance, _reserves: Balance) -> WrappedU256 {
         let util = utilization_rate(_cash, _borrows, _reserves);
-        let data = self;
-        if util.le(&U256::from(data.kink)) {
-            let result = util
-                .mul(U256::from(data.multiplier_per_milli_second_slope_1))
-                .div(base())
-                .add(data.base_rate_per_milli_second);
-            return WrappedU256::from(result)
-        }
-        let normal_rate = U256::from(data.kink)
-            .mul(U256::from(self.multiplier_per_milli_second_slope_1))
-            .div(base())
-            .add(U256::from(data.base_rate_per_milli_second));
-        let excess_util = util.sub(U256::from(data.kink));
-        let excess_rate = excess_util
-            .mul(U256::from(data.multiplier_per_milli_second_slope_2))
-            .div(base());
-        WrappedU256::from(normal_rate.add(excess_rate))
+        WrappedU256::from(jump_rate(
+            util,
+            self.base_rate_per_milli_second.into(),
+            self.multiplier_per_milli_second_slope_1.into(),
+            self.multiplier_per_milli_second_slope_2.into(),
+            self.kink.into(),
+        ))
     }
 
     fn supply_rate(
@@ -144,6 +197,15 @@ impl Data {
 }
 
 impl<T: Storage<Data>> InterestRateModel for T {
+    default fn utilization_rate(
+        &self,
+        cash: Balance,
+        borrows: Balance,
+        reserves: Balance,
+    ) -> WrappedU256 {
+        WrappedU256::from(utilization_rate(cash, borrows, reserves))
+    }
+
     default fn get_borrow_rate(
         &self,
         cash: Balance,
@@ -162,6 +224,14 @@ impl<T: Storage<Data>> InterestRateModel for T {
     ) -> WrappedU256 {
         self._get_supply_rate(cash, borrows, reserves, reserve_factor_mantissa)
     }
+
+    default fn sample_curve(
+        &self,
+        points: u8,
+        reserve_factor_mantissa: WrappedU256,
+    ) -> Vec<RateCurvePoint> {
+        sample_curve(self, points, reserve_factor_mantissa)
+    }
 }
 
 impl<T: Storage<Data>> Internal for T {
@@ -367,4 +437,25 @@ mod tests {
             assert_eq!(got, want)
         }
     }
+
+    #[test]
+    fn test_double_kink_rate() {
+        let multipliers = [percent(10), percent(50), percent(300)];
+        let rate = |util| double_kink_rate(util, percent(1), multipliers, percent(80), percent(95));
+        // under kink 1: 1% + 10% * 50%
+        assert_eq!(rate(percent(50)), percent(1).add(percent(5)));
+        // between kinks: 1% + 10% * 80% + 50% * 10%
+        assert_eq!(
+            rate(percent(90)),
+            percent(1).add(percent(8)).add(percent(5))
+        );
+        // over kink 2: 1% + 10% * 80% + 50% * 15% + 300% * 5%
+        assert_eq!(
+            rate(percent(100)),
+            percent(1)
+                .add(percent(8))
+                .add(mul_base(75).div(U256::from(1000)))
+                .add(percent(15))
+        );
+    }
 }
@@ -0,0 +1,179 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::interest_rate_model::{
+    base,
+    jump_rate,
+    milliseconds_per_year,
+    supply_rate,
+    utilization_rate,
+};
+pub use crate::traits::jump_rate_model::*;
+use crate::traits::types::WrappedU256;
+use core::ops::{
+    Div,
+    Sub,
+};
+use openbrush::{
+    contracts::ownable::{
+        self,
+        only_owner,
+    },
+    modifiers,
+    traits::{
+        Balance,
+        Storage,
+    },
+};
+use primitive_types::U256;
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Debug, Default)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// Borrow rate per milliseconds at zero utilization
+    pub base_rate_per_milli_second: WrappedU256,
+    /// Slope of the borrow rate per milliseconds up to the kink
+    pub multiplier_per_milli_second: WrappedU256,
+    /// Slope of the borrow rate per milliseconds above the kink
+    pub jump_multiplier_per_milli_second: WrappedU256,
+    /// Utilization rate above which the jump multiplier applies
+    pub kink: WrappedU256,
+}
+
+pub trait Internal {
+    fn _update_jump_rate_model(
+        &mut self,
+        base_rate_per_year: WrappedU256,
+        multiplier_per_year: WrappedU256,
+        jump_multiplier_per_year: WrappedU256,
+        kink: WrappedU256,
+    ) -> Result<()>;
+    fn _get_borrow_rate(&self, cash: Balance, borrows: Balance, reserves: Balance) -> WrappedU256;
+    fn _get_supply_rate(
+        &self,
+        cash: Balance,
+        borrows: Balance,
+        reserves: Balance,
+        reserve_factor_mantissa: WrappedU256,
+    ) -> WrappedU256;
+
+    // event emission
+    fn _emit_new_interest_params_event(
+        &self,
+        base_rate_per_milli_second: WrappedU256,
+        multiplier_per_milli_second: WrappedU256,
+        jump_multiplier_per_milli_second: WrappedU256,
+        kink: WrappedU256,
+    );
+}
+
+fn per_milli_second(per_year: WrappedU256) -> WrappedU256 {
+    WrappedU256::from(U256::from(per_year).div(milliseconds_per_year()))
+}
+
+impl<T: Storage<Data> + Storage<ownable::Data>> JumpRateModel for T {
+    #[modifiers(only_owner)]
+    default fn update_jump_rate_model(
+        &mut self,
+        base_rate_per_year: WrappedU256,
+        multiplier_per_year: WrappedU256,
+        jump_multiplier_per_year: WrappedU256,
+        kink: WrappedU256,
+    ) -> Result<()> {
+        self._update_jump_rate_model(
+            base_rate_per_year,
+            multiplier_per_year,
+            jump_multiplier_per_year,
+            kink,
+        )
+    }
+
+    default fn base_rate_per_milli_second(&self) -> WrappedU256 {
+        self.data::<Data>().base_rate_per_milli_second
+    }
+
+    default fn multiplier_per_milli_second(&self) -> WrappedU256 {
+        self.data::<Data>().multiplier_per_milli_second
+    }
+
+    default fn jump_multiplier_per_milli_second(&self) -> WrappedU256 {
+        self.data::<Data>().jump_multiplier_per_milli_second
+    }
+
+    default fn kink(&self) -> WrappedU256 {
+        self.data::<Data>().kink
+    }
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _update_jump_rate_model(
+        &mut self,
+        base_rate_per_year: WrappedU256,
+        multiplier_per_year: WrappedU256,
+        jump_multiplier_per_year: WrappedU256,
+        kink: WrappedU256,
+    ) -> Result<()> {
+        if U256::from(kink).gt(&base()) {
+            return Err(Error::InvalidKink)
+        }
+        let data = self.data::<Data>();
+        data.base_rate_per_milli_second = per_milli_second(base_rate_per_year);
+        data.multiplier_per_milli_second = per_milli_second(multiplier_per_year);
+        data.jump_multiplier_per_milli_second = per_milli_second(jump_multiplier_per_year);
+        data.kink = kink;
+        self._emit_new_interest_params_event(
+            self.data::<Data>().base_rate_per_milli_second,
+            self.data::<Data>().multiplier_per_milli_second,
+            self.data::<Data>().jump_multiplier_per_milli_second,
+            kink,
+        );
+        Ok(())
+    }
+
+    default fn _get_borrow_rate(
+        &self,
+        cash: Balance,
+        borrows: Balance,
+        reserves: Balance,
+    ) -> WrappedU256 {
+        let data = self.data::<Data>();
+        WrappedU256::from(jump_rate(
+            utilization_rate(cash, borrows, reserves),
+            data.base_rate_per_milli_second.into(),
+            data.multiplier_per_milli_second.into(),
+            data.jump_multiplier_per_milli_second.into(),
+            data.kink.into(),
+        ))
+    }
+
+    default fn _get_supply_rate(
+        &self,
+        cash: Balance,
+        borrows: Balance,
+        reserves: Balance,
+        reserve_factor_mantissa: WrappedU256,
+    ) -> WrappedU256 {
+        let one_minus_reserve_factor = base().sub(U256::from(reserve_factor_mantissa));
+        let borrow_rate = self._get_borrow_rate(cash, borrows, reserves);
+        WrappedU256::from(supply_rate(
+            utilization_rate(cash, borrows, reserves),
+            borrow_rate.into(),
+            one_minus_reserve_factor,
+        ))
+    }
+
+    default fn _emit_new_interest_params_event(
+        &self,
+        _base_rate_per_milli_second: WrappedU256,
+        _multiplier_per_milli_second: WrappedU256,
+        _jump_multiplier_per_milli_second: WrappedU256,
+        _kink: WrappedU256,
+    ) {
+    }
+}
@@ -0,0 +1,101 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+pub use crate::traits::lay_token::*;
+use core::ops::Sub;
+use openbrush::{
+    contracts::psp22::{
+        self,
+        PSP22Error,
+        PSP22,
+    },
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+    },
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Debug, Default)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// Maximum total supply
+    pub cap: Balance,
+}
+
+pub trait Internal {
+    fn _initialize(&mut self, cap: Balance);
+    fn _mint(&mut self, to: AccountId, amount: Balance) -> Result<()>;
+    fn _burn(&mut self, account: AccountId, amount: Balance) -> Result<()>;
+    fn _burn_with_allowance(
+        &mut self,
+        spender: AccountId,
+        account: AccountId,
+        amount: Balance,
+    ) -> Result<()>;
+
+    // view functions
+    fn _cap(&self) -> Balance;
+}
+
+/// NOTE: Apply permission control to `mint` by overriding it with roles.
+impl<T: Storage<Data> + Storage<psp22::Data>> LayToken for T {
+    default fn mint(&mut self, to: AccountId, amount: Balance) -> Result<()> {
+        self._mint(to, amount)
+    }
+
+    default fn burn(&mut self, amount: Balance) -> Result<()> {
+        self._burn(Self::env().caller(), amount)
+    }
+
+    default fn burn_from(&mut self, account: AccountId, amount: Balance) -> Result<()> {
+        self._burn_with_allowance(Self::env().caller(), account, amount)
+    }
+
+    default fn cap(&self) -> Balance {
+        self._cap()
+    }
+}
+
+impl<T: Storage<Data> + Storage<psp22::Data>> Internal for T {
+    default fn _initialize(&mut self, cap: Balance) {
+        self.data::<Data>().cap = cap;
+    }
+
+    default fn _mint(&mut self, to: AccountId, amount: Balance) -> Result<()> {
+        if amount > self._cap().saturating_sub(PSP22::total_supply(self)) {
+            return Err(Error::CapExceeded)
+        }
+        psp22::Internal::_mint_to(self, to, amount)?;
+        Ok(())
+    }
+
+    default fn _burn(&mut self, account: AccountId, amount: Balance) -> Result<()> {
+        psp22::Internal::_burn_from(self, account, amount)?;
+        Ok(())
+    }
+
+    default fn _burn_with_allowance(
+        &mut self,
+        spender: AccountId,
+        account: AccountId,
+        amount: Balance,
+    ) -> Result<()> {
+        let allowance = PSP22::allowance(self, account, spender);
+        if allowance < amount {
+            return Err(PSP22Error::InsufficientAllowance.into())
+        }
+        psp22::Internal::_approve_from_to(self, account, spender, allowance.sub(amount))?;
+        self._burn(account, amount)
+    }
+
+    default fn _cap(&self) -> Balance {
+        self.data::<Data>().cap
+    }
+}
@@ -19,6 +19,10 @@ use super::{
         AccountData,
         ControllerRef,
     },
+    flashloan_gateway::{
+        FlashLoanType,
+        FlashloanGatewayRef,
+    },
     pool::PoolRef,
     price_oracle::{
         PriceOracleRef,
@@ -30,6 +34,10 @@ pub use crate::traits::{
     leverager::*,
     types::WrappedU256,
 };
+use ink::prelude::{
+    vec,
+    vec::Vec,
+};
 use openbrush::{
     contracts::psp22::PSP22Ref,
     traits::{
@@ -39,6 +47,10 @@ use openbrush::{
     },
 };
 use primitive_types::U256;
+use scale::{
+    Decode,
+    Encode,
+};
 
 pub const CLOSE_MAX_LOOPS: u128 = 40;
 
@@ -55,6 +67,8 @@ pub struct Data {
     pub price_oracle: Option<AccountId>,
     /// AccountId of Manager
     pub manager: Option<AccountId>,
+    /// AccountId of Flashloan Gateway
+    pub flashloan_gateway: Option<AccountId>,
 }
 
 pub trait Internal {
@@ -66,6 +80,8 @@ pub trait Internal {
 
     fn _manager(&self) -> Option<AccountId>;
 
+    fn _flashloan_gateway(&self) -> Option<AccountId>;
+
     fn _get_available_borrows(&self, account: AccountId) -> Option<AvailableBorrows>;
 
     fn _loan_to_value(&self, asset: AccountId) -> u128;
@@ -111,6 +127,19 @@ pub trait Internal {
     ) -> Result<()>;
 
     fn _close(&mut self, asset: AccountId) -> Result<()>;
+
+    fn _leverage_asset(&mut self, asset: AccountId, amount: Balance, leverage: u128) -> Result<()>;
+
+    fn _close_position(&mut self, asset: AccountId) -> Result<()>;
+
+    fn _execute_operation(
+        &mut self,
+        assets: Vec<AccountId>,
+        amounts: Vec<Balance>,
+        premiums: Vec<Balance>,
+        initiator: AccountId,
+        params: Vec<u8>,
+    ) -> Result<()>;
 }
 
 impl<T: Storage<Data>> Leverager for T {
@@ -130,6 +159,10 @@ impl<T: Storage<Data>> Leverager for T {
         self._manager()
     }
 
+    default fn flashloan_gateway(&self) -> Option<AccountId> {
+        self._flashloan_gateway()
+    }
+
     default fn get_available_borrows(&self, account: AccountId) -> Option<AvailableBorrows> {
         self._get_available_borrows(account)
     }
@@ -186,6 +219,25 @@ impl<T: Storage<Data>> Leverager for T {
     default fn loop_eth(&mut self, borrow_ratio: u128, loop_count: u128) -> Result<()> {
         self._loop_eth(borrow_ratio, loop_count)
     }
+
+    default fn set_flashloan_gateway(&mut self, flashloan_gateway: AccountId) -> Result<()> {
+        self._assert_manager()?;
+        self.data().flashloan_gateway = Some(flashloan_gateway);
+        Ok(())
+    }
+
+    default fn leverage_asset(
+        &mut self,
+        asset: AccountId,
+        amount: Balance,
+        leverage: u128,
+    ) -> Result<()> {
+        self._leverage_asset(asset, amount, leverage)
+    }
+
+    default fn close_position(&mut self, asset: AccountId) -> Result<()> {
+        self._close_position(asset)
+    }
 }
 
 impl<T: Storage<Data>> Internal for T {
@@ -227,6 +279,10 @@ impl<T: Storage<Data>> Internal for T {
         self.data().manager
     }
 
+    default fn _flashloan_gateway(&self) -> Option<AccountId> {
+        self.data().flashloan_gateway
+    }
+
     default fn _get_available_borrows(&self, account: AccountId) -> Option<AvailableBorrows> {
         if let Some(controller) = self._controller() {
             let account_data_result =
@@ -558,4 +614,142 @@ impl<T: Storage<Data>> Internal for T {
 
         Ok(())
     }
+
+    default fn _leverage_asset(
+        &mut self,
+        asset: AccountId,
+        amount: Balance,
+        leverage: u128,
+    ) -> Result<()> {
+        // the borrowed share of the deposit must stay within the loan to value
+        let ltv = self._loan_to_value(asset);
+        if leverage <= 10000
+            || U256::from(leverage - 10000).mul(U256::from(10000))
+                > U256::from(leverage).mul(U256::from(ltv))
+        {
+            return Err(Error::InappropriateLeverage)
+        }
+        let flashloan_gateway = self
+            ._flashloan_gateway()
+            .ok_or(Error::FlashloanGatewayIsNotSet)?;
+
+        let caller = Self::env().caller();
+        let contract_addr = Self::env().account_id();
+        PSP22Ref::transfer_from(&asset, caller, contract_addr, amount, Default::default())?;
+
+        let flashloan_amount = U256::from(amount)
+            .mul(U256::from(leverage - 10000))
+            .div(U256::from(10000))
+            .as_u128();
+        let params = FlashloanOperation::Open {
+            account: caller,
+            deposit: amount,
+        }
+        .encode();
+        FlashloanGatewayRef::flashloan_builder(
+            &flashloan_gateway,
+            contract_addr,
+            vec![asset],
+            vec![flashloan_amount],
+            vec![FlashLoanType::Borrowing as u8],
+            caller,
+            params,
+        )
+        .call_flags(ink_env::CallFlags::default().set_allow_reentry(true))
+        .try_invoke()
+        .unwrap()
+        .unwrap()?;
+        Ok(())
+    }
+
+    default fn _close_position(&mut self, asset: AccountId) -> Result<()> {
+        let flashloan_gateway = self
+            ._flashloan_gateway()
+            .ok_or(Error::FlashloanGatewayIsNotSet)?;
+        let controller = self._controller().ok_or(Error::ControllerIsNotSet)?;
+        let pool = ControllerRef::market_of_underlying(&controller, asset)
+            .ok_or(Error::MarketNotListed)?;
+
+        let caller = Self::env().caller();
+        let contract_addr = Self::env().account_id();
+        let repay_amount = PoolRef::borrow_balance_current(&pool, caller)?;
+        let params = FlashloanOperation::Close { account: caller }.encode();
+        FlashloanGatewayRef::flashloan_builder(
+            &flashloan_gateway,
+            contract_addr,
+            vec![asset],
+            vec![repay_amount],
+            vec![FlashLoanType::None as u8],
+            caller,
+            params,
+        )
+        .call_flags(ink_env::CallFlags::default().set_allow_reentry(true))
+        .try_invoke()
+        .unwrap()
+        .unwrap()?;
+        Ok(())
+    }
+
+    default fn _execute_operation(
+        &mut self,
+        assets: Vec<AccountId>,
+        amounts: Vec<Balance>,
+        premiums: Vec<Balance>,
+        initiator: AccountId,
+        params: Vec<u8>,
+    ) -> Result<()> {
+        let flashloan_gateway = self
+            ._flashloan_gateway()
+            .ok_or(Error::FlashloanGatewayIsNotSet)?;
+        if Self::env().caller() != flashloan_gateway {
+            return Err(Error::CallerIsNotFlashloanGateway)
+        }
+        let contract_addr = Self::env().account_id();
+        // only flashloans initiated by this contract carry operations to run
+        if initiator != contract_addr || assets.len() != 1 {
+            return Err(Error::InvalidFlashloanParams)
+        }
+        let operation = FlashloanOperation::decode(&mut &params[..])
+            .map_err(|_| Error::InvalidFlashloanParams)?;
+
+        let (asset, amount, premium) = (assets[0], amounts[0], premiums[0]);
+        let controller = self._controller().ok_or(Error::ControllerIsNotSet)?;
+        let pool = ControllerRef::market_of_underlying(&controller, asset)
+            .ok_or(Error::MarketNotListed)?;
+
+        match operation {
+            FlashloanOperation::Open { account, deposit } => {
+                let deposit_amount = deposit + amount;
+                PSP22Ref::approve(&asset, pool, deposit_amount)?;
+                PoolRef::mint_to(&pool, account, deposit_amount)?;
+            }
+            FlashloanOperation::Close { account } => {
+                PSP22Ref::approve(&asset, pool, amount)?;
+                PoolRef::repay_borrow_behalf(&pool, account, amount)?;
+
+                let withdraw_amount = PoolRef::balance_of(&pool, account);
+                let repayment = amount + premium;
+                if withdraw_amount < repayment {
+                    return Err(Error::InsufficientDeposit)
+                }
+                PoolRef::transfer_from(
+                    &pool,
+                    account,
+                    contract_addr,
+                    withdraw_amount,
+                    Default::default(),
+                )?;
+                PoolRef::redeem_underlying(&pool, withdraw_amount)?;
+                PSP22Ref::transfer(
+                    &asset,
+                    account,
+                    withdraw_amount - repayment,
+                    Default::default(),
+                )?;
+                // the gateway pulls the repayment from this contract
+                PSP22Ref::approve(&asset, flashloan_gateway, repayment)?;
+            }
+        }
+        Ok(())
+    }
 }
@@ -8,14 +8,45 @@
 pub use crate::traits::manager::*;
 use crate::traits::{
     controller::ControllerRef,
-    pool::PoolRef,
-    types::WrappedU256,
+    flashloan_gateway::FlashloanGatewayRef,
+    ownable_2step::Ownable2StepRef,
+    pool::{
+        Facet,
+        KeeperIncentive,
+        PoolRef,
+        UnderlyingCallConfig,
+    },
+    pool_factory::{
+        PoolFactoryRef,
+        PoolParameters,
+        RateModelParameters,
+    },
+    price_oracle::{
+        DeviationBreaker,
+        ManualPriceOracleRef,
+        PriceBounds,
+    },
+    types::{
+        ActionId,
+        WrappedU256,
+    },
+    upgradeable::UpgradeableRef,
 };
-use openbrush::traits::{
-    AccountId,
-    Balance,
-    Storage,
+use ink::{
+    prelude::vec::Vec,
+    selector_bytes,
 };
+use openbrush::{
+    contracts::psp22::PSP22Ref,
+    traits::{
+        AccountId,
+        Balance,
+        Hash,
+        Storage,
+        Timestamp,
+    },
+};
+use scale::Encode;
 
 pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
 
@@ -24,10 +55,13 @@ pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
 pub struct Data {
     /// AccountId of Controller
     pub controller: AccountId,
+    /// AccountId receiving reduced reserves
+    pub treasury: Option<AccountId>,
 }
 
 pub trait Internal {
     fn _controller(&self) -> AccountId;
+    fn _treasury(&self) -> Option<AccountId>;
     fn _set_controller(&mut self, id: AccountId) -> Result<()>;
     fn _set_price_oracle(&mut self, new_oracle: AccountId) -> Result<()>;
     fn _set_flashloan_gateway(&mut self, new_flashloan_gateway: AccountId) -> Result<()>;
@@ -57,7 +91,108 @@ pub trait Internal {
         new_reserve_factor_mantissa: WrappedU256,
     ) -> Result<()>;
     fn _reduce_reserves(&mut self, pool: AccountId, amount: Balance) -> Result<()>;
+    fn _cover_bad_debt(&mut self, pool: AccountId, amount: Balance) -> Result<()>;
     fn _sweep_token(&mut self, pool: AccountId, asset: AccountId) -> Result<()>;
+    fn _price_oracle(&self) -> Result<AccountId>;
+    fn _flashloan_gateway(&self) -> Result<AccountId>;
+    fn _pool_factory(&self) -> Result<AccountId>;
+    fn _set_seize_guardian_paused(&mut self, paused: bool) -> Result<()>;
+    fn _set_transfer_guardian_paused(&mut self, paused: bool) -> Result<()>;
+    fn _set_shutdown_guardian(&mut self, guardian: Option<AccountId>) -> Result<()>;
+    fn _set_pool_factory(&mut self, pool_factory: Option<AccountId>) -> Result<()>;
+    fn _create_pool(&mut self, underlying: AccountId, parameters: PoolParameters) -> Result<()>;
+    fn _set_pool_code_hash(&mut self, code_hash: Hash) -> Result<()>;
+    fn _create_rate_model(
+        &mut self,
+        code_hash: Hash,
+        parameters: RateModelParameters,
+    ) -> Result<()>;
+    fn _set_rate_model_code_hash_approval(&mut self, code_hash: Hash, approved: bool)
+        -> Result<()>;
+    fn _shutdown_protocol(&mut self) -> Result<()>;
+    fn _recover_protocol(&mut self) -> Result<()>;
+    fn _reactivate_protocol(&mut self) -> Result<()>;
+    fn _set_interest_rate_model(
+        &mut self,
+        pool: AccountId,
+        new_interest_rate_model: AccountId,
+    ) -> Result<()>;
+    fn _set_liquidation_threshold(
+        &mut self,
+        pool: AccountId,
+        new_liquidation_threshold: u128,
+    ) -> Result<()>;
+    fn _set_borrow_rate_max_mantissa(
+        &mut self,
+        pool: AccountId,
+        new_borrow_rate_max_mantissa: WrappedU256,
+    ) -> Result<()>;
+    fn _set_stable_rate_config(
+        &mut self,
+        pool: AccountId,
+        enabled: bool,
+        premium_mantissa: WrappedU256,
+        rebalance_threshold_mantissa: WrappedU256,
+    ) -> Result<()>;
+    fn _set_underlying_call_config(
+        &mut self,
+        pool: AccountId,
+        config: UnderlyingCallConfig,
+    ) -> Result<()>;
+    fn _set_max_accrual_delta(
+        &mut self,
+        pool: AccountId,
+        new_max_accrual_delta: Timestamp,
+    ) -> Result<()>;
+    fn _set_compound_interest(&mut self, pool: AccountId, enabled: bool) -> Result<()>;
+    fn _set_flash_mint_premium(&mut self, pool: AccountId, premium: u128) -> Result<()>;
+    fn _set_origination_fee(&mut self, pool: AccountId, fee: u128) -> Result<()>;
+    fn _set_reserves_sweep_threshold(&mut self, pool: AccountId, threshold: Balance) -> Result<()>;
+    fn _set_keeper_incentive(&mut self, pool: AccountId, incentive: KeeperIncentive) -> Result<()>;
+    fn _set_incentives_controller(
+        &mut self,
+        pool: AccountId,
+        incentives_controller: AccountId,
+    ) -> Result<()>;
+    fn _set_account_supply_cap(&mut self, pool: AccountId, new_cap: Balance) -> Result<()>;
+    fn _set_outflow_limit(
+        &mut self,
+        pool: AccountId,
+        limit: Balance,
+        window: Timestamp,
+    ) -> Result<()>;
+    fn _set_reporter(&mut self, reporter: AccountId, authorized: bool) -> Result<()>;
+    fn _set_heartbeat(&mut self, asset: AccountId, heartbeat: Timestamp) -> Result<()>;
+    fn _set_price_decimals(&mut self, asset: AccountId, decimals: u8) -> Result<()>;
+    fn _set_price_bounds(&mut self, asset: AccountId, bounds: PriceBounds) -> Result<()>;
+    fn _set_deviation_breaker(&mut self, asset: AccountId, breaker: DeviationBreaker)
+        -> Result<()>;
+    fn _set_flashloan_premium_total(&mut self, premium: u128) -> Result<()>;
+    fn _set_flashloan_premium_to_protocol(&mut self, premium_to_protocol: u128) -> Result<()>;
+    fn _set_treasury(&mut self, treasury: AccountId) -> Result<()>;
+    fn _set_premium_exemption(&mut self, account: AccountId, premium: Option<u128>) -> Result<()>;
+    fn _set_flashloan_paused(&mut self, paused: bool) -> Result<()>;
+    fn _set_flashloan_enabled(&mut self, asset: AccountId, enabled: bool) -> Result<()>;
+    fn _accept_controller_manager(&mut self) -> Result<()>;
+    fn _accept_pool_manager(&mut self, pool: AccountId) -> Result<()>;
+    fn _accept_ownership_of(&mut self, target: AccountId) -> Result<()>;
+    fn _upgrade_controller_code(&mut self, code_hash: Hash) -> Result<()>;
+    fn _upgrade_pool_code(&mut self, pool: AccountId, code_hash: Hash) -> Result<()>;
+    fn _set_pool_facet(
+        &mut self,
+        pool: AccountId,
+        facet: Facet,
+        code_hash: Option<Hash>,
+    ) -> Result<()>;
+
+    // event emission
+    fn _emit_admin_action_event(
+        &self,
+        action: ActionId,
+        target: Option<AccountId>,
+        old_value: Vec<u8>,
+        new_value: Vec<u8>,
+    );
 }
 
 impl<T: Storage<Data>> Manager for T {
@@ -126,29 +261,442 @@ impl<T: Storage<Data>> Manager for T {
     default fn reduce_reserves(&mut self, pool: AccountId, amount: Balance) -> Result<()> {
         self._reduce_reserves(pool, amount)
     }
+    default fn cover_bad_debt(&mut self, pool: AccountId, amount: Balance) -> Result<()> {
+        self._cover_bad_debt(pool, amount)
+    }
     default fn sweep_token(&mut self, pool: AccountId, asset: AccountId) -> Result<()> {
         self._sweep_token(pool, asset)
     }
+    default fn set_seize_guardian_paused(&mut self, paused: bool) -> Result<()> {
+        self._set_seize_guardian_paused(paused)
+    }
+    default fn set_transfer_guardian_paused(&mut self, paused: bool) -> Result<()> {
+        self._set_transfer_guardian_paused(paused)
+    }
+    default fn set_shutdown_guardian(&mut self, guardian: Option<AccountId>) -> Result<()> {
+        self._set_shutdown_guardian(guardian)
+    }
+    default fn set_pool_factory(&mut self, pool_factory: Option<AccountId>) -> Result<()> {
+        self._set_pool_factory(pool_factory)
+    }
+    default fn create_pool(
+        &mut self,
+        underlying: AccountId,
+        parameters: PoolParameters,
+    ) -> Result<()> {
+        self._create_pool(underlying, parameters)
+    }
+    default fn set_pool_code_hash(&mut self, code_hash: Hash) -> Result<()> {
+        self._set_pool_code_hash(code_hash)
+    }
+    default fn create_rate_model(
+        &mut self,
+        code_hash: Hash,
+        parameters: RateModelParameters,
+    ) -> Result<()> {
+        self._create_rate_model(code_hash, parameters)
+    }
+    default fn set_rate_model_code_hash_approval(
+        &mut self,
+        code_hash: Hash,
+        approved: bool,
+    ) -> Result<()> {
+        self._set_rate_model_code_hash_approval(code_hash, approved)
+    }
+    default fn shutdown_protocol(&mut self) -> Result<()> {
+        self._shutdown_protocol()
+    }
+    default fn recover_protocol(&mut self) -> Result<()> {
+        self._recover_protocol()
+    }
+    default fn reactivate_protocol(&mut self) -> Result<()> {
+        self._reactivate_protocol()
+    }
+    default fn set_interest_rate_model(
+        &mut self,
+        pool: AccountId,
+        new_interest_rate_model: AccountId,
+    ) -> Result<()> {
+        self._set_interest_rate_model(pool, new_interest_rate_model)
+    }
+    default fn set_liquidation_threshold(
+        &mut self,
+        pool: AccountId,
+        new_liquidation_threshold: u128,
+    ) -> Result<()> {
+        self._set_liquidation_threshold(pool, new_liquidation_threshold)
+    }
+    default fn set_borrow_rate_max_mantissa(
+        &mut self,
+        pool: AccountId,
+        new_borrow_rate_max_mantissa: WrappedU256,
+    ) -> Result<()> {
+        self._set_borrow_rate_max_mantissa(pool, new_borrow_rate_max_mantissa)
+    }
+    default fn set_stable_rate_config(
+        &mut self,
+        pool: AccountId,
+        enabled: bool,
+        premium_mantissa: WrappedU256,
+        rebalance_threshold_mantissa: WrappedU256,
+    ) -> Result<()> {
+        self._set_stable_rate_config(
+            pool,
+            enabled,
+            premium_mantissa,
+            rebalance_threshold_mantissa,
+        )
+    }
+    default fn set_underlying_call_config(
+        &mut self,
+        pool: AccountId,
+        config: UnderlyingCallConfig,
+    ) -> Result<()> {
+        self._set_underlying_call_config(pool, config)
+    }
+    default fn set_max_accrual_delta(
+        &mut self,
+        pool: AccountId,
+        new_max_accrual_delta: Timestamp,
+    ) -> Result<()> {
+        self._set_max_accrual_delta(pool, new_max_accrual_delta)
+    }
+    default fn set_compound_interest(&mut self, pool: AccountId, enabled: bool) -> Result<()> {
+        self._set_compound_interest(pool, enabled)
+    }
+    default fn set_flash_mint_premium(&mut self, pool: AccountId, premium: u128) -> Result<()> {
+        self._set_flash_mint_premium(pool, premium)
+    }
+    default fn set_origination_fee(&mut self, pool: AccountId, fee: u128) -> Result<()> {
+        self._set_origination_fee(pool, fee)
+    }
+    default fn set_reserves_sweep_threshold(
+        &mut self,
+        pool: AccountId,
+        threshold: Balance,
+    ) -> Result<()> {
+        self._set_reserves_sweep_threshold(pool, threshold)
+    }
+    default fn set_keeper_incentive(
+        &mut self,
+        pool: AccountId,
+        incentive: KeeperIncentive,
+    ) -> Result<()> {
+        self._set_keeper_incentive(pool, incentive)
+    }
+    default fn set_incentives_controller(
+        &mut self,
+        pool: AccountId,
+        incentives_controller: AccountId,
+    ) -> Result<()> {
+        self._set_incentives_controller(pool, incentives_controller)
+    }
+    default fn set_account_supply_cap(&mut self, pool: AccountId, new_cap: Balance) -> Result<()> {
+        self._set_account_supply_cap(pool, new_cap)
+    }
+    default fn set_outflow_limit(
+        &mut self,
+        pool: AccountId,
+        limit: Balance,
+        window: Timestamp,
+    ) -> Result<()> {
+        self._set_outflow_limit(pool, limit, window)
+    }
+    default fn set_reporter(&mut self, reporter: AccountId, authorized: bool) -> Result<()> {
+        self._set_reporter(reporter, authorized)
+    }
+    default fn set_heartbeat(&mut self, asset: AccountId, heartbeat: Timestamp) -> Result<()> {
+        self._set_heartbeat(asset, heartbeat)
+    }
+    default fn set_price_decimals(&mut self, asset: AccountId, decimals: u8) -> Result<()> {
+        self._set_price_decimals(asset, decimals)
+    }
+    default fn set_price_bounds(&mut self, asset: AccountId, bounds: PriceBounds) -> Result<()> {
+        self._set_price_bounds(asset, bounds)
+    }
+    default fn set_deviation_breaker(
+        &mut self,
+        asset: AccountId,
+        breaker: DeviationBreaker,
+    ) -> Result<()> {
+        self._set_deviation_breaker(asset, breaker)
+    }
+    default fn set_flashloan_premium_total(&mut self, premium: u128) -> Result<()> {
+        self._set_flashloan_premium_total(premium)
+    }
+    default fn set_flashloan_premium_to_protocol(
+        &mut self,
+        premium_to_protocol: u128,
+    ) -> Result<()> {
+        self._set_flashloan_premium_to_protocol(premium_to_protocol)
+    }
+    default fn set_treasury(&mut self, treasury: AccountId) -> Result<()> {
+        self._set_treasury(treasury)
+    }
+    default fn treasury(&self) -> Option<AccountId> {
+        self._treasury()
+    }
+    default fn set_premium_exemption(
+        &mut self,
+        account: AccountId,
+        premium: Option<u128>,
+    ) -> Result<()> {
+        self._set_premium_exemption(account, premium)
+    }
+    default fn set_flashloan_paused(&mut self, paused: bool) -> Result<()> {
+        self._set_flashloan_paused(paused)
+    }
+    default fn set_flashloan_enabled(&mut self, asset: AccountId, enabled: bool) -> Result<()> {
+        self._set_flashloan_enabled(asset, enabled)
+    }
+
+    default fn accept_controller_manager(&mut self) -> Result<()> {
+        self._accept_controller_manager()
+    }
+    default fn accept_pool_manager(&mut self, pool: AccountId) -> Result<()> {
+        self._accept_pool_manager(pool)
+    }
+    default fn accept_ownership_of(&mut self, target: AccountId) -> Result<()> {
+        self._accept_ownership_of(target)
+    }
+    default fn upgrade_controller_code(&mut self, code_hash: Hash) -> Result<()> {
+        self._upgrade_controller_code(code_hash)
+    }
+    default fn upgrade_pool_code(&mut self, pool: AccountId, code_hash: Hash) -> Result<()> {
+        self._upgrade_pool_code(pool, code_hash)
+    }
+    default fn set_pool_facet(
+        &mut self,
+        pool: AccountId,
+        facet: Facet,
+        code_hash: Option<Hash>,
+    ) -> Result<()> {
+        self._set_pool_facet(pool, facet, code_hash)
+    }
+
+    default fn execute_batch(&mut self, calls: Vec<Call>) -> Result<()> {
+        // dispatched to the messages, so each call checks the role of caller
+        for call in calls {
+            match call {
+                Call::SetController { address } => self.set_controller(address)?,
+                Call::SetPriceOracle { new_oracle } => self.set_price_oracle(new_oracle)?,
+                Call::SetFlashloanGateway {
+                    new_flashloan_gateway,
+                } => self.set_flashloan_gateway(new_flashloan_gateway)?,
+                Call::SupportMarket { pool, underlying } => {
+                    self.support_market(pool, underlying)?
+                }
+                Call::SupportMarketWithCollateralFactorMantissa {
+                    pool,
+                    underlying,
+                    collateral_factor_mantissa,
+                } => {
+                    self.support_market_with_collateral_factor_mantissa(
+                        pool,
+                        underlying,
+                        collateral_factor_mantissa,
+                    )?
+                }
+                Call::SetCollateralFactorMantissa {
+                    pool,
+                    new_collateral_factor_mantissa,
+                } => self.set_collateral_factor_mantissa(pool, new_collateral_factor_mantissa)?,
+                Call::SetMintGuardianPaused { pool, paused } => {
+                    self.set_mint_guardian_paused(pool, paused)?
+                }
+                Call::SetBorrowGuardianPaused { pool, paused } => {
+                    self.set_borrow_guardian_paused(pool, paused)?
+                }
+                Call::SetCloseFactorMantissa {
+                    new_close_factor_mantissa,
+                } => self.set_close_factor_mantissa(new_close_factor_mantissa)?,
+                Call::SetLiquidationIncentiveMantissa {
+                    new_liquidation_incentive_mantissa,
+                } => self.set_liquidation_incentive_mantissa(new_liquidation_incentive_mantissa)?,
+                Call::SetBorrowCap { pool, new_cap } => self.set_borrow_cap(pool, new_cap)?,
+                Call::SetReserveFactorMantissa {
+                    pool,
+                    new_reserve_factor_mantissa,
+                } => self.set_reserve_factor_mantissa(pool, new_reserve_factor_mantissa)?,
+                Call::ReduceReserves { pool, amount } => self.reduce_reserves(pool, amount)?,
+                Call::CoverBadDebt { pool, amount } => self.cover_bad_debt(pool, amount)?,
+                Call::SweepToken { pool, asset } => self.sweep_token(pool, asset)?,
+                Call::SetSeizeGuardianPaused { paused } => {
+                    self.set_seize_guardian_paused(paused)?
+                }
+                Call::SetTransferGuardianPaused { paused } => {
+                    self.set_transfer_guardian_paused(paused)?
+                }
+                Call::SetShutdownGuardian { guardian } => self.set_shutdown_guardian(guardian)?,
+                Call::SetPoolFactory { pool_factory } => self.set_pool_factory(pool_factory)?,
+                Call::CreatePool {
+                    underlying,
+                    parameters,
+                } => self.create_pool(underlying, parameters)?,
+                Call::SetPoolCodeHash { code_hash } => self.set_pool_code_hash(code_hash)?,
+                Call::CreateRateModel {
+                    code_hash,
+                    parameters,
+                } => self.create_rate_model(code_hash, parameters)?,
+                Call::SetRateModelCodeHashApproval {
+                    code_hash,
+                    approved,
+                } => self.set_rate_model_code_hash_approval(code_hash, approved)?,
+                Call::ShutdownProtocol => self.shutdown_protocol()?,
+                Call::RecoverProtocol => self.recover_protocol()?,
+                Call::ReactivateProtocol => self.reactivate_protocol()?,
+                Call::SetInterestRateModel {
+                    pool,
+                    new_interest_rate_model,
+                } => self.set_interest_rate_model(pool, new_interest_rate_model)?,
+                Call::SetLiquidationThreshold {
+                    pool,
+                    new_liquidation_threshold,
+                } => self.set_liquidation_threshold(pool, new_liquidation_threshold)?,
+                Call::SetBorrowRateMaxMantissa {
+                    pool,
+                    new_borrow_rate_max_mantissa,
+                } => self.set_borrow_rate_max_mantissa(pool, new_borrow_rate_max_mantissa)?,
+                Call::SetStableRateConfig {
+                    pool,
+                    enabled,
+                    premium_mantissa,
+                    rebalance_threshold_mantissa,
+                } => {
+                    self.set_stable_rate_config(
+                        pool,
+                        enabled,
+                        premium_mantissa,
+                        rebalance_threshold_mantissa,
+                    )?
+                }
+                Call::SetUnderlyingCallConfig { pool, config } => {
+                    self.set_underlying_call_config(pool, config)?
+                }
+                Call::SetMaxAccrualDelta {
+                    pool,
+                    new_max_accrual_delta,
+                } => self.set_max_accrual_delta(pool, new_max_accrual_delta)?,
+                Call::SetCompoundInterest { pool, enabled } => {
+                    self.set_compound_interest(pool, enabled)?
+                }
+                Call::SetFlashMintPremium { pool, premium } => {
+                    self.set_flash_mint_premium(pool, premium)?
+                }
+                Call::SetOriginationFee { pool, fee } => self.set_origination_fee(pool, fee)?,
+                Call::SetReservesSweepThreshold { pool, threshold } => {
+                    self.set_reserves_sweep_threshold(pool, threshold)?
+                }
+                Call::SetKeeperIncentive { pool, incentive } => {
+                    self.set_keeper_incentive(pool, incentive)?
+                }
+                Call::SetIncentivesController {
+                    pool,
+                    incentives_controller,
+                } => self.set_incentives_controller(pool, incentives_controller)?,
+                Call::SetAccountSupplyCap { pool, new_cap } => {
+                    self.set_account_supply_cap(pool, new_cap)?
+                }
+                Call::SetOutflowLimit {
+                    pool,
+                    limit,
+                    window,
+                } => self.set_outflow_limit(pool, limit, window)?,
+                Call::SetReporter {
+                    reporter,
+                    authorized,
+                } => self.set_reporter(reporter, authorized)?,
+                Call::SetHeartbeat { asset, heartbeat } => self.set_heartbeat(asset, heartbeat)?,
+                Call::SetPriceDecimals { asset, decimals } => {
+                    self.set_price_decimals(asset, decimals)?
+                }
+                Call::SetPriceBounds { asset, bounds } => self.set_price_bounds(asset, bounds)?,
+                Call::SetDeviationBreaker { asset, breaker } => {
+                    self.set_deviation_breaker(asset, breaker)?
+                }
+                Call::SetFlashloanPremiumTotal { premium } => {
+                    self.set_flashloan_premium_total(premium)?
+                }
+                Call::SetFlashloanPremiumToProtocol {
+                    premium_to_protocol,
+                } => self.set_flashloan_premium_to_protocol(premium_to_protocol)?,
+                Call::SetTreasury { treasury } => self.set_treasury(treasury)?,
+                Call::SetPremiumExemption { account, premium } => {
+                    self.set_premium_exemption(account, premium)?
+                }
+                Call::SetFlashloanPaused { paused } => self.set_flashloan_paused(paused)?,
+                Call::SetFlashloanEnabled { asset, enabled } => {
+                    self.set_flashloan_enabled(asset, enabled)?
+                }
+                Call::AcceptControllerManager => self.accept_controller_manager()?,
+                Call::AcceptPoolManager { pool } => self.accept_pool_manager(pool)?,
+                Call::AcceptOwnershipOf { target } => self.accept_ownership_of(target)?,
+                Call::UpgradeControllerCode { code_hash } => {
+                    self.upgrade_controller_code(code_hash)?
+                }
+                Call::UpgradePoolCode { pool, code_hash } => {
+                    self.upgrade_pool_code(pool, code_hash)?
+                }
+                Call::SetPoolFacet {
+                    pool,
+                    facet,
+                    code_hash,
+                } => self.set_pool_facet(pool, facet, code_hash)?,
+            }
+        }
+        Ok(())
+    }
 }
 
 impl<T: Storage<Data>> Internal for T {
     default fn _controller(&self) -> AccountId {
         self.data().controller
     }
+    default fn _treasury(&self) -> Option<AccountId> {
+        self.data().treasury
+    }
     default fn _set_controller(&mut self, id: AccountId) -> Result<()> {
+        let old = self._controller();
         self.data().controller = id;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_controller"),
+            None,
+            old.encode(),
+            id.encode(),
+        );
         Ok(())
     }
     default fn _set_price_oracle(&mut self, new_oracle: AccountId) -> Result<()> {
-        ControllerRef::set_price_oracle(&self._controller(), new_oracle)?;
+        let target = self._controller();
+        ControllerRef::set_price_oracle(&target, new_oracle)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_price_oracle"),
+            Some(target),
+            Vec::new(),
+            new_oracle.encode(),
+        );
         Ok(())
     }
     default fn _set_flashloan_gateway(&mut self, new_flashloan_gateway: AccountId) -> Result<()> {
-        ControllerRef::set_flashloan_gateway(&self._controller(), new_flashloan_gateway)?;
+        let target = self._controller();
+        ControllerRef::set_flashloan_gateway(&target, new_flashloan_gateway)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_flashloan_gateway"),
+            Some(target),
+            Vec::new(),
+            new_flashloan_gateway.encode(),
+        );
         Ok(())
     }
     default fn _support_market(&mut self, pool: AccountId, underlying: AccountId) -> Result<()> {
-        ControllerRef::support_market(&self._controller(), pool, underlying)?;
+        let target = self._controller();
+        ControllerRef::support_market(&target, pool, underlying)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::support_market"),
+            Some(target),
+            Vec::new(),
+            (pool, underlying).encode(),
+        );
         Ok(())
     }
     default fn _support_market_with_collateral_factor_mantissa(
@@ -157,12 +705,19 @@ impl<T: Storage<Data>> Internal for T {
         underlying: AccountId,
         collateral_factor_mantissa: WrappedU256,
     ) -> Result<()> {
+        let target = self._controller();
         ControllerRef::support_market_with_collateral_factor_mantissa(
-            &self._controller(),
+            &target,
             pool,
             underlying,
             collateral_factor_mantissa,
         )?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::support_market_with_collateral_factor_mantissa"),
+            Some(target),
+            Vec::new(),
+            (pool, underlying, collateral_factor_mantissa).encode(),
+        );
         Ok(())
     }
     default fn _set_collateral_factor_mantissa(
@@ -170,40 +725,82 @@ impl<T: Storage<Data>> Internal for T {
         pool: AccountId,
         new_collateral_factor_mantissa: WrappedU256,
     ) -> Result<()> {
+        let target = self._controller();
         ControllerRef::set_collateral_factor_mantissa(
-            &self._controller(),
+            &target,
             pool,
             new_collateral_factor_mantissa,
         )?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_collateral_factor_mantissa"),
+            Some(target),
+            Vec::new(),
+            (pool, new_collateral_factor_mantissa).encode(),
+        );
         Ok(())
     }
     default fn _set_mint_guardian_paused(&mut self, pool: AccountId, paused: bool) -> Result<()> {
-        ControllerRef::set_mint_guardian_paused(&self._controller(), pool, paused)?;
+        let target = self._controller();
+        ControllerRef::set_mint_guardian_paused(&target, pool, paused)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_mint_guardian_paused"),
+            Some(target),
+            Vec::new(),
+            (pool, paused).encode(),
+        );
         Ok(())
     }
     default fn _set_borrow_guardian_paused(&mut self, pool: AccountId, paused: bool) -> Result<()> {
-        ControllerRef::set_borrow_guardian_paused(&self._controller(), pool, paused)?;
+        let target = self._controller();
+        ControllerRef::set_borrow_guardian_paused(&target, pool, paused)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_borrow_guardian_paused"),
+            Some(target),
+            Vec::new(),
+            (pool, paused).encode(),
+        );
         Ok(())
     }
     default fn _set_close_factor_mantissa(
         &mut self,
         new_close_factor_mantissa: WrappedU256,
     ) -> Result<()> {
-        ControllerRef::set_close_factor_mantissa(&self._controller(), new_close_factor_mantissa)?;
+        let target = self._controller();
+        ControllerRef::set_close_factor_mantissa(&target, new_close_factor_mantissa)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_close_factor_mantissa"),
+            Some(target),
+            Vec::new(),
+            new_close_factor_mantissa.encode(),
+        );
         Ok(())
     }
     default fn _set_liquidation_incentive_mantissa(
         &mut self,
         new_liquidation_incentive_mantissa: WrappedU256,
     ) -> Result<()> {
+        let target = self._controller();
         ControllerRef::set_liquidation_incentive_mantissa(
-            &self._controller(),
+            &target,
             new_liquidation_incentive_mantissa,
         )?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_liquidation_incentive_mantissa"),
+            Some(target),
+            Vec::new(),
+            new_liquidation_incentive_mantissa.encode(),
+        );
         Ok(())
     }
     default fn _set_borrow_cap(&mut self, pool: AccountId, new_cap: Balance) -> Result<()> {
-        ControllerRef::set_borrow_cap(&self._controller(), pool, new_cap)?;
+        let target = self._controller();
+        ControllerRef::set_borrow_cap(&target, pool, new_cap)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_borrow_cap"),
+            Some(target),
+            Vec::new(),
+            (pool, new_cap).encode(),
+        );
         Ok(())
     }
     default fn _set_reserve_factor_mantissa(
@@ -212,14 +809,598 @@ impl<T: Storage<Data>> Internal for T {
         new_reserve_factor_mantissa: WrappedU256,
     ) -> Result<()> {
         PoolRef::set_reserve_factor_mantissa(&pool, new_reserve_factor_mantissa)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_reserve_factor_mantissa"),
+            Some(pool),
+            Vec::new(),
+            new_reserve_factor_mantissa.encode(),
+        );
         Ok(())
     }
     default fn _reduce_reserves(&mut self, pool: AccountId, amount: Balance) -> Result<()> {
         PoolRef::reduce_reserves(&pool, amount)?;
+        // reserves are sent to this contract, forward them to the treasury
+        if let (Some(treasury), Some(underlying)) = (self._treasury(), PoolRef::underlying(&pool)) {
+            PSP22Ref::transfer(&underlying, treasury, amount, Vec::<u8>::new())?;
+        }
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::reduce_reserves"),
+            Some(pool),
+            Vec::new(),
+            amount.encode(),
+        );
+        Ok(())
+    }
+    default fn _cover_bad_debt(&mut self, pool: AccountId, amount: Balance) -> Result<()> {
+        PoolRef::cover_bad_debt(&pool, amount)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::cover_bad_debt"),
+            Some(pool),
+            Vec::new(),
+            amount.encode(),
+        );
         Ok(())
     }
     default fn _sweep_token(&mut self, pool: AccountId, asset: AccountId) -> Result<()> {
         PoolRef::sweep_token(&pool, asset)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::sweep_token"),
+            Some(pool),
+            Vec::new(),
+            asset.encode(),
+        );
+        Ok(())
+    }
+    default fn _price_oracle(&self) -> Result<AccountId> {
+        ControllerRef::oracle(&self._controller()).ok_or(Error::PriceOracleIsNotSet)
+    }
+    default fn _flashloan_gateway(&self) -> Result<AccountId> {
+        ControllerRef::flashloan_gateway(&self._controller()).ok_or(Error::FlashloanGatewayIsNotSet)
+    }
+    default fn _pool_factory(&self) -> Result<AccountId> {
+        ControllerRef::pool_factory(&self._controller()).ok_or(Error::PoolFactoryIsNotSet)
+    }
+    default fn _set_seize_guardian_paused(&mut self, paused: bool) -> Result<()> {
+        let target = self._controller();
+        ControllerRef::set_seize_guardian_paused(&target, paused)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_seize_guardian_paused"),
+            Some(target),
+            Vec::new(),
+            paused.encode(),
+        );
         Ok(())
     }
+    default fn _set_transfer_guardian_paused(&mut self, paused: bool) -> Result<()> {
+        let target = self._controller();
+        ControllerRef::set_transfer_guardian_paused(&target, paused)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_transfer_guardian_paused"),
+            Some(target),
+            Vec::new(),
+            paused.encode(),
+        );
+        Ok(())
+    }
+    default fn _set_shutdown_guardian(&mut self, guardian: Option<AccountId>) -> Result<()> {
+        let target = self._controller();
+        ControllerRef::set_shutdown_guardian(&target, guardian)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_shutdown_guardian"),
+            Some(target),
+            Vec::new(),
+            guardian.encode(),
+        );
+        Ok(())
+    }
+    default fn _set_pool_factory(&mut self, pool_factory: Option<AccountId>) -> Result<()> {
+        let target = self._controller();
+        ControllerRef::set_pool_factory(&target, pool_factory)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_pool_factory"),
+            Some(target),
+            Vec::new(),
+            pool_factory.encode(),
+        );
+        Ok(())
+    }
+    default fn _create_pool(
+        &mut self,
+        underlying: AccountId,
+        parameters: PoolParameters,
+    ) -> Result<()> {
+        let target = self._pool_factory()?;
+        let pool = PoolFactoryRef::create_pool(&target, underlying, parameters)?;
+        // the factory proposes this contract as the manager of the pool
+        PoolRef::accept_manager(&pool)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::create_pool"),
+            Some(target),
+            Vec::new(),
+            (underlying, parameters).encode(),
+        );
+        Ok(())
+    }
+    default fn _set_pool_code_hash(&mut self, code_hash: Hash) -> Result<()> {
+        let target = self._pool_factory()?;
+        PoolFactoryRef::set_pool_code_hash(&target, code_hash)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_pool_code_hash"),
+            Some(target),
+            Vec::new(),
+            code_hash.encode(),
+        );
+        Ok(())
+    }
+    default fn _create_rate_model(
+        &mut self,
+        code_hash: Hash,
+        parameters: RateModelParameters,
+    ) -> Result<()> {
+        let target = self._pool_factory()?;
+        PoolFactoryRef::create_rate_model(&target, code_hash, parameters)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::create_rate_model"),
+            Some(target),
+            Vec::new(),
+            (code_hash, parameters).encode(),
+        );
+        Ok(())
+    }
+    default fn _set_rate_model_code_hash_approval(
+        &mut self,
+        code_hash: Hash,
+        approved: bool,
+    ) -> Result<()> {
+        let target = self._pool_factory()?;
+        PoolFactoryRef::set_rate_model_code_hash_approval(&target, code_hash, approved)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_rate_model_code_hash_approval"),
+            Some(target),
+            Vec::new(),
+            (code_hash, approved).encode(),
+        );
+        Ok(())
+    }
+    default fn _shutdown_protocol(&mut self) -> Result<()> {
+        let target = self._controller();
+        ControllerRef::shutdown(&target)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::shutdown_protocol"),
+            Some(target),
+            Vec::new(),
+            Vec::new(),
+        );
+        Ok(())
+    }
+    default fn _recover_protocol(&mut self) -> Result<()> {
+        let target = self._controller();
+        ControllerRef::recover(&target)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::recover_protocol"),
+            Some(target),
+            Vec::new(),
+            Vec::new(),
+        );
+        Ok(())
+    }
+    default fn _reactivate_protocol(&mut self) -> Result<()> {
+        let target = self._controller();
+        ControllerRef::reactivate(&target)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::reactivate_protocol"),
+            Some(target),
+            Vec::new(),
+            Vec::new(),
+        );
+        Ok(())
+    }
+    default fn _set_interest_rate_model(
+        &mut self,
+        pool: AccountId,
+        new_interest_rate_model: AccountId,
+    ) -> Result<()> {
+        PoolRef::set_interest_rate_model(&pool, new_interest_rate_model)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_interest_rate_model"),
+            Some(pool),
+            Vec::new(),
+            new_interest_rate_model.encode(),
+        );
+        Ok(())
+    }
+    default fn _set_liquidation_threshold(
+        &mut self,
+        pool: AccountId,
+        new_liquidation_threshold: u128,
+    ) -> Result<()> {
+        PoolRef::set_liquidation_threshold(&pool, new_liquidation_threshold)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_liquidation_threshold"),
+            Some(pool),
+            Vec::new(),
+            new_liquidation_threshold.encode(),
+        );
+        Ok(())
+    }
+    default fn _set_borrow_rate_max_mantissa(
+        &mut self,
+        pool: AccountId,
+        new_borrow_rate_max_mantissa: WrappedU256,
+    ) -> Result<()> {
+        PoolRef::set_borrow_rate_max_mantissa(&pool, new_borrow_rate_max_mantissa)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_borrow_rate_max_mantissa"),
+            Some(pool),
+            Vec::new(),
+            new_borrow_rate_max_mantissa.encode(),
+        );
+        Ok(())
+    }
+    default fn _set_stable_rate_config(
+        &mut self,
+        pool: AccountId,
+        enabled: bool,
+        premium_mantissa: WrappedU256,
+        rebalance_threshold_mantissa: WrappedU256,
+    ) -> Result<()> {
+        PoolRef::set_stable_rate_config(
+            &pool,
+            enabled,
+            premium_mantissa,
+            rebalance_threshold_mantissa,
+        )?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_stable_rate_config"),
+            Some(pool),
+            Vec::new(),
+            (enabled, premium_mantissa, rebalance_threshold_mantissa).encode(),
+        );
+        Ok(())
+    }
+    default fn _set_underlying_call_config(
+        &mut self,
+        pool: AccountId,
+        config: UnderlyingCallConfig,
+    ) -> Result<()> {
+        PoolRef::set_underlying_call_config(&pool, config)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_underlying_call_config"),
+            Some(pool),
+            Vec::new(),
+            config.encode(),
+        );
+        Ok(())
+    }
+    default fn _set_max_accrual_delta(
+        &mut self,
+        pool: AccountId,
+        new_max_accrual_delta: Timestamp,
+    ) -> Result<()> {
+        PoolRef::set_max_accrual_delta(&pool, new_max_accrual_delta)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_max_accrual_delta"),
+            Some(pool),
+            Vec::new(),
+            new_max_accrual_delta.encode(),
+        );
+        Ok(())
+    }
+    default fn _set_compound_interest(&mut self, pool: AccountId, enabled: bool) -> Result<()> {
+        PoolRef::set_compound_interest(&pool, enabled)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_compound_interest"),
+            Some(pool),
+            Vec::new(),
+            enabled.encode(),
+        );
+        Ok(())
+    }
+    default fn _set_flash_mint_premium(&mut self, pool: AccountId, premium: u128) -> Result<()> {
+        PoolRef::set_flash_mint_premium(&pool, premium)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_flash_mint_premium"),
+            Some(pool),
+            Vec::new(),
+            premium.encode(),
+        );
+        Ok(())
+    }
+    default fn _set_origination_fee(&mut self, pool: AccountId, fee: u128) -> Result<()> {
+        PoolRef::set_origination_fee(&pool, fee)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_origination_fee"),
+            Some(pool),
+            Vec::new(),
+            fee.encode(),
+        );
+        Ok(())
+    }
+    default fn _set_reserves_sweep_threshold(
+        &mut self,
+        pool: AccountId,
+        threshold: Balance,
+    ) -> Result<()> {
+        // reserves of every pool are swept to the treasury of the protocol
+        PoolRef::set_reserves_sweep_config(&pool, self._treasury(), threshold)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_reserves_sweep_threshold"),
+            Some(pool),
+            Vec::new(),
+            threshold.encode(),
+        );
+        Ok(())
+    }
+    default fn _set_keeper_incentive(
+        &mut self,
+        pool: AccountId,
+        incentive: KeeperIncentive,
+    ) -> Result<()> {
+        PoolRef::set_keeper_incentive(&pool, incentive)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_keeper_incentive"),
+            Some(pool),
+            Vec::new(),
+            incentive.encode(),
+        );
+        Ok(())
+    }
+    default fn _set_incentives_controller(
+        &mut self,
+        pool: AccountId,
+        incentives_controller: AccountId,
+    ) -> Result<()> {
+        PoolRef::set_incentives_controller(&pool, incentives_controller)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_incentives_controller"),
+            Some(pool),
+            Vec::new(),
+            incentives_controller.encode(),
+        );
+        Ok(())
+    }
+    default fn _set_account_supply_cap(&mut self, pool: AccountId, new_cap: Balance) -> Result<()> {
+        PoolRef::set_account_supply_cap(&pool, new_cap)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_account_supply_cap"),
+            Some(pool),
+            Vec::new(),
+            new_cap.encode(),
+        );
+        Ok(())
+    }
+    default fn _set_outflow_limit(
+        &mut self,
+        pool: AccountId,
+        limit: Balance,
+        window: Timestamp,
+    ) -> Result<()> {
+        PoolRef::set_outflow_limit(&pool, limit, window)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_outflow_limit"),
+            Some(pool),
+            Vec::new(),
+            (limit, window).encode(),
+        );
+        Ok(())
+    }
+    default fn _set_reporter(&mut self, reporter: AccountId, authorized: bool) -> Result<()> {
+        let target = self._price_oracle()?;
+        ManualPriceOracleRef::set_reporter(&target, reporter, authorized)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_reporter"),
+            Some(target),
+            Vec::new(),
+            (reporter, authorized).encode(),
+        );
+        Ok(())
+    }
+    default fn _set_heartbeat(&mut self, asset: AccountId, heartbeat: Timestamp) -> Result<()> {
+        let target = self._price_oracle()?;
+        ManualPriceOracleRef::set_heartbeat(&target, asset, heartbeat)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_heartbeat"),
+            Some(target),
+            Vec::new(),
+            (asset, heartbeat).encode(),
+        );
+        Ok(())
+    }
+    default fn _set_price_decimals(&mut self, asset: AccountId, decimals: u8) -> Result<()> {
+        let target = self._price_oracle()?;
+        ManualPriceOracleRef::set_price_decimals(&target, asset, decimals)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_price_decimals"),
+            Some(target),
+            Vec::new(),
+            (asset, decimals).encode(),
+        );
+        Ok(())
+    }
+    default fn _set_price_bounds(&mut self, asset: AccountId, bounds: PriceBounds) -> Result<()> {
+        let target = self._price_oracle()?;
+        ManualPriceOracleRef::set_price_bounds(&target, asset, bounds)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_price_bounds"),
+            Some(target),
+            Vec::new(),
+            (asset, bounds).encode(),
+        );
+        Ok(())
+    }
+    default fn _set_deviation_breaker(
+        &mut self,
+        asset: AccountId,
+        breaker: DeviationBreaker,
+    ) -> Result<()> {
+        let target = self._price_oracle()?;
+        ManualPriceOracleRef::set_deviation_breaker(&target, asset, breaker)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_deviation_breaker"),
+            Some(target),
+            Vec::new(),
+            (asset, breaker).encode(),
+        );
+        Ok(())
+    }
+    default fn _set_flashloan_premium_total(&mut self, premium: u128) -> Result<()> {
+        let target = self._flashloan_gateway()?;
+        FlashloanGatewayRef::set_flashloan_premium_total(&target, premium)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_flashloan_premium_total"),
+            Some(target),
+            Vec::new(),
+            premium.encode(),
+        );
+        Ok(())
+    }
+    default fn _set_flashloan_premium_to_protocol(
+        &mut self,
+        premium_to_protocol: u128,
+    ) -> Result<()> {
+        let target = self._flashloan_gateway()?;
+        FlashloanGatewayRef::set_flashloan_premium_to_protocol(&target, premium_to_protocol)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_flashloan_premium_to_protocol"),
+            Some(target),
+            Vec::new(),
+            premium_to_protocol.encode(),
+        );
+        Ok(())
+    }
+    default fn _set_treasury(&mut self, treasury: AccountId) -> Result<()> {
+        let old = self._treasury();
+        self.data().treasury = Some(treasury);
+        let target = self._flashloan_gateway().ok();
+        if let Some(flashloan_gateway) = target {
+            FlashloanGatewayRef::set_treasury(&flashloan_gateway, treasury)?;
+        }
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_treasury"),
+            target,
+            old.encode(),
+            treasury.encode(),
+        );
+        Ok(())
+    }
+    default fn _set_premium_exemption(
+        &mut self,
+        account: AccountId,
+        premium: Option<u128>,
+    ) -> Result<()> {
+        let target = self._flashloan_gateway()?;
+        FlashloanGatewayRef::set_premium_exemption(&target, account, premium)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_premium_exemption"),
+            Some(target),
+            Vec::new(),
+            (account, premium).encode(),
+        );
+        Ok(())
+    }
+    default fn _set_flashloan_paused(&mut self, paused: bool) -> Result<()> {
+        let target = self._flashloan_gateway()?;
+        FlashloanGatewayRef::set_flashloan_paused(&target, paused)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_flashloan_paused"),
+            Some(target),
+            Vec::new(),
+            paused.encode(),
+        );
+        Ok(())
+    }
+    default fn _set_flashloan_enabled(&mut self, asset: AccountId, enabled: bool) -> Result<()> {
+        let target = self._flashloan_gateway()?;
+        FlashloanGatewayRef::set_flashloan_enabled(&target, asset, enabled)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_flashloan_enabled"),
+            Some(target),
+            Vec::new(),
+            (asset, enabled).encode(),
+        );
+        Ok(())
+    }
+    default fn _accept_controller_manager(&mut self) -> Result<()> {
+        let target = self._controller();
+        ControllerRef::accept_manager(&target)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::accept_controller_manager"),
+            Some(target),
+            Vec::new(),
+            Vec::new(),
+        );
+        Ok(())
+    }
+    default fn _accept_pool_manager(&mut self, pool: AccountId) -> Result<()> {
+        PoolRef::accept_manager(&pool)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::accept_pool_manager"),
+            Some(pool),
+            Vec::new(),
+            Vec::new(),
+        );
+        Ok(())
+    }
+    default fn _accept_ownership_of(&mut self, target: AccountId) -> Result<()> {
+        Ownable2StepRef::accept_ownership(&target)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::accept_ownership_of"),
+            Some(target),
+            Vec::new(),
+            Vec::new(),
+        );
+        Ok(())
+    }
+
+    default fn _upgrade_controller_code(&mut self, code_hash: Hash) -> Result<()> {
+        let controller = self._controller();
+        UpgradeableRef::upgrade_code(&controller, code_hash)?;
+        // the new code runs from this call on
+        UpgradeableRef::migrate(&controller)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::upgrade_controller_code"),
+            Some(controller),
+            Vec::new(),
+            code_hash.encode(),
+        );
+        Ok(())
+    }
+
+    default fn _upgrade_pool_code(&mut self, pool: AccountId, code_hash: Hash) -> Result<()> {
+        UpgradeableRef::upgrade_code(&pool, code_hash)?;
+        // the new code runs from this call on
+        UpgradeableRef::migrate(&pool)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::upgrade_pool_code"),
+            Some(pool),
+            Vec::new(),
+            code_hash.encode(),
+        );
+        Ok(())
+    }
+
+    default fn _set_pool_facet(
+        &mut self,
+        pool: AccountId,
+        facet: Facet,
+        code_hash: Option<Hash>,
+    ) -> Result<()> {
+        PoolRef::set_facet(&pool, facet, code_hash)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Manager::set_pool_facet"),
+            Some(pool),
+            Vec::new(),
+            (facet, code_hash).encode(),
+        );
+        Ok(())
+    }
+
+    default fn _emit_admin_action_event(
+        &self,
+        _action: ActionId,
+        _target: Option<AccountId>,
+        _old_value: Vec<u8>,
+        _new_value: Vec<u8>,
+    ) {
+    }
 }
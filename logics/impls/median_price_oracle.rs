@@ -0,0 +1,226 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::price_oracle::is_stale;
+use crate::traits::pool::PoolRef;
+pub use crate::traits::{
+    median_price_oracle::*,
+    price_oracle::*,
+};
+use ink::prelude::vec::Vec;
+use openbrush::{
+    contracts::ownable::{
+        self,
+        only_owner,
+    },
+    modifiers,
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Storage,
+        Timestamp,
+    },
+};
+
+/// Largest number of reporters, bounding the cost of reading a price
+pub const MAX_REPORTERS: usize = 16;
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Debug)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// Reporters whose prices are aggregated
+    pub reporters: Vec<AccountId>,
+    /// Last price each reporter posted for each asset
+    pub reports: Mapping<(AccountId, AccountId), PriceData>,
+    /// Longest time a report of each asset is used for, 0 if unlimited
+    pub heartbeats: Mapping<AccountId, Timestamp>,
+    /// Number of fresh reports a price requires
+    pub min_reports: u8,
+}
+
+impl Default for Data {
+    fn default() -> Self {
+        Data {
+            reporters: Default::default(),
+            reports: Default::default(),
+            heartbeats: Default::default(),
+            min_reports: 1,
+        }
+    }
+}
+
+/// Median of `prices`, the mean of the two middle prices if their number is even
+pub fn median(mut prices: Vec<u128>) -> Option<u128> {
+    if prices.is_empty() {
+        return None
+    }
+    prices.sort_unstable();
+    let mid = prices.len() / 2;
+    if prices.len() % 2 == 1 {
+        return Some(prices[mid])
+    }
+    let (low, high) = (prices[mid - 1], prices[mid]);
+    Some(low + (high - low) / 2)
+}
+
+pub trait Internal {
+    fn _get_price(&self, asset: AccountId) -> Option<u128>;
+    fn _get_underlying_price(&self, pool: AccountId) -> Option<u128>;
+    fn _post_price(&mut self, asset: AccountId, value: u128) -> Result<()>;
+    fn _set_reporter(&mut self, reporter: AccountId, authorized: bool) -> Result<()>;
+    fn _is_reporter(&self, account: AccountId) -> bool;
+    fn _set_min_reports(&mut self, min_reports: u8) -> Result<()>;
+    fn _set_heartbeat(&mut self, asset: AccountId, heartbeat: Timestamp) -> Result<()>;
+    fn _heartbeat(&self, asset: AccountId) -> Timestamp;
+
+    // event emission
+    fn _emit_price_posted_event(&self, asset: AccountId, price: u128, reporter: AccountId);
+    fn _emit_reporter_updated_event(&self, reporter: AccountId, authorized: bool);
+}
+
+impl<T: Storage<Data> + Storage<ownable::Data>> MedianPriceOracle for T {
+    #[modifiers(only_owner)]
+    default fn set_reporter(&mut self, reporter: AccountId, authorized: bool) -> Result<()> {
+        self._set_reporter(reporter, authorized)?;
+        self._emit_reporter_updated_event(reporter, authorized);
+        Ok(())
+    }
+
+    default fn reporters(&self) -> Vec<AccountId> {
+        self.data::<Data>().reporters.clone()
+    }
+
+    #[modifiers(only_owner)]
+    default fn set_min_reports(&mut self, min_reports: u8) -> Result<()> {
+        self._set_min_reports(min_reports)
+    }
+
+    default fn min_reports(&self) -> u8 {
+        self.data::<Data>().min_reports
+    }
+
+    #[modifiers(only_owner)]
+    default fn set_heartbeat(&mut self, asset: AccountId, heartbeat: Timestamp) -> Result<()> {
+        self._set_heartbeat(asset, heartbeat)
+    }
+
+    default fn heartbeat(&self, asset: AccountId) -> Timestamp {
+        self._heartbeat(asset)
+    }
+
+    default fn report(&self, asset: AccountId, reporter: AccountId) -> Option<PriceData> {
+        self.data::<Data>().reports.get(&(asset, reporter))
+    }
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _get_price(&self, asset: AccountId) -> Option<u128> {
+        let heartbeat = self._heartbeat(asset);
+        let now = Self::env().block_timestamp();
+        let data = self.data::<Data>();
+        // reports of revoked reporters are ignored
+        let prices: Vec<u128> = data
+            .reporters
+            .iter()
+            .filter_map(|reporter| data.reports.get(&(asset, *reporter)))
+            .filter(|report| !is_stale(report.updated_at, heartbeat, now))
+            .map(|report| report.price)
+            .collect();
+        if prices.len() < usize::from(data.min_reports) {
+            return None
+        }
+        median(prices)
+    }
+
+    default fn _get_underlying_price(&self, pool: AccountId) -> Option<u128> {
+        let underlying = PoolRef::underlying(&pool)?;
+        self._get_price(underlying)
+    }
+
+    default fn _post_price(&mut self, asset: AccountId, value: u128) -> Result<()> {
+        let caller = Self::env().caller();
+        if !self._is_reporter(caller) {
+            return Err(Error::CallerIsNotReporter)
+        }
+        self.data::<Data>().reports.insert(
+            &(asset, caller),
+            &PriceData {
+                price: value,
+                updated_at: Self::env().block_timestamp(),
+            },
+        );
+        self._emit_price_posted_event(asset, value, caller);
+        Ok(())
+    }
+
+    default fn _set_reporter(&mut self, reporter: AccountId, authorized: bool) -> Result<()> {
+        let reporters = &mut self.data::<Data>().reporters;
+        let index = reporters.iter().position(|account| *account == reporter);
+        match (index, authorized) {
+            (None, true) => {
+                if reporters.len() >= MAX_REPORTERS {
+                    return Err(Error::TooManyReporters)
+                }
+                reporters.push(reporter);
+            }
+            (Some(index), false) => {
+                reporters.swap_remove(index);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    default fn _is_reporter(&self, account: AccountId) -> bool {
+        self.data::<Data>().reporters.contains(&account)
+    }
+
+    default fn _set_min_reports(&mut self, min_reports: u8) -> Result<()> {
+        if min_reports == 0 || usize::from(min_reports) > MAX_REPORTERS {
+            return Err(Error::InvalidMinReports)
+        }
+        self.data::<Data>().min_reports = min_reports;
+        Ok(())
+    }
+
+    default fn _set_heartbeat(&mut self, asset: AccountId, heartbeat: Timestamp) -> Result<()> {
+        self.data::<Data>().heartbeats.insert(&asset, &heartbeat);
+        Ok(())
+    }
+
+    default fn _heartbeat(&self, asset: AccountId) -> Timestamp {
+        self.data::<Data>().heartbeats.get(&asset).unwrap_or(0)
+    }
+
+    default fn _emit_price_posted_event(
+        &self,
+        _asset: AccountId,
+        _price: u128,
+        _reporter: AccountId,
+    ) {
+    }
+    default fn _emit_reporter_updated_event(&self, _reporter: AccountId, _authorized: bool) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ink::prelude::vec;
+
+    #[test]
+    fn test_median() {
+        assert_eq!(median(vec![]), None);
+        assert_eq!(median(vec![7]), Some(7));
+        assert_eq!(median(vec![3, 1, 2]), Some(2));
+        assert_eq!(median(vec![4, 1, 3, 2]), Some(2));
+        assert_eq!(median(vec![u128::MAX, u128::MAX - 2]), Some(u128::MAX - 1));
+        // a single outlier does not move the median
+        assert_eq!(median(vec![100, 101, 99, 1, u128::MAX]), Some(100));
+    }
+}
@@ -0,0 +1,229 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+pub use crate::traits::merkle_distributor::*;
+use core::ops::Sub;
+use ink::{
+    env::hash::Keccak256,
+    prelude::vec::Vec,
+};
+use openbrush::{
+    contracts::{
+        ownable::{
+            self,
+            only_owner,
+        },
+        psp22::PSP22Ref,
+    },
+    modifiers,
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Balance,
+        Hash,
+        Storage,
+    },
+};
+use scale::Encode;
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Debug, Default)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// AccountId of the distributed token
+    pub token: Option<AccountId>,
+    /// Root of the tree of the cumulative amount of each account
+    pub merkle_root: Option<Hash>,
+    /// Cumulative amount claimed by each account
+    pub claimed: Mapping<AccountId, Balance>,
+}
+
+fn keccak256(input: &[u8]) -> Hash {
+    let mut output = [0_u8; 32];
+    ink::env::hash_bytes::<Keccak256>(input, &mut output);
+    Hash::from(output)
+}
+
+/// Leaf of the entry of `account` with the cumulative `amount`
+pub fn leaf(account: AccountId, amount: Balance) -> Hash {
+    keccak256(&(account, amount).encode())
+}
+
+/// Parent of two nodes, the pair is sorted so that proofs need no position
+pub fn hash_pair(a: Hash, b: Hash) -> Hash {
+    if a.as_ref() <= b.as_ref() {
+        keccak256(&(a, b).encode())
+    } else {
+        keccak256(&(b, a).encode())
+    }
+}
+
+/// Whether `proof` leads from `leaf` to `root`
+pub fn verify(proof: &[Hash], root: Hash, leaf: Hash) -> bool {
+    proof
+        .iter()
+        .fold(leaf, |node, sibling| hash_pair(node, *sibling))
+        == root
+}
+
+pub trait Internal {
+    fn _initialize(&mut self, token: AccountId);
+    fn _set_merkle_root(&mut self, merkle_root: Hash) -> Result<()>;
+    fn _withdraw(&mut self, to: AccountId, amount: Balance) -> Result<()>;
+    fn _claim(&mut self, account: AccountId, amount: Balance, proof: Vec<Hash>) -> Result<Balance>;
+
+    // view functions
+    fn _token(&self) -> Option<AccountId>;
+    fn _merkle_root(&self) -> Option<Hash>;
+    fn _claimed(&self, account: AccountId) -> Balance;
+
+    // event emission
+    fn _emit_merkle_root_updated_event(&self, merkle_root: Hash);
+    fn _emit_claimed_event(&self, account: AccountId, amount: Balance);
+}
+
+impl<T: Storage<Data> + Storage<ownable::Data>> MerkleDistributor for T {
+    #[modifiers(only_owner)]
+    default fn set_merkle_root(&mut self, merkle_root: Hash) -> Result<()> {
+        self._set_merkle_root(merkle_root)?;
+        self._emit_merkle_root_updated_event(merkle_root);
+        Ok(())
+    }
+
+    #[modifiers(only_owner)]
+    default fn withdraw(&mut self, to: AccountId, amount: Balance) -> Result<()> {
+        self._withdraw(to, amount)
+    }
+
+    default fn claim(
+        &mut self,
+        account: AccountId,
+        amount: Balance,
+        proof: Vec<Hash>,
+    ) -> Result<Balance> {
+        let claimable = self._claim(account, amount, proof)?;
+        self._emit_claimed_event(account, claimable);
+        Ok(claimable)
+    }
+
+    default fn merkle_root(&self) -> Option<Hash> {
+        self._merkle_root()
+    }
+
+    default fn claimed(&self, account: AccountId) -> Balance {
+        self._claimed(account)
+    }
+
+    default fn token(&self) -> Option<AccountId> {
+        self._token()
+    }
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _initialize(&mut self, token: AccountId) {
+        self.data::<Data>().token = Some(token);
+    }
+
+    default fn _set_merkle_root(&mut self, merkle_root: Hash) -> Result<()> {
+        self.data::<Data>().merkle_root = Some(merkle_root);
+        Ok(())
+    }
+
+    default fn _withdraw(&mut self, to: AccountId, amount: Balance) -> Result<()> {
+        let token = self._token().ok_or(Error::TokenIsNotSet)?;
+        PSP22Ref::transfer(&token, to, amount, Vec::<u8>::new())?;
+        Ok(())
+    }
+
+    default fn _claim(
+        &mut self,
+        account: AccountId,
+        amount: Balance,
+        proof: Vec<Hash>,
+    ) -> Result<Balance> {
+        let token = self._token().ok_or(Error::TokenIsNotSet)?;
+        let root = self._merkle_root().ok_or(Error::MerkleRootIsNotSet)?;
+        if !verify(&proof, root, leaf(account, amount)) {
+            return Err(Error::InvalidProof)
+        }
+        // amounts are cumulative, only the increase since the last claim is paid
+        let claimed = self._claimed(account);
+        if amount <= claimed {
+            return Err(Error::NothingToClaim)
+        }
+        let claimable = amount.sub(claimed);
+        self.data::<Data>().claimed.insert(&account, &amount);
+        PSP22Ref::transfer(&token, account, claimable, Vec::<u8>::new())?;
+        Ok(claimable)
+    }
+
+    default fn _token(&self) -> Option<AccountId> {
+        self.data::<Data>().token
+    }
+
+    default fn _merkle_root(&self) -> Option<Hash> {
+        self.data::<Data>().merkle_root
+    }
+
+    default fn _claimed(&self, account: AccountId) -> Balance {
+        self.data::<Data>()
+            .claimed
+            .get(&account)
+            .unwrap_or_default()
+    }
+
+    default fn _emit_merkle_root_updated_event(&self, _merkle_root: Hash) {}
+    default fn _emit_claimed_event(&self, _account: AccountId, _amount: Balance) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(n: u8) -> AccountId {
+        AccountId::from([n; 32])
+    }
+
+    #[test]
+    fn test_verify() {
+        let leaves = [
+            leaf(account(1), 100),
+            leaf(account(2), 200),
+            leaf(account(3), 300),
+            leaf(account(4), 400),
+        ];
+        let left = hash_pair(leaves[0], leaves[1]);
+        let right = hash_pair(leaves[2], leaves[3]);
+        let root = hash_pair(left, right);
+
+        assert!(verify(&[leaves[1], right], root, leaves[0]));
+        assert!(verify(&[leaves[0], right], root, leaves[1]));
+        assert!(verify(&[leaves[3], left], root, leaves[2]));
+        assert!(verify(&[leaves[2], left], root, leaves[3]));
+        // an amount other than the posted one
+        assert!(!verify(&[leaves[1], right], root, leaf(account(1), 101)));
+        // a proof of another leaf
+        assert!(!verify(&[leaves[0], right], root, leaves[0]));
+        assert!(!verify(&[], root, leaves[0]));
+    }
+
+    #[test]
+    fn test_verify_single_leaf() {
+        let root = leaf(account(1), 100);
+        assert!(verify(&[], root, root));
+        assert!(!verify(&[], root, leaf(account(2), 100)));
+    }
+
+    #[test]
+    fn test_hash_pair_is_commutative() {
+        let a = leaf(account(1), 100);
+        let b = leaf(account(2), 200);
+        assert_eq!(hash_pair(a, b), hash_pair(b, a));
+        assert_ne!(hash_pair(a, b), hash_pair(a, a));
+    }
+}
@@ -5,17 +5,47 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+pub mod aggregator_price_oracle;
+pub mod band_price_oracle;
+pub mod collateral_swap;
 pub mod controller;
+pub mod dex_twap_price_oracle;
+pub mod dia_price_oracle;
+pub mod double_kink_rate_model;
+pub mod dynamic_rate_model;
+pub mod evm_migrator;
 pub mod exp_no_err;
+pub mod fixed_rate_model;
+pub mod flash_liquidator;
 pub mod flashloan_gateway;
 pub mod flashloan_receiver;
+pub mod governor;
 pub mod incentives_controller;
 pub mod interest_rate_model;
+pub mod jump_rate_model;
+pub mod lay_token;
 pub mod leverager;
 pub mod manager;
+pub mod median_price_oracle;
+pub mod merkle_distributor;
+pub mod ownable_2step;
+pub mod params;
 pub mod percent_math;
 pub mod pool;
+pub mod pool_facet;
+pub mod pool_factory;
 pub mod price_oracle;
+pub mod registry;
+pub mod router;
+pub mod staking;
+pub mod timelock;
+pub mod treasury;
+pub mod upgradeable;
+pub mod vault;
+pub mod vesting;
+pub mod vote_escrow;
+pub mod votes;
 pub mod wad_ray_math;
 pub mod weth;
 pub mod weth_gateway;
+pub mod xcm_deposit;
@@ -0,0 +1,122 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+pub use crate::traits::ownable_2step::*;
+use openbrush::{
+    contracts::ownable::{
+        self,
+        Internal as _,
+        OwnableError,
+    },
+    traits::{
+        AccountId,
+        Storage,
+        ZERO_ADDRESS,
+    },
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Debug, Default)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// Account proposed as the next owner
+    pub pending_owner: Option<AccountId>,
+}
+
+/// Manager role handed over in two steps like the ownership, for contracts
+/// keeping their manager in their own storage instead of `ownable::Data`.
+pub trait ManagerHandover {
+    fn manager_mut(&mut self) -> &mut Option<AccountId>;
+    fn pending_manager_mut(&mut self) -> &mut Option<AccountId>;
+
+    /// Proposes `new_manager`, returning the account proposed before
+    fn propose_manager(&mut self, new_manager: AccountId) -> Option<AccountId> {
+        self.pending_manager_mut().replace(new_manager)
+    }
+
+    /// Hands the role over to `caller` if it was proposed, returning the previous manager
+    fn accept_manager(&mut self, caller: AccountId) -> Result<Option<AccountId>> {
+        if *self.pending_manager_mut() != Some(caller) {
+            return Err(Error::CallerIsNotPendingManager)
+        }
+        *self.pending_manager_mut() = None;
+        Ok(self.manager_mut().replace(caller))
+    }
+}
+
+/// Contracts override `transfer_ownership` and `renounce_ownership` of `Ownable`
+/// with `_propose_owner` and `_renounce_ownership`, keeping their `only_owner` modifier.
+pub trait Internal {
+    fn _pending_owner(&self) -> Option<AccountId>;
+    fn _propose_owner(&mut self, new_owner: AccountId) -> core::result::Result<(), OwnableError>;
+    fn _accept_ownership(&mut self) -> Result<()>;
+    fn _renounce_ownership(&mut self) -> core::result::Result<(), OwnableError>;
+
+    // event emission
+    fn _emit_ownership_transfer_started_event(
+        &self,
+        previous_owner: AccountId,
+        new_owner: AccountId,
+    );
+}
+
+impl<T: Storage<Data> + Storage<ownable::Data>> Ownable2Step for T {
+    default fn pending_owner(&self) -> Option<AccountId> {
+        self._pending_owner()
+    }
+
+    default fn accept_ownership(&mut self) -> Result<()> {
+        self._accept_ownership()
+    }
+}
+
+impl<T: Storage<Data> + Storage<ownable::Data>> Internal for T {
+    default fn _pending_owner(&self) -> Option<AccountId> {
+        self.data::<Data>().pending_owner
+    }
+
+    default fn _propose_owner(
+        &mut self,
+        new_owner: AccountId,
+    ) -> core::result::Result<(), OwnableError> {
+        if new_owner == ZERO_ADDRESS.into() {
+            return Err(OwnableError::NewOwnerIsZero)
+        }
+        self.data::<Data>().pending_owner = Some(new_owner);
+        let owner = self.data::<ownable::Data>().owner;
+        self._emit_ownership_transfer_started_event(owner, new_owner);
+        Ok(())
+    }
+
+    default fn _accept_ownership(&mut self) -> Result<()> {
+        let caller = Self::env().caller();
+        if self._pending_owner() != Some(caller) {
+            return Err(Error::CallerIsNotPendingOwner)
+        }
+        let previous_owner = self.data::<ownable::Data>().owner;
+        self.data::<ownable::Data>().owner = caller;
+        self.data::<Data>().pending_owner = None;
+        self._emit_ownership_transferred_event(Some(previous_owner), Some(caller));
+        Ok(())
+    }
+
+    default fn _renounce_ownership(&mut self) -> core::result::Result<(), OwnableError> {
+        let previous_owner = self.data::<ownable::Data>().owner;
+        self.data::<ownable::Data>().owner = ZERO_ADDRESS.into();
+        self.data::<Data>().pending_owner = None;
+        self._emit_ownership_transferred_event(Some(previous_owner), None);
+        Ok(())
+    }
+
+    default fn _emit_ownership_transfer_started_event(
+        &self,
+        _previous_owner: AccountId,
+        _new_owner: AccountId,
+    ) {
+    }
+}
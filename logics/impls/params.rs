@@ -0,0 +1,214 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Hard bounds of the protocol parameters
+//!
+//! Every setter of the Controller, Pools and PriceOracles validates its value through this
+//! module, so a value out of these bounds can never be configured.
+
+use super::exp_no_err::exp_scale;
+use crate::traits::types::Bps;
+use core::ops::{
+    Add,
+    Div,
+    Mul,
+};
+use openbrush::traits::Timestamp;
+use primitive_types::U256;
+
+fn percent(value: u128) -> U256 {
+    exp_scale().mul(U256::from(value)).div(U256::from(100))
+}
+
+const fn bps(value: u16) -> Bps {
+    match Bps::new(value) {
+        Some(bps) => bps,
+        None => panic!("out of range of basis points"),
+    }
+}
+
+/// Minimum value of Close Factor: 5%
+pub const CLOSE_FACTOR_MIN: Bps = bps(500);
+
+/// Maximum value of Close Factor: 90%
+pub const CLOSE_FACTOR_MAX: Bps = bps(9000);
+
+/// Maximum bonus of Liquidation Incentive over the repaid amount: 20%
+pub const LIQUIDATION_INCENTIVE_BONUS_MAX: Bps = bps(2000);
+
+/// Maximum value of Reserve Factor: 100%
+pub const RESERVE_FACTOR_MAX: Bps = bps(Bps::MAX);
+
+/// Maximum value of Collateral Factor
+pub fn collateral_factor_max_mantissa() -> U256 {
+    // 90%
+    percent(90)
+}
+
+/// Minimum value of Close Factor
+pub fn close_factor_min_mantissa() -> U256 {
+    CLOSE_FACTOR_MIN.to_mantissa()
+}
+
+/// Maximum value of Close Factor
+pub fn close_factor_max_mantissa() -> U256 {
+    CLOSE_FACTOR_MAX.to_mantissa()
+}
+
+/// Minimum value of Liquidation Incentive, a liquidator never receives less than it repays
+pub fn liquidation_incentive_min_mantissa() -> U256 {
+    // 100%
+    exp_scale()
+}
+
+/// Maximum value of Liquidation Incentive
+pub fn liquidation_incentive_max_mantissa() -> U256 {
+    liquidation_incentive_min_mantissa().add(LIQUIDATION_INCENTIVE_BONUS_MAX.to_mantissa())
+}
+
+/// Maximum value of Reserve Factor
+pub fn reserve_factor_max_mantissa() -> U256 {
+    RESERVE_FACTOR_MAX.to_mantissa()
+}
+
+/// Maximum value of Borrow Rate
+pub fn borrow_rate_absolute_max_mantissa() -> U256 {
+    // .005% / time
+    exp_scale().mul(U256::from(5)).div(U256::from(100 * 100))
+}
+
+/// Maximum value of the longest period interest accrues for in a single accrual
+pub fn accrual_delta_max() -> Timestamp {
+    // 30 days
+    1000 * 60 * 60 * 24 * 30
+}
+
+/// Maximum value of Liquidation Threshold, in basis points
+pub const LIQUIDATION_THRESHOLD_MAX: u128 = 10000;
+
+/// Upper bound of the flash mint premium, in basis points
+pub const FLASH_MINT_PREMIUM_MAX: u128 = 10000;
+
+/// Upper bound of the borrow origination fee, in basis points: 10%
+pub const ORIGINATION_FEE_MAX: u128 = 1000;
+
+/// Maximum value of the tolerance and deviation of prices
+pub fn price_deviation_max_mantissa() -> U256 {
+    // 100%
+    exp_scale()
+}
+
+/// Maximum value of the decimals of a reported price
+pub const PRICE_DECIMALS_MAX: u8 = 36;
+
+/// Longest time a guardian can override a price for: 7 days
+pub const MAX_OVERRIDE_DURATION: Timestamp = 7 * 24 * 60 * 60 * 1000;
+
+pub fn is_valid_collateral_factor(value: U256) -> bool {
+    !value.is_zero() && value <= collateral_factor_max_mantissa()
+}
+
+pub fn is_valid_close_factor(value: U256) -> bool {
+    value >= close_factor_min_mantissa() && value <= close_factor_max_mantissa()
+}
+
+pub fn is_valid_liquidation_incentive(value: U256) -> bool {
+    value >= liquidation_incentive_min_mantissa() && value <= liquidation_incentive_max_mantissa()
+}
+
+pub fn is_valid_reserve_factor(value: U256) -> bool {
+    value <= reserve_factor_max_mantissa()
+}
+
+pub fn is_valid_borrow_rate(value: U256) -> bool {
+    value <= borrow_rate_absolute_max_mantissa()
+}
+
+/// 0 disables the cap of the accrual period
+pub fn is_valid_accrual_delta(value: Timestamp) -> bool {
+    value <= accrual_delta_max()
+}
+
+pub fn is_valid_liquidation_threshold(value: u128) -> bool {
+    value <= LIQUIDATION_THRESHOLD_MAX
+}
+
+pub fn is_valid_flash_mint_premium(value: u128) -> bool {
+    value <= FLASH_MINT_PREMIUM_MAX
+}
+
+pub fn is_valid_origination_fee(value: u128) -> bool {
+    value <= ORIGINATION_FEE_MAX
+}
+
+pub fn is_valid_price_deviation(value: U256) -> bool {
+    value <= price_deviation_max_mantissa()
+}
+
+pub fn is_valid_price_decimals(value: u8) -> bool {
+    value <= PRICE_DECIMALS_MAX
+}
+
+pub fn is_valid_override_duration(value: Timestamp) -> bool {
+    value != 0 && value <= MAX_OVERRIDE_DURATION
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::ops::Sub;
+
+    #[test]
+    fn percentages_between_basis_points_are_valid() {
+        assert!(is_valid_close_factor(percent(50).add(1)));
+        assert!(is_valid_liquidation_incentive(percent(108).add(1)));
+        assert!(is_valid_reserve_factor(percent(10).add(1)));
+    }
+
+    #[test]
+    fn bounds_are_inclusive() {
+        assert!(is_valid_collateral_factor(collateral_factor_max_mantissa()));
+        assert!(!is_valid_collateral_factor(
+            collateral_factor_max_mantissa().add(1)
+        ));
+        assert!(!is_valid_collateral_factor(U256::zero()));
+
+        assert!(is_valid_close_factor(close_factor_min_mantissa()));
+        assert!(is_valid_close_factor(close_factor_max_mantissa()));
+        assert!(!is_valid_close_factor(close_factor_min_mantissa().sub(1)));
+        assert!(!is_valid_close_factor(close_factor_max_mantissa().add(1)));
+
+        assert!(is_valid_liquidation_incentive(exp_scale()));
+        assert!(!is_valid_liquidation_incentive(exp_scale().sub(1)));
+        assert!(!is_valid_liquidation_incentive(
+            liquidation_incentive_max_mantissa().add(1)
+        ));
+
+        assert!(is_valid_reserve_factor(U256::zero()));
+        assert!(is_valid_reserve_factor(reserve_factor_max_mantissa()));
+        assert!(!is_valid_reserve_factor(
+            reserve_factor_max_mantissa().add(1)
+        ));
+
+        assert!(is_valid_liquidation_threshold(LIQUIDATION_THRESHOLD_MAX));
+        assert!(!is_valid_liquidation_threshold(
+            LIQUIDATION_THRESHOLD_MAX + 1
+        ));
+
+        assert!(is_valid_origination_fee(ORIGINATION_FEE_MAX));
+        assert!(!is_valid_origination_fee(ORIGINATION_FEE_MAX + 1));
+
+        assert!(is_valid_accrual_delta(0));
+        assert!(!is_valid_accrual_delta(accrual_delta_max() + 1));
+
+        assert!(is_valid_price_decimals(PRICE_DECIMALS_MAX));
+        assert!(!is_valid_price_decimals(PRICE_DECIMALS_MAX + 1));
+
+        assert!(!is_valid_override_duration(0));
+        assert!(!is_valid_override_duration(MAX_OVERRIDE_DURATION + 1));
+    }
+}
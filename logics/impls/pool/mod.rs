@@ -14,11 +14,28 @@ use super::{
         exp_scale,
         Exp,
     },
+    ownable_2step::ManagerHandover,
+    params::{
+        is_valid_accrual_delta,
+        is_valid_borrow_rate,
+        is_valid_flash_mint_premium,
+        is_valid_liquidation_threshold,
+        is_valid_origination_fee,
+        is_valid_reserve_factor,
+    },
+    pool_facet::{
+        PoolAdminFacet,
+        PoolLiquidationFacet,
+    },
 };
 use crate::traits::{
     controller,
+    flashloan_receiver::FlashloanReceiverRef,
     incentives_controller::IncentivesControllerRef,
-    types::WrappedU256,
+    types::{
+        ActionId,
+        WrappedU256,
+    },
 };
 pub use crate::traits::{
     controller::ControllerRef,
@@ -31,7 +48,22 @@ use core::ops::{
     Mul,
     Sub,
 };
-use ink::prelude::vec::Vec;
+use ink::{
+    env::{
+        call::{
+            build_call,
+            DelegateCall,
+            ExecutionInput,
+        },
+        CallFlags,
+        DefaultEnvironment,
+    },
+    prelude::{
+        vec,
+        vec::Vec,
+    },
+    selector_bytes,
+};
 use openbrush::{
     contracts::psp22::{
         self,
@@ -51,24 +83,44 @@ use openbrush::{
         AccountId,
         AccountIdExt,
         Balance,
+        Hash,
         Storage,
         String,
         Timestamp,
     },
 };
 use primitive_types::U256;
+use scale::{
+    Decode,
+    Encode,
+};
 
+pub mod pallet_assets;
 pub mod utils;
+pub mod xvm;
 use self::utils::{
+    accrual_delta_max,
+    borrow_rate_max_mantissa,
     calculate_interest,
     exchange_rate,
     from_scaled_amount,
+    from_scaled_amount_truncate,
+    origination_fee_of,
     protocol_seize_amount,
     protocol_seize_share_mantissa,
-    reserve_factor_max_mantissa,
+    reserves_of_interest,
     scaled_amount_of,
+    scaled_amount_of_ceil,
+    scaled_amount_of_truncate,
+    stable_balance_at,
+    try_as_u128,
+    try_from_scaled_amount_ceil,
+    utilization_rate,
+    weighted_average_rate,
     CalculateInterestInput,
     CalculateInterestOutput,
+    CHECKPOINTS_CAPACITY,
+    FLASH_MINT_PREMIUM_MAX,
 };
 
 pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
@@ -76,7 +128,7 @@ pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
 #[derive(Debug)]
 #[openbrush::upgradeable_storage(STORAGE_KEY)]
 pub struct Data {
-    /// AccountId of underlying asset
+    /// AccountId of underlying asset, the id of `underlying_kind` if not a PSP22
     pub underlying: Option<AccountId>,
     /// AccountId of Controller managing this pool
     pub controller: Option<AccountId>,
@@ -106,6 +158,72 @@ pub struct Data {
     pub delegate_allowance: Mapping<(AccountId, AccountId), Balance, AllowancesKey>,
     /// Represent if user is using his reserve as collateral or not
     pub using_reserve_as_collateral: Mapping<AccountId, bool>,
+    /// Underlying held by this pool, tracked on every transfer in/out
+    pub cash: Balance,
+    /// Principal borrowed by accounts and borrow index at their last update
+    pub account_borrow_snapshots: Mapping<AccountId, BorrowSnapshot>,
+    /// Accounts with an outstanding borrow, indexed from 0
+    pub borrowers: Mapping<u32, AccountId>,
+    /// Position of each borrower in `borrowers`
+    pub borrower_positions: Mapping<AccountId, u32>,
+    /// Number of accounts with an outstanding borrow
+    pub borrowers_count: u32,
+    /// Maximum underlying an account can supply, 0 if unlimited
+    pub account_supply_cap: Balance,
+    /// Maximum underlying leaving the pool by redeem and borrow per window, 0 if unlimited
+    pub outflow_limit: Balance,
+    /// Length of the outflow limit window
+    pub outflow_window: Timestamp,
+    /// Start of the current outflow limit window
+    pub outflow_window_start: Timestamp,
+    /// Underlying left the pool in the current window
+    pub outflow_in_window: Balance,
+    /// Stable rate debt of accounts
+    pub stable_borrows: Mapping<AccountId, StableBorrow>,
+    /// Total stable borrows, including interest up to `stable_last_update`
+    pub total_stable_borrows: Balance,
+    /// Weighted average rate of all stable borrows
+    pub average_stable_rate: WrappedU256,
+    /// Last time `total_stable_borrows` was updated
+    pub stable_last_update: Timestamp,
+    /// Whether new borrows can be taken at a stable rate
+    pub stable_borrow_enabled: bool,
+    /// Premium over the variable borrow rate charged on new stable borrows
+    pub stable_rate_premium_mantissa: WrappedU256,
+    /// Drift from the current stable rate allowing a stable rate to be rebalanced
+    pub stable_rebalance_threshold_mantissa: WrappedU256,
+    /// Ring buffer of rate checkpoints written on accrual
+    pub checkpoints: Mapping<u32, RateCheckpoint>,
+    /// Total number of checkpoints ever written
+    pub checkpoints_written: u32,
+    /// AccountId proposed as the next manager
+    pub pending_manager: Option<AccountId>,
+    /// Code the messages of each facet are delegated to
+    pub facets: Mapping<Facet, Hash>,
+    /// Debt written off and not repaid yet
+    pub bad_debt: Balance,
+    /// Treasury reserves are swept to
+    pub treasury: Option<AccountId>,
+    /// Reserves kept in the pool when swept to the treasury
+    pub reserves_sweep_threshold: Balance,
+    /// Kind of the underlying asset
+    pub underlying_kind: UnderlyingKind,
+    /// Maximum borrow rate that can ever be applied, 0 to use the protocol default
+    pub borrow_rate_max_mantissa: WrappedU256,
+    /// Longest period interest accrues for in a single accrual, 0 if unlimited
+    pub max_accrual_delta: Timestamp,
+    /// Call flags and gas limit used when calling the underlying token contract
+    pub underlying_call_config: UnderlyingCallConfig,
+    /// Compounds the borrow rate over each accrual instead of accruing `rate * delta`
+    pub compound_interest: bool,
+    /// Premium charged on flash mints, in basis points
+    pub flash_mint_premium: u128,
+    /// Bounties paid from reserves to keepers
+    pub keeper_incentive: KeeperIncentive,
+    /// One-time fee charged on borrows and added to the debt, in basis points
+    pub origination_fee: u128,
+    /// Pool tokens minted by flash mints in progress, left out of the exchange rate
+    pub flash_minted: Balance,
 }
 
 pub struct AllowancesKey;
@@ -114,6 +232,16 @@ impl<'a> TypeGuard<'a> for AllowancesKey {
     type Type = &'a (&'a AccountId, &'a AccountId);
 }
 
+impl ManagerHandover for Data {
+    fn manager_mut(&mut self) -> &mut Option<AccountId> {
+        &mut self.manager
+    }
+
+    fn pending_manager_mut(&mut self) -> &mut Option<AccountId> {
+        &mut self.pending_manager
+    }
+}
+
 impl Default for Data {
     fn default() -> Self {
         Data {
@@ -132,6 +260,39 @@ impl Default for Data {
             reserve_factor_mantissa: WrappedU256::from(U256::zero()),
             liquidation_threshold: 10000,
             using_reserve_as_collateral: Default::default(),
+            cash: 0,
+            account_borrow_snapshots: Default::default(),
+            borrowers: Default::default(),
+            borrower_positions: Default::default(),
+            borrowers_count: 0,
+            account_supply_cap: 0,
+            outflow_limit: 0,
+            outflow_window: 0,
+            outflow_window_start: 0,
+            outflow_in_window: 0,
+            stable_borrows: Default::default(),
+            total_stable_borrows: 0,
+            average_stable_rate: WrappedU256::from(U256::zero()),
+            stable_last_update: 0,
+            stable_borrow_enabled: false,
+            stable_rate_premium_mantissa: WrappedU256::from(U256::zero()),
+            stable_rebalance_threshold_mantissa: WrappedU256::from(U256::zero()),
+            checkpoints: Default::default(),
+            checkpoints_written: 0,
+            pending_manager: None,
+            facets: Default::default(),
+            bad_debt: 0,
+            treasury: None,
+            reserves_sweep_threshold: 0,
+            underlying_kind: UnderlyingKind::PSP22,
+            borrow_rate_max_mantissa: WrappedU256::from(borrow_rate_max_mantissa()),
+            max_accrual_delta: accrual_delta_max(),
+            underlying_call_config: Default::default(),
+            compound_interest: true,
+            flash_mint_premium: 0,
+            keeper_incentive: Default::default(),
+            origination_fee: 0,
+            flash_minted: 0,
         }
     }
 }
@@ -154,11 +315,18 @@ pub trait Internal {
     ) -> core::result::Result<(), PSP22Error>;
     fn _mint(&mut self, minter: AccountId, mint_amount: Balance) -> Result<()>;
     fn _redeem(&mut self, redeemer: AccountId, amount: Balance) -> Result<()>;
+    fn _redeem_to(
+        &mut self,
+        redeemer: AccountId,
+        receiver: AccountId,
+        amount: Balance,
+    ) -> Result<()>;
     fn _borrow(
         &mut self,
         borrower: AccountId,
         borrow_amount: Balance,
         release_underlying: bool,
+        rate_mode: RateMode,
     ) -> Result<()>;
     fn _repay_borrow(
         &mut self,
@@ -173,6 +341,11 @@ pub trait Internal {
         repay_amount: Balance,
         collateral: AccountId,
     ) -> Result<()>;
+    fn _liquidate_max_repay_amount(
+        &self,
+        borrower: AccountId,
+        collateral: AccountId,
+    ) -> Result<Balance>;
     fn _seize(
         &mut self,
         seizer_token: AccountId,
@@ -180,9 +353,12 @@ pub trait Internal {
         borrower: AccountId,
         seize_tokens: Balance,
     ) -> Result<()>;
+    fn _absorb(&mut self, borrower: AccountId) -> Result<Balance>;
+    fn _repay_bad_debt(&mut self, payer: AccountId, amount: Balance) -> Result<()>;
 
     // admin functions
     fn _set_controller(&mut self, new_controller: AccountId) -> Result<()>;
+    fn _set_manager(&mut self, new_manager: AccountId) -> Result<()>;
     fn _set_reserve_factor_mantissa(
         &mut self,
         new_reserve_factor_mantissa: WrappedU256,
@@ -190,8 +366,42 @@ pub trait Internal {
     fn _set_interest_rate_model(&mut self, new_interest_rate_model: AccountId) -> Result<()>;
     fn _add_reserves(&mut self, amount: Balance) -> Result<()>;
     fn _reduce_reserves(&mut self, admin: AccountId, amount: Balance) -> Result<()>;
+    fn _cover_bad_debt(&mut self, amount: Balance) -> Result<()>;
+    fn _sweep_reserves_to_treasury(&mut self) -> Result<Balance>;
     fn _sweep_token(&mut self, asset: AccountId) -> Result<()>;
     fn _set_liquidation_threshold(&mut self, new_liquidation_threshold: u128) -> Result<()>;
+    fn _set_borrow_rate_max_mantissa(
+        &mut self,
+        new_borrow_rate_max_mantissa: WrappedU256,
+    ) -> Result<()>;
+    fn _set_account_supply_cap(&mut self, new_cap: Balance) -> Result<()>;
+    fn _set_max_accrual_delta(&mut self, new_max_accrual_delta: Timestamp) -> Result<()>;
+    fn _set_compound_interest(&mut self, enabled: bool) -> Result<()>;
+    fn _set_flash_mint_premium(&mut self, premium: u128) -> Result<()>;
+    fn _set_origination_fee(&mut self, fee: u128) -> Result<()>;
+    fn _set_keeper_incentive(&mut self, incentive: KeeperIncentive) -> Result<()>;
+    fn _pay_keeper_bounty(&mut self, keeper: AccountId, bounty: Balance) -> Result<Balance>;
+    fn _pay_liquidation_bounty(
+        &mut self,
+        liquidator: AccountId,
+        repay_amount: Balance,
+    ) -> Result<()>;
+    fn _set_underlying_call_config(&mut self, config: UnderlyingCallConfig) -> Result<()>;
+    fn _set_stable_rate_config(
+        &mut self,
+        enabled: bool,
+        premium_mantissa: WrappedU256,
+        rebalance_threshold_mantissa: WrappedU256,
+    ) -> Result<()>;
+    fn _rebalance_stable_borrow_rate(&mut self, account: AccountId) -> Result<()>;
+    fn _swap_borrow_rate_mode(&mut self, borrower: AccountId, rate_mode: RateMode) -> Result<()>;
+    fn _set_outflow_limit(&mut self, limit: Balance, window: Timestamp) -> Result<()>;
+    fn _set_reserves_sweep_config(
+        &mut self,
+        treasury: Option<AccountId>,
+        threshold: Balance,
+    ) -> Result<()>;
+    fn _consume_outflow(&mut self, amount: Balance) -> Result<()>;
     fn _approve_delegate(
         &mut self,
         owner: AccountId,
@@ -199,14 +409,23 @@ pub trait Internal {
         amount: Balance,
     ) -> Result<()>;
     fn _set_use_reserve_as_collateral(&mut self, user: AccountId, use_as_collateral: bool);
+    fn _write_checkpoint(&mut self, at: Timestamp);
     // utilities
     fn _transfer_underlying_from(
-        &self,
+        &mut self,
         from: AccountId,
         to: AccountId,
         value: Balance,
     ) -> Result<()>;
-    fn _transfer_underlying(&self, to: AccountId, value: Balance) -> Result<()>;
+    fn _transfer_underlying(&mut self, to: AccountId, value: Balance) -> Result<()>;
+    fn _record_flashloan_repayment(&mut self, amount: Balance, premium: Balance) -> Result<()>;
+    fn _flash_mint(
+        &mut self,
+        initiator: AccountId,
+        receiver: AccountId,
+        amount: Balance,
+        params: Vec<u8>,
+    ) -> Result<()>;
     fn _assert_manager(&self) -> Result<()>;
     fn _validate_set_use_reserve_as_collateral(
         &self,
@@ -215,16 +434,30 @@ pub trait Internal {
     ) -> Result<()>;
     fn _accrue_reward(&self, user: AccountId) -> Result<()>;
     fn _set_incentives_controller(&mut self, incentives_controller: AccountId) -> Result<()>;
+    fn _set_facet(&mut self, facet: Facet, code_hash: Option<Hash>) -> Result<()>;
+    /// Runs `input` on the code of `facet` in the context of this pool. The call is a tail call,
+    /// the result of the facet is returned to the caller of the pool and this message ends there.
+    fn _delegate_to_facet<Args: Encode, R: Decode>(
+        &mut self,
+        facet: Facet,
+        input: ExecutionInput<Args>,
+    ) -> Result<R>;
     // view functions
     fn _underlying(&self) -> Option<AccountId>;
+    fn _underlying_asset(&self) -> Option<Underlying>;
     fn _controller(&self) -> Option<AccountId>;
     fn _manager(&self) -> Option<AccountId>;
+    fn _pending_manager(&self) -> Option<AccountId>;
     fn _incentives_controller(&self) -> Option<AccountId>;
+    fn _facet(&self, facet: Facet) -> Option<Hash>;
     fn _get_cash_prior(&self) -> Balance;
     fn _total_borrows(&self) -> Balance;
+    fn _total_variable_borrows(&self) -> Balance;
+    fn _total_stable_borrows(&self) -> Balance;
     fn _borrows_scaled(&self) -> Balance;
     fn _total_reserves(&self) -> Balance;
     fn _reserves_scaled(&self) -> Balance;
+    fn _bad_debt(&self) -> Balance;
     fn _rate_model(&self) -> Option<AccountId>;
     fn _borrow_rate_per_msec(
         &self,
@@ -232,6 +465,7 @@ pub trait Internal {
         borrows: Balance,
         reserves: Balance,
     ) -> WrappedU256;
+    fn _utilization_rate(&self, cash: Balance, borrows: Balance, reserves: Balance) -> WrappedU256;
     fn _supply_rate_per_msec(
         &self,
         cash: Balance,
@@ -239,18 +473,61 @@ pub trait Internal {
         reserves: Balance,
         reserve_factor: WrappedU256,
     ) -> WrappedU256;
-    fn _borrow_balance_stored(&self, account: AccountId) -> Balance;
+    fn _borrow_balance_stored(&self, account: AccountId) -> Result<Balance>;
+    fn _variable_borrow_balance_stored(&self, account: AccountId) -> Result<Balance>;
+    fn _stable_borrow_balance_stored(&self, account: AccountId) -> Balance;
+    fn _stable_borrow_of(&self, account: AccountId) -> StableBorrow;
+    fn _stable_borrow_rate_per_msec(&self) -> WrappedU256;
+    fn _average_stable_rate(&self) -> WrappedU256;
+    fn _stable_borrow_enabled(&self) -> bool;
+    fn _stable_rate_premium_mantissa(&self) -> WrappedU256;
+    fn _stable_rebalance_threshold_mantissa(&self) -> WrappedU256;
+    fn _borrow_snapshot(&self, account: AccountId) -> BorrowSnapshot;
     fn _balance_of_underlying(&self, account: AccountId) -> Balance;
     fn _principal_balance_of(&self, account: &AccountId) -> Balance;
     fn _principal_total_supply(&self) -> Balance;
     fn _accrual_block_timestamp(&self) -> Timestamp;
+    fn _borrowers_count(&self) -> u32;
+    fn _borrowers_paginated(&self, offset: u32, limit: u32) -> Vec<AccountId>;
+    fn _checkpoints_count(&self) -> u32;
+    fn _checkpoints(&self, offset: u32, limit: u32) -> Vec<RateCheckpoint>;
     fn _borrow_index(&self) -> WrappedU256;
     fn _initial_exchange_rate_mantissa(&self) -> WrappedU256;
     fn _reserve_factor_mantissa(&self) -> WrappedU256;
     fn _exchange_rate_stored(&self) -> U256;
     fn _get_interest_at(&self, at: Timestamp) -> Result<CalculateInterestOutput>;
-    fn _increase_debt(&mut self, borrower: AccountId, amount: Balance, neg: bool);
+    fn _increase_debt(&mut self, borrower: AccountId, amount: Balance, neg: bool) -> Result<()>;
+    fn _increase_stable_debt(
+        &mut self,
+        borrower: AccountId,
+        amount: Balance,
+        neg: bool,
+    ) -> Result<()>;
+    fn _update_stable_totals(
+        &mut self,
+        amount: Balance,
+        rate: WrappedU256,
+        neg: bool,
+    ) -> Result<()>;
+    fn _update_borrow_snapshot(&mut self, borrower: AccountId, amount: Balance, neg: bool);
+    fn _add_borrower(&mut self, borrower: AccountId);
+    fn _remove_borrower(&mut self, borrower: AccountId);
     fn _liquidation_threshold(&self) -> u128;
+    fn _borrow_rate_max_mantissa(&self) -> WrappedU256;
+    fn _account_supply_cap(&self) -> Balance;
+    fn _max_accrual_delta(&self) -> Timestamp;
+    fn _compound_interest(&self) -> bool;
+    fn _flash_mint_premium(&self) -> u128;
+    fn _flash_minted(&self) -> Balance;
+    fn _exchange_rate_supply(&self) -> Balance;
+    fn _origination_fee(&self) -> u128;
+    fn _keeper_incentive(&self) -> KeeperIncentive;
+    fn _underlying_call_config(&self) -> UnderlyingCallConfig;
+    fn _outflow_limit(&self) -> Balance;
+    fn _outflow_window(&self) -> Timestamp;
+    fn _outflow_in_window(&self) -> Balance;
+    fn _treasury(&self) -> Option<AccountId>;
+    fn _reserves_sweep_threshold(&self) -> Balance;
     fn _delegate_allowance(&self, owner: &AccountId, delegatee: &AccountId) -> Balance;
     fn _using_reserve_as_collateral(&self, user: AccountId) -> Option<bool>;
     // event emission
@@ -285,6 +562,21 @@ pub trait Internal {
         new_index: WrappedU256,
         new_total_borrows: Balance,
     );
+    fn _emit_swap_borrow_rate_mode_event(
+        &self,
+        borrower: AccountId,
+        rate_mode: RateMode,
+        amount: Balance,
+    );
+    fn _emit_flash_mint_event(
+        &self,
+        receiver: AccountId,
+        initiator: AccountId,
+        amount: Balance,
+        premium: Balance,
+    );
+    fn _emit_keeper_bounty_paid_event(&self, keeper: AccountId, amount: Balance);
+    fn _emit_origination_fee_charged_event(&self, borrower: AccountId, fee: Balance);
     fn _emit_reserves_added_event(
         &self,
         benefactor: AccountId,
@@ -292,9 +584,29 @@ pub trait Internal {
         new_total_reserves: Balance,
     );
     fn _emit_reserves_reduced_event(&self, reduce_amount: Balance, total_reserves_new: Balance);
+    fn _emit_reserves_swept_event(
+        &self,
+        treasury: AccountId,
+        amount: Balance,
+        total_reserves_new: Balance,
+    );
+    fn _emit_bad_debt_absorbed_event(
+        &self,
+        borrower: AccountId,
+        amount: Balance,
+        bad_debt: Balance,
+    );
+    fn _emit_bad_debt_repaid_event(&self, payer: AccountId, amount: Balance, bad_debt: Balance);
     fn _emit_new_controller_event(&self, old: Option<AccountId>, new: Option<AccountId>);
     fn _emit_new_interest_rate_model_event(&self, old: Option<AccountId>, new: Option<AccountId>);
     fn _emit_new_reserve_factor_event(&self, old: WrappedU256, new: WrappedU256);
+    fn _emit_admin_action_event(
+        &self,
+        action: ActionId,
+        target: Option<AccountId>,
+        old_value: Vec<u8>,
+        new_value: Vec<u8>,
+    );
     fn _emit_delegate_approval_event(
         &self,
         owner: AccountId,
@@ -351,7 +663,20 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
     }
 
     default fn accrue_interest(&mut self) -> Result<()> {
-        self._accrue_interest()
+        let incentive = self._keeper_incentive();
+        let elapsed = Self::env()
+            .block_timestamp()
+            .saturating_sub(self._accrual_block_timestamp());
+        self._accrue_interest()?;
+
+        // pools accruing their collateral during a liquidation are not paid
+        if incentive.accrual_bounty != 0
+            && elapsed >= incentive.stale_after
+            && Self::env().caller_is_origin()
+        {
+            self._pay_keeper_bounty(Self::env().caller(), incentive.accrual_bounty)?;
+        }
+        Ok(())
     }
 
     default fn mint(&mut self, mint_amount: Balance) -> Result<()> {
@@ -387,23 +712,55 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
 
     default fn borrow(&mut self, borrow_amount: Balance) -> Result<()> {
         self._accrue_interest()?;
-        self._borrow(Self::env().caller(), borrow_amount, true)
+        self._borrow(
+            Self::env().caller(),
+            borrow_amount,
+            true,
+            RateMode::Variable,
+        )
+    }
+
+    default fn borrow_with_rate_mode(
+        &mut self,
+        borrow_amount: Balance,
+        rate_mode: RateMode,
+    ) -> Result<()> {
+        self._accrue_interest()?;
+        self._borrow(Self::env().caller(), borrow_amount, true, rate_mode)
+    }
+
+    default fn swap_borrow_rate_mode(&mut self, rate_mode: RateMode) -> Result<()> {
+        self._accrue_interest()?;
+        self._swap_borrow_rate_mode(Self::env().caller(), rate_mode)
+    }
+
+    default fn rebalance_stable_borrow_rate(&mut self, account: AccountId) -> Result<()> {
+        self._accrue_interest()?;
+        self._rebalance_stable_borrow_rate(account)
     }
 
     #[modifiers(delegated_allowed(borrower, borrow_amount))]
     default fn borrow_for(&mut self, borrower: AccountId, borrow_amount: Balance) -> Result<()> {
         self._accrue_interest()?;
-        self._borrow(borrower, borrow_amount, true)
+        self._borrow(borrower, borrow_amount, true, RateMode::Variable)
     }
 
     #[modifiers(only_flashloan_gateway)]
     default fn borrow_for_flashloan(
         &mut self,
         borrower: AccountId,
+        initiator: AccountId,
         borrow_amount: Balance,
     ) -> Result<()> {
         self._accrue_interest()?;
-        self._borrow(borrower, borrow_amount, false)
+        if initiator != borrower {
+            let delegate_allowance = self._delegate_allowance(&borrower, &initiator);
+            if delegate_allowance < borrow_amount {
+                return Err(Error::InsufficientDelegateAllowance)
+            }
+            self._approve_delegate(borrower, initiator, delegate_allowance - borrow_amount)?;
+        }
+        self._borrow(borrower, borrow_amount, false, RateMode::Variable)
     }
 
     default fn repay_borrow(&mut self, repay_amount: Balance) -> Result<()> {
@@ -434,11 +791,15 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         repay_amount: Balance,
         collateral: AccountId,
     ) -> Result<()> {
-        self._accrue_interest()?;
-        if collateral != Self::env().account_id() {
-            PoolRef::accrue_interest(&collateral)?;
-        }
-        self._liquidate_borrow(Self::env().caller(), borrower, repay_amount, collateral)
+        PoolLiquidationFacet::liquidate_borrow(self, borrower, repay_amount, collateral)
+    }
+
+    default fn liquidate_borrow_max(
+        &mut self,
+        borrower: AccountId,
+        collateral: AccountId,
+    ) -> Result<()> {
+        PoolLiquidationFacet::liquidate_borrow_max(self, borrower, collateral)
     }
 
     default fn seize(
@@ -447,8 +808,11 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         borrower: AccountId,
         seize_tokens: Balance,
     ) -> Result<()> {
-        self._accrue_interest()?;
-        self._seize(Self::env().caller(), liquidator, borrower, seize_tokens)
+        PoolLiquidationFacet::seize(self, liquidator, borrower, seize_tokens)
+    }
+
+    default fn absorb(&mut self, borrower: AccountId) -> Result<Balance> {
+        PoolLiquidationFacet::absorb(self, borrower)
     }
 
     #[modifiers(only_flashloan_gateway)]
@@ -456,11 +820,63 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         self._transfer_underlying(to, amount)
     }
 
+    #[modifiers(only_flashloan_gateway)]
+    default fn record_flashloan_repayment(
+        &mut self,
+        amount: Balance,
+        premium: Balance,
+    ) -> Result<()> {
+        self._record_flashloan_repayment(amount, premium)
+    }
+
+    default fn flash_mint(
+        &mut self,
+        amount: Balance,
+        receiver: AccountId,
+        params: Vec<u8>,
+    ) -> Result<()> {
+        self._accrue_interest()?;
+        self._flash_mint(Self::env().caller(), receiver, amount, params)
+    }
+
     default fn set_controller(&mut self, new_controller: AccountId) -> Result<()> {
         self._assert_manager()?;
         let old = self._controller();
         self._set_controller(new_controller)?;
         self._emit_new_controller_event(old, Some(new_controller));
+        self._emit_admin_action_event(
+            selector_bytes!("Pool::set_controller"),
+            None,
+            old.encode(),
+            Some(new_controller).encode(),
+        );
+        Ok(())
+    }
+
+    default fn set_manager(&mut self, new_manager: AccountId) -> Result<()> {
+        self._assert_manager()?;
+        let old = self.data::<Data>().propose_manager(new_manager);
+        self._emit_admin_action_event(
+            selector_bytes!("Pool::set_manager"),
+            None,
+            old.encode(),
+            Some(new_manager).encode(),
+        );
+        Ok(())
+    }
+
+    default fn accept_manager(&mut self) -> Result<()> {
+        let caller = Self::env().caller();
+        let old = self
+            .data::<Data>()
+            .accept_manager(caller)
+            .map_err(|_| Error::CallerIsNotPendingManager)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Pool::accept_manager"),
+            None,
+            old.encode(),
+            Some(caller).encode(),
+        );
         Ok(())
     }
 
@@ -468,11 +884,7 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         &mut self,
         new_reserve_factor_mantissa: WrappedU256,
     ) -> Result<()> {
-        self._assert_manager()?;
-        let old = self._reserve_factor_mantissa();
-        self._set_reserve_factor_mantissa(new_reserve_factor_mantissa)?;
-        self._emit_new_reserve_factor_event(old, new_reserve_factor_mantissa);
-        Ok(())
+        PoolAdminFacet::set_reserve_factor_mantissa(self, new_reserve_factor_mantissa)
     }
 
     default fn set_interest_rate_model(
@@ -483,6 +895,12 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         let old = self._rate_model();
         self._set_interest_rate_model(new_interest_rate_model)?;
         self._emit_new_interest_rate_model_event(old, Some(new_interest_rate_model));
+        self._emit_admin_action_event(
+            selector_bytes!("Pool::set_interest_rate_model"),
+            None,
+            old.encode(),
+            Some(new_interest_rate_model).encode(),
+        );
         Ok(())
     }
 
@@ -491,19 +909,90 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         self._add_reserves(amount)
     }
 
-    default fn reduce_reserves(&mut self, amount: Balance) -> Result<()> {
-        self._assert_manager()?;
+    default fn repay_bad_debt(&mut self, amount: Balance) -> Result<()> {
         self._accrue_interest()?;
-        self._reduce_reserves(Self::env().caller(), amount)
+        self._repay_bad_debt(Self::env().caller(), amount)
+    }
+
+    default fn reduce_reserves(&mut self, amount: Balance) -> Result<()> {
+        PoolAdminFacet::reduce_reserves(self, amount)
+    }
+
+    default fn cover_bad_debt(&mut self, amount: Balance) -> Result<()> {
+        PoolAdminFacet::cover_bad_debt(self, amount)
     }
 
     default fn sweep_token(&mut self, asset: AccountId) -> Result<()> {
-        self._assert_manager()?;
-        self._sweep_token(asset)
+        PoolAdminFacet::sweep_token(self, asset)
+    }
+
+    default fn sweep_reserves_to_treasury(&mut self) -> Result<Balance> {
+        PoolAdminFacet::sweep_reserves_to_treasury(self)
     }
 
     default fn set_liquidation_threshold(&mut self, new_liquidation_threshold: u128) -> Result<()> {
-        self._set_liquidation_threshold(new_liquidation_threshold)
+        PoolAdminFacet::set_liquidation_threshold(self, new_liquidation_threshold)
+    }
+
+    default fn set_borrow_rate_max_mantissa(
+        &mut self,
+        new_borrow_rate_max_mantissa: WrappedU256,
+    ) -> Result<()> {
+        PoolAdminFacet::set_borrow_rate_max_mantissa(self, new_borrow_rate_max_mantissa)
+    }
+
+    default fn set_account_supply_cap(&mut self, new_cap: Balance) -> Result<()> {
+        PoolAdminFacet::set_account_supply_cap(self, new_cap)
+    }
+
+    default fn set_underlying_call_config(&mut self, config: UnderlyingCallConfig) -> Result<()> {
+        PoolAdminFacet::set_underlying_call_config(self, config)
+    }
+
+    default fn set_max_accrual_delta(&mut self, new_max_accrual_delta: Timestamp) -> Result<()> {
+        PoolAdminFacet::set_max_accrual_delta(self, new_max_accrual_delta)
+    }
+
+    default fn set_compound_interest(&mut self, enabled: bool) -> Result<()> {
+        PoolAdminFacet::set_compound_interest(self, enabled)
+    }
+
+    default fn set_flash_mint_premium(&mut self, premium: u128) -> Result<()> {
+        PoolAdminFacet::set_flash_mint_premium(self, premium)
+    }
+
+    default fn set_origination_fee(&mut self, fee: u128) -> Result<()> {
+        PoolAdminFacet::set_origination_fee(self, fee)
+    }
+
+    default fn set_keeper_incentive(&mut self, incentive: KeeperIncentive) -> Result<()> {
+        PoolAdminFacet::set_keeper_incentive(self, incentive)
+    }
+
+    default fn set_stable_rate_config(
+        &mut self,
+        enabled: bool,
+        premium_mantissa: WrappedU256,
+        rebalance_threshold_mantissa: WrappedU256,
+    ) -> Result<()> {
+        PoolAdminFacet::set_stable_rate_config(
+            self,
+            enabled,
+            premium_mantissa,
+            rebalance_threshold_mantissa,
+        )
+    }
+
+    default fn set_outflow_limit(&mut self, limit: Balance, window: Timestamp) -> Result<()> {
+        PoolAdminFacet::set_outflow_limit(self, limit, window)
+    }
+
+    default fn set_reserves_sweep_config(
+        &mut self,
+        treasury: Option<AccountId>,
+        threshold: Balance,
+    ) -> Result<()> {
+        PoolAdminFacet::set_reserves_sweep_config(self, treasury, threshold)
     }
 
     default fn approve_delegate(&mut self, delegatee: AccountId, amount: Balance) -> Result<()> {
@@ -549,14 +1038,30 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         &mut self,
         incentives_controller: AccountId,
     ) -> Result<()> {
+        PoolAdminFacet::set_incentives_controller(self, incentives_controller)
+    }
+
+    default fn set_facet(&mut self, facet: Facet, code_hash: Option<Hash>) -> Result<()> {
         self._assert_manager()?;
-        self._set_incentives_controller(incentives_controller)
+        let old = self._facet(facet);
+        self._set_facet(facet, code_hash)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Pool::set_facet"),
+            None,
+            (facet, old).encode(),
+            (facet, code_hash).encode(),
+        );
+        Ok(())
     }
 
     default fn underlying(&self) -> Option<AccountId> {
         self._underlying()
     }
 
+    default fn underlying_asset(&self) -> Option<Underlying> {
+        self._underlying_asset()
+    }
+
     default fn controller(&self) -> Option<AccountId> {
         self._controller()
     }
@@ -565,6 +1070,10 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         self._manager()
     }
 
+    default fn pending_manager(&self) -> Option<AccountId> {
+        self._pending_manager()
+    }
+
     default fn incentives_controller(&self) -> Option<AccountId> {
         self._incentives_controller()
     }
@@ -590,29 +1099,47 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         self._total_reserves()
     }
 
+    default fn bad_debt(&self) -> Balance {
+        self._bad_debt()
+    }
+
     default fn get_account_snapshot(&self, account: AccountId) -> (Balance, Balance, U256) {
+        // a debt overflowing u128 is reported as the largest one, never as no debt
+        let borrow_balance = self._borrow_balance_stored(account).unwrap_or(Balance::MAX);
         let using_as_collateral = self._using_reserve_as_collateral(account);
         if using_as_collateral.unwrap_or(false) {
             return (
                 Internal::_balance_of(self, &account),
-                self._borrow_balance_stored(account),
+                borrow_balance,
                 self._exchange_rate_stored(),
             )
         }
-        (
-            0,
-            self._borrow_balance_stored(account),
-            self._exchange_rate_stored(),
-        )
+        (0, borrow_balance, self._exchange_rate_stored())
     }
 
     default fn borrow_balance_stored(&self, account: AccountId) -> Balance {
-        self._borrow_balance_stored(account)
+        self._borrow_balance_stored(account).unwrap_or(Balance::MAX)
     }
 
     default fn borrow_balance_current(&mut self, account: AccountId) -> Result<Balance> {
         self._accrue_interest()?;
-        Ok(self._borrow_balance_stored(account))
+        self._borrow_balance_stored(account)
+    }
+
+    default fn accrued_interest_of(
+        &mut self,
+        account: AccountId,
+    ) -> Result<(Balance, WrappedU256)> {
+        self._accrue_interest()?;
+        let snapshot = self._borrow_snapshot(account);
+        let interest = self
+            ._borrow_balance_stored(account)?
+            .saturating_sub(snapshot.principal);
+        Ok((interest, snapshot.interest_index))
+    }
+
+    default fn borrow_snapshot(&self, account: AccountId) -> BorrowSnapshot {
+        self._borrow_snapshot(account)
     }
 
     default fn borrow_rate_per_msec(&self) -> WrappedU256 {
@@ -630,6 +1157,13 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         self._supply_rate_per_msec(cash, borrows, reserves, reserve_factor)
     }
 
+    default fn utilization_rate(&self) -> WrappedU256 {
+        let cash = self._get_cash_prior();
+        let borrows = self._total_borrows();
+        let reserves = self._total_reserves();
+        self._utilization_rate(cash, borrows, reserves)
+    }
+
     default fn principal_balance_of(&self, account: AccountId) -> Balance {
         self._principal_balance_of(&account)
     }
@@ -650,6 +1184,86 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         self._liquidation_threshold()
     }
 
+    default fn borrow_rate_max_mantissa(&self) -> WrappedU256 {
+        self._borrow_rate_max_mantissa()
+    }
+
+    default fn stable_borrow_enabled(&self) -> bool {
+        self._stable_borrow_enabled()
+    }
+
+    default fn stable_rate_premium_mantissa(&self) -> WrappedU256 {
+        self._stable_rate_premium_mantissa()
+    }
+
+    default fn stable_rebalance_threshold_mantissa(&self) -> WrappedU256 {
+        self._stable_rebalance_threshold_mantissa()
+    }
+
+    default fn stable_borrow_rate_per_msec(&self) -> WrappedU256 {
+        self._stable_borrow_rate_per_msec()
+    }
+
+    default fn average_stable_rate_per_msec(&self) -> WrappedU256 {
+        self._average_stable_rate()
+    }
+
+    default fn total_stable_borrows(&self) -> Balance {
+        self._total_stable_borrows()
+    }
+
+    default fn stable_borrow_of(&self, account: AccountId) -> StableBorrow {
+        self._stable_borrow_of(account)
+    }
+
+    default fn account_supply_cap(&self) -> Balance {
+        self._account_supply_cap()
+    }
+
+    default fn max_accrual_delta(&self) -> Timestamp {
+        self._max_accrual_delta()
+    }
+
+    default fn compound_interest(&self) -> bool {
+        self._compound_interest()
+    }
+
+    default fn flash_mint_premium(&self) -> u128 {
+        self._flash_mint_premium()
+    }
+
+    default fn origination_fee(&self) -> u128 {
+        self._origination_fee()
+    }
+
+    default fn keeper_incentive(&self) -> KeeperIncentive {
+        self._keeper_incentive()
+    }
+
+    default fn underlying_call_config(&self) -> UnderlyingCallConfig {
+        self._underlying_call_config()
+    }
+
+    default fn outflow_limit(&self) -> Balance {
+        self._outflow_limit()
+    }
+
+    default fn outflow_window(&self) -> Timestamp {
+        self._outflow_window()
+    }
+
+    default fn outflow_in_window(&self) -> Balance {
+        self._outflow_in_window()
+    }
+
+    default fn treasury(&self) -> Option<AccountId> {
+        self._treasury()
+    }
+
+    default fn reserves_sweep_threshold(&self) -> Balance {
+        self._reserves_sweep_threshold()
+    }
+
     default fn delegate_allowance(&self, owner: AccountId, delegatee: AccountId) -> Balance {
         self._delegate_allowance(&owner, &delegatee)
     }
@@ -673,6 +1287,60 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
             exchange_rate: self._exchange_rate_stored(),
         }
     }
+
+    default fn get_pool_metadata(&self) -> PoolMarketMetadata {
+        let contract_addr = Self::env().account_id();
+        let (mint_paused, borrow_paused, seize_paused, transfer_paused) = match self._controller() {
+            Some(controller) => {
+                (
+                    ControllerRef::mint_guardian_paused(&controller, contract_addr)
+                        .unwrap_or_default(),
+                    ControllerRef::borrow_guardian_paused(&controller, contract_addr)
+                        .unwrap_or_default(),
+                    ControllerRef::seize_guardian_paused(&controller),
+                    ControllerRef::transfer_guardian_paused(&controller),
+                )
+            }
+            None => (false, false, false, false),
+        };
+        PoolMarketMetadata {
+            underlying: self._underlying(),
+            decimals: self.token_decimals(),
+            exchange_rate: self._exchange_rate_stored().into(),
+            cash: self._get_cash_prior(),
+            total_supply: self._principal_total_supply(),
+            total_borrows: self._total_borrows(),
+            total_reserves: self._total_reserves(),
+            reserve_factor_mantissa: self._reserve_factor_mantissa(),
+            borrow_rate_per_msec: self.borrow_rate_per_msec(),
+            supply_rate_per_msec: self.supply_rate_per_msec(),
+            liquidation_threshold: self._liquidation_threshold(),
+            mint_paused,
+            borrow_paused,
+            seize_paused,
+            transfer_paused,
+        }
+    }
+
+    default fn borrowers_count(&self) -> u32 {
+        self._borrowers_count()
+    }
+
+    default fn borrowers_paginated(&self, offset: u32, limit: u32) -> Vec<AccountId> {
+        self._borrowers_paginated(offset, limit)
+    }
+
+    default fn checkpoints_count(&self) -> u32 {
+        self._checkpoints_count()
+    }
+
+    default fn checkpoints(&self, offset: u32, limit: u32) -> Vec<RateCheckpoint> {
+        self._checkpoints(offset, limit)
+    }
+
+    default fn facet(&self, facet: Facet) -> Option<Hash> {
+        self._facet(facet)
+    }
 }
 
 impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metadata::Data>> Internal
@@ -691,6 +1359,9 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         let mut data = self.data::<Data>();
         data.accrual_block_timestamp = at;
         data.borrow_index = out.borrow_index.into();
+        // credit the reserve share of stable interest now, as calculate_interest does for variable
+        self._update_stable_totals(0, WrappedU256::from(U256::zero()), false)?;
+        self._write_checkpoint(at);
         self._emit_accrue_interest_event(
             out.interest_accumulated,
             out.borrow_index.into(),
@@ -709,15 +1380,36 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         let borrow_rate =
             InterestRateModelRef::get_borrow_rate(&rate_model, cash, borrows, reserves);
 
-        calculate_interest(&CalculateInterestInput {
-            total_borrows: borrows,
+        // stable borrows accrue at their own rate, see `_total_stable_borrows`
+        let mut out = calculate_interest(&CalculateInterestInput {
+            total_borrows: self._total_variable_borrows(),
             total_reserves: reserves,
             borrow_index: idx.into(),
             borrow_rate: borrow_rate.into(),
             old_block_timestamp: self._accrual_block_timestamp(),
             new_block_timestamp: at,
             reserve_factor_mantissa: self._reserve_factor_mantissa().into(),
-        })
+            borrow_rate_max_mantissa: self._borrow_rate_max_mantissa().into(),
+            max_accrual_delta: self._max_accrual_delta(),
+            compound: self._compound_interest(),
+        })?;
+
+        // market-wide totals: stable debt and its reserve share since the last settlement
+        let data = self.data::<Data>();
+        let stable_new = stable_balance_at(
+            data.total_stable_borrows,
+            data.average_stable_rate.into(),
+            data.stable_last_update,
+            at,
+        )?;
+        let stable_reserves = reserves_of_interest(
+            stable_new.saturating_sub(data.total_stable_borrows),
+            self._reserve_factor_mantissa().into(),
+        )?;
+        out.interest_accumulated += stable_new.saturating_sub(self._total_stable_borrows());
+        out.total_borrows += stable_new;
+        out.total_reserves += stable_reserves;
+        Ok(out)
     }
 
     default fn _transfer_tokens(
@@ -728,16 +1420,6 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         value: Balance,
         data: Vec<u8>,
     ) -> core::result::Result<(), PSP22Error> {
-        let reward_result = self._accrue_reward(src);
-        if reward_result.is_err() {
-            return Err(PSP22Error::Custom(String::from("AccrueRewardFailed")))
-        }
-
-        let reward_result = self._accrue_reward(dst);
-        if reward_result.is_err() {
-            return Err(PSP22Error::Custom(String::from("AccrueRewardFailed")))
-        }
-
         let contract_addr = Self::env().account_id();
         let (account_balance, account_borrow_balance, exchange_rate) =
             self.get_account_snapshot(src);
@@ -773,7 +1455,8 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
             Exp {
                 mantissa: exchange_rate.into(),
             },
-        );
+        )
+        .map_err(|_| PSP22Error::Custom(String::from("MathOverflow")))?;
 
         if spender == src {
             // copied from PSP22#transfer
@@ -802,7 +1485,6 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
     }
 
     default fn _mint(&mut self, minter: AccountId, mint_amount: Balance) -> Result<()> {
-        self._accrue_reward(minter)?;
         let contract_addr = Self::env().account_id();
 
         let controller = self._controller().ok_or(Error::ControllerIsNotSet)?;
@@ -817,17 +1499,29 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
             return Err(Error::AccrualBlockNumberIsNotFresh)
         };
 
+        let account_supply_cap = self._account_supply_cap();
+        if account_supply_cap != 0
+            && self
+                ._balance_of_underlying(minter)
+                .saturating_add(mint_amount)
+                > account_supply_cap
+        {
+            return Err(Error::AccountSupplyCapExceeded)
+        }
+
         let exchange_rate = self._exchange_rate_stored(); // NOTE: need exchange_rate calculation before transfer underlying
         let caller = Self::env().caller();
 
         self._transfer_underlying_from(caller, contract_addr, mint_amount)?;
-        let minted_tokens = U256::from(mint_amount)
-            .mul(exp_scale())
-            .div(exchange_rate)
-            .as_u128();
-
-        // Check if it is first deposit.
-        let lp_balance = self._principal_balance_of(&caller);
+        let minted_tokens = scaled_amount_of_truncate(
+            mint_amount,
+            Exp {
+                mantissa: exchange_rate.into(),
+            },
+        )?;
+
+        // Check if it is first deposit.
+        let lp_balance = self._principal_balance_of(&caller);
         if lp_balance == 0 {
             self._set_use_reserve_as_collateral(minter, true);
         }
@@ -842,7 +1536,15 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
     }
 
     default fn _redeem(&mut self, redeemer: AccountId, redeem_amount: Balance) -> Result<()> {
-        self._accrue_reward(redeemer)?;
+        self._redeem_to(redeemer, redeemer, redeem_amount)
+    }
+
+    default fn _redeem_to(
+        &mut self,
+        redeemer: AccountId,
+        receiver: AccountId,
+        redeem_amount: Balance,
+    ) -> Result<()> {
         if redeem_amount == 0
             || !self
                 ._using_reserve_as_collateral(redeemer)
@@ -889,14 +1591,15 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
 
         self._burn_from(
             redeemer,
-            scaled_amount_of(
+            scaled_amount_of_ceil(
                 redeem_amount,
                 Exp {
                     mantissa: exchange_rate.into(),
                 },
-            ),
+            )?,
         )?;
-        self._transfer_underlying(redeemer, redeem_amount)?;
+        self._consume_outflow(redeem_amount)?;
+        self._transfer_underlying(receiver, redeem_amount)?;
 
         self._emit_redeem_event(redeemer, redeem_amount);
 
@@ -906,13 +1609,20 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         Ok(())
     }
 
-    default fn _increase_debt(&mut self, borrower: AccountId, amount: Balance, neg: bool) {
-        let scaled = scaled_amount_of(
-            amount,
-            Exp {
-                mantissa: self._borrow_index(),
-            },
-        );
+    default fn _increase_debt(
+        &mut self,
+        borrower: AccountId,
+        amount: Balance,
+        neg: bool,
+    ) -> Result<()> {
+        let idx = Exp {
+            mantissa: self._borrow_index(),
+        };
+        let scaled = if neg {
+            scaled_amount_of_truncate(amount, idx)?
+        } else {
+            scaled_amount_of_ceil(amount, idx)?
+        };
         let account_borrows_prev = self
             .data::<Data>()
             .account_borrows
@@ -922,13 +1632,159 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
             self.data::<Data>()
                 .account_borrows
                 .insert(&borrower, &(account_borrows_prev - scaled));
-            self.data::<Data>().borrows_scaled -= scaled
+            self.data::<Data>().borrows_scaled -= scaled;
         } else {
             self.data::<Data>()
                 .account_borrows
                 .insert(&borrower, &(account_borrows_prev + scaled));
-            self.data::<Data>().borrows_scaled += scaled
+            self.data::<Data>().borrows_scaled += scaled;
         }
+        self._update_borrow_snapshot(borrower, amount, neg);
+        Ok(())
+    }
+
+    default fn _increase_stable_debt(
+        &mut self,
+        borrower: AccountId,
+        amount: Balance,
+        neg: bool,
+    ) -> Result<()> {
+        let prev = self._stable_borrow_of(borrower);
+        let balance_prev = self._stable_borrow_balance_stored(borrower);
+        let rate = if neg {
+            prev.rate
+        } else {
+            self._stable_borrow_rate_per_msec()
+        };
+        self._update_stable_totals(amount, rate, neg)?;
+
+        if neg && balance_prev <= amount {
+            self.data::<Data>().stable_borrows.remove(&borrower);
+        } else {
+            let (balance_new, rate_new) = if neg {
+                (balance_prev - amount, prev.rate)
+            } else {
+                (
+                    balance_prev + amount,
+                    weighted_average_rate(
+                        balance_prev,
+                        prev.rate.into(),
+                        amount,
+                        rate.into(),
+                        false,
+                    )
+                    .into(),
+                )
+            };
+            self.data::<Data>().stable_borrows.insert(
+                &borrower,
+                &StableBorrow {
+                    balance: balance_new,
+                    rate: rate_new,
+                    last_update: self._accrual_block_timestamp(),
+                },
+            );
+        }
+        self._update_borrow_snapshot(borrower, amount, neg);
+        Ok(())
+    }
+
+    default fn _update_stable_totals(
+        &mut self,
+        amount: Balance,
+        rate: WrappedU256,
+        neg: bool,
+    ) -> Result<()> {
+        let total_prev = self._total_stable_borrows();
+        // stable interest accrued since the last update, credited to reserves by the reserve factor
+        let interest = total_prev.saturating_sub(self.data::<Data>().total_stable_borrows);
+        let reserves_scaled = scaled_amount_of_truncate(
+            reserves_of_interest(interest, self._reserve_factor_mantissa().into())?,
+            Exp {
+                mantissa: self._borrow_index(),
+            },
+        )?;
+        let average_new = weighted_average_rate(
+            total_prev,
+            self._average_stable_rate().into(),
+            amount,
+            rate.into(),
+            neg,
+        );
+        let total_new = if neg {
+            total_prev.saturating_sub(amount)
+        } else {
+            total_prev + amount
+        };
+        let at = self._accrual_block_timestamp();
+        let data = self.data::<Data>();
+        data.total_stable_borrows = total_new;
+        data.average_stable_rate = average_new.into();
+        data.stable_last_update = at;
+        data.reserves_scaled += reserves_scaled;
+        Ok(())
+    }
+
+    default fn _update_borrow_snapshot(&mut self, borrower: AccountId, amount: Balance, neg: bool) {
+        let snapshot_prev = self._borrow_snapshot(borrower);
+        if neg {
+            // repayments settle accrued interest first, then principal
+            let balance_new = self
+                ._borrow_balance_stored(borrower)
+                .unwrap_or(Balance::MAX);
+            if balance_new == 0 {
+                self.data::<Data>()
+                    .account_borrow_snapshots
+                    .remove(&borrower);
+                self._remove_borrower(borrower);
+                return
+            }
+            self.data::<Data>().account_borrow_snapshots.insert(
+                &borrower,
+                &BorrowSnapshot {
+                    principal: snapshot_prev.principal.min(balance_new),
+                    interest_index: self._borrow_index(),
+                },
+            );
+        } else {
+            self._add_borrower(borrower);
+            self.data::<Data>().account_borrow_snapshots.insert(
+                &borrower,
+                &BorrowSnapshot {
+                    principal: snapshot_prev.principal + amount,
+                    interest_index: self._borrow_index(),
+                },
+            );
+        }
+    }
+
+    default fn _add_borrower(&mut self, borrower: AccountId) {
+        let data = self.data::<Data>();
+        if data.borrower_positions.get(&borrower).is_some() {
+            return
+        }
+        let position = data.borrowers_count;
+        data.borrowers.insert(&position, &borrower);
+        data.borrower_positions.insert(&borrower, &position);
+        data.borrowers_count += 1;
+    }
+
+    default fn _remove_borrower(&mut self, borrower: AccountId) {
+        let data = self.data::<Data>();
+        let position = match data.borrower_positions.get(&borrower) {
+            Some(position) => position,
+            None => return,
+        };
+        // swap the last borrower into the freed slot
+        let last = data.borrowers_count - 1;
+        if position != last {
+            let last_borrower = data.borrowers.get(&last).unwrap();
+            data.borrowers.insert(&position, &last_borrower);
+            data.borrower_positions.insert(&last_borrower, &position);
+        }
+        data.borrowers.remove(&last);
+        data.borrower_positions.remove(&borrower);
+        data.borrowers_count = last;
     }
 
     default fn _borrow(
@@ -936,7 +1792,11 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         borrower: AccountId,
         borrow_amount: Balance,
         release_underlying: bool,
+        rate_mode: RateMode,
     ) -> Result<()> {
+        if rate_mode == RateMode::Stable && !self._stable_borrow_enabled() {
+            return Err(Error::StableBorrowNotEnabled)
+        }
         self._accrue_reward(borrower)?;
 
         let controller = self._controller().ok_or(Error::ControllerIsNotSet)?;
@@ -944,6 +1804,9 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         let caller: ink_primitives::AccountId = Self::env().caller();
         let (account_balance, account_borrow_balance, exchange_rate) =
             self.get_account_snapshot(borrower);
+        // the fee is added to the debt, so the borrower needs liquidity for it as well
+        let fee = origination_fee_of(borrow_amount, self._origination_fee())?;
+        let debt_amount = borrow_amount + fee;
 
         let pool_attribute = PoolAttributes {
             pool: Some(contract_addr),
@@ -960,7 +1823,7 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
             &controller,
             contract_addr,
             borrower,
-            borrow_amount,
+            debt_amount,
             Some(pool_attribute),
         )?;
 
@@ -968,18 +1831,32 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         if self._accrual_block_timestamp() != current_timestamp {
             return Err(Error::AccrualBlockNumberIsNotFresh)
         };
-        if self._get_cash_prior() < borrow_amount {
+        // underlying not released here already left the cash, e.g. lent by a flashloan
+        if release_underlying && self._get_cash_prior() < borrow_amount {
             return Err(Error::BorrowCashNotAvailable)
         }
 
-        let account_borrows_prev = self._borrow_balance_stored(borrower);
-        let account_borrows_new = account_borrows_prev + borrow_amount;
-        let total_borrows_new = self._total_borrows() + borrow_amount;
+        let account_borrows_prev = self._borrow_balance_stored(borrower)?;
+        let account_borrows_new = account_borrows_prev + debt_amount;
+        let total_borrows_new = self._total_borrows() + debt_amount;
 
+        self._consume_outflow(borrow_amount)?;
         if release_underlying {
             self._transfer_underlying(caller, borrow_amount)?;
         }
-        self._increase_debt(borrower, borrow_amount, false);
+        match rate_mode {
+            RateMode::Variable => self._increase_debt(borrower, debt_amount, false)?,
+            RateMode::Stable => self._increase_stable_debt(borrower, debt_amount, false)?,
+        }
+        if fee > 0 {
+            self.data::<Data>().reserves_scaled += scaled_amount_of_truncate(
+                fee,
+                Exp {
+                    mantissa: self._borrow_index(),
+                },
+            )?;
+            self._emit_origination_fee_charged_event(borrower, fee);
+        }
 
         self._emit_borrow_event(
             borrower,
@@ -1018,7 +1895,7 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
             return Err(Error::AccrualBlockNumberIsNotFresh)
         };
 
-        let account_borrow_prev = self._borrow_balance_stored(borrower);
+        let account_borrow_prev = self._borrow_balance_stored(borrower)?;
         let repay_amount_final = if repay_amount == u128::MAX {
             account_borrow_prev
         } else {
@@ -1029,7 +1906,16 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
 
         let account_borrows_new = account_borrow_prev - repay_amount_final;
         let total_borrows_new = self._total_borrows() - repay_amount_final;
-        self._increase_debt(borrower, repay_amount_final, true);
+        // variable debt is repaid first, then stable debt
+        let repay_variable =
+            repay_amount_final.min(self._variable_borrow_balance_stored(borrower)?);
+        let repay_stable = repay_amount_final - repay_variable;
+        if repay_variable > 0 {
+            self._increase_debt(borrower, repay_variable, true)?;
+        }
+        if repay_stable > 0 {
+            self._increase_stable_debt(borrower, repay_stable, true)?;
+        }
         self._emit_repay_borrow_event(
             payer,
             borrower,
@@ -1134,6 +2020,8 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
             seize_tokens
         };
 
+        self._pay_liquidation_bounty(liquidator, actual_repay_amount)?;
+
         self._emit_liquidate_borrow_event(
             liquidator,
             borrower,
@@ -1148,6 +2036,52 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         Ok(())
     }
 
+    default fn _liquidate_max_repay_amount(
+        &self,
+        borrower: AccountId,
+        collateral: AccountId,
+    ) -> Result<Balance> {
+        let controller = self._controller().ok_or(Error::ControllerIsNotSet)?;
+        let contract_addr = Self::env().account_id();
+        let borrow_balance = self._borrow_balance_stored(borrower)?;
+        let pool_borrowed_attributes = Some(PoolAttributesForSeizeCalculation {
+            underlying: self._underlying(),
+            decimals: self.token_decimals(),
+        });
+        let (collateral_tokens, exchange_rate, pool_collateral_attributes) =
+            if collateral == contract_addr {
+                let (collateral_tokens, _, exchange_rate) = self.get_account_snapshot(borrower);
+                (
+                    collateral_tokens,
+                    exchange_rate,
+                    pool_borrowed_attributes.clone(),
+                )
+            } else {
+                let (collateral_tokens, _, exchange_rate) =
+                    PoolRef::get_account_snapshot(&collateral, borrower);
+                (
+                    collateral_tokens,
+                    exchange_rate,
+                    Some(PoolAttributesForSeizeCalculation {
+                        underlying: PoolRef::underlying(&collateral),
+                        decimals: PoolRef::token_decimals(&collateral),
+                    }),
+                )
+            };
+
+        ControllerRef::liquidate_max_repay_amount(
+            &controller,
+            contract_addr,
+            collateral,
+            WrappedU256::from(exchange_rate),
+            borrow_balance,
+            collateral_tokens,
+            pool_borrowed_attributes,
+            pool_collateral_attributes,
+        )
+        .map_err(Error::from)
+    }
+
     default fn _seize(
         &mut self,
         seizer_token: AccountId,
@@ -1177,16 +2111,16 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
             mantissa: WrappedU256::from(self._exchange_rate_stored()),
         };
         let (liquidator_seize_tokens, protocol_seize_amount, protocol_seize_tokens) =
-            protocol_seize_amount(exchange_rate, seize_tokens, protocol_seize_share_mantissa());
+            protocol_seize_amount(exchange_rate, seize_tokens, protocol_seize_share_mantissa())?;
         let total_reserves_new = self._total_reserves() + protocol_seize_amount;
 
         // EFFECTS & INTERACTIONS
-        self.data::<Data>().reserves_scaled += scaled_amount_of(
+        self.data::<Data>().reserves_scaled += scaled_amount_of_truncate(
             protocol_seize_amount,
             Exp {
                 mantissa: self._borrow_index(),
             },
-        );
+        )?;
         self.data::<PSP22Data>().supply -= protocol_seize_tokens;
         self._burn_from(borrower, seize_tokens)?;
         self._mint_to(liquidator, liquidator_seize_tokens)?;
@@ -1199,12 +2133,67 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         Ok(())
     }
 
+    default fn _absorb(&mut self, borrower: AccountId) -> Result<Balance> {
+        self._accrue_reward(borrower)?;
+        let controller = self._controller().ok_or(Error::ControllerIsNotSet)?;
+        ControllerRef::absorb_allowed(&controller, Self::env().account_id(), borrower)?;
+
+        let current_timestamp = Self::env().block_timestamp();
+        if self._accrual_block_timestamp() != current_timestamp {
+            return Err(Error::AccrualBlockNumberIsNotFresh)
+        }
+        if Internal::_balance_of(self, &borrower) > 0 {
+            return Err(Error::AbsorbBorrowerHasCollateral)
+        }
+        let amount = self._borrow_balance_stored(borrower)?;
+        if amount == 0 {
+            return Err(Error::AbsorbNoDebt)
+        }
+
+        let write_off_variable = self._variable_borrow_balance_stored(borrower)?;
+        let write_off_stable = amount - write_off_variable;
+        if write_off_variable > 0 {
+            self._increase_debt(borrower, write_off_variable, true)?;
+        }
+        if write_off_stable > 0 {
+            self._increase_stable_debt(borrower, write_off_stable, true)?;
+        }
+        // the loss is borne by suppliers through the exchange rate until the bad debt is repaid
+        let bad_debt = self._bad_debt() + amount;
+        self.data::<Data>().bad_debt = bad_debt;
+
+        self._emit_bad_debt_absorbed_event(borrower, amount, bad_debt);
+        Ok(amount)
+    }
+
+    default fn _repay_bad_debt(&mut self, payer: AccountId, amount: Balance) -> Result<()> {
+        let current_timestamp = Self::env().block_timestamp();
+        if self._accrual_block_timestamp() != current_timestamp {
+            return Err(Error::AccrualBlockNumberIsNotFresh)
+        }
+        if self._bad_debt() < amount {
+            return Err(Error::BadDebtExceeded)
+        }
+
+        self._transfer_underlying_from(payer, Self::env().account_id(), amount)?;
+        let bad_debt = self._bad_debt() - amount;
+        self.data::<Data>().bad_debt = bad_debt;
+
+        self._emit_bad_debt_repaid_event(payer, amount, bad_debt);
+        Ok(())
+    }
+
     // admin functions
     default fn _set_controller(&mut self, new_controller: AccountId) -> Result<()> {
         self.data::<Data>().controller = Some(new_controller);
         Ok(())
     }
 
+    default fn _set_manager(&mut self, new_manager: AccountId) -> Result<()> {
+        self.data::<Data>().manager = Some(new_manager);
+        Ok(())
+    }
+
     default fn _set_reserve_factor_mantissa(
         &mut self,
         new_reserve_factor_mantissa: WrappedU256,
@@ -1216,7 +2205,7 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
             return Err(Error::AccrualBlockNumberIsNotFresh)
         }
 
-        if U256::from(new_reserve_factor_mantissa).gt(&reserve_factor_max_mantissa()) {
+        if !is_valid_reserve_factor(new_reserve_factor_mantissa.into()) {
             return Err(Error::SetReserveFactorBoundsCheck)
         }
 
@@ -1245,12 +2234,12 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
 
         let total_reserves_new = self._total_reserves().add(amount);
 
-        self.data::<Data>().reserves_scaled += scaled_amount_of(
+        self.data::<Data>().reserves_scaled += scaled_amount_of_truncate(
             amount,
             Exp {
                 mantissa: self._borrow_index(),
             },
-        );
+        )?;
         let caller = Self::env().caller();
         self._transfer_underlying_from(caller, Self::env().account_id(), amount)?;
 
@@ -1274,12 +2263,12 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         }
         let total_reserves_new = self._total_reserves().sub(amount);
         let mut data = self.data::<Data>();
-        data.reserves_scaled -= scaled_amount_of(
+        data.reserves_scaled -= scaled_amount_of_ceil(
             amount,
             Exp {
                 mantissa: data.borrow_index,
             },
-        );
+        )?;
         self._transfer_underlying(admin, amount)?;
 
         // event
@@ -1287,6 +2276,62 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         Ok(())
     }
 
+    default fn _sweep_reserves_to_treasury(&mut self) -> Result<Balance> {
+        let current_timestamp = Self::env().block_timestamp();
+        if self._accrual_block_timestamp() != current_timestamp {
+            return Err(Error::AccrualBlockNumberIsNotFresh)
+        }
+        let treasury = self._treasury().ok_or(Error::TreasuryIsNotSet)?;
+        let total_reserves = self._total_reserves();
+        let threshold = self._reserves_sweep_threshold();
+        if total_reserves <= threshold {
+            return Err(Error::ReservesBelowSweepThreshold)
+        }
+
+        let amount = (total_reserves - threshold).min(self._get_cash_prior());
+        let total_reserves_new = total_reserves - amount;
+        let mut data = self.data::<Data>();
+        data.reserves_scaled -= scaled_amount_of_ceil(
+            amount,
+            Exp {
+                mantissa: data.borrow_index,
+            },
+        )?;
+        self._transfer_underlying(treasury, amount)?;
+
+        self._emit_reserves_swept_event(treasury, amount, total_reserves_new);
+        Ok(amount)
+    }
+
+    default fn _cover_bad_debt(&mut self, amount: Balance) -> Result<()> {
+        let current_timestamp = Self::env().block_timestamp();
+        if self._accrual_block_timestamp() != current_timestamp {
+            return Err(Error::AccrualBlockNumberIsNotFresh)
+        }
+        if self._bad_debt() < amount {
+            return Err(Error::BadDebtExceeded)
+        }
+        if self._total_reserves() < amount {
+            return Err(Error::CoverBadDebtReservesValidation)
+        }
+
+        // reserves stay in the pool as cash, owned by suppliers from now on
+        let total_reserves_new = self._total_reserves() - amount;
+        let bad_debt = self._bad_debt() - amount;
+        let mut data = self.data::<Data>();
+        data.reserves_scaled -= scaled_amount_of_ceil(
+            amount,
+            Exp {
+                mantissa: data.borrow_index,
+            },
+        )?;
+        data.bad_debt = bad_debt;
+
+        self._emit_reserves_reduced_event(amount, total_reserves_new);
+        self._emit_bad_debt_repaid_event(Self::env().account_id(), amount, bad_debt);
+        Ok(())
+    }
+
     default fn _sweep_token(&mut self, asset: AccountId) -> Result<()> {
         let underlying = self._underlying().ok_or(Error::UnderlyingIsNotSet)?;
         if asset == underlying {
@@ -1303,10 +2348,269 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         &mut self,
         new_liquidation_threshold: u128,
     ) -> Result<()> {
+        if !is_valid_liquidation_threshold(new_liquidation_threshold) {
+            return Err(Error::InvalidLiquidationThreshold)
+        }
         self.data::<Data>().liquidation_threshold = new_liquidation_threshold;
         Ok(())
     }
 
+    default fn _set_borrow_rate_max_mantissa(
+        &mut self,
+        new_borrow_rate_max_mantissa: WrappedU256,
+    ) -> Result<()> {
+        if !is_valid_borrow_rate(new_borrow_rate_max_mantissa.into()) {
+            return Err(Error::SetBorrowRateMaxBoundsCheck)
+        }
+        self._accrue_interest()?;
+        self.data::<Data>().borrow_rate_max_mantissa = new_borrow_rate_max_mantissa;
+        Ok(())
+    }
+
+    default fn _set_account_supply_cap(&mut self, new_cap: Balance) -> Result<()> {
+        self.data::<Data>().account_supply_cap = new_cap;
+        Ok(())
+    }
+
+    default fn _set_underlying_call_config(&mut self, config: UnderlyingCallConfig) -> Result<()> {
+        self.data::<Data>().underlying_call_config = config;
+        Ok(())
+    }
+
+    default fn _set_max_accrual_delta(&mut self, new_max_accrual_delta: Timestamp) -> Result<()> {
+        if !is_valid_accrual_delta(new_max_accrual_delta) {
+            return Err(Error::InvalidMaxAccrualDelta)
+        }
+        self._accrue_interest()?;
+        self.data::<Data>().max_accrual_delta = new_max_accrual_delta;
+        Ok(())
+    }
+
+    default fn _set_compound_interest(&mut self, enabled: bool) -> Result<()> {
+        self._accrue_interest()?;
+        self.data::<Data>().compound_interest = enabled;
+        Ok(())
+    }
+
+    default fn _set_flash_mint_premium(&mut self, premium: u128) -> Result<()> {
+        if !is_valid_flash_mint_premium(premium) {
+            return Err(Error::InvalidFlashMintPremium)
+        }
+        self.data::<Data>().flash_mint_premium = premium;
+        Ok(())
+    }
+
+    default fn _set_origination_fee(&mut self, fee: u128) -> Result<()> {
+        if !is_valid_origination_fee(fee) {
+            return Err(Error::InvalidOriginationFee)
+        }
+        self.data::<Data>().origination_fee = fee;
+        Ok(())
+    }
+
+    default fn _set_keeper_incentive(&mut self, incentive: KeeperIncentive) -> Result<()> {
+        self.data::<Data>().keeper_incentive = incentive;
+        Ok(())
+    }
+
+    default fn _pay_keeper_bounty(
+        &mut self,
+        keeper: AccountId,
+        bounty: Balance,
+    ) -> Result<Balance> {
+        let amount = bounty
+            .min(self._total_reserves())
+            .min(self._get_cash_prior());
+        if amount == 0 {
+            return Ok(0)
+        }
+        let mut data = self.data::<Data>();
+        data.reserves_scaled -= scaled_amount_of_ceil(
+            amount,
+            Exp {
+                mantissa: data.borrow_index,
+            },
+        )?;
+        self._transfer_underlying(keeper, amount)?;
+
+        self._emit_keeper_bounty_paid_event(keeper, amount);
+        Ok(amount)
+    }
+
+    default fn _pay_liquidation_bounty(
+        &mut self,
+        liquidator: AccountId,
+        repay_amount: Balance,
+    ) -> Result<()> {
+        let incentive = self._keeper_incentive();
+        if incentive.liquidation_bounty == 0 {
+            return Ok(())
+        }
+        let controller = self._controller().ok_or(Error::ControllerIsNotSet)?;
+        let incentive_mantissa =
+            U256::from(ControllerRef::liquidation_incentive_mantissa(&controller));
+        // the liquidator's gain on the repaid amount, before gas
+        let profit = U256::from(repay_amount)
+            .mul(incentive_mantissa.saturating_sub(exp_scale()))
+            .div(exp_scale());
+        if profit >= U256::from(incentive.min_liquidation_profit) {
+            return Ok(())
+        }
+        self._pay_keeper_bounty(liquidator, incentive.liquidation_bounty)?;
+        Ok(())
+    }
+
+    default fn _set_stable_rate_config(
+        &mut self,
+        enabled: bool,
+        premium_mantissa: WrappedU256,
+        rebalance_threshold_mantissa: WrappedU256,
+    ) -> Result<()> {
+        if !is_valid_borrow_rate(premium_mantissa.into()) {
+            return Err(Error::InvalidParameter)
+        }
+        let data = self.data::<Data>();
+        data.stable_borrow_enabled = enabled;
+        data.stable_rate_premium_mantissa = premium_mantissa;
+        data.stable_rebalance_threshold_mantissa = rebalance_threshold_mantissa;
+        Ok(())
+    }
+
+    default fn _swap_borrow_rate_mode(
+        &mut self,
+        borrower: AccountId,
+        rate_mode: RateMode,
+    ) -> Result<()> {
+        if rate_mode == RateMode::Stable && !self._stable_borrow_enabled() {
+            return Err(Error::StableBorrowNotEnabled)
+        }
+        let current_timestamp = Self::env().block_timestamp();
+        if self._accrual_block_timestamp() != current_timestamp {
+            return Err(Error::AccrualBlockNumberIsNotFresh)
+        };
+
+        let amount = match rate_mode {
+            RateMode::Variable => self._stable_borrow_balance_stored(borrower),
+            RateMode::Stable => self._variable_borrow_balance_stored(borrower)?,
+        };
+        if amount == 0 {
+            return Err(Error::NoDebtOfRateMode)
+        }
+
+        // the position only moves between tracks, so its principal is carried over
+        let snapshot = self._borrow_snapshot(borrower);
+        match rate_mode {
+            RateMode::Variable => {
+                self._increase_stable_debt(borrower, amount, true)?;
+                self._increase_debt(borrower, amount, false)?;
+            }
+            RateMode::Stable => {
+                self._increase_debt(borrower, amount, true)?;
+                self._increase_stable_debt(borrower, amount, false)?;
+            }
+        }
+        self.data::<Data>().account_borrow_snapshots.insert(
+            &borrower,
+            &BorrowSnapshot {
+                principal: snapshot.principal,
+                interest_index: self._borrow_index(),
+            },
+        );
+
+        self._emit_swap_borrow_rate_mode_event(borrower, rate_mode, amount);
+        Ok(())
+    }
+
+    default fn _rebalance_stable_borrow_rate(&mut self, account: AccountId) -> Result<()> {
+        let prev = self._stable_borrow_of(account);
+        if prev.balance == 0 {
+            return Err(Error::RebalanceConditionsNotMet)
+        }
+        let current_rate = U256::from(self._stable_borrow_rate_per_msec());
+        let account_rate = U256::from(prev.rate);
+        let drift = if account_rate > current_rate {
+            account_rate - current_rate
+        } else {
+            current_rate - account_rate
+        };
+        if drift <= U256::from(self._stable_rebalance_threshold_mantissa()) {
+            return Err(Error::RebalanceConditionsNotMet)
+        }
+
+        let balance = self._stable_borrow_balance_stored(account);
+        self._update_stable_totals(balance, prev.rate, true)?;
+        self._update_stable_totals(balance, current_rate.into(), false)?;
+        self.data::<Data>().stable_borrows.insert(
+            &account,
+            &StableBorrow {
+                balance,
+                rate: current_rate.into(),
+                last_update: self._accrual_block_timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    default fn _set_outflow_limit(&mut self, limit: Balance, window: Timestamp) -> Result<()> {
+        if limit != 0 && window == 0 {
+            return Err(Error::InvalidParameter)
+        }
+        let data = self.data::<Data>();
+        data.outflow_limit = limit;
+        data.outflow_window = window;
+        data.outflow_window_start = Self::env().block_timestamp();
+        data.outflow_in_window = 0;
+        Ok(())
+    }
+
+    default fn _set_reserves_sweep_config(
+        &mut self,
+        treasury: Option<AccountId>,
+        threshold: Balance,
+    ) -> Result<()> {
+        let data = self.data::<Data>();
+        data.treasury = treasury;
+        data.reserves_sweep_threshold = threshold;
+        Ok(())
+    }
+
+    default fn _consume_outflow(&mut self, amount: Balance) -> Result<()> {
+        let limit = self._outflow_limit();
+        if limit == 0 {
+            return Ok(())
+        }
+        let now = Self::env().block_timestamp();
+        let outflow = self._outflow_in_window();
+        if outflow.eq(&0) {
+            self.data::<Data>().outflow_window_start = now;
+        }
+        let outflow_new = outflow.saturating_add(amount);
+        if outflow_new > limit {
+            return Err(Error::OutflowLimitExceeded)
+        }
+        self.data::<Data>().outflow_in_window = outflow_new;
+        Ok(())
+    }
+
+    default fn _write_checkpoint(&mut self, at: Timestamp) {
+        let checkpoint = RateCheckpoint {
+            timestamp: at,
+            exchange_rate: self._exchange_rate_stored().into(),
+            borrow_index: self._borrow_index(),
+            utilization_rate: utilization_rate(
+                self._get_cash_prior(),
+                self._total_borrows(),
+                self._total_reserves(),
+            )
+            .into(),
+        };
+        let written = self.data::<Data>().checkpoints_written;
+        self.data::<Data>()
+            .checkpoints
+            .insert(&(written % CHECKPOINTS_CAPACITY), &checkpoint);
+        self.data::<Data>().checkpoints_written = written.wrapping_add(1);
+    }
+
     default fn _approve_delegate(
         &mut self,
         owner: AccountId,
@@ -1396,7 +2700,7 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
                 self._principal_total_supply(),
                 self._total_borrows(),
                 self._principal_balance_of(&user),
-                self._borrow_balance_stored(user),
+                self._borrow_balance_stored(user)?,
             );
 
             if handle_result.is_ok() {
@@ -1409,23 +2713,216 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
 
     // utilities
     default fn _transfer_underlying_from(
-        &self,
+        &mut self,
         from: AccountId,
         to: AccountId,
         value: Balance,
     ) -> Result<()> {
-        let underlying = self._underlying().ok_or(Error::UnderlyingIsNotSet)?;
-        PSP22Ref::transfer_from_builder(&underlying, from, to, value, Vec::<u8>::new())
-            .call_flags(ink::env::CallFlags::default().set_allow_reentry(true))
-            .try_invoke()
-            .unwrap()
-            .unwrap()
-            .map_err(to_psp22_error)
+        let underlying = self._underlying_asset().ok_or(Error::UnderlyingIsNotSet)?;
+        // native token sent along with the call is neither counted as cash nor refunded
+        if underlying != Underlying::Native && Self::env().transferred_value() > 0 {
+            return Err(Error::InvalidParameter)
+        }
+        match underlying {
+            Underlying::PSP22(underlying) => {
+                let config = self._underlying_call_config();
+                PSP22Ref::transfer_from_builder(&underlying, from, to, value, Vec::<u8>::new())
+                    .call_flags(
+                        ink::env::CallFlags::default().set_allow_reentry(config.allow_reentry),
+                    )
+                    .gas_limit(config.gas_limit)
+                    .try_invoke()
+                    .map_err(|_| Error::TransferUnderlyingFailed)?
+                    .map_err(|_| Error::TransferUnderlyingFailed)?
+                    .map_err(to_psp22_error)?;
+            }
+            Underlying::PalletAsset(asset_id) => {
+                pallet_assets::transfer_approved(asset_id, from, to, value)
+                    .map_err(|_| Error::TransferPalletAssetFailed)?;
+            }
+            Underlying::EvmErc20(token) => {
+                xvm::transfer_from(token, from, to, value)
+                    .map_err(|_| Error::TransferEvmErc20Failed)?;
+            }
+            Underlying::Native => {
+                // native token can only be paid in by the caller, along with the call
+                if from != Self::env().caller() || to != Self::env().account_id() {
+                    return Err(Error::InvalidParameter)
+                }
+                let transferred = Self::env().transferred_value();
+                if transferred < value {
+                    return Err(Error::InsufficientTransferredValue)
+                }
+                if transferred > value {
+                    Self::env()
+                        .transfer(from, transferred - value)
+                        .map_err(|_| Error::TransferNativeFailed)?;
+                }
+            }
+        }
+        if to == Self::env().account_id() {
+            self.data::<Data>().cash += value;
+        }
+        Ok(())
     }
 
-    default fn _transfer_underlying(&self, to: AccountId, value: Balance) -> Result<()> {
-        let underlying = self._underlying().ok_or(Error::UnderlyingIsNotSet)?;
-        PSP22Ref::transfer(&underlying, to, value, Vec::<u8>::new()).map_err(to_psp22_error)
+    default fn _transfer_underlying(&mut self, to: AccountId, value: Balance) -> Result<()> {
+        let cash = self
+            ._get_cash_prior()
+            .checked_sub(value)
+            .ok_or(Error::InsufficientCash)?;
+        match self._underlying_asset().ok_or(Error::UnderlyingIsNotSet)? {
+            Underlying::PSP22(underlying) => {
+                let config = self._underlying_call_config();
+                PSP22Ref::transfer_builder(&underlying, to, value, Vec::<u8>::new())
+                    .call_flags(
+                        ink::env::CallFlags::default().set_allow_reentry(config.allow_reentry),
+                    )
+                    .gas_limit(config.gas_limit)
+                    .try_invoke()
+                    .map_err(|_| Error::TransferUnderlyingFailed)?
+                    .map_err(|_| Error::TransferUnderlyingFailed)?
+                    .map_err(to_psp22_error)?;
+            }
+            Underlying::Native => {
+                Self::env()
+                    .transfer(to, value)
+                    .map_err(|_| Error::TransferNativeFailed)?;
+            }
+            Underlying::PalletAsset(asset_id) => {
+                pallet_assets::transfer(asset_id, to, value)
+                    .map_err(|_| Error::TransferPalletAssetFailed)?;
+            }
+            Underlying::EvmErc20(token) => {
+                xvm::transfer(token, to, value).map_err(|_| Error::TransferEvmErc20Failed)?;
+            }
+        }
+        self.data::<Data>().cash = cash;
+        Ok(())
+    }
+
+    default fn _record_flashloan_repayment(
+        &mut self,
+        amount: Balance,
+        premium: Balance,
+    ) -> Result<()> {
+        let current_timestamp = Self::env().block_timestamp();
+        if self._accrual_block_timestamp() != current_timestamp {
+            return Err(Error::AccrualBlockNumberIsNotFresh)
+        }
+
+        // the repayment must have been received on top of the cash already tracked
+        let cash = self
+            ._get_cash_prior()
+            .checked_add(amount)
+            .and_then(|cash| cash.checked_add(premium))
+            .ok_or(Error::MathOverflow)?;
+        let contract_addr = Self::env().account_id();
+        let balance = match self._underlying_asset().ok_or(Error::UnderlyingIsNotSet)? {
+            Underlying::PSP22(underlying) => PSP22Ref::balance_of(&underlying, contract_addr),
+            Underlying::Native => Self::env().balance(),
+            Underlying::PalletAsset(asset_id) => pallet_assets::balance_of(asset_id, contract_addr),
+            // no balance query through XVM, flash loans only lend PSP22 underlyings
+            Underlying::EvmErc20(_) => return Err(Error::InvalidParameter),
+        };
+        if balance < cash {
+            return Err(Error::FlashloanRepaymentNotReceived)
+        }
+        self.data::<Data>().cash = cash;
+        if premium == 0 {
+            return Ok(())
+        }
+        let total_reserves_new = self._total_reserves().add(premium);
+        self.data::<Data>().reserves_scaled += scaled_amount_of_truncate(
+            premium,
+            Exp {
+                mantissa: self._borrow_index(),
+            },
+        )?;
+        self._emit_reserves_added_event(Self::env().caller(), premium, total_reserves_new);
+        Ok(())
+    }
+
+    default fn _flash_mint(
+        &mut self,
+        initiator: AccountId,
+        receiver: AccountId,
+        amount: Balance,
+        params: Vec<u8>,
+    ) -> Result<()> {
+        let current_timestamp = Self::env().block_timestamp();
+        if self._accrual_block_timestamp() != current_timestamp {
+            return Err(Error::AccrualBlockNumberIsNotFresh)
+        }
+
+        let contract_addr = Self::env().account_id();
+        // flash mints are mints: they stop while minting is paused or the protocol is shut down
+        let controller = self._controller().ok_or(Error::ControllerIsNotSet)?;
+        ControllerRef::mint_allowed(&controller, contract_addr, receiver, amount)?;
+
+        let premium = try_as_u128(
+            U256::from(amount)
+                .mul(U256::from(self._flash_mint_premium()))
+                .div(U256::from(FLASH_MINT_PREMIUM_MAX)),
+        )?;
+        // NOTE: fix the exchange rate so that the same amount of underlying is minted and burned
+        let exchange_rate = Exp {
+            mantissa: WrappedU256::from(self._exchange_rate_stored()),
+        };
+
+        // NOTE: the minted tokens are not backed by underlying, so they are left out of the
+        // exchange rate until burned: a receiver reentering `mint` must not get extra tokens
+        let minted = scaled_amount_of(amount, exchange_rate.clone())?;
+        self._mint_to(receiver, minted)?;
+        self.data::<Data>().flash_minted += minted;
+
+        let succeeded = FlashloanReceiverRef::execute_operation_builder(
+            &receiver,
+            vec![contract_addr],
+            vec![amount],
+            vec![premium],
+            initiator,
+            params,
+        )
+        .call_flags(ink_env::CallFlags::default().set_allow_reentry(true))
+        .try_invoke()
+        .map_err(|_| Error::FlashMintFailed)?
+        .map_err(|_| Error::FlashMintFailed)?;
+        if !succeeded {
+            return Err(Error::FlashMintFailed)
+        }
+        self.data::<Data>().flash_minted -= minted;
+
+        let burn_amount = amount.add(premium);
+        if self
+            ._using_reserve_as_collateral(receiver)
+            .unwrap_or_default()
+        {
+            // burning the premium must not leave the receiver's borrows undercollateralized
+            let (_, account_borrow_balance, exchange_rate_mantissa) =
+                self.get_account_snapshot(receiver);
+            let pool_attribute = PoolAttributes {
+                pool: Some(contract_addr),
+                underlying: self._underlying(),
+                decimals: self.token_decimals(),
+                liquidation_threshold: self._liquidation_threshold(),
+                account_balance: Internal::_balance_of(self, &receiver),
+                account_borrow_balance,
+                exchange_rate: exchange_rate_mantissa,
+                total_borrows: self._total_borrows(),
+            };
+            ControllerRef::redeem_allowed(
+                &controller,
+                contract_addr,
+                receiver,
+                burn_amount,
+                Some(pool_attribute),
+            )?;
+        }
+        self._burn_from(receiver, scaled_amount_of(burn_amount, exchange_rate)?)?;
+
+        self._emit_flash_mint_event(receiver, initiator, amount, premium);
+        Ok(())
     }
 
     default fn _assert_manager(&self) -> Result<()> {
@@ -1445,11 +2942,47 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         Ok(())
     }
 
+    default fn _set_facet(&mut self, facet: Facet, code_hash: Option<Hash>) -> Result<()> {
+        let facets = &mut self.data::<Data>().facets;
+        match code_hash {
+            Some(code_hash) => {
+                facets.insert(&facet, &code_hash);
+            }
+            None => facets.remove(&facet),
+        }
+        Ok(())
+    }
+
+    default fn _delegate_to_facet<Args: Encode, R: Decode>(
+        &mut self,
+        facet: Facet,
+        input: ExecutionInput<Args>,
+    ) -> Result<R> {
+        let code_hash = self._facet(facet).ok_or(Error::FacetIsNotSet)?;
+        // a tail call, so the stale storage of this message is never written over the facet's
+        let result = build_call::<DefaultEnvironment>()
+            .call_type(DelegateCall::new(code_hash))
+            .call_flags(CallFlags::default().set_tail_call(true))
+            .exec_input(input)
+            .returns::<Result<R>>()
+            .try_invoke();
+        match result {
+            Ok(Ok(result)) => result,
+            _ => Err(Error::FacetCallFailed),
+        }
+    }
+
     // view functions
     default fn _underlying(&self) -> Option<AccountId> {
         self.data::<Data>().underlying
     }
 
+    default fn _underlying_asset(&self) -> Option<Underlying> {
+        let data = self.data::<Data>();
+        data.underlying
+            .map(|asset_id| Underlying::from_asset_id(data.underlying_kind, asset_id))
+    }
+
     default fn _controller(&self) -> Option<AccountId> {
         self.data::<Data>().controller
     }
@@ -1458,19 +2991,39 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         self.data::<Data>().manager
     }
 
+    default fn _pending_manager(&self) -> Option<AccountId> {
+        self.data::<Data>().pending_manager
+    }
+
     default fn _incentives_controller(&self) -> Option<AccountId> {
         self.data::<Data>().incentives_controller
     }
 
+    default fn _facet(&self, facet: Facet) -> Option<Hash> {
+        self.data::<Data>().facets.get(&facet)
+    }
+
     default fn _get_cash_prior(&self) -> Balance {
-        if let Some(underlying) = self._underlying() {
-            let contract_addr = Self::env().account_id();
-            return PSP22Ref::balance_of(&underlying, contract_addr)
-        }
-        0
+        self.data::<Data>().cash
     }
 
     default fn _total_borrows(&self) -> Balance {
+        self._total_variable_borrows() + self._total_stable_borrows()
+    }
+
+    default fn _total_stable_borrows(&self) -> Balance {
+        let data = self.data::<Data>();
+        // totals overflowing u128 are reported as the largest ones, like debts
+        stable_balance_at(
+            data.total_stable_borrows,
+            data.average_stable_rate.into(),
+            data.stable_last_update,
+            data.accrual_block_timestamp,
+        )
+        .unwrap_or(Balance::MAX)
+    }
+
+    default fn _total_variable_borrows(&self) -> Balance {
         let borrows = self.data::<Data>().borrows_scaled;
         if borrows == 0 {
             return 0
@@ -1481,6 +3034,7 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
                 mantissa: self._borrow_index(),
             },
         )
+        .unwrap_or(Balance::MAX)
     }
 
     default fn _borrows_scaled(&self) -> Balance {
@@ -1504,6 +3058,19 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         WrappedU256::from(U256::zero())
     }
 
+    default fn _utilization_rate(
+        &self,
+        cash: Balance,
+        borrows: Balance,
+        reserves: Balance,
+    ) -> WrappedU256 {
+        if let Some(rate_model) = self._rate_model() {
+            return InterestRateModelRef::utilization_rate(&rate_model, cash, borrows, reserves)
+        }
+
+        utilization_rate(cash, borrows, reserves).into()
+    }
+
     default fn _supply_rate_per_msec(
         &self,
         cash: Balance,
@@ -1529,31 +3096,119 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
     }
 
     default fn _total_reserves(&self) -> Balance {
-        from_scaled_amount(
+        from_scaled_amount_truncate(
             self.data::<Data>().reserves_scaled.into(),
             Exp {
                 mantissa: self._borrow_index(),
             },
         )
+        .unwrap_or(Balance::MAX)
+    }
+
+    default fn _borrowers_count(&self) -> u32 {
+        self.data::<Data>().borrowers_count
+    }
+
+    default fn _borrowers_paginated(&self, offset: u32, limit: u32) -> Vec<AccountId> {
+        let count = self._borrowers_count();
+        let end = offset.saturating_add(limit).min(count);
+        (offset.min(end)..end)
+            .filter_map(|i| self.data::<Data>().borrowers.get(&i))
+            .collect()
+    }
+
+    default fn _checkpoints_count(&self) -> u32 {
+        self.data::<Data>()
+            .checkpoints_written
+            .min(CHECKPOINTS_CAPACITY)
+    }
+
+    default fn _checkpoints(&self, offset: u32, limit: u32) -> Vec<RateCheckpoint> {
+        let count = self._checkpoints_count();
+        let oldest = self.data::<Data>().checkpoints_written.wrapping_sub(count);
+        let end = offset.saturating_add(limit).min(count);
+        (offset.min(end)..end)
+            .filter_map(|i| {
+                self.data::<Data>()
+                    .checkpoints
+                    .get(&(oldest.wrapping_add(i) % CHECKPOINTS_CAPACITY))
+            })
+            .collect()
     }
+
     default fn _reserves_scaled(&self) -> Balance {
         self.data::<Data>().reserves_scaled
     }
+
+    default fn _bad_debt(&self) -> Balance {
+        self.data::<Data>().bad_debt
+    }
     default fn _borrow_index(&self) -> WrappedU256 {
         self.data::<Data>().borrow_index
     }
 
-    default fn _borrow_balance_stored(&self, account: AccountId) -> Balance {
+    default fn _borrow_balance_stored(&self, account: AccountId) -> Result<Balance> {
+        self._variable_borrow_balance_stored(account)?
+            .checked_add(self._stable_borrow_balance_stored(account))
+            .ok_or(Error::MathOverflow)
+    }
+
+    default fn _stable_borrow_balance_stored(&self, account: AccountId) -> Balance {
+        let stable = self._stable_borrow_of(account);
+        stable_balance_at(
+            stable.balance,
+            stable.rate.into(),
+            stable.last_update,
+            self._accrual_block_timestamp(),
+        )
+        .unwrap_or(Balance::MAX)
+    }
+
+    default fn _stable_borrow_of(&self, account: AccountId) -> StableBorrow {
+        self.data::<Data>()
+            .stable_borrows
+            .get(&account)
+            .unwrap_or_default()
+    }
+
+    default fn _stable_borrow_rate_per_msec(&self) -> WrappedU256 {
+        let variable_rate = self._borrow_rate_per_msec(
+            self._get_cash_prior(),
+            self._total_borrows(),
+            self._total_reserves(),
+        );
+        U256::from(variable_rate)
+            .add(U256::from(self._stable_rate_premium_mantissa()))
+            .into()
+    }
+
+    default fn _average_stable_rate(&self) -> WrappedU256 {
+        self.data::<Data>().average_stable_rate
+    }
+
+    default fn _stable_borrow_enabled(&self) -> bool {
+        self.data::<Data>().stable_borrow_enabled
+    }
+
+    default fn _stable_rate_premium_mantissa(&self) -> WrappedU256 {
+        self.data::<Data>().stable_rate_premium_mantissa
+    }
+
+    default fn _stable_rebalance_threshold_mantissa(&self) -> WrappedU256 {
+        self.data::<Data>().stable_rebalance_threshold_mantissa
+    }
+
+    default fn _variable_borrow_balance_stored(&self, account: AccountId) -> Result<Balance> {
         let snapshot = match self.data::<Data>().account_borrows.get(&account) {
             Some(value) => {
                 match value {
-                    0 => return 0,
+                    0 => return Ok(0),
                     _ => value,
                 }
             }
-            None => return 0,
+            None => return Ok(0),
         };
-        from_scaled_amount(
+        try_from_scaled_amount_ceil(
             snapshot,
             Exp {
                 mantissa: self._borrow_index(),
@@ -1561,12 +3216,19 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         )
     }
 
+    default fn _borrow_snapshot(&self, account: AccountId) -> BorrowSnapshot {
+        self.data::<Data>()
+            .account_borrow_snapshots
+            .get(&account)
+            .unwrap_or_default()
+    }
+
     default fn _balance_of(&self, owner: &AccountId) -> Balance {
         self._balance_of_underlying(*owner)
     }
 
     default fn _total_supply(&self) -> Balance {
-        let supply = self.data::<PSP22Data>().supply;
+        let supply = self._exchange_rate_supply();
         let interest = self
             ._get_interest_at(Self::env().block_timestamp())
             .unwrap();
@@ -1583,6 +3245,7 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
                 mantissa: rate.into(),
             },
         )
+        .unwrap_or(Balance::MAX)
     }
 
     default fn _balance_of_underlying(&self, account: AccountId) -> Balance {
@@ -1590,7 +3253,7 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
             mantissa: self._exchange_rate_stored().into(),
         };
         let pool_token_balance = self._principal_balance_of(&account);
-        from_scaled_amount(pool_token_balance, exchange_rate)
+        from_scaled_amount_truncate(pool_token_balance, exchange_rate).unwrap_or(Balance::MAX)
     }
 
     default fn _principal_balance_of(&self, account: &AccountId) -> Balance {
@@ -1611,7 +3274,7 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
 
     default fn _exchange_rate_stored(&self) -> U256 {
         exchange_rate(
-            self.data::<PSP22Data>().supply,
+            self._exchange_rate_supply(),
             self._get_cash_prior(),
             self._total_borrows(),
             self._total_reserves(),
@@ -1623,6 +3286,79 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         self.data::<Data>().liquidation_threshold
     }
 
+    default fn _borrow_rate_max_mantissa(&self) -> WrappedU256 {
+        let max = self.data::<Data>().borrow_rate_max_mantissa;
+        if U256::from(max).is_zero() {
+            return WrappedU256::from(borrow_rate_max_mantissa())
+        }
+        max
+    }
+
+    default fn _account_supply_cap(&self) -> Balance {
+        self.data::<Data>().account_supply_cap
+    }
+
+    default fn _max_accrual_delta(&self) -> Timestamp {
+        self.data::<Data>().max_accrual_delta
+    }
+
+    default fn _compound_interest(&self) -> bool {
+        self.data::<Data>().compound_interest
+    }
+
+    default fn _flash_mint_premium(&self) -> u128 {
+        self.data::<Data>().flash_mint_premium
+    }
+
+    default fn _flash_minted(&self) -> Balance {
+        self.data::<Data>().flash_minted
+    }
+
+    default fn _exchange_rate_supply(&self) -> Balance {
+        self.data::<PSP22Data>()
+            .supply
+            .saturating_sub(self._flash_minted())
+    }
+
+    default fn _origination_fee(&self) -> u128 {
+        self.data::<Data>().origination_fee
+    }
+
+    default fn _keeper_incentive(&self) -> KeeperIncentive {
+        self.data::<Data>().keeper_incentive
+    }
+
+    default fn _underlying_call_config(&self) -> UnderlyingCallConfig {
+        self.data::<Data>().underlying_call_config
+    }
+
+    default fn _outflow_limit(&self) -> Balance {
+        self.data::<Data>().outflow_limit
+    }
+
+    default fn _outflow_window(&self) -> Timestamp {
+        self.data::<Data>().outflow_window
+    }
+
+    default fn _treasury(&self) -> Option<AccountId> {
+        self.data::<Data>().treasury
+    }
+
+    default fn _reserves_sweep_threshold(&self) -> Balance {
+        self.data::<Data>().reserves_sweep_threshold
+    }
+
+    default fn _outflow_in_window(&self) -> Balance {
+        let data = self.data::<Data>();
+        let elapsed = Self::env()
+            .block_timestamp()
+            .saturating_sub(data.outflow_window_start);
+        if elapsed >= data.outflow_window {
+            return 0
+        }
+        data.outflow_in_window
+    }
+
     default fn _delegate_allowance(&self, owner: &AccountId, delegatee: &AccountId) -> Balance {
         self.data::<Data>()
             .delegate_allowance
@@ -1670,6 +3406,23 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
     ) {
     }
 
+    default fn _emit_swap_borrow_rate_mode_event(
+        &self,
+        _borrower: AccountId,
+        _rate_mode: RateMode,
+        _amount: Balance,
+    ) {
+    }
+    default fn _emit_flash_mint_event(
+        &self,
+        _receiver: AccountId,
+        _initiator: AccountId,
+        _amount: Balance,
+        _premium: Balance,
+    ) {
+    }
+    default fn _emit_keeper_bounty_paid_event(&self, _keeper: AccountId, _amount: Balance) {}
+    default fn _emit_origination_fee_charged_event(&self, _borrower: AccountId, _fee: Balance) {}
     default fn _emit_reserves_added_event(
         &self,
         _benefactor: AccountId,
@@ -1692,6 +3445,27 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
         _total_reserves_new: Balance,
     ) {
     }
+    default fn _emit_reserves_swept_event(
+        &self,
+        _treasury: AccountId,
+        _amount: Balance,
+        _total_reserves_new: Balance,
+    ) {
+    }
+    default fn _emit_bad_debt_absorbed_event(
+        &self,
+        _borrower: AccountId,
+        _amount: Balance,
+        _bad_debt: Balance,
+    ) {
+    }
+    default fn _emit_bad_debt_repaid_event(
+        &self,
+        _payer: AccountId,
+        _amount: Balance,
+        _bad_debt: Balance,
+    ) {
+    }
 
     default fn _emit_new_controller_event(&self, _old: Option<AccountId>, _new: Option<AccountId>) {
     }
@@ -1702,6 +3476,14 @@ impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metada
     ) {
     }
     default fn _emit_new_reserve_factor_event(&self, _old: WrappedU256, _new: WrappedU256) {}
+    default fn _emit_admin_action_event(
+        &self,
+        _action: ActionId,
+        _target: Option<AccountId>,
+        _old_value: Vec<u8>,
+        _new_value: Vec<u8>,
+    ) {
+    }
     default fn _emit_delegate_approval_event(
         &self,
         _owner: AccountId,
@@ -0,0 +1,91 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Calls into Astar's pallet-assets chain extension, used by pools whose
+//! underlying is an XC-20 asset rather than a PSP22 contract.
+
+use ink::env::chain_extension::ChainExtensionMethod;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+
+/// Extension id of pallet-assets chain extension
+const EXTENSION_ID: u32 = 2;
+
+/// Function ids of pallet-assets chain extension
+const FUNC_TRANSFER: u32 = 2;
+const FUNC_BALANCE_OF: u32 = 6;
+const FUNC_APPROVE_TRANSFER: u32 = 9;
+const FUNC_TRANSFER_APPROVED: u32 = 10;
+
+const fn func_id(func: u32) -> u32 {
+    (EXTENSION_ID << 16) + func
+}
+
+/// Error code returned by pallet-assets chain extension
+pub type AssetsErrorCode = u32;
+
+/// Transfer `amount` of `asset_id` from this contract to `to`
+pub fn transfer(
+    asset_id: u128,
+    to: AccountId,
+    amount: Balance,
+) -> core::result::Result<(), AssetsErrorCode> {
+    ChainExtensionMethod::build(func_id(FUNC_TRANSFER))
+        .input::<(u128, AccountId, Balance)>()
+        .output::<core::result::Result<(), AssetsErrorCode>, true>()
+        .ignore_error_code()
+        .call(&(asset_id, to, amount))
+}
+
+/// Transfer `amount` of `asset_id` from `owner` to `to`, using the allowance given to this contract
+pub fn transfer_approved(
+    asset_id: u128,
+    owner: AccountId,
+    to: AccountId,
+    amount: Balance,
+) -> core::result::Result<(), AssetsErrorCode> {
+    ChainExtensionMethod::build(func_id(FUNC_TRANSFER_APPROVED))
+        .input::<(u128, AccountId, AccountId, Balance)>()
+        .output::<core::result::Result<(), AssetsErrorCode>, true>()
+        .ignore_error_code()
+        .call(&(asset_id, owner, to, amount))
+}
+
+/// Approve `delegate` to transfer `amount` of `asset_id` from this contract
+pub fn approve_transfer(
+    asset_id: u128,
+    delegate: AccountId,
+    amount: Balance,
+) -> core::result::Result<(), AssetsErrorCode> {
+    ChainExtensionMethod::build(func_id(FUNC_APPROVE_TRANSFER))
+        .input::<(u128, AccountId, Balance)>()
+        .output::<core::result::Result<(), AssetsErrorCode>, true>()
+        .ignore_error_code()
+        .call(&(asset_id, delegate, amount))
+}
+
+/// Balance of `who` in `asset_id`
+pub fn balance_of(asset_id: u128, who: AccountId) -> Balance {
+    ChainExtensionMethod::build(func_id(FUNC_BALANCE_OF))
+        .input::<(u128, AccountId)>()
+        .output::<Balance, false>()
+        .ignore_error_code()
+        .call(&(asset_id, who))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_func_id() {
+        assert_eq!(func_id(FUNC_TRANSFER), 0x0002_0002);
+        assert_eq!(func_id(FUNC_TRANSFER_APPROVED), 0x0002_000a);
+    }
+}
@@ -6,10 +6,19 @@
 // except according to those terms.
 
 use super::super::exp_no_err::{
+    div_ceil,
     exp_scale,
     Exp,
 };
-pub use crate::traits::pool::*;
+pub use crate::{
+    impls::params::{
+        accrual_delta_max,
+        borrow_rate_absolute_max_mantissa,
+        reserve_factor_max_mantissa,
+        FLASH_MINT_PREMIUM_MAX,
+    },
+    traits::pool::*,
+};
 use crate::{
     impls::wad_ray_math::{
         exp_ray_ratio,
@@ -34,15 +43,13 @@ pub fn borrow_rate_max_mantissa() -> U256 {
     exp_scale().mul(U256::from(5)).div(U256::from(1000 * 100))
 }
 
-pub fn reserve_factor_max_mantissa() -> U256 {
-    // 100% / time
-    exp_scale()
-}
-
 pub fn protocol_seize_share_mantissa() -> U256 {
     exp_scale().mul(U256::from(28)).div(U256::from(10 * 100)) // 2.8%
 }
 
+/// Number of rate checkpoints retained by the ring buffer
+pub const CHECKPOINTS_CAPACITY: u32 = 256;
+
 pub struct CalculateInterestInput {
     pub total_borrows: Balance,
     pub total_reserves: Balance,
@@ -51,6 +58,11 @@ pub struct CalculateInterestInput {
     pub old_block_timestamp: Timestamp,
     pub new_block_timestamp: Timestamp,
     pub reserve_factor_mantissa: U256,
+    pub borrow_rate_max_mantissa: U256,
+    /// Longest period interest accrues for in a single accrual, 0 if unlimited
+    pub max_accrual_delta: Timestamp,
+    /// Compounds the borrow rate over the period instead of accruing `rate * delta`
+    pub compound: bool,
 }
 
 pub struct CalculateInterestOutput {
@@ -60,20 +72,66 @@ pub struct CalculateInterestOutput {
     pub interest_accumulated: Balance,
 }
 
-pub fn scaled_amount_of(amount: Balance, idx: Exp) -> Balance {
+/// `value` as u128, `MathOverflow` if it does not fit instead of truncating it
+pub fn try_as_u128(value: U256) -> Result<Balance> {
+    Balance::try_from(value).map_err(|_| Error::MathOverflow)
+}
+
+pub fn scaled_amount_of(amount: Balance, idx: Exp) -> Result<Balance> {
     let divided = Ray {
         mantissa: WrappedU256::from(U256::from(amount)),
     }
     .ray_div(idx.to_ray())
-    .unwrap();
-    U256::from(divided.mantissa).as_u128()
+    .map_err(|_| Error::MathOverflow)?;
+    try_as_u128(U256::from(divided.mantissa))
 }
 
-pub fn from_scaled_amount(scaled_amount: Balance, idx: Exp) -> Balance {
-    let multiplied = idx.to_ray().ray_mul(Ray {
-        mantissa: WrappedU256::from(U256::from(scaled_amount)),
-    });
-    U256::from(multiplied.unwrap().mantissa).as_u128()
+pub fn from_scaled_amount(scaled_amount: Balance, idx: Exp) -> Result<Balance> {
+    let multiplied = idx
+        .to_ray()
+        .ray_mul(Ray {
+            mantissa: WrappedU256::from(U256::from(scaled_amount)),
+        })
+        .map_err(|_| Error::MathOverflow)?;
+    try_as_u128(U256::from(multiplied.mantissa))
+}
+
+// Rounding policy: conversions of an account's position round against the account,
+// so that rounding never moves value out of the pool.
+// - supplies: tokens minted and underlying balances round down, tokens burned round up
+// - borrows: debt added and debt balances round up, debt removed rounds down
+// - reserves: credits round down, debits round up
+
+pub fn scaled_amount_of_truncate(amount: Balance, idx: Exp) -> Result<Balance> {
+    try_as_u128(
+        U256::from(amount)
+            .mul(exp_scale())
+            .div(U256::from(idx.mantissa)),
+    )
+}
+
+pub fn scaled_amount_of_ceil(amount: Balance, idx: Exp) -> Result<Balance> {
+    try_as_u128(div_ceil(
+        U256::from(amount).mul(exp_scale()),
+        U256::from(idx.mantissa),
+    ))
+}
+
+pub fn from_scaled_amount_truncate(scaled_amount: Balance, idx: Exp) -> Result<Balance> {
+    try_as_u128(idx.mul_scalar_truncate(U256::from(scaled_amount)))
+}
+
+/// `from_scaled_amount` rounded up, failing with `MathOverflow` instead of truncating
+pub fn try_from_scaled_amount_ceil(scaled_amount: Balance, idx: Exp) -> Result<Balance> {
+    try_as_u128(idx.mul_scalar_ceil(U256::from(scaled_amount)))
+}
+
+fn simple_interest(borrow_rate_per_millisec: &Exp, delta: U256) -> Exp {
+    Exp {
+        mantissa: U256::from(borrow_rate_per_millisec.mantissa)
+            .mul(delta)
+            .into(),
+    }
 }
 
 fn compound_interest(borrow_rate_per_millisec: &Exp, delta: U256) -> Exp {
@@ -115,23 +173,28 @@ fn compound_interest(borrow_rate_per_millisec: &Exp, delta: U256) -> Exp {
 }
 
 pub fn calculate_interest(input: &CalculateInterestInput) -> Result<CalculateInterestOutput> {
-    if input.borrow_rate.gt(&borrow_rate_max_mantissa()) {
+    if input.borrow_rate.gt(&input.borrow_rate_max_mantissa) {
         return Err(Error::BorrowRateIsAbsurdlyHigh)
     }
-    let delta = input
+    let mut delta = input
         .new_block_timestamp
         .abs_diff(input.old_block_timestamp);
-    let compound_interest_factor = compound_interest(
-        &Exp {
-            mantissa: input.borrow_rate.into(),
-        },
-        U256::from(delta),
-    );
+    if input.max_accrual_delta != 0 && delta > input.max_accrual_delta {
+        delta = input.max_accrual_delta;
+    }
+    let borrow_rate = Exp {
+        mantissa: input.borrow_rate.into(),
+    };
+    let compound_interest_factor = if input.compound {
+        compound_interest(&borrow_rate, U256::from(delta))
+    } else {
+        simple_interest(&borrow_rate, U256::from(delta))
+    };
 
     let interest_accumulated =
         compound_interest_factor.mul_scalar_truncate(U256::from(input.total_borrows));
 
-    let total_borrows_new = interest_accumulated.as_u128().add(input.total_borrows);
+    let total_borrows_new = try_as_u128(interest_accumulated.add(U256::from(input.total_borrows)))?;
     let total_reserves_new = Exp {
         mantissa: WrappedU256::from(input.reserve_factor_mantissa),
     }
@@ -141,9 +204,9 @@ pub fn calculate_interest(input: &CalculateInterestInput) -> Result<CalculateInt
     Ok(CalculateInterestOutput {
         borrow_index: borrow_index_new,
 
-        interest_accumulated: interest_accumulated.as_u128(),
+        interest_accumulated: try_as_u128(interest_accumulated)?,
         total_borrows: total_borrows_new,
-        total_reserves: total_reserves_new.as_u128(),
+        total_reserves: try_as_u128(total_reserves_new)?,
     })
 }
 
@@ -152,19 +215,17 @@ pub fn protocol_seize_amount(
     exchange_rate: Exp,
     seize_tokens: Balance,
     protocol_seize_share_mantissa: U256,
-) -> (Balance, Balance, Balance) {
+) -> Result<(Balance, Balance, Balance)> {
     let protocol_seize_tokens = Exp {
         mantissa: WrappedU256::from(U256::from(seize_tokens).mul(protocol_seize_share_mantissa)),
     }
     .truncate();
     let liquidator_seize_tokens = U256::from(seize_tokens).sub(protocol_seize_tokens);
-    (
-        liquidator_seize_tokens.as_u128(),
-        exchange_rate
-            .mul_scalar_truncate(protocol_seize_tokens)
-            .as_u128(),
-        protocol_seize_tokens.as_u128(),
-    )
+    Ok((
+        try_as_u128(liquidator_seize_tokens)?,
+        try_as_u128(exchange_rate.mul_scalar_truncate(protocol_seize_tokens))?,
+        try_as_u128(protocol_seize_tokens)?,
+    ))
 }
 
 pub fn exchange_rate(
@@ -177,12 +238,91 @@ pub fn exchange_rate(
     if total_supply == 0 {
         return default_exchange_rate_mantissa
     };
-    let cash_plus_borrows_minus_reserves = total_cash.add(total_borrows).sub(total_reserves);
-    U256::from(cash_plus_borrows_minus_reserves)
+    // reserves exceeding cash and borrows, e.g. after bad debt, leave nothing to suppliers
+    let cash_plus_borrows_minus_reserves = U256::from(total_cash)
+        .add(U256::from(total_borrows))
+        .saturating_sub(U256::from(total_reserves));
+    cash_plus_borrows_minus_reserves
         .mul(exp_scale())
         .div(U256::from(total_supply))
 }
 
+/// One-time fee in basis points charged on borrowing `amount`
+pub fn origination_fee_of(amount: Balance, fee: u128) -> Result<Balance> {
+    try_as_u128(
+        U256::from(amount)
+            .mul(U256::from(fee))
+            .div(U256::from(10000)),
+    )
+}
+
+/// Share of `interest` credited to reserves at `reserve_factor_mantissa`
+pub fn reserves_of_interest(interest: Balance, reserve_factor_mantissa: U256) -> Result<Balance> {
+    try_as_u128(
+        Exp {
+            mantissa: reserve_factor_mantissa.into(),
+        }
+        .mul_scalar_truncate(U256::from(interest)),
+    )
+}
+
+/// Balance of a stable rate debt after accruing simple interest at `rate` from `from` to `to`
+pub fn stable_balance_at(
+    balance: Balance,
+    rate: U256,
+    from: Timestamp,
+    to: Timestamp,
+) -> Result<Balance> {
+    if balance == 0 || to <= from {
+        return Ok(balance)
+    }
+    let interest = Exp {
+        mantissa: rate.mul(U256::from(to - from)).into(),
+    }
+    .mul_scalar_truncate(U256::from(balance));
+    try_as_u128(interest.add(U256::from(balance)))
+}
+
+/// Average rate of `balance` at `rate` after adding (or removing if `neg`) `amount` at `amount_rate`
+pub fn weighted_average_rate(
+    balance: Balance,
+    rate: U256,
+    amount: Balance,
+    amount_rate: U256,
+    neg: bool,
+) -> U256 {
+    let weighted = U256::from(balance).mul(rate);
+    let amount_weighted = U256::from(amount).mul(amount_rate);
+    if neg {
+        let balance_new = balance.saturating_sub(amount);
+        if balance_new == 0 {
+            return U256::zero()
+        }
+        return weighted
+            .saturating_sub(amount_weighted)
+            .div(U256::from(balance_new))
+    }
+    let balance_new = balance.add(amount);
+    if balance_new == 0 {
+        return U256::zero()
+    }
+    weighted.add(amount_weighted).div(U256::from(balance_new))
+}
+
+pub fn utilization_rate(
+    total_cash: Balance,
+    total_borrows: Balance,
+    total_reserves: Balance,
+) -> U256 {
+    if total_borrows == 0 {
+        return U256::zero()
+    };
+    let cash_plus_borrows_minus_reserves = total_cash.add(total_borrows).sub(total_reserves);
+    U256::from(total_borrows)
+        .mul(exp_scale())
+        .div(U256::from(cash_plus_borrows_minus_reserves))
+}
+
 #[cfg(test)]
 
 mod tests {
@@ -232,7 +372,7 @@ mod tests {
             },
         ];
         for c in cases {
-            assert_eq!(scaled_amount_of(c.amount, c.idx), c.want)
+            assert_eq!(scaled_amount_of(c.amount, c.idx), Ok(c.want))
         }
     }
     #[test]
@@ -245,6 +385,9 @@ mod tests {
             reserve_factor_mantissa: U256::zero(),
             total_borrows: Balance::default(),
             total_reserves: Balance::default(),
+            borrow_rate_max_mantissa: borrow_rate_max_mantissa(),
+            max_accrual_delta: 0,
+            compound: true,
         };
         let out = calculate_interest(&input);
         assert_eq!(out.err().unwrap(), Error::BorrowRateIsAbsurdlyHigh)
@@ -313,6 +456,9 @@ mod tests {
                 reserve_factor_mantissa: mantissa().div(100), // 1 %
                 total_borrows: 10_000 * (10_u128.pow(18)),
                 total_reserves: 10_000 * (10_u128.pow(18)),
+                borrow_rate_max_mantissa: borrow_rate_max_mantissa(),
+                max_accrual_delta: 0,
+                compound: true,
             },
             CalculateInterestInput {
                 old_block_timestamp: old_timestamp,
@@ -322,6 +468,9 @@ mod tests {
                 reserve_factor_mantissa: mantissa().div(10),
                 total_borrows: 100_000 * (10_u128.pow(18)),
                 total_reserves: 1_000_000 * (10_u128.pow(18)),
+                borrow_rate_max_mantissa: borrow_rate_max_mantissa(),
+                max_accrual_delta: 0,
+                compound: true,
             },
             CalculateInterestInput {
                 old_block_timestamp: old_timestamp,
@@ -331,6 +480,9 @@ mod tests {
                 reserve_factor_mantissa: mantissa().div(10).mul(2),
                 total_borrows: 123_456 * (10_u128.pow(18)),
                 total_reserves: 789_012 * (10_u128.pow(18)),
+                borrow_rate_max_mantissa: borrow_rate_max_mantissa(),
+                max_accrual_delta: 0,
+                compound: true,
             },
         ];
 
@@ -381,7 +533,8 @@ mod tests {
         let liquidator_seize_tokens_want = seize_tokens.mul(9).div(10);
         let protocol_seize_amount_want = protocol_seize_tokens.mul(1).div(100); // 1%
         let (liquidator_seize_tokens_got, protocol_seize_amount_got, protocol_seize_tokens_got) =
-            protocol_seize_amount(exchange_rate, seize_tokens, protocol_seize_share_mantissa);
+            protocol_seize_amount(exchange_rate, seize_tokens, protocol_seize_share_mantissa)
+                .unwrap();
         assert_eq!(liquidator_seize_tokens_got, liquidator_seize_tokens_want);
         assert_eq!(protocol_seize_amount_got, protocol_seize_amount_want);
         assert_eq!(protocol_seize_tokens_got, protocol_seize_tokens);
@@ -445,4 +598,232 @@ mod tests {
             )
         }
     }
+
+    #[test]
+    fn test_exchange_rate_with_reserves_above_cash_and_borrows() {
+        let initial = U256::one().mul(exp_scale());
+        assert_eq!(exchange_rate(100, 10, 20, 31, initial), U256::zero());
+        assert_eq!(exchange_rate(100, 10, 20, 30, initial), U256::zero());
+        assert_eq!(
+            exchange_rate(1, Balance::MAX, Balance::MAX, 0, initial),
+            U256::from(Balance::MAX).mul(2).mul(exp_scale())
+        );
+    }
+
+    #[test]
+    fn test_utilization_rate() {
+        assert_eq!(utilization_rate(100, 0, 0), U256::zero());
+        assert_eq!(utilization_rate(0, 100, 0), mantissa());
+        assert_eq!(utilization_rate(150, 50, 0), mantissa().div(4));
+        assert_eq!(utilization_rate(160, 50, 10), mantissa().div(4));
+    }
+
+    #[test]
+    fn test_origination_fee_of() {
+        assert_eq!(origination_fee_of(1_000_000, 0), Ok(0));
+        assert_eq!(origination_fee_of(1_000_000, 10), Ok(1_000)); // 0.1%
+        assert_eq!(origination_fee_of(999, 10), Ok(0)); // rounded down
+        assert_eq!(origination_fee_of(u128::MAX, 10), Ok(u128::MAX / 1000));
+    }
+
+    #[test]
+    fn test_reserves_of_interest() {
+        assert_eq!(reserves_of_interest(1_000_000, U256::zero()), Ok(0));
+        assert_eq!(
+            reserves_of_interest(1_000_000, mantissa().div(10)),
+            Ok(100_000)
+        ); // 10%
+        assert_eq!(reserves_of_interest(999, mantissa().div(100)), Ok(9)); // rounded down
+        assert_eq!(reserves_of_interest(u128::MAX, mantissa()), Ok(u128::MAX));
+    }
+
+    #[test]
+    fn test_stable_balance_at() {
+        let rate = mantissa().div(1000); // 0.1% / ms
+        assert_eq!(stable_balance_at(1000, rate, 10, 10), Ok(1000));
+        assert_eq!(stable_balance_at(1000, rate, 10, 5), Ok(1000));
+        assert_eq!(stable_balance_at(0, rate, 0, 100), Ok(0));
+        assert_eq!(stable_balance_at(1000, rate, 0, 100), Ok(1100));
+        assert_eq!(
+            stable_balance_at(u128::MAX, rate, 0, 100),
+            Err(Error::MathOverflow)
+        );
+    }
+
+    #[test]
+    fn test_weighted_average_rate() {
+        let r1 = mantissa().div(100);
+        let r3 = mantissa().mul(3).div(100);
+        assert_eq!(weighted_average_rate(0, U256::zero(), 100, r1, false), r1);
+        assert_eq!(
+            weighted_average_rate(100, r1, 100, r3, false),
+            mantissa().mul(2).div(100)
+        );
+        assert_eq!(
+            weighted_average_rate(200, mantissa().mul(2).div(100), 100, r3, true),
+            r1
+        );
+        assert_eq!(weighted_average_rate(100, r1, 100, r1, true), U256::zero());
+    }
+
+    #[test]
+    fn test_calculate_interest_caps_delta() {
+        let old_timestamp = Timestamp::default();
+        let input = |max_accrual_delta, new_block_timestamp| {
+            CalculateInterestInput {
+                old_block_timestamp: old_timestamp,
+                new_block_timestamp,
+                borrow_index: mantissa(),
+                borrow_rate: mantissa().div(1_000_000_000),
+                reserve_factor_mantissa: mantissa().div(10),
+                total_borrows: 10_000 * (10_u128.pow(18)),
+                total_reserves: 0,
+                borrow_rate_max_mantissa: borrow_rate_max_mantissa(),
+                max_accrual_delta,
+                compound: true,
+            }
+        };
+        let capped =
+            calculate_interest(&input(accrual_delta_max(), accrual_delta_max() * 12)).unwrap();
+        let at_cap = calculate_interest(&input(0, accrual_delta_max())).unwrap();
+        let uncapped = calculate_interest(&input(0, accrual_delta_max() * 12)).unwrap();
+        assert_eq!(capped.interest_accumulated, at_cap.interest_accumulated);
+        assert_eq!(capped.borrow_index, at_cap.borrow_index);
+        assert!(uncapped.interest_accumulated > capped.interest_accumulated);
+    }
+
+    #[test]
+    fn test_calculate_interest_simple() {
+        let old_timestamp = Timestamp::default();
+        let input = |compound| {
+            CalculateInterestInput {
+                old_block_timestamp: old_timestamp,
+                new_block_timestamp: old_timestamp + 1000 * 60 * 60 * 24 * 365, // 1 year
+                borrow_index: mantissa(),
+                borrow_rate: mantissa().div(1000 * 60 * 60 * 24 * 365), // 100 % per year
+                reserve_factor_mantissa: U256::zero(),
+                total_borrows: 10_000 * (10_u128.pow(18)),
+                total_reserves: 0,
+                borrow_rate_max_mantissa: borrow_rate_max_mantissa(),
+                max_accrual_delta: 0,
+                compound,
+            }
+        };
+        let simple = calculate_interest(&input(false)).unwrap();
+        let compound = calculate_interest(&input(true)).unwrap();
+        // rate * delta, truncated by the rate per milliseconds
+        let want = U256::from(input(false).borrow_rate)
+            .mul(U256::from(1000_u128 * 60 * 60 * 24 * 365))
+            .mul(U256::from(10_000_u128))
+            .as_u128();
+        assert_eq!(simple.interest_accumulated, want);
+        assert!(compound.interest_accumulated > simple.interest_accumulated);
+        assert!(compound.borrow_index > simple.borrow_index);
+    }
+
+    #[test]
+    fn test_try_as_u128() {
+        assert_eq!(try_as_u128(U256::zero()), Ok(0));
+        assert_eq!(try_as_u128(U256::from(u128::MAX)), Ok(u128::MAX));
+        assert_eq!(
+            try_as_u128(U256::from(u128::MAX).add(1)),
+            Err(Error::MathOverflow)
+        );
+    }
+
+    #[test]
+    fn test_try_from_scaled_amount_ceil() {
+        let idx = Exp {
+            mantissa: WrappedU256::from(mantissa()),
+        };
+        assert_eq!(
+            try_from_scaled_amount_ceil(u128::MAX, idx.clone()),
+            Ok(u128::MAX)
+        );
+        let double = Exp {
+            mantissa: WrappedU256::from(mantissa().mul(2)),
+        };
+        assert_eq!(
+            try_from_scaled_amount_ceil(u128::MAX / 2, double.clone()),
+            Ok(u128::MAX - 1)
+        );
+        assert_eq!(
+            try_from_scaled_amount_ceil(u128::MAX, double),
+            Err(Error::MathOverflow)
+        );
+    }
+
+    #[test]
+    fn test_scaled_amount_rounding() {
+        let idx = Exp {
+            mantissa: WrappedU256::from(mantissa().mul(3)),
+        };
+        assert_eq!(scaled_amount_of_truncate(10, idx.clone()), Ok(3));
+        assert_eq!(scaled_amount_of_ceil(10, idx.clone()), Ok(4));
+        assert_eq!(scaled_amount_of_truncate(9, idx.clone()), Ok(3));
+        assert_eq!(scaled_amount_of_ceil(9, idx), Ok(3));
+        let third = Exp::from_ratio(U256::one(), U256::from(3));
+        assert_eq!(from_scaled_amount_truncate(10, third.clone()), Ok(3));
+        assert_eq!(try_from_scaled_amount_ceil(10, third), Ok(4));
+        // an underlying balance rounded down never burns more tokens than held
+        let rate = Exp::from_ratio(U256::from(7), U256::from(3));
+        let tokens = 1_000_003;
+        let balance = from_scaled_amount_truncate(tokens, rate.clone()).unwrap();
+        assert!(scaled_amount_of_ceil(balance, rate).unwrap() <= tokens);
+    }
+
+    #[test]
+    fn test_scaled_amount_overflow() {
+        let half = Exp {
+            mantissa: WrappedU256::from(mantissa().div(2)),
+        };
+        let double = Exp {
+            mantissa: WrappedU256::from(mantissa().mul(2)),
+        };
+        assert_eq!(
+            scaled_amount_of(u128::MAX, half.clone()),
+            Err(Error::MathOverflow)
+        );
+        assert_eq!(
+            scaled_amount_of_truncate(u128::MAX, half.clone()),
+            Err(Error::MathOverflow)
+        );
+        assert_eq!(
+            scaled_amount_of_ceil(u128::MAX, half),
+            Err(Error::MathOverflow)
+        );
+        assert_eq!(
+            from_scaled_amount(u128::MAX, double.clone()),
+            Err(Error::MathOverflow)
+        );
+        assert_eq!(
+            from_scaled_amount_truncate(u128::MAX, double),
+            Err(Error::MathOverflow)
+        );
+    }
+
+    #[test]
+    fn test_calculate_interest_overflow() {
+        let old_timestamp = Timestamp::default();
+        let input = |total_borrows| {
+            CalculateInterestInput {
+                old_block_timestamp: old_timestamp,
+                new_block_timestamp: old_timestamp + 1000,
+                borrow_index: mantissa(),
+                borrow_rate: borrow_rate_max_mantissa(),
+                reserve_factor_mantissa: U256::zero(),
+                total_borrows,
+                total_reserves: 0,
+                borrow_rate_max_mantissa: borrow_rate_max_mantissa(),
+                max_accrual_delta: 0,
+                compound: false,
+            }
+        };
+        assert!(calculate_interest(&input(u128::MAX / 2)).is_ok());
+        // borrows with their interest no longer fit u128
+        assert_eq!(
+            calculate_interest(&input(u128::MAX)).err(),
+            Some(Error::MathOverflow)
+        );
+    }
 }
@@ -0,0 +1,161 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Calls into Astar's XVM chain extension, used by pools whose underlying is
+//! an ERC-20 token deployed on the EVM side.
+//!
+//! Accounts are passed to the EVM as the H160 address Astar derives from an
+//! AccountId32, i.e. its first 20 bytes. Users approve that address of the
+//! pool on the token before depositing.
+
+use crate::traits::pool::EvmAddress;
+use ink::{
+    env::chain_extension::ChainExtensionMethod,
+    prelude::vec::Vec,
+};
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+
+/// Extension id of XVM chain extension
+const EXTENSION_ID: u32 = 1;
+
+/// Function ids of XVM chain extension
+const FUNC_XVM_CALL: u32 = 1;
+
+/// Virtual machine id of the EVM
+const VM_ID_EVM: u8 = 0x0F;
+
+/// Selectors of ERC-20 functions
+const SELECTOR_TRANSFER: [u8; 4] = [0xa9, 0x05, 0x9c, 0xbb];
+const SELECTOR_TRANSFER_FROM: [u8; 4] = [0x23, 0xb8, 0x72, 0xdd];
+const SELECTOR_APPROVE: [u8; 4] = [0x09, 0x5e, 0xa7, 0xb3];
+
+const fn func_id(func: u32) -> u32 {
+    (EXTENSION_ID << 16) + func
+}
+
+/// Error code returned by XVM chain extension
+pub type XvmErrorCode = u32;
+
+/// H160 address of `account` on the EVM side
+pub fn to_evm_address(account: &AccountId) -> EvmAddress {
+    let mut address = [0_u8; 20];
+    address.copy_from_slice(&AsRef::<[u8; 32]>::as_ref(account)[..20]);
+    address
+}
+
+/// Transfer `amount` of `token` from this contract to `to`
+pub fn transfer(
+    token: EvmAddress,
+    to: AccountId,
+    amount: Balance,
+) -> core::result::Result<(), XvmErrorCode> {
+    let mut input = SELECTOR_TRANSFER.to_vec();
+    encode_address(&mut input, &to_evm_address(&to));
+    encode_uint(&mut input, amount);
+    erc20_call(token, input)
+}
+
+/// Transfer `amount` of `token` from `owner` to `to`, using the allowance given to this contract
+pub fn transfer_from(
+    token: EvmAddress,
+    owner: AccountId,
+    to: AccountId,
+    amount: Balance,
+) -> core::result::Result<(), XvmErrorCode> {
+    transfer_from_address(token, to_evm_address(&owner), to_evm_address(&to), amount)
+}
+
+/// Same as `transfer_from`, between addresses on the EVM side
+pub fn transfer_from_address(
+    token: EvmAddress,
+    owner: EvmAddress,
+    to: EvmAddress,
+    amount: Balance,
+) -> core::result::Result<(), XvmErrorCode> {
+    let mut input = SELECTOR_TRANSFER_FROM.to_vec();
+    encode_address(&mut input, &owner);
+    encode_address(&mut input, &to);
+    encode_uint(&mut input, amount);
+    erc20_call(token, input)
+}
+
+/// Approve `spender` to transfer `amount` of `token` from this contract
+pub fn approve(
+    token: EvmAddress,
+    spender: EvmAddress,
+    amount: Balance,
+) -> core::result::Result<(), XvmErrorCode> {
+    let mut input = SELECTOR_APPROVE.to_vec();
+    encode_address(&mut input, &spender);
+    encode_uint(&mut input, amount);
+    erc20_call(token, input)
+}
+
+/// Call `target` on the EVM side with ABI-encoded `input`, returning its output
+pub fn call(target: EvmAddress, input: Vec<u8>) -> core::result::Result<Vec<u8>, XvmErrorCode> {
+    ChainExtensionMethod::build(func_id(FUNC_XVM_CALL))
+        .input::<(u8, Vec<u8>, Vec<u8>, u128)>()
+        .output::<core::result::Result<Vec<u8>, XvmErrorCode>, true>()
+        .ignore_error_code()
+        .call(&(VM_ID_EVM, target.to_vec(), input, 0))
+}
+
+/// Call `token` with ABI-encoded `input` and check the returned bool, if any
+fn erc20_call(token: EvmAddress, input: Vec<u8>) -> core::result::Result<(), XvmErrorCode> {
+    let output = call(token, input)?;
+    // tokens that do not return a value revert on failure
+    if output.is_empty() || decodes_true(&output) {
+        Ok(())
+    } else {
+        Err(XvmErrorCode::MAX)
+    }
+}
+
+/// Append `address` to `buf` as an ABI-encoded `address`
+pub fn encode_address(buf: &mut Vec<u8>, address: &EvmAddress) {
+    buf.extend_from_slice(&[0_u8; 12]);
+    buf.extend_from_slice(address);
+}
+
+/// Append `value` to `buf` as an ABI-encoded `uint256`
+pub fn encode_uint(buf: &mut Vec<u8>, value: u128) {
+    buf.extend_from_slice(&[0_u8; 16]);
+    buf.extend_from_slice(&value.to_be_bytes());
+}
+
+fn decodes_true(output: &[u8]) -> bool {
+    output.len() == 32 && output[..31].iter().all(|b| *b == 0) && output[31] == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_func_id() {
+        assert_eq!(func_id(FUNC_XVM_CALL), 0x0001_0001);
+    }
+
+    #[test]
+    fn test_abi_encoding() {
+        let mut buf = SELECTOR_TRANSFER.to_vec();
+        encode_address(&mut buf, &[0x11; 20]);
+        encode_uint(&mut buf, 0x0102);
+        assert_eq!(buf.len(), 4 + 32 + 32);
+        assert_eq!(buf[4..16], [0_u8; 12]);
+        assert_eq!(buf[16..36], [0x11_u8; 20]);
+        assert_eq!(buf[66..], [0x01, 0x02]);
+
+        let mut output = [0_u8; 32];
+        assert!(!decodes_true(&output));
+        output[31] = 1;
+        assert!(decodes_true(&output));
+    }
+}
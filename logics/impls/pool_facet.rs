@@ -0,0 +1,933 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::pool::{
+    Data,
+    Internal,
+    KeeperIncentive,
+    PoolRef,
+    Result,
+    UnderlyingCallConfig,
+};
+pub use crate::traits::pool_facet::*;
+use crate::traits::types::WrappedU256;
+use ink::{
+    prelude::vec::Vec,
+    selector_bytes,
+};
+use openbrush::{
+    contracts::psp22,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+        Timestamp,
+    },
+};
+use scale::Encode;
+
+impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metadata::Data>>
+    PoolAdminFacet for T
+{
+    default fn set_reserve_factor_mantissa(
+        &mut self,
+        new_reserve_factor_mantissa: WrappedU256,
+    ) -> Result<()> {
+        self._assert_manager()?;
+        let old = self._reserve_factor_mantissa();
+        self._set_reserve_factor_mantissa(new_reserve_factor_mantissa)?;
+        self._emit_new_reserve_factor_event(old, new_reserve_factor_mantissa);
+        self._emit_admin_action_event(
+            selector_bytes!("Pool::set_reserve_factor_mantissa"),
+            None,
+            old.encode(),
+            new_reserve_factor_mantissa.encode(),
+        );
+        Ok(())
+    }
+
+    default fn set_liquidation_threshold(&mut self, new_liquidation_threshold: u128) -> Result<()> {
+        self._assert_manager()?;
+        let old = self._liquidation_threshold();
+        self._set_liquidation_threshold(new_liquidation_threshold)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Pool::set_liquidation_threshold"),
+            None,
+            old.encode(),
+            new_liquidation_threshold.encode(),
+        );
+        Ok(())
+    }
+
+    default fn set_borrow_rate_max_mantissa(
+        &mut self,
+        new_borrow_rate_max_mantissa: WrappedU256,
+    ) -> Result<()> {
+        self._assert_manager()?;
+        let old = self._borrow_rate_max_mantissa();
+        self._set_borrow_rate_max_mantissa(new_borrow_rate_max_mantissa)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Pool::set_borrow_rate_max_mantissa"),
+            None,
+            old.encode(),
+            new_borrow_rate_max_mantissa.encode(),
+        );
+        Ok(())
+    }
+
+    default fn set_stable_rate_config(
+        &mut self,
+        enabled: bool,
+        premium_mantissa: WrappedU256,
+        rebalance_threshold_mantissa: WrappedU256,
+    ) -> Result<()> {
+        self._assert_manager()?;
+        let old = (
+            self._stable_borrow_enabled(),
+            self._stable_rate_premium_mantissa(),
+            self._stable_rebalance_threshold_mantissa(),
+        );
+        self._set_stable_rate_config(enabled, premium_mantissa, rebalance_threshold_mantissa)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Pool::set_stable_rate_config"),
+            None,
+            old.encode(),
+            (enabled, premium_mantissa, rebalance_threshold_mantissa).encode(),
+        );
+        Ok(())
+    }
+
+    default fn set_underlying_call_config(&mut self, config: UnderlyingCallConfig) -> Result<()> {
+        self._assert_manager()?;
+        let old = self._underlying_call_config();
+        self._set_underlying_call_config(config)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Pool::set_underlying_call_config"),
+            None,
+            old.encode(),
+            config.encode(),
+        );
+        Ok(())
+    }
+
+    default fn set_max_accrual_delta(&mut self, new_max_accrual_delta: Timestamp) -> Result<()> {
+        self._assert_manager()?;
+        let old = self._max_accrual_delta();
+        self._set_max_accrual_delta(new_max_accrual_delta)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Pool::set_max_accrual_delta"),
+            None,
+            old.encode(),
+            new_max_accrual_delta.encode(),
+        );
+        Ok(())
+    }
+
+    default fn set_compound_interest(&mut self, enabled: bool) -> Result<()> {
+        self._assert_manager()?;
+        let old = self._compound_interest();
+        self._set_compound_interest(enabled)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Pool::set_compound_interest"),
+            None,
+            old.encode(),
+            enabled.encode(),
+        );
+        Ok(())
+    }
+
+    default fn set_flash_mint_premium(&mut self, premium: u128) -> Result<()> {
+        self._assert_manager()?;
+        let old = self._flash_mint_premium();
+        self._set_flash_mint_premium(premium)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Pool::set_flash_mint_premium"),
+            None,
+            old.encode(),
+            premium.encode(),
+        );
+        Ok(())
+    }
+
+    default fn set_origination_fee(&mut self, fee: u128) -> Result<()> {
+        self._assert_manager()?;
+        let old = self._origination_fee();
+        self._set_origination_fee(fee)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Pool::set_origination_fee"),
+            None,
+            old.encode(),
+            fee.encode(),
+        );
+        Ok(())
+    }
+
+    default fn set_keeper_incentive(&mut self, incentive: KeeperIncentive) -> Result<()> {
+        self._assert_manager()?;
+        let old = self._keeper_incentive();
+        self._set_keeper_incentive(incentive)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Pool::set_keeper_incentive"),
+            None,
+            old.encode(),
+            incentive.encode(),
+        );
+        Ok(())
+    }
+
+    default fn set_account_supply_cap(&mut self, new_cap: Balance) -> Result<()> {
+        self._assert_manager()?;
+        let old = self._account_supply_cap();
+        self._set_account_supply_cap(new_cap)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Pool::set_account_supply_cap"),
+            None,
+            old.encode(),
+            new_cap.encode(),
+        );
+        Ok(())
+    }
+
+    default fn set_outflow_limit(&mut self, limit: Balance, window: Timestamp) -> Result<()> {
+        self._assert_manager()?;
+        let old = (self._outflow_limit(), self._outflow_window());
+        self._set_outflow_limit(limit, window)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Pool::set_outflow_limit"),
+            None,
+            old.encode(),
+            (limit, window).encode(),
+        );
+        Ok(())
+    }
+
+    default fn set_reserves_sweep_config(
+        &mut self,
+        treasury: Option<AccountId>,
+        threshold: Balance,
+    ) -> Result<()> {
+        self._assert_manager()?;
+        let old = (self._treasury(), self._reserves_sweep_threshold());
+        self._set_reserves_sweep_config(treasury, threshold)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Pool::set_reserves_sweep_config"),
+            None,
+            old.encode(),
+            (treasury, threshold).encode(),
+        );
+        Ok(())
+    }
+
+    default fn reduce_reserves(&mut self, amount: Balance) -> Result<()> {
+        self._assert_manager()?;
+        self._accrue_interest()?;
+        self._reduce_reserves(Self::env().caller(), amount)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Pool::reduce_reserves"),
+            None,
+            Vec::new(),
+            amount.encode(),
+        );
+        Ok(())
+    }
+
+    default fn cover_bad_debt(&mut self, amount: Balance) -> Result<()> {
+        self._assert_manager()?;
+        self._accrue_interest()?;
+        self._cover_bad_debt(amount)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Pool::cover_bad_debt"),
+            None,
+            Vec::new(),
+            amount.encode(),
+        );
+        Ok(())
+    }
+
+    default fn sweep_token(&mut self, asset: AccountId) -> Result<()> {
+        self._assert_manager()?;
+        self._sweep_token(asset)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Pool::sweep_token"),
+            Some(asset),
+            Vec::new(),
+            Vec::new(),
+        );
+        Ok(())
+    }
+
+    default fn sweep_reserves_to_treasury(&mut self) -> Result<Balance> {
+        self._accrue_interest()?;
+        self._sweep_reserves_to_treasury()
+    }
+
+    default fn set_incentives_controller(
+        &mut self,
+        incentives_controller: AccountId,
+    ) -> Result<()> {
+        self._assert_manager()?;
+        let old = self._incentives_controller();
+        self._set_incentives_controller(incentives_controller)?;
+        self._emit_admin_action_event(
+            selector_bytes!("Pool::set_incentives_controller"),
+            None,
+            old.encode(),
+            Some(incentives_controller).encode(),
+        );
+        Ok(())
+    }
+}
+
+impl<T: Storage<Data> + Storage<psp22::Data> + Storage<psp22::extensions::metadata::Data>>
+    PoolLiquidationFacet for T
+{
+    default fn liquidate_borrow(
+        &mut self,
+        borrower: AccountId,
+        repay_amount: Balance,
+        collateral: AccountId,
+    ) -> Result<()> {
+        self._accrue_interest()?;
+        if collateral != Self::env().account_id() {
+            PoolRef::accrue_interest(&collateral)?;
+        }
+        self._liquidate_borrow(Self::env().caller(), borrower, repay_amount, collateral)
+    }
+
+    default fn liquidate_borrow_max(
+        &mut self,
+        borrower: AccountId,
+        collateral: AccountId,
+    ) -> Result<()> {
+        self._accrue_interest()?;
+        if collateral != Self::env().account_id() {
+            PoolRef::accrue_interest(&collateral)?;
+        }
+        let repay_amount = self._liquidate_max_repay_amount(borrower, collateral)?;
+        self._liquidate_borrow(Self::env().caller(), borrower, repay_amount, collateral)
+    }
+
+    default fn seize(
+        &mut self,
+        liquidator: AccountId,
+        borrower: AccountId,
+        seize_tokens: Balance,
+    ) -> Result<()> {
+        self._accrue_interest()?;
+        self._seize(Self::env().caller(), liquidator, borrower, seize_tokens)
+    }
+
+    default fn absorb(&mut self, borrower: AccountId) -> Result<Balance> {
+        self._accrue_interest()?;
+        self._absorb(borrower)
+    }
+}
+
+/// Declares a facet contract of Pool, running the code of `$facet` on the storage of the Pool.
+///
+/// Only the code of a facet is used, run by Pools through a delegate call, so it shares the
+/// storage and events of the Pool: both are declared here as in the Pool contract, in the same
+/// order and under the same name, and have to be kept identical to the Pool's.
+#[macro_export]
+macro_rules! pool_facet_contract {
+    ($(#[$attr:meta])* $facet:ident) => {
+        $(#[$attr])*
+        #[openbrush::contract]
+        pub mod contract {
+            use ink::{
+                codegen::{
+                    EmitEvent,
+                    Env,
+                },
+                prelude::vec::Vec,
+            };
+            use $crate::{
+                impls::{
+                    pool::{
+                        Internal,
+                        *,
+                    },
+                    pool_facet::$facet,
+                    upgradeable,
+                    votes,
+                },
+                traits::types::{
+                    ActionId,
+                    WrappedU256,
+                },
+            };
+            use openbrush::{
+                contracts::psp22::{
+                    extensions::metadata,
+                    psp22,
+                    PSP22Error,
+                },
+                traits::{
+                    Storage,
+                    String,
+                },
+            };
+
+            /// Contract's Storage
+            #[ink(storage)]
+            #[derive(Default, Storage)]
+            pub struct PoolContract {
+                #[storage_field]
+                pool: Data,
+                #[storage_field]
+                psp22: psp22::Data,
+                #[storage_field]
+                metadata: metadata::Data,
+                #[storage_field]
+                votes: votes::Data,
+                #[storage_field]
+                upgradeable: upgradeable::Data,
+            }
+
+            /// Event: Execute `Mint`
+            #[ink(event)]
+            pub struct Mint {
+                #[ink(topic)]
+                pub minter: AccountId,
+                pub mint_amount: Balance,
+                pub mint_tokens: Balance,
+            }
+            /// Event: Execute `Redeem`
+            #[ink(event)]
+            pub struct Redeem {
+                #[ink(topic)]
+                pub redeemer: AccountId,
+                pub redeem_amount: Balance,
+            }
+            /// Event: Execute `Borrow`
+            #[ink(event)]
+            pub struct Borrow {
+                #[ink(topic)]
+                pub borrower: AccountId,
+                pub borrow_amount: Balance,
+                pub account_borrows: Balance,
+                pub total_borrows: Balance,
+            }
+            /// Event: Execute `RepayBorrow`
+            #[ink(event)]
+            pub struct RepayBorrow {
+                #[ink(topic)]
+                pub payer: AccountId,
+                #[ink(topic)]
+                pub borrower: AccountId,
+                pub repay_amount: Balance,
+                pub account_borrows: Balance,
+                pub total_borrows: Balance,
+            }
+            /// Event: Execute `LiquidateBorrow`
+            #[ink(event)]
+            pub struct LiquidateBorrow {
+                #[ink(topic)]
+                pub liquidator: AccountId,
+                #[ink(topic)]
+                pub borrower: AccountId,
+                pub repay_amount: Balance,
+                #[ink(topic)]
+                pub token_collateral: AccountId,
+                pub seize_tokens: Balance,
+            }
+            /// Event: Adding to Reserves
+            #[ink(event)]
+            pub struct ReservesAdded {
+                #[ink(topic)]
+                pub benefactor: AccountId,
+                pub add_amount: Balance,
+                pub new_total_reserves: Balance,
+            }
+            /// Event: Reducing Reserves
+            #[ink(event)]
+            pub struct ReservesReduced {
+                pub reduce_amount: Balance,
+                pub total_reserves_new: Balance,
+            }
+            /// Event: Reserves above the sweep threshold are transferred to the treasury
+            #[ink(event)]
+            pub struct ReservesSwept {
+                #[ink(topic)]
+                pub treasury: AccountId,
+                pub amount: Balance,
+                pub total_reserves_new: Balance,
+            }
+            /// Event: Debt of a borrower is written off
+            #[ink(event)]
+            pub struct BadDebtAbsorbed {
+                #[ink(topic)]
+                pub borrower: AccountId,
+                pub amount: Balance,
+                pub bad_debt: Balance,
+            }
+            /// Event: Bad debt is repaid
+            #[ink(event)]
+            pub struct BadDebtRepaid {
+                #[ink(topic)]
+                pub payer: AccountId,
+                pub amount: Balance,
+                pub bad_debt: Balance,
+            }
+            /// Event: Interest is accrued
+            #[ink(event)]
+            pub struct AccrueInterest {
+                pub interest_accumulated: Balance,
+                pub new_index: WrappedU256,
+                pub new_total_borrows: Balance,
+            }
+            /// Event: Controller is changed
+            #[ink(event)]
+            pub struct NewController {
+                pub old: Option<AccountId>,
+                pub new: Option<AccountId>,
+            }
+            /// Event: Interest Rate Model is changed
+            #[ink(event)]
+            pub struct NewInterestRateModel {
+                pub old: Option<AccountId>,
+                pub new: Option<AccountId>,
+            }
+            /// Event: Reserve Factor is changed
+            #[ink(event)]
+            pub struct NewReserveFactor {
+                pub old: WrappedU256,
+                pub new: WrappedU256,
+            }
+
+            /// Event: Transfer Pool Token
+            ///
+            /// NOTE: Use event emitter included in PSP22 Interface
+            /// [PSP22 | Brushfam](https://learn.brushfam.io/docs/OpenBrush/smart-contracts/PSP22/)
+            #[ink(event)]
+            pub struct Transfer {
+                #[ink(topic)]
+                pub from: Option<AccountId>,
+                #[ink(topic)]
+                pub to: Option<AccountId>,
+                pub value: Balance,
+            }
+            /// Event: Allowance of a spender for an owner is set
+            ///
+            /// NOTE: Use event emitter included in PSP22 Interface
+            /// [PSP22 | Brushfam](https://learn.brushfam.io/docs/OpenBrush/smart-contracts/PSP22/)
+            #[ink(event)]
+            pub struct Approval {
+                #[ink(topic)]
+                pub owner: AccountId,
+                #[ink(topic)]
+                pub spender: AccountId,
+                pub value: Balance,
+            }
+
+            /// Event: Delegation Allowance for Borrowing is changed
+            #[ink(event)]
+            pub struct DelegateApproval {
+                #[ink(topic)]
+                pub owner: AccountId,
+                #[ink(topic)]
+                pub delegatee: AccountId,
+                pub amount: Balance,
+            }
+
+            /// Event: Debt of a borrower is converted into another rate mode
+            #[ink(event)]
+            pub struct SwapBorrowRateMode {
+                #[ink(topic)]
+                pub borrower: AccountId,
+                pub rate_mode: RateMode,
+                pub amount: Balance,
+            }
+
+            /// Event: Pool tokens are flash minted to a receiver and burned with a premium
+            #[ink(event)]
+            pub struct FlashMint {
+                #[ink(topic)]
+                pub receiver: AccountId,
+                #[ink(topic)]
+                pub initiator: AccountId,
+                pub amount: Balance,
+                pub premium: Balance,
+            }
+
+            /// Event: Bounty is paid from reserves to a keeper of this pool
+            #[ink(event)]
+            pub struct KeeperBountyPaid {
+                #[ink(topic)]
+                pub keeper: AccountId,
+                pub amount: Balance,
+            }
+
+            /// Event: Origination fee is charged on a borrow and credited to reserves
+            #[ink(event)]
+            pub struct OriginationFeeCharged {
+                #[ink(topic)]
+                pub borrower: AccountId,
+                pub fee: Balance,
+            }
+
+            /// Event: Voting weight of an account is delegated to another delegatee
+            #[ink(event)]
+            pub struct DelegateChanged {
+                #[ink(topic)]
+                pub delegator: AccountId,
+                pub from_delegate: Option<AccountId>,
+                #[ink(topic)]
+                pub to_delegate: AccountId,
+            }
+
+            /// Event: Voting weight of a delegatee is changed
+            #[ink(event)]
+            pub struct DelegateVotesChanged {
+                #[ink(topic)]
+                pub delegate: AccountId,
+                pub previous_balance: Balance,
+                pub new_balance: Balance,
+            }
+
+            #[ink(event)]
+            pub struct ReserveUsedAsCollateralEnabled {
+                #[ink(topic)]
+                pub user: AccountId,
+            }
+
+            #[ink(event)]
+            pub struct ReserveUsedAsCollateralDisabled {
+                #[ink(topic)]
+                pub user: AccountId,
+            }
+
+            /// Event: Privileged action is executed.
+            ///
+            /// `action` is the selector of the message, values are SCALE encoded.
+            #[ink(event)]
+            pub struct AdminAction {
+                #[ink(topic)]
+                pub action: ActionId,
+                #[ink(topic)]
+                pub caller: AccountId,
+                #[ink(topic)]
+                pub target: Option<AccountId>,
+                pub old_value: Vec<u8>,
+                pub new_value: Vec<u8>,
+            }
+
+            /// Event: Code of this contract is replaced
+            #[ink(event)]
+            pub struct CodeUpgraded {
+                pub code_hash: Hash,
+                pub version: u32,
+            }
+
+            /// Event: Storage is migrated to the layout of the running code
+            #[ink(event)]
+            pub struct Migrated {
+                pub from: u32,
+                pub to: u32,
+            }
+
+            impl $facet for PoolContract {}
+
+            impl Internal for PoolContract {
+                fn _emit_admin_action_event(
+                    &self,
+                    action: ActionId,
+                    target: Option<AccountId>,
+                    old_value: Vec<u8>,
+                    new_value: Vec<u8>,
+                ) {
+                    self.env().emit_event(AdminAction {
+                        action,
+                        caller: self.env().caller(),
+                        target,
+                        old_value,
+                        new_value,
+                    })
+                }
+
+                fn _emit_mint_event(&self, minter: AccountId, mint_amount: Balance, mint_tokens: Balance) {
+                    self.env().emit_event(Mint {
+                        minter,
+                        mint_amount,
+                        mint_tokens,
+                    })
+                }
+                fn _emit_redeem_event(&self, redeemer: AccountId, redeem_amount: Balance) {
+                    self.env().emit_event(Redeem {
+                        redeemer,
+                        redeem_amount,
+                    })
+                }
+                fn _emit_borrow_event(
+                    &self,
+                    borrower: AccountId,
+                    borrow_amount: Balance,
+                    account_borrows: Balance,
+                    total_borrows: Balance,
+                ) {
+                    self.env().emit_event(Borrow {
+                        borrower,
+                        borrow_amount,
+                        account_borrows,
+                        total_borrows,
+                    })
+                }
+                fn _emit_repay_borrow_event(
+                    &self,
+                    payer: AccountId,
+                    borrower: AccountId,
+                    repay_amount: Balance,
+                    account_borrows: Balance,
+                    total_borrows: Balance,
+                ) {
+                    self.env().emit_event(RepayBorrow {
+                        payer,
+                        borrower,
+                        repay_amount,
+                        account_borrows,
+                        total_borrows,
+                    })
+                }
+                fn _emit_liquidate_borrow_event(
+                    &self,
+                    liquidator: AccountId,
+                    borrower: AccountId,
+                    repay_amount: Balance,
+                    token_collateral: AccountId,
+                    seize_tokens: Balance,
+                ) {
+                    self.env().emit_event(LiquidateBorrow {
+                        liquidator,
+                        borrower,
+                        repay_amount,
+                        token_collateral,
+                        seize_tokens,
+                    })
+                }
+                fn _emit_swap_borrow_rate_mode_event(
+                    &self,
+                    borrower: AccountId,
+                    rate_mode: RateMode,
+                    amount: Balance,
+                ) {
+                    self.env().emit_event(SwapBorrowRateMode {
+                        borrower,
+                        rate_mode,
+                        amount,
+                    })
+                }
+                fn _emit_flash_mint_event(
+                    &self,
+                    receiver: AccountId,
+                    initiator: AccountId,
+                    amount: Balance,
+                    premium: Balance,
+                ) {
+                    self.env().emit_event(FlashMint {
+                        receiver,
+                        initiator,
+                        amount,
+                        premium,
+                    })
+                }
+                fn _emit_keeper_bounty_paid_event(&self, keeper: AccountId, amount: Balance) {
+                    self.env().emit_event(KeeperBountyPaid { keeper, amount })
+                }
+                fn _emit_origination_fee_charged_event(&self, borrower: AccountId, fee: Balance) {
+                    self.env()
+                        .emit_event(OriginationFeeCharged { borrower, fee })
+                }
+                fn _emit_reserves_added_event(
+                    &self,
+                    benefactor: AccountId,
+                    add_amount: Balance,
+                    new_total_reserves: Balance,
+                ) {
+                    self.env().emit_event(ReservesAdded {
+                        benefactor,
+                        add_amount,
+                        new_total_reserves,
+                    })
+                }
+                fn _emit_reserves_reduced_event(
+                    &self,
+                    reduce_amount: Balance,
+                    total_reserves_new: Balance,
+                ) {
+                    self.env().emit_event(ReservesReduced {
+                        reduce_amount,
+                        total_reserves_new,
+                    })
+                }
+                fn _emit_reserves_swept_event(
+                    &self,
+                    treasury: AccountId,
+                    amount: Balance,
+                    total_reserves_new: Balance,
+                ) {
+                    self.env().emit_event(ReservesSwept {
+                        treasury,
+                        amount,
+                        total_reserves_new,
+                    })
+                }
+                fn _emit_bad_debt_absorbed_event(
+                    &self,
+                    borrower: AccountId,
+                    amount: Balance,
+                    bad_debt: Balance,
+                ) {
+                    self.env().emit_event(BadDebtAbsorbed {
+                        borrower,
+                        amount,
+                        bad_debt,
+                    })
+                }
+                fn _emit_bad_debt_repaid_event(
+                    &self,
+                    payer: AccountId,
+                    amount: Balance,
+                    bad_debt: Balance,
+                ) {
+                    self.env().emit_event(BadDebtRepaid {
+                        payer,
+                        amount,
+                        bad_debt,
+                    })
+                }
+                fn _emit_accrue_interest_event(
+                    &self,
+                    interest_accumulated: Balance,
+                    new_index: WrappedU256,
+                    new_total_borrows: Balance,
+                ) {
+                    self.env().emit_event(AccrueInterest {
+                        interest_accumulated,
+                        new_index,
+                        new_total_borrows,
+                    })
+                }
+                fn _emit_new_controller_event(&self, old: Option<AccountId>, new: Option<AccountId>) {
+                    self.env().emit_event(NewController { old, new })
+                }
+                fn _emit_new_interest_rate_model_event(
+                    &self,
+                    old: Option<AccountId>,
+                    new: Option<AccountId>,
+                ) {
+                    self.env().emit_event(NewInterestRateModel { old, new })
+                }
+                fn _emit_new_reserve_factor_event(&self, old: WrappedU256, new: WrappedU256) {
+                    self.env().emit_event(NewReserveFactor { old, new })
+                }
+
+                fn _emit_delegate_approval_event(
+                    &self,
+                    owner: AccountId,
+                    delegatee: AccountId,
+                    amount: Balance,
+                ) {
+                    self.env().emit_event(DelegateApproval {
+                        owner,
+                        delegatee,
+                        amount,
+                    })
+                }
+
+                fn _emit_reserve_used_as_collateral_enabled_event(&self, user: AccountId) {
+                    self.env()
+                        .emit_event(ReserveUsedAsCollateralEnabled { user })
+                }
+
+                fn _emit_reserve_used_as_collateral_disabled_event(&self, user: AccountId) {
+                    self.env()
+                        .emit_event(ReserveUsedAsCollateralDisabled { user })
+                }
+            }
+
+            impl psp22::Internal for PoolContract {
+                fn _emit_transfer_event(
+                    &self,
+                    from: Option<AccountId>,
+                    to: Option<AccountId>,
+                    value: Balance,
+                ) {
+                    self.env().emit_event(Transfer { from, to, value });
+                }
+
+                fn _emit_approval_event(&self, owner: AccountId, spender: AccountId, value: Balance) {
+                    self.env().emit_event(Approval {
+                        owner,
+                        spender,
+                        value,
+                    });
+                }
+
+                fn _before_token_transfer(
+                    &mut self,
+                    from: Option<&AccountId>,
+                    to: Option<&AccountId>,
+                    _amount: &Balance,
+                ) -> core::result::Result<(), PSP22Error> {
+                    // accrue rewards with the balances before any mint, burn or transfer
+                    for account in [from, to].into_iter().flatten() {
+                        if Internal::_accrue_reward(self, *account).is_err() {
+                            return Err(PSP22Error::Custom(String::from("AccrueRewardFailed")))
+                        }
+                    }
+                    Ok(())
+                }
+
+                fn _after_token_transfer(
+                    &mut self,
+                    from: Option<&AccountId>,
+                    to: Option<&AccountId>,
+                    amount: &Balance,
+                ) -> core::result::Result<(), PSP22Error> {
+                    votes::Internal::_after_token_transfer_votes(self, from.copied(), to.copied(), *amount);
+                    Ok(())
+                }
+            }
+
+            impl votes::Internal for PoolContract {
+                fn _emit_delegate_changed_event(
+                    &self,
+                    delegator: AccountId,
+                    from_delegate: Option<AccountId>,
+                    to_delegate: AccountId,
+                ) {
+                    self.env().emit_event(DelegateChanged {
+                        delegator,
+                        from_delegate,
+                        to_delegate,
+                    })
+                }
+
+                fn _emit_delegate_votes_changed_event(
+                    &self,
+                    delegate: AccountId,
+                    previous_balance: Balance,
+                    new_balance: Balance,
+                ) {
+                    self.env().emit_event(DelegateVotesChanged {
+                        delegate,
+                        previous_balance,
+                        new_balance,
+                    })
+                }
+            }
+
+            impl PoolContract {
+                /// Generate this contract, only to upload its code
+                #[ink(constructor)]
+                pub fn new() -> Self {
+                    Self::default()
+                }
+            }
+        }
+    };
+}
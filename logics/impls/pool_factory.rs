@@ -0,0 +1,275 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+pub use crate::traits::pool_factory::*;
+use crate::traits::{
+    controller::ControllerRef,
+    pool::PoolRef,
+};
+use ink::prelude::vec::Vec;
+use openbrush::{
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Hash,
+        Storage,
+    },
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Debug, Default)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// AccountId of Controller the pools are listed in
+    pub controller: Option<AccountId>,
+    /// Code new pools are deployed from
+    pub pool_code_hash: Option<Hash>,
+    /// Pool created for each underlying
+    pub pools: Mapping<AccountId, AccountId>,
+    /// Codes rate models can be deployed from
+    pub approved_rate_model_code_hashes: Mapping<Hash, bool>,
+    /// Rate models created by this factory
+    pub rate_models: Vec<AccountId>,
+}
+
+pub trait Internal {
+    fn _initialize(&mut self, controller: AccountId, pool_code_hash: Hash);
+    fn _assert_manager(&self) -> Result<()>;
+    fn _create_pool(
+        &mut self,
+        underlying: AccountId,
+        parameters: PoolParameters,
+    ) -> Result<AccountId>;
+    /// Instantiates the pool code, implemented by the contract knowing the constructor of the pool
+    fn _instantiate_pool(
+        &mut self,
+        underlying: AccountId,
+        parameters: &PoolParameters,
+    ) -> Result<AccountId>;
+    fn _set_pool_code_hash(&mut self, code_hash: Hash) -> Result<()>;
+    fn _create_rate_model(
+        &mut self,
+        code_hash: Hash,
+        parameters: RateModelParameters,
+    ) -> Result<AccountId>;
+    /// Instantiates an approved rate model code, implemented by the contract
+    fn _instantiate_rate_model(
+        &mut self,
+        code_hash: Hash,
+        parameters: &RateModelParameters,
+        salt: u32,
+    ) -> Result<AccountId>;
+    fn _set_rate_model_code_hash_approval(&mut self, code_hash: Hash, approved: bool)
+        -> Result<()>;
+
+    // view functions
+    fn _controller(&self) -> Option<AccountId>;
+    fn _pool_code_hash(&self) -> Option<Hash>;
+    fn _pool_of(&self, underlying: AccountId) -> Option<AccountId>;
+    fn _is_rate_model_code_hash_approved(&self, code_hash: Hash) -> bool;
+    fn _rate_models(&self) -> Vec<AccountId>;
+
+    // event emission
+    fn _emit_pool_created_event(&self, underlying: AccountId, pool: AccountId);
+    fn _emit_new_pool_code_hash_event(&self, old: Option<Hash>, new: Hash);
+    fn _emit_rate_model_created_event(&self, code_hash: Hash, rate_model: AccountId);
+    fn _emit_rate_model_code_hash_approval_updated_event(&self, code_hash: Hash, approved: bool);
+}
+
+impl<T: Storage<Data>> PoolFactory for T {
+    default fn create_pool(
+        &mut self,
+        underlying: AccountId,
+        parameters: PoolParameters,
+    ) -> Result<AccountId> {
+        self._assert_manager()?;
+        self._create_pool(underlying, parameters)
+    }
+
+    default fn set_pool_code_hash(&mut self, code_hash: Hash) -> Result<()> {
+        self._assert_manager()?;
+        let old = self._pool_code_hash();
+        self._set_pool_code_hash(code_hash)?;
+        self._emit_new_pool_code_hash_event(old, code_hash);
+        Ok(())
+    }
+
+    default fn pool_code_hash(&self) -> Option<Hash> {
+        self._pool_code_hash()
+    }
+
+    default fn controller(&self) -> Option<AccountId> {
+        self._controller()
+    }
+
+    default fn pool_of(&self, underlying: AccountId) -> Option<AccountId> {
+        self._pool_of(underlying)
+    }
+
+    default fn create_rate_model(
+        &mut self,
+        code_hash: Hash,
+        parameters: RateModelParameters,
+    ) -> Result<AccountId> {
+        self._assert_manager()?;
+        self._create_rate_model(code_hash, parameters)
+    }
+
+    default fn set_rate_model_code_hash_approval(
+        &mut self,
+        code_hash: Hash,
+        approved: bool,
+    ) -> Result<()> {
+        self._assert_manager()?;
+        self._set_rate_model_code_hash_approval(code_hash, approved)?;
+        self._emit_rate_model_code_hash_approval_updated_event(code_hash, approved);
+        Ok(())
+    }
+
+    default fn is_rate_model_code_hash_approved(&self, code_hash: Hash) -> bool {
+        self._is_rate_model_code_hash_approved(code_hash)
+    }
+
+    default fn rate_models(&self) -> Vec<AccountId> {
+        self._rate_models()
+    }
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _initialize(&mut self, controller: AccountId, pool_code_hash: Hash) {
+        let data = self.data::<Data>();
+        data.controller = Some(controller);
+        data.pool_code_hash = Some(pool_code_hash);
+    }
+
+    default fn _assert_manager(&self) -> Result<()> {
+        let controller = self._controller().ok_or(Error::ControllerIsNotSet)?;
+        if ControllerRef::manager(&controller) != Some(Self::env().caller()) {
+            return Err(Error::CallerIsNotManager)
+        }
+        Ok(())
+    }
+
+    default fn _create_pool(
+        &mut self,
+        underlying: AccountId,
+        parameters: PoolParameters,
+    ) -> Result<AccountId> {
+        if self._pool_of(underlying).is_some() {
+            return Err(Error::PoolAlreadyCreated)
+        }
+        let pool = self._instantiate_pool(underlying, &parameters)?;
+        self.data::<Data>().pools.insert(&underlying, &pool);
+
+        // this contract is the manager of the pool until the manager of the Controller accepts it
+        PoolRef::set_reserve_factor_mantissa(&pool, parameters.reserve_factor_mantissa)?;
+        let controller = self._controller().ok_or(Error::ControllerIsNotSet)?;
+        match parameters.collateral_factor_mantissa {
+            Some(collateral_factor_mantissa) => {
+                ControllerRef::support_market_with_collateral_factor_mantissa(
+                    &controller,
+                    pool,
+                    underlying,
+                    collateral_factor_mantissa,
+                )?
+            }
+            None => ControllerRef::support_market(&controller, pool, underlying)?,
+        }
+        PoolRef::set_manager(&pool, Self::env().caller())?;
+
+        self._emit_pool_created_event(underlying, pool);
+        Ok(pool)
+    }
+
+    default fn _instantiate_pool(
+        &mut self,
+        _underlying: AccountId,
+        _parameters: &PoolParameters,
+    ) -> Result<AccountId> {
+        Err(Error::InstantiatePoolFailed)
+    }
+
+    default fn _set_pool_code_hash(&mut self, code_hash: Hash) -> Result<()> {
+        self.data::<Data>().pool_code_hash = Some(code_hash);
+        Ok(())
+    }
+
+    default fn _create_rate_model(
+        &mut self,
+        code_hash: Hash,
+        parameters: RateModelParameters,
+    ) -> Result<AccountId> {
+        if !self._is_rate_model_code_hash_approved(code_hash) {
+            return Err(Error::RateModelCodeHashIsNotApproved)
+        }
+        // salted with the number of created models, models with the same parameters can coexist
+        let salt = self.data::<Data>().rate_models.len() as u32;
+        let rate_model = self._instantiate_rate_model(code_hash, &parameters, salt)?;
+        self.data::<Data>().rate_models.push(rate_model);
+
+        self._emit_rate_model_created_event(code_hash, rate_model);
+        Ok(rate_model)
+    }
+
+    default fn _instantiate_rate_model(
+        &mut self,
+        _code_hash: Hash,
+        _parameters: &RateModelParameters,
+        _salt: u32,
+    ) -> Result<AccountId> {
+        Err(Error::InstantiateRateModelFailed)
+    }
+
+    default fn _set_rate_model_code_hash_approval(
+        &mut self,
+        code_hash: Hash,
+        approved: bool,
+    ) -> Result<()> {
+        let data = self.data::<Data>();
+        if approved {
+            data.approved_rate_model_code_hashes
+                .insert(&code_hash, &true);
+        } else {
+            data.approved_rate_model_code_hashes.remove(&code_hash);
+        }
+        Ok(())
+    }
+
+    default fn _controller(&self) -> Option<AccountId> {
+        self.data::<Data>().controller
+    }
+
+    default fn _pool_code_hash(&self) -> Option<Hash> {
+        self.data::<Data>().pool_code_hash
+    }
+
+    default fn _pool_of(&self, underlying: AccountId) -> Option<AccountId> {
+        self.data::<Data>().pools.get(&underlying)
+    }
+
+    default fn _is_rate_model_code_hash_approved(&self, code_hash: Hash) -> bool {
+        self.data::<Data>()
+            .approved_rate_model_code_hashes
+            .get(&code_hash)
+            .unwrap_or(false)
+    }
+
+    default fn _rate_models(&self) -> Vec<AccountId> {
+        self.data::<Data>().rate_models.clone()
+    }
+
+    default fn _emit_pool_created_event(&self, _underlying: AccountId, _pool: AccountId) {}
+    default fn _emit_new_pool_code_hash_event(&self, _old: Option<Hash>, _new: Hash) {}
+    default fn _emit_rate_model_created_event(&self, _code_hash: Hash, _rate_model: AccountId) {}
+    default fn _emit_rate_model_code_hash_approval_updated_event(
+        &self,
+        _code_hash: Hash,
+        _approved: bool,
+    ) {
+    }
+}
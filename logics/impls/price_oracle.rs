@@ -5,30 +5,120 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use crate::traits::pool::PoolRef;
+use super::{
+    exp_no_err::exp_scale,
+    params::{
+        is_valid_price_decimals,
+        is_valid_price_deviation,
+    },
+};
 pub use crate::traits::price_oracle::*;
+use crate::traits::{
+    manager::ManagerRef,
+    pool::PoolRef,
+};
+use core::ops::{
+    Div,
+    Mul,
+};
 use openbrush::{
+    contracts::ownable::{
+        self,
+        only_owner,
+    },
+    modifiers,
     storage::Mapping,
     traits::{
         AccountId,
         Storage,
+        Timestamp,
     },
 };
+use primitive_types::U256;
 
 pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
-#[derive(Debug)]
+#[derive(Debug, Default)]
 #[openbrush::upgradeable_storage(STORAGE_KEY)]
 pub struct Data {
-    /// Fixed prices to behave as Mock
-    pub fixed_prices: Mapping<AccountId, u128>,
+    /// Prices posted by reporters and the time they were posted at
+    pub prices: Mapping<AccountId, PriceData>,
+    /// Accounts authorized to post prices
+    pub reporters: Mapping<AccountId, bool>,
+    /// Longest time a price of each asset is used for after it was posted, 0 if unlimited
+    pub heartbeats: Mapping<AccountId, Timestamp>,
+    /// Range prices of each asset must be within
+    pub price_bounds: Mapping<AccountId, PriceBounds>,
+    /// Decimals reporters post prices of each asset with, `PRICE_DECIMALS` if unset
+    pub price_decimals: Mapping<AccountId, u8>,
+    /// Largest deviation from the last price each asset accepts within a window
+    pub deviation_breakers: Mapping<AccountId, DeviationBreaker>,
+    /// Assets whose breaker tripped
+    pub tripped: Mapping<AccountId, bool>,
+    /// Manager the borrows of tripped markets are paused through
+    pub manager: Option<AccountId>,
 }
 
+/// Precision of the prices all oracles return, whatever the decimals of their source
 pub const PRICE_PRECISION: u128 = 10_u128.pow(18);
 
+/// Decimals of `PRICE_PRECISION`
+pub const PRICE_DECIMALS: u8 = 18;
+
+/// Converts a price given with `decimals` decimals to `PRICE_PRECISION`, None on overflow
+pub fn normalize_price(price: u128, decimals: u8) -> Option<u128> {
+    if decimals > PRICE_DECIMALS {
+        let divisor = 10_u128.checked_pow(u32::from(decimals - PRICE_DECIMALS))?;
+        Some(price / divisor)
+    } else {
+        price.checked_mul(10_u128.pow(u32::from(PRICE_DECIMALS - decimals)))
+    }
+}
+
+/// Whether a price updated at `updated_at` is older than `heartbeat` at `now`. 0 disables it
+pub fn is_stale(updated_at: Timestamp, heartbeat: Timestamp, now: Timestamp) -> bool {
+    heartbeat != 0 && now.saturating_sub(updated_at) > heartbeat
+}
+
+/// Whether `price` deviates from `reference` by at most `max_deviation_mantissa` of `reference`
+pub fn is_within_deviation(price: u128, reference: u128, max_deviation_mantissa: U256) -> bool {
+    let max_deviation = U256::from(reference)
+        .mul(max_deviation_mantissa)
+        .div(exp_scale());
+    U256::from(price.abs_diff(reference)) <= max_deviation
+}
+
 pub trait Internal {
     fn _get_price(&self, asset: AccountId) -> Option<u128>;
+    fn _get_price_data(&self, asset: AccountId) -> Option<PriceData>;
     fn _get_underlying_price(&self, pool: AccountId) -> Option<u128>;
     fn _set_fixed_price(&mut self, asset: AccountId, price: u128) -> Result<()>;
+    fn _set_reporter(&mut self, reporter: AccountId, authorized: bool) -> Result<()>;
+    fn _is_reporter(&self, account: AccountId) -> bool;
+    fn _set_heartbeat(&mut self, asset: AccountId, heartbeat: Timestamp) -> Result<()>;
+    fn _heartbeat(&self, asset: AccountId) -> Timestamp;
+    fn _set_price_bounds(&mut self, asset: AccountId, bounds: PriceBounds) -> Result<()>;
+    fn _price_bounds(&self, asset: AccountId) -> PriceBounds;
+    fn _set_price_decimals(&mut self, asset: AccountId, decimals: u8) -> Result<()>;
+    fn _price_decimals(&self, asset: AccountId) -> u8;
+    fn _set_deviation_breaker(&mut self, asset: AccountId, breaker: DeviationBreaker)
+        -> Result<()>;
+    fn _deviation_breaker(&self, asset: AccountId) -> DeviationBreaker;
+    fn _set_manager(&mut self, manager: AccountId) -> Result<()>;
+    fn _reset_breaker(&mut self, asset: AccountId) -> Result<()>;
+    fn _is_tripped(&self, asset: AccountId) -> bool;
+    fn _breaks_deviation(&self, asset: AccountId, price: u128) -> bool;
+    fn _trip_breaker(&mut self, asset: AccountId, price: u128);
+
+    // event emission
+    fn _emit_breaker_tripped_event(
+        &self,
+        asset: AccountId,
+        last_price: u128,
+        price: u128,
+        borrow_paused: bool,
+    );
+    fn _emit_price_posted_event(&self, asset: AccountId, price: u128, reporter: AccountId);
+    fn _emit_reporter_updated_event(&self, reporter: AccountId, authorized: bool);
 }
 
 impl<T: Storage<Data>> PriceOracle for T {
@@ -39,13 +129,95 @@ impl<T: Storage<Data>> PriceOracle for T {
         self._get_underlying_price(pool)
     }
     default fn set_fixed_price(&mut self, asset: AccountId, value: u128) -> Result<()> {
-        self._set_fixed_price(asset, value)
+        if !self._is_reporter(Self::env().caller()) {
+            return Err(Error::CallerIsNotReporter)
+        }
+        let price =
+            normalize_price(value, self._price_decimals(asset)).ok_or(Error::PriceOverflow)?;
+        self._set_fixed_price(asset, price)
+    }
+}
+
+impl<T: Storage<Data> + Storage<ownable::Data>> ManualPriceOracle for T {
+    #[modifiers(only_owner)]
+    default fn set_reporter(&mut self, reporter: AccountId, authorized: bool) -> Result<()> {
+        self._set_reporter(reporter, authorized)
+    }
+    default fn is_reporter(&self, account: AccountId) -> bool {
+        self._is_reporter(account)
+    }
+    #[modifiers(only_owner)]
+    default fn set_heartbeat(&mut self, asset: AccountId, heartbeat: Timestamp) -> Result<()> {
+        self._set_heartbeat(asset, heartbeat)
+    }
+    default fn heartbeat(&self, asset: AccountId) -> Timestamp {
+        self._heartbeat(asset)
+    }
+    #[modifiers(only_owner)]
+    default fn set_price_bounds(&mut self, asset: AccountId, bounds: PriceBounds) -> Result<()> {
+        self._set_price_bounds(asset, bounds)
+    }
+    default fn price_bounds(&self, asset: AccountId) -> PriceBounds {
+        self._price_bounds(asset)
+    }
+    #[modifiers(only_owner)]
+    default fn set_price_decimals(&mut self, asset: AccountId, decimals: u8) -> Result<()> {
+        self._set_price_decimals(asset, decimals)
+    }
+    default fn price_decimals(&self, asset: AccountId) -> u8 {
+        self._price_decimals(asset)
+    }
+    #[modifiers(only_owner)]
+    default fn set_deviation_breaker(
+        &mut self,
+        asset: AccountId,
+        breaker: DeviationBreaker,
+    ) -> Result<()> {
+        self._set_deviation_breaker(asset, breaker)
+    }
+    default fn deviation_breaker(&self, asset: AccountId) -> DeviationBreaker {
+        self._deviation_breaker(asset)
+    }
+    #[modifiers(only_owner)]
+    default fn set_manager(&mut self, manager: AccountId) -> Result<()> {
+        self._set_manager(manager)
+    }
+    default fn manager(&self) -> Option<AccountId> {
+        self.data::<Data>().manager
+    }
+    #[modifiers(only_owner)]
+    default fn reset_breaker(&mut self, asset: AccountId) -> Result<()> {
+        self._reset_breaker(asset)
+    }
+    default fn is_tripped(&self, asset: AccountId) -> bool {
+        self._is_tripped(asset)
+    }
+    default fn get_price_data(&self, asset: AccountId) -> Option<PriceData> {
+        self._get_price_data(asset)
     }
 }
 
 impl<T: Storage<Data>> Internal for T {
     default fn _get_price(&self, asset: AccountId) -> Option<u128> {
-        self.data().fixed_prices.get(&asset)
+        if self._is_tripped(asset) {
+            return None
+        }
+        let data = self._get_price_data(asset)?;
+        if is_stale(
+            data.updated_at,
+            self._heartbeat(asset),
+            Self::env().block_timestamp(),
+        ) {
+            return None
+        }
+        // bounds may have been tightened since the price was posted
+        if !self._price_bounds(asset).contains(data.price) {
+            return None
+        }
+        Some(data.price)
+    }
+    default fn _get_price_data(&self, asset: AccountId) -> Option<PriceData> {
+        self.data().prices.get(&asset)
     }
     default fn _get_underlying_price(&self, pool: AccountId) -> Option<u128> {
         if let Some(underlying) = PoolRef::underlying(&pool) {
@@ -54,7 +226,177 @@ impl<T: Storage<Data>> Internal for T {
         None
     }
     default fn _set_fixed_price(&mut self, asset: AccountId, value: u128) -> Result<()> {
-        self.data().fixed_prices.insert(&asset, &value);
+        if !self._price_bounds(asset).contains(value) {
+            return Err(Error::PriceOutOfBounds)
+        }
+        // tripping must not revert, so that the pause and the flag persist
+        if self._breaks_deviation(asset, value) {
+            self._trip_breaker(asset, value);
+            return Ok(())
+        }
+        self.data().prices.insert(
+            &asset,
+            &PriceData {
+                price: value,
+                updated_at: Self::env().block_timestamp(),
+            },
+        );
+        self._emit_price_posted_event(asset, value, Self::env().caller());
         Ok(())
     }
+    default fn _set_reporter(&mut self, reporter: AccountId, authorized: bool) -> Result<()> {
+        if authorized {
+            self.data().reporters.insert(&reporter, &true);
+        } else {
+            self.data().reporters.remove(&reporter);
+        }
+        self._emit_reporter_updated_event(reporter, authorized);
+        Ok(())
+    }
+    default fn _is_reporter(&self, account: AccountId) -> bool {
+        self.data().reporters.get(&account).unwrap_or(false)
+    }
+    default fn _set_heartbeat(&mut self, asset: AccountId, heartbeat: Timestamp) -> Result<()> {
+        self.data().heartbeats.insert(&asset, &heartbeat);
+        Ok(())
+    }
+    default fn _heartbeat(&self, asset: AccountId) -> Timestamp {
+        self.data().heartbeats.get(&asset).unwrap_or_default()
+    }
+    default fn _set_price_bounds(&mut self, asset: AccountId, bounds: PriceBounds) -> Result<()> {
+        if bounds.max != 0 && bounds.min > bounds.max {
+            return Err(Error::InvalidPriceBounds)
+        }
+        self.data().price_bounds.insert(&asset, &bounds);
+        Ok(())
+    }
+    default fn _price_bounds(&self, asset: AccountId) -> PriceBounds {
+        self.data().price_bounds.get(&asset).unwrap_or_default()
+    }
+    default fn _set_price_decimals(&mut self, asset: AccountId, decimals: u8) -> Result<()> {
+        if !is_valid_price_decimals(decimals) {
+            return Err(Error::InvalidPriceDecimals)
+        }
+        self.data().price_decimals.insert(&asset, &decimals);
+        Ok(())
+    }
+    default fn _set_deviation_breaker(
+        &mut self,
+        asset: AccountId,
+        breaker: DeviationBreaker,
+    ) -> Result<()> {
+        if !is_valid_price_deviation(breaker.max_deviation_mantissa.into()) {
+            return Err(Error::InvalidDeviationBreaker)
+        }
+        self.data().deviation_breakers.insert(&asset, &breaker);
+        Ok(())
+    }
+    default fn _deviation_breaker(&self, asset: AccountId) -> DeviationBreaker {
+        self.data()
+            .deviation_breakers
+            .get(&asset)
+            .unwrap_or_default()
+    }
+    default fn _set_manager(&mut self, manager: AccountId) -> Result<()> {
+        self.data().manager = Some(manager);
+        Ok(())
+    }
+    default fn _reset_breaker(&mut self, asset: AccountId) -> Result<()> {
+        self.data().tripped.remove(&asset);
+        Ok(())
+    }
+    default fn _is_tripped(&self, asset: AccountId) -> bool {
+        self.data().tripped.get(&asset).unwrap_or(false)
+    }
+    default fn _breaks_deviation(&self, asset: AccountId, price: u128) -> bool {
+        let breaker = self._deviation_breaker(asset);
+        let max_deviation = U256::from(breaker.max_deviation_mantissa);
+        if max_deviation.is_zero() {
+            return false
+        }
+        match self._get_price_data(asset) {
+            Some(last)
+                if Self::env()
+                    .block_timestamp()
+                    .saturating_sub(last.updated_at)
+                    <= breaker.window =>
+            {
+                !is_within_deviation(price, last.price, max_deviation)
+            }
+            _ => false,
+        }
+    }
+    default fn _trip_breaker(&mut self, asset: AccountId, price: u128) {
+        self.data().tripped.insert(&asset, &true);
+        let last_price = self
+            ._get_price_data(asset)
+            .map(|data| data.price)
+            .unwrap_or_default();
+        let borrow_paused = match (self.data().manager, self._deviation_breaker(asset).pool) {
+            (Some(manager), Some(pool)) => {
+                ManagerRef::set_borrow_guardian_paused(&manager, pool, true).is_ok()
+            }
+            _ => false,
+        };
+        self._emit_breaker_tripped_event(asset, last_price, price, borrow_paused);
+    }
+    default fn _price_decimals(&self, asset: AccountId) -> u8 {
+        self.data()
+            .price_decimals
+            .get(&asset)
+            .unwrap_or(PRICE_DECIMALS)
+    }
+
+    default fn _emit_price_posted_event(
+        &self,
+        _asset: AccountId,
+        _price: u128,
+        _reporter: AccountId,
+    ) {
+    }
+    default fn _emit_breaker_tripped_event(
+        &self,
+        _asset: AccountId,
+        _last_price: u128,
+        _price: u128,
+        _borrow_paused: bool,
+    ) {
+    }
+    default fn _emit_reporter_updated_event(&self, _reporter: AccountId, _authorized: bool) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_price() {
+        assert_eq!(normalize_price(PRICE_PRECISION, 18), Some(PRICE_PRECISION));
+        assert_eq!(normalize_price(1_500_000, 6), Some(PRICE_PRECISION * 3 / 2));
+        assert_eq!(
+            normalize_price(15 * 10_u128.pow(19), 20),
+            Some(PRICE_PRECISION * 3 / 2)
+        );
+        assert_eq!(normalize_price(u128::MAX, 0), None);
+        assert_eq!(normalize_price(u128::MAX, 60), None);
+    }
+
+    #[test]
+    fn test_is_within_deviation() {
+        let percent = |val: u128| exp_scale().mul(U256::from(val)).div(U256::from(100));
+        assert!(is_within_deviation(100, 100, U256::zero()));
+        assert!(is_within_deviation(105, 100, percent(5)));
+        assert!(is_within_deviation(95, 100, percent(5)));
+        assert!(!is_within_deviation(106, 100, percent(5)));
+        assert!(!is_within_deviation(94, 100, percent(5)));
+    }
+
+    #[test]
+    fn test_is_stale() {
+        assert!(!is_stale(100, 0, 1_000_000));
+        assert!(!is_stale(100, 50, 150));
+        assert!(is_stale(100, 50, 151));
+        // prices posted after now are not stale
+        assert!(!is_stale(200, 50, 100));
+    }
 }
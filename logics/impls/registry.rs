@@ -0,0 +1,167 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::traits::controller::ControllerRef;
+pub use crate::traits::registry::*;
+use ink::prelude::vec::Vec;
+use openbrush::{
+    contracts::ownable::{
+        self,
+        only_owner,
+    },
+    modifiers,
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Storage,
+    },
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Debug, Default)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// AccountId of Controller pools are resolved from when not registered
+    pub controller: Option<AccountId>,
+    /// Contracts serving each registered asset
+    pub addresses: Mapping<AccountId, AssetAddresses>,
+    /// Registered assets
+    pub assets: Vec<AccountId>,
+}
+
+pub trait Internal {
+    fn _initialize(&mut self, controller: Option<AccountId>);
+    fn _set_addresses(&mut self, asset: AccountId, addresses: AssetAddresses) -> Result<()>;
+    fn _remove_addresses(&mut self, asset: AccountId) -> Result<()>;
+    fn _set_controller(&mut self, controller: Option<AccountId>) -> Result<()>;
+
+    // view functions
+    fn _controller(&self) -> Option<AccountId>;
+    fn _registered_addresses(&self, asset: AccountId) -> Option<AssetAddresses>;
+    fn _addresses_of(&self, asset: AccountId) -> AssetAddresses;
+    fn _assets(&self) -> Vec<AccountId>;
+
+    // event emission
+    fn _emit_addresses_updated_event(&self, asset: AccountId, addresses: AssetAddresses);
+    fn _emit_addresses_removed_event(&self, asset: AccountId);
+    fn _emit_new_controller_event(&self, old: Option<AccountId>, new: Option<AccountId>);
+}
+
+impl<T: Storage<Data> + Storage<ownable::Data>> Registry for T {
+    #[modifiers(only_owner)]
+    default fn set_addresses(&mut self, asset: AccountId, addresses: AssetAddresses) -> Result<()> {
+        self._set_addresses(asset, addresses)?;
+        self._emit_addresses_updated_event(asset, addresses);
+        Ok(())
+    }
+
+    #[modifiers(only_owner)]
+    default fn remove_addresses(&mut self, asset: AccountId) -> Result<()> {
+        self._remove_addresses(asset)?;
+        self._emit_addresses_removed_event(asset);
+        Ok(())
+    }
+
+    #[modifiers(only_owner)]
+    default fn set_controller(&mut self, controller: Option<AccountId>) -> Result<()> {
+        let old = self._controller();
+        self._set_controller(controller)?;
+        self._emit_new_controller_event(old, controller);
+        Ok(())
+    }
+
+    default fn controller(&self) -> Option<AccountId> {
+        self._controller()
+    }
+
+    default fn addresses_of(&self, asset: AccountId) -> AssetAddresses {
+        self._addresses_of(asset)
+    }
+
+    default fn pool_of(&self, asset: AccountId) -> Option<AccountId> {
+        self._addresses_of(asset).pool
+    }
+
+    default fn gateway_of(&self, asset: AccountId) -> Option<AccountId> {
+        self._addresses_of(asset).gateway
+    }
+
+    default fn debt_token_of(&self, asset: AccountId) -> Option<AccountId> {
+        self._addresses_of(asset).debt_token
+    }
+
+    default fn oracle_adapter_of(&self, asset: AccountId) -> Option<AccountId> {
+        self._addresses_of(asset).oracle_adapter
+    }
+
+    default fn assets(&self) -> Vec<AccountId> {
+        self._assets()
+    }
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _initialize(&mut self, controller: Option<AccountId>) {
+        self.data::<Data>().controller = controller;
+    }
+
+    default fn _set_addresses(
+        &mut self,
+        asset: AccountId,
+        addresses: AssetAddresses,
+    ) -> Result<()> {
+        let data = self.data::<Data>();
+        if data.addresses.get(&asset).is_none() {
+            data.assets.push(asset);
+        }
+        data.addresses.insert(&asset, &addresses);
+        Ok(())
+    }
+
+    default fn _remove_addresses(&mut self, asset: AccountId) -> Result<()> {
+        let data = self.data::<Data>();
+        if data.addresses.get(&asset).is_none() {
+            return Err(Error::AssetIsNotRegistered)
+        }
+        data.addresses.remove(&asset);
+        data.assets.retain(|registered| *registered != asset);
+        Ok(())
+    }
+
+    default fn _set_controller(&mut self, controller: Option<AccountId>) -> Result<()> {
+        self.data::<Data>().controller = controller;
+        Ok(())
+    }
+
+    default fn _controller(&self) -> Option<AccountId> {
+        self.data::<Data>().controller
+    }
+
+    default fn _registered_addresses(&self, asset: AccountId) -> Option<AssetAddresses> {
+        self.data::<Data>().addresses.get(&asset)
+    }
+
+    default fn _addresses_of(&self, asset: AccountId) -> AssetAddresses {
+        let mut addresses = self._registered_addresses(asset).unwrap_or_default();
+        if addresses.pool.is_none() {
+            addresses.pool = self
+                ._controller()
+                .and_then(|controller| ControllerRef::market_of_underlying(&controller, asset));
+        }
+        addresses
+    }
+
+    default fn _assets(&self) -> Vec<AccountId> {
+        self.data::<Data>().assets.clone()
+    }
+
+    default fn _emit_addresses_updated_event(&self, _asset: AccountId, _addresses: AssetAddresses) {
+    }
+    default fn _emit_addresses_removed_event(&self, _asset: AccountId) {}
+    default fn _emit_new_controller_event(&self, _old: Option<AccountId>, _new: Option<AccountId>) {
+    }
+}
@@ -0,0 +1,202 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+pub use crate::traits::router::*;
+use crate::traits::{
+    controller::ControllerRef,
+    incentives_controller::IncentivesControllerRef,
+    pool::PoolRef,
+};
+use ink::prelude::vec::Vec;
+use openbrush::{
+    contracts::psp22::PSP22Ref,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+    },
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Debug, Default)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// AccountId of Controller, which lists the pools routed to
+    pub controller: Option<AccountId>,
+}
+
+pub trait Internal {
+    fn _initialize(&mut self, controller: AccountId);
+    fn _controller(&self) -> Option<AccountId>;
+    fn _assert_listed(&self, pool: AccountId) -> Result<()>;
+    fn _deposit_for(&mut self, account: AccountId, pool: AccountId, amount: Balance) -> Result<()>;
+    fn _repay_for(&mut self, account: AccountId, pool: AccountId, amount: Balance) -> Result<()>;
+    fn _redeem_for(&mut self, account: AccountId, pool: AccountId, amount: Balance) -> Result<()>;
+}
+
+impl<T: Storage<Data>> Router for T {
+    default fn controller(&self) -> Option<AccountId> {
+        self._controller()
+    }
+
+    default fn deposit_and_enable_collateral(
+        &mut self,
+        pool: AccountId,
+        amount: Balance,
+    ) -> Result<()> {
+        let caller = Self::env().caller();
+        self._deposit_for(caller, pool, amount)?;
+        if !PoolRef::using_reserve_as_collateral(&pool, caller) {
+            return Err(Error::CollateralNotEnabled)
+        }
+        Ok(())
+    }
+
+    default fn deposit_multiple(&mut self, deposits: Vec<(AccountId, Balance)>) -> Result<()> {
+        if deposits.is_empty() {
+            return Err(Error::EmptyDeposits)
+        }
+        let caller = Self::env().caller();
+        for (pool, amount) in deposits {
+            self._deposit_for(caller, pool, amount)?;
+        }
+        Ok(())
+    }
+
+    default fn repay_and_redeem(
+        &mut self,
+        repay_pool: AccountId,
+        repay_amount: Balance,
+        redeem_pool: AccountId,
+        redeem_amount: Balance,
+    ) -> Result<()> {
+        let caller = Self::env().caller();
+        if repay_amount != 0 {
+            self._repay_for(caller, repay_pool, repay_amount)?;
+        }
+        if redeem_amount != 0 {
+            self._redeem_for(caller, redeem_pool, redeem_amount)?;
+        }
+        Ok(())
+    }
+
+    default fn claim_and_deposit(
+        &mut self,
+        incentives_controller: AccountId,
+        pool: AccountId,
+    ) -> Result<Balance> {
+        self._assert_listed(pool)?;
+        let reward_token = match IncentivesControllerRef::reward_token(&incentives_controller) {
+            Some(token) if PoolRef::underlying(&pool) == Some(token) => token,
+            _ => return Err(Error::RewardTokenMismatch),
+        };
+
+        let caller = Self::env().caller();
+        let claimed = IncentivesControllerRef::claim_rewards_on_behalf(
+            &incentives_controller,
+            caller,
+            Self::env().account_id(),
+        )?;
+        if claimed == 0 {
+            return Ok(0)
+        }
+        PSP22Ref::approve(&reward_token, pool, claimed)?;
+        PoolRef::mint_to(&pool, caller, claimed)?;
+        Ok(claimed)
+    }
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _initialize(&mut self, controller: AccountId) {
+        self.data().controller = Some(controller);
+    }
+
+    default fn _controller(&self) -> Option<AccountId> {
+        self.data().controller
+    }
+
+    default fn _assert_listed(&self, pool: AccountId) -> Result<()> {
+        let controller = self._controller().ok_or(Error::ControllerIsNotSet)?;
+        if !ControllerRef::is_listed(&controller, pool) {
+            return Err(Error::MarketNotListed)
+        }
+        Ok(())
+    }
+
+    default fn _deposit_for(
+        &mut self,
+        account: AccountId,
+        pool: AccountId,
+        amount: Balance,
+    ) -> Result<()> {
+        self._assert_listed(pool)?;
+        let underlying = PoolRef::underlying(&pool).ok_or(Error::UnderlyingIsNotSet)?;
+        PSP22Ref::transfer_from(
+            &underlying,
+            account,
+            Self::env().account_id(),
+            amount,
+            Vec::<u8>::new(),
+        )?;
+        PSP22Ref::approve(&underlying, pool, amount)?;
+        PoolRef::mint_to(&pool, account, amount)?;
+        Ok(())
+    }
+
+    default fn _repay_for(
+        &mut self,
+        account: AccountId,
+        pool: AccountId,
+        amount: Balance,
+    ) -> Result<()> {
+        self._assert_listed(pool)?;
+        let underlying = PoolRef::underlying(&pool).ok_or(Error::UnderlyingIsNotSet)?;
+        let mut repay_amount = amount;
+        if amount == u128::MAX {
+            repay_amount = PoolRef::borrow_balance_current(&pool, account)?;
+        }
+
+        PSP22Ref::transfer_from(
+            &underlying,
+            account,
+            Self::env().account_id(),
+            repay_amount,
+            Vec::<u8>::new(),
+        )?;
+        PSP22Ref::approve(&underlying, pool, repay_amount)?;
+        PoolRef::repay_borrow_behalf(&pool, account, repay_amount)?;
+        Ok(())
+    }
+
+    default fn _redeem_for(
+        &mut self,
+        account: AccountId,
+        pool: AccountId,
+        amount: Balance,
+    ) -> Result<()> {
+        self._assert_listed(pool)?;
+        let underlying = PoolRef::underlying(&pool).ok_or(Error::UnderlyingIsNotSet)?;
+        let contract_address = Self::env().account_id();
+        let mut redeem_amount = amount;
+        if amount == u128::MAX {
+            redeem_amount = PoolRef::balance_of(&pool, account);
+        }
+
+        // NOTE: pool token balances are denominated in underlying
+        PoolRef::transfer_from(
+            &pool,
+            account,
+            contract_address,
+            redeem_amount,
+            Vec::<u8>::new(),
+        )?;
+        PoolRef::redeem_underlying(&pool, redeem_amount)?;
+        PSP22Ref::transfer(&underlying, account, redeem_amount, Vec::<u8>::new())?;
+        Ok(())
+    }
+}
@@ -0,0 +1,355 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::{
+    exp_no_err::exp_scale,
+    incentives_controller::accrued_rewards,
+};
+pub use crate::traits::staking::*;
+use crate::traits::types::WrappedU256;
+use core::ops::{
+    Add,
+    Div,
+    Mul,
+    Sub,
+};
+use ink::prelude::vec::Vec;
+use openbrush::{
+    contracts::{
+        ownable::{
+            self,
+            only_owner,
+        },
+        psp22::PSP22Ref,
+    },
+    modifiers,
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+    },
+};
+use primitive_types::U256;
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Debug, Default)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// AccountId of the staked token, LAY
+    pub staking_token: Option<AccountId>,
+    /// Stake of each account
+    pub balances: Mapping<AccountId, Balance>,
+    /// Sum of all stakes
+    pub total_staked: Balance,
+    /// Assets rewards are paid in
+    pub reward_assets: Vec<AccountId>,
+    /// Reward index of each reward asset
+    pub reward_indexes: Mapping<AccountId, WrappedU256>,
+    /// Reward index of each reward asset at which each account last accrued
+    pub account_indexes: Mapping<(AccountId, AccountId), WrappedU256>,
+    /// Rewards accrued and not claimed by each account in each reward asset
+    pub unclaimed_rewards: Mapping<(AccountId, AccountId), Balance>,
+    /// Amount of each reward asset distributed and not claimed yet
+    pub distributed_unclaimed: Mapping<AccountId, Balance>,
+}
+
+/// Reward index after `amount` is distributed to `total` staked units
+pub fn distributed_reward_index(index: U256, amount: Balance, total: Balance) -> U256 {
+    if total == 0 {
+        return index
+    }
+    index.add(U256::from(amount).mul(exp_scale()).div(U256::from(total)))
+}
+
+pub trait Internal {
+    fn _initialize(&mut self, staking_token: AccountId);
+    fn _stake(&mut self, account: AccountId, amount: Balance) -> Result<()>;
+    fn _unstake(&mut self, account: AccountId, amount: Balance) -> Result<()>;
+    fn _accrue_rewards(&mut self, account: AccountId);
+    fn _claim_rewards(&mut self, account: AccountId) -> Result<Vec<(AccountId, Balance)>>;
+    fn _distribute(&mut self, asset: AccountId) -> Result<Balance>;
+    fn _add_reward_asset(&mut self, asset: AccountId) -> Result<()>;
+
+    // view functions
+    fn _staking_token(&self) -> Option<AccountId>;
+    fn _staked_balance_of(&self, account: AccountId) -> Balance;
+    fn _total_staked(&self) -> Balance;
+    fn _reward_assets(&self) -> Vec<AccountId>;
+    fn _reward_index(&self, asset: AccountId) -> U256;
+    fn _claimable_rewards(&self, asset: AccountId, account: AccountId) -> Balance;
+    /// Amount of `asset` held for stakes and rewards not claimed yet
+    fn _tracked_balance(&self, asset: AccountId) -> Balance;
+
+    // event emission
+    fn _emit_staked_event(&self, account: AccountId, amount: Balance);
+    fn _emit_unstaked_event(&self, account: AccountId, amount: Balance);
+    fn _emit_rewards_distributed_event(&self, asset: AccountId, amount: Balance, index: U256);
+    fn _emit_rewards_claimed_event(&self, account: AccountId, asset: AccountId, amount: Balance);
+    fn _emit_reward_asset_added_event(&self, asset: AccountId);
+}
+
+impl<T: Storage<Data> + Storage<ownable::Data>> Staking for T {
+    default fn stake(&mut self, amount: Balance) -> Result<()> {
+        let caller = Self::env().caller();
+        self._stake(caller, amount)?;
+        self._emit_staked_event(caller, amount);
+        Ok(())
+    }
+
+    default fn unstake(&mut self, amount: Balance) -> Result<()> {
+        let caller = Self::env().caller();
+        self._unstake(caller, amount)?;
+        self._emit_unstaked_event(caller, amount);
+        Ok(())
+    }
+
+    default fn claim_rewards(&mut self) -> Result<Vec<(AccountId, Balance)>> {
+        self._claim_rewards(Self::env().caller())
+    }
+
+    default fn distribute(&mut self, asset: AccountId) -> Result<Balance> {
+        let amount = self._distribute(asset)?;
+        self._emit_rewards_distributed_event(asset, amount, self._reward_index(asset));
+        Ok(amount)
+    }
+
+    #[modifiers(only_owner)]
+    default fn add_reward_asset(&mut self, asset: AccountId) -> Result<()> {
+        self._add_reward_asset(asset)?;
+        self._emit_reward_asset_added_event(asset);
+        Ok(())
+    }
+
+    default fn staking_token(&self) -> Option<AccountId> {
+        self._staking_token()
+    }
+
+    default fn staked_balance_of(&self, account: AccountId) -> Balance {
+        self._staked_balance_of(account)
+    }
+
+    default fn total_staked(&self) -> Balance {
+        self._total_staked()
+    }
+
+    default fn reward_assets(&self) -> Vec<AccountId> {
+        self._reward_assets()
+    }
+
+    default fn reward_index(&self, asset: AccountId) -> WrappedU256 {
+        WrappedU256::from(self._reward_index(asset))
+    }
+
+    default fn claimable_rewards(&self, asset: AccountId, account: AccountId) -> Balance {
+        self._claimable_rewards(asset, account)
+    }
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _initialize(&mut self, staking_token: AccountId) {
+        self.data::<Data>().staking_token = Some(staking_token);
+    }
+
+    default fn _stake(&mut self, account: AccountId, amount: Balance) -> Result<()> {
+        let staking_token = self._staking_token().ok_or(Error::StakingTokenIsNotSet)?;
+        if amount == 0 {
+            return Err(Error::AmountIsZero)
+        }
+        // rewards until now are earned by the stake before the change
+        self._accrue_rewards(account);
+        let balance = self._staked_balance_of(account);
+        let data = self.data::<Data>();
+        data.balances.insert(&account, &balance.add(amount));
+        data.total_staked = data.total_staked.add(amount);
+        PSP22Ref::transfer_from(
+            &staking_token,
+            account,
+            Self::env().account_id(),
+            amount,
+            Vec::<u8>::new(),
+        )?;
+        Ok(())
+    }
+
+    default fn _unstake(&mut self, account: AccountId, amount: Balance) -> Result<()> {
+        let staking_token = self._staking_token().ok_or(Error::StakingTokenIsNotSet)?;
+        if amount == 0 {
+            return Err(Error::AmountIsZero)
+        }
+        let balance = self._staked_balance_of(account);
+        if balance < amount {
+            return Err(Error::InsufficientStake)
+        }
+        self._accrue_rewards(account);
+        let data = self.data::<Data>();
+        data.balances.insert(&account, &balance.sub(amount));
+        data.total_staked = data.total_staked.sub(amount);
+        PSP22Ref::transfer(&staking_token, account, amount, Vec::<u8>::new())?;
+        Ok(())
+    }
+
+    default fn _accrue_rewards(&mut self, account: AccountId) {
+        for asset in self._reward_assets() {
+            let claimable = self._claimable_rewards(asset, account);
+            let index = WrappedU256::from(self._reward_index(asset));
+            let data = self.data::<Data>();
+            data.account_indexes.insert(&(asset, account), &index);
+            data.unclaimed_rewards.insert(&(asset, account), &claimable);
+        }
+    }
+
+    default fn _claim_rewards(&mut self, account: AccountId) -> Result<Vec<(AccountId, Balance)>> {
+        self._accrue_rewards(account);
+        let mut claimed = Vec::new();
+        for asset in self._reward_assets() {
+            let amount = self._claimable_rewards(asset, account);
+            if amount == 0 {
+                continue
+            }
+            let data = self.data::<Data>();
+            data.unclaimed_rewards.remove(&(asset, account));
+            let distributed = data.distributed_unclaimed.get(&asset).unwrap_or_default();
+            data.distributed_unclaimed
+                .insert(&asset, &distributed.sub(amount));
+            PSP22Ref::transfer(&asset, account, amount, Vec::<u8>::new())?;
+            self._emit_rewards_claimed_event(account, asset, amount);
+            claimed.push((asset, amount));
+        }
+        Ok(claimed)
+    }
+
+    default fn _distribute(&mut self, asset: AccountId) -> Result<Balance> {
+        if !self._reward_assets().contains(&asset) {
+            return Err(Error::RewardAssetIsNotAdded)
+        }
+        let total_staked = self._total_staked();
+        if total_staked == 0 {
+            return Err(Error::NoStakers)
+        }
+        let balance = PSP22Ref::balance_of(&asset, Self::env().account_id());
+        let amount = balance.saturating_sub(self._tracked_balance(asset));
+        if amount == 0 {
+            return Err(Error::NothingToDistribute)
+        }
+        let index = distributed_reward_index(self._reward_index(asset), amount, total_staked);
+        let data = self.data::<Data>();
+        data.reward_indexes
+            .insert(&asset, &WrappedU256::from(index));
+        let distributed = data.distributed_unclaimed.get(&asset).unwrap_or_default();
+        data.distributed_unclaimed
+            .insert(&asset, &distributed.add(amount));
+        Ok(amount)
+    }
+
+    default fn _add_reward_asset(&mut self, asset: AccountId) -> Result<()> {
+        if self._reward_assets().contains(&asset) {
+            return Err(Error::RewardAssetAlreadyAdded)
+        }
+        self.data::<Data>().reward_assets.push(asset);
+        Ok(())
+    }
+
+    default fn _staking_token(&self) -> Option<AccountId> {
+        self.data::<Data>().staking_token
+    }
+
+    default fn _staked_balance_of(&self, account: AccountId) -> Balance {
+        self.data::<Data>()
+            .balances
+            .get(&account)
+            .unwrap_or_default()
+    }
+
+    default fn _total_staked(&self) -> Balance {
+        self.data::<Data>().total_staked
+    }
+
+    default fn _reward_assets(&self) -> Vec<AccountId> {
+        self.data::<Data>().reward_assets.clone()
+    }
+
+    default fn _reward_index(&self, asset: AccountId) -> U256 {
+        self.data::<Data>()
+            .reward_indexes
+            .get(&asset)
+            .map(U256::from)
+            .unwrap_or_default()
+    }
+
+    default fn _claimable_rewards(&self, asset: AccountId, account: AccountId) -> Balance {
+        let data = self.data::<Data>();
+        let unclaimed = data
+            .unclaimed_rewards
+            .get(&(asset, account))
+            .unwrap_or_default();
+        let account_index = data
+            .account_indexes
+            .get(&(asset, account))
+            .map(U256::from)
+            .unwrap_or_default();
+        unclaimed.add(accrued_rewards(
+            self._staked_balance_of(account),
+            self._reward_index(asset),
+            account_index,
+        ))
+    }
+
+    default fn _tracked_balance(&self, asset: AccountId) -> Balance {
+        let distributed = self
+            .data::<Data>()
+            .distributed_unclaimed
+            .get(&asset)
+            .unwrap_or_default();
+        // LAY distributed as rewards is held along with the stakes
+        if self._staking_token() == Some(asset) {
+            return distributed.add(self._total_staked())
+        }
+        distributed
+    }
+
+    default fn _emit_staked_event(&self, _account: AccountId, _amount: Balance) {}
+    default fn _emit_unstaked_event(&self, _account: AccountId, _amount: Balance) {}
+    default fn _emit_rewards_distributed_event(
+        &self,
+        _asset: AccountId,
+        _amount: Balance,
+        _index: U256,
+    ) {
+    }
+    default fn _emit_rewards_claimed_event(
+        &self,
+        _account: AccountId,
+        _asset: AccountId,
+        _amount: Balance,
+    ) {
+    }
+    default fn _emit_reward_asset_added_event(&self, _asset: AccountId) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distributed_reward_index() {
+        let index = exp_scale();
+        // nothing is distributed without stakers
+        assert_eq!(distributed_reward_index(index, 100, 0), index);
+        assert_eq!(distributed_reward_index(index, 0, 100), index);
+        // 300 shared by 100 staked units: 3 per unit
+        assert_eq!(
+            distributed_reward_index(index, 300, 100),
+            index.add(exp_scale().mul(3))
+        );
+        // stakers earn their share of the distribution
+        let next = distributed_reward_index(U256::zero(), 300, 400);
+        assert_eq!(accrued_rewards(100, next, U256::zero()), 75);
+        assert_eq!(accrued_rewards(300, next, U256::zero()), 225);
+    }
+}
@@ -0,0 +1,237 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+pub use crate::traits::timelock::*;
+use ink::{
+    env::{
+        call::{
+            build_call,
+            Call,
+            ExecutionInput,
+            Selector,
+        },
+        hash::Blake2x256,
+        DefaultEnvironment,
+    },
+    prelude::{
+        vec,
+        vec::Vec,
+    },
+};
+use openbrush::{
+    contracts::ownable::{
+        self,
+        only_owner,
+    },
+    modifiers,
+    storage::Mapping,
+    traits::{
+        Storage,
+        Timestamp,
+    },
+};
+use scale::{
+    Decode,
+    Encode,
+    Input,
+    Output,
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+const DAY: Timestamp = 24 * 60 * 60 * 1000;
+pub const GRACE_PERIOD: Timestamp = 14 * DAY;
+pub const MINIMUM_DELAY: Timestamp = 2 * DAY;
+pub const MAXIMUM_DELAY: Timestamp = 30 * DAY;
+
+/// Only message of this contract a queued transaction can call on it
+const SET_DELAY_SELECTOR: [u8; 4] = ink::selector_bytes!("Timelock::set_delay");
+
+#[derive(Debug, Default)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// Least time between queueing a transaction and executing it
+    pub delay: Timestamp,
+    /// Transactions queued and not executed nor cancelled yet
+    pub queued: Mapping<TransactionId, bool>,
+}
+
+/// Arguments already encoded, pushed to the input as is
+struct RawInput<'a>(&'a [u8]);
+
+impl Encode for RawInput<'_> {
+    fn encode_to<T: Output + ?Sized>(&self, dest: &mut T) {
+        dest.write(self.0);
+    }
+}
+
+/// Return value left encoded, made of all remaining bytes of the output
+struct RawOutput(Vec<u8>);
+
+impl Decode for RawOutput {
+    fn decode<I: Input>(input: &mut I) -> core::result::Result<Self, scale::Error> {
+        let len = input
+            .remaining_len()?
+            .ok_or("Remaining length of the output is unknown")?;
+        let mut output = vec![0; len];
+        input.read(&mut output)?;
+        Ok(RawOutput(output))
+    }
+}
+
+pub trait Internal {
+    fn _initialize(&mut self, delay: Timestamp) -> Result<()>;
+    fn _delay(&self) -> Timestamp;
+    fn _is_queued(&self, id: TransactionId) -> bool;
+    fn _transaction_id(&self, transaction: &Transaction) -> TransactionId;
+    fn _set_delay(&mut self, delay: Timestamp) -> Result<()>;
+    fn _call(&self, transaction: &Transaction) -> Result<Vec<u8>>;
+    fn _call_self(&mut self, transaction: &Transaction) -> Result<Vec<u8>>;
+
+    // event emission
+    fn _emit_queue_transaction_event(&self, id: TransactionId, transaction: Transaction);
+    fn _emit_cancel_transaction_event(&self, id: TransactionId);
+    fn _emit_execute_transaction_event(&self, id: TransactionId);
+    fn _emit_new_delay_event(&self, delay: Timestamp);
+}
+
+impl<T: Storage<Data> + Storage<ownable::Data>> Timelock for T {
+    default fn delay(&self) -> Timestamp {
+        self._delay()
+    }
+
+    default fn grace_period(&self) -> Timestamp {
+        GRACE_PERIOD
+    }
+
+    default fn is_queued(&self, id: TransactionId) -> bool {
+        self._is_queued(id)
+    }
+
+    default fn transaction_id(&self, transaction: Transaction) -> TransactionId {
+        self._transaction_id(&transaction)
+    }
+
+    #[modifiers(only_owner)]
+    default fn queue_transaction(&mut self, transaction: Transaction) -> Result<TransactionId> {
+        if transaction.eta < Self::env().block_timestamp() + self._delay() {
+            return Err(Error::EtaBeforeDelay)
+        }
+        if transaction.target == Self::env().account_id()
+            && transaction.selector != SET_DELAY_SELECTOR
+        {
+            return Err(Error::UnsupportedSelfCall)
+        }
+        let id = self._transaction_id(&transaction);
+        self.data::<Data>().queued.insert(&id, &true);
+        self._emit_queue_transaction_event(id, transaction);
+        Ok(id)
+    }
+
+    #[modifiers(only_owner)]
+    default fn cancel_transaction(&mut self, transaction: Transaction) -> Result<()> {
+        let id = self._transaction_id(&transaction);
+        if !self._is_queued(id) {
+            return Err(Error::TransactionNotQueued)
+        }
+        self.data::<Data>().queued.remove(&id);
+        self._emit_cancel_transaction_event(id);
+        Ok(())
+    }
+
+    default fn execute_transaction(&mut self, transaction: Transaction) -> Result<Vec<u8>> {
+        let id = self._transaction_id(&transaction);
+        if !self._is_queued(id) {
+            return Err(Error::TransactionNotQueued)
+        }
+        let now = Self::env().block_timestamp();
+        if now < transaction.eta {
+            return Err(Error::TransactionNotReady)
+        }
+        if now > transaction.eta + GRACE_PERIOD {
+            return Err(Error::TransactionIsStale)
+        }
+
+        self.data::<Data>().queued.remove(&id);
+        let output = if transaction.target == Self::env().account_id() {
+            self._call_self(&transaction)?
+        } else {
+            self._call(&transaction)?
+        };
+        self._emit_execute_transaction_event(id);
+        Ok(output)
+    }
+
+    default fn set_delay(&mut self, delay: Timestamp) -> Result<()> {
+        if Self::env().caller() != Self::env().account_id() {
+            return Err(Error::CallerIsNotTimelock)
+        }
+        self._set_delay(delay)
+    }
+}
+
+impl<T: Storage<Data> + Storage<ownable::Data>> Internal for T {
+    default fn _initialize(&mut self, delay: Timestamp) -> Result<()> {
+        self._set_delay(delay)
+    }
+
+    default fn _delay(&self) -> Timestamp {
+        self.data::<Data>().delay
+    }
+
+    default fn _is_queued(&self, id: TransactionId) -> bool {
+        self.data::<Data>().queued.get(&id).unwrap_or(false)
+    }
+
+    default fn _transaction_id(&self, transaction: &Transaction) -> TransactionId {
+        let mut output = [0_u8; 32];
+        ink::env::hash_bytes::<Blake2x256>(&transaction.encode(), &mut output);
+        output
+    }
+
+    default fn _set_delay(&mut self, delay: Timestamp) -> Result<()> {
+        if !(MINIMUM_DELAY..=MAXIMUM_DELAY).contains(&delay) {
+            return Err(Error::DelayOutOfBounds)
+        }
+        self.data::<Data>().delay = delay;
+        self._emit_new_delay_event(delay);
+        Ok(())
+    }
+
+    default fn _call(&self, transaction: &Transaction) -> Result<Vec<u8>> {
+        let result = build_call::<DefaultEnvironment>()
+            .call_type(Call::new(transaction.target))
+            .exec_input(
+                ExecutionInput::new(Selector::new(transaction.selector))
+                    .push_arg(RawInput(&transaction.input)),
+            )
+            .returns::<RawOutput>()
+            .try_invoke();
+        match result {
+            Ok(Ok(RawOutput(output))) => Ok(output),
+            _ => Err(Error::TransactionFailed),
+        }
+    }
+
+    default fn _call_self(&mut self, transaction: &Transaction) -> Result<Vec<u8>> {
+        // applied here rather than by reentering this contract: the storage of the outer call
+        // would be written back when it ends, overwriting the changes of the inner one
+        if transaction.selector != SET_DELAY_SELECTOR {
+            return Err(Error::UnsupportedSelfCall)
+        }
+        let delay =
+            Timestamp::decode(&mut &transaction.input[..]).map_err(|_| Error::TransactionFailed)?;
+        self._set_delay(delay)?;
+        Ok(Vec::new())
+    }
+
+    default fn _emit_queue_transaction_event(&self, _id: TransactionId, _transaction: Transaction) {
+    }
+    default fn _emit_cancel_transaction_event(&self, _id: TransactionId) {}
+    default fn _emit_execute_transaction_event(&self, _id: TransactionId) {}
+    default fn _emit_new_delay_event(&self, _delay: Timestamp) {}
+}
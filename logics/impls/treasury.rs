@@ -0,0 +1,265 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+pub use crate::traits::treasury::*;
+use core::ops::{
+    Add,
+    Div,
+    Mul,
+    Sub,
+};
+use ink::prelude::vec::Vec;
+use openbrush::{
+    contracts::psp22::PSP22Ref,
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+        Timestamp,
+    },
+};
+use primitive_types::U256;
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Debug, Default)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// Budget streams by identifier
+    pub streams: Mapping<StreamId, Stream>,
+    /// Identifier of the next stream
+    pub next_stream_id: StreamId,
+    /// Amount of each asset committed to streams and not withdrawn yet
+    pub committed: Mapping<AccountId, Balance>,
+}
+
+/// Amount of `stream` streamed at `now`, including what has been withdrawn
+pub fn streamed_amount(stream: &Stream, now: Timestamp) -> Balance {
+    if now <= stream.start {
+        return 0
+    }
+    if now >= stream.end {
+        return stream.amount
+    }
+    U256::from(stream.amount)
+        .mul(U256::from(now.sub(stream.start)))
+        .div(U256::from(stream.end.sub(stream.start)))
+        .as_u128()
+}
+
+pub trait Internal {
+    fn _transfer(&mut self, asset: AccountId, to: AccountId, amount: Balance) -> Result<()>;
+    fn _create_stream(&mut self, stream: Stream) -> Result<StreamId>;
+    fn _cancel_stream(&mut self, stream_id: StreamId) -> Result<Balance>;
+    fn _withdraw_from_stream(&mut self, stream_id: StreamId) -> Result<Balance>;
+    /// Pays the amount of a stream withdrawable now to its recipient
+    fn _pay_stream(&mut self, stream_id: StreamId, stream: Stream) -> Result<Balance>;
+    fn _assert_uncommitted(&self, asset: AccountId, amount: Balance) -> Result<()>;
+    fn _add_committed(&mut self, asset: AccountId, amount: Balance);
+    fn _remove_committed(&mut self, asset: AccountId, amount: Balance);
+
+    // view functions
+    fn _stream(&self, stream_id: StreamId) -> Option<Stream>;
+    fn _withdrawable_amount(&self, stream_id: StreamId) -> Balance;
+    fn _committed_amount(&self, asset: AccountId) -> Balance;
+
+    // event emission
+    fn _emit_transfer_event(&self, asset: AccountId, to: AccountId, amount: Balance);
+    fn _emit_stream_created_event(&self, stream_id: StreamId, stream: Stream);
+    fn _emit_stream_canceled_event(&self, stream_id: StreamId, released: Balance);
+    fn _emit_stream_withdrawn_event(&self, stream_id: StreamId, amount: Balance);
+}
+
+impl<T: Storage<Data>> Treasury for T {
+    default fn transfer(&mut self, asset: AccountId, to: AccountId, amount: Balance) -> Result<()> {
+        self._transfer(asset, to, amount)?;
+        self._emit_transfer_event(asset, to, amount);
+        Ok(())
+    }
+
+    default fn create_stream(
+        &mut self,
+        recipient: AccountId,
+        asset: AccountId,
+        amount: Balance,
+        start: Timestamp,
+        end: Timestamp,
+    ) -> Result<StreamId> {
+        let stream = Stream {
+            recipient,
+            asset,
+            amount,
+            withdrawn: 0,
+            start,
+            end,
+        };
+        let stream_id = self._create_stream(stream)?;
+        self._emit_stream_created_event(stream_id, stream);
+        Ok(stream_id)
+    }
+
+    default fn cancel_stream(&mut self, stream_id: StreamId) -> Result<()> {
+        let released = self._cancel_stream(stream_id)?;
+        self._emit_stream_canceled_event(stream_id, released);
+        Ok(())
+    }
+
+    default fn withdraw_from_stream(&mut self, stream_id: StreamId) -> Result<Balance> {
+        let amount = self._withdraw_from_stream(stream_id)?;
+        self._emit_stream_withdrawn_event(stream_id, amount);
+        Ok(amount)
+    }
+
+    default fn stream(&self, stream_id: StreamId) -> Option<Stream> {
+        self._stream(stream_id)
+    }
+
+    default fn withdrawable_amount(&self, stream_id: StreamId) -> Balance {
+        self._withdrawable_amount(stream_id)
+    }
+
+    default fn committed_amount(&self, asset: AccountId) -> Balance {
+        self._committed_amount(asset)
+    }
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _transfer(
+        &mut self,
+        asset: AccountId,
+        to: AccountId,
+        amount: Balance,
+    ) -> Result<()> {
+        if amount == 0 {
+            return Err(Error::AmountIsZero)
+        }
+        self._assert_uncommitted(asset, amount)?;
+        PSP22Ref::transfer(&asset, to, amount, Vec::<u8>::new())?;
+        Ok(())
+    }
+
+    default fn _create_stream(&mut self, stream: Stream) -> Result<StreamId> {
+        if stream.amount == 0 {
+            return Err(Error::AmountIsZero)
+        }
+        if stream.end <= stream.start || stream.end <= Self::env().block_timestamp() {
+            return Err(Error::InvalidStreamPeriod)
+        }
+        // the budget has to be funded when the stream is created
+        self._assert_uncommitted(stream.asset, stream.amount)?;
+        self._add_committed(stream.asset, stream.amount);
+        let data = self.data::<Data>();
+        let stream_id = data.next_stream_id;
+        data.streams.insert(&stream_id, &stream);
+        data.next_stream_id = stream_id.add(1);
+        Ok(stream_id)
+    }
+
+    default fn _cancel_stream(&mut self, stream_id: StreamId) -> Result<Balance> {
+        let stream = self._stream(stream_id).ok_or(Error::StreamDoesNotExist)?;
+        self._pay_stream(stream_id, stream)?;
+        let stream = self._stream(stream_id).ok_or(Error::StreamDoesNotExist)?;
+        let released = stream.amount.sub(stream.withdrawn);
+        self._remove_committed(stream.asset, released);
+        self.data::<Data>().streams.remove(&stream_id);
+        Ok(released)
+    }
+
+    default fn _withdraw_from_stream(&mut self, stream_id: StreamId) -> Result<Balance> {
+        let stream = self._stream(stream_id).ok_or(Error::StreamDoesNotExist)?;
+        if Self::env().caller() != stream.recipient {
+            return Err(Error::CallerIsNotRecipient)
+        }
+        let amount = self._pay_stream(stream_id, stream)?;
+        if amount == 0 {
+            return Err(Error::NothingToWithdraw)
+        }
+        Ok(amount)
+    }
+
+    default fn _pay_stream(&mut self, stream_id: StreamId, mut stream: Stream) -> Result<Balance> {
+        let amount = streamed_amount(&stream, Self::env().block_timestamp()).sub(stream.withdrawn);
+        if amount == 0 {
+            return Ok(0)
+        }
+        stream.withdrawn = stream.withdrawn.add(amount);
+        self.data::<Data>().streams.insert(&stream_id, &stream);
+        self._remove_committed(stream.asset, amount);
+        PSP22Ref::transfer(&stream.asset, stream.recipient, amount, Vec::<u8>::new())?;
+        Ok(amount)
+    }
+
+    default fn _assert_uncommitted(&self, asset: AccountId, amount: Balance) -> Result<()> {
+        let balance = PSP22Ref::balance_of(&asset, Self::env().account_id());
+        if balance.saturating_sub(self._committed_amount(asset)) < amount {
+            return Err(Error::InsufficientUncommittedBalance)
+        }
+        Ok(())
+    }
+
+    default fn _add_committed(&mut self, asset: AccountId, amount: Balance) {
+        let committed = self._committed_amount(asset);
+        self.data::<Data>()
+            .committed
+            .insert(&asset, &committed.add(amount));
+    }
+
+    default fn _remove_committed(&mut self, asset: AccountId, amount: Balance) {
+        let committed = self._committed_amount(asset);
+        self.data::<Data>()
+            .committed
+            .insert(&asset, &committed.saturating_sub(amount));
+    }
+
+    default fn _stream(&self, stream_id: StreamId) -> Option<Stream> {
+        self.data::<Data>().streams.get(&stream_id)
+    }
+
+    default fn _withdrawable_amount(&self, stream_id: StreamId) -> Balance {
+        match self._stream(stream_id) {
+            Some(stream) => {
+                streamed_amount(&stream, Self::env().block_timestamp()).sub(stream.withdrawn)
+            }
+            None => 0,
+        }
+    }
+
+    default fn _committed_amount(&self, asset: AccountId) -> Balance {
+        self.data::<Data>()
+            .committed
+            .get(&asset)
+            .unwrap_or_default()
+    }
+
+    default fn _emit_transfer_event(&self, _asset: AccountId, _to: AccountId, _amount: Balance) {}
+    default fn _emit_stream_created_event(&self, _stream_id: StreamId, _stream: Stream) {}
+    default fn _emit_stream_canceled_event(&self, _stream_id: StreamId, _released: Balance) {}
+    default fn _emit_stream_withdrawn_event(&self, _stream_id: StreamId, _amount: Balance) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_streamed_amount() {
+        let stream = Stream {
+            recipient: AccountId::from([0x01; 32]),
+            asset: AccountId::from([0x02; 32]),
+            amount: 1000,
+            withdrawn: 0,
+            start: 100,
+            end: 200,
+        };
+        assert_eq!(streamed_amount(&stream, 0), 0);
+        assert_eq!(streamed_amount(&stream, 100), 0);
+        assert_eq!(streamed_amount(&stream, 125), 250);
+        assert_eq!(streamed_amount(&stream, 200), 1000);
+        assert_eq!(streamed_amount(&stream, 300), 1000);
+    }
+}
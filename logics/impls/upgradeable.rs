@@ -0,0 +1,136 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+pub use crate::traits::upgradeable::*;
+use ink::storage::traits::Storable;
+use openbrush::traits::{
+    Hash,
+    Storage,
+};
+use scale::Encode;
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Debug, Default)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// Version of the layout the storage is in
+    pub version: u32,
+}
+
+/// Rewrites the value stored at `key` in the `Old` layout to the `New` layout.
+/// The entry of a `Mapping` is stored at `(root key, key of the entry)`.
+/// Returns false if nothing is stored at `key`.
+pub fn migrate_value<K, Old, New, F>(key: &K, transform: F) -> bool
+where
+    K: Encode,
+    Old: Storable,
+    New: Storable,
+    F: FnOnce(Old) -> New,
+{
+    match ink::env::get_contract_storage::<K, Old>(key) {
+        Ok(Some(old)) => {
+            ink::env::set_contract_storage(key, &transform(old));
+            true
+        }
+        _ => false,
+    }
+}
+
+/// Contracts override `_assert_upgrade_admin` with their own admin check
+/// and `_code_version` with the storage version their code is built for.
+/// Code bumping the version overrides `_migrate_step` with the transformation from each
+/// previous version, reading the old layout with `migrate_value`.
+pub trait Internal {
+    fn _assert_upgrade_admin(&self) -> Result<()>;
+    fn _code_version(&self) -> u32;
+    fn _version(&self) -> u32;
+    fn _set_version(&mut self, version: u32);
+    fn _upgrade_code(&mut self, new_code_hash: Hash) -> Result<()>;
+    fn _migrate(&mut self) -> Result<()>;
+    /// Transforms the storage in the layout of `from` to the layout of `from + 1`
+    fn _migrate_step(&mut self, from: u32) -> Result<()>;
+
+    // event emission
+    fn _emit_code_upgraded_event(&self, code_hash: Hash, version: u32);
+    fn _emit_migrated_event(&self, from: u32, to: u32);
+}
+
+impl<T: Storage<Data>> Upgradeable for T {
+    default fn upgrade_code(&mut self, new_code_hash: Hash) -> Result<()> {
+        self._assert_upgrade_admin()?;
+        self._upgrade_code(new_code_hash)
+    }
+
+    default fn migrate(&mut self) -> Result<()> {
+        self._migrate()
+    }
+
+    default fn version(&self) -> u32 {
+        self._version()
+    }
+
+    default fn code_version(&self) -> u32 {
+        self._code_version()
+    }
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _assert_upgrade_admin(&self) -> Result<()> {
+        Err(Error::CallerIsNotAdmin)
+    }
+
+    default fn _code_version(&self) -> u32 {
+        0
+    }
+
+    default fn _version(&self) -> u32 {
+        self.data().version
+    }
+
+    default fn _set_version(&mut self, version: u32) {
+        self.data().version = version;
+    }
+
+    default fn _upgrade_code(&mut self, new_code_hash: Hash) -> Result<()> {
+        // the storage of a previous upgrade has to be migrated before the next one
+        let version = self._version();
+        if version != self._code_version() {
+            return Err(Error::VersionMismatch)
+        }
+        Self::env()
+            .set_code_hash(&new_code_hash)
+            .map_err(|_| Error::SetCodeHashFailed)?;
+        self._emit_code_upgraded_event(new_code_hash, version);
+        Ok(())
+    }
+
+    default fn _migrate(&mut self) -> Result<()> {
+        let from = self._version();
+        let to = self._code_version();
+        if from > to {
+            return Err(Error::VersionMismatch)
+        }
+        if from == to {
+            return Ok(())
+        }
+        for version in from..to {
+            self._migrate_step(version)?;
+        }
+        self._set_version(to);
+        self._emit_migrated_event(from, to);
+        Ok(())
+    }
+
+    default fn _migrate_step(&mut self, _from: u32) -> Result<()> {
+        Err(Error::MigrationNotFound)
+    }
+
+    default fn _emit_code_upgraded_event(&self, _code_hash: Hash, _version: u32) {}
+
+    default fn _emit_migrated_event(&self, _from: u32, _to: u32) {}
+}
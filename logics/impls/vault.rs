@@ -0,0 +1,189 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::{
+    exp_no_err::{
+        exp_scale,
+        Exp,
+    },
+    pool::{
+        self,
+        utils::{
+            from_scaled_amount,
+            scaled_amount_of,
+        },
+    },
+};
+pub use crate::traits::vault::*;
+use core::ops::{
+    Add,
+    Div,
+    Mul,
+    Sub,
+};
+use openbrush::{
+    contracts::psp22,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+    },
+};
+use primitive_types::U256;
+
+pub trait Internal {
+    fn _exchange_rate(&self) -> Exp;
+    fn _shares_of(&self, owner: AccountId) -> Balance;
+    /// Assets needed for `shares`, rounded up so the vault never mints shares for free
+    fn _assets_rounded_up(&self, shares: Balance) -> Balance;
+    /// Shares needed for `assets`, rounded up so the vault never sends assets for free
+    fn _shares_rounded_up(&self, assets: Balance) -> Balance;
+}
+
+impl<T> Vault for T
+where
+    T: Storage<pool::Data> + Storage<psp22::Data> + Storage<psp22::extensions::metadata::Data>,
+{
+    default fn asset(&self) -> Option<AccountId> {
+        pool::Internal::_underlying(self)
+    }
+
+    default fn total_assets(&self) -> Balance {
+        pool::Internal::_get_cash_prior(self)
+            .add(pool::Internal::_total_borrows(self))
+            .saturating_sub(pool::Internal::_total_reserves(self))
+    }
+
+    default fn convert_to_shares(&self, assets: Balance) -> Balance {
+        scaled_amount_of(assets, self._exchange_rate()).unwrap_or(Balance::MAX)
+    }
+
+    default fn convert_to_assets(&self, shares: Balance) -> Balance {
+        from_scaled_amount(shares, self._exchange_rate()).unwrap_or(Balance::MAX)
+    }
+
+    default fn max_deposit(&self, receiver: AccountId) -> Balance {
+        let cap = pool::Internal::_account_supply_cap(self);
+        if cap == 0 {
+            return Balance::MAX
+        }
+        cap.saturating_sub(pool::Internal::_balance_of_underlying(self, receiver))
+    }
+
+    default fn preview_deposit(&self, assets: Balance) -> Balance {
+        self.convert_to_shares(assets)
+    }
+
+    default fn deposit(&mut self, assets: Balance, receiver: AccountId) -> Result<Balance> {
+        pool::Internal::_accrue_interest(self)?;
+        let shares_prev = self._shares_of(receiver);
+        pool::Internal::_mint(self, receiver, assets)?;
+        Ok(self._shares_of(receiver).sub(shares_prev))
+    }
+
+    default fn max_mint(&self, receiver: AccountId) -> Balance {
+        let max_assets = self.max_deposit(receiver);
+        if max_assets == Balance::MAX {
+            return Balance::MAX
+        }
+        self.convert_to_shares(max_assets)
+    }
+
+    default fn preview_mint(&self, shares: Balance) -> Balance {
+        self._assets_rounded_up(shares)
+    }
+
+    default fn mint(&mut self, shares: Balance, receiver: AccountId) -> Result<Balance> {
+        pool::Internal::_accrue_interest(self)?;
+        let assets = self._assets_rounded_up(shares);
+        pool::Internal::_mint(self, receiver, assets)?;
+        Ok(assets)
+    }
+
+    default fn max_withdraw(&self, owner: AccountId) -> Balance {
+        pool::Internal::_balance_of_underlying(self, owner)
+            .min(pool::Internal::_get_cash_prior(self))
+    }
+
+    default fn preview_withdraw(&self, assets: Balance) -> Balance {
+        self._shares_rounded_up(assets)
+    }
+
+    default fn withdraw(
+        &mut self,
+        assets: Balance,
+        receiver: AccountId,
+        owner: AccountId,
+    ) -> Result<Balance> {
+        if Self::env().caller() != owner {
+            return Err(Error::CallerIsNotOwner)
+        }
+        pool::Internal::_accrue_interest(self)?;
+        let shares_prev = self._shares_of(owner);
+        pool::Internal::_redeem_to(self, owner, receiver, assets)?;
+        Ok(shares_prev.sub(self._shares_of(owner)))
+    }
+
+    default fn max_redeem(&self, owner: AccountId) -> Balance {
+        self._shares_of(owner)
+    }
+
+    default fn preview_redeem(&self, shares: Balance) -> Balance {
+        self.convert_to_assets(shares)
+    }
+
+    default fn redeem(
+        &mut self,
+        shares: Balance,
+        receiver: AccountId,
+        owner: AccountId,
+    ) -> Result<Balance> {
+        if Self::env().caller() != owner {
+            return Err(Error::CallerIsNotOwner)
+        }
+        pool::Internal::_accrue_interest(self)?;
+        let assets = self.convert_to_assets(shares);
+        pool::Internal::_redeem_to(self, owner, receiver, assets)?;
+        Ok(assets)
+    }
+}
+
+impl<T> Internal for T
+where
+    T: Storage<pool::Data> + Storage<psp22::Data> + Storage<psp22::extensions::metadata::Data>,
+{
+    default fn _exchange_rate(&self) -> Exp {
+        Exp {
+            mantissa: pool::Internal::_exchange_rate_stored(self).into(),
+        }
+    }
+
+    default fn _shares_of(&self, owner: AccountId) -> Balance {
+        pool::Internal::_principal_balance_of(self, &owner)
+    }
+
+    default fn _assets_rounded_up(&self, shares: Balance) -> Balance {
+        let rate = U256::from(self._exchange_rate().mantissa);
+        U256::from(shares)
+            .mul(rate)
+            .add(exp_scale().sub(1))
+            .div(exp_scale())
+            .as_u128()
+    }
+
+    default fn _shares_rounded_up(&self, assets: Balance) -> Balance {
+        let rate = U256::from(self._exchange_rate().mantissa);
+        if rate.is_zero() {
+            return 0
+        }
+        U256::from(assets)
+            .mul(exp_scale())
+            .add(rate.sub(1))
+            .div(rate)
+            .as_u128()
+    }
+}
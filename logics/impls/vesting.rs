@@ -0,0 +1,288 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+pub use crate::traits::vesting::*;
+use core::ops::{
+    Add,
+    Div,
+    Mul,
+    Sub,
+};
+use ink::prelude::vec::Vec;
+use openbrush::{
+    contracts::{
+        ownable::{
+            self,
+            only_owner,
+        },
+        psp22::PSP22Ref,
+    },
+    modifiers,
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+        Timestamp,
+    },
+};
+use primitive_types::U256;
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Debug, Default)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// AccountId of the vested token
+    pub token: Option<AccountId>,
+    /// Schedule of each beneficiary
+    pub schedules: Mapping<AccountId, VestingSchedule>,
+    /// Beneficiaries with a schedule
+    pub beneficiaries: Vec<AccountId>,
+}
+
+/// Amount of `schedule` vested at `now`, ignoring its revocation
+pub fn vested_amount(schedule: &VestingSchedule, now: Timestamp) -> Balance {
+    if now < schedule.start.add(schedule.cliff) {
+        return 0
+    }
+    let elapsed = now.sub(schedule.start);
+    if elapsed >= schedule.duration {
+        return schedule.amount
+    }
+    U256::from(schedule.amount)
+        .mul(U256::from(elapsed))
+        .div(U256::from(schedule.duration))
+        .as_u128()
+}
+
+pub trait Internal {
+    fn _initialize(&mut self, token: AccountId);
+    fn _create_vesting(
+        &mut self,
+        funder: AccountId,
+        beneficiary: AccountId,
+        schedule: VestingSchedule,
+    ) -> Result<()>;
+    fn _revoke(&mut self, beneficiary: AccountId, refund_to: AccountId) -> Result<Balance>;
+    fn _claim(&mut self, beneficiary: AccountId) -> Result<Balance>;
+
+    // view functions
+    fn _token(&self) -> Option<AccountId>;
+    fn _vesting_of(&self, beneficiary: AccountId) -> Option<VestingSchedule>;
+    fn _releasable_amount(&self, beneficiary: AccountId) -> Balance;
+    fn _beneficiaries(&self) -> Vec<AccountId>;
+
+    // event emission
+    fn _emit_vesting_created_event(&self, beneficiary: AccountId, schedule: VestingSchedule);
+    fn _emit_vesting_revoked_event(&self, beneficiary: AccountId, refund: Balance);
+    fn _emit_claimed_event(&self, beneficiary: AccountId, amount: Balance);
+}
+
+impl<T: Storage<Data> + Storage<ownable::Data>> Vesting for T {
+    #[modifiers(only_owner)]
+    default fn create_vesting(
+        &mut self,
+        beneficiary: AccountId,
+        amount: Balance,
+        start: Timestamp,
+        cliff: Timestamp,
+        duration: Timestamp,
+        revocable: bool,
+    ) -> Result<()> {
+        let schedule = VestingSchedule {
+            amount,
+            released: 0,
+            start,
+            cliff,
+            duration,
+            revocable,
+            revoked: false,
+        };
+        self._create_vesting(Self::env().caller(), beneficiary, schedule)?;
+        self._emit_vesting_created_event(beneficiary, schedule);
+        Ok(())
+    }
+
+    #[modifiers(only_owner)]
+    default fn revoke(&mut self, beneficiary: AccountId) -> Result<Balance> {
+        let refund = self._revoke(beneficiary, Self::env().caller())?;
+        self._emit_vesting_revoked_event(beneficiary, refund);
+        Ok(refund)
+    }
+
+    default fn claim(&mut self) -> Result<Balance> {
+        let caller = Self::env().caller();
+        let amount = self._claim(caller)?;
+        self._emit_claimed_event(caller, amount);
+        Ok(amount)
+    }
+
+    default fn vesting_of(&self, beneficiary: AccountId) -> Option<VestingSchedule> {
+        self._vesting_of(beneficiary)
+    }
+
+    default fn releasable_amount(&self, beneficiary: AccountId) -> Balance {
+        self._releasable_amount(beneficiary)
+    }
+
+    default fn beneficiaries(&self) -> Vec<AccountId> {
+        self._beneficiaries()
+    }
+
+    default fn token(&self) -> Option<AccountId> {
+        self._token()
+    }
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _initialize(&mut self, token: AccountId) {
+        self.data::<Data>().token = Some(token);
+    }
+
+    default fn _create_vesting(
+        &mut self,
+        funder: AccountId,
+        beneficiary: AccountId,
+        schedule: VestingSchedule,
+    ) -> Result<()> {
+        let token = self._token().ok_or(Error::TokenIsNotSet)?;
+        if schedule.amount == 0 {
+            return Err(Error::AmountIsZero)
+        }
+        if schedule.duration == 0 {
+            return Err(Error::DurationIsZero)
+        }
+        if schedule.cliff > schedule.duration {
+            return Err(Error::CliffIsLongerThanDuration)
+        }
+        if self._vesting_of(beneficiary).is_some() {
+            return Err(Error::VestingAlreadyExists)
+        }
+        let data = self.data::<Data>();
+        data.schedules.insert(&beneficiary, &schedule);
+        data.beneficiaries.push(beneficiary);
+        PSP22Ref::transfer_from(
+            &token,
+            funder,
+            Self::env().account_id(),
+            schedule.amount,
+            Vec::<u8>::new(),
+        )?;
+        Ok(())
+    }
+
+    default fn _revoke(&mut self, beneficiary: AccountId, refund_to: AccountId) -> Result<Balance> {
+        let token = self._token().ok_or(Error::TokenIsNotSet)?;
+        let mut schedule = self
+            ._vesting_of(beneficiary)
+            .ok_or(Error::VestingDoesNotExist)?;
+        if !schedule.revocable {
+            return Err(Error::VestingIsNotRevocable)
+        }
+        if schedule.revoked {
+            return Err(Error::VestingIsRevoked)
+        }
+        // the schedule ends with what is vested now
+        let vested = vested_amount(&schedule, Self::env().block_timestamp());
+        let refund = schedule.amount.sub(vested);
+        schedule.amount = vested;
+        schedule.revoked = true;
+        self.data::<Data>()
+            .schedules
+            .insert(&beneficiary, &schedule);
+        if refund > 0 {
+            PSP22Ref::transfer(&token, refund_to, refund, Vec::<u8>::new())?;
+        }
+        Ok(refund)
+    }
+
+    default fn _claim(&mut self, beneficiary: AccountId) -> Result<Balance> {
+        let token = self._token().ok_or(Error::TokenIsNotSet)?;
+        let mut schedule = self
+            ._vesting_of(beneficiary)
+            .ok_or(Error::VestingDoesNotExist)?;
+        let amount = self._releasable_amount(beneficiary);
+        if amount == 0 {
+            return Err(Error::NothingToClaim)
+        }
+        schedule.released = schedule.released.add(amount);
+        self.data::<Data>()
+            .schedules
+            .insert(&beneficiary, &schedule);
+        PSP22Ref::transfer(&token, beneficiary, amount, Vec::<u8>::new())?;
+        Ok(amount)
+    }
+
+    default fn _token(&self) -> Option<AccountId> {
+        self.data::<Data>().token
+    }
+
+    default fn _vesting_of(&self, beneficiary: AccountId) -> Option<VestingSchedule> {
+        self.data::<Data>().schedules.get(&beneficiary)
+    }
+
+    default fn _releasable_amount(&self, beneficiary: AccountId) -> Balance {
+        match self._vesting_of(beneficiary) {
+            // a revoked schedule is fully vested at its reduced amount
+            Some(schedule) if schedule.revoked => schedule.amount.sub(schedule.released),
+            Some(schedule) => {
+                vested_amount(&schedule, Self::env().block_timestamp()).sub(schedule.released)
+            }
+            None => 0,
+        }
+    }
+
+    default fn _beneficiaries(&self) -> Vec<AccountId> {
+        self.data::<Data>().beneficiaries.clone()
+    }
+
+    default fn _emit_vesting_created_event(
+        &self,
+        _beneficiary: AccountId,
+        _schedule: VestingSchedule,
+    ) {
+    }
+    default fn _emit_vesting_revoked_event(&self, _beneficiary: AccountId, _refund: Balance) {}
+    default fn _emit_claimed_event(&self, _beneficiary: AccountId, _amount: Balance) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(start: Timestamp, cliff: Timestamp, duration: Timestamp) -> VestingSchedule {
+        VestingSchedule {
+            amount: 1000,
+            start,
+            cliff,
+            duration,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_vested_amount() {
+        let schedule = schedule(100, 25, 100);
+        // nothing before the start and the cliff
+        assert_eq!(vested_amount(&schedule, 0), 0);
+        assert_eq!(vested_amount(&schedule, 124), 0);
+        // linearly from the start once past the cliff
+        assert_eq!(vested_amount(&schedule, 125), 250);
+        assert_eq!(vested_amount(&schedule, 150), 500);
+        assert_eq!(vested_amount(&schedule, 200), 1000);
+        assert_eq!(vested_amount(&schedule, 1000), 1000);
+    }
+
+    #[test]
+    fn test_vested_amount_without_cliff() {
+        let schedule = schedule(100, 0, 100);
+        assert_eq!(vested_amount(&schedule, 99), 0);
+        assert_eq!(vested_amount(&schedule, 100), 0);
+        assert_eq!(vested_amount(&schedule, 101), 10);
+    }
+}
@@ -0,0 +1,356 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use crate::traits::types::WrappedU256;
+pub use crate::traits::vote_escrow::*;
+use core::ops::{
+    Add,
+    Div,
+    Mul,
+    Sub,
+};
+use ink::prelude::vec::Vec;
+use openbrush::{
+    contracts::psp22::PSP22Ref,
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+        Timestamp,
+    },
+};
+use primitive_types::U256;
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+/// Most weeks walked by a checkpoint, longer than the longest lock
+const MAX_CHECKPOINT_WEEKS: usize = 255;
+
+#[derive(Debug, Default)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// AccountId of the locked token
+    pub token: Option<AccountId>,
+    /// Lock of each account
+    pub locks: Mapping<AccountId, LockedBalance>,
+    /// Sum of amounts of locks not expired at the last checkpoint
+    pub locked_amount: Balance,
+    /// Sum of amounts times unlock times of locks not expired at the last checkpoint
+    pub locked_amount_end: WrappedU256,
+    /// Sum of amounts of locks unlocking at each week
+    pub expiring_amounts: Mapping<Timestamp, Balance>,
+    /// Time of the last checkpoint
+    pub last_checkpoint: Timestamp,
+}
+
+/// `time` rounded down to a whole week
+pub fn floor_week(time: Timestamp) -> Timestamp {
+    time / WEEK * WEEK
+}
+
+/// veLAY balance of a lock of `amount` until `end` at `now`
+pub fn voting_power(amount: Balance, end: Timestamp, now: Timestamp) -> Balance {
+    if end <= now {
+        return 0
+    }
+    U256::from(amount)
+        .mul(U256::from(end - now))
+        .div(U256::from(MAX_LOCK_DURATION))
+        .as_u128()
+}
+
+/// Sum of veLAY balances at `now` of locks summing `amount` and `amount_end`
+pub fn total_voting_power(amount: Balance, amount_end: U256, now: Timestamp) -> Balance {
+    amount_end
+        .saturating_sub(U256::from(amount).mul(U256::from(now)))
+        .div(U256::from(MAX_LOCK_DURATION))
+        .as_u128()
+}
+
+pub trait Internal {
+    fn _initialize(&mut self, token: AccountId);
+    /// Removes the locks expired until `now` from the sums
+    fn _checkpoint(&mut self, now: Timestamp);
+    /// Sums of locks not expired at `now`
+    fn _locked_sums_at(&self, now: Timestamp) -> (Balance, U256);
+    fn _add_to_sums(&mut self, amount: Balance, end: Timestamp);
+    fn _remove_from_sums(&mut self, amount: Balance, end: Timestamp);
+    fn _deposit(
+        &mut self,
+        account: AccountId,
+        amount: Balance,
+        unlock_time: Option<Timestamp>,
+    ) -> Result<LockedBalance>;
+    fn _validate_unlock_time(&self, unlock_time: Timestamp) -> Result<Timestamp>;
+    fn _withdraw(&mut self, account: AccountId) -> Result<Balance>;
+
+    // view functions
+    fn _token(&self) -> Option<AccountId>;
+    fn _locked(&self, account: AccountId) -> LockedBalance;
+    fn _balance_of(&self, account: AccountId) -> Balance;
+    fn _total_supply(&self) -> Balance;
+
+    // event emission
+    fn _emit_locked_event(&self, account: AccountId, amount: Balance, end: Timestamp);
+    fn _emit_withdrawn_event(&self, account: AccountId, amount: Balance);
+}
+
+impl<T: Storage<Data>> VoteEscrow for T {
+    default fn create_lock(&mut self, amount: Balance, unlock_time: Timestamp) -> Result<()> {
+        let caller = Self::env().caller();
+        if self._locked(caller).amount > 0 {
+            return Err(Error::LockAlreadyExists)
+        }
+        let lock = self._deposit(caller, amount, Some(unlock_time))?;
+        self._emit_locked_event(caller, lock.amount, lock.end);
+        Ok(())
+    }
+
+    default fn increase_amount(&mut self, amount: Balance) -> Result<()> {
+        let caller = Self::env().caller();
+        if self._locked(caller).amount == 0 {
+            return Err(Error::LockDoesNotExist)
+        }
+        let lock = self._deposit(caller, amount, None)?;
+        self._emit_locked_event(caller, lock.amount, lock.end);
+        Ok(())
+    }
+
+    default fn increase_unlock_time(&mut self, unlock_time: Timestamp) -> Result<()> {
+        let caller = Self::env().caller();
+        if self._locked(caller).amount == 0 {
+            return Err(Error::LockDoesNotExist)
+        }
+        let lock = self._deposit(caller, 0, Some(unlock_time))?;
+        self._emit_locked_event(caller, lock.amount, lock.end);
+        Ok(())
+    }
+
+    default fn withdraw(&mut self) -> Result<Balance> {
+        let caller = Self::env().caller();
+        let amount = self._withdraw(caller)?;
+        self._emit_withdrawn_event(caller, amount);
+        Ok(amount)
+    }
+
+    default fn balance_of(&self, account: AccountId) -> Balance {
+        self._balance_of(account)
+    }
+
+    default fn total_supply(&self) -> Balance {
+        self._total_supply()
+    }
+
+    default fn locked(&self, account: AccountId) -> LockedBalance {
+        self._locked(account)
+    }
+
+    default fn token(&self) -> Option<AccountId> {
+        self._token()
+    }
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _initialize(&mut self, token: AccountId) {
+        let data = self.data::<Data>();
+        data.token = Some(token);
+        data.last_checkpoint = Self::env().block_timestamp();
+    }
+
+    default fn _checkpoint(&mut self, now: Timestamp) {
+        let (amount, amount_end) = self._locked_sums_at(now);
+        let data = self.data::<Data>();
+        data.locked_amount = amount;
+        data.locked_amount_end = WrappedU256::from(amount_end);
+        data.last_checkpoint = now;
+    }
+
+    default fn _locked_sums_at(&self, now: Timestamp) -> (Balance, U256) {
+        let data = self.data::<Data>();
+        let mut amount = data.locked_amount;
+        let mut amount_end = U256::from(data.locked_amount_end);
+        let mut week = floor_week(data.last_checkpoint).add(WEEK);
+        for _ in 0..MAX_CHECKPOINT_WEEKS {
+            if week > now {
+                break
+            }
+            let expiring = data.expiring_amounts.get(&week).unwrap_or_default();
+            amount = amount.saturating_sub(expiring);
+            amount_end = amount_end.saturating_sub(U256::from(expiring).mul(U256::from(week)));
+            week = week.add(WEEK);
+        }
+        (amount, amount_end)
+    }
+
+    default fn _add_to_sums(&mut self, amount: Balance, end: Timestamp) {
+        let data = self.data::<Data>();
+        data.locked_amount = data.locked_amount.add(amount);
+        data.locked_amount_end = WrappedU256::from(
+            U256::from(data.locked_amount_end).add(U256::from(amount).mul(U256::from(end))),
+        );
+        let expiring = data.expiring_amounts.get(&end).unwrap_or_default();
+        data.expiring_amounts.insert(&end, &expiring.add(amount));
+    }
+
+    default fn _remove_from_sums(&mut self, amount: Balance, end: Timestamp) {
+        let data = self.data::<Data>();
+        data.locked_amount = data.locked_amount.saturating_sub(amount);
+        data.locked_amount_end = WrappedU256::from(
+            U256::from(data.locked_amount_end)
+                .saturating_sub(U256::from(amount).mul(U256::from(end))),
+        );
+        let expiring = data.expiring_amounts.get(&end).unwrap_or_default();
+        data.expiring_amounts
+            .insert(&end, &expiring.saturating_sub(amount));
+    }
+
+    default fn _deposit(
+        &mut self,
+        account: AccountId,
+        amount: Balance,
+        unlock_time: Option<Timestamp>,
+    ) -> Result<LockedBalance> {
+        let token = self._token().ok_or(Error::TokenIsNotSet)?;
+        let now = Self::env().block_timestamp();
+        let old = self._locked(account);
+        let creating = old.amount == 0;
+        if !creating && old.end <= now {
+            return Err(Error::LockIsExpired)
+        }
+        let end = match unlock_time {
+            Some(unlock_time) => {
+                let end = self._validate_unlock_time(unlock_time)?;
+                if !creating && end <= old.end {
+                    return Err(Error::UnlockTimeIsNotIncreased)
+                }
+                end
+            }
+            None => old.end,
+        };
+        // only the unlock time of an existing lock can be changed alone
+        if amount == 0 && (creating || unlock_time.is_none()) {
+            return Err(Error::AmountIsZero)
+        }
+
+        self._checkpoint(now);
+        if !creating {
+            self._remove_from_sums(old.amount, old.end);
+        }
+        let new = LockedBalance {
+            amount: old.amount.add(amount),
+            end,
+        };
+        self._add_to_sums(new.amount, new.end);
+        self.data::<Data>().locks.insert(&account, &new);
+
+        if amount > 0 {
+            PSP22Ref::transfer_from(
+                &token,
+                account,
+                Self::env().account_id(),
+                amount,
+                Vec::<u8>::new(),
+            )?;
+        }
+        Ok(new)
+    }
+
+    default fn _validate_unlock_time(&self, unlock_time: Timestamp) -> Result<Timestamp> {
+        let now = Self::env().block_timestamp();
+        let end = floor_week(unlock_time);
+        if end <= now || unlock_time.sub(now) < WEEK {
+            return Err(Error::UnlockTimeIsTooShort)
+        }
+        if end.sub(now) > MAX_LOCK_DURATION {
+            return Err(Error::UnlockTimeIsTooLong)
+        }
+        Ok(end)
+    }
+
+    default fn _withdraw(&mut self, account: AccountId) -> Result<Balance> {
+        let token = self._token().ok_or(Error::TokenIsNotSet)?;
+        let lock = self._locked(account);
+        if lock.amount == 0 {
+            return Err(Error::LockDoesNotExist)
+        }
+        let now = Self::env().block_timestamp();
+        if lock.end > now {
+            return Err(Error::LockIsNotExpired)
+        }
+        // the expired lock leaves the sums at the checkpoint
+        self._checkpoint(now);
+        self.data::<Data>().locks.remove(&account);
+        PSP22Ref::transfer(&token, account, lock.amount, Vec::<u8>::new())?;
+        Ok(lock.amount)
+    }
+
+    default fn _token(&self) -> Option<AccountId> {
+        self.data::<Data>().token
+    }
+
+    default fn _locked(&self, account: AccountId) -> LockedBalance {
+        self.data::<Data>().locks.get(&account).unwrap_or_default()
+    }
+
+    default fn _balance_of(&self, account: AccountId) -> Balance {
+        let lock = self._locked(account);
+        voting_power(lock.amount, lock.end, Self::env().block_timestamp())
+    }
+
+    default fn _total_supply(&self) -> Balance {
+        let now = Self::env().block_timestamp();
+        let (amount, amount_end) = self._locked_sums_at(now);
+        total_voting_power(amount, amount_end, now)
+    }
+
+    default fn _emit_locked_event(&self, _account: AccountId, _amount: Balance, _end: Timestamp) {}
+    default fn _emit_withdrawn_event(&self, _account: AccountId, _amount: Balance) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_floor_week() {
+        assert_eq!(floor_week(0), 0);
+        assert_eq!(floor_week(WEEK - 1), 0);
+        assert_eq!(floor_week(WEEK), WEEK);
+        assert_eq!(floor_week(WEEK * 3 + 1), WEEK * 3);
+    }
+
+    #[test]
+    fn test_voting_power() {
+        // a 4-year lock is worth its amount, decaying linearly
+        assert_eq!(voting_power(1000, MAX_LOCK_DURATION, 0), 1000);
+        assert_eq!(
+            voting_power(1000, MAX_LOCK_DURATION, MAX_LOCK_DURATION / 4),
+            750
+        );
+        assert_eq!(voting_power(1000, MAX_LOCK_DURATION, MAX_LOCK_DURATION), 0);
+        assert_eq!(voting_power(1000, WEEK, WEEK * 2), 0);
+    }
+
+    #[test]
+    fn test_total_voting_power() {
+        // locks of 1000 until the max duration and of 2000 until half of it
+        let amount = 3000;
+        let amount_end = U256::from(1000_u128 * MAX_LOCK_DURATION as u128)
+            .add(U256::from(2000_u128 * (MAX_LOCK_DURATION / 2) as u128));
+        assert_eq!(
+            total_voting_power(amount, amount_end, 0),
+            voting_power(1000, MAX_LOCK_DURATION, 0) + voting_power(2000, MAX_LOCK_DURATION / 2, 0)
+        );
+        let now = MAX_LOCK_DURATION / 4;
+        assert_eq!(
+            total_voting_power(amount, amount_end, now),
+            voting_power(1000, MAX_LOCK_DURATION, now)
+                + voting_power(2000, MAX_LOCK_DURATION / 2, now)
+        );
+    }
+}
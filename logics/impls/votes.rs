@@ -0,0 +1,278 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+pub use crate::traits::votes::*;
+use openbrush::{
+    contracts::psp22,
+    storage::Mapping,
+    traits::{
+        AccountId,
+        Balance,
+        Storage,
+        Timestamp,
+    },
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+/// Checkpointed quantity tracked by this module
+#[derive(Debug, Clone, Copy, PartialEq, Eq, scale::Decode, scale::Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum CheckpointKind {
+    Votes(AccountId),
+    Balance(AccountId),
+    TotalSupply,
+}
+
+#[derive(Debug, Default)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// Delegatee of each account
+    pub delegates: Mapping<AccountId, AccountId>,
+    /// Checkpoints of each quantity, indexed from 0
+    pub checkpoints: Mapping<(CheckpointKind, u32), Checkpoint>,
+    /// Number of checkpoints of each quantity
+    pub num_checkpoints: Mapping<CheckpointKind, u32>,
+}
+
+pub trait Internal {
+    fn _delegate(&mut self, delegator: AccountId, delegatee: AccountId);
+    fn _move_voting_power(
+        &mut self,
+        from: Option<AccountId>,
+        to: Option<AccountId>,
+        amount: Balance,
+    );
+    fn _after_token_transfer_votes(
+        &mut self,
+        from: Option<AccountId>,
+        to: Option<AccountId>,
+        amount: Balance,
+    );
+    fn _push_checkpoint(&mut self, kind: CheckpointKind, value: Balance, neg: bool);
+    fn _latest_checkpoint(&self, kind: CheckpointKind) -> Balance;
+    fn _checkpoint_at(&self, kind: CheckpointKind, timestamp: Timestamp) -> Result<Balance>;
+    fn _token_balance_of(&self, account: AccountId) -> Balance;
+
+    // event emission
+    fn _emit_delegate_changed_event(
+        &self,
+        delegator: AccountId,
+        from_delegate: Option<AccountId>,
+        to_delegate: AccountId,
+    );
+    fn _emit_delegate_votes_changed_event(
+        &self,
+        delegate: AccountId,
+        previous_balance: Balance,
+        new_balance: Balance,
+    );
+}
+
+impl<T: Storage<Data> + Storage<psp22::Data>> Votes for T {
+    default fn delegate(&mut self, delegatee: AccountId) -> Result<()> {
+        self._delegate(Self::env().caller(), delegatee);
+        Ok(())
+    }
+
+    default fn delegates(&self, account: AccountId) -> Option<AccountId> {
+        self.data::<Data>().delegates.get(&account)
+    }
+
+    default fn get_votes(&self, account: AccountId) -> Balance {
+        self._latest_checkpoint(CheckpointKind::Votes(account))
+    }
+
+    default fn get_past_votes(&self, account: AccountId, timestamp: Timestamp) -> Result<Balance> {
+        self._checkpoint_at(CheckpointKind::Votes(account), timestamp)
+    }
+
+    default fn get_past_balance(
+        &self,
+        account: AccountId,
+        timestamp: Timestamp,
+    ) -> Result<Balance> {
+        self._checkpoint_at(CheckpointKind::Balance(account), timestamp)
+    }
+
+    default fn get_past_total_supply(&self, timestamp: Timestamp) -> Result<Balance> {
+        self._checkpoint_at(CheckpointKind::TotalSupply, timestamp)
+    }
+
+    default fn vote_checkpoints_count(&self, account: AccountId) -> u32 {
+        self.data::<Data>()
+            .num_checkpoints
+            .get(&CheckpointKind::Votes(account))
+            .unwrap_or(0)
+    }
+
+    default fn vote_checkpoint(&self, account: AccountId, position: u32) -> Option<Checkpoint> {
+        self.data::<Data>()
+            .checkpoints
+            .get(&(CheckpointKind::Votes(account), position))
+    }
+}
+
+impl<T: Storage<Data> + Storage<psp22::Data>> Internal for T {
+    default fn _delegate(&mut self, delegator: AccountId, delegatee: AccountId) {
+        let current = self.data::<Data>().delegates.get(&delegator);
+        self.data::<Data>().delegates.insert(&delegator, &delegatee);
+        self._emit_delegate_changed_event(delegator, current, delegatee);
+        let balance = self._token_balance_of(delegator);
+        self._move_voting_power(current, Some(delegatee), balance);
+    }
+
+    default fn _move_voting_power(
+        &mut self,
+        from: Option<AccountId>,
+        to: Option<AccountId>,
+        amount: Balance,
+    ) {
+        if from == to || amount == 0 {
+            return
+        }
+        if let Some(from) = from {
+            let previous = self._latest_checkpoint(CheckpointKind::Votes(from));
+            self._push_checkpoint(CheckpointKind::Votes(from), amount, true);
+            self._emit_delegate_votes_changed_event(
+                from,
+                previous,
+                previous.saturating_sub(amount),
+            );
+        }
+        if let Some(to) = to {
+            let previous = self._latest_checkpoint(CheckpointKind::Votes(to));
+            self._push_checkpoint(CheckpointKind::Votes(to), amount, false);
+            self._emit_delegate_votes_changed_event(to, previous, previous + amount);
+        }
+    }
+
+    default fn _after_token_transfer_votes(
+        &mut self,
+        from: Option<AccountId>,
+        to: Option<AccountId>,
+        amount: Balance,
+    ) {
+        match (from, to) {
+            (None, Some(_)) => self._push_checkpoint(CheckpointKind::TotalSupply, amount, false),
+            (Some(_), None) => self._push_checkpoint(CheckpointKind::TotalSupply, amount, true),
+            _ => {}
+        }
+        if let Some(from) = from {
+            self._push_checkpoint(CheckpointKind::Balance(from), amount, true);
+        }
+        if let Some(to) = to {
+            self._push_checkpoint(CheckpointKind::Balance(to), amount, false);
+        }
+        let from_delegate = from.and_then(|from| self.data::<Data>().delegates.get(&from));
+        let to_delegate = to.and_then(|to| self.data::<Data>().delegates.get(&to));
+        self._move_voting_power(from_delegate, to_delegate, amount);
+    }
+
+    default fn _push_checkpoint(&mut self, kind: CheckpointKind, value: Balance, neg: bool) {
+        let now = Self::env().block_timestamp();
+        let count = self.data::<Data>().num_checkpoints.get(&kind).unwrap_or(0);
+        let latest = self._latest_checkpoint(kind);
+        let value_new = if neg {
+            latest.saturating_sub(value)
+        } else {
+            latest + value
+        };
+        let checkpoint = Checkpoint {
+            timestamp: now,
+            value: value_new,
+        };
+        // several updates in the same block share one checkpoint
+        if count > 0 {
+            let last = self.data::<Data>().checkpoints.get(&(kind, count - 1));
+            if last.map(|c| c.timestamp == now).unwrap_or(false) {
+                self.data::<Data>()
+                    .checkpoints
+                    .insert(&(kind, count - 1), &checkpoint);
+                return
+            }
+        }
+        self.data::<Data>()
+            .checkpoints
+            .insert(&(kind, count), &checkpoint);
+        self.data::<Data>()
+            .num_checkpoints
+            .insert(&kind, &(count + 1));
+    }
+
+    default fn _latest_checkpoint(&self, kind: CheckpointKind) -> Balance {
+        let count = self.data::<Data>().num_checkpoints.get(&kind).unwrap_or(0);
+        if count == 0 {
+            return 0
+        }
+        self.data::<Data>()
+            .checkpoints
+            .get(&(kind, count - 1))
+            .map(|c| c.value)
+            .unwrap_or(0)
+    }
+
+    default fn _checkpoint_at(
+        &self,
+        kind: CheckpointKind,
+        timestamp: Timestamp,
+    ) -> Result<Balance> {
+        if timestamp >= Self::env().block_timestamp() {
+            return Err(Error::FutureLookup)
+        }
+        let count = self.data::<Data>().num_checkpoints.get(&kind).unwrap_or(0);
+        // binary search for the last checkpoint at or before `timestamp`
+        let (mut low, mut high) = (0, count);
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let checkpoint = self
+                .data::<Data>()
+                .checkpoints
+                .get(&(kind, mid))
+                .unwrap_or_default();
+            if checkpoint.timestamp > timestamp {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+        if high == 0 {
+            return Ok(0)
+        }
+        Ok(self
+            .data::<Data>()
+            .checkpoints
+            .get(&(kind, high - 1))
+            .map(|c| c.value)
+            .unwrap_or(0))
+    }
+
+    default fn _token_balance_of(&self, account: AccountId) -> Balance {
+        self.data::<psp22::Data>()
+            .balances
+            .get(&account)
+            .unwrap_or(0)
+    }
+
+    default fn _emit_delegate_changed_event(
+        &self,
+        _delegator: AccountId,
+        _from_delegate: Option<AccountId>,
+        _to_delegate: AccountId,
+    ) {
+    }
+    default fn _emit_delegate_votes_changed_event(
+        &self,
+        _delegate: AccountId,
+        _previous_balance: Balance,
+        _new_balance: Balance,
+    ) {
+    }
+}
@@ -8,6 +8,7 @@
 pub use crate::traits::weth_gateway::*;
 use crate::traits::{
     pool::PoolRef,
+    psp22_permit::PSP22PermitRef,
     weth::*,
 };
 use ink::prelude::vec::Vec;
@@ -37,6 +38,7 @@ pub trait Internal {
     fn _emit_withdraw_eth_event_(&self, pool: AccountId, to: AccountId, value: Balance);
     fn _emit_borrow_eth_event_(&self, pool: AccountId, to: AccountId, value: Balance);
     fn _emit_repay_eth_event_(&self, pool: AccountId, from: AccountId, value: Balance);
+    fn _emit_deposit_with_permit_event_(&self, pool: AccountId, from: AccountId, value: Balance);
     fn _weth_address(&self) -> Option<AccountId>;
 }
 
@@ -71,6 +73,14 @@ impl<T: Storage<Data> + Storage<ownable::Data>> Internal for T {
     default fn _emit_borrow_eth_event_(&self, _pool: AccountId, _to: AccountId, _value: Balance) {}
 
     default fn _emit_repay_eth_event_(&self, _pool: AccountId, _from: AccountId, _value: Balance) {}
+
+    default fn _emit_deposit_with_permit_event_(
+        &self,
+        _pool: AccountId,
+        _from: AccountId,
+        _value: Balance,
+    ) {
+    }
 }
 
 impl<T> WETHGateway for T
@@ -147,6 +157,37 @@ where
         self._safe_transfer_eth(caller, amount)
     }
 
+    default fn deposit_with_permit(
+        &mut self,
+        pool: AccountId,
+        amount: Balance,
+        permit_sig: PermitSignature,
+    ) -> Result<()> {
+        let caller = Self::env().caller();
+        let contract_address = Self::env().account_id();
+        let underlying = PoolRef::underlying(&pool).ok_or(Error::UnderlyingIsNotSet)?;
+
+        // NOTE: anyone holding the signature can front-run the permit, so accept the allowance it set
+        if PSP22PermitRef::permit(&underlying, caller, contract_address, amount, permit_sig)
+            .is_err()
+            && PSP22Ref::allowance(&underlying, caller, contract_address) < amount
+        {
+            return Err(Error::PermitFailed)
+        }
+
+        PSP22Ref::transfer_from(
+            &underlying,
+            caller,
+            contract_address,
+            amount,
+            Vec::<u8>::new(),
+        )?;
+        PSP22Ref::approve(&underlying, pool, amount)?;
+        PoolRef::mint_to(&pool, caller, amount)?;
+        self._emit_deposit_with_permit_event_(pool, caller, amount);
+        Ok(())
+    }
+
     default fn emergency_token_transfer(
         &mut self,
         token: AccountId,
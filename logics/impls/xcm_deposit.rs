@@ -0,0 +1,100 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+pub use crate::traits::xcm_deposit::*;
+use crate::{
+    impls::pool::pallet_assets,
+    traits::{
+        controller::ControllerRef,
+        pool::{
+            pallet_asset_id,
+            PoolRef,
+            Underlying,
+        },
+    },
+};
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Storage,
+};
+
+pub const STORAGE_KEY: u32 = openbrush::storage_unique_key!(Data);
+
+#[derive(Debug, Default)]
+#[openbrush::upgradeable_storage(STORAGE_KEY)]
+pub struct Data {
+    /// AccountId of Controller, which lists the pools deposited into
+    pub controller: Option<AccountId>,
+}
+
+pub trait Internal {
+    fn _initialize(&mut self, controller: AccountId);
+    fn _controller(&self) -> Option<AccountId>;
+    fn _pool_of(&self, asset_id: u128) -> Option<AccountId>;
+    fn _emit_deposited_event(
+        &self,
+        asset_id: u128,
+        pool: AccountId,
+        account: AccountId,
+        amount: Balance,
+    );
+}
+
+impl<T: Storage<Data>> XcmDeposit for T {
+    default fn controller(&self) -> Option<AccountId> {
+        self._controller()
+    }
+
+    default fn pool_of(&self, asset_id: u128) -> Option<AccountId> {
+        self._pool_of(asset_id)
+    }
+
+    default fn deposit(&mut self, asset_id: u128) -> Result<Balance> {
+        self._controller().ok_or(Error::ControllerIsNotSet)?;
+        let pool = self._pool_of(asset_id).ok_or(Error::MarketNotListed)?;
+        if PoolRef::underlying_asset(&pool) != Some(Underlying::PalletAsset(asset_id)) {
+            return Err(Error::MarketNotListed)
+        }
+
+        let amount = pallet_assets::balance_of(asset_id, Self::env().account_id());
+        if amount == 0 {
+            return Err(Error::NothingReceived)
+        }
+        pallet_assets::approve_transfer(asset_id, pool, amount)
+            .map_err(|_| Error::ApprovePalletAssetFailed)?;
+        let account = Self::env().caller();
+        PoolRef::mint_to(&pool, account, amount)?;
+
+        self._emit_deposited_event(asset_id, pool, account, amount);
+        Ok(amount)
+    }
+}
+
+impl<T: Storage<Data>> Internal for T {
+    default fn _initialize(&mut self, controller: AccountId) {
+        self.data().controller = Some(controller);
+    }
+
+    default fn _controller(&self) -> Option<AccountId> {
+        self.data().controller
+    }
+
+    default fn _pool_of(&self, asset_id: u128) -> Option<AccountId> {
+        let controller = self._controller()?;
+        ControllerRef::market_of_underlying(&controller, pallet_asset_id(asset_id))
+    }
+
+    default fn _emit_deposited_event(
+        &self,
+        _asset_id: u128,
+        _pool: AccountId,
+        _account: AccountId,
+        _amount: Balance,
+    ) {
+    }
+}
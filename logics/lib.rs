@@ -2,4 +2,6 @@
 #![feature(min_specialization)]
 
 pub mod impls;
+#[cfg(feature = "math")]
+pub mod math;
 pub mod traits;
@@ -0,0 +1,103 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! Pure math of the protocol, the same code the contracts run.
+//!
+//! Built with the `math` feature, for off-chain callers such as bots, simulators and
+//! front ends (e.g. through wasm-bindgen). Nothing here reads or writes contract storage.
+
+pub use crate::{
+    impls::{
+        controller::{
+            balance_decrease_allowed,
+            calculate_available_borrow_in_base_currency,
+            calculate_health_factor_from_balances,
+            get_hypothetical_account_liquidity,
+            liquidate_calculate_seize_tokens,
+            liquidate_max_repay_amount,
+            BalanceDecreaseAllowedParam,
+            GetHypotheticalAccountLiquidityInput,
+            HypotheticalAccountLiquidityCalculationParam,
+            LiquidateCalculateSeizeTokensInput,
+            LiquidateMaxRepayAmountInput,
+        },
+        exp_no_err::{
+            div_ceil,
+            exp_scale,
+            Double,
+            Exp,
+        },
+        interest_rate_model::{
+            double_kink_rate,
+            jump_rate,
+            milliseconds_per_year,
+            supply_rate,
+        },
+        params,
+        pool::utils::{
+            calculate_interest,
+            exchange_rate,
+            from_scaled_amount,
+            from_scaled_amount_truncate,
+            origination_fee_of,
+            protocol_seize_amount,
+            protocol_seize_share_mantissa,
+            scaled_amount_of,
+            scaled_amount_of_ceil,
+            scaled_amount_of_truncate,
+            stable_balance_at,
+            try_from_scaled_amount_ceil,
+            utilization_rate,
+            weighted_average_rate,
+            CalculateInterestInput,
+            CalculateInterestOutput,
+        },
+        wad_ray_math::{
+            Ray,
+            Wad,
+        },
+    },
+    traits::types::{
+        Bps,
+        SignedExp,
+        WrappedU256,
+    },
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use primitive_types::U256;
+
+    #[test]
+    fn test_pool_math() {
+        // (cash + borrows - reserves) / supply
+        assert_eq!(exchange_rate(100, 50, 60, 10, U256::zero()), exp_scale());
+        assert_eq!(exchange_rate(0, 50, 60, 10, exp_scale()), exp_scale());
+
+        let idx = Exp {
+            mantissa: WrappedU256::from(exp_scale() * 5 / 4),
+        };
+        assert_eq!(scaled_amount_of_ceil(100, idx.clone()).unwrap(), 80);
+        assert_eq!(from_scaled_amount_truncate(80, idx).unwrap(), 100);
+
+        assert_eq!(utilization_rate(50, 50, 0), exp_scale() / 2);
+    }
+
+    #[test]
+    fn test_interest_rate_model_math() {
+        // 50% utilized at a borrow rate of 10%, 10% of which goes to reserves
+        assert_eq!(
+            supply_rate(exp_scale() / 2, exp_scale() / 10, exp_scale() * 9 / 10),
+            exp_scale() * 45 / 1000
+        );
+        assert_eq!(
+            milliseconds_per_year(),
+            U256::from(365 * 24 * 60 * 60 * 1000_u64)
+        );
+    }
+}
@@ -0,0 +1,175 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use openbrush::{
+    contracts::ownable::*,
+    modifiers,
+    traits::{
+        AccountId,
+        Timestamp,
+    },
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+use super::{
+    price_oracle::*,
+    types::WrappedU256,
+};
+
+#[openbrush::wrapper]
+pub type AggregatorPriceOracleRef = dyn AggregatorPriceOracle + PriceOracle + Ownable;
+
+#[openbrush::wrapper]
+pub type ExchangeRateProviderRef = dyn ExchangeRateProvider;
+
+/// Contract reporting how many base assets a derivative asset is redeemable for
+#[openbrush::trait_definition]
+pub trait ExchangeRateProvider {
+    /// Returns the amount of base asset one derivative asset is worth, scaled by 1e18
+    #[ink(message)]
+    fn exchange_rate(&self) -> WrappedU256;
+}
+
+/// Price oracle combining other price oracles per asset
+///
+/// The price of the primary source is used when it is within the tolerance of the anchor,
+/// otherwise the price of the fallback source is used.
+/// Assets configured as derivatives are priced from their base asset instead.
+/// During feed outages, a guardian can pin a temporary price overriding both.
+#[openbrush::trait_definition]
+pub trait AggregatorPriceOracle: PriceOracle + Ownable {
+    /// Sets the sources pricing an asset
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_sources(&mut self, asset: AccountId, sources: PriceSources) -> Result<()>;
+
+    /// Removes the sources of an asset
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn remove_sources(&mut self, asset: AccountId) -> Result<()>;
+
+    /// Returns the sources pricing an asset
+    #[ink(message)]
+    fn sources(&self, asset: AccountId) -> Option<PriceSources>;
+
+    /// Prices an asset as its base asset price times its exchange rate
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_derivative(&mut self, asset: AccountId, derivative: DerivativeSource) -> Result<()>;
+
+    /// Stops pricing an asset as a derivative
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn remove_derivative(&mut self, asset: AccountId) -> Result<()>;
+
+    /// Returns the composition of a derivative asset
+    #[ink(message)]
+    fn derivative(&self, asset: AccountId) -> Option<DerivativeSource>;
+
+    /// Sets the guardian allowed to override prices
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_guardian(&mut self, guardian: AccountId) -> Result<()>;
+
+    /// Returns the guardian allowed to override prices
+    #[ink(message)]
+    fn guardian(&self) -> Option<AccountId>;
+
+    /// Sets the manager the borrows of overridden markets are paused through
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_manager(&mut self, manager: AccountId) -> Result<()>;
+
+    /// Returns the manager the borrows of overridden markets are paused through
+    #[ink(message)]
+    fn manager(&self) -> Option<AccountId>;
+
+    /// Pins the price of an asset for `duration`. Caller must be the guardian.
+    ///
+    /// Borrows of `pool`, which must lend `asset`, are paused so that users can only repay
+    /// and withdraw at this price. They are resumed when the override is cleared.
+    #[ink(message)]
+    fn set_override_price(
+        &mut self,
+        asset: AccountId,
+        price: u128,
+        duration: Timestamp,
+        pool: Option<AccountId>,
+    ) -> Result<()>;
+
+    /// Removes the override of an asset before it expires. Caller must be the guardian.
+    #[ink(message)]
+    fn clear_override_price(&mut self, asset: AccountId) -> Result<()>;
+
+    /// Removes the expired override of an asset, resuming the borrows it paused.
+    /// Callable by anyone, so that borrows do not stay paused after the override.
+    #[ink(message)]
+    fn clear_expired_override(&mut self, asset: AccountId) -> Result<()>;
+
+    /// Returns the pool whose borrows are paused by the override of an asset
+    #[ink(message)]
+    fn override_paused_pool(&self, asset: AccountId) -> Option<AccountId>;
+
+    /// Returns the override of an asset, even if expired
+    #[ink(message)]
+    fn override_price(&self, asset: AccountId) -> Option<PriceOverride>;
+}
+
+/// Price oracles pricing an asset
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct PriceSources {
+    pub primary: AccountId,
+    /// Source the primary price is validated against, e.g. a TWAP
+    pub anchor: Option<AccountId>,
+    /// Source used when the primary price is unavailable or out of bounds
+    pub fallback: Option<AccountId>,
+    /// Largest relative deviation of the primary price from the anchor price
+    pub anchor_tolerance_mantissa: WrappedU256,
+}
+
+/// Composition of an asset valued from another, e.g. an lToken or a liquid-staking token
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct DerivativeSource {
+    /// Asset the derivative is redeemable for, priced by this oracle
+    pub base: AccountId,
+    pub exchange_rate: ExchangeRateSource,
+}
+
+/// Contract the exchange rate of a derivative asset is read from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum ExchangeRateSource {
+    /// Pool whose stored exchange rate prices its lToken
+    Pool(AccountId),
+    /// Contract implementing `ExchangeRateProvider`
+    Provider(AccountId),
+}
+
+/// Price pinned by the guardian for an asset until it expires
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct PriceOverride {
+    pub price: u128,
+    pub expires_at: Timestamp,
+}
@@ -0,0 +1,93 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use ink::prelude::string::String;
+use openbrush::{
+    contracts::ownable::*,
+    modifiers,
+    traits::{
+        AccountId,
+        Timestamp,
+    },
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+use super::price_oracle::*;
+
+#[openbrush::wrapper]
+pub type StdReferenceRef = dyn StdReference;
+
+/// Interface of Band Protocol's std_reference contract
+///
+/// The trait name is part of the message selector, so it must match Band's.
+#[openbrush::trait_definition]
+pub trait StdReference {
+    /// Returns the rate of `base` quoted in `quote`, e.g. `("ASTR", "USD")`
+    #[ink(message)]
+    fn get_reference_data(&self, base: String, quote: String) -> Option<ReferenceData>;
+}
+
+/// Rate of a pair with 18 decimals and the times in seconds both symbols were last updated at
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct ReferenceData {
+    pub rate: u128,
+    pub last_updated_base: u64,
+    pub last_updated_quote: u64,
+}
+
+#[openbrush::wrapper]
+pub type BandPriceOracleRef = dyn BandPriceOracle + PriceOracle + Ownable;
+
+/// Price oracle reading prices of assets from Band Protocol's std_reference contract
+#[openbrush::trait_definition]
+pub trait BandPriceOracle: PriceOracle + Ownable {
+    /// Sets Band's std_reference contract
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_std_reference(&mut self, std_reference: AccountId) -> Result<()>;
+
+    /// Maps an asset to the base and quote symbols of its pair and its heartbeat
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_symbols(
+        &mut self,
+        asset: AccountId,
+        base: String,
+        quote: String,
+        heartbeat: Timestamp,
+    ) -> Result<()>;
+
+    /// Removes the symbols of an asset
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn remove_symbols(&mut self, asset: AccountId) -> Result<()>;
+
+    /// Returns Band's std_reference contract
+    #[ink(message)]
+    fn std_reference(&self) -> Option<AccountId>;
+
+    /// Returns the symbols of an asset
+    #[ink(message)]
+    fn symbols(&self, asset: AccountId) -> Option<BandSymbols>;
+}
+
+/// Base and quote symbols of the pair pricing an asset and its heartbeat
+#[derive(Debug, Default, Clone, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct BandSymbols {
+    pub base: String,
+    pub quote: String,
+    /// Longest time a rate is used for after Band updated either symbol, 0 if unlimited
+    pub heartbeat: Timestamp,
+}
@@ -0,0 +1,125 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::error_code::{
+    error_code,
+    module,
+    ErrorCode,
+};
+use ink::prelude::vec::Vec;
+use openbrush::{
+    contracts::psp22::PSP22Error,
+    traits::{
+        AccountId,
+        Balance,
+    },
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+use super::{
+    dex_router::DexRouterError,
+    flashloan_gateway::Error as FlashloanGatewayError,
+    pool::Error as PoolError,
+};
+
+#[openbrush::wrapper]
+pub type CollateralSwapRef = dyn CollateralSwap;
+
+/// Periphery rotating a collateral into another without closing the borrows it backs
+#[openbrush::trait_definition]
+pub trait CollateralSwap {
+    /// Get Controller AccountId
+    #[ink(message)]
+    fn controller(&self) -> Option<AccountId>;
+
+    /// Get Flashloan Gateway AccountId
+    #[ink(message)]
+    fn flashloan_gateway(&self) -> Option<AccountId>;
+
+    /// Get DEX Router AccountId
+    #[ink(message)]
+    fn dex_router(&self) -> Option<AccountId>;
+
+    /// Flash-borrows `amount` of the first asset of `path` and swaps it for the last one through the DEX.
+    /// The proceeds are deposited for caller, and the flashloan is repaid
+    /// by redeeming caller's deposit of the first asset.
+    /// Caller must approve its pool tokens of the first asset to this contract.
+    #[ink(message)]
+    fn swap_collateral(
+        &mut self,
+        amount: Balance,
+        min_amount_out: Balance,
+        path: Vec<AccountId>,
+    ) -> Result<()>;
+}
+
+/// Swap run on receiving a flashloan, encoded in its params
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+pub struct CollateralSwapParams {
+    pub account: AccountId,
+    pub min_amount_out: Balance,
+    pub path: Vec<AccountId>,
+}
+
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
+pub enum Error {
+    ControllerIsNotSet = 0,
+    FlashloanGatewayIsNotSet = 1,
+    DexRouterIsNotSet = 2,
+    MarketNotListed = 3,
+    InvalidSwapPath = 4,
+    CallerIsNotFlashloanGateway = 5,
+    InvalidFlashloanParams = 6,
+    InsufficientDeposit = 7,
+    Pool(PoolError) = 8,
+    FlashloanGateway(FlashloanGatewayError) = 9,
+    DexRouter(DexRouterError) = 10,
+    PSP22(PSP22Error) = 11,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        match self {
+            Error::Pool(error) => error.code(),
+            Error::FlashloanGateway(error) => error.code(),
+            Error::DexRouter(error) => error.code(),
+            Error::PSP22(error) => error.code(),
+            _ => error_code(module::COLLATERAL_SWAP, self),
+        }
+    }
+}
+
+impl From<PoolError> for Error {
+    fn from(error: PoolError) -> Self {
+        Error::Pool(error)
+    }
+}
+
+impl From<FlashloanGatewayError> for Error {
+    fn from(error: FlashloanGatewayError) -> Self {
+        Error::FlashloanGateway(error)
+    }
+}
+
+impl From<DexRouterError> for Error {
+    fn from(error: DexRouterError) -> Self {
+        Error::DexRouter(error)
+    }
+}
+
+impl From<PSP22Error> for Error {
+    fn from(error: PSP22Error) -> Self {
+        Error::PSP22(error)
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
@@ -5,6 +5,11 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use super::error_code::{
+    error_code,
+    module,
+    ErrorCode,
+};
 use ink::prelude::vec::Vec;
 use openbrush::traits::{
     AccountId,
@@ -144,6 +149,12 @@ pub trait Controller {
         seize_tokens: Balance,
     ) -> Result<()>;
 
+    /// Checks if the debt of the borrower in the pool can be written off as bad debt.
+    /// The borrower must have no supply left in any market other than the calling pool,
+    /// which checks its own balance
+    #[ink(message)]
+    fn absorb_allowed(&self, pool: AccountId, borrower: AccountId) -> Result<()>;
+
     /// Checks if the account should be allowed to transfer tokens in the given market
     #[ink(message)]
     fn transfer_allowed(
@@ -177,8 +188,30 @@ pub trait Controller {
         pool_collateral_attributes: Option<PoolAttributesForSeizeCalculation>,
     ) -> Result<Balance>;
 
+    /// Calculates the largest amount a liquidator may repay, bounded by the close factor and the borrower's collateral
+    #[ink(message)]
+    fn liquidate_max_repay_amount(
+        &self,
+        pool_borrowed: AccountId,
+        pool_collateral: AccountId,
+        exchange_rate_mantissa: WrappedU256,
+        borrow_balance: Balance,
+        collateral_tokens: Balance,
+        pool_borrowed_attributes: Option<PoolAttributesForSeizeCalculation>,
+        pool_collateral_attributes: Option<PoolAttributesForSeizeCalculation>,
+    ) -> Result<Balance>;
+
     // admin functions
 
+    /// Proposes a new manager of the controller, e.g. a timelock.
+    /// Admin rights are handed over once it calls `accept_manager`
+    #[ink(message)]
+    fn set_manager(&mut self, new_manager: AccountId) -> Result<()>;
+
+    /// Takes over admin rights of the controller proposed to caller
+    #[ink(message)]
+    fn accept_manager(&mut self) -> Result<()>;
+
     /// Sets a new price oracle for the controller
     #[ink(message)]
     fn set_price_oracle(&mut self, new_oracle: AccountId) -> Result<()>;
@@ -239,6 +272,28 @@ pub trait Controller {
     #[ink(message)]
     fn set_borrow_cap(&mut self, pool: AccountId, new_cap: Balance) -> Result<()>;
 
+    /// Sets the account allowed to shut the protocol down besides the manager
+    #[ink(message)]
+    fn set_shutdown_guardian(&mut self, guardian: Option<AccountId>) -> Result<()>;
+
+    /// Sets the factory allowed to list the pools it deploys besides the manager
+    #[ink(message)]
+    fn set_pool_factory(&mut self, pool_factory: Option<AccountId>) -> Result<()>;
+
+    /// Shuts the protocol down in an incident, freezing mints, borrows and transfers of
+    /// pool tokens in all markets. Callable by the manager or the shutdown guardian
+    #[ink(message)]
+    fn shutdown(&mut self) -> Result<()>;
+
+    /// First stage of re-activation after a shutdown: mints and transfers resume, borrows stay
+    /// frozen
+    #[ink(message)]
+    fn recover(&mut self) -> Result<()>;
+
+    /// Last stage of re-activation after a shutdown: borrows resume
+    #[ink(message)]
+    fn reactivate(&mut self) -> Result<()>;
+
     // view function
     /// Returns the list of all markets that are currently supported
     #[ink(message)]
@@ -271,6 +326,18 @@ pub trait Controller {
     #[ink(message)]
     fn transfer_guardian_paused(&self) -> bool;
 
+    /// Returns the stage of the emergency shutdown the protocol is in
+    #[ink(message)]
+    fn protocol_status(&self) -> ProtocolStatus;
+
+    /// Returns the account allowed to shut the protocol down besides the manager
+    #[ink(message)]
+    fn shutdown_guardian(&self) -> Option<AccountId>;
+
+    /// Returns the factory allowed to list the pools it deploys besides the manager
+    #[ink(message)]
+    fn pool_factory(&self) -> Option<AccountId>;
+
     /// Returns the price oracle account id
     #[ink(message)]
     fn oracle(&self) -> Option<AccountId>;
@@ -291,6 +358,10 @@ pub trait Controller {
     #[ink(message)]
     fn manager(&self) -> Option<AccountId>;
 
+    /// Returns the account id proposed as the next manager
+    #[ink(message)]
+    fn pending_manager(&self) -> Option<AccountId>;
+
     /// Returns whether a given pool is currently listed
     #[ink(message)]
     fn is_listed(&self, pool: AccountId) -> bool;
@@ -330,6 +401,22 @@ pub trait Controller {
     ) -> Result<(U256, U256)>;
 }
 
+/// Stage of the emergency shutdown of the protocol.
+/// Repays, redeems, liquidations and reward claims work in every stage, so users can exit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum ProtocolStatus {
+    /// Every action is available
+    Active,
+    /// Mints, borrows and transfers of pool tokens are frozen
+    Shutdown,
+    /// Borrows are frozen, until the protocol is reactivated
+    Recovering,
+}
+
 /// Structure for holding information about the Pool
 ///
 /// NOTE: Used to prevent cross contract calls to the caller pool
@@ -386,27 +473,45 @@ pub struct AccountCollateralData {
 
 /// Custom error definitions for Controller
 #[derive(Debug, PartialEq, Eq, Encode, Decode)]
-#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+#[repr(u8)]
 pub enum Error {
-    MintIsPaused,
-    BorrowIsPaused,
-    SeizeIsPaused,
-    TransferIsPaused,
-    MarketNotListed,
-    MarketAlreadyListed,
-    ControllerMismatch,
-    PriceError,
-    TooMuchRepay,
-    BorrowCapReached,
-    InsufficientLiquidity,
-    InsufficientShortfall,
-    CallerIsNotManager,
-    InvalidCollateralFactor,
-    UnderlyingIsNotSet,
-    PoolIsNotSet,
-    ManagerIsNotSet,
-    OracleIsNotSet,
-    BalanceDecreaseNotAllowed,
+    MintIsPaused = 0,
+    BorrowIsPaused = 1,
+    SeizeIsPaused = 2,
+    TransferIsPaused = 3,
+    MarketNotListed = 4,
+    MarketAlreadyListed = 5,
+    ControllerMismatch = 6,
+    PriceError = 7,
+    TooMuchRepay = 8,
+    BorrowCapReached = 9,
+    InsufficientLiquidity = 10,
+    InsufficientShortfall = 11,
+    CallerIsNotManager = 12,
+    CallerIsNotPendingManager = 13,
+    CallerIsNotShutdownGuardian = 14,
+    ProtocolIsShutdown = 15,
+    InvalidProtocolStatus = 16,
+    InvalidCollateralFactor = 17,
+    InvalidCloseFactor = 18,
+    InvalidLiquidationIncentive = 19,
+    UnderlyingIsNotSet = 20,
+    PoolIsNotSet = 21,
+    ManagerIsNotSet = 22,
+    OracleIsNotSet = 23,
+    BalanceDecreaseNotAllowed = 24,
+    BorrowerHasCollateral = 25,
+    MathOverflow = 26,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        error_code(module::CONTROLLER, self)
+    }
 }
 
 pub type Result<T> = core::result::Result<T, Error>;
@@ -0,0 +1,56 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::error_code::{
+    error_code,
+    module,
+    ErrorCode,
+};
+use ink::prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+#[openbrush::wrapper]
+pub type DexRouterRef = dyn Router;
+
+/// Interface of an ArthSwap-style AMM router
+///
+/// The trait name is part of the message selector, so it must match the router's.
+#[openbrush::trait_definition]
+pub trait Router {
+    /// Swaps exactly `amount_in` of the first token of `path` for at least `amount_out_min`
+    /// of the last one, sent to `to`. Returns the amounts swapped along the path.
+    #[ink(message)]
+    fn swap_exact_tokens_for_tokens(
+        &mut self,
+        amount_in: Balance,
+        amount_out_min: Balance,
+        path: Vec<AccountId>,
+        to: AccountId,
+        deadline: u64,
+    ) -> Result<Vec<Balance>, DexRouterError>;
+}
+
+/// Errors of the router are not inspected, callers only revert on them
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
+pub enum DexRouterError {
+    SwapFailed = 0,
+}
+
+impl ErrorCode for DexRouterError {
+    fn code(&self) -> u32 {
+        error_code(module::DEX_ROUTER, self)
+    }
+}
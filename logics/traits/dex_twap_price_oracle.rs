@@ -0,0 +1,104 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use openbrush::{
+    contracts::ownable::*,
+    modifiers,
+    traits::{
+        AccountId,
+        Balance,
+        Timestamp,
+    },
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+use super::{
+    price_oracle::*,
+    types::WrappedU256,
+};
+
+#[openbrush::wrapper]
+pub type PairRef = dyn Pair;
+
+/// Interface of an ArthSwap-style AMM pair
+///
+/// The trait name is part of the message selector, so it must match the pair's.
+#[openbrush::trait_definition]
+pub trait Pair {
+    /// Returns the reserves of both tokens and the time they were last updated at
+    #[ink(message)]
+    fn get_reserves(&self) -> (Balance, Balance, Timestamp);
+
+    /// Sum of the price of token 0 in token 1 as UQ112x112 times the elapsed time
+    #[ink(message)]
+    fn price_0_cumulative_last(&self) -> WrappedU256;
+
+    /// Sum of the price of token 1 in token 0 as UQ112x112 times the elapsed time
+    #[ink(message)]
+    fn price_1_cumulative_last(&self) -> WrappedU256;
+}
+
+#[openbrush::wrapper]
+pub type DexTwapPriceOracleRef = dyn DexTwapPriceOracle + PriceOracle + Ownable;
+
+/// Price oracle pricing assets by the time-weighted average price of an AMM pair
+///
+/// Prices are quoted in the other token of the pair, so pairs should be quoted in a USD stablecoin.
+/// The average is computed over fixed windows, each closed by a call to `update`.
+#[openbrush::trait_definition]
+pub trait DexTwapPriceOracle: PriceOracle + Ownable {
+    /// Sets the pair pricing an asset and records its first observation
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_twap_config(&mut self, asset: AccountId, config: TwapConfig) -> Result<()>;
+
+    /// Closes the current window of an asset, once at least `window` has elapsed
+    #[ink(message)]
+    fn update(&mut self, asset: AccountId) -> Result<()>;
+
+    /// Returns the pair pricing an asset
+    #[ink(message)]
+    fn twap_config(&self, asset: AccountId) -> Option<TwapConfig>;
+
+    /// Returns the observation the current window of an asset started at
+    #[ink(message)]
+    fn observation(&self, asset: AccountId) -> Option<TwapObservation>;
+}
+
+/// Pair pricing an asset and the window its price is averaged over
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct TwapConfig {
+    pub pair: AccountId,
+    /// Whether the asset is token 0 of the pair
+    pub is_token_0: bool,
+    /// Shortest period the price is averaged over
+    pub window: Timestamp,
+    /// Decimals of the asset
+    pub base_decimals: u8,
+    /// Decimals of the other token of the pair
+    pub quote_decimals: u8,
+}
+
+/// Cumulative price of the pair at a time, and the average price of the last window
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct TwapObservation {
+    pub price_cumulative: WrappedU256,
+    pub timestamp: Timestamp,
+    /// Average price of the last window with `PRICE_PRECISION`, 0 until a window closes
+    pub price: u128,
+}
@@ -0,0 +1,84 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use ink::prelude::string::String;
+use openbrush::{
+    contracts::ownable::*,
+    modifiers,
+    traits::{
+        AccountId,
+        Timestamp,
+    },
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+use super::price_oracle::*;
+
+#[openbrush::wrapper]
+pub type TokenPriceStorageRef = dyn TokenPriceStorage;
+
+/// Interface of DIA's price feed contract
+///
+/// The trait name is part of the message selector, so it must match DIA's.
+#[openbrush::trait_definition]
+pub trait TokenPriceStorage {
+    /// Returns the timestamp in seconds and the price of the pair, e.g. `"ASTR/USD"`
+    #[ink(message)]
+    fn get_latest_price(&self, pair: String) -> Option<(u64, u128)>;
+}
+
+#[openbrush::wrapper]
+pub type DIAPriceOracleRef = dyn DIAPriceOracle + PriceOracle + Ownable;
+
+/// Price oracle reading prices of assets from DIA's price feed contract
+#[openbrush::trait_definition]
+pub trait DIAPriceOracle: PriceOracle + Ownable {
+    /// Sets DIA's price feed contract
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_dia_oracle(&mut self, dia_oracle: AccountId) -> Result<()>;
+
+    /// Maps an asset to the pair key of DIA's feed, the decimals of its price and its heartbeat
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_feed(
+        &mut self,
+        asset: AccountId,
+        key: String,
+        decimals: u8,
+        heartbeat: Timestamp,
+    ) -> Result<()>;
+
+    /// Removes the feed of an asset
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn remove_feed(&mut self, asset: AccountId) -> Result<()>;
+
+    /// Returns DIA's price feed contract
+    #[ink(message)]
+    fn dia_oracle(&self) -> Option<AccountId>;
+
+    /// Returns the feed of an asset
+    #[ink(message)]
+    fn feed(&self, asset: AccountId) -> Option<DIAFeed>;
+}
+
+/// Pair key of DIA's feed for an asset, the decimals of its price and its heartbeat
+#[derive(Debug, Default, Clone, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct DIAFeed {
+    pub key: String,
+    pub decimals: u8,
+    /// Longest time a price is used for after DIA updated it, 0 if unlimited
+    pub heartbeat: Timestamp,
+}
@@ -0,0 +1,32 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::{
+    interest_rate_model::*,
+    types::WrappedU256,
+};
+
+#[openbrush::wrapper]
+pub type DoubleKinkRateModelRef = dyn DoubleKinkRateModel + InterestRateModel;
+
+/// Interest rate model with a gentle slope up to a first kink, a steep slope
+/// up to a second kink and an extreme slope above it
+#[openbrush::trait_definition]
+pub trait DoubleKinkRateModel: InterestRateModel {
+    /// Borrow rate per milliseconds at zero utilization
+    #[ink(message)]
+    fn base_rate_per_milli_second(&self) -> WrappedU256;
+
+    /// Slopes of the borrow rate per milliseconds below the first kink,
+    /// between the kinks and above the second kink
+    #[ink(message)]
+    fn multipliers_per_milli_second(&self) -> [WrappedU256; 3];
+
+    /// Utilization rates at which the slope changes
+    #[ink(message)]
+    fn kinks(&self) -> (WrappedU256, WrappedU256);
+}
@@ -0,0 +1,109 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::error_code::{
+    error_code,
+    module,
+    ErrorCode,
+};
+use openbrush::{
+    contracts::ownable::*,
+    modifiers,
+    traits::{
+        AccountId,
+        Timestamp,
+    },
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+use super::{
+    interest_rate_model::*,
+    types::WrappedU256,
+};
+
+#[openbrush::wrapper]
+pub type DynamicRateModelRef = dyn DynamicRateModel + InterestRateModel + Ownable;
+
+/// Interest rate model whose multiplier adjusts over time toward a target utilization
+///
+/// The multiplier rises while utilization is above the target and falls while it is
+/// below, at `adjustment_speed` per millisecond relative to the distance from the target.
+/// Rates are quoted with the multiplier adjusted up to now; `update_multiplier` stores it.
+#[openbrush::trait_definition]
+pub trait DynamicRateModel: InterestRateModel + Ownable {
+    /// Stores the multiplier adjusted up to now, using the utilization of the pool
+    #[ink(message)]
+    fn update_multiplier(&mut self) -> Result<()>;
+
+    /// Sets the pool whose utilization drives the multiplier
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_pool(&mut self, pool: AccountId) -> Result<()>;
+
+    /// Sets the utilization the multiplier is driven toward and the speed it adjusts at
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_controller_params(
+        &mut self,
+        target_utilization: WrappedU256,
+        adjustment_speed: WrappedU256,
+    ) -> Result<()>;
+
+    /// Pool whose utilization drives the multiplier
+    #[ink(message)]
+    fn pool(&self) -> Option<AccountId>;
+
+    /// Multiplier per milliseconds as last stored
+    #[ink(message)]
+    fn multiplier_per_milli_second(&self) -> WrappedU256;
+
+    /// Multiplier per milliseconds adjusted up to now for `utilization_rate`
+    #[ink(message)]
+    fn current_multiplier_per_milli_second(&self, utilization_rate: WrappedU256) -> WrappedU256;
+
+    /// Utilization the multiplier is driven toward
+    #[ink(message)]
+    fn target_utilization(&self) -> WrappedU256;
+
+    /// Relative change of the multiplier per milliseconds at full distance from the target
+    #[ink(message)]
+    fn adjustment_speed(&self) -> WrappedU256;
+
+    /// Last time the multiplier was stored
+    #[ink(message)]
+    fn last_update(&self) -> Timestamp;
+}
+
+/// Custom error definitions for DynamicRateModel
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
+pub enum Error {
+    PoolIsNotSet = 0,
+    InvalidTargetUtilization = 1,
+    Ownable(OwnableError) = 2,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        match self {
+            Error::Ownable(error) => error.code(),
+            _ => error_code(module::DYNAMIC_RATE_MODEL, self),
+        }
+    }
+}
+
+impl From<OwnableError> for Error {
+    fn from(error: OwnableError) -> Self {
+        Error::Ownable(error)
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
@@ -0,0 +1,152 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use ink::LangError;
+use openbrush::contracts::traits::{
+    access_control::AccessControlError,
+    ownable::OwnableError,
+    psp22::PSP22Error,
+    reentrancy_guard::ReentrancyGuardError,
+};
+use scale::Encode;
+
+/// Stable numeric code of an error, for front ends and indexers.
+///
+/// The code is `module * MODULE_CODE_BASE + variant`, where `variant` is the discriminant
+/// of the variant in its Error enum. Modules and variants keep their numbers across releases:
+/// new ones are appended and removed ones are never reused.
+/// An error wrapping the error of another module reports the code of the wrapped error.
+pub trait ErrorCode {
+    fn code(&self) -> u32;
+}
+
+pub const MODULE_CODE_BASE: u32 = 1000;
+
+/// Numbers of the modules defining errors
+pub mod module {
+    pub const CONTROLLER: u32 = 1;
+    pub const POOL: u32 = 2;
+    pub const MANAGER: u32 = 3;
+    pub const PRICE_ORACLE: u32 = 4;
+    pub const FLASHLOAN_GATEWAY: u32 = 5;
+    pub const WETH_GATEWAY: u32 = 6;
+    pub const POOL_FACTORY: u32 = 7;
+    pub const REGISTRY: u32 = 8;
+    pub const INCENTIVES_CONTROLLER: u32 = 9;
+    pub const UPGRADEABLE: u32 = 10;
+    pub const OWNABLE_2STEP: u32 = 11;
+    pub const FIXED_RATE_MODEL: u32 = 12;
+    pub const JUMP_RATE_MODEL: u32 = 13;
+    pub const DYNAMIC_RATE_MODEL: u32 = 14;
+    pub const TIMELOCK: u32 = 15;
+    pub const GOVERNOR: u32 = 16;
+    pub const VOTES: u32 = 17;
+    pub const VOTE_ESCROW: u32 = 18;
+    pub const TREASURY: u32 = 19;
+    pub const VESTING: u32 = 20;
+    pub const LAY_TOKEN: u32 = 21;
+    pub const STAKING: u32 = 22;
+    pub const MERKLE_DISTRIBUTOR: u32 = 23;
+    pub const ROUTER: u32 = 24;
+    pub const VAULT: u32 = 25;
+    pub const LEVERAGER: u32 = 26;
+    pub const COLLATERAL_SWAP: u32 = 27;
+    pub const FLASH_LIQUIDATOR: u32 = 28;
+    pub const EVM_MIGRATOR: u32 = 29;
+    pub const XCM_DEPOSIT: u32 = 30;
+    pub const DEX_ROUTER: u32 = 31;
+
+    // errors of dependencies
+    pub const PSP22: u32 = 101;
+    pub const OWNABLE: u32 = 102;
+    pub const ACCESS_CONTROL: u32 = 103;
+    pub const REENTRANCY_GUARD: u32 = 104;
+    pub const LANG: u32 = 105;
+}
+
+/// Code of `error` in `module`, from the index its variant is encoded with
+pub fn error_code<E: Encode>(module: u32, error: &E) -> u32 {
+    module * MODULE_CODE_BASE + error.using_encoded(|bytes| u32::from(bytes[0]))
+}
+
+impl ErrorCode for PSP22Error {
+    fn code(&self) -> u32 {
+        error_code(module::PSP22, self)
+    }
+}
+
+impl ErrorCode for OwnableError {
+    fn code(&self) -> u32 {
+        error_code(module::OWNABLE, self)
+    }
+}
+
+impl ErrorCode for AccessControlError {
+    fn code(&self) -> u32 {
+        error_code(module::ACCESS_CONTROL, self)
+    }
+}
+
+impl ErrorCode for ReentrancyGuardError {
+    fn code(&self) -> u32 {
+        error_code(module::REENTRANCY_GUARD, self)
+    }
+}
+
+impl ErrorCode for LangError {
+    fn code(&self) -> u32 {
+        error_code(module::LANG, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::{
+        controller::Error as ControllerError,
+        manager::Error as ManagerError,
+        pool::Error as PoolError,
+    };
+
+    #[test]
+    fn test_code_of_variant() {
+        assert_eq!(PoolError::NotImplemented.code(), 2000);
+        assert_eq!(PoolError::BorrowCashNotAvailable.code(), 2002);
+        assert_eq!(ControllerError::MintIsPaused.code(), 1000);
+        assert_eq!(ControllerError::MathOverflow.code(), 1026);
+        assert_eq!(ManagerError::PriceOracleIsNotSet.code(), 3000);
+    }
+
+    #[test]
+    fn test_code_of_wrapped_error() {
+        assert_eq!(
+            PoolError::Controller(ControllerError::MintIsPaused).code(),
+            ControllerError::MintIsPaused.code()
+        );
+        assert_eq!(
+            ManagerError::Pool(PoolError::Controller(ControllerError::MathOverflow)).code(),
+            1026
+        );
+        assert_eq!(
+            PoolError::PSP22(PSP22Error::InsufficientBalance).code(),
+            PSP22Error::InsufficientBalance.code()
+        );
+        assert_eq!(
+            PSP22Error::InsufficientBalance.code() / MODULE_CODE_BASE,
+            module::PSP22
+        );
+    }
+
+    #[test]
+    fn test_variant_is_encoded_with_its_discriminant() {
+        assert_eq!(PoolError::BorrowCashNotAvailable.encode(), vec![2]);
+        assert_eq!(
+            ControllerError::MathOverflow.encode(),
+            vec![ControllerError::MathOverflow as u8]
+        );
+    }
+}
@@ -0,0 +1,138 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::error_code::{
+    error_code,
+    module,
+    ErrorCode,
+};
+use ink::prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+pub use super::{
+    flashloan_gateway::Error as FlashloanGatewayError,
+    pool::{
+        Error as PoolError,
+        EvmAddress,
+    },
+};
+
+#[openbrush::wrapper]
+pub type EvmMigratorRef = dyn EvmMigrator;
+
+/// Periphery moving a position from the EVM deployment of Starlay into this one.
+///
+/// The EVM deployment lives on the same chain, so the position is read and closed through XVM
+/// instead of being attested off-chain. The EVM account proves it belongs to caller by signing
+/// `migration_digest`. Markets migrated must have the EVM token as underlying.
+#[openbrush::trait_definition]
+pub trait EvmMigrator {
+    /// Get Controller AccountId
+    #[ink(message)]
+    fn controller(&self) -> Option<AccountId>;
+
+    /// Get Flashloan Gateway AccountId
+    #[ink(message)]
+    fn flashloan_gateway(&self) -> Option<AccountId>;
+
+    /// Address of the lending pool of the EVM deployment
+    #[ink(message)]
+    fn evm_lending_pool(&self) -> Option<EvmAddress>;
+
+    /// Number of migrations done from `evm_account`, part of the digest it signs
+    #[ink(message)]
+    fn migration_nonce(&self, evm_account: EvmAddress) -> u64;
+
+    /// Hash `evm_account` signs with `personal_sign` to migrate its position to `account`
+    #[ink(message)]
+    fn migration_digest(&self, evm_account: EvmAddress, account: AccountId) -> [u8; 32];
+
+    /// Migrates the position of `evm_account` to caller.
+    /// `debt_amount` of the underlying of `debt_pool` is flash-borrowed on behalf of caller
+    /// and repaid on the EVM side, then each collateral is withdrawn there and deposited here.
+    /// `evm_account` must approve its lTokens to this contract on the EVM side,
+    /// and caller must approve this contract as delegatee of the borrow in `debt_pool`.
+    #[ink(message)]
+    fn migrate(
+        &mut self,
+        evm_account: EvmAddress,
+        signature: [u8; 65],
+        debt_pool: AccountId,
+        debt_amount: Balance,
+        collaterals: Vec<CollateralMigration>,
+    ) -> Result<()>;
+}
+
+/// Collateral moved from the EVM deployment
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct CollateralMigration {
+    /// Pool deposited into in this deployment
+    pub pool: AccountId,
+    /// lToken of the EVM deployment withdrawn from
+    pub l_token: EvmAddress,
+    /// Amount of underlying migrated
+    pub amount: Balance,
+}
+
+/// Migration run on receiving a flashloan, encoded in its params
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+pub struct MigrationParams {
+    pub account: AccountId,
+    pub evm_account: EvmAddress,
+    pub debt_token: EvmAddress,
+    pub collaterals: Vec<CollateralMigration>,
+}
+
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
+pub enum Error {
+    ControllerIsNotSet = 0,
+    FlashloanGatewayIsNotSet = 1,
+    EvmLendingPoolIsNotSet = 2,
+    MarketNotListed = 3,
+    UnderlyingIsNotEvmErc20 = 4,
+    InvalidSignature = 5,
+    NothingToMigrate = 6,
+    CallerIsNotFlashloanGateway = 7,
+    InvalidFlashloanParams = 8,
+    EvmCallFailed = 9,
+    Pool(PoolError) = 10,
+    FlashloanGateway(FlashloanGatewayError) = 11,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        match self {
+            Error::Pool(error) => error.code(),
+            Error::FlashloanGateway(error) => error.code(),
+            _ => error_code(module::EVM_MIGRATOR, self),
+        }
+    }
+}
+
+impl From<PoolError> for Error {
+    fn from(error: PoolError) -> Self {
+        Error::Pool(error)
+    }
+}
+
+impl From<FlashloanGatewayError> for Error {
+    fn from(error: FlashloanGatewayError) -> Self {
+        Error::FlashloanGateway(error)
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
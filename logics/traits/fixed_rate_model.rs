@@ -0,0 +1,61 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::error_code::ErrorCode;
+use openbrush::{
+    contracts::ownable::*,
+    modifiers,
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+use super::{
+    interest_rate_model::*,
+    types::WrappedU256,
+};
+
+#[openbrush::wrapper]
+pub type FixedRateModelRef = dyn FixedRateModel + InterestRateModel + Ownable;
+
+/// Interest rate model whose borrow rate is set by its owner regardless of utilization, for fixed-rate credit lines
+#[openbrush::trait_definition]
+pub trait FixedRateModel: InterestRateModel + Ownable {
+    /// Sets the borrow rate, given as a rate per year
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_borrow_rate(&mut self, borrow_rate_per_year: WrappedU256) -> Result<()>;
+
+    /// Borrow rate per milliseconds
+    #[ink(message)]
+    fn borrow_rate_per_milli_second(&self) -> WrappedU256;
+}
+
+/// Custom error definitions for FixedRateModel
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
+pub enum Error {
+    Ownable(OwnableError) = 0,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        match self {
+            Error::Ownable(error) => error.code(),
+        }
+    }
+}
+
+impl From<OwnableError> for Error {
+    fn from(error: OwnableError) -> Self {
+        Error::Ownable(error)
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
@@ -0,0 +1,130 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::error_code::{
+    error_code,
+    module,
+    ErrorCode,
+};
+use ink::prelude::vec::Vec;
+use openbrush::{
+    contracts::psp22::PSP22Error,
+    traits::{
+        AccountId,
+        Balance,
+    },
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+use super::{
+    dex_router::DexRouterError,
+    flashloan_gateway::Error as FlashloanGatewayError,
+    pool::Error as PoolError,
+};
+
+#[openbrush::wrapper]
+pub type FlashLiquidatorRef = dyn FlashLiquidator;
+
+/// Periphery liquidating borrows with a flashloan, so that liquidators need no upfront capital
+#[openbrush::trait_definition]
+pub trait FlashLiquidator {
+    /// Get Controller AccountId
+    #[ink(message)]
+    fn controller(&self) -> Option<AccountId>;
+
+    /// Get Flashloan Gateway AccountId
+    #[ink(message)]
+    fn flashloan_gateway(&self) -> Option<AccountId>;
+
+    /// Get DEX Router AccountId
+    #[ink(message)]
+    fn dex_router(&self) -> Option<AccountId>;
+
+    /// Flash-borrows `repay_amount` of `repay_asset` to liquidate `borrower`,
+    /// then redeems the seized collateral and swaps it back to `repay_asset` along `path`,
+    /// which is empty if both assets are the same.
+    /// Once the flashloan is repaid, the profit, of at least `min_profit`, is sent to caller.
+    #[ink(message)]
+    fn liquidate(
+        &mut self,
+        borrower: AccountId,
+        repay_asset: AccountId,
+        repay_amount: Balance,
+        collateral_asset: AccountId,
+        path: Vec<AccountId>,
+        min_profit: Balance,
+    ) -> Result<()>;
+}
+
+/// Liquidation run on receiving a flashloan, encoded in its params
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+pub struct LiquidationParams {
+    pub account: AccountId,
+    pub borrower: AccountId,
+    pub collateral_asset: AccountId,
+    pub path: Vec<AccountId>,
+    pub min_profit: Balance,
+}
+
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
+pub enum Error {
+    ControllerIsNotSet = 0,
+    FlashloanGatewayIsNotSet = 1,
+    DexRouterIsNotSet = 2,
+    MarketNotListed = 3,
+    InvalidSwapPath = 4,
+    CallerIsNotFlashloanGateway = 5,
+    InvalidFlashloanParams = 6,
+    InsufficientProfit = 7,
+    Pool(PoolError) = 8,
+    FlashloanGateway(FlashloanGatewayError) = 9,
+    DexRouter(DexRouterError) = 10,
+    PSP22(PSP22Error) = 11,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        match self {
+            Error::Pool(error) => error.code(),
+            Error::FlashloanGateway(error) => error.code(),
+            Error::DexRouter(error) => error.code(),
+            Error::PSP22(error) => error.code(),
+            _ => error_code(module::FLASH_LIQUIDATOR, self),
+        }
+    }
+}
+
+impl From<PoolError> for Error {
+    fn from(error: PoolError) -> Self {
+        Error::Pool(error)
+    }
+}
+
+impl From<FlashloanGatewayError> for Error {
+    fn from(error: FlashloanGatewayError) -> Self {
+        Error::FlashloanGateway(error)
+    }
+}
+
+impl From<DexRouterError> for Error {
+    fn from(error: DexRouterError) -> Self {
+        Error::DexRouter(error)
+    }
+}
+
+impl From<PSP22Error> for Error {
+    fn from(error: PSP22Error) -> Self {
+        Error::PSP22(error)
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
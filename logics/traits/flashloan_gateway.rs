@@ -5,10 +5,20 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use super::error_code::{
+    error_code,
+    module,
+    ErrorCode,
+};
 use crate::traits::pool::Error as PoolError;
 use ink::prelude::vec::Vec;
 use openbrush::{
-    contracts::psp22::PSP22Error,
+    contracts::{
+        ownable::*,
+        psp22::PSP22Error,
+        reentrancy_guard::ReentrancyGuardError,
+    },
+    modifiers,
     traits::{
         AccountId,
         Balance,
@@ -20,15 +30,25 @@ use scale::{
 };
 
 #[openbrush::wrapper]
-pub type FlashloanGatewayRef = dyn FlashloanGateway;
+pub type FlashloanGatewayRef = dyn FlashloanGateway + Ownable;
+
+/// Largest flashloan premium and protocol share, in basis points
+pub const MAX_BPS: u128 = 10000;
 
 #[openbrush::trait_definition]
-pub trait FlashloanGateway {
+pub trait FlashloanGateway: Ownable {
     /// Allows smartcontracts to access the liquidity of the pool within one transaction, as long as the amount taken plus a fee is returned.
     ///  IMPORTANT There are security concerns for developers of flashloan receiver contracts that must be kept into consideration.
+    ///
+    /// For each asset, `mods` selects a `FlashLoanType`: with `Borrowing`, the amount is not
+    /// returned but borrowed by `on_behalf_of`, who must be the caller or have delegated enough
+    /// credit to the caller on the pool. No fee is charged on borrowed amounts.
+    ///
+    /// Flashloans cannot be reentered, and each asset can be lent once per flashloan. Repaid
+    /// flashloans fail unless the underlying balance of each pool grew by its premium.
     #[ink(message)]
     fn flashloan(
-        &self,
+        &mut self,
         receiver_address: AccountId,
         assets: Vec<AccountId>,
         amounts: Vec<Balance>,
@@ -41,30 +61,134 @@ pub trait FlashloanGateway {
     #[ink(message)]
     fn flashloan_premium_total(&self) -> u128;
 
+    /// Returns the share of the fee sent to the treasury, the rest going to the pool's reserves
+    #[ink(message)]
+    fn flashloan_premium_to_protocol(&self) -> u128;
+
+    /// Returns Treasury Address
+    #[ink(message)]
+    fn treasury(&self) -> Option<AccountId>;
+
+    /// Sets the fee on flash loans, in basis points
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_flashloan_premium_total(&mut self, premium: u128) -> Result<()>;
+
+    /// Sets the share of the fee sent to the treasury, in basis points of the fee
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_flashloan_premium_to_protocol(&mut self, premium_to_protocol: u128) -> Result<()>;
+
+    /// Sets the treasury receiving the protocol share of the fee
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_treasury(&mut self, treasury: AccountId) -> Result<()>;
+
+    /// Lets flashloans received or initiated by an account pay a reduced fee, in basis points.
+    /// None removes the account from the whitelist.
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_premium_exemption(&mut self, account: AccountId, premium: Option<u128>) -> Result<()>;
+
+    /// Returns the reduced fee of an account, if whitelisted
+    #[ink(message)]
+    fn premium_exemption(&self, account: AccountId) -> Option<u128>;
+
+    /// Sets the guardian allowed to pause flashloans along with the owner
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_guardian(&mut self, guardian: AccountId) -> Result<()>;
+
+    /// Returns the guardian allowed to pause flashloans
+    #[ink(message)]
+    fn guardian(&self) -> Option<AccountId>;
+
+    /// Pauses or resumes all flashloans. Caller must be the guardian or the owner.
+    #[ink(message)]
+    fn set_flashloan_paused(&mut self, paused: bool) -> Result<()>;
+
+    /// Returns whether all flashloans are paused
+    #[ink(message)]
+    fn flashloan_paused(&self) -> bool;
+
+    /// Enables or disables flashloans of an asset. Caller must be the guardian or the owner.
+    #[ink(message)]
+    fn set_flashloan_enabled(&mut self, asset: AccountId, enabled: bool) -> Result<()>;
+
+    /// Returns whether flashloans of an asset are enabled, true by default
+    #[ink(message)]
+    fn flashloan_enabled(&self, asset: AccountId) -> bool;
+
     /// Returns Controller Address
     #[ink(message)]
     fn controller(&self) -> Option<AccountId>;
+
+    /// Registers the pool of the underlying of every market listed in the controller
+    /// whose underlying is a PSP22 token
+    #[ink(message)]
+    fn sync_pools(&mut self) -> Result<()>;
+
+    /// Returns the pool lending an underlying asset, from the registry or the controller.
+    /// Flashloans of assets without pool, or whose pool does not lend a PSP22 token,
+    /// fail with `MarketNotListed`.
+    #[ink(message)]
+    fn pool_of(&self, asset: AccountId) -> Option<AccountId>;
 }
 
 #[derive(Debug, PartialEq, Eq, Encode, Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
 pub enum Error {
-    InconsistentFlashloanParams,
-    InvalidFlashloanExecutorReturn,
-    MarketNotListed,
-    ControllerIsNotSet,
-    PSP22(PSP22Error),
-    Pool(PoolError),
+    InconsistentFlashloanParams = 0,
+    InvalidFlashloanExecutorReturn = 1,
+    MarketNotListed = 2,
+    ControllerIsNotSet = 3,
+    InvalidFlashloanPremium = 4,
+    InvalidFlashloanMode = 5,
+    FlashloanPaused = 6,
+    FlashloanDisabled = 7,
+    CallerIsNotGuardian = 8,
+    InsufficientFlashloanRepayment = 9,
+    PSP22(PSP22Error) = 10,
+    Pool(PoolError) = 11,
+    Ownable(OwnableError) = 12,
+    ReentrancyGuard(ReentrancyGuardError) = 13,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        match self {
+            Error::PSP22(error) => error.code(),
+            Error::Pool(error) => error.code(),
+            Error::Ownable(error) => error.code(),
+            Error::ReentrancyGuard(error) => error.code(),
+            _ => error_code(module::FLASHLOAN_GATEWAY, self),
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Encode, Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 #[repr(u8)]
 pub enum FlashLoanType {
+    /// The amount plus the fee is returned at the end of the flashloan
     None = 0,
+    /// A variable rate borrow position of the amount is opened for `on_behalf_of`
     Borrowing = 1,
 }
 
+impl TryFrom<u8> for FlashLoanType {
+    type Error = Error;
+
+    fn try_from(mode: u8) -> Result<Self> {
+        match mode {
+            0 => Ok(FlashLoanType::None),
+            1 => Ok(FlashLoanType::Borrowing),
+            _ => Err(Error::InvalidFlashloanMode),
+        }
+    }
+}
+
 impl From<PSP22Error> for Error {
     fn from(error: PSP22Error) -> Self {
         Error::PSP22(error)
@@ -77,4 +201,16 @@ impl From<PoolError> for Error {
     }
 }
 
+impl From<OwnableError> for Error {
+    fn from(error: OwnableError) -> Self {
+        Error::Ownable(error)
+    }
+}
+
+impl From<ReentrancyGuardError> for Error {
+    fn from(error: ReentrancyGuardError) -> Self {
+        Error::ReentrancyGuard(error)
+    }
+}
+
 pub type Result<T> = core::result::Result<T, Error>;
@@ -17,9 +17,12 @@ pub type FlashloanReceiverRef = dyn FlashloanReceiver;
 #[openbrush::trait_definition]
 pub trait FlashloanReceiver {
     /// Run FlashLoan action
+    ///
+    /// `initiator` is the caller of the flashloan and `params` is forwarded as is from it,
+    /// so that receivers can run parameterized strategies. Returns whether the action succeeded.
     #[ink(message)]
     fn execute_operation(
-        &self,
+        &mut self,
         assets: Vec<AccountId>,
         amounts: Vec<Balance>,
         premiums: Vec<Balance>,
@@ -0,0 +1,251 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::error_code::{
+    error_code,
+    module,
+    ErrorCode,
+};
+use ink::prelude::{
+    string::String,
+    vec::Vec,
+};
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Timestamp,
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+pub use super::{
+    timelock::Error as TimelockError,
+    votes::Error as VotesError,
+};
+
+#[openbrush::wrapper]
+pub type GovernorRef = dyn Governor;
+
+/// Identifier of a proposal, assigned in order of creation
+pub type ProposalId = u64;
+
+/// Largest quorum, in basis points
+pub const MAX_BPS: u128 = 10000;
+
+/// Largest number of actions in a proposal
+pub const MAX_ACTIONS: u32 = 10;
+
+/// Lets holders of a `Votes` token propose and vote on admin actions.
+/// Votes are weighted by the voting power delegated at the snapshot of the proposal.
+/// Succeeded proposals are queued in the timelock, which this contract must own,
+/// and executed through it once its delay has passed.
+#[openbrush::trait_definition]
+pub trait Governor {
+    /// Get the AccountId of the token votes are weighted by
+    #[ink(message)]
+    fn token(&self) -> Option<AccountId>;
+
+    /// Get the AccountId of the Timelock executing proposals
+    #[ink(message)]
+    fn timelock(&self) -> Option<AccountId>;
+
+    /// Time between proposing and the snapshot voting starts from
+    #[ink(message)]
+    fn voting_delay(&self) -> Timestamp;
+
+    /// Time voting lasts from the snapshot
+    #[ink(message)]
+    fn voting_period(&self) -> Timestamp;
+
+    /// Least voting power required to propose
+    #[ink(message)]
+    fn proposal_threshold(&self) -> Balance;
+
+    /// Share of the total supply that must vote for or abstain, in basis points
+    #[ink(message)]
+    fn quorum_fraction(&self) -> u128;
+
+    /// Votes required for a proposal whose snapshot is `timestamp` to succeed
+    #[ink(message)]
+    fn quorum(&self, timestamp: Timestamp) -> Result<Balance>;
+
+    /// Number of proposals created
+    #[ink(message)]
+    fn proposal_count(&self) -> ProposalId;
+
+    /// Get the proposal
+    #[ink(message)]
+    fn proposal(&self, proposal_id: ProposalId) -> Option<Proposal>;
+
+    /// Get the actions of the proposal
+    #[ink(message)]
+    fn proposal_actions(&self, proposal_id: ProposalId) -> Vec<ProposalAction>;
+
+    /// Get the vote cast by `account` on the proposal
+    #[ink(message)]
+    fn receipt(&self, proposal_id: ProposalId, account: AccountId) -> Option<Receipt>;
+
+    /// Current state of the proposal
+    #[ink(message)]
+    fn state(&self, proposal_id: ProposalId) -> Result<ProposalState>;
+
+    /// Creates a proposal of `actions`, voted on after `voting_delay`.
+    /// Caller must have at least `proposal_threshold` of voting power.
+    #[ink(message)]
+    fn propose(&mut self, actions: Vec<ProposalAction>, description: String) -> Result<ProposalId>;
+
+    /// Casts the voting power of caller at the snapshot on an active proposal
+    #[ink(message)]
+    fn cast_vote(&mut self, proposal_id: ProposalId, support: VoteType) -> Result<Balance>;
+
+    /// Queues the actions of a succeeded proposal in the timelock
+    #[ink(message)]
+    fn queue(&mut self, proposal_id: ProposalId) -> Result<()>;
+
+    /// Executes the actions of a queued proposal through the timelock, once its eta has passed
+    #[ink(message)]
+    fn execute(&mut self, proposal_id: ProposalId) -> Result<()>;
+
+    /// Cancels a proposal not executed yet, removing its actions from the timelock.
+    /// Callable by the proposer, or by anyone once the proposer falls below the threshold.
+    #[ink(message)]
+    fn cancel(&mut self, proposal_id: ProposalId) -> Result<()>;
+
+    /// Sets the voting delay. Must be called by the timelock, i.e. through a proposal
+    #[ink(message)]
+    fn set_voting_delay(&mut self, voting_delay: Timestamp) -> Result<()>;
+
+    /// Sets the voting period. Must be called by the timelock, i.e. through a proposal
+    #[ink(message)]
+    fn set_voting_period(&mut self, voting_period: Timestamp) -> Result<()>;
+
+    /// Sets the proposal threshold. Must be called by the timelock, i.e. through a proposal
+    #[ink(message)]
+    fn set_proposal_threshold(&mut self, proposal_threshold: Balance) -> Result<()>;
+
+    /// Sets the quorum fraction. Must be called by the timelock, i.e. through a proposal
+    #[ink(message)]
+    fn set_quorum_fraction(&mut self, quorum_fraction: u128) -> Result<()>;
+}
+
+/// Call made by the timelock if the proposal is executed
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct ProposalAction {
+    /// Contract called
+    pub target: AccountId,
+    /// Selector of the message called
+    pub selector: [u8; 4],
+    /// Encoded arguments of the message
+    pub input: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Proposal {
+    pub proposer: AccountId,
+    /// Time voting power is read at, voting is open after it
+    pub snapshot: Timestamp,
+    /// Time voting is closed after
+    pub deadline: Timestamp,
+    /// Time the actions can be executed from, once queued
+    pub eta: Option<Timestamp>,
+    pub for_votes: Balance,
+    pub against_votes: Balance,
+    pub abstain_votes: Balance,
+    pub canceled: bool,
+    pub executed: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum VoteType {
+    Against,
+    For,
+    Abstain,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Receipt {
+    pub support: VoteType,
+    pub votes: Balance,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum ProposalState {
+    Pending,
+    Active,
+    Canceled,
+    Defeated,
+    Succeeded,
+    Queued,
+    Expired,
+    Executed,
+}
+
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
+pub enum Error {
+    TokenIsNotSet = 0,
+    TimelockIsNotSet = 1,
+    InvalidVotingPeriod = 2,
+    InvalidQuorumFraction = 3,
+    InvalidActions = 4,
+    BelowProposalThreshold = 5,
+    ProposalNotFound = 6,
+    ProposalNotActive = 7,
+    ProposalNotSucceeded = 8,
+    ProposalNotQueued = 9,
+    ProposalIsFinalized = 10,
+    ActionAlreadyQueued = 11,
+    AlreadyVoted = 12,
+    CallerIsNotProposer = 13,
+    CallerIsNotTimelock = 14,
+    Votes(VotesError) = 15,
+    Timelock(TimelockError) = 16,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        match self {
+            Error::Votes(error) => error.code(),
+            Error::Timelock(error) => error.code(),
+            _ => error_code(module::GOVERNOR, self),
+        }
+    }
+}
+
+impl From<VotesError> for Error {
+    fn from(error: VotesError) -> Self {
+        Error::Votes(error)
+    }
+}
+
+impl From<TimelockError> for Error {
+    fn from(error: TimelockError) -> Self {
+        Error::Timelock(error)
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
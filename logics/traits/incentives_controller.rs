@@ -5,11 +5,25 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use super::{
+    error_code::{
+        error_code,
+        module,
+        ErrorCode,
+    },
+    types::WrappedU256,
+};
+use ink::prelude::vec::Vec;
 use openbrush::{
     self,
+    contracts::{
+        ownable::*,
+        psp22::PSP22Error,
+    },
     traits::{
         AccountId,
         Balance,
+        Timestamp,
     },
 };
 use scale::{
@@ -20,6 +34,16 @@ use scale::{
 #[openbrush::wrapper]
 pub type IncentivesControllerRef = dyn IncentivesController;
 
+/// Basis points
+pub const BPS: u128 = 10000;
+/// Share of the rewards of a balance earned without veLAY, in basis points.
+/// The rest is earned in proportion of the veLAY share of the account relative to its share of
+/// the pool, as in the EVM protocol
+pub const UNBOOSTED_REWARDS_BPS: u128 = 4000;
+
+/// Emits rewards to suppliers and borrowers of pools, outside of the pools and the Controller
+/// so the emission can be replaced without touching them.
+/// Pools call `handle_action` before any change of the balances of an account.
 #[openbrush::trait_definition]
 pub trait IncentivesController {
     /// Called by pools to accrue rewards.
@@ -32,12 +56,114 @@ pub trait IncentivesController {
         user_deposit: Balance,
         user_borrow: Balance,
     ) -> Result<()>;
+
+    /// Claims all rewards of `user` to `to`. Caller must be `user` or a claimer authorized by it,
+    /// or a claim operator approved by it, which can only claim to `user`.
+    #[ink(message)]
+    fn claim_rewards_on_behalf(&mut self, user: AccountId, to: AccountId) -> Result<Balance>;
+
+    /// Token in which rewards are paid
+    #[ink(message)]
+    fn reward_token(&self) -> Option<AccountId>;
+
+    /// Rewards emitted per millisecond to suppliers and to borrowers of a pool
+    #[ink(message)]
+    fn reward_speeds(&self, pool: AccountId) -> (Balance, Balance);
+
+    /// Rewards accrued by `user` and not claimed yet
+    #[ink(message)]
+    fn unclaimed_rewards(&self, user: AccountId) -> Balance;
+
+    /// Sets the rewards emitted per millisecond to suppliers and to borrowers of a pool
+    #[ink(message)]
+    fn set_reward_speeds(
+        &mut self,
+        pool: AccountId,
+        supply_speed: Balance,
+        borrow_speed: Balance,
+    ) -> Result<()>;
+
+    /// Sets the token in which rewards are paid, unclaimed rewards are paid in the new token
+    #[ink(message)]
+    fn set_reward_token(&mut self, reward_token: Option<AccountId>) -> Result<()>;
+
+    /// Authorizes `claimer` to claim the rewards of the caller
+    #[ink(message)]
+    fn set_claimer(&mut self, claimer: Option<AccountId>) -> Result<()>;
+
+    /// Returns the claimer authorized by `user`
+    #[ink(message)]
+    fn claimer_of(&self, user: AccountId) -> Option<AccountId>;
+
+    /// Approves `operator`, e.g. an auto-compounder, to claim the rewards of the caller to it
+    #[ink(message)]
+    fn set_claim_operator(&mut self, operator: AccountId, approved: bool) -> Result<()>;
+
+    /// Returns whether `operator` is approved to claim the rewards of `user` to `user`
+    #[ink(message)]
+    fn is_claim_operator(&self, user: AccountId, operator: AccountId) -> bool;
+
+    /// Returns the pools rewards have been configured for
+    #[ink(message)]
+    fn pools(&self) -> Vec<AccountId>;
+
+    /// Returns the reward indexes of suppliers and of borrowers of a pool
+    #[ink(message)]
+    fn reward_states(&self, pool: AccountId) -> (RewardState, RewardState);
+
+    /// Sets the vote escrow whose veLAY boosts rewards, rewards are not boosted if \`None\`
+    #[ink(message)]
+    fn set_vote_escrow(&mut self, vote_escrow: Option<AccountId>) -> Result<()>;
+
+    /// Returns the vote escrow whose veLAY boosts rewards
+    #[ink(message)]
+    fn vote_escrow(&self) -> Option<AccountId>;
+}
+
+/// Rewards per unit of balance accumulated in a pool since its configuration
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct RewardState {
+    /// Accumulated rewards per unit of balance, scaled by 1e18
+    pub index: WrappedU256,
+    pub timestamp: Timestamp,
 }
 
 #[derive(Debug, PartialEq, Eq, Encode, Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
 pub enum Error {
-    CallerIsNotConfiguredAsset,
+    CallerIsNotConfiguredAsset = 0,
+    CallerIsNotAuthorizedClaimer = 1,
+    OperatorCanOnlyClaimToUser = 2,
+    RewardTokenIsNotSet = 3,
+    PSP22(PSP22Error) = 4,
+    Ownable(OwnableError) = 5,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        match self {
+            Error::PSP22(error) => error.code(),
+            Error::Ownable(error) => error.code(),
+            _ => error_code(module::INCENTIVES_CONTROLLER, self),
+        }
+    }
+}
+
+impl From<PSP22Error> for Error {
+    fn from(error: PSP22Error) -> Self {
+        Error::PSP22(error)
+    }
+}
+
+impl From<OwnableError> for Error {
+    fn from(error: OwnableError) -> Self {
+        Error::Ownable(error)
+    }
 }
 
 pub type Result<T> = core::result::Result<T, Error>;
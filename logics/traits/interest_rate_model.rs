@@ -5,7 +5,12 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use ink::prelude::vec::Vec;
 use openbrush::traits::Balance;
+use scale::{
+    Decode,
+    Encode,
+};
 
 use super::types::WrappedU256;
 
@@ -15,6 +20,10 @@ pub type InterestRateModelRef = dyn InterestRateModel;
 /// Trait defines the interface for interest rate model
 #[openbrush::trait_definition]
 pub trait InterestRateModel {
+    /// Calculates the utilization rate of the market: `borrows / (cash + borrows - reserves)`
+    #[ink(message)]
+    fn utilization_rate(&self, cash: Balance, borrows: Balance, reserves: Balance) -> WrappedU256;
+
     /// Calculates the current borrow interest rate per milliseconds
     #[ink(message)]
     fn get_borrow_rate(&self, cash: Balance, borrows: Balance, reserves: Balance) -> WrappedU256;
@@ -28,4 +37,18 @@ pub trait InterestRateModel {
         reserves: Balance,
         reserve_factor_mantissa: WrappedU256,
     ) -> WrappedU256;
+
+    /// Samples the borrow and supply rates at `points` utilization rates evenly spaced from 0 to 100%
+    #[ink(message)]
+    fn sample_curve(&self, points: u8, reserve_factor_mantissa: WrappedU256)
+        -> Vec<RateCurvePoint>;
+}
+
+/// Rates per milliseconds of the model at a utilization rate
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct RateCurvePoint {
+    pub utilization_rate: WrappedU256,
+    pub borrow_rate: WrappedU256,
+    pub supply_rate: WrappedU256,
 }
@@ -0,0 +1,85 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::error_code::{
+    error_code,
+    module,
+    ErrorCode,
+};
+use openbrush::{
+    contracts::ownable::*,
+    modifiers,
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+use super::{
+    interest_rate_model::*,
+    types::WrappedU256,
+};
+
+#[openbrush::wrapper]
+pub type JumpRateModelRef = dyn JumpRateModel + InterestRateModel + Ownable;
+
+/// Interest rate model whose borrow rate jumps above a target utilization, updatable by its owner
+#[openbrush::trait_definition]
+pub trait JumpRateModel: InterestRateModel + Ownable {
+    /// Updates the parameters of the model, given as rates per year
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn update_jump_rate_model(
+        &mut self,
+        base_rate_per_year: WrappedU256,
+        multiplier_per_year: WrappedU256,
+        jump_multiplier_per_year: WrappedU256,
+        kink: WrappedU256,
+    ) -> Result<()>;
+
+    /// Borrow rate per milliseconds at zero utilization
+    #[ink(message)]
+    fn base_rate_per_milli_second(&self) -> WrappedU256;
+
+    /// Slope of the borrow rate per milliseconds up to the kink
+    #[ink(message)]
+    fn multiplier_per_milli_second(&self) -> WrappedU256;
+
+    /// Slope of the borrow rate per milliseconds above the kink
+    #[ink(message)]
+    fn jump_multiplier_per_milli_second(&self) -> WrappedU256;
+
+    /// Utilization rate above which the jump multiplier applies
+    #[ink(message)]
+    fn kink(&self) -> WrappedU256;
+}
+
+/// Custom error definitions for JumpRateModel
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
+pub enum Error {
+    InvalidKink = 0,
+    Ownable(OwnableError) = 1,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        match self {
+            Error::Ownable(error) => error.code(),
+            _ => error_code(module::JUMP_RATE_MODEL, self),
+        }
+    }
+}
+
+impl From<OwnableError> for Error {
+    fn from(error: OwnableError) -> Self {
+        Error::Ownable(error)
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
@@ -0,0 +1,84 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::error_code::{
+    error_code,
+    module,
+    ErrorCode,
+};
+use openbrush::{
+    contracts::{
+        access_control::AccessControlError,
+        psp22::PSP22Error,
+    },
+    traits::{
+        AccountId,
+        Balance,
+    },
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+#[openbrush::wrapper]
+pub type LayTokenRef = dyn LayToken;
+
+/// Supply management of LAY, the governance token.
+/// New tokens are minted by the holders of the minter role, e.g. the incentives controller,
+/// and the total supply never exceeds the cap set at deployment.
+#[openbrush::trait_definition]
+pub trait LayToken {
+    /// Mints `amount` to `to`
+    #[ink(message)]
+    fn mint(&mut self, to: AccountId, amount: Balance) -> Result<()>;
+
+    /// Burns `amount` of the caller's tokens
+    #[ink(message)]
+    fn burn(&mut self, amount: Balance) -> Result<()>;
+
+    /// Burns `amount` of the tokens of `account`, spending the caller's allowance
+    #[ink(message)]
+    fn burn_from(&mut self, account: AccountId, amount: Balance) -> Result<()>;
+
+    /// Returns the maximum total supply
+    #[ink(message)]
+    fn cap(&self) -> Balance;
+}
+
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
+pub enum Error {
+    CapExceeded = 0,
+    PSP22(PSP22Error) = 1,
+    AccessControl(AccessControlError) = 2,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        match self {
+            Error::PSP22(error) => error.code(),
+            Error::AccessControl(error) => error.code(),
+            _ => error_code(module::LAY_TOKEN, self),
+        }
+    }
+}
+
+impl From<PSP22Error> for Error {
+    fn from(error: PSP22Error) -> Self {
+        Error::PSP22(error)
+    }
+}
+
+impl From<AccessControlError> for Error {
+    fn from(error: AccessControlError) -> Self {
+        Error::AccessControl(error)
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
@@ -5,6 +5,11 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use super::error_code::{
+    error_code,
+    module,
+    ErrorCode,
+};
 use openbrush::{
     contracts::psp22::PSP22Error,
     traits::{
@@ -20,6 +25,7 @@ use scale::{
 
 use super::{
     controller::Error as ControllerError,
+    flashloan_gateway::Error as FlashloanGatewayError,
     pool::Error as PoolError,
 };
 
@@ -45,6 +51,10 @@ pub trait Leverager {
     #[ink(message)]
     fn manager(&self) -> Option<AccountId>;
 
+    /// Get Flashloan Gateway AccountId
+    #[ink(message)]
+    fn flashloan_gateway(&self) -> Option<AccountId>;
+
     /// Get Borrowable information of an account
     #[ink(message)]
     fn get_available_borrows(&self, account: AccountId) -> Option<AvailableBorrows>;
@@ -99,6 +109,35 @@ pub trait Leverager {
     /// Loop the withdrawing and repaying asset
     #[ink(message)]
     fn close(&mut self, asset: AccountId) -> Result<()>;
+
+    /// Sets the flashloan gateway used for one-shot leverage
+    #[ink(message)]
+    fn set_flashloan_gateway(&mut self, flashloan_gateway: AccountId) -> Result<()>;
+
+    /// Deposits `amount` along with a flashloan borrowed on behalf of caller,
+    /// for a deposit of `leverage` times `amount` in a single transaction.
+    /// `leverage` is in basis points, e.g. 30000 for 3x.
+    /// Caller must approve Leverager as delegatee of the borrow.
+    #[ink(message)]
+    fn leverage_asset(&mut self, asset: AccountId, amount: Balance, leverage: u128) -> Result<()>;
+
+    /// Repays the whole borrow of asset with a flashloan, repaid by redeeming caller's deposit,
+    /// and sends the remaining deposit back to caller.
+    /// Caller must approve its pool tokens to Leverager.
+    #[ink(message)]
+    fn close_position(&mut self, asset: AccountId) -> Result<()>;
+}
+
+/// Operation run by Leverager on receiving a flashloan, encoded in its params
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+pub enum FlashloanOperation {
+    /// Deposits `deposit` along with the loan for `account`
+    Open {
+        account: AccountId,
+        deposit: Balance,
+    },
+    /// Repays the borrow of `account` with the loan and withdraws its deposit
+    Close { account: AccountId },
 }
 
 #[derive(Clone, Decode, Encode, Default)]
@@ -126,17 +165,36 @@ pub struct Withdrawable {
 /// Custom error definitions for Controller
 #[derive(Debug, PartialEq, Eq, Encode, Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
 pub enum Error {
-    ManagerIsNotSet,
-    CallerIsNotManager,
-    InappropriateBorrowRate,
-    InappropriateLoopCount,
-    ControllerIsNotSet,
-    MarketNotListed,
-    WETHIsNotSet,
-    Controller(ControllerError),
-    Pool(PoolError),
-    PSP22(PSP22Error),
+    ManagerIsNotSet = 0,
+    CallerIsNotManager = 1,
+    InappropriateBorrowRate = 2,
+    InappropriateLoopCount = 3,
+    ControllerIsNotSet = 4,
+    MarketNotListed = 5,
+    WETHIsNotSet = 6,
+    InappropriateLeverage = 7,
+    FlashloanGatewayIsNotSet = 8,
+    CallerIsNotFlashloanGateway = 9,
+    InvalidFlashloanParams = 10,
+    InsufficientDeposit = 11,
+    Controller(ControllerError) = 12,
+    Pool(PoolError) = 13,
+    FlashloanGateway(FlashloanGatewayError) = 14,
+    PSP22(PSP22Error) = 15,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        match self {
+            Error::Controller(error) => error.code(),
+            Error::Pool(error) => error.code(),
+            Error::FlashloanGateway(error) => error.code(),
+            Error::PSP22(error) => error.code(),
+            _ => error_code(module::LEVERAGER, self),
+        }
+    }
 }
 
 impl From<ControllerError> for Error {
@@ -151,6 +209,12 @@ impl From<PoolError> for Error {
     }
 }
 
+impl From<FlashloanGatewayError> for Error {
+    fn from(error: FlashloanGatewayError) -> Self {
+        Error::FlashloanGateway(error)
+    }
+}
+
 impl From<PSP22Error> for Error {
     fn from(error: PSP22Error) -> Self {
         Error::PSP22(error)
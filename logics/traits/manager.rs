@@ -7,13 +7,42 @@
 
 use super::{
     controller::Error as ControllerError,
-    pool::Error as PoolError,
+    error_code::{
+        error_code,
+        module,
+        ErrorCode,
+    },
+    flashloan_gateway::Error as FlashloanGatewayError,
+    ownable_2step::Error as Ownable2StepError,
+    pool::{
+        Error as PoolError,
+        Facet,
+        KeeperIncentive,
+        UnderlyingCallConfig,
+    },
+    pool_factory::{
+        Error as PoolFactoryError,
+        PoolParameters,
+        RateModelParameters,
+    },
+    price_oracle::{
+        DeviationBreaker,
+        Error as PriceOracleError,
+        PriceBounds,
+    },
+    upgradeable::Error as UpgradeableError,
 };
+use ink::prelude::vec::Vec;
 use openbrush::{
-    contracts::traits::access_control::AccessControlError,
+    contracts::traits::{
+        access_control::AccessControlError,
+        psp22::PSP22Error,
+    },
     traits::{
         AccountId,
         Balance,
+        Hash,
+        Timestamp,
     },
 };
 use scale::{
@@ -27,6 +56,7 @@ use super::types::WrappedU256;
 pub type ManagerRef = dyn Manager;
 
 /// Trait for managing a lending pool (Controller, all pools etc)
+/// Contracts administered must have this contract as their manager or owner.
 #[openbrush::trait_definition]
 pub trait Manager {
     /// Get the controller
@@ -101,18 +131,478 @@ pub trait Manager {
     #[ink(message)]
     fn reduce_reserves(&mut self, pool: AccountId, amount: Balance) -> Result<()>;
 
+    /// Accrues interest and covers bad debt with reserves (call Pool)
+    #[ink(message)]
+    fn cover_bad_debt(&mut self, pool: AccountId, amount: Balance) -> Result<()>;
+
     /// A public function to sweep accidental token transfers to this contract. (call Pool)
     #[ink(message)]
     fn sweep_token(&mut self, pool: AccountId, asset: AccountId) -> Result<()>;
+
+    /// Update the pause status of seize action (call Controller)
+    #[ink(message)]
+    fn set_seize_guardian_paused(&mut self, paused: bool) -> Result<()>;
+
+    /// Update the pause status of transfer action (call Controller)
+    #[ink(message)]
+    fn set_transfer_guardian_paused(&mut self, paused: bool) -> Result<()>;
+
+    /// Sets the account allowed to shut the protocol down besides this contract (call Controller)
+    #[ink(message)]
+    fn set_shutdown_guardian(&mut self, guardian: Option<AccountId>) -> Result<()>;
+
+    /// Sets the factory allowed to list the pools it deploys (call Controller)
+    #[ink(message)]
+    fn set_pool_factory(&mut self, pool_factory: Option<AccountId>) -> Result<()>;
+
+    /// Deploys and lists the pool of `underlying` and takes over its management (call Pool Factory)
+    #[ink(message)]
+    fn create_pool(&mut self, underlying: AccountId, parameters: PoolParameters) -> Result<()>;
+
+    /// Registers the code new pools are deployed from (call Pool Factory)
+    #[ink(message)]
+    fn set_pool_code_hash(&mut self, code_hash: Hash) -> Result<()>;
+
+    /// Deploys a rate model from an approved code (call Pool Factory)
+    #[ink(message)]
+    fn create_rate_model(&mut self, code_hash: Hash, parameters: RateModelParameters)
+        -> Result<()>;
+
+    /// Approves or revokes a code rate models can be deployed from (call Pool Factory)
+    #[ink(message)]
+    fn set_rate_model_code_hash_approval(&mut self, code_hash: Hash, approved: bool) -> Result<()>;
+
+    /// Shuts the protocol down in an incident (call Controller)
+    #[ink(message)]
+    fn shutdown_protocol(&mut self) -> Result<()>;
+
+    /// Resumes mints and transfers after a shutdown, borrows staying frozen (call Controller)
+    #[ink(message)]
+    fn recover_protocol(&mut self) -> Result<()>;
+
+    /// Resumes borrows once the protocol has recovered (call Controller)
+    #[ink(message)]
+    fn reactivate_protocol(&mut self) -> Result<()>;
+
+    /// Sets a new interest rate model for the pool (call Pool)
+    #[ink(message)]
+    fn set_interest_rate_model(
+        &mut self,
+        pool: AccountId,
+        new_interest_rate_model: AccountId,
+    ) -> Result<()>;
+
+    /// Sets the liquidation threshold of the pool (call Pool)
+    #[ink(message)]
+    fn set_liquidation_threshold(
+        &mut self,
+        pool: AccountId,
+        new_liquidation_threshold: u128,
+    ) -> Result<()>;
+
+    /// Sets the maximum borrow rate of the pool (call Pool)
+    #[ink(message)]
+    fn set_borrow_rate_max_mantissa(
+        &mut self,
+        pool: AccountId,
+        new_borrow_rate_max_mantissa: WrappedU256,
+    ) -> Result<()>;
+
+    /// Sets the stable rate configuration of the pool (call Pool)
+    #[ink(message)]
+    fn set_stable_rate_config(
+        &mut self,
+        pool: AccountId,
+        enabled: bool,
+        premium_mantissa: WrappedU256,
+        rebalance_threshold_mantissa: WrappedU256,
+    ) -> Result<()>;
+
+    /// Sets the options of calls the pool makes to its underlying (call Pool)
+    #[ink(message)]
+    fn set_underlying_call_config(
+        &mut self,
+        pool: AccountId,
+        config: UnderlyingCallConfig,
+    ) -> Result<()>;
+
+    /// Caps the period interest of the pool accrues for at once (call Pool)
+    #[ink(message)]
+    fn set_max_accrual_delta(
+        &mut self,
+        pool: AccountId,
+        new_max_accrual_delta: Timestamp,
+    ) -> Result<()>;
+
+    /// Switches the pool between compounded and simple interest (call Pool)
+    #[ink(message)]
+    fn set_compound_interest(&mut self, pool: AccountId, enabled: bool) -> Result<()>;
+
+    /// Sets the premium on flash mints of the pool (call Pool)
+    #[ink(message)]
+    fn set_flash_mint_premium(&mut self, pool: AccountId, premium: u128) -> Result<()>;
+
+    /// Sets the one-time fee charged on borrows from the pool (call Pool)
+    #[ink(message)]
+    fn set_origination_fee(&mut self, pool: AccountId, fee: u128) -> Result<()>;
+
+    /// Sets the reserves kept in the pool when swept to the treasury of this contract (call Pool)
+    #[ink(message)]
+    fn set_reserves_sweep_threshold(&mut self, pool: AccountId, threshold: Balance) -> Result<()>;
+
+    /// Sets the bounties paid to keepers of the pool (call Pool)
+    #[ink(message)]
+    fn set_keeper_incentive(&mut self, pool: AccountId, incentive: KeeperIncentive) -> Result<()>;
+
+    /// Sets the incentives controller of the pool (call Pool)
+    #[ink(message)]
+    fn set_incentives_controller(
+        &mut self,
+        pool: AccountId,
+        incentives_controller: AccountId,
+    ) -> Result<()>;
+
+    /// Caps underlying a single account can supply to the pool (call Pool)
+    #[ink(message)]
+    fn set_account_supply_cap(&mut self, pool: AccountId, new_cap: Balance) -> Result<()>;
+
+    /// Caps underlying leaving the pool per window (call Pool)
+    #[ink(message)]
+    fn set_outflow_limit(
+        &mut self,
+        pool: AccountId,
+        limit: Balance,
+        window: Timestamp,
+    ) -> Result<()>;
+
+    /// Authorizes or revokes a reporter of prices (call Price Oracle)
+    #[ink(message)]
+    fn set_reporter(&mut self, reporter: AccountId, authorized: bool) -> Result<()>;
+
+    /// Sets the longest time a price of the asset is used for (call Price Oracle)
+    #[ink(message)]
+    fn set_heartbeat(&mut self, asset: AccountId, heartbeat: Timestamp) -> Result<()>;
+
+    /// Sets the decimals prices of the asset are posted with (call Price Oracle)
+    #[ink(message)]
+    fn set_price_decimals(&mut self, asset: AccountId, decimals: u8) -> Result<()>;
+
+    /// Sets the range prices of the asset must be within (call Price Oracle)
+    #[ink(message)]
+    fn set_price_bounds(&mut self, asset: AccountId, bounds: PriceBounds) -> Result<()>;
+
+    /// Sets the largest deviation of prices of the asset (call Price Oracle)
+    #[ink(message)]
+    fn set_deviation_breaker(&mut self, asset: AccountId, breaker: DeviationBreaker) -> Result<()>;
+
+    /// Sets the fee on flash loans (call Flashloan Gateway)
+    #[ink(message)]
+    fn set_flashloan_premium_total(&mut self, premium: u128) -> Result<()>;
+
+    /// Sets the share of the flashloan fee sent to the treasury (call Flashloan Gateway)
+    #[ink(message)]
+    fn set_flashloan_premium_to_protocol(&mut self, premium_to_protocol: u128) -> Result<()>;
+
+    /// Sets the treasury receiving reduced reserves and the protocol share of the flashloan fee
+    /// (call Flashloan Gateway if set)
+    #[ink(message)]
+    fn set_treasury(&mut self, treasury: AccountId) -> Result<()>;
+
+    /// Get the treasury receiving reduced reserves
+    #[ink(message)]
+    fn treasury(&self) -> Option<AccountId>;
+
+    /// Sets the reduced flashloan fee of an account (call Flashloan Gateway)
+    #[ink(message)]
+    fn set_premium_exemption(&mut self, account: AccountId, premium: Option<u128>) -> Result<()>;
+
+    /// Pauses or resumes all flashloans (call Flashloan Gateway)
+    #[ink(message)]
+    fn set_flashloan_paused(&mut self, paused: bool) -> Result<()>;
+
+    /// Enables or disables flashloans of an asset (call Flashloan Gateway)
+    #[ink(message)]
+    fn set_flashloan_enabled(&mut self, asset: AccountId, enabled: bool) -> Result<()>;
+
+    /// Takes over admin rights of the controller proposed to this contract (call Controller)
+    #[ink(message)]
+    fn accept_controller_manager(&mut self) -> Result<()>;
+
+    /// Takes over admin rights of the pool proposed to this contract (call Pool)
+    #[ink(message)]
+    fn accept_pool_manager(&mut self, pool: AccountId) -> Result<()>;
+
+    /// Takes over ownership of an oracle, gateway or rate model proposed to this contract
+    #[ink(message)]
+    fn accept_ownership_of(&mut self, target: AccountId) -> Result<()>;
+
+    /// Replaces the code of the controller and migrates its storage (call Controller)
+    #[ink(message)]
+    fn upgrade_controller_code(&mut self, code_hash: Hash) -> Result<()>;
+
+    /// Replaces the code of the pool and migrates its storage (call Pool)
+    #[ink(message)]
+    fn upgrade_pool_code(&mut self, pool: AccountId, code_hash: Hash) -> Result<()>;
+
+    /// Sets the code the messages of a facet of the pool are delegated to (call Pool)
+    #[ink(message)]
+    fn set_pool_facet(
+        &mut self,
+        pool: AccountId,
+        facet: Facet,
+        code_hash: Option<Hash>,
+    ) -> Result<()>;
+
+    /// Runs admin actions in order, reverting all of them if any fails.
+    /// Each action requires the same role as its own message.
+    #[ink(message)]
+    fn execute_batch(&mut self, calls: Vec<Call>) -> Result<()>;
+}
+
+/// Admin action of the Manager, run by `execute_batch`
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum Call {
+    SetController {
+        address: AccountId,
+    },
+    SetPriceOracle {
+        new_oracle: AccountId,
+    },
+    SetFlashloanGateway {
+        new_flashloan_gateway: AccountId,
+    },
+    SupportMarket {
+        pool: AccountId,
+        underlying: AccountId,
+    },
+    SupportMarketWithCollateralFactorMantissa {
+        pool: AccountId,
+        underlying: AccountId,
+        collateral_factor_mantissa: WrappedU256,
+    },
+    SetCollateralFactorMantissa {
+        pool: AccountId,
+        new_collateral_factor_mantissa: WrappedU256,
+    },
+    SetMintGuardianPaused {
+        pool: AccountId,
+        paused: bool,
+    },
+    SetBorrowGuardianPaused {
+        pool: AccountId,
+        paused: bool,
+    },
+    SetCloseFactorMantissa {
+        new_close_factor_mantissa: WrappedU256,
+    },
+    SetLiquidationIncentiveMantissa {
+        new_liquidation_incentive_mantissa: WrappedU256,
+    },
+    SetBorrowCap {
+        pool: AccountId,
+        new_cap: Balance,
+    },
+    SetReserveFactorMantissa {
+        pool: AccountId,
+        new_reserve_factor_mantissa: WrappedU256,
+    },
+    ReduceReserves {
+        pool: AccountId,
+        amount: Balance,
+    },
+    CoverBadDebt {
+        pool: AccountId,
+        amount: Balance,
+    },
+    SweepToken {
+        pool: AccountId,
+        asset: AccountId,
+    },
+    SetSeizeGuardianPaused {
+        paused: bool,
+    },
+    SetTransferGuardianPaused {
+        paused: bool,
+    },
+    SetShutdownGuardian {
+        guardian: Option<AccountId>,
+    },
+    SetPoolFactory {
+        pool_factory: Option<AccountId>,
+    },
+    CreatePool {
+        underlying: AccountId,
+        parameters: PoolParameters,
+    },
+    SetPoolCodeHash {
+        code_hash: Hash,
+    },
+    CreateRateModel {
+        code_hash: Hash,
+        parameters: RateModelParameters,
+    },
+    SetRateModelCodeHashApproval {
+        code_hash: Hash,
+        approved: bool,
+    },
+    ShutdownProtocol,
+    RecoverProtocol,
+    ReactivateProtocol,
+    SetInterestRateModel {
+        pool: AccountId,
+        new_interest_rate_model: AccountId,
+    },
+    SetLiquidationThreshold {
+        pool: AccountId,
+        new_liquidation_threshold: u128,
+    },
+    SetBorrowRateMaxMantissa {
+        pool: AccountId,
+        new_borrow_rate_max_mantissa: WrappedU256,
+    },
+    SetStableRateConfig {
+        pool: AccountId,
+        enabled: bool,
+        premium_mantissa: WrappedU256,
+        rebalance_threshold_mantissa: WrappedU256,
+    },
+    SetUnderlyingCallConfig {
+        pool: AccountId,
+        config: UnderlyingCallConfig,
+    },
+    SetMaxAccrualDelta {
+        pool: AccountId,
+        new_max_accrual_delta: Timestamp,
+    },
+    SetCompoundInterest {
+        pool: AccountId,
+        enabled: bool,
+    },
+    SetFlashMintPremium {
+        pool: AccountId,
+        premium: u128,
+    },
+    SetOriginationFee {
+        pool: AccountId,
+        fee: u128,
+    },
+    SetReservesSweepThreshold {
+        pool: AccountId,
+        threshold: Balance,
+    },
+    SetKeeperIncentive {
+        pool: AccountId,
+        incentive: KeeperIncentive,
+    },
+    SetIncentivesController {
+        pool: AccountId,
+        incentives_controller: AccountId,
+    },
+    SetAccountSupplyCap {
+        pool: AccountId,
+        new_cap: Balance,
+    },
+    SetOutflowLimit {
+        pool: AccountId,
+        limit: Balance,
+        window: Timestamp,
+    },
+    SetReporter {
+        reporter: AccountId,
+        authorized: bool,
+    },
+    SetHeartbeat {
+        asset: AccountId,
+        heartbeat: Timestamp,
+    },
+    SetPriceDecimals {
+        asset: AccountId,
+        decimals: u8,
+    },
+    SetPriceBounds {
+        asset: AccountId,
+        bounds: PriceBounds,
+    },
+    SetDeviationBreaker {
+        asset: AccountId,
+        breaker: DeviationBreaker,
+    },
+    SetFlashloanPremiumTotal {
+        premium: u128,
+    },
+    SetFlashloanPremiumToProtocol {
+        premium_to_protocol: u128,
+    },
+    SetTreasury {
+        treasury: AccountId,
+    },
+    SetPremiumExemption {
+        account: AccountId,
+        premium: Option<u128>,
+    },
+    SetFlashloanPaused {
+        paused: bool,
+    },
+    SetFlashloanEnabled {
+        asset: AccountId,
+        enabled: bool,
+    },
+    AcceptControllerManager,
+    AcceptPoolManager {
+        pool: AccountId,
+    },
+    AcceptOwnershipOf {
+        target: AccountId,
+    },
+    UpgradeControllerCode {
+        code_hash: Hash,
+    },
+    UpgradePoolCode {
+        pool: AccountId,
+        code_hash: Hash,
+    },
+    SetPoolFacet {
+        pool: AccountId,
+        facet: Facet,
+        code_hash: Option<Hash>,
+    },
 }
 
 /// Custom error definitions for Manager
 #[derive(Debug, PartialEq, Eq, Encode, Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
 pub enum Error {
-    AccessControl(AccessControlError),
-    Controller(ControllerError),
-    Pool(PoolError),
+    PriceOracleIsNotSet = 0,
+    FlashloanGatewayIsNotSet = 1,
+    PoolFactoryIsNotSet = 2,
+    AccessControl(AccessControlError) = 3,
+    Controller(ControllerError) = 4,
+    Pool(PoolError) = 5,
+    PriceOracle(PriceOracleError) = 6,
+    FlashloanGateway(FlashloanGatewayError) = 7,
+    Ownable2Step(Ownable2StepError) = 8,
+    Upgradeable(UpgradeableError) = 9,
+    PoolFactory(PoolFactoryError) = 10,
+    PSP22(PSP22Error) = 11,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        match self {
+            Error::AccessControl(error) => error.code(),
+            Error::Controller(error) => error.code(),
+            Error::Pool(error) => error.code(),
+            Error::PriceOracle(error) => error.code(),
+            Error::FlashloanGateway(error) => error.code(),
+            Error::Ownable2Step(error) => error.code(),
+            Error::Upgradeable(error) => error.code(),
+            Error::PoolFactory(error) => error.code(),
+            Error::PSP22(error) => error.code(),
+            _ => error_code(module::MANAGER, self),
+        }
+    }
 }
 
 impl From<AccessControlError> for Error {
@@ -133,4 +623,40 @@ impl From<PoolError> for Error {
     }
 }
 
+impl From<PriceOracleError> for Error {
+    fn from(error: PriceOracleError) -> Self {
+        Error::PriceOracle(error)
+    }
+}
+
+impl From<FlashloanGatewayError> for Error {
+    fn from(error: FlashloanGatewayError) -> Self {
+        Error::FlashloanGateway(error)
+    }
+}
+
+impl From<Ownable2StepError> for Error {
+    fn from(error: Ownable2StepError) -> Self {
+        Error::Ownable2Step(error)
+    }
+}
+
+impl From<UpgradeableError> for Error {
+    fn from(error: UpgradeableError) -> Self {
+        Error::Upgradeable(error)
+    }
+}
+
+impl From<PoolFactoryError> for Error {
+    fn from(error: PoolFactoryError) -> Self {
+        Error::PoolFactory(error)
+    }
+}
+
+impl From<PSP22Error> for Error {
+    fn from(error: PSP22Error) -> Self {
+        Error::PSP22(error)
+    }
+}
+
 pub type Result<T> = core::result::Result<T, Error>;
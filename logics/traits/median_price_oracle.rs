@@ -0,0 +1,59 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use ink::prelude::vec::Vec;
+use openbrush::{
+    contracts::ownable::*,
+    modifiers,
+    traits::{
+        AccountId,
+        Timestamp,
+    },
+};
+
+use super::price_oracle::*;
+
+#[openbrush::wrapper]
+pub type MedianPriceOracleRef = dyn MedianPriceOracle + PriceOracle + Ownable;
+
+/// Price oracle returning the median of the prices posted by its reporters
+///
+/// Reports older than the heartbeat of the asset are ignored, so that a failing or compromised
+/// reporter cannot move the price as long as most reporters are honest.
+#[openbrush::trait_definition]
+pub trait MedianPriceOracle: PriceOracle + Ownable {
+    /// Authorizes or revokes a reporter
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_reporter(&mut self, reporter: AccountId, authorized: bool) -> Result<()>;
+
+    /// Returns the reporters whose prices are aggregated
+    #[ink(message)]
+    fn reporters(&self) -> Vec<AccountId>;
+
+    /// Sets the number of fresh reports a price requires
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_min_reports(&mut self, min_reports: u8) -> Result<()>;
+
+    /// Returns the number of fresh reports a price requires
+    #[ink(message)]
+    fn min_reports(&self) -> u8;
+
+    /// Sets the longest time a report of the asset is used for after it was posted. 0 disables it
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_heartbeat(&mut self, asset: AccountId, heartbeat: Timestamp) -> Result<()>;
+
+    /// Returns the longest time a report of the asset is used for after it was posted
+    #[ink(message)]
+    fn heartbeat(&self, asset: AccountId) -> Timestamp;
+
+    /// Returns the last price a reporter posted for the asset, even if stale
+    #[ink(message)]
+    fn report(&self, asset: AccountId, reporter: AccountId) -> Option<PriceData>;
+}
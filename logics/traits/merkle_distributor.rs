@@ -0,0 +1,98 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::error_code::{
+    error_code,
+    module,
+    ErrorCode,
+};
+use ink::prelude::vec::Vec;
+use openbrush::{
+    contracts::{
+        ownable::*,
+        psp22::PSP22Error,
+    },
+    traits::{
+        AccountId,
+        Balance,
+        Hash,
+    },
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+#[openbrush::wrapper]
+pub type MerkleDistributorRef = dyn MerkleDistributor + Ownable;
+
+/// Distributes rewards computed off-chain, e.g. retroactive usage incentives and airdrops.
+/// The owner, governance, posts the merkle root of the cumulative amount of each account
+/// and anyone can claim the part not claimed yet to the account with a proof.
+#[openbrush::trait_definition]
+pub trait MerkleDistributor {
+    /// Replaces the merkle root, the new root must include the amounts of the previous one
+    #[ink(message)]
+    fn set_merkle_root(&mut self, merkle_root: Hash) -> Result<()>;
+
+    /// Transfers `amount` of the distributed token to `to`, e.g. to recover unclaimed rewards
+    #[ink(message)]
+    fn withdraw(&mut self, to: AccountId, amount: Balance) -> Result<()>;
+
+    /// Transfers to `account` its cumulative `amount` minus what it claimed already,
+    /// `proof` proves the entry of (`account`, `amount`) under the merkle root
+    #[ink(message)]
+    fn claim(&mut self, account: AccountId, amount: Balance, proof: Vec<Hash>) -> Result<Balance>;
+
+    /// Returns the current merkle root
+    #[ink(message)]
+    fn merkle_root(&self) -> Option<Hash>;
+
+    /// Returns the cumulative amount claimed by `account`
+    #[ink(message)]
+    fn claimed(&self, account: AccountId) -> Balance;
+
+    /// Returns the distributed token
+    #[ink(message)]
+    fn token(&self) -> Option<AccountId>;
+}
+
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
+pub enum Error {
+    TokenIsNotSet = 0,
+    MerkleRootIsNotSet = 1,
+    InvalidProof = 2,
+    NothingToClaim = 3,
+    PSP22(PSP22Error) = 4,
+    Ownable(OwnableError) = 5,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        match self {
+            Error::PSP22(error) => error.code(),
+            Error::Ownable(error) => error.code(),
+            _ => error_code(module::MERKLE_DISTRIBUTOR, self),
+        }
+    }
+}
+
+impl From<PSP22Error> for Error {
+    fn from(error: PSP22Error) -> Self {
+        Error::PSP22(error)
+    }
+}
+
+impl From<OwnableError> for Error {
+    fn from(error: OwnableError) -> Self {
+        Error::Ownable(error)
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
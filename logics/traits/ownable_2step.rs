@@ -0,0 +1,62 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::error_code::{
+    error_code,
+    module,
+    ErrorCode,
+};
+use openbrush::{
+    contracts::ownable::*,
+    traits::AccountId,
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+#[openbrush::wrapper]
+pub type Ownable2StepRef = dyn Ownable2Step + Ownable;
+
+/// Ownership handed over in two steps, so it cannot be lost to a wrong address.
+/// `transfer_ownership` of `Ownable` only proposes the new owner, who takes over by accepting.
+#[openbrush::trait_definition]
+pub trait Ownable2Step: Ownable {
+    /// Account proposed as the next owner
+    #[ink(message)]
+    fn pending_owner(&self) -> Option<AccountId>;
+
+    /// Takes over the ownership proposed to caller
+    #[ink(message)]
+    fn accept_ownership(&mut self) -> Result<()>;
+}
+
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
+pub enum Error {
+    CallerIsNotPendingOwner = 0,
+    Ownable(OwnableError) = 1,
+    CallerIsNotPendingManager = 2,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        match self {
+            Error::Ownable(error) => error.code(),
+            _ => error_code(module::OWNABLE_2STEP, self),
+        }
+    }
+}
+
+impl From<OwnableError> for Error {
+    fn from(error: OwnableError) -> Self {
+        Error::Ownable(error)
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
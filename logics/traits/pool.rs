@@ -5,7 +5,15 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use ink::LangError;
+use super::error_code::{
+    error_code,
+    module,
+    ErrorCode,
+};
+use ink::{
+    prelude::vec::Vec,
+    LangError,
+};
 use openbrush::{
     contracts::{
         psp22::PSP22Error,
@@ -17,6 +25,7 @@ use openbrush::{
     traits::{
         AccountId,
         Balance,
+        Hash,
         Timestamp,
     },
 };
@@ -42,11 +51,11 @@ pub trait Pool: PSP22 + PSP22Metadata {
     fn accrue_interest(&mut self) -> Result<()>;
 
     /// Sender supplies assets into the market and receives pool tokens in exchange
-    #[ink(message)]
+    #[ink(message, payable)]
     fn mint(&mut self, mint_amount: Balance) -> Result<()>;
 
     /// Sender supplies assets into the market and receives pool tokens in exchange
-    #[ink(message)]
+    #[ink(message, payable)]
     fn mint_to(&mut self, mint_account: AccountId, mint_amount: Balance) -> Result<()>;
 
     /// Sender redeems pool tokens in exchange for the underlying asset
@@ -65,30 +74,50 @@ pub trait Pool: PSP22 + PSP22Metadata {
     #[ink(message)]
     fn borrow(&mut self, borrow_amount: Balance) -> Result<()>;
 
+    /// Sender borrows assets at a variable rate or at a stable rate locked at origination
+    #[ink(message)]
+    fn borrow_with_rate_mode(&mut self, borrow_amount: Balance, rate_mode: RateMode) -> Result<()>;
+
+    /// Converts all of the sender's debt of the other rate mode into `rate_mode`
+    #[ink(message)]
+    fn swap_borrow_rate_mode(&mut self, rate_mode: RateMode) -> Result<()>;
+
+    /// Resets the stable rate of an account to the current stable rate
+    /// once it has drifted from it by more than the rebalance threshold
+    #[ink(message)]
+    fn rebalance_stable_borrow_rate(&mut self, account: AccountId) -> Result<()>;
+
     /// borrows assets from the protocol to Borrower
     #[ink(message)]
     fn borrow_for(&mut self, borrower: AccountId, borrow_amount: Balance) -> Result<()>;
 
+    /// Opens a borrow position for the underlying a flashloan released, instead of its repayment.
+    /// `initiator` must be the borrower or have enough delegate allowance from the borrower.
     #[ink(message)]
-    fn borrow_for_flashloan(&mut self, borrower: AccountId, borrow_amount: Balance) -> Result<()>;
+    fn borrow_for_flashloan(
+        &mut self,
+        borrower: AccountId,
+        initiator: AccountId,
+        borrow_amount: Balance,
+    ) -> Result<()>;
 
     /// Sender repays their own borrow
-    #[ink(message)]
+    #[ink(message, payable)]
     fn repay_borrow(&mut self, repay_amount: Balance) -> Result<()>;
 
     /// Sender repays all their own borrow
-    #[ink(message)]
+    #[ink(message, payable)]
     fn repay_borrow_all(&mut self) -> Result<()>;
 
     /// Sender repays a borrow belonging to borrower
-    #[ink(message)]
+    #[ink(message, payable)]
     fn repay_borrow_behalf(&mut self, borrower: AccountId, repay_amount: Balance) -> Result<()>;
 
     #[ink(message)]
     fn borrows_scaled(&self) -> Balance;
 
     /// The sender liquidates the borrowers collateral.
-    #[ink(message)]
+    #[ink(message, payable)]
     fn liquidate_borrow(
         &mut self,
         borrower: AccountId,
@@ -96,6 +125,10 @@ pub trait Pool: PSP22 + PSP22Metadata {
         collateral: AccountId,
     ) -> Result<()>;
 
+    /// Liquidate the largest amount allowed by the close factor and the borrower's collateral
+    #[ink(message, payable)]
+    fn liquidate_borrow_max(&mut self, borrower: AccountId, collateral: AccountId) -> Result<()>;
+
     /// Transfers collateral tokens (this market) to the liquidator.
     #[ink(message)]
     fn seize(
@@ -105,15 +138,41 @@ pub trait Pool: PSP22 + PSP22Metadata {
         seize_tokens: Balance,
     ) -> Result<()>;
 
+    /// Writes off the debt of a borrower with no collateral left in any market
+    /// as bad debt of this pool. Returns the amount written off
+    #[ink(message)]
+    fn absorb(&mut self, borrower: AccountId) -> Result<Balance>;
+
     /// Transfer Underlying Token.
     #[ink(message)]
     fn transfer_underlying(&mut self, to: AccountId, amount: Balance) -> Result<()>;
 
+    /// Accounts underlying returned by a flashloan receiver into the pool's cash,
+    /// adding the premium to the reserves. Only callable by the flashloan gateway,
+    /// and fails unless the pool holds the repayment on top of its cash.
+    #[ink(message)]
+    fn record_flashloan_repayment(&mut self, amount: Balance, premium: Balance) -> Result<()>;
+
+    /// Mints `amount` of pool tokens to `receiver` for the duration of its `execute_operation`,
+    /// then burns them plus a premium, which accrues to suppliers.
+    /// The receiver can call back into the pool, e.g. to transfer or borrow against the tokens.
+    #[ink(message)]
+    fn flash_mint(&mut self, amount: Balance, receiver: AccountId, params: Vec<u8>) -> Result<()>;
+
     // admin functions
     /// Sets a new controller for the market
     #[ink(message)]
     fn set_controller(&mut self, new_controller: AccountId) -> Result<()>;
 
+    /// Proposes a new manager of the market, e.g. a timelock.
+    /// Admin rights are handed over once it calls `accept_manager`
+    #[ink(message)]
+    fn set_manager(&mut self, new_manager: AccountId) -> Result<()>;
+
+    /// Takes over admin rights of the market proposed to caller
+    #[ink(message)]
+    fn accept_manager(&mut self) -> Result<()>;
+
     /// accrues interest and sets a new reserve factor for the protocol using _set_reserve_factor_mantissa
     #[ink(message)]
     fn set_reserve_factor_mantissa(
@@ -129,18 +188,88 @@ pub trait Pool: PSP22 + PSP22Metadata {
     #[ink(message)]
     fn set_liquidation_threshold(&mut self, new_liquidation_threshold: u128) -> Result<()>;
 
-    /// The sender adds to reserves.
+    /// Sets the maximum borrow rate applied on accrual, bounded by the protocol's absolute maximum
     #[ink(message)]
+    fn set_borrow_rate_max_mantissa(
+        &mut self,
+        new_borrow_rate_max_mantissa: WrappedU256,
+    ) -> Result<()>;
+
+    /// Enables stable rate borrowing and sets the premium over the variable rate
+    /// and the drift allowing a stable rate to be rebalanced
+    #[ink(message)]
+    fn set_stable_rate_config(
+        &mut self,
+        enabled: bool,
+        premium_mantissa: WrappedU256,
+        rebalance_threshold_mantissa: WrappedU256,
+    ) -> Result<()>;
+
+    /// Sets the call flags and gas limit used when calling the underlying token contract
+    #[ink(message)]
+    fn set_underlying_call_config(&mut self, config: UnderlyingCallConfig) -> Result<()>;
+
+    /// Caps the period interest accrues for in a single accrual. 0 disables the cap
+    #[ink(message)]
+    fn set_max_accrual_delta(&mut self, new_max_accrual_delta: Timestamp) -> Result<()>;
+
+    /// Switches accrual between a compounded borrow rate and simple `rate * delta`
+    #[ink(message)]
+    fn set_compound_interest(&mut self, enabled: bool) -> Result<()>;
+
+    /// Sets the premium on flash mints, in basis points
+    #[ink(message)]
+    fn set_flash_mint_premium(&mut self, premium: u128) -> Result<()>;
+
+    /// Sets the one-time fee charged on borrows, in basis points
+    #[ink(message)]
+    fn set_origination_fee(&mut self, fee: u128) -> Result<()>;
+
+    /// Sets the bounties paid from reserves to keepers of this pool
+    #[ink(message)]
+    fn set_keeper_incentive(&mut self, incentive: KeeperIncentive) -> Result<()>;
+
+    /// Caps underlying a single account can supply. 0 disables the cap
+    #[ink(message)]
+    fn set_account_supply_cap(&mut self, new_cap: Balance) -> Result<()>;
+
+    /// Caps underlying leaving the pool by redeem and borrow to `limit` per `window`. 0 disables the cap
+    #[ink(message)]
+    fn set_outflow_limit(&mut self, limit: Balance, window: Timestamp) -> Result<()>;
+
+    /// Sets the treasury and the reserves kept in the pool when swept to it. None disables sweeping
+    #[ink(message)]
+    fn set_reserves_sweep_config(
+        &mut self,
+        treasury: Option<AccountId>,
+        threshold: Balance,
+    ) -> Result<()>;
+
+    /// The sender adds to reserves.
+    #[ink(message, payable)]
     fn add_reserves(&mut self, amount: Balance) -> Result<()>;
 
+    /// The sender, e.g. the safety module, repays bad debt of this pool
+    #[ink(message, payable)]
+    fn repay_bad_debt(&mut self, amount: Balance) -> Result<()>;
+
     /// Accrues interest and reduces reserves by transferring to admin
     #[ink(message)]
     fn reduce_reserves(&mut self, amount: Balance) -> Result<()>;
 
+    /// Accrues interest and covers bad debt of this pool with its reserves
+    #[ink(message)]
+    fn cover_bad_debt(&mut self, amount: Balance) -> Result<()>;
+
     /// A public function to sweep accidental token transfers to this contract. Tokens are sent to admin
     #[ink(message)]
     fn sweep_token(&mut self, asset: AccountId) -> Result<()>;
 
+    /// Accrues interest and transfers reserves above the sweep threshold to the treasury.
+    /// Callable by anyone, returns the amount swept
+    #[ink(message)]
+    fn sweep_reserves_to_treasury(&mut self) -> Result<Balance>;
+
     /// Delegates borrowing power to a user on the specific debt token
     #[ink(message)]
     fn approve_delegate(&mut self, delegatee: AccountId, amount: Balance) -> Result<()>;
@@ -169,11 +298,17 @@ pub trait Pool: PSP22 + PSP22Metadata {
     /// Set incentives Controller AccountId for reward
     #[ink(message)]
     fn set_incentives_controller(&mut self, incentives_controller: AccountId) -> Result<()>;
+    /// Sets the code the messages of `facet` are delegated to, `None` to unset it
+    #[ink(message)]
+    fn set_facet(&mut self, facet: Facet, code_hash: Option<Hash>) -> Result<()>;
 
     // view functions
     /// AccountId of underlying token
     #[ink(message)]
     fn underlying(&self) -> Option<AccountId>;
+    /// Kind of underlying asset held by this pool
+    #[ink(message)]
+    fn underlying_asset(&self) -> Option<Underlying>;
     /// Accountid of controller
     #[ink(message)]
     fn controller(&self) -> Option<AccountId>;
@@ -181,6 +316,9 @@ pub trait Pool: PSP22 + PSP22Metadata {
     /// AccountId of manager
     fn manager(&self) -> Option<AccountId>;
     #[ink(message)]
+    /// AccountId proposed as the next manager
+    fn pending_manager(&self) -> Option<AccountId>;
+    #[ink(message)]
     /// AccountId of incentives controller
     fn incentives_controller(&self) -> Option<AccountId>;
     /// Get Pool's underlying Balance
@@ -192,6 +330,9 @@ pub trait Pool: PSP22 + PSP22Metadata {
     /// Total reserves in pool
     #[ink(message)]
     fn total_reserves(&self) -> Balance;
+    /// Debt written off and not repaid yet
+    #[ink(message)]
+    fn bad_debt(&self) -> Balance;
     /// Get collateral detail of an account
     #[ink(message)]
     fn get_account_snapshot(&self, account: AccountId) -> (Balance, Balance, U256);
@@ -201,6 +342,12 @@ pub trait Pool: PSP22 + PSP22Metadata {
     /// Get user's borrow with interest
     #[ink(message)]
     fn borrow_balance_current(&mut self, account: AccountId) -> Result<Balance>;
+    /// Get user's accrued interest on borrows and the borrow index it was last updated at
+    #[ink(message)]
+    fn accrued_interest_of(&mut self, account: AccountId) -> Result<(Balance, WrappedU256)>;
+    /// Get user's borrow principal and the borrow index it was last updated at
+    #[ink(message)]
+    fn borrow_snapshot(&self, account: AccountId) -> BorrowSnapshot;
     /// Get last block stamp of interest calculation process execution
     #[ink(message)]
     fn get_accrual_block_timestamp(&self) -> Timestamp;
@@ -210,6 +357,9 @@ pub trait Pool: PSP22 + PSP22Metadata {
     /// Calculates the current supply interest rate per milliseconds
     #[ink(message)]
     fn supply_rate_per_msec(&self) -> WrappedU256;
+    /// Calculates the current utilization rate with the interest rate model
+    #[ink(message)]
+    fn utilization_rate(&self) -> WrappedU256;
     /// Return the saved exchange rate
     #[ink(message)]
     fn exchange_rate_stored(&self) -> WrappedU256;
@@ -231,6 +381,66 @@ pub trait Pool: PSP22 + PSP22Metadata {
     /// Get Liquidation Threshold for
     #[ink(message)]
     fn liquidation_threshold(&self) -> u128;
+    /// Maximum borrow rate applied on accrual
+    #[ink(message)]
+    fn borrow_rate_max_mantissa(&self) -> WrappedU256;
+    /// Whether new borrows can be taken at a stable rate
+    #[ink(message)]
+    fn stable_borrow_enabled(&self) -> bool;
+    /// Premium over the variable borrow rate charged on new stable borrows
+    #[ink(message)]
+    fn stable_rate_premium_mantissa(&self) -> WrappedU256;
+    /// Drift from the current stable rate allowing an account's stable rate to be rebalanced
+    #[ink(message)]
+    fn stable_rebalance_threshold_mantissa(&self) -> WrappedU256;
+    /// Stable rate per milliseconds applied to a new stable borrow
+    #[ink(message)]
+    fn stable_borrow_rate_per_msec(&self) -> WrappedU256;
+    /// Weighted average of the stable rates of all stable borrows
+    #[ink(message)]
+    fn average_stable_rate_per_msec(&self) -> WrappedU256;
+    /// Total stable borrows without interest accrued since the last update
+    #[ink(message)]
+    fn total_stable_borrows(&self) -> Balance;
+    /// Get user's stable borrow
+    #[ink(message)]
+    fn stable_borrow_of(&self, account: AccountId) -> StableBorrow;
+    /// Maximum underlying an account can supply, 0 if unlimited
+    #[ink(message)]
+    fn account_supply_cap(&self) -> Balance;
+    /// Longest period interest accrues for in a single accrual, 0 if unlimited
+    #[ink(message)]
+    fn max_accrual_delta(&self) -> Timestamp;
+    /// Whether the borrow rate is compounded over each accrual
+    #[ink(message)]
+    fn compound_interest(&self) -> bool;
+    /// Premium on flash mints, in basis points
+    #[ink(message)]
+    fn flash_mint_premium(&self) -> u128;
+    /// One-time fee charged on borrows, in basis points
+    #[ink(message)]
+    fn origination_fee(&self) -> u128;
+    /// Bounties paid from reserves to keepers of this pool
+    #[ink(message)]
+    fn keeper_incentive(&self) -> KeeperIncentive;
+    /// Call flags and gas limit used when calling the underlying token contract
+    #[ink(message)]
+    fn underlying_call_config(&self) -> UnderlyingCallConfig;
+    /// Maximum underlying leaving the pool per window, 0 if unlimited
+    #[ink(message)]
+    fn outflow_limit(&self) -> Balance;
+    /// Length of the outflow limit window
+    #[ink(message)]
+    fn outflow_window(&self) -> Timestamp;
+    /// Underlying left the pool in the current window
+    #[ink(message)]
+    fn outflow_in_window(&self) -> Balance;
+    /// Treasury reserves are swept to
+    #[ink(message)]
+    fn treasury(&self) -> Option<AccountId>;
+    /// Reserves kept in the pool when swept to the treasury
+    #[ink(message)]
+    fn reserves_sweep_threshold(&self) -> Balance;
     /// Returns the delegation allowance of the user
     #[ink(message)]
     fn delegate_allowance(&self, owner: AccountId, delegatee: AccountId) -> Balance;
@@ -241,6 +451,194 @@ pub trait Pool: PSP22 + PSP22Metadata {
     fn metadata(&self) -> PoolMetaData;
     #[ink(message)]
     fn status(&self) -> PoolStatus;
+    /// Market parameters, balances, rates and pause flags of the pool in a single query
+    #[ink(message)]
+    fn get_pool_metadata(&self) -> PoolMarketMetadata;
+    /// Number of accounts with an outstanding borrow
+    #[ink(message)]
+    fn borrowers_count(&self) -> u32;
+    /// Accounts with an outstanding borrow, starting at `offset`
+    #[ink(message)]
+    fn borrowers_paginated(&self, offset: u32, limit: u32) -> Vec<AccountId>;
+    /// Number of rate checkpoints currently retained
+    #[ink(message)]
+    fn checkpoints_count(&self) -> u32;
+    /// Rate checkpoints from oldest to newest, starting at `offset`
+    #[ink(message)]
+    fn checkpoints(&self, offset: u32, limit: u32) -> Vec<RateCheckpoint>;
+    /// Code the messages of `facet` are delegated to
+    #[ink(message)]
+    fn facet(&self, facet: Facet) -> Option<Hash>;
+}
+
+/// Asset lent and borrowed in a Pool
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum Underlying {
+    /// PSP22 token contract
+    PSP22(AccountId),
+    /// Native token of the chain, moved with the call's transferred value
+    Native,
+    /// Asset of pallet-assets, moved through the chain extension
+    PalletAsset(u128),
+    /// ERC-20 token on the EVM side, moved through XVM
+    EvmErc20(EvmAddress),
+}
+
+/// H160 address of a contract or account on the EVM side
+pub type EvmAddress = [u8; 20];
+
+/// Kind of the underlying of a Pool, stored apart from its AccountId.
+/// Pools deployed before other kinds were supported read as `PSP22`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum UnderlyingKind {
+    #[default]
+    PSP22,
+    Native,
+    PalletAsset,
+    EvmErc20,
+}
+
+/// Options of calls made to a PSP22 underlying
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct UnderlyingCallConfig {
+    /// Whether the underlying may call back into the pool during a transfer
+    pub allow_reentry: bool,
+    /// Gas limit of a transfer, 0 to forward all remaining gas
+    pub gas_limit: u64,
+}
+
+/// Bounties paid from reserves for permissionless maintenance of a Pool.
+/// A bounty is capped by the reserves and cash available, 0 disables it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct KeeperIncentive {
+    /// Paid to an account calling `accrue_interest` directly on a stale pool
+    pub accrual_bounty: Balance,
+    /// Time since the last accrual after which the pool is stale
+    pub stale_after: Timestamp,
+    /// Paid to a liquidator whose liquidation incentive is below `min_liquidation_profit`
+    pub liquidation_bounty: Balance,
+    /// Liquidation incentive on the repaid amount below which the bounty is paid, in underlying
+    pub min_liquidation_profit: Balance,
+}
+
+/// Group of messages a Pool built with the `facets` feature runs in a separate logic contract
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub enum Facet {
+    /// Messages of `PoolAdminFacet`
+    Admin,
+    /// Messages of `PoolLiquidationFacet`
+    Liquidation,
+}
+
+/// Identifier used in place of a token contract for the native token,
+/// e.g. as the key for prices and `market_of_underlying`.
+pub fn native_asset_id() -> AccountId {
+    AccountId::from([0xee; 32])
+}
+
+/// Identifier used in place of a token contract for a pallet-assets asset
+///
+/// Follows the XC-20 convention of prefixing the asset id with `0xff` bytes.
+pub fn pallet_asset_id(asset_id: u128) -> AccountId {
+    let mut bytes = [0xff_u8; 32];
+    bytes[16..].copy_from_slice(&asset_id.to_be_bytes());
+    AccountId::from(bytes)
+}
+
+/// Identifier used in place of a token contract for an EVM ERC-20 token
+///
+/// Prefixes the token address with `evm:`, keeping it apart from the other ids.
+pub fn evm_erc20_id(address: EvmAddress) -> AccountId {
+    let mut bytes = [0_u8; 32];
+    bytes[..4].copy_from_slice(b"evm:");
+    bytes[12..].copy_from_slice(&address);
+    AccountId::from(bytes)
+}
+
+impl Underlying {
+    /// AccountId identifying this asset
+    pub fn asset_id(&self) -> AccountId {
+        match self {
+            Underlying::PSP22(token) => *token,
+            Underlying::Native => native_asset_id(),
+            Underlying::PalletAsset(asset_id) => pallet_asset_id(*asset_id),
+            Underlying::EvmErc20(address) => evm_erc20_id(*address),
+        }
+    }
+
+    /// Kind of this asset
+    pub fn kind(&self) -> UnderlyingKind {
+        match self {
+            Underlying::PSP22(_) => UnderlyingKind::PSP22,
+            Underlying::Native => UnderlyingKind::Native,
+            Underlying::PalletAsset(_) => UnderlyingKind::PalletAsset,
+            Underlying::EvmErc20(_) => UnderlyingKind::EvmErc20,
+        }
+    }
+
+    /// Asset of the kind identified by `asset_id`, the inverse of `asset_id`
+    pub fn from_asset_id(kind: UnderlyingKind, asset_id: AccountId) -> Self {
+        let bytes: &[u8; 32] = asset_id.as_ref();
+        match kind {
+            UnderlyingKind::PSP22 => Underlying::PSP22(asset_id),
+            UnderlyingKind::Native => Underlying::Native,
+            UnderlyingKind::PalletAsset => {
+                let mut id = [0_u8; 16];
+                id.copy_from_slice(&bytes[16..]);
+                Underlying::PalletAsset(u128::from_be_bytes(id))
+            }
+            UnderlyingKind::EvmErc20 => {
+                let mut address = [0_u8; 20];
+                address.copy_from_slice(&bytes[12..]);
+                Underlying::EvmErc20(address)
+            }
+        }
+    }
+}
+
+/// Interest rate mode of a borrow
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub enum RateMode {
+    /// Rate follows the interest rate model, accrued through the borrow index
+    Variable,
+    /// Rate is locked when borrowing, until rebalanced
+    Stable,
+}
+
+/// Structure to hold the stable rate debt of an account
+///
+/// `balance` includes interest up to `last_update`; `rate` is the weighted
+/// average of the stable rates the account borrowed at.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct StableBorrow {
+    pub balance: Balance,
+    pub rate: WrappedU256,
+    pub last_update: Timestamp,
 }
 
 /// Structure to hold Metadata information of a Pool
@@ -265,37 +663,130 @@ pub struct PoolStatus {
     pub exchange_rate: U256,
 }
 
+/// Structure to hold market information of a Pool
+///
+/// Used by UIs to refresh a pool with a single query.
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct PoolMarketMetadata {
+    pub underlying: Option<AccountId>,
+    pub decimals: u8,
+    pub exchange_rate: WrappedU256,
+    pub cash: Balance,
+    pub total_supply: Balance,
+    pub total_borrows: Balance,
+    pub total_reserves: Balance,
+    pub reserve_factor_mantissa: WrappedU256,
+    pub borrow_rate_per_msec: WrappedU256,
+    pub supply_rate_per_msec: WrappedU256,
+    pub liquidation_threshold: u128,
+    pub mint_paused: bool,
+    pub borrow_paused: bool,
+    pub seize_paused: bool,
+    pub transfer_paused: bool,
+}
+
+/// Structure to hold the borrow principal of an account
+///
+/// `principal` excludes accrued interest; `interest_index` is the pool's borrow
+/// index when the snapshot was last updated.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct BorrowSnapshot {
+    pub principal: Balance,
+    pub interest_index: WrappedU256,
+}
+
+/// Snapshot of pool rates written on each interest accrual
+///
+/// Kept in a fixed-size ring buffer so analytics and liquidation bots can read
+/// recent history without an indexer.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct RateCheckpoint {
+    pub timestamp: Timestamp,
+    pub exchange_rate: WrappedU256,
+    pub borrow_index: WrappedU256,
+    pub utilization_rate: WrappedU256,
+}
+
 /// Custom error definitions for Pool
 #[derive(Debug, PartialEq, Eq, Encode, Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
 pub enum Error {
-    NotImplemented,
-    InvalidParameter,
-    BorrowCashNotAvailable,
-    RedeemTransferOutNotPossible,
-    LiquidateLiquidatorIsBorrower,
-    LiquidateCloseAmountIsZero,
-    AccrualBlockNumberIsNotFresh,
-    LiquidateSeizeLiquidatorIsBorrower,
-    ReduceReservesCashNotAvailable,
-    ReduceReservesCashValidation,
-    BorrowRateIsAbsurdlyHigh,
-    SetReserveFactorBoundsCheck,
-    CannotSweepUnderlyingToken,
-    CallerIsNotManager,
-    ZeroOwnerAddress,
-    ZeroDelegateeAddress,
-    InsufficientDelegateAllowance,
-    CallerIsNotFlashloanGateway,
-    ControllerIsNotSet,
-    InterestRateModelIsNotSet,
-    UnderlyingIsNotSet,
-    ManagerIsNotSet,
-    IncentivesControllerIsNotSet,
-    AccrueRewardFailed,
-    Controller(ControllerError),
-    PSP22(PSP22Error),
-    Lang(LangError),
+    NotImplemented = 0,
+    InvalidParameter = 1,
+    BorrowCashNotAvailable = 2,
+    RedeemTransferOutNotPossible = 3,
+    LiquidateLiquidatorIsBorrower = 4,
+    LiquidateCloseAmountIsZero = 5,
+    AccrualBlockNumberIsNotFresh = 6,
+    LiquidateSeizeLiquidatorIsBorrower = 7,
+    ReduceReservesCashNotAvailable = 8,
+    ReduceReservesCashValidation = 9,
+    BorrowRateIsAbsurdlyHigh = 10,
+    SetReserveFactorBoundsCheck = 11,
+    SetBorrowRateMaxBoundsCheck = 12,
+    InvalidLiquidationThreshold = 13,
+    InvalidMaxAccrualDelta = 14,
+    CannotSweepUnderlyingToken = 15,
+    CallerIsNotManager = 16,
+    CallerIsNotPendingManager = 17,
+    ZeroOwnerAddress = 18,
+    ZeroDelegateeAddress = 19,
+    InsufficientDelegateAllowance = 20,
+    CallerIsNotFlashloanGateway = 21,
+    ControllerIsNotSet = 22,
+    InterestRateModelIsNotSet = 23,
+    UnderlyingIsNotSet = 24,
+    ManagerIsNotSet = 25,
+    IncentivesControllerIsNotSet = 26,
+    AccrueRewardFailed = 27,
+    InsufficientTransferredValue = 28,
+    TransferNativeFailed = 29,
+    TransferPalletAssetFailed = 30,
+    TransferEvmErc20Failed = 31,
+    OutflowLimitExceeded = 32,
+    AccountSupplyCapExceeded = 33,
+    StableBorrowNotEnabled = 34,
+    RebalanceConditionsNotMet = 35,
+    NoDebtOfRateMode = 36,
+    InvalidFlashMintPremium = 37,
+    InvalidOriginationFee = 38,
+    TreasuryIsNotSet = 39,
+    ReservesBelowSweepThreshold = 40,
+    FlashMintFailed = 41,
+    FacetIsNotSet = 42,
+    FacetCallFailed = 43,
+    AbsorbBorrowerHasCollateral = 44,
+    AbsorbNoDebt = 45,
+    BadDebtExceeded = 46,
+    CoverBadDebtReservesValidation = 47,
+    MathOverflow = 48,
+    Controller(ControllerError) = 49,
+    PSP22(PSP22Error) = 50,
+    Lang(LangError) = 51,
+    TransferUnderlyingFailed = 52,
+    InsufficientCash = 53,
+    FlashloanRepaymentNotReceived = 54,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        match self {
+            Error::Controller(error) => error.code(),
+            Error::PSP22(error) => error.code(),
+            Error::Lang(error) => error.code(),
+            _ => error_code(module::POOL, self),
+        }
+    }
 }
 
 impl From<ControllerError> for Error {
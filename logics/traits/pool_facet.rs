@@ -0,0 +1,147 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Timestamp,
+};
+
+use super::{
+    pool::{
+        KeeperIncentive,
+        Result,
+        UnderlyingCallConfig,
+    },
+    types::WrappedU256,
+};
+
+/// Admin setters of a Pool, moved out of the Pool code to keep its size under the limit.
+/// A Pool built with the `facets` feature delegates them to the code set for `Facet::Admin`.
+#[openbrush::trait_definition]
+pub trait PoolAdminFacet {
+    /// accrues interest and sets a new reserve factor for the protocol using _set_reserve_factor_mantissa
+    #[ink(message)]
+    fn set_reserve_factor_mantissa(
+        &mut self,
+        new_reserve_factor_mantissa: WrappedU256,
+    ) -> Result<()>;
+
+    /// Set Liquidation Threshold
+    #[ink(message)]
+    fn set_liquidation_threshold(&mut self, new_liquidation_threshold: u128) -> Result<()>;
+
+    /// Sets the maximum borrow rate applied on accrual, bounded by the protocol's absolute maximum
+    #[ink(message)]
+    fn set_borrow_rate_max_mantissa(
+        &mut self,
+        new_borrow_rate_max_mantissa: WrappedU256,
+    ) -> Result<()>;
+
+    /// Enables stable rate borrowing and sets the premium over the variable rate
+    /// and the drift allowing a stable rate to be rebalanced
+    #[ink(message)]
+    fn set_stable_rate_config(
+        &mut self,
+        enabled: bool,
+        premium_mantissa: WrappedU256,
+        rebalance_threshold_mantissa: WrappedU256,
+    ) -> Result<()>;
+
+    /// Sets the call flags and gas limit used when calling the underlying token contract
+    #[ink(message)]
+    fn set_underlying_call_config(&mut self, config: UnderlyingCallConfig) -> Result<()>;
+
+    /// Caps the period interest accrues for in a single accrual. 0 disables the cap
+    #[ink(message)]
+    fn set_max_accrual_delta(&mut self, new_max_accrual_delta: Timestamp) -> Result<()>;
+
+    /// Switches accrual between a compounded borrow rate and simple `rate * delta`
+    #[ink(message)]
+    fn set_compound_interest(&mut self, enabled: bool) -> Result<()>;
+
+    /// Sets the premium on flash mints, in basis points
+    #[ink(message)]
+    fn set_flash_mint_premium(&mut self, premium: u128) -> Result<()>;
+
+    /// Sets the one-time fee charged on borrows, in basis points
+    #[ink(message)]
+    fn set_origination_fee(&mut self, fee: u128) -> Result<()>;
+
+    /// Sets the bounties paid from reserves to keepers of this pool
+    #[ink(message)]
+    fn set_keeper_incentive(&mut self, incentive: KeeperIncentive) -> Result<()>;
+
+    /// Caps underlying a single account can supply. 0 disables the cap
+    #[ink(message)]
+    fn set_account_supply_cap(&mut self, new_cap: Balance) -> Result<()>;
+
+    /// Caps underlying leaving the pool by redeem and borrow to `limit` per `window`. 0 disables the cap
+    #[ink(message)]
+    fn set_outflow_limit(&mut self, limit: Balance, window: Timestamp) -> Result<()>;
+
+    /// Sets the treasury and the reserves kept in the pool when swept to it. None disables sweeping
+    #[ink(message)]
+    fn set_reserves_sweep_config(
+        &mut self,
+        treasury: Option<AccountId>,
+        threshold: Balance,
+    ) -> Result<()>;
+
+    /// Accrues interest and reduces reserves by transferring to admin
+    #[ink(message)]
+    fn reduce_reserves(&mut self, amount: Balance) -> Result<()>;
+
+    /// Accrues interest and covers bad debt of this pool with its reserves
+    #[ink(message)]
+    fn cover_bad_debt(&mut self, amount: Balance) -> Result<()>;
+
+    /// A public function to sweep accidental token transfers to this contract. Tokens are sent to admin
+    #[ink(message)]
+    fn sweep_token(&mut self, asset: AccountId) -> Result<()>;
+
+    /// Accrues interest and transfers reserves above the sweep threshold to the treasury.
+    /// Callable by anyone, returns the amount swept
+    #[ink(message)]
+    fn sweep_reserves_to_treasury(&mut self) -> Result<Balance>;
+
+    /// Set incentives Controller AccountId for reward
+    #[ink(message)]
+    fn set_incentives_controller(&mut self, incentives_controller: AccountId) -> Result<()>;
+}
+
+/// Liquidation path of a Pool, moved out of the Pool code to keep its size under the limit.
+/// A Pool built with the `facets` feature delegates it to the code set for `Facet::Liquidation`.
+#[openbrush::trait_definition]
+pub trait PoolLiquidationFacet {
+    /// The sender liquidates the borrowers collateral.
+    #[ink(message, payable)]
+    fn liquidate_borrow(
+        &mut self,
+        borrower: AccountId,
+        repay_amount: Balance,
+        collateral: AccountId,
+    ) -> Result<()>;
+
+    /// Liquidate the largest amount allowed by the close factor and the borrower's collateral
+    #[ink(message, payable)]
+    fn liquidate_borrow_max(&mut self, borrower: AccountId, collateral: AccountId) -> Result<()>;
+
+    /// Transfers collateral tokens (this market) to the liquidator.
+    #[ink(message)]
+    fn seize(
+        &mut self,
+        liquidator: AccountId,
+        borrower: AccountId,
+        seize_tokens: Balance,
+    ) -> Result<()>;
+
+    /// Writes off the debt of a borrower with no collateral left in any market
+    /// as bad debt of this pool. Returns the amount written off
+    #[ink(message)]
+    fn absorb(&mut self, borrower: AccountId) -> Result<Balance>;
+}
@@ -0,0 +1,150 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::error_code::{
+    error_code,
+    module,
+    ErrorCode,
+};
+use ink::prelude::vec::Vec;
+use openbrush::traits::{
+    AccountId,
+    Hash,
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+use super::{
+    controller::Error as ControllerError,
+    pool::Error as PoolError,
+    types::WrappedU256,
+};
+
+#[openbrush::wrapper]
+pub type PoolFactoryRef = dyn PoolFactory;
+
+/// Deploys pools from a registered code and lists them in the Controller in the same transaction,
+/// so every market is deployed and configured the same way.
+/// Also deploys the rate models of new markets from approved codes.
+/// Only the manager of the Controller can create pools and rate models, the Controller has to
+/// accept this contract as its pool factory.
+#[openbrush::trait_definition]
+pub trait PoolFactory {
+    /// Deploys the pool of `underlying` with a salt derived from it, sets its reserve factor,
+    /// lists it in the Controller and proposes the manager of the Controller as its manager.
+    /// Returns the deployed pool
+    #[ink(message)]
+    fn create_pool(
+        &mut self,
+        underlying: AccountId,
+        parameters: PoolParameters,
+    ) -> Result<AccountId>;
+
+    /// Registers the code new pools are deployed from
+    #[ink(message)]
+    fn set_pool_code_hash(&mut self, code_hash: Hash) -> Result<()>;
+
+    /// Returns the code new pools are deployed from
+    #[ink(message)]
+    fn pool_code_hash(&self) -> Option<Hash>;
+
+    /// Returns the Controller the pools are listed in
+    #[ink(message)]
+    fn controller(&self) -> Option<AccountId>;
+
+    /// Returns the pool created for `underlying`
+    #[ink(message)]
+    fn pool_of(&self, underlying: AccountId) -> Option<AccountId>;
+
+    /// Deploys a rate model from an approved code, whose constructor `new` takes
+    /// the base rate, the multipliers and the kink like the jump rate models.
+    /// Returns the deployed rate model
+    #[ink(message)]
+    fn create_rate_model(
+        &mut self,
+        code_hash: Hash,
+        parameters: RateModelParameters,
+    ) -> Result<AccountId>;
+
+    /// Approves or revokes a code rate models can be deployed from
+    #[ink(message)]
+    fn set_rate_model_code_hash_approval(&mut self, code_hash: Hash, approved: bool) -> Result<()>;
+
+    /// Returns whether rate models can be deployed from the code
+    #[ink(message)]
+    fn is_rate_model_code_hash_approved(&self, code_hash: Hash) -> bool;
+
+    /// Returns the rate models created by this factory
+    #[ink(message)]
+    fn rate_models(&self) -> Vec<AccountId>;
+}
+
+/// Initial parameters of a pool created by the factory
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct PoolParameters {
+    pub incentives_controller: Option<AccountId>,
+    pub rate_model: AccountId,
+    pub initial_exchange_rate_mantissa: WrappedU256,
+    pub reserve_factor_mantissa: WrappedU256,
+    /// Listed without a collateral factor if `None`
+    pub collateral_factor_mantissa: Option<WrappedU256>,
+    pub liquidation_threshold: u128,
+}
+
+/// Constructor arguments of a rate model created by the factory, per year
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct RateModelParameters {
+    pub base_rate_per_year: WrappedU256,
+    /// Slope of the rate below the kink
+    pub multiplier_per_year: WrappedU256,
+    /// Slope of the rate above the kink
+    pub jump_multiplier_per_year: WrappedU256,
+    pub kink: WrappedU256,
+}
+
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
+pub enum Error {
+    CallerIsNotManager = 0,
+    ControllerIsNotSet = 1,
+    PoolCodeHashIsNotSet = 2,
+    PoolAlreadyCreated = 3,
+    InstantiatePoolFailed = 4,
+    RateModelCodeHashIsNotApproved = 5,
+    InstantiateRateModelFailed = 6,
+    Pool(PoolError) = 7,
+    Controller(ControllerError) = 8,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        match self {
+            Error::Pool(error) => error.code(),
+            Error::Controller(error) => error.code(),
+            _ => error_code(module::POOL_FACTORY, self),
+        }
+    }
+}
+
+impl From<PoolError> for Error {
+    fn from(error: PoolError) -> Self {
+        Error::Pool(error)
+    }
+}
+
+impl From<ControllerError> for Error {
+    fn from(error: ControllerError) -> Self {
+        Error::Controller(error)
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
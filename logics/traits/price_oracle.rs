@@ -5,7 +5,21 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
-use openbrush::traits::AccountId;
+use super::error_code::{
+    error_code,
+    module,
+    ErrorCode,
+};
+use openbrush::{
+    contracts::ownable::*,
+    modifiers,
+    traits::{
+        AccountId,
+        Timestamp,
+    },
+};
+
+use super::types::WrappedU256;
 use scale::{
     Decode,
     Encode,
@@ -16,24 +30,184 @@ pub type PriceOracleRef = dyn PriceOracle;
 
 /// Trait defines the functions that a PriceOracle must implement.
 /// A PriceOracle is responsible for providing the current market price of an asset.
+///
+/// Prices are in USD per whole token with 18 decimals, whatever the decimals of the source feed.
+/// Token decimals are applied by the controller, so the same price can be used for any amount.
 #[openbrush::trait_definition]
 pub trait PriceOracle {
-    /// Returns the current price for the given asset, if available.
+    /// Returns the current price for the given asset, if available, not stale and within bounds.
     #[ink(message)]
     fn get_price(&self, asset: AccountId) -> Option<u128>;
 
-    /// Returns the underlying price of the given pool, if available.
+    /// Returns the underlying price of the given pool, if available, not stale and within bounds.
     #[ink(message)]
     fn get_underlying_price(&self, pool: AccountId) -> Option<u128>;
 
-    /// Sets a fixed price for the given asset.
+    /// Posts a price for the given asset with its price decimals. Caller must be a reporter.
     #[ink(message)]
     fn set_fixed_price(&mut self, asset: AccountId, value: u128) -> Result<()>;
 }
 
+#[openbrush::wrapper]
+pub type ManualPriceOracleRef = dyn ManualPriceOracle + PriceOracle + Ownable;
+
+/// Price oracle whose prices are posted by reporters authorized by its owner
+#[openbrush::trait_definition]
+pub trait ManualPriceOracle: PriceOracle + Ownable {
+    /// Authorizes or revokes a reporter
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_reporter(&mut self, reporter: AccountId, authorized: bool) -> Result<()>;
+
+    /// Returns whether the account can post prices
+    #[ink(message)]
+    fn is_reporter(&self, account: AccountId) -> bool;
+
+    /// Sets the longest time a price of the asset is used for after it was posted. 0 disables it
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_heartbeat(&mut self, asset: AccountId, heartbeat: Timestamp) -> Result<()>;
+
+    /// Returns the longest time a price of the asset is used for after it was posted
+    #[ink(message)]
+    fn heartbeat(&self, asset: AccountId) -> Timestamp;
+
+    /// Sets the decimals reporters post prices of the asset with, 18 by default
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_price_decimals(&mut self, asset: AccountId, decimals: u8) -> Result<()>;
+
+    /// Returns the decimals reporters post prices of the asset with
+    #[ink(message)]
+    fn price_decimals(&self, asset: AccountId) -> u8;
+
+    /// Sets the range prices of the asset must be within, with 18 decimals. A max of 0 is unlimited
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_price_bounds(&mut self, asset: AccountId, bounds: PriceBounds) -> Result<()>;
+
+    /// Returns the range prices of the asset must be within
+    #[ink(message)]
+    fn price_bounds(&self, asset: AccountId) -> PriceBounds;
+
+    /// Sets the largest deviation from the last price the asset accepts within a window
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_deviation_breaker(&mut self, asset: AccountId, breaker: DeviationBreaker) -> Result<()>;
+
+    /// Returns the largest deviation from the last price the asset accepts within a window
+    #[ink(message)]
+    fn deviation_breaker(&self, asset: AccountId) -> DeviationBreaker;
+
+    /// Sets the manager the borrows of tripped markets are paused through
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn set_manager(&mut self, manager: AccountId) -> Result<()>;
+
+    /// Returns the manager the borrows of tripped markets are paused through
+    #[ink(message)]
+    fn manager(&self) -> Option<AccountId>;
+
+    /// Resumes the price of an asset after its breaker tripped
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn reset_breaker(&mut self, asset: AccountId) -> Result<()>;
+
+    /// Returns whether the breaker of an asset tripped, making its price unavailable
+    #[ink(message)]
+    fn is_tripped(&self, asset: AccountId) -> bool;
+
+    /// Returns the price of the given asset with the time it was posted, even if stale.
+    #[ink(message)]
+    fn get_price_data(&self, asset: AccountId) -> Option<PriceData>;
+}
+
+/// Price posted for an asset and the time it was posted at
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct PriceData {
+    pub price: u128,
+    pub updated_at: Timestamp,
+}
+
+/// Range prices of an asset must be within, rejecting fat-finger or manipulated reports
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct PriceBounds {
+    pub min: u128,
+    /// 0 if unlimited
+    pub max: u128,
+}
+
+impl PriceBounds {
+    pub fn contains(&self, price: u128) -> bool {
+        price >= self.min && (self.max == 0 || price <= self.max)
+    }
+}
+
+/// Largest deviation from the last price an asset accepts within a window
+///
+/// A price deviating more trips the breaker instead of being accepted:
+/// the price of the asset becomes unavailable and borrows of `pool` are paused.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct DeviationBreaker {
+    /// 0 disables the breaker
+    pub max_deviation_mantissa: WrappedU256,
+    /// Time since the last price the breaker applies within
+    pub window: Timestamp,
+    /// Market whose borrows are paused when the breaker trips
+    pub pool: Option<AccountId>,
+}
+
 /// Custom error definitions for PriceOracle
 #[derive(Debug, PartialEq, Eq, Encode, Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
-pub enum Error {}
+#[repr(u8)]
+pub enum Error {
+    CallerIsNotReporter = 0,
+    FixedPriceNotSupported = 1,
+    TwapConfigIsNotSet = 2,
+    TwapWindowNotElapsed = 3,
+    InvalidTwapConfig = 4,
+    InvalidAnchorTolerance = 5,
+    PriceOutOfBounds = 6,
+    InvalidPriceBounds = 7,
+    PriceOverflow = 8,
+    InvalidDerivative = 9,
+    TooManyReporters = 10,
+    InvalidMinReports = 11,
+    CallerIsNotGuardian = 12,
+    InvalidOverride = 13,
+    InvalidPriceDecimals = 14,
+    InvalidDeviationBreaker = 15,
+    Ownable(OwnableError) = 16,
+    PoolAssetMismatch = 17,
+    OverrideNotExpired = 18,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        match self {
+            Error::Ownable(error) => error.code(),
+            _ => error_code(module::PRICE_ORACLE, self),
+        }
+    }
+}
+
+impl From<OwnableError> for Error {
+    fn from(error: OwnableError) -> Self {
+        Error::Ownable(error)
+    }
+}
 
 pub type Result<T> = core::result::Result<T, Error>;
@@ -0,0 +1,51 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use openbrush::{
+    contracts::psp22::PSP22Error,
+    traits::{
+        AccountId,
+        Balance,
+        Timestamp,
+    },
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+#[openbrush::wrapper]
+pub type PSP22PermitRef = dyn PSP22Permit;
+
+/// PSP22 extension that approves a spender from an off-chain signature of the owner
+#[openbrush::trait_definition]
+pub trait PSP22Permit {
+    /// Sets `value` as the allowance of `spender` over `owner`'s tokens,
+    /// given `owner`'s signature over the permit and its nonce
+    #[ink(message)]
+    fn permit(
+        &mut self,
+        owner: AccountId,
+        spender: AccountId,
+        value: Balance,
+        permit: PermitSignature,
+    ) -> Result<(), PSP22Error>;
+
+    /// Nonce to be signed by `owner` for its next permit
+    #[ink(message)]
+    fn nonces(&self, owner: AccountId) -> u64;
+}
+
+/// Signed permit of a token owner
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct PermitSignature {
+    /// Timestamp after which the permit can no longer be used
+    pub deadline: Timestamp,
+    /// ECDSA signature of the owner
+    pub signature: [u8; 65],
+}
@@ -0,0 +1,108 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::error_code::{
+    error_code,
+    module,
+    ErrorCode,
+};
+use ink::prelude::vec::Vec;
+use openbrush::{
+    contracts::ownable::*,
+    traits::AccountId,
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+#[openbrush::wrapper]
+pub type RegistryRef = dyn Registry + Ownable;
+
+/// Resolves the protocol contracts serving an underlying asset,
+/// so periphery contracts and integrators do not hard-code their addresses.
+/// Entries are maintained by the owner, pools not registered are resolved from the Controller.
+#[openbrush::trait_definition]
+pub trait Registry {
+    /// Registers the contracts serving `asset`, replacing the previous entry
+    #[ink(message)]
+    fn set_addresses(&mut self, asset: AccountId, addresses: AssetAddresses) -> Result<()>;
+
+    /// Removes the entry of `asset`
+    #[ink(message)]
+    fn remove_addresses(&mut self, asset: AccountId) -> Result<()>;
+
+    /// Sets the Controller pools are resolved from when not registered
+    #[ink(message)]
+    fn set_controller(&mut self, controller: Option<AccountId>) -> Result<()>;
+
+    /// Returns the Controller pools are resolved from when not registered
+    #[ink(message)]
+    fn controller(&self) -> Option<AccountId>;
+
+    /// Returns the entry of `asset`, with the pool resolved from the Controller if not registered
+    #[ink(message)]
+    fn addresses_of(&self, asset: AccountId) -> AssetAddresses;
+
+    /// Returns the pool lending `asset`
+    #[ink(message)]
+    fn pool_of(&self, asset: AccountId) -> Option<AccountId>;
+
+    /// Returns the gateway wrapping `asset`
+    #[ink(message)]
+    fn gateway_of(&self, asset: AccountId) -> Option<AccountId>;
+
+    /// Returns the debt token tracking borrows of `asset`
+    #[ink(message)]
+    fn debt_token_of(&self, asset: AccountId) -> Option<AccountId>;
+
+    /// Returns the price oracle adapter of `asset`
+    #[ink(message)]
+    fn oracle_adapter_of(&self, asset: AccountId) -> Option<AccountId>;
+
+    /// Returns the registered assets
+    #[ink(message)]
+    fn assets(&self) -> Vec<AccountId>;
+}
+
+/// Contracts serving an underlying asset
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct AssetAddresses {
+    pub pool: Option<AccountId>,
+    pub gateway: Option<AccountId>,
+    pub debt_token: Option<AccountId>,
+    pub oracle_adapter: Option<AccountId>,
+}
+
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
+pub enum Error {
+    AssetIsNotRegistered = 0,
+    Ownable(OwnableError) = 1,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        match self {
+            Error::Ownable(error) => error.code(),
+            _ => error_code(module::REGISTRY, self),
+        }
+    }
+}
+
+impl From<OwnableError> for Error {
+    fn from(error: OwnableError) -> Self {
+        Error::Ownable(error)
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
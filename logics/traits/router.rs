@@ -0,0 +1,115 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::error_code::{
+    error_code,
+    module,
+    ErrorCode,
+};
+use ink::prelude::vec::Vec;
+use openbrush::{
+    contracts::psp22::PSP22Error,
+    traits::{
+        AccountId,
+        Balance,
+    },
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+pub use super::{
+    incentives_controller::Error as IncentivesControllerError,
+    pool::Error as PoolError,
+};
+
+#[openbrush::wrapper]
+pub type RouterRef = dyn Router;
+
+/// Periphery composing common user flows across pools into a single transaction.
+/// Underlying and pool tokens are pulled from caller by PSP22 allowance.
+#[openbrush::trait_definition]
+pub trait Router {
+    /// Get Controller AccountId
+    #[ink(message)]
+    fn controller(&self) -> Option<AccountId>;
+
+    /// Deposits `amount` of the pool's underlying for caller and ensures it is used as collateral
+    #[ink(message)]
+    fn deposit_and_enable_collateral(&mut self, pool: AccountId, amount: Balance) -> Result<()>;
+
+    /// Deposits into several pools at once
+    #[ink(message)]
+    fn deposit_multiple(&mut self, deposits: Vec<(AccountId, Balance)>) -> Result<()>;
+
+    /// Repays caller's borrow in `repay_pool` (all of it if Balance::MAX is specified),
+    /// then redeems `redeem_amount` of underlying from caller's pool tokens in `redeem_pool`
+    #[ink(message)]
+    fn repay_and_redeem(
+        &mut self,
+        repay_pool: AccountId,
+        repay_amount: Balance,
+        redeem_pool: AccountId,
+        redeem_amount: Balance,
+    ) -> Result<()>;
+
+    /// Claims caller's rewards and deposits them into the pool of the reward token on its behalf.
+    /// Router must be authorized as a claimer of caller in the incentives controller.
+    #[ink(message)]
+    fn claim_and_deposit(
+        &mut self,
+        incentives_controller: AccountId,
+        pool: AccountId,
+    ) -> Result<Balance>;
+}
+
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
+pub enum Error {
+    ControllerIsNotSet = 0,
+    MarketNotListed = 1,
+    UnderlyingIsNotSet = 2,
+    RewardTokenMismatch = 3,
+    CollateralNotEnabled = 4,
+    EmptyDeposits = 5,
+    Pool(PoolError) = 6,
+    IncentivesController(IncentivesControllerError) = 7,
+    PSP22(PSP22Error) = 8,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        match self {
+            Error::Pool(error) => error.code(),
+            Error::IncentivesController(error) => error.code(),
+            Error::PSP22(error) => error.code(),
+            _ => error_code(module::ROUTER, self),
+        }
+    }
+}
+
+impl From<PoolError> for Error {
+    fn from(error: PoolError) -> Self {
+        Error::Pool(error)
+    }
+}
+
+impl From<IncentivesControllerError> for Error {
+    fn from(error: IncentivesControllerError) -> Self {
+        Error::IncentivesController(error)
+    }
+}
+
+impl From<PSP22Error> for Error {
+    fn from(error: PSP22Error) -> Self {
+        Error::PSP22(error)
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
@@ -0,0 +1,125 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::{
+    error_code::{
+        error_code,
+        module,
+        ErrorCode,
+    },
+    types::WrappedU256,
+};
+use ink::prelude::vec::Vec;
+use openbrush::{
+    contracts::{
+        ownable::*,
+        psp22::PSP22Error,
+    },
+    traits::{
+        AccountId,
+        Balance,
+    },
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+#[openbrush::wrapper]
+pub type StakingRef = dyn Staking + Ownable;
+
+/// Shares protocol revenue with LAY stakers pro-rata to their stake.
+/// Reward assets listed by the owner are received by plain transfers, e.g. treasury streams or
+/// swept reserves, and `distribute` adds what has been received since the last distribution
+/// to the reward index of the asset.
+#[openbrush::trait_definition]
+pub trait Staking {
+    /// Stakes `amount` of LAY transferred from the caller
+    #[ink(message)]
+    fn stake(&mut self, amount: Balance) -> Result<()>;
+
+    /// Transfers `amount` of the caller's stake back to the caller
+    #[ink(message)]
+    fn unstake(&mut self, amount: Balance) -> Result<()>;
+
+    /// Transfers the rewards of the caller in every reward asset to the caller.
+    /// Returns the claimed amount of each reward asset
+    #[ink(message)]
+    fn claim_rewards(&mut self) -> Result<Vec<(AccountId, Balance)>>;
+
+    /// Distributes the amount of `asset` received since the last distribution to stakers.
+    /// Returns the distributed amount
+    #[ink(message)]
+    fn distribute(&mut self, asset: AccountId) -> Result<Balance>;
+
+    /// Lists `asset` as a reward asset
+    #[ink(message)]
+    fn add_reward_asset(&mut self, asset: AccountId) -> Result<()>;
+
+    /// Returns the staked token
+    #[ink(message)]
+    fn staking_token(&self) -> Option<AccountId>;
+
+    /// Returns the stake of `account`
+    #[ink(message)]
+    fn staked_balance_of(&self, account: AccountId) -> Balance;
+
+    /// Returns the sum of all stakes
+    #[ink(message)]
+    fn total_staked(&self) -> Balance;
+
+    /// Returns the reward assets
+    #[ink(message)]
+    fn reward_assets(&self) -> Vec<AccountId>;
+
+    /// Returns the amount of `asset` distributed per staked unit so far, scaled by 1e18
+    #[ink(message)]
+    fn reward_index(&self, asset: AccountId) -> WrappedU256;
+
+    /// Returns the amount of `asset` `account` can claim now
+    #[ink(message)]
+    fn claimable_rewards(&self, asset: AccountId, account: AccountId) -> Balance;
+}
+
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
+pub enum Error {
+    StakingTokenIsNotSet = 0,
+    AmountIsZero = 1,
+    InsufficientStake = 2,
+    RewardAssetAlreadyAdded = 3,
+    RewardAssetIsNotAdded = 4,
+    NothingToDistribute = 5,
+    NoStakers = 6,
+    PSP22(PSP22Error) = 7,
+    Ownable(OwnableError) = 8,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        match self {
+            Error::PSP22(error) => error.code(),
+            Error::Ownable(error) => error.code(),
+            _ => error_code(module::STAKING, self),
+        }
+    }
+}
+
+impl From<PSP22Error> for Error {
+    fn from(error: PSP22Error) -> Self {
+        Error::PSP22(error)
+    }
+}
+
+impl From<OwnableError> for Error {
+    fn from(error: OwnableError) -> Self {
+        Error::Ownable(error)
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
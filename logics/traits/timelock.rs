@@ -0,0 +1,119 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::error_code::{
+    error_code,
+    module,
+    ErrorCode,
+};
+use ink::prelude::vec::Vec;
+use openbrush::{
+    contracts::ownable::*,
+    modifiers,
+    traits::{
+        AccountId,
+        Timestamp,
+    },
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+#[openbrush::wrapper]
+pub type TimelockRef = dyn Timelock + Ownable;
+
+/// Identifier of a queued transaction, the hash of its encoding
+pub type TransactionId = [u8; 32];
+
+/// Holds admin rights over protocol contracts and delays the calls made with them.
+/// Calls are queued by the owner, and executed by anyone between their eta and the grace period.
+/// Set it as the manager of the controller and pools to have their parameter changes delayed.
+#[openbrush::trait_definition]
+pub trait Timelock: Ownable {
+    /// Least time between queueing a transaction and executing it
+    #[ink(message)]
+    fn delay(&self) -> Timestamp;
+
+    /// Time after its eta a transaction can be executed within
+    #[ink(message)]
+    fn grace_period(&self) -> Timestamp;
+
+    /// Returns whether the transaction is queued
+    #[ink(message)]
+    fn is_queued(&self, id: TransactionId) -> bool;
+
+    /// Returns the identifier of the transaction
+    #[ink(message)]
+    fn transaction_id(&self, transaction: Transaction) -> TransactionId;
+
+    /// Queues the transaction, to be executed from its eta, at least `delay` from now.
+    /// A transaction to this contract is rejected unless it calls `set_delay`
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn queue_transaction(&mut self, transaction: Transaction) -> Result<TransactionId>;
+
+    /// Removes the transaction from the queue
+    #[ink(message)]
+    #[modifiers(only_owner)]
+    fn cancel_transaction(&mut self, transaction: Transaction) -> Result<()>;
+
+    /// Sends a queued transaction whose eta has passed, returning the encoded output of the call.
+    /// A transaction to this contract is applied by it directly, and may only be `set_delay`
+    #[ink(message)]
+    fn execute_transaction(&mut self, transaction: Transaction) -> Result<Vec<u8>>;
+
+    /// Sets the delay. Must be called by this contract through a queued transaction
+    #[ink(message)]
+    fn set_delay(&mut self, delay: Timestamp) -> Result<()>;
+}
+
+/// Call to a contract queued in the timelock
+#[derive(Debug, Clone, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct Transaction {
+    /// Contract called
+    pub target: AccountId,
+    /// Selector of the message called
+    pub selector: [u8; 4],
+    /// Encoded arguments of the message
+    pub input: Vec<u8>,
+    /// Time from which the transaction can be executed
+    pub eta: Timestamp,
+}
+
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
+pub enum Error {
+    DelayOutOfBounds = 0,
+    EtaBeforeDelay = 1,
+    TransactionNotQueued = 2,
+    TransactionNotReady = 3,
+    TransactionIsStale = 4,
+    TransactionFailed = 5,
+    CallerIsNotTimelock = 6,
+    Ownable(OwnableError) = 7,
+    UnsupportedSelfCall = 8,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        match self {
+            Error::Ownable(error) => error.code(),
+            _ => error_code(module::TIMELOCK, self),
+        }
+    }
+}
+
+impl From<OwnableError> for Error {
+    fn from(error: OwnableError) -> Self {
+        Error::Ownable(error)
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
@@ -0,0 +1,129 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::error_code::{
+    error_code,
+    module,
+    ErrorCode,
+};
+use openbrush::{
+    contracts::{
+        access_control::AccessControlError,
+        psp22::PSP22Error,
+    },
+    traits::{
+        AccountId,
+        Balance,
+        Timestamp,
+    },
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+#[openbrush::wrapper]
+pub type TreasuryRef = dyn Treasury;
+
+/// Identifier of a budget stream
+pub type StreamId = u128;
+
+/// Single destination of the protocol revenue: reduced reserves and gateway fees.
+/// Spends it by role-gated transfers or budget streams paid over time.
+/// Amounts streamed and not withdrawn yet are committed and cannot be transferred.
+#[openbrush::trait_definition]
+pub trait Treasury {
+    /// Transfers `amount` of `asset` not committed to streams to `to`
+    #[ink(message)]
+    fn transfer(&mut self, asset: AccountId, to: AccountId, amount: Balance) -> Result<()>;
+
+    /// Commits `amount` of `asset` paid to `recipient` linearly from `start` to `end`.
+    /// Returns the identifier of the stream
+    #[ink(message)]
+    fn create_stream(
+        &mut self,
+        recipient: AccountId,
+        asset: AccountId,
+        amount: Balance,
+        start: Timestamp,
+        end: Timestamp,
+    ) -> Result<StreamId>;
+
+    /// Stops a stream, paying what has been streamed to its recipient and releasing the rest
+    #[ink(message)]
+    fn cancel_stream(&mut self, stream_id: StreamId) -> Result<()>;
+
+    /// Transfers the amount streamed and not withdrawn yet to the recipient, the caller
+    #[ink(message)]
+    fn withdraw_from_stream(&mut self, stream_id: StreamId) -> Result<Balance>;
+
+    /// Returns a stream
+    #[ink(message)]
+    fn stream(&self, stream_id: StreamId) -> Option<Stream>;
+
+    /// Returns the amount of a stream its recipient can withdraw now
+    #[ink(message)]
+    fn withdrawable_amount(&self, stream_id: StreamId) -> Balance;
+
+    /// Returns the amount of `asset` committed to streams
+    #[ink(message)]
+    fn committed_amount(&self, asset: AccountId) -> Balance;
+}
+
+/// Budget paid to a recipient linearly over time
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Stream {
+    pub recipient: AccountId,
+    pub asset: AccountId,
+    pub amount: Balance,
+    /// Amount withdrawn so far
+    pub withdrawn: Balance,
+    pub start: Timestamp,
+    pub end: Timestamp,
+}
+
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
+pub enum Error {
+    AmountIsZero = 0,
+    InvalidStreamPeriod = 1,
+    InsufficientUncommittedBalance = 2,
+    StreamDoesNotExist = 3,
+    CallerIsNotRecipient = 4,
+    NothingToWithdraw = 5,
+    AccessControl(AccessControlError) = 6,
+    PSP22(PSP22Error) = 7,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        match self {
+            Error::AccessControl(error) => error.code(),
+            Error::PSP22(error) => error.code(),
+            _ => error_code(module::TREASURY, self),
+        }
+    }
+}
+
+impl From<AccessControlError> for Error {
+    fn from(error: AccessControlError) -> Self {
+        Error::AccessControl(error)
+    }
+}
+
+impl From<PSP22Error> for Error {
+    fn from(error: PSP22Error) -> Self {
+        Error::PSP22(error)
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
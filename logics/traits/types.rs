@@ -5,6 +5,12 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use core::ops::{
+    Add,
+    Div,
+    Mul,
+    Sub,
+};
 #[cfg(feature = "std")]
 use ink::metadata::layout::{
     Layout,
@@ -21,8 +27,11 @@ use scale::{
     Encode,
 };
 
+/// Identifier of a privileged action in `AdminAction` events, the selector of its message
+pub type ActionId = [u8; 4];
+
 /// Wrapper definition for easier handling of U256
-#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Encode, Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
 pub struct WrappedU256(U256);
 
@@ -38,6 +47,163 @@ impl From<U256> for WrappedU256 {
     }
 }
 
+impl WrappedU256 {
+    pub fn zero() -> Self {
+        WrappedU256(U256::zero())
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(WrappedU256)
+    }
+
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(WrappedU256)
+    }
+
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        self.0.checked_mul(other.0).map(WrappedU256)
+    }
+
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        self.0.checked_div(other.0).map(WrappedU256)
+    }
+}
+
+macro_rules! impl_op {
+    ( $( $trait:ident, $method:ident );* ) => {
+        $(
+            impl $trait for WrappedU256 {
+                type Output = WrappedU256;
+                #[inline]
+                fn $method(self, other: Self) -> Self::Output {
+                    WrappedU256(self.0.$method(other.0))
+                }
+            }
+        )*
+    };
+}
+
+impl_op!(Add, add; Sub, sub; Mul, mul; Div, div);
+
+/// Mantissa of 1 basis point, 1e18 being 100%
+const MANTISSA_PER_BPS: u128 = 100_000_000_000_000;
+
+/// Percentage in basis points, never above 100%
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Encode, Decode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Bps(u16);
+
+impl Bps {
+    /// 100%
+    pub const MAX: u16 = 10000;
+
+    pub const fn new(value: u16) -> Option<Bps> {
+        if value > Bps::MAX {
+            return None
+        }
+        Some(Bps(value))
+    }
+
+    /// Percentage of a mantissa scaled by 1e18, which must be a whole number of basis points
+    pub fn from_mantissa(mantissa: U256) -> Option<Bps> {
+        let per_bps = U256::from(MANTISSA_PER_BPS);
+        if !(mantissa % per_bps).is_zero() || mantissa > per_bps.mul(U256::from(Bps::MAX)) {
+            return None
+        }
+        Bps::new(mantissa.div(per_bps).as_u32() as u16)
+    }
+
+    pub const fn value(&self) -> u16 {
+        self.0
+    }
+
+    /// Mantissa scaled by 1e18
+    pub fn to_mantissa(&self) -> U256 {
+        U256::from(self.0).mul(U256::from(MANTISSA_PER_BPS))
+    }
+}
+
+/// Signed fixed-point value as a magnitude and a sign, e.g. liquidity net of borrows.
+/// Zero is never negative
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+pub struct SignedExp {
+    pub mantissa: WrappedU256,
+    pub negative: bool,
+}
+
+impl SignedExp {
+    pub fn new(mantissa: U256, negative: bool) -> SignedExp {
+        SignedExp {
+            mantissa: WrappedU256::from(mantissa),
+            negative: negative && !mantissa.is_zero(),
+        }
+    }
+
+    pub fn zero() -> SignedExp {
+        SignedExp::default()
+    }
+
+    /// `a - b`
+    pub fn difference(a: U256, b: U256) -> SignedExp {
+        if a >= b {
+            SignedExp::new(a.sub(b), false)
+        } else {
+            SignedExp::new(b.sub(a), true)
+        }
+    }
+
+    pub fn magnitude(&self) -> U256 {
+        U256::from(self.mantissa)
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn neg(&self) -> SignedExp {
+        SignedExp::new(self.magnitude(), !self.negative)
+    }
+
+    pub fn add(&self, another: SignedExp) -> SignedExp {
+        if self.negative == another.negative {
+            return SignedExp::new(self.magnitude().add(another.magnitude()), self.negative)
+        }
+        let difference = SignedExp::difference(self.magnitude(), another.magnitude());
+        if self.negative {
+            difference.neg()
+        } else {
+            difference
+        }
+    }
+
+    pub fn sub(&self, another: SignedExp) -> SignedExp {
+        self.add(another.neg())
+    }
+
+    /// Splits into the positive part and the negative part, e.g. (liquidity, shortfall)
+    pub fn split(&self) -> (U256, U256) {
+        if self.negative {
+            (U256::zero(), self.magnitude())
+        } else {
+            (self.magnitude(), U256::zero())
+        }
+    }
+}
+
+impl From<U256> for SignedExp {
+    fn from(value: U256) -> Self {
+        SignedExp::new(value, false)
+    }
+}
+
 #[cfg(feature = "std")]
 impl StorageLayout for WrappedU256 {
     fn layout(key: &Key) -> Layout {
@@ -65,4 +231,102 @@ macro_rules! construct_from {
     };
 }
 
-construct_from!(u8, u16, u32, u64, usize, i8, i16, i32, i64);
+construct_from!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signed(value: i64) -> SignedExp {
+        SignedExp::new(U256::from(value.unsigned_abs()), value < 0)
+    }
+
+    #[test]
+    fn test_wrapped_u256_ops() {
+        let a = WrappedU256::from(6_u128);
+        let b = WrappedU256::from(3_u128);
+        assert_eq!(a + b, WrappedU256::from(9_u128));
+        assert_eq!(a - b, WrappedU256::from(3_u128));
+        assert_eq!(a * b, WrappedU256::from(18_u128));
+        assert_eq!(a / b, WrappedU256::from(2_u128));
+        assert!(a > b);
+        assert_eq!(a.max(b), a);
+    }
+
+    #[test]
+    fn test_wrapped_u256_checked_ops() {
+        let max = WrappedU256::from(U256::MAX);
+        let one = WrappedU256::from(1_u128);
+        assert_eq!(max.checked_add(one), None);
+        assert_eq!(WrappedU256::zero().checked_sub(one), None);
+        assert_eq!(max.checked_mul(WrappedU256::from(2_u128)), None);
+        assert_eq!(one.checked_div(WrappedU256::zero()), None);
+        assert_eq!(one.checked_add(one), Some(WrappedU256::from(2_u128)));
+        assert_eq!(one.checked_sub(one), Some(WrappedU256::zero()));
+    }
+
+    #[test]
+    fn test_wrapped_u256_conversions() {
+        assert_eq!(u128::try_from(WrappedU256::from(u128::MAX)), Ok(u128::MAX));
+        assert!(u128::try_from(WrappedU256::from(U256::from(u128::MAX) + 1)).is_err());
+        assert_eq!(u64::try_from(WrappedU256::from(7_u64)), Ok(7));
+    }
+
+    #[test]
+    fn test_bps() {
+        assert_eq!(Bps::new(Bps::MAX).map(|bps| bps.value()), Some(10000));
+        assert_eq!(Bps::new(Bps::MAX + 1), None);
+        assert_eq!(
+            Bps::new(5000).unwrap().to_mantissa(),
+            U256::from(500_000_000_000_000_000_u128)
+        );
+        assert_eq!(
+            Bps::from_mantissa(U256::from(1_000_000_000_000_000_000_u128)),
+            Bps::new(10000)
+        );
+        assert_eq!(Bps::from_mantissa(U256::zero()), Bps::new(0));
+        // above 100% or not a whole number of basis points
+        assert_eq!(
+            Bps::from_mantissa(U256::from(1_000_100_000_000_000_000_u128)),
+            None
+        );
+        assert_eq!(Bps::from_mantissa(U256::from(1)), None);
+        assert_eq!(Bps::from_mantissa(U256::MAX), None);
+    }
+
+    #[test]
+    fn test_signed_exp_difference() {
+        assert_eq!(
+            SignedExp::difference(U256::from(5), U256::from(3)),
+            signed(2)
+        );
+        assert_eq!(
+            SignedExp::difference(U256::from(3), U256::from(5)),
+            signed(-2)
+        );
+        assert_eq!(
+            SignedExp::difference(U256::from(3), U256::from(3)),
+            signed(0)
+        );
+        assert!(!SignedExp::difference(U256::from(3), U256::from(3)).is_negative());
+    }
+
+    #[test]
+    fn test_signed_exp_add_sub() {
+        assert_eq!(signed(2).add(signed(3)), signed(5));
+        assert_eq!(signed(-2).add(signed(-3)), signed(-5));
+        assert_eq!(signed(2).add(signed(-3)), signed(-1));
+        assert_eq!(signed(-2).add(signed(3)), signed(1));
+        assert_eq!(signed(-3).add(signed(3)), signed(0));
+        assert_eq!(signed(2).sub(signed(3)), signed(-1));
+        assert_eq!(signed(-2).sub(signed(-3)), signed(1));
+        assert_eq!(signed(0).neg(), signed(0));
+    }
+
+    #[test]
+    fn test_signed_exp_split() {
+        assert_eq!(signed(2).split(), (U256::from(2), U256::zero()));
+        assert_eq!(signed(-2).split(), (U256::zero(), U256::from(2)));
+        assert_eq!(signed(0).split(), (U256::zero(), U256::zero()));
+    }
+}
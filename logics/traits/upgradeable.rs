@@ -0,0 +1,61 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::error_code::{
+    error_code,
+    module,
+    ErrorCode,
+};
+use openbrush::traits::Hash;
+use scale::{
+    Decode,
+    Encode,
+};
+
+#[openbrush::wrapper]
+pub type UpgradeableRef = dyn Upgradeable;
+
+/// Code of a deployed contract replaced in place, keeping its storage and address.
+/// An upgrade is only accepted while the storage version matches the version of the running code,
+/// code changing the storage layout bumps its version and `migrate` transforms the storage to it.
+#[openbrush::trait_definition]
+pub trait Upgradeable {
+    /// Replaces the code of this contract with the code uploaded as `new_code_hash`
+    #[ink(message)]
+    fn upgrade_code(&mut self, new_code_hash: Hash) -> Result<()>;
+
+    /// Transforms the storage from its version to the version of the running code, one version at
+    /// a time. Anyone can call it, as every step is determined by the code.
+    #[ink(message)]
+    fn migrate(&mut self) -> Result<()>;
+
+    /// Returns the version of the layout the storage is in
+    #[ink(message)]
+    fn version(&self) -> u32;
+
+    /// Returns the version of the layout the running code expects
+    #[ink(message)]
+    fn code_version(&self) -> u32;
+}
+
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
+pub enum Error {
+    CallerIsNotAdmin = 0,
+    VersionMismatch = 1,
+    SetCodeHashFailed = 2,
+    MigrationNotFound = 3,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        error_code(module::UPGRADEABLE, self)
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
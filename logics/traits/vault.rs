@@ -0,0 +1,128 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::error_code::{
+    error_code,
+    module,
+    ErrorCode,
+};
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+use super::pool::Error as PoolError;
+
+#[openbrush::wrapper]
+pub type VaultRef = dyn Vault;
+
+/// ERC-4626 style vault interface of a Pool
+///
+/// Shares are the pool's principal (non-rebasing) token balances and assets are
+/// the underlying, so yield aggregators can integrate pools as a standard vault.
+#[openbrush::trait_definition]
+pub trait Vault {
+    /// AccountId of the underlying asset
+    #[ink(message)]
+    fn asset(&self) -> Option<AccountId>;
+
+    /// Underlying managed by the vault, including outstanding borrows net of reserves
+    #[ink(message)]
+    fn total_assets(&self) -> Balance;
+
+    /// Shares exchanged for `assets` at the stored exchange rate
+    #[ink(message)]
+    fn convert_to_shares(&self, assets: Balance) -> Balance;
+
+    /// Assets exchanged for `shares` at the stored exchange rate
+    #[ink(message)]
+    fn convert_to_assets(&self, shares: Balance) -> Balance;
+
+    /// Maximum assets `receiver` can deposit
+    #[ink(message)]
+    fn max_deposit(&self, receiver: AccountId) -> Balance;
+
+    /// Shares minted by depositing `assets`
+    #[ink(message)]
+    fn preview_deposit(&self, assets: Balance) -> Balance;
+
+    /// Deposits `assets` from the sender and mints shares to `receiver`, returns the shares minted
+    #[ink(message, payable)]
+    fn deposit(&mut self, assets: Balance, receiver: AccountId) -> Result<Balance>;
+
+    /// Maximum shares `receiver` can mint
+    #[ink(message)]
+    fn max_mint(&self, receiver: AccountId) -> Balance;
+
+    /// Assets needed to mint `shares`
+    #[ink(message)]
+    fn preview_mint(&self, shares: Balance) -> Balance;
+
+    /// Mints `shares` to `receiver` for assets of the sender, returns the assets deposited
+    #[ink(message, payable)]
+    fn mint(&mut self, shares: Balance, receiver: AccountId) -> Result<Balance>;
+
+    /// Maximum assets `owner` can withdraw
+    #[ink(message)]
+    fn max_withdraw(&self, owner: AccountId) -> Balance;
+
+    /// Shares burned by withdrawing `assets`
+    #[ink(message)]
+    fn preview_withdraw(&self, assets: Balance) -> Balance;
+
+    /// Burns shares of `owner` and sends `assets` to `receiver`, returns the shares burned
+    #[ink(message)]
+    fn withdraw(
+        &mut self,
+        assets: Balance,
+        receiver: AccountId,
+        owner: AccountId,
+    ) -> Result<Balance>;
+
+    /// Maximum shares `owner` can redeem
+    #[ink(message)]
+    fn max_redeem(&self, owner: AccountId) -> Balance;
+
+    /// Assets sent by redeeming `shares`
+    #[ink(message)]
+    fn preview_redeem(&self, shares: Balance) -> Balance;
+
+    /// Burns `shares` of `owner` and sends the assets to `receiver`, returns the assets sent
+    #[ink(message)]
+    fn redeem(&mut self, shares: Balance, receiver: AccountId, owner: AccountId)
+        -> Result<Balance>;
+}
+
+/// Custom error definitions for Vault
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
+pub enum Error {
+    CallerIsNotOwner = 0,
+    Pool(PoolError) = 1,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        match self {
+            Error::Pool(error) => error.code(),
+            _ => error_code(module::VAULT, self),
+        }
+    }
+}
+
+impl From<PoolError> for Error {
+    fn from(error: PoolError) -> Self {
+        Error::Pool(error)
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
@@ -0,0 +1,134 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::error_code::{
+    error_code,
+    module,
+    ErrorCode,
+};
+use ink::prelude::vec::Vec;
+use openbrush::{
+    contracts::{
+        ownable::*,
+        psp22::PSP22Error,
+    },
+    traits::{
+        AccountId,
+        Balance,
+        Timestamp,
+    },
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+#[openbrush::wrapper]
+pub type VestingRef = dyn Vesting + Ownable;
+
+/// Releases LAY to beneficiaries linearly after a cliff.
+/// Schedules are created and funded by the owner, the treasury.
+#[openbrush::trait_definition]
+pub trait Vesting {
+    /// Creates the schedule of `beneficiary`, transferring `amount` from the caller
+    #[ink(message)]
+    fn create_vesting(
+        &mut self,
+        beneficiary: AccountId,
+        amount: Balance,
+        start: Timestamp,
+        cliff: Timestamp,
+        duration: Timestamp,
+        revocable: bool,
+    ) -> Result<()>;
+
+    /// Stops the revocable schedule of `beneficiary`, the amount not vested yet is returned to
+    /// the caller. The vested amount stays claimable
+    #[ink(message)]
+    fn revoke(&mut self, beneficiary: AccountId) -> Result<Balance>;
+
+    /// Transfers the vested amount not released yet to the caller
+    #[ink(message)]
+    fn claim(&mut self) -> Result<Balance>;
+
+    /// Returns the schedule of `beneficiary`
+    #[ink(message)]
+    fn vesting_of(&self, beneficiary: AccountId) -> Option<VestingSchedule>;
+
+    /// Returns the amount `beneficiary` can claim now
+    #[ink(message)]
+    fn releasable_amount(&self, beneficiary: AccountId) -> Balance;
+
+    /// Returns the beneficiaries with a schedule
+    #[ink(message)]
+    fn beneficiaries(&self) -> Vec<AccountId>;
+
+    /// Returns the vested token
+    #[ink(message)]
+    fn token(&self) -> Option<AccountId>;
+}
+
+/// Vesting schedule of a beneficiary
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct VestingSchedule {
+    /// Total amount vested by the end of the schedule
+    pub amount: Balance,
+    /// Amount claimed so far
+    pub released: Balance,
+    pub start: Timestamp,
+    /// Time from the start before which nothing is vested
+    pub cliff: Timestamp,
+    /// Time from the start at which everything is vested
+    pub duration: Timestamp,
+    pub revocable: bool,
+    pub revoked: bool,
+}
+
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
+pub enum Error {
+    TokenIsNotSet = 0,
+    AmountIsZero = 1,
+    DurationIsZero = 2,
+    CliffIsLongerThanDuration = 3,
+    VestingAlreadyExists = 4,
+    VestingDoesNotExist = 5,
+    VestingIsNotRevocable = 6,
+    VestingIsRevoked = 7,
+    NothingToClaim = 8,
+    PSP22(PSP22Error) = 9,
+    Ownable(OwnableError) = 10,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        match self {
+            Error::PSP22(error) => error.code(),
+            Error::Ownable(error) => error.code(),
+            _ => error_code(module::VESTING, self),
+        }
+    }
+}
+
+impl From<PSP22Error> for Error {
+    fn from(error: PSP22Error) -> Self {
+        Error::PSP22(error)
+    }
+}
+
+impl From<OwnableError> for Error {
+    fn from(error: OwnableError) -> Self {
+        Error::Ownable(error)
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
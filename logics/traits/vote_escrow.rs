@@ -0,0 +1,114 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::error_code::{
+    error_code,
+    module,
+    ErrorCode,
+};
+use openbrush::{
+    contracts::psp22::PSP22Error,
+    traits::{
+        AccountId,
+        Balance,
+        Timestamp,
+    },
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+#[openbrush::wrapper]
+pub type VoteEscrowRef = dyn VoteEscrow;
+
+/// One week in milliseconds, unlock times are rounded down to whole weeks
+pub const WEEK: Timestamp = 7 * 24 * 60 * 60 * 1000;
+/// Longest lock, 4 years in milliseconds
+pub const MAX_LOCK_DURATION: Timestamp = 4 * 365 * 24 * 60 * 60 * 1000;
+
+/// Locks LAY for 1 week to 4 years in exchange of veLAY,
+/// a non-transferable balance decaying linearly to zero at the end of the lock.
+/// A lock of `amount` for 4 years is worth `amount` veLAY.
+#[openbrush::trait_definition]
+pub trait VoteEscrow {
+    /// Locks `amount` of the caller until `unlock_time`, rounded down to a week
+    #[ink(message)]
+    fn create_lock(&mut self, amount: Balance, unlock_time: Timestamp) -> Result<()>;
+
+    /// Adds `amount` to the lock of the caller without changing its unlock time
+    #[ink(message)]
+    fn increase_amount(&mut self, amount: Balance) -> Result<()>;
+
+    /// Extends the lock of the caller until `unlock_time`, rounded down to a week
+    #[ink(message)]
+    fn increase_unlock_time(&mut self, unlock_time: Timestamp) -> Result<()>;
+
+    /// Withdraws the expired lock of the caller
+    #[ink(message)]
+    fn withdraw(&mut self) -> Result<Balance>;
+
+    /// Returns the veLAY balance of `account` now
+    #[ink(message)]
+    fn balance_of(&self, account: AccountId) -> Balance;
+
+    /// Returns the sum of veLAY balances now
+    #[ink(message)]
+    fn total_supply(&self) -> Balance;
+
+    /// Returns the lock of `account`
+    #[ink(message)]
+    fn locked(&self, account: AccountId) -> LockedBalance;
+
+    /// Returns the locked token
+    #[ink(message)]
+    fn token(&self) -> Option<AccountId>;
+}
+
+/// Tokens locked by an account and when they unlock
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct LockedBalance {
+    pub amount: Balance,
+    pub end: Timestamp,
+}
+
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
+pub enum Error {
+    TokenIsNotSet = 0,
+    AmountIsZero = 1,
+    LockAlreadyExists = 2,
+    LockDoesNotExist = 3,
+    LockIsExpired = 4,
+    LockIsNotExpired = 5,
+    UnlockTimeIsTooShort = 6,
+    UnlockTimeIsTooLong = 7,
+    UnlockTimeIsNotIncreased = 8,
+    PSP22(PSP22Error) = 9,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        match self {
+            Error::PSP22(error) => error.code(),
+            _ => error_code(module::VOTE_ESCROW, self),
+        }
+    }
+}
+
+impl From<PSP22Error> for Error {
+    fn from(error: PSP22Error) -> Self {
+        Error::PSP22(error)
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
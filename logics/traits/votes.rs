@@ -0,0 +1,87 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::error_code::{
+    error_code,
+    module,
+    ErrorCode,
+};
+use openbrush::traits::{
+    AccountId,
+    Balance,
+    Timestamp,
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+#[openbrush::wrapper]
+pub type VotesRef = dyn Votes;
+
+/// Trait for tokens whose balances are checkpointed and can be delegated as voting weight
+#[openbrush::trait_definition]
+pub trait Votes {
+    /// Delegates the sender's voting weight to `delegatee`
+    #[ink(message)]
+    fn delegate(&mut self, delegatee: AccountId) -> Result<()>;
+
+    /// Account the voting weight of `account` is delegated to
+    #[ink(message)]
+    fn delegates(&self, account: AccountId) -> Option<AccountId>;
+
+    /// Current voting weight of `account`
+    #[ink(message)]
+    fn get_votes(&self, account: AccountId) -> Balance;
+
+    /// Voting weight of `account` at the end of `timestamp`
+    #[ink(message)]
+    fn get_past_votes(&self, account: AccountId, timestamp: Timestamp) -> Result<Balance>;
+
+    /// Token balance of `account` at the end of `timestamp`
+    #[ink(message)]
+    fn get_past_balance(&self, account: AccountId, timestamp: Timestamp) -> Result<Balance>;
+
+    /// Total supply at the end of `timestamp`
+    #[ink(message)]
+    fn get_past_total_supply(&self, timestamp: Timestamp) -> Result<Balance>;
+
+    /// Number of voting weight checkpoints of `account`
+    #[ink(message)]
+    fn vote_checkpoints_count(&self, account: AccountId) -> u32;
+
+    /// Voting weight checkpoint of `account` at `position`
+    #[ink(message)]
+    fn vote_checkpoint(&self, account: AccountId, position: u32) -> Option<Checkpoint>;
+}
+
+/// Value of a checkpointed quantity from `timestamp` onwards
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Decode, Encode)]
+#[cfg_attr(
+    feature = "std",
+    derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+)]
+pub struct Checkpoint {
+    pub timestamp: Timestamp,
+    pub value: Balance,
+}
+
+/// Custom error definitions for Votes
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
+pub enum Error {
+    FutureLookup = 0,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        error_code(module::VOTES, self)
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
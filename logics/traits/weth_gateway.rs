@@ -5,6 +5,11 @@
 // option. This file may not be copied, modified, or distributed
 // except according to those terms.
 
+use super::error_code::{
+    error_code,
+    module,
+    ErrorCode,
+};
 use openbrush::{
     contracts::{
         ownable::*,
@@ -21,7 +26,10 @@ use scale::{
     Encode,
 };
 
-pub use super::pool::Error as PoolError;
+pub use super::{
+    pool::Error as PoolError,
+    psp22_permit::PermitSignature,
+};
 
 #[openbrush::wrapper]
 pub type WETHGatewayRef = dyn WETHGateway + Ownable;
@@ -44,6 +52,16 @@ pub trait WETHGateway: Ownable {
     #[ink(message)]
     fn borrow_eth(&mut self, pool: AccountId, amount: Balance) -> Result<()>;
 
+    /// Deposits `amount` of the pool's underlying from caller, approved with a PSP22 permit instead of a prior `approve`.
+    /// Falls back to an existing allowance if the permit has already been consumed.
+    #[ink(message)]
+    fn deposit_with_permit(
+        &mut self,
+        pool: AccountId,
+        amount: Balance,
+        permit_sig: PermitSignature,
+    ) -> Result<()>;
+
     /// Transfer PSP22 from the utility contract, for PSP22 recovery in case of stuck tokens due direct transfers to the contract address.
     #[ink(message)]
     #[modifiers(only_owner)]
@@ -66,12 +84,25 @@ pub trait WETHGateway: Ownable {
 
 #[derive(Debug, PartialEq, Eq, Encode, Decode)]
 #[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
 pub enum Error {
-    SafeETHTransferFailed,
-    InsufficientPayback,
-    WethIsNotSet,
-    Pool(PoolError),
-    PSP22(PSP22Error),
+    SafeETHTransferFailed = 0,
+    InsufficientPayback = 1,
+    WethIsNotSet = 2,
+    UnderlyingIsNotSet = 3,
+    PermitFailed = 4,
+    Pool(PoolError) = 5,
+    PSP22(PSP22Error) = 6,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        match self {
+            Error::Pool(error) => error.code(),
+            Error::PSP22(error) => error.code(),
+            _ => error_code(module::WETH_GATEWAY, self),
+        }
+    }
 }
 
 impl From<PSP22Error> for Error {
@@ -0,0 +1,73 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+use super::error_code::{
+    error_code,
+    module,
+    ErrorCode,
+};
+use openbrush::traits::{
+    AccountId,
+    Balance,
+};
+use scale::{
+    Decode,
+    Encode,
+};
+
+pub use super::pool::Error as PoolError;
+
+#[openbrush::wrapper]
+pub type XcmDepositRef = dyn XcmDeposit;
+
+/// Receiver of XC-20 assets reserve-transferred through XCM, depositing them into their pools.
+///
+/// Remote chains send the assets to this contract and, in the same XCM program, `Transact`
+/// a call to `deposit`. The call is dispatched from the account derived from the remote origin,
+/// which receives the pool tokens.
+#[openbrush::trait_definition]
+pub trait XcmDeposit {
+    /// Get Controller AccountId
+    #[ink(message)]
+    fn controller(&self) -> Option<AccountId>;
+
+    /// Pool whose underlying is the XC-20 asset `asset_id`
+    #[ink(message)]
+    fn pool_of(&self, asset_id: u128) -> Option<AccountId>;
+
+    /// Deposits all of `asset_id` held by this contract into its pool on behalf of caller
+    #[ink(message)]
+    fn deposit(&mut self, asset_id: u128) -> Result<Balance>;
+}
+
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[cfg_attr(feature = "std", derive(scale_info::TypeInfo))]
+#[repr(u8)]
+pub enum Error {
+    ControllerIsNotSet = 0,
+    MarketNotListed = 1,
+    NothingReceived = 2,
+    ApprovePalletAssetFailed = 3,
+    Pool(PoolError) = 4,
+}
+
+impl ErrorCode for Error {
+    fn code(&self) -> u32 {
+        match self {
+            Error::Pool(error) => error.code(),
+            _ => error_code(module::XCM_DEPOSIT, self),
+        }
+    }
+}
+
+impl From<PoolError> for Error {
+    fn from(error: PoolError) -> Self {
+        Error::Pool(error)
+    }
+}
+
+pub type Result<T> = core::result::Result<T, Error>;
@@ -0,0 +1,206 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![feature(min_specialization)]
+
+#[cfg(test)]
+mod tests;
+
+/// Controller whose checks of pool actions and seize calculation are set by the tests,
+/// allowing every action unless told otherwise
+#[openbrush::contract]
+pub mod contract {
+    use logics::{
+        impls::{
+            controller::*,
+            exp_no_err::Exp,
+            pool::utils::scaled_amount_of_truncate,
+        },
+        traits::types::WrappedU256,
+    };
+    use openbrush::{
+        storage::Mapping,
+        traits::Storage,
+    };
+    use scale::{
+        Decode,
+        Encode,
+    };
+
+    /// Action checked by a `*_allowed` message
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+    #[cfg_attr(
+        feature = "std",
+        derive(scale_info::TypeInfo, ink::storage::traits::StorageLayout)
+    )]
+    pub enum Action {
+        Mint,
+        Redeem,
+        Borrow,
+        RepayBorrow,
+        LiquidateBorrow,
+        Seize,
+        Absorb,
+        Transfer,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct ControllerContract {
+        #[storage_field]
+        controller: Data,
+        /// error returned by the check of an action, allowed if none
+        errors: Mapping<Action, Error>,
+        /// seize tokens returned by `liquidate_calculate_seize_tokens`,
+        /// `repay_amount` converted with the exchange rate if none
+        seize_tokens: Option<Balance>,
+    }
+
+    impl Controller for ControllerContract {
+        #[ink(message)]
+        fn mint_allowed(
+            &self,
+            _pool: AccountId,
+            _minter: AccountId,
+            _mint_amount: Balance,
+        ) -> Result<()> {
+            self._allowed(Action::Mint)
+        }
+
+        #[ink(message)]
+        fn redeem_allowed(
+            &self,
+            _pool: AccountId,
+            _redeemer: AccountId,
+            _redeem_amount: Balance,
+            _pool_attribute: Option<PoolAttributes>,
+        ) -> Result<()> {
+            self._allowed(Action::Redeem)
+        }
+
+        #[ink(message)]
+        fn borrow_allowed(
+            &self,
+            _pool: AccountId,
+            _borrower: AccountId,
+            _borrow_amount: Balance,
+            _pool_attribute: Option<PoolAttributes>,
+        ) -> Result<()> {
+            self._allowed(Action::Borrow)
+        }
+
+        #[ink(message)]
+        fn repay_borrow_allowed(
+            &self,
+            _pool: AccountId,
+            _payer: AccountId,
+            _borrower: AccountId,
+            _repay_amount: Balance,
+        ) -> Result<()> {
+            self._allowed(Action::RepayBorrow)
+        }
+
+        #[ink(message)]
+        fn liquidate_borrow_allowed(
+            &self,
+            _pool_borrowed: AccountId,
+            _pool_collateral: AccountId,
+            _liquidator: AccountId,
+            _borrower: AccountId,
+            _repay_amount: Balance,
+            _pool_attribute: Option<PoolAttributes>,
+        ) -> Result<()> {
+            self._allowed(Action::LiquidateBorrow)
+        }
+
+        #[ink(message)]
+        fn seize_allowed(
+            &self,
+            _pool_collateral: AccountId,
+            _pool_borrowed: AccountId,
+            _liquidator: AccountId,
+            _borrower: AccountId,
+            _seize_tokens: Balance,
+        ) -> Result<()> {
+            self._allowed(Action::Seize)
+        }
+
+        #[ink(message)]
+        fn absorb_allowed(&self, _pool: AccountId, _borrower: AccountId) -> Result<()> {
+            self._allowed(Action::Absorb)
+        }
+
+        #[ink(message)]
+        fn transfer_allowed(
+            &self,
+            _pool: AccountId,
+            _src: AccountId,
+            _dst: AccountId,
+            _transfer_tokens: Balance,
+            _pool_attribute: Option<PoolAttributes>,
+        ) -> Result<()> {
+            self._allowed(Action::Transfer)
+        }
+
+        #[ink(message)]
+        fn liquidate_calculate_seize_tokens(
+            &self,
+            _pool_borrowed: AccountId,
+            _pool_collateral: AccountId,
+            exchange_rate_mantissa: WrappedU256,
+            repay_amount: Balance,
+            _pool_borrowed_attributes: Option<PoolAttributesForSeizeCalculation>,
+            _pool_collateral_attributes: Option<PoolAttributesForSeizeCalculation>,
+        ) -> Result<Balance> {
+            if let Some(seize_tokens) = self.seize_tokens {
+                return Ok(seize_tokens)
+            }
+            let exchange_rate = Exp {
+                mantissa: exchange_rate_mantissa,
+            };
+            if exchange_rate.mantissa.is_zero() {
+                return Err(Error::MathOverflow)
+            }
+            scaled_amount_of_truncate(repay_amount, exchange_rate).map_err(|_| Error::MathOverflow)
+        }
+    }
+
+    impl ControllerContract {
+        #[ink(constructor)]
+        pub fn new(manager: AccountId) -> Self {
+            let mut instance = Self::default();
+            instance.controller.manager = Some(manager);
+            instance
+        }
+
+        /// Makes the check of `action` fail with `error`, or pass if none
+        #[ink(message)]
+        pub fn set_allowed_result(&mut self, action: Action, error: Option<Error>) -> Result<()> {
+            match error {
+                Some(error) => {
+                    self.errors.insert(&action, &error);
+                }
+                None => self.errors.remove(&action),
+            }
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_seize_tokens(&mut self, seize_tokens: Option<Balance>) -> Result<()> {
+            self.seize_tokens = seize_tokens;
+            Ok(())
+        }
+
+        fn _allowed(&self, action: Action) -> Result<()> {
+            match self.errors.get(&action) {
+                Some(error) => Err(error),
+                None => Ok(()),
+            }
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use crate::contract::*;
+use logics::{
+    impls::{
+        controller::*,
+        exp_no_err::exp_scale,
+    },
+    traits::types::WrappedU256,
+};
+use openbrush::traits::AccountId;
+
+#[ink::test]
+fn actions_are_allowed_by_default() {
+    let pool = AccountId::from([0x01; 32]);
+    let account = AccountId::from([0x02; 32]);
+    let contract = ControllerContract::new(account);
+
+    assert!(contract.mint_allowed(pool, account, 100).is_ok());
+    assert!(contract.redeem_allowed(pool, account, 100, None).is_ok());
+    assert!(contract.borrow_allowed(pool, account, 100, None).is_ok());
+    assert!(contract
+        .repay_borrow_allowed(pool, account, account, 100)
+        .is_ok());
+    assert!(contract
+        .liquidate_borrow_allowed(pool, pool, account, account, 100, None)
+        .is_ok());
+    assert!(contract
+        .seize_allowed(pool, pool, account, account, 100)
+        .is_ok());
+    assert!(contract.absorb_allowed(pool, account).is_ok());
+    assert!(contract
+        .transfer_allowed(pool, account, account, 100, None)
+        .is_ok());
+}
+
+#[ink::test]
+fn set_allowed_result_works() {
+    let pool = AccountId::from([0x01; 32]);
+    let account = AccountId::from([0x02; 32]);
+    let mut contract = ControllerContract::new(account);
+
+    assert!(contract
+        .set_allowed_result(Action::Borrow, Some(Error::BorrowIsPaused))
+        .is_ok());
+    assert_eq!(
+        contract
+            .borrow_allowed(pool, account, 100, None)
+            .unwrap_err(),
+        Error::BorrowIsPaused
+    );
+    // other actions are unaffected
+    assert!(contract.mint_allowed(pool, account, 100).is_ok());
+
+    assert!(contract.set_allowed_result(Action::Borrow, None).is_ok());
+    assert!(contract.borrow_allowed(pool, account, 100, None).is_ok());
+}
+
+#[ink::test]
+fn liquidate_calculate_seize_tokens_works() {
+    let pool = AccountId::from([0x01; 32]);
+    let account = AccountId::from([0x02; 32]);
+    let mut contract = ControllerContract::new(account);
+
+    // repay amount converted with the exchange rate
+    let exchange_rate = WrappedU256::from(exp_scale() * 2);
+    assert_eq!(
+        contract
+            .liquidate_calculate_seize_tokens(pool, pool, exchange_rate, 100, None, None)
+            .unwrap(),
+        50
+    );
+    assert_eq!(
+        contract
+            .liquidate_calculate_seize_tokens(pool, pool, WrappedU256::from(0), 100, None, None)
+            .unwrap_err(),
+        Error::MathOverflow
+    );
+
+    assert!(contract.set_seize_tokens(Some(7)).is_ok());
+    assert_eq!(
+        contract
+            .liquidate_calculate_seize_tokens(pool, pool, exchange_rate, 100, None, None)
+            .unwrap(),
+        7
+    );
+}
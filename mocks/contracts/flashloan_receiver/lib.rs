@@ -10,6 +10,7 @@
 
 #[openbrush::contract]
 pub mod contract {
+    use ink::prelude::vec::Vec;
     use logics::impls::flashloan_receiver::{
         Data,
         Internal,
@@ -45,5 +46,35 @@ pub mod contract {
         pub fn fail_execution_transfer(&self) -> bool {
             self._fail_execution_transfer()
         }
+
+        #[ink(message)]
+        pub fn last_initiator(&self) -> Option<AccountId> {
+            self._last_initiator()
+        }
+
+        #[ink(message)]
+        pub fn last_params(&self) -> Vec<u8> {
+            self._last_params()
+        }
+
+        #[ink(message)]
+        pub fn set_execution_mode(&mut self, mode: ExecutionMode) {
+            self._set_execution_mode(mode);
+        }
+
+        #[ink(message)]
+        pub fn execution_mode(&self) -> ExecutionMode {
+            self._execution_mode()
+        }
+
+        #[ink(message)]
+        pub fn set_wrong_asset(&mut self, asset: AccountId) {
+            self._set_wrong_asset(asset);
+        }
+
+        #[ink(message)]
+        pub fn set_gas_burn_rounds(&mut self, rounds: u32) {
+            self._set_gas_burn_rounds(rounds);
+        }
     }
 }
@@ -12,13 +12,20 @@
 pub mod contract {
     use logics::impls::incentives_controller::*;
 
-    use openbrush::traits::Storage;
+    use openbrush::{
+        contracts::ownable,
+        traits::Storage,
+    };
 
     #[ink(storage)]
     #[derive(Default, Storage)]
     pub struct IncentivesControllerContract {
         #[storage_field]
         data: Data,
+        #[storage_field]
+        ownable: ownable::Data,
+        /// return if handle_action result is ok
+        is_ok: bool,
     }
 
     impl IncentivesController for IncentivesControllerContract {
@@ -43,7 +50,8 @@ pub mod contract {
 
         #[ink(message)]
         pub fn set_ok(&mut self, is_ok: bool) -> Result<()> {
-            self._set_ok(is_ok)
+            self.is_ok = is_ok;
+            Ok(())
         }
     }
 }
@@ -0,0 +1,98 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![feature(min_specialization)]
+
+#[cfg(test)]
+mod tests;
+
+/// Interest rate model returning the rates set by the tests, whatever the utilization rate
+#[openbrush::contract]
+pub mod contract {
+    use ink::prelude::vec::Vec;
+    use logics::{
+        impls::interest_rate_model::{
+            sample_curve,
+            utilization_rate,
+            InterestRateModel,
+            RateCurvePoint,
+        },
+        traits::types::WrappedU256,
+    };
+
+    #[ink(storage)]
+    #[derive(Default)]
+    pub struct InterestRateModelContract {
+        /// borrow rate per milliseconds
+        borrow_rate: WrappedU256,
+        /// supply rate per milliseconds
+        supply_rate: WrappedU256,
+    }
+
+    impl InterestRateModel for InterestRateModelContract {
+        #[ink(message)]
+        fn utilization_rate(
+            &self,
+            cash: Balance,
+            borrows: Balance,
+            reserves: Balance,
+        ) -> WrappedU256 {
+            WrappedU256::from(utilization_rate(cash, borrows, reserves))
+        }
+
+        #[ink(message)]
+        fn get_borrow_rate(
+            &self,
+            _cash: Balance,
+            _borrows: Balance,
+            _reserves: Balance,
+        ) -> WrappedU256 {
+            self.borrow_rate
+        }
+
+        #[ink(message)]
+        fn get_supply_rate(
+            &self,
+            _cash: Balance,
+            _borrows: Balance,
+            _reserves: Balance,
+            _reserve_factor_mantissa: WrappedU256,
+        ) -> WrappedU256 {
+            self.supply_rate
+        }
+
+        #[ink(message)]
+        fn sample_curve(
+            &self,
+            points: u8,
+            reserve_factor_mantissa: WrappedU256,
+        ) -> Vec<RateCurvePoint> {
+            sample_curve(self, points, reserve_factor_mantissa)
+        }
+    }
+
+    impl InterestRateModelContract {
+        #[ink(constructor)]
+        pub fn new(borrow_rate: WrappedU256, supply_rate: WrappedU256) -> Self {
+            Self {
+                borrow_rate,
+                supply_rate,
+            }
+        }
+
+        #[ink(message)]
+        pub fn set_borrow_rate(&mut self, rate: WrappedU256) {
+            self.borrow_rate = rate;
+        }
+
+        #[ink(message)]
+        pub fn set_supply_rate(&mut self, rate: WrappedU256) {
+            self.supply_rate = rate;
+        }
+    }
+}
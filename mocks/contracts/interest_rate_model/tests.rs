@@ -0,0 +1,67 @@
+use crate::contract::*;
+use ink::prelude::vec::Vec;
+use logics::{
+    impls::{
+        exp_no_err::exp_scale,
+        interest_rate_model::InterestRateModel,
+    },
+    traits::types::WrappedU256,
+};
+
+#[ink::test]
+fn rates_ignore_utilization() {
+    let borrow_rate = WrappedU256::from(100);
+    let supply_rate = WrappedU256::from(50);
+    let contract = InterestRateModelContract::new(borrow_rate, supply_rate);
+
+    for (cash, borrows, reserves) in [(100, 0, 0), (50, 50, 0), (0, 100, 10)] {
+        assert_eq!(
+            contract.get_borrow_rate(cash, borrows, reserves),
+            borrow_rate
+        );
+        assert_eq!(
+            contract.get_supply_rate(cash, borrows, reserves, WrappedU256::from(0)),
+            supply_rate
+        );
+    }
+    assert_eq!(
+        contract.utilization_rate(50, 50, 0),
+        WrappedU256::from(exp_scale() / 2)
+    );
+}
+
+#[ink::test]
+fn set_rates_works() {
+    let mut contract =
+        InterestRateModelContract::new(WrappedU256::from(100), WrappedU256::from(50));
+    contract.set_borrow_rate(WrappedU256::from(200));
+    contract.set_supply_rate(WrappedU256::from(150));
+    assert_eq!(contract.get_borrow_rate(0, 0, 0), WrappedU256::from(200));
+    assert_eq!(
+        contract.get_supply_rate(0, 0, 0, WrappedU256::from(0)),
+        WrappedU256::from(150)
+    );
+}
+
+#[ink::test]
+fn sample_curve_works() {
+    let borrow_rate = WrappedU256::from(100);
+    let supply_rate = WrappedU256::from(50);
+    let contract = InterestRateModelContract::new(borrow_rate, supply_rate);
+
+    let curve = contract.sample_curve(3, WrappedU256::from(0));
+    assert_eq!(curve.len(), 3);
+    let utilization_rates: Vec<WrappedU256> =
+        curve.iter().map(|point| point.utilization_rate).collect();
+    assert_eq!(
+        utilization_rates,
+        [
+            WrappedU256::from(0),
+            WrappedU256::from(exp_scale() / 2),
+            WrappedU256::from(exp_scale()),
+        ]
+    );
+    assert!(curve
+        .iter()
+        .all(|point| point.borrow_rate == borrow_rate && point.supply_rate == supply_rate));
+}
@@ -0,0 +1,78 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![feature(min_specialization)]
+
+#[cfg(test)]
+mod tests;
+
+/// Price oracle whose prices anyone can set, and which can be made to behave as if stale
+#[openbrush::contract]
+pub mod contract {
+    use logics::traits::{
+        pool::PoolRef,
+        price_oracle::*,
+    };
+    use openbrush::storage::Mapping;
+
+    #[ink(storage)]
+    #[derive(Default)]
+    pub struct PriceOracleContract {
+        prices: Mapping<AccountId, u128>,
+        stale: Mapping<AccountId, bool>,
+        all_stale: bool,
+    }
+
+    impl PriceOracle for PriceOracleContract {
+        #[ink(message)]
+        fn get_price(&self, asset: AccountId) -> Option<u128> {
+            if self.all_stale || self.stale.get(&asset).unwrap_or(false) {
+                return None
+            }
+            self.prices.get(&asset)
+        }
+
+        #[ink(message)]
+        fn get_underlying_price(&self, pool: AccountId) -> Option<u128> {
+            let underlying = PoolRef::underlying(&pool)?;
+            self.get_price(underlying)
+        }
+
+        #[ink(message)]
+        fn set_fixed_price(&mut self, asset: AccountId, value: u128) -> Result<()> {
+            self.set_price(asset, value)
+        }
+    }
+
+    impl PriceOracleContract {
+        #[ink(constructor)]
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        #[ink(message)]
+        pub fn set_price(&mut self, asset: AccountId, price: u128) -> Result<()> {
+            self.prices.insert(&asset, &price);
+            Ok(())
+        }
+
+        /// Makes the price of the asset unavailable, as a stale price would be
+        #[ink(message)]
+        pub fn set_stale(&mut self, asset: AccountId, stale: bool) -> Result<()> {
+            self.stale.insert(&asset, &stale);
+            Ok(())
+        }
+
+        /// Makes the prices of all assets unavailable, as stale prices would be
+        #[ink(message)]
+        pub fn set_all_stale(&mut self, stale: bool) -> Result<()> {
+            self.all_stale = stale;
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use crate::contract::*;
+use logics::traits::price_oracle::PriceOracle;
+use openbrush::traits::AccountId;
+
+#[ink::test]
+fn set_price_works() {
+    let mut contract = PriceOracleContract::new();
+    let asset = AccountId::from([0x01; 32]);
+    assert_eq!(contract.get_price(asset), None);
+
+    assert!(contract.set_price(asset, 100).is_ok());
+    assert_eq!(contract.get_price(asset), Some(100));
+    assert!(contract.set_fixed_price(asset, 200).is_ok());
+    assert_eq!(contract.get_price(asset), Some(200));
+}
+
+#[ink::test]
+fn set_stale_works() {
+    let mut contract = PriceOracleContract::new();
+    let asset = AccountId::from([0x01; 32]);
+    let other = AccountId::from([0x02; 32]);
+    assert!(contract.set_price(asset, 100).is_ok());
+    assert!(contract.set_price(other, 200).is_ok());
+
+    assert!(contract.set_stale(asset, true).is_ok());
+    assert_eq!(contract.get_price(asset), None);
+    assert_eq!(contract.get_price(other), Some(200));
+
+    assert!(contract.set_stale(asset, false).is_ok());
+    assert_eq!(contract.get_price(asset), Some(100));
+}
+
+#[ink::test]
+fn set_all_stale_works() {
+    let mut contract = PriceOracleContract::new();
+    let asset = AccountId::from([0x01; 32]);
+    let other = AccountId::from([0x02; 32]);
+    assert!(contract.set_price(asset, 100).is_ok());
+    assert!(contract.set_price(other, 200).is_ok());
+
+    assert!(contract.set_all_stale(true).is_ok());
+    assert_eq!(contract.get_price(asset), None);
+    assert_eq!(contract.get_price(other), None);
+
+    // a price stale on its own stays stale once the rest are fresh again
+    assert!(contract.set_stale(asset, true).is_ok());
+    assert!(contract.set_all_stale(false).is_ok());
+    assert_eq!(contract.get_price(asset), None);
+    assert_eq!(contract.get_price(other), Some(200));
+}
@@ -0,0 +1,242 @@
+// Copyright 2023 Asynmatrix Pte. Ltd.
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+#![cfg_attr(not(feature = "std"), no_std, no_main)]
+#![feature(min_specialization)]
+
+#[cfg(test)]
+mod tests;
+
+/// PSP22 token with configurable misbehaviour, for testing the edge cases of the pools
+#[openbrush::contract]
+pub mod token {
+    use ink::{
+        codegen::{
+            EmitEvent,
+            Env,
+        },
+        prelude::vec::Vec,
+        storage::Mapping,
+    };
+    use openbrush::{
+        contracts::psp22::extensions::{
+            burnable::*,
+            metadata::*,
+            mintable::*,
+        },
+        traits::{
+            Storage,
+            String,
+        },
+    };
+
+    const PERCENTAGE_MAX: u8 = 100;
+
+    #[ink(event)]
+    pub struct Transfer {
+        #[ink(topic)]
+        from: Option<AccountId>,
+        #[ink(topic)]
+        to: Option<AccountId>,
+        value: Balance,
+    }
+
+    #[ink(event)]
+    pub struct Approval {
+        #[ink(topic)]
+        owner: AccountId,
+        #[ink(topic)]
+        spender: AccountId,
+        value: Balance,
+    }
+
+    #[ink(storage)]
+    #[derive(Default, Storage)]
+    pub struct MockPSP22Token {
+        #[storage_field]
+        psp22: psp22::Data,
+        #[storage_field]
+        metadata: metadata::Data,
+        /// percentage of each transfer burned from the sender, the receiver gets the rest
+        transfer_fee_percentage: u8,
+        /// accounts which can neither send nor receive tokens
+        blacklisted: Mapping<AccountId, bool>,
+        /// fail transfers until cleared
+        fail_next_transfer: bool,
+    }
+
+    impl PSP22 for MockPSP22Token {
+        #[ink(message)]
+        fn transfer(
+            &mut self,
+            to: AccountId,
+            value: Balance,
+            data: Vec<u8>,
+        ) -> core::result::Result<(), PSP22Error> {
+            let caller = self.env().caller();
+            self._transfer_with_fee(caller, to, value, data)
+        }
+
+        #[ink(message)]
+        fn transfer_from(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            value: Balance,
+            data: Vec<u8>,
+        ) -> core::result::Result<(), PSP22Error> {
+            let caller = self.env().caller();
+            let allowance = self._allowance(&from, &caller);
+            if allowance < value {
+                return Err(PSP22Error::InsufficientAllowance)
+            }
+            self._approve_from_to(from, caller, allowance - value)?;
+            self._transfer_with_fee(from, to, value, data)
+        }
+    }
+
+    impl psp22::Internal for MockPSP22Token {
+        fn _emit_transfer_event(
+            &self,
+            from: Option<AccountId>,
+            to: Option<AccountId>,
+            amount: Balance,
+        ) {
+            self.env().emit_event(Transfer {
+                from,
+                to,
+                value: amount,
+            });
+        }
+
+        fn _emit_approval_event(&self, owner: AccountId, spender: AccountId, amount: Balance) {
+            self.env().emit_event(Approval {
+                owner,
+                spender,
+                value: amount,
+            });
+        }
+
+        fn _before_token_transfer(
+            &mut self,
+            from: Option<&AccountId>,
+            to: Option<&AccountId>,
+            _amount: &Balance,
+        ) -> core::result::Result<(), PSP22Error> {
+            if [from, to]
+                .into_iter()
+                .flatten()
+                .any(|account| self.is_blacklisted(*account))
+            {
+                return Err(PSP22Error::Custom(String::from("Blacklisted")))
+            }
+            if self.fail_next_transfer && from.is_some() && to.is_some() {
+                return Err(PSP22Error::Custom(String::from("TransferFailed")))
+            }
+            Ok(())
+        }
+    }
+
+    impl PSP22Metadata for MockPSP22Token {}
+
+    impl PSP22Mintable for MockPSP22Token {}
+
+    impl PSP22Burnable for MockPSP22Token {}
+
+    impl MockPSP22Token {
+        #[ink(constructor)]
+        pub fn new(
+            total_supply: Balance,
+            name: Option<String>,
+            symbol: Option<String>,
+            decimals: u8,
+        ) -> Self {
+            let mut instance = Self::default();
+            instance.metadata.name = name;
+            instance.metadata.symbol = symbol;
+            instance.metadata.decimals = decimals;
+            instance
+                ._mint_to(instance.env().caller(), total_supply)
+                .expect("Should mint");
+            instance
+        }
+
+        #[ink(message)]
+        pub fn set_decimals(&mut self, decimals: u8) -> core::result::Result<(), PSP22Error> {
+            self.metadata.decimals = decimals;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_transfer_fee_percentage(
+            &mut self,
+            percentage: u8,
+        ) -> core::result::Result<(), PSP22Error> {
+            if percentage > PERCENTAGE_MAX {
+                return Err(PSP22Error::Custom(String::from("InvalidPercentage")))
+            }
+            self.transfer_fee_percentage = percentage;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn set_blacklisted(
+            &mut self,
+            account: AccountId,
+            blacklisted: bool,
+        ) -> core::result::Result<(), PSP22Error> {
+            if blacklisted {
+                self.blacklisted.insert(&account, &true);
+            } else {
+                self.blacklisted.remove(&account);
+            }
+            Ok(())
+        }
+
+        /// The failing transfer reverts its changes, so the flag stays set until cleared
+        #[ink(message)]
+        pub fn set_fail_next_transfer(
+            &mut self,
+            fail: bool,
+        ) -> core::result::Result<(), PSP22Error> {
+            self.fail_next_transfer = fail;
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn transfer_fee_percentage(&self) -> u8 {
+            self.transfer_fee_percentage
+        }
+
+        #[ink(message)]
+        pub fn is_blacklisted(&self, account: AccountId) -> bool {
+            self.blacklisted.get(&account).unwrap_or_default()
+        }
+
+        #[ink(message)]
+        pub fn fail_next_transfer(&self) -> bool {
+            self.fail_next_transfer
+        }
+
+        fn _transfer_with_fee(
+            &mut self,
+            from: AccountId,
+            to: AccountId,
+            value: Balance,
+            data: Vec<u8>,
+        ) -> core::result::Result<(), PSP22Error> {
+            let fee = value
+                .checked_mul(self.transfer_fee_percentage as Balance)
+                .ok_or(PSP22Error::Custom(String::from("Overflow")))?
+                / PERCENTAGE_MAX as Balance;
+            if fee > 0 {
+                self._burn_from(from, fee)?;
+            }
+            self._transfer_from_to(from, to, value - fee, data)
+        }
+    }
+}
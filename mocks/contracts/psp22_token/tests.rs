@@ -0,0 +1,116 @@
+use crate::token::*;
+use ink::{
+    env::{
+        test::{
+            self,
+            DefaultAccounts,
+        },
+        DefaultEnvironment,
+    },
+    prelude::vec::Vec,
+};
+use openbrush::{
+    contracts::psp22::{
+        PSP22Error,
+        PSP22,
+    },
+    traits::{
+        AccountId,
+        String,
+    },
+};
+
+fn default_accounts() -> DefaultAccounts<DefaultEnvironment> {
+    test::default_accounts::<DefaultEnvironment>()
+}
+fn set_caller(id: AccountId) {
+    test::set_caller::<DefaultEnvironment>(id);
+}
+
+#[ink::test]
+fn transfer_fee_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut token = MockPSP22Token::new(1_000, None, None, 18);
+    assert_eq!(
+        token.set_transfer_fee_percentage(101).unwrap_err(),
+        PSP22Error::Custom(String::from("InvalidPercentage"))
+    );
+    assert!(token.set_transfer_fee_percentage(10).is_ok());
+    assert_eq!(token.transfer_fee_percentage(), 10);
+
+    // the fee is burned from the sender
+    assert!(token.transfer(accounts.charlie, 100, Vec::new()).is_ok());
+    assert_eq!(token.balance_of(accounts.bob), 900);
+    assert_eq!(token.balance_of(accounts.charlie), 90);
+    assert_eq!(token.total_supply(), 990);
+
+    assert!(token.approve(accounts.django, 100).is_ok());
+    set_caller(accounts.django);
+    assert!(token
+        .transfer_from(accounts.bob, accounts.django, 50, Vec::new())
+        .is_ok());
+    assert_eq!(token.balance_of(accounts.bob), 850);
+    assert_eq!(token.balance_of(accounts.django), 45);
+    assert_eq!(token.allowance(accounts.bob, accounts.django), 50);
+}
+
+#[ink::test]
+fn blacklist_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut token = MockPSP22Token::new(1_000, None, None, 18);
+    assert!(token.set_blacklisted(accounts.charlie, true).is_ok());
+    assert!(token.is_blacklisted(accounts.charlie));
+
+    let blacklisted = PSP22Error::Custom(String::from("Blacklisted"));
+    assert_eq!(
+        token
+            .transfer(accounts.charlie, 100, Vec::new())
+            .unwrap_err(),
+        blacklisted
+    );
+
+    assert!(token.set_blacklisted(accounts.charlie, false).is_ok());
+    assert!(token.transfer(accounts.charlie, 100, Vec::new()).is_ok());
+
+    // blacklisted accounts cannot send either
+    assert!(token.set_blacklisted(accounts.charlie, true).is_ok());
+    set_caller(accounts.charlie);
+    assert_eq!(
+        token.transfer(accounts.bob, 100, Vec::new()).unwrap_err(),
+        blacklisted
+    );
+    assert_eq!(token.balance_of(accounts.charlie), 100);
+}
+
+#[ink::test]
+fn fail_next_transfer_works() {
+    let accounts = default_accounts();
+    set_caller(accounts.bob);
+
+    let mut token = MockPSP22Token::new(1_000, None, None, 18);
+    assert!(token.set_fail_next_transfer(true).is_ok());
+    assert!(token.fail_next_transfer());
+
+    let failed = PSP22Error::Custom(String::from("TransferFailed"));
+    assert_eq!(
+        token
+            .transfer(accounts.charlie, 100, Vec::new())
+            .unwrap_err(),
+        failed
+    );
+    // transfers keep failing until cleared
+    assert_eq!(
+        token
+            .transfer(accounts.charlie, 100, Vec::new())
+            .unwrap_err(),
+        failed
+    );
+
+    assert!(token.set_fail_next_transfer(false).is_ok());
+    assert!(token.transfer(accounts.charlie, 100, Vec::new()).is_ok());
+    assert_eq!(token.balance_of(accounts.charlie), 100);
+}
@@ -3,6 +3,7 @@ import type { ApiPromise } from '@polkadot/api'
 import type { KeyringPair } from '@polkadot/keyring/types'
 import { LastArrayElement } from 'type-fest'
 
+import AggregatorPriceOracle_factory from '../../types/constructors/aggregator_price_oracle'
 import Controller_factory from '../../types/constructors/controller'
 import DefaultInterestRateModel_factory from '../../types/constructors/default_interest_rate_model'
 import Faucet_factory from '../../types/constructors/faucet'
@@ -18,6 +19,7 @@ import PSP22Token_factory from '../../types/constructors/psp22_token'
 import WETH_factory from '../../types/constructors/weth'
 import WETHGateway_factory from '../../types/constructors/weth_gateway'
 
+import AggregatorPriceOracle from '../../types/contracts/aggregator_price_oracle'
 import Controller from '../../types/contracts/controller'
 import DefaultInterestRateModel from '../../types/contracts/default_interest_rate_model'
 import Faucet from '../../types/contracts/faucet'
@@ -94,6 +96,21 @@ export const deployPriceOracle = async ({
   return result
 }
 
+export const deployAggregatorPriceOracle = async ({
+  api,
+  signer,
+  args,
+  option = defaultOption(api),
+}: FactoryArgs<
+  AggregatorPriceOracle_factory['new']
+>): Promise<AggregatorPriceOracle> => {
+  const factory = new AggregatorPriceOracle_factory(api, signer)
+  const contract = await factory.new(...args, option)
+  const result = new AggregatorPriceOracle(contract.address, signer, api)
+  await afterDeployment(result.name, contract)
+  return result
+}
+
 export const deployFaucet = async ({
   api,
   signer,
@@ -0,0 +1,149 @@
+import { BN } from '@polkadot/util'
+import { ONE_ETHER, ROLE, ZERO_ADDRESS } from '../scripts/helper/constants'
+import {
+  deployAggregatorPriceOracle,
+  deployController,
+  deployDefaultInterestRateModel,
+  deployIncentivesController,
+  deployManager,
+} from '../scripts/helper/deploy_helper'
+import { preparePoolsWithPreparedTokens } from './testContractHelper'
+import { shouldNotRevert } from './testHelpers'
+
+describe('AggregatorPriceOracle spec', () => {
+  const setup = async () => {
+    const { api, alice: deployer, bob: guardian, charlie } = globalThis.setup
+
+    const manager = await deployManager({
+      api,
+      signer: deployer,
+      args: [ZERO_ADDRESS],
+    })
+    const controller = await deployController({
+      api,
+      signer: deployer,
+      args: [manager.address],
+    })
+    await shouldNotRevert(manager, 'setController', [controller.address])
+
+    const incentivesController = await deployIncentivesController({
+      api,
+      signer: deployer,
+      args: [null],
+    })
+    const rateModel = await deployDefaultInterestRateModel({
+      api,
+      signer: deployer,
+      args: [[0], [0], [0], [0]],
+    })
+    const pools = await preparePoolsWithPreparedTokens({
+      api,
+      controller,
+      rateModel,
+      manager: deployer,
+      incentivesController,
+    })
+
+    const oracle = await deployAggregatorPriceOracle({
+      api,
+      signer: deployer,
+      args: [],
+    })
+    await shouldNotRevert(oracle, 'setGuardian', [guardian.address])
+    await shouldNotRevert(oracle, 'setManager', [manager.address])
+    // the oracle pauses borrows through the manager
+    await shouldNotRevert(manager, 'grantRole', [
+      ROLE.PAUSE_GUARDIAN,
+      oracle.address,
+    ])
+
+    return { controller, pools, oracle, guardian, anyone: charlie }
+  }
+
+  const price = ONE_ETHER.mul(new BN(2))
+
+  it('fails with a pool not lending the asset', async () => {
+    const { pools, oracle, guardian } = await setup()
+    const { dai, usdc } = pools
+
+    const { value } = await oracle
+      .withSigner(guardian)
+      .query.setOverridePrice(
+        dai.token.address,
+        price,
+        60_000,
+        usdc.pool.address,
+      )
+    expect(value.ok.err).toStrictEqual({ poolAssetMismatch: null })
+  })
+
+  it('resumes the borrows it paused when cleared', async () => {
+    const { controller, pools, oracle, guardian } = await setup()
+    const { dai } = pools
+
+    const { events } = await oracle
+      .withSigner(guardian)
+      .tx.setOverridePrice(
+        dai.token.address,
+        price,
+        60_000,
+        dai.pool.address,
+      )
+    expect(events[0].name).toEqual('PriceOverridden')
+    expect(events[0].args.borrowPaused).toBe(true)
+    expect(
+      (await controller.query.borrowGuardianPaused(dai.pool.address)).value.ok,
+    ).toBe(true)
+    expect(
+      (await oracle.query.overridePausedPool(dai.token.address)).value.ok,
+    ).toBe(dai.pool.address)
+
+    const { events: cleared } = await oracle
+      .withSigner(guardian)
+      .tx.clearOverridePrice(dai.token.address)
+    expect(cleared[0].name).toEqual('OverrideCleared')
+    expect(cleared[0].args.borrowResumed).toBe(true)
+    expect(
+      (await controller.query.borrowGuardianPaused(dai.pool.address)).value.ok,
+    ).toBe(false)
+    expect(
+      (await oracle.query.overridePausedPool(dai.token.address)).value.ok,
+    ).toBeNull()
+  })
+
+  it('resumes the borrows it paused once expired', async () => {
+    const { controller, pools, oracle, guardian, anyone } = await setup()
+    const { dai } = pools
+
+    await oracle
+      .withSigner(guardian)
+      .tx.setOverridePrice(
+        dai.token.address,
+        price,
+        2_000,
+        dai.pool.address,
+      )
+    const { value } = await oracle
+      .withSigner(anyone)
+      .query.clearExpiredOverride(dai.token.address)
+    expect(value.ok.err).toStrictEqual({ overrideNotExpired: null })
+
+    // the override expires, but borrows stay paused until it is cleared
+    await new Promise((resolve) => setTimeout(resolve, 4000))
+    expect((await oracle.query.getPrice(dai.token.address)).value.ok).toBeNull()
+    expect(
+      (await controller.query.borrowGuardianPaused(dai.pool.address)).value.ok,
+    ).toBe(true)
+
+    const { events } = await oracle
+      .withSigner(anyone)
+      .tx.clearExpiredOverride(dai.token.address)
+    expect(events[0].args.borrowResumed).toBe(true)
+    expect(
+      (await controller.query.borrowGuardianPaused(dai.pool.address)).value.ok,
+    ).toBe(false)
+    expect(
+      (await oracle.query.overridePrice(dai.token.address)).value.ok,
+    ).toBeNull()
+  })
+})
@@ -48,7 +48,7 @@ describe('Controller spec', () => {
     const incentivesController = await deployIncentivesController({
       api,
       signer: deployer,
-      args: [],
+      args: [null],
     })
 
     // initialize
@@ -13,7 +13,9 @@ import Controller from '../types/contracts/controller'
 import Contract from '../types/contracts/default_interest_rate_model'
 import FlashloanGateway from '../types/contracts/flashloan_gateway'
 import FlashloanReceiver from '../types/contracts/flashloan_receiver'
+import { ExecutionMode } from '../types/types-arguments/flashloan_receiver'
 import { FlashLoan } from '../types/event-types/flashloan_gateway'
+import { FlashMint } from '../types/event-types/pool'
 import {
   PoolContracts,
   Pools,
@@ -57,7 +59,7 @@ describe('Controller spec', () => {
     const incentivesController = await deployIncentivesController({
       api,
       signer: deployer,
-      args: [],
+      args: [null],
     })
 
     const pools = await preparePoolsWithPreparedTokens({
@@ -188,11 +190,12 @@ describe('Controller spec', () => {
 
     expect(events).toHaveLength(1)
     expectToEmit<FlashLoan>(events[0], 'FlashLoan', {
-      target: flashloanReceiver.address,
+      receiver: flashloanReceiver.address,
       initiator: users[0].address,
       asset: dai.token.address,
       amount: flashLoanAmount,
       premium: premiumAmount,
+      protocolPremium: 0,
     })
 
     const user0Balance = (
@@ -204,6 +207,127 @@ describe('Controller spec', () => {
       await dai.token.query.balanceOf(dai.pool.address)
     ).value.ok.toNumber()
     expect(poolBalance).toEqual(depositedDai + premiumAmount)
+
+    // the premium accrues to the reserves of the pool
+    const totalReserves = (
+      await dai.pool.query.totalReserves()
+    ).value.ok.toNumber()
+    expect(totalReserves).toEqual(premiumAmount)
+  })
+
+  it('User 0 takes a Flash Loan with a treasury set, the premium is split between the treasury and the pool', async () => {
+    const treasury = users[2]
+    const premiumToProtocol = 3000
+    await shouldNotRevert(flashloanGateway, 'setTreasury', [treasury.address])
+    await shouldNotRevert(flashloanGateway, 'setFlashloanPremiumToProtocol', [
+      premiumToProtocol,
+    ])
+
+    const premiumTotal = (
+      await flashloanGateway.query.flashloanPremiumTotal()
+    ).value.ok.toNumber()
+
+    const flashLoanAmount = 200_000
+    const premiumAmount = (flashLoanAmount * premiumTotal) / 10000
+    const protocolPremium = Math.floor(
+      (premiumAmount * premiumToProtocol) / 10000,
+    )
+    await shouldNotRevert(dai.token.withSigner(users[0]), 'approve', [
+      flashloanReceiver.address,
+      premiumAmount,
+    ])
+    const poolBalanceBefore = (
+      await dai.token.query.balanceOf(dai.pool.address)
+    ).value.ok.toNumber()
+
+    await shouldNotRevert(flashloanGateway.withSigner(users[0]), 'flashloan', [
+      flashloanReceiver.address,
+      [dai.token.address],
+      [flashLoanAmount],
+      [0],
+      users[0].address,
+      [],
+    ])
+
+    const treasuryBalance = (
+      await dai.token.query.balanceOf(treasury.address)
+    ).value.ok.toNumber()
+    expect(treasuryBalance).toEqual(protocolPremium)
+    const poolBalance = (
+      await dai.token.query.balanceOf(dai.pool.address)
+    ).value.ok.toNumber()
+    expect(poolBalance).toEqual(
+      poolBalanceBefore + premiumAmount - protocolPremium,
+    )
+
+    await shouldNotRevert(flashloanGateway, 'setFlashloanPremiumToProtocol', [
+      0,
+    ])
+  })
+
+  it('User 0 takes a Flash Loan with params, the receiver gets the initiator and the params', async () => {
+    const premiumTotal = (
+      await flashloanGateway.query.flashloanPremiumTotal()
+    ).value.ok.toNumber()
+
+    const flashLoanAmount = 200_000
+    const premiumAmount = (flashLoanAmount * premiumTotal) / 10000
+    await shouldNotRevert(dai.token.withSigner(users[0]), 'approve', [
+      flashloanReceiver.address,
+      premiumAmount,
+    ])
+    const params = [1, 2, 3]
+    await shouldNotRevert(flashloanGateway.withSigner(users[0]), 'flashloan', [
+      flashloanReceiver.address,
+      [dai.token.address],
+      [flashLoanAmount],
+      [0],
+      users[0].address,
+      params,
+    ])
+
+    expect(
+      (await flashloanReceiver.query.lastInitiator()).value.ok,
+    ).toEqual(users[0].address)
+    expect(
+      (await flashloanReceiver.query.lastParams()).value.ok.map(Number),
+    ).toEqual(params)
+  })
+
+  it('User 0 takes a Flash Loan through an exempted receiver, no premium is charged', async () => {
+    await shouldNotRevert(flashloanGateway, 'setPremiumExemption', [
+      flashloanReceiver.address,
+      0,
+    ])
+
+    const flashLoanAmount = 200_000
+    const userBalanceBefore = (
+      await dai.token.query.balanceOf(users[0].address)
+    ).value.ok.toNumber()
+    const { events } = await shouldNotRevert(
+      flashloanGateway.withSigner(users[0]),
+      'flashloan',
+      [
+        flashloanReceiver.address,
+        [dai.token.address],
+        [flashLoanAmount],
+        [0],
+        users[0].address,
+        [],
+      ],
+    )
+
+    expect(events).toHaveLength(1)
+    expect(events[0].args.premium.toNumber()).toEqual(0)
+    const userBalance = (
+      await dai.token.query.balanceOf(users[0].address)
+    ).value.ok.toNumber()
+    expect(userBalance).toEqual(userBalanceBefore)
+
+    await shouldNotRevert(flashloanGateway, 'setPremiumExemption', [
+      flashloanReceiver.address,
+      null,
+    ])
   })
 
   it('User 0 try to Flash Loan with mod 1 (revert expected for insufficient liquidity)', async () => {
@@ -277,12 +401,9 @@ describe('Controller spec', () => {
 
     await flashloanReceiver.tx.setFailExecutionTransfer(false)
 
-    const premiumTotal = (
-      await flashloanGateway.query.flashloanPremiumTotal()
-    ).value.ok.toNumber()
-
+    // borrowed amounts pay interest instead of the premium
     const flashLoanAmount = 80_000
-    const premiumAmount = (flashLoanAmount * premiumTotal) / 10000
+    const premiumAmount = 0
     const { events } = await shouldNotRevert(
       flashloanGateway.withSigner(users[0]),
       'flashloan',
@@ -298,11 +419,12 @@ describe('Controller spec', () => {
 
     expect(events).toHaveLength(1)
     expectToEmit<FlashLoan>(events[0], 'FlashLoan', {
-      target: flashloanReceiver.address,
+      receiver: flashloanReceiver.address,
       initiator: users[0].address,
       asset: dai.token.address,
       amount: flashLoanAmount,
       premium: premiumAmount,
+      protocolPremium: 0,
     })
 
     const borrowBalance = (
@@ -312,6 +434,263 @@ describe('Controller spec', () => {
     expect(borrowBalance).toEqual(flashLoanAmount)
   })
 
+  it('User 0 takes a DAI flashloan with mode = 1 on behalf of User 1, the debt is opened for User 1 within its delegated allowance', async () => {
+    const deposited = 100_000
+    await shouldNotRevert(usdc.token, 'mint', [users[1].address, deposited])
+    await shouldNotRevert(usdc.token.withSigner(users[1]), 'approve', [
+      usdc.pool.address,
+      deposited,
+    ])
+    await shouldNotRevert(usdc.pool.withSigner(users[1]), 'mint', [deposited])
+
+    const flashLoanAmount = 50_000
+    const withoutAllowance = (
+      await flashloanGateway
+        .withSigner(users[0])
+        .query.flashloan(
+          flashloanReceiver.address,
+          [dai.token.address],
+          [flashLoanAmount],
+          [1],
+          users[1].address,
+          [],
+        )
+    ).value.ok
+    expect(withoutAllowance.err).toStrictEqual({
+      pool: 'InsufficientDelegateAllowance',
+    })
+
+    await shouldNotRevert(dai.pool.withSigner(users[1]), 'approveDelegate', [
+      users[0].address,
+      flashLoanAmount,
+    ])
+    await shouldNotRevert(flashloanGateway.withSigner(users[0]), 'flashloan', [
+      flashloanReceiver.address,
+      [dai.token.address],
+      [flashLoanAmount],
+      [1],
+      users[1].address,
+      [],
+    ])
+
+    const borrowBalance = (
+      await dai.pool.query.borrowBalanceStored(users[1].address)
+    ).value.ok.toNumber()
+    expect(borrowBalance).toEqual(flashLoanAmount)
+    const allowance = (
+      await dai.pool.query.delegateAllowance(users[1].address, users[0].address)
+    ).value.ok.toNumber()
+    expect(allowance).toEqual(0)
+  })
+
+  describe('with a misbehaving receiver', () => {
+    const flashLoanAmount = 200_000
+    const approvePremium = async () => {
+      const premiumTotal = (
+        await flashloanGateway.query.flashloanPremiumTotal()
+      ).value.ok.toNumber()
+      const premiumAmount = (flashLoanAmount * premiumTotal) / 10000
+      await shouldNotRevert(dai.token.withSigner(users[0]), 'approve', [
+        flashloanReceiver.address,
+        premiumAmount,
+      ])
+      return premiumAmount
+    }
+    const queryFlashloan = async () =>
+      (
+        await flashloanGateway
+          .withSigner(users[0])
+          .query.flashloan(
+            flashloanReceiver.address,
+            [dai.token.address],
+            [flashLoanAmount],
+            [0],
+            users[0].address,
+            [],
+          )
+      ).value.ok
+    const balanceOf = async (account: string) =>
+      (await dai.token.query.balanceOf(account)).value.ok.toNumber()
+
+    afterAll(async () => {
+      await flashloanReceiver.tx.setExecutionMode(ExecutionMode.normal)
+    })
+
+    it('receiver repays half of the amount (revert expected)', async () => {
+      await flashloanReceiver.tx.setExecutionMode(
+        ExecutionMode.partialRepayment,
+      )
+      await approvePremium()
+      expect((await queryFlashloan()).err).toStrictEqual({
+        psp22: 'InsufficientAllowance',
+      })
+    })
+
+    it('receiver repays with another asset (revert expected)', async () => {
+      await flashloanReceiver.tx.setExecutionMode(ExecutionMode.wrongAsset)
+      await flashloanReceiver.tx.setWrongAsset(usdc.token.address)
+      await approvePremium()
+      expect((await queryFlashloan()).err).toStrictEqual({
+        psp22: 'InsufficientAllowance',
+      })
+    })
+
+    it('receiver requests the cash of the pool directly, the pool rejects it and the loan is repaid', async () => {
+      await flashloanReceiver.tx.setExecutionMode(ExecutionMode.reenterPool)
+      const premiumAmount = await approvePremium()
+      const poolBalanceBefore = await balanceOf(dai.pool.address)
+      const receiverBalanceBefore = await balanceOf(flashloanReceiver.address)
+
+      await shouldNotRevert(
+        flashloanGateway.withSigner(users[0]),
+        'flashloan',
+        [
+          flashloanReceiver.address,
+          [dai.token.address],
+          [flashLoanAmount],
+          [0],
+          users[0].address,
+          [],
+        ],
+      )
+      expect(await balanceOf(dai.pool.address)).toEqual(
+        poolBalanceBefore + premiumAmount,
+      )
+      expect(await balanceOf(flashloanReceiver.address)).toEqual(
+        receiverBalanceBefore,
+      )
+    })
+
+    it('receiver reenters the gateway, no flashloan is paid out twice', async () => {
+      await flashloanReceiver.tx.setExecutionMode(ExecutionMode.reenterGateway)
+      await approvePremium()
+      const poolBalanceBefore = await balanceOf(dai.pool.address)
+      const receiverBalanceBefore = await balanceOf(flashloanReceiver.address)
+
+      // the reentrant call is rejected, either alone or with the whole flashloan
+      await flashloanGateway
+        .withSigner(users[0])
+        .tx.flashloan(
+          flashloanReceiver.address,
+          [dai.token.address],
+          [flashLoanAmount],
+          [0],
+          users[0].address,
+          [],
+        )
+        .catch(() => undefined)
+      expect(await balanceOf(dai.pool.address)).toBeGreaterThanOrEqual(
+        poolBalanceBefore,
+      )
+      expect(await balanceOf(flashloanReceiver.address)).toEqual(
+        receiverBalanceBefore,
+      )
+    })
+  })
+
+  describe('flash mint', () => {
+    const flashMintAmount = 1_000_000
+    const exchangeRate = async () =>
+      new BN((await dai.pool.query.exchangeRateStored()).value.ok.toHuman())
+    const principalBalanceOf = async (account: string) =>
+      (await dai.pool.query.principalBalanceOf(account)).value.ok.toNumber()
+    const principalTotalSupply = async () =>
+      (await dai.pool.query.principalTotalSupply()).value.ok.toNumber()
+
+    afterAll(async () => {
+      await flashloanReceiver.tx.setExecutionMode(ExecutionMode.normal)
+    })
+
+    it('mints pool tokens to the receiver during the callback and burns them', async () => {
+      const rateBefore = await exchangeRate()
+      const supplyBefore = await principalTotalSupply()
+
+      const { events } = await shouldNotRevert(
+        dai.pool.withSigner(users[0]),
+        'flashMint',
+        [flashMintAmount, flashloanReceiver.address, []],
+      )
+      expectToEmit<FlashMint>(
+        events.find(({ name }) => name === 'FlashMint'),
+        'FlashMint',
+        {
+          receiver: flashloanReceiver.address,
+          initiator: users[0].address,
+          amount: flashMintAmount,
+          premium: 0,
+        },
+      )
+
+      expect(await principalBalanceOf(flashloanReceiver.address)).toEqual(0)
+      expect(await principalTotalSupply()).toEqual(supplyBefore)
+      expect((await exchangeRate()).eq(rateBefore)).toBeTruthy()
+    })
+
+    it('fails while minting is paused', async () => {
+      await shouldNotRevert(controller, 'setMintGuardianPaused', [
+        dai.pool.address,
+        true,
+      ])
+      const { value } = await dai.pool
+        .withSigner(users[0])
+        .query.flashMint(flashMintAmount, flashloanReceiver.address, [])
+      expect(value.ok.err).toStrictEqual({ controller: 'MintIsPaused' })
+
+      await shouldNotRevert(controller, 'setMintGuardianPaused', [
+        dai.pool.address,
+        false,
+      ])
+    })
+
+    it('receiver reentering mint during the callback gets pool tokens at the undiluted exchange rate', async () => {
+      const supplied = 10_000
+      await shouldNotRevert(dai.token, 'mint', [
+        flashloanReceiver.address,
+        supplied,
+      ])
+      await flashloanReceiver.tx.setExecutionMode(ExecutionMode.reenterMint)
+      const rateBefore = await exchangeRate()
+      const balanceBefore = await principalBalanceOf(flashloanReceiver.address)
+
+      await shouldNotRevert(dai.pool.withSigner(users[0]), 'flashMint', [
+        flashMintAmount,
+        flashloanReceiver.address,
+        [],
+      ])
+
+      // the tokens minted at a diluted rate would outlive the flash mint
+      const expected = new BN(supplied).mul(ONE_ETHER).div(rateBefore).toNumber()
+      const minted =
+        (await principalBalanceOf(flashloanReceiver.address)) - balanceBefore
+      expect(Math.abs(minted - expected)).toBeLessThanOrEqual(1)
+      expect((await exchangeRate()).eq(rateBefore)).toBeTruthy()
+    })
+  })
+
+  it('syncs the pools of the listed markets by underlying asset', async () => {
+    const { events } = await shouldNotRevert(flashloanGateway, 'syncPools', [])
+    expect(events).toHaveLength(3)
+    expect(events.map((e) => e.name)).toEqual(
+      Array(3).fill('PoolRegistered'),
+    )
+    for (const { token, pool } of [dai, usdc, usdt]) {
+      expect((await flashloanGateway.query.poolOf(token.address)).value.ok).toBe(
+        pool.address,
+      )
+    }
+    expect(
+      (await flashloanGateway.query.poolOf(dai.pool.address)).value.ok,
+    ).toBeNull()
+
+    // pools already registered are not registered again
+    const { events: resyncEvents } = await shouldNotRevert(
+      flashloanGateway,
+      'syncPools',
+      [],
+    )
+    expect(resyncEvents).toHaveLength(0)
+  })
+
   it('tries to take a flashloan that is not listed in the market', async () => {
     const flashLoanAmount = 100_000
     const result = (
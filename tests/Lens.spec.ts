@@ -252,6 +252,44 @@ describe('Lens', () => {
       expect(res.borrowGuardianPaused).toBeFalsy()
     })
 
+    it('Pool Data', async () => {
+      const token = tokens[0]
+      const pool = pools[0]
+      const {
+        value: { ok: res },
+      } = await lens.query.poolData(pool.address)
+
+      expect(res.pool).toBe(pool.address)
+      expect(res.underlyingAssetAddress).toBe(token.address)
+      expect(res.isListed).toBeTruthy()
+      expect(res.totalCash.toNumber()).toBe(0)
+      expect(res.totalSupply.toNumber()).toBe(0)
+      expect(res.totalBorrows.toNumber()).toBe(0)
+      expect(res.totalReserves.toNumber()).toBe(0)
+      expect(res.exchangeRateCurrent.toHuman()).toEqual(ONE_ETHER.toString())
+      expect(res.collateralFactorMantissa.toHuman()).toEqual(
+        collateralFactor.toString(),
+      )
+      expect(res.reserveFactorMantissa.toHuman()).toEqual(
+        reserveFactor.toString(),
+      )
+      // @ts-ignore
+      expect(new ReturnNumber(res.borrowCap).toHuman()).toBe(
+        borrowCap.toString(),
+      )
+      expect(res.liquidationThreshold.toNumber()).toBe(10000)
+      expect(res.mintGuardianPaused).toBeFalsy()
+      expect(res.borrowGuardianPaused).toBeFalsy()
+      expect(res.underlyingPrice.toNumber()).toBe(price)
+
+      const {
+        value: { ok: all },
+      } = await lens.query.allPoolData(controller.address)
+      expect(all.map(({ pool }) => pool)).toEqual(
+        pools.map(({ address }) => address),
+      )
+    })
+
     it('Pool Balances', async () => {
       const pool = pools[0]
       const {
@@ -292,6 +330,28 @@ describe('Lens', () => {
       expect(res.toNumber()).toBe(0)
     })
 
+    it('Reward Speeds and APRs', async () => {
+      // the pools have no incentives controller
+      const pool = pools[0]
+      const {
+        value: {
+          ok: [speeds],
+        },
+      } = await lens.query.rewardSpeedsAll([pool.address])
+      expect(speeds.pool).toBe(pool.address)
+      expect(speeds.supplySpeed.toNumber()).toBe(0)
+      expect(speeds.borrowSpeed.toNumber()).toBe(0)
+
+      const {
+        value: {
+          ok: [apr],
+        },
+      } = await lens.query.rewardAprAll([pool.address], price)
+      expect(apr.pool).toBe(pool.address)
+      expect(apr.supplyApr.toHuman()).toEqual('0')
+      expect(apr.borrowApr.toHuman()).toEqual('0')
+    })
+
     it('Configuration', async () => {
       const {
         value: { ok: res },
@@ -506,5 +566,81 @@ describe('Lens', () => {
       expect(metadata.mintGuardianPaused).toBeTruthy()
       expect(metadata.borrowGuardianPaused).toBeTruthy()
     })
+    it('account data', async () => {
+      const depositAmount = 100
+      const borrowAmount = 50
+      const pool = pools[0].withSigner(signer)
+      const token = tokens[0].withSigner(signer)
+
+      await shouldNotRevert(token, 'approve', [pool.address, depositAmount])
+      await shouldNotRevert(pool, 'mint', [depositAmount, { gasLimit }])
+      await shouldNotRevert(pool, 'borrow', [borrowAmount, { gasLimit }])
+
+      const {
+        value: { ok: res },
+      } = await lens.query.accountData(controller.address, signer.address)
+
+      expect(res.pools).toHaveLength(1)
+      const [position] = res.pools
+      expect(position.pool).toBe(pool.address)
+      expect(position.supplyBalance.toNumber()).toBe(depositAmount)
+      expect(position.borrowBalance.toNumber()).toBe(borrowAmount)
+      expect(position.usingAsCollateral).toBeTruthy()
+      expect(res.liquidity.toHuman()).not.toBe('0')
+      expect(res.shortfall.toHuman()).toBe('0')
+      expect(new BN(res.healthFactor.toHuman()).gt(ONE_ETHER)).toBeTruthy()
+    })
+    it('underwater accounts', async () => {
+      const depositAmount = 100
+      const borrowAmount = 50
+      const pool = pools[0].withSigner(signer)
+      const token = tokens[0].withSigner(signer)
+
+      await shouldNotRevert(token, 'approve', [pool.address, depositAmount])
+      await shouldNotRevert(pool, 'mint', [depositAmount, { gasLimit }])
+      await shouldNotRevert(pool, 'borrow', [borrowAmount, { gasLimit }])
+
+      const {
+        value: { ok: healthy },
+      } = await lens.query.underwaterAccounts(controller.address, [
+        signer.address,
+      ])
+      expect(healthy).toHaveLength(0)
+
+      // the collateral no longer backs the borrow
+      await shouldNotRevert(controller, 'setCollateralFactorMantissa', [
+        pool.address,
+        [0],
+      ])
+      const {
+        value: { ok: res },
+      } = await lens.query.underwaterAccounts(controller.address, [
+        signer.address,
+      ])
+      expect(res).toHaveLength(1)
+      const [underwater] = res
+      expect(underwater.account).toBe(signer.address)
+      expect(underwater.shortfall.toHuman()).not.toBe('0')
+      expect(underwater.opportunities).toHaveLength(1)
+      const [opportunity] = underwater.opportunities
+      expect(opportunity.poolBorrowed).toBe(pool.address)
+      expect(opportunity.poolCollateral).toBe(pool.address)
+      expect(opportunity.maxRepayAmount.toNumber()).toBeGreaterThan(0)
+      expect(opportunity.maxRepayAmount.toNumber()).toBeLessThanOrEqual(
+        borrowAmount,
+      )
+
+      const {
+        value: { ok: borrowers },
+      } = await lens.query.underwaterBorrowers(
+        controller.address,
+        pool.address,
+        0,
+        10,
+      )
+      expect(borrowers.map(({ account }) => account)).toEqual([
+        signer.address,
+      ])
+    })
   })
 })
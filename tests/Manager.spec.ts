@@ -30,7 +30,7 @@ describe('Manager spec', () => {
     const incentivesController = await deployIncentivesController({
       api,
       signer: deployer,
-      args: [],
+      args: [null],
     })
 
     // initialize
@@ -60,7 +60,7 @@ describe('Pool spec 1', () => {
     const incentivesController = await deployIncentivesController({
       api,
       signer: deployer,
-      args: [],
+      args: [null],
     })
 
     const pools = await preparePoolsWithPreparedTokens({
@@ -115,6 +115,39 @@ describe('Pool spec 1', () => {
     ).toEqual('10000')
   })
 
+  it('.get_pool_metadata', async () => {
+    const { pools, controller } = await setup()
+    const { pool, token } = pools.dai
+    const {
+      value: { ok: metadata },
+    } = await pool.query.getPoolMetadata()
+    expect(metadata.underlying).toEqual(token.address)
+    expect(metadata.decimals).toEqual(18)
+    expect(metadata.cash.toNumber()).toEqual(0)
+    expect(metadata.totalSupply.toNumber()).toEqual(0)
+    expect(metadata.totalBorrows.toNumber()).toEqual(0)
+    expect(metadata.totalReserves.toNumber()).toEqual(0)
+    expect(metadata.liquidationThreshold.toString()).toEqual('10000')
+    expect(metadata.mintPaused).toBeFalsy()
+    expect(metadata.borrowPaused).toBeFalsy()
+
+    await shouldNotRevert(controller, 'setMintGuardianPaused', [
+      pool.address,
+      true,
+    ])
+    await shouldNotRevert(controller, 'setBorrowGuardianPaused', [
+      pool.address,
+      true,
+    ])
+    const {
+      value: { ok: paused },
+    } = await pool.query.getPoolMetadata()
+    expect(paused.mintPaused).toBeTruthy()
+    expect(paused.borrowPaused).toBeTruthy()
+    expect(paused.seizePaused).toBeFalsy()
+    expect(paused.transferPaused).toBeFalsy()
+  })
+
   describe('.mint', () => {
     let deployer: KeyringPair
     let token: PSP22Token
@@ -893,6 +926,40 @@ describe('Pool spec 1', () => {
     })
   })
 
+  it('.absorb (fail when borrower has supply not used as collateral)', async () => {
+    const { deployer, pools, users } = await setup()
+    const { usdc, usdt } = pools
+    const [borrower] = users
+
+    // add liquidity to usdc pool
+    await usdc.token.tx.mint(deployer.address, toDec6(10_000))
+    await usdc.token.tx.approve(usdc.pool.address, toDec6(10_000))
+    await usdc.pool.tx.mint(toDec6(10_000))
+
+    // supply & borrow usdc
+    await usdc.token.tx.mint(borrower.address, toDec6(2_000))
+    await usdc.token
+      .withSigner(borrower)
+      .tx.approve(usdc.pool.address, toDec6(2_000))
+    await usdc.pool.withSigner(borrower).tx.mint(toDec6(2_000))
+    await usdc.pool.withSigner(borrower).tx.borrow(toDec6(1_000))
+
+    // supply usdt without using it as collateral
+    await usdt.token.tx.mint(borrower.address, toDec6(1_000))
+    await usdt.token
+      .withSigner(borrower)
+      .tx.approve(usdt.pool.address, toDec6(1_000))
+    await usdt.pool.withSigner(borrower).tx.mint(toDec6(1_000))
+    await usdt.pool.withSigner(borrower).tx.setUseReserveAsCollateral(false)
+    expect(
+      (await usdt.pool.query.usingReserveAsCollateral(borrower.address)).value
+        .ok,
+    ).toEqual(false)
+
+    const { value } = await usdc.pool.query.absorb(borrower.address)
+    expect(value.ok.err).toEqual({ controller: 'BorrowerHasCollateral' })
+  })
+
   it('.seize (cannot call by users)', async () => {
     const {
       api,
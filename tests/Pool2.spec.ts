@@ -20,6 +20,7 @@ import {
   ReserveUsedAsCollateralEnabled,
 } from '../types/event-types/pool'
 import { Transfer } from '../types/event-types/psp22_token'
+import { RateMode } from '../types/types-arguments/pool'
 import {
   PoolContracts,
   Pools,
@@ -62,7 +63,7 @@ describe('Pool spec 2', () => {
     const incentivesController = await deployIncentivesController({
       api,
       signer: deployer,
-      args: [],
+      args: [null],
     })
 
     const pools = await preparePoolsWithPreparedTokens({
@@ -823,6 +824,68 @@ describe('Pool spec 2', () => {
         ).toBe('0')
         expect((await pool.query.totalBorrows()).value.ok.toString()).toBe('0')
       })
+      it('stable borrows are part of the accrued totals, reserves and exchange rate', async () => {
+        const { pool, users, pools, token, gasLimit } = await setupExtended()
+        const [alice, bob] = users
+        const otherPool = pools.usdt
+        const deposit = new BN(1000).mul(BN_TEN.pow(new BN(8)))
+        const borrow = new BN(300).mul(BN_TEN.pow(new BN(8)))
+        await pool.tx.setReserveFactorMantissa([ONE_ETHER.div(new BN(10))])
+        await pool.tx.setStableRateConfig(true, [0], [ONE_ETHER])
+        await token.withSigner(alice).tx.mint(alice.address, deposit)
+        await token.withSigner(alice).tx.approve(pool.address, deposit)
+        await pool.withSigner(alice).tx.mint(deposit, { gasLimit })
+        await otherPool.token
+          .withSigner(bob)
+          .tx.mint(bob.address, ONE_ETHER.toString())
+        await otherPool.token
+          .withSigner(bob)
+          .tx.approve(otherPool.pool.address, ONE_ETHER.toString())
+        await otherPool.pool.withSigner(bob).tx.mint(ONE_ETHER, { gasLimit })
+        await pool.withSigner(bob).tx.borrow(borrow, { gasLimit })
+        await pool
+          .withSigner(bob)
+          .tx.borrowWithRateMode(borrow, RateMode.stable, { gasLimit })
+        // wait 2 sec
+        await new Promise((resolve) => setTimeout(resolve, 2000))
+
+        const { events } = await pool.tx.accrueInterest({ gasLimit })
+        const totalBorrows = (await pool.query.totalBorrows()).value.ok
+        const totalStableBorrows = (await pool.query.totalStableBorrows())
+          .value.ok
+        const totalReserves = (await pool.query.totalReserves()).value.ok
+        expect(totalStableBorrows.toNumber()).toBeGreaterThan(
+          borrow.toNumber(),
+        )
+        expect(totalBorrows.toNumber()).toBeGreaterThan(
+          totalStableBorrows.toNumber() + borrow.toNumber(),
+        )
+        const accrued = events.find((e) => e.name === 'AccrueInterest')
+        expect(accrued.args.newTotalBorrows.toString()).toBe(
+          totalBorrows.toString(),
+        )
+
+        // the reserve factor applies to stable interest as well
+        const stableInterest = new BN(totalStableBorrows.toString()).sub(
+          borrow,
+        )
+        expect(
+          new BN(totalReserves.toString()).gte(stableInterest.divn(10)),
+        ).toBe(true)
+
+        const cash = (await pool.query.getCashPrior()).value.ok
+        const supply = (await pool.query.principalTotalSupply()).value.ok
+        const rate = new BN(
+          (await pool.query.exchangeRateStored()).value.ok.toHuman(),
+        )
+        const want = new BN(cash.toString())
+          .add(new BN(totalBorrows.toString()))
+          .sub(new BN(totalReserves.toString()))
+          .mul(ONE_ETHER)
+          .div(new BN(supply.toString()))
+        expect(rate.toString()).toBe(want.toString())
+        expect(rate.gt(ONE_ETHER)).toBe(true)
+      })
     })
   })
 
@@ -71,7 +71,7 @@ describe('WETHGateway spec', () => {
     const incentivesController = await deployIncentivesController({
       api,
       signer: deployer,
-      args: [],
+      args: [null],
     })
 
     const pools = await preparePoolsWithPreparedTokens({